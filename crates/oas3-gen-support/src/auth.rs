@@ -0,0 +1,286 @@
+use std::{
+  fmt,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::runtime::Instant;
+
+/// Tokens are refreshed this long before the server-reported expiry.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+  #[error("requesting access token from `{token_url}`: {source}")]
+  Http {
+    token_url: String,
+    #[source]
+    source: reqwest::Error,
+  },
+
+  #[error("token endpoint `{token_url}` returned {status}: {body}")]
+  Status {
+    token_url: String,
+    status: reqwest::StatusCode,
+    body: String,
+  },
+
+  #[error("parsing token response from `{token_url}`: {source}")]
+  Format {
+    token_url: String,
+    #[source]
+    source: serde_json::Error,
+  },
+}
+
+/// Username and password for HTTP basic authentication.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BasicAuth {
+  pub username: String,
+  pub password: Option<String>,
+}
+
+impl BasicAuth {
+  #[must_use]
+  pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+    Self {
+      username: username.into(),
+      password: Some(password.into()),
+    }
+  }
+}
+
+impl fmt::Debug for BasicAuth {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("BasicAuth")
+      .field("username", &self.username)
+      .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+      .finish()
+  }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+  access_token: String,
+  #[serde(default)]
+  expires_in: Option<u64>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+  access_token: String,
+  expires_at: Option<Instant>,
+}
+
+impl CachedToken {
+  fn is_fresh(&self) -> bool {
+    self.expires_at.is_none_or(|expires_at| Instant::now() < expires_at)
+  }
+}
+
+/// OAuth2 client-credentials grant that fetches and caches bearer tokens.
+///
+/// Generated clients call [`ClientCredentials::access_token`] before each request that
+/// requires the scheme. A token is requested from `token_url` on first use and reused
+/// until shortly before its `expires_in` elapses. Clones share the same cached token.
+///
+/// The client id and secret are sent with HTTP basic authentication, as RFC 6749
+/// section 2.3.1 requires every authorization server to support.
+#[derive(Clone)]
+pub struct ClientCredentials {
+  token_url: String,
+  client_id: String,
+  client_secret: String,
+  scopes: Vec<String>,
+  token: Arc<Mutex<Option<CachedToken>>>,
+}
+
+impl ClientCredentials {
+  #[must_use]
+  pub fn new(token_url: impl Into<String>, client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+    Self {
+      token_url: token_url.into(),
+      client_id: client_id.into(),
+      client_secret: client_secret.into(),
+      scopes: vec![],
+      token: Arc::default(),
+    }
+  }
+
+  /// Request these scopes instead of the server's default.
+  #[must_use]
+  pub fn with_scopes(mut self, scopes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self.scopes = scopes.into_iter().map(Into::into).collect();
+    self
+  }
+
+  /// Returns the token endpoint URL.
+  #[must_use]
+  pub fn token_url(&self) -> &str {
+    &self.token_url
+  }
+
+  /// Returns a cached access token, or requests a new one through `client`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the token endpoint cannot be reached, responds with a
+  /// non-success status, or returns a body without an `access_token`.
+  pub async fn access_token(&self, client: &reqwest::Client) -> Result<String, AuthError> {
+    if let Some(token) = self.lock().as_ref().filter(|token| token.is_fresh()) {
+      return Ok(token.access_token.clone());
+    }
+
+    let token = self.request_token(client).await?;
+    let access_token = token.access_token.clone();
+    *self.lock() = Some(token);
+    Ok(access_token)
+  }
+
+  /// Drop the cached token so the next request fetches a new one.
+  pub fn invalidate(&self) {
+    *self.lock() = None;
+  }
+
+  async fn request_token(&self, client: &reqwest::Client) -> Result<CachedToken, AuthError> {
+    let http_error = |source| AuthError::Http {
+      token_url: self.token_url.clone(),
+      source,
+    };
+
+    let mut form = vec![("grant_type", "client_credentials".to_string())];
+    if !self.scopes.is_empty() {
+      form.push(("scope", self.scopes.join(" ")));
+    }
+
+    let requested_at = Instant::now();
+    let response = client
+      .post(&self.token_url)
+      .basic_auth(&self.client_id, Some(&self.client_secret))
+      .header(reqwest::header::ACCEPT, "application/json")
+      .form(&form)
+      .send()
+      .await
+      .map_err(http_error)?;
+
+    let status = response.status();
+    let body = response.bytes().await.map_err(http_error)?;
+    if !status.is_success() {
+      return Err(AuthError::Status {
+        token_url: self.token_url.clone(),
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+      });
+    }
+
+    let token = serde_json::from_slice::<TokenResponse>(&body).map_err(|source| AuthError::Format {
+      token_url: self.token_url.clone(),
+      source,
+    })?;
+    Ok(CachedToken {
+      access_token: token.access_token,
+      expires_at: token
+        .expires_in
+        .map(|secs| requested_at + Duration::from_secs(secs).saturating_sub(EXPIRY_MARGIN)),
+    })
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, Option<CachedToken>> {
+    self.token.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+  }
+}
+
+impl fmt::Debug for ClientCredentials {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClientCredentials")
+      .field("token_url", &self.token_url)
+      .field("client_id", &self.client_id)
+      .field("client_secret", &"<redacted>")
+      .field("scopes", &self.scopes)
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+
+  use super::*;
+
+  async fn serve_tokens(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/token", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    tokio::spawn(async move {
+      loop {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = vec![0; 4096];
+        let _ = stream.read(&mut request).await.unwrap();
+        counter.fetch_add(1, Ordering::SeqCst);
+        let response = format!(
+          "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+          body.len()
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+      }
+    });
+    (url, hits)
+  }
+
+  #[tokio::test]
+  async fn test_access_token_is_cached_until_invalidated() {
+    let (url, hits) = serve_tokens(
+      "200 OK",
+      r#"{"access_token":"abc","token_type":"Bearer","expires_in":3600}"#,
+    )
+    .await;
+    let credentials = ClientCredentials::new(url, "id", "secret").with_scopes(["read"]);
+    let client = reqwest::Client::new();
+
+    assert_eq!(credentials.access_token(&client).await.unwrap(), "abc");
+    assert_eq!(credentials.clone().access_token(&client).await.unwrap(), "abc");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+    credentials.invalidate();
+    assert_eq!(credentials.access_token(&client).await.unwrap(), "abc");
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+  }
+
+  #[tokio::test]
+  async fn test_access_token_reports_endpoint_failures() {
+    let cases = [
+      ("401 Unauthorized", r#"{"error":"invalid_client"}"#, "returned 401"),
+      ("200 OK", r#"{"token_type":"Bearer"}"#, "parsing token response"),
+    ];
+
+    for (status, body, expected) in cases {
+      let (url, _) = serve_tokens(status, body).await;
+      let err = ClientCredentials::new(url, "id", "secret")
+        .access_token(&reqwest::Client::new())
+        .await
+        .unwrap_err();
+      assert!(err.to_string().contains(expected), "{status}: {err}");
+    }
+  }
+
+  #[test]
+  fn test_debug_redacts_secrets() {
+    let basic = format!("{:?}", BasicAuth::new("user", "hunter2"));
+    let credentials = format!(
+      "{:?}",
+      ClientCredentials::new("https://auth.test/token", "id", "hunter2")
+    );
+    for output in [basic, credentials] {
+      assert!(!output.contains("hunter2"), "{output}");
+      assert!(output.contains("<redacted>"), "{output}");
+    }
+  }
+}
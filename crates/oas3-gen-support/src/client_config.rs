@@ -0,0 +1,32 @@
+use crate::RetryPolicy;
+
+/// Settings a generated client applies at once through its `with_config` method.
+///
+/// Fields left unset keep the client's behavior without them, so
+/// `ClientConfig::default()` changes nothing.
+///
+/// ```ignore
+/// let client = PetStoreClient::new().with_config(
+///   ClientConfig::builder()
+///     .retry(RetryPolicy::builder().max_retries(5).build())
+///     .build(),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, bon::Builder)]
+pub struct ClientConfig {
+  /// Retry policy for failed requests; `None` sends every request once.
+  pub retry: Option<RetryPolicy>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_builder_leaves_unset_fields_off() {
+    assert_eq!(ClientConfig::builder().build(), ClientConfig::default());
+
+    let config = ClientConfig::builder().retry(RetryPolicy::default()).build();
+    assert_eq!(config.retry, Some(RetryPolicy::default()));
+  }
+}
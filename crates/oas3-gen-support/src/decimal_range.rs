@@ -0,0 +1,213 @@
+use std::{borrow::Cow, cmp::Ordering, fmt::Display};
+
+use validator::ValidationError;
+
+/// Checks `value >= MANTISSA × 10^-SCALE`, for `#[validate(custom(function = ...))]` on
+/// decimal fields, whose types `range` cannot validate.
+///
+/// Works with any type that displays as a decimal number, such as
+/// `rust_decimal::Decimal` or `bigdecimal::BigDecimal`.
+///
+/// ```ignore
+/// #[validate(custom(function = "oas3_gen_support::decimal_min::<1050, 2>"))]
+/// pub amount: oas3_gen_support::Decimal, // at least 10.50
+/// ```
+///
+/// # Errors
+///
+/// Returns a `range` error when `value` is below the bound or is not a decimal number.
+pub fn decimal_min<const MANTISSA: i128, const SCALE: u32>(value: &impl Display) -> Result<(), ValidationError> {
+  check(value, MANTISSA, SCALE, "min", |ord| ord != Ordering::Less)
+}
+
+/// Checks `value <= MANTISSA × 10^-SCALE`. See [`decimal_min`].
+///
+/// # Errors
+///
+/// Returns a `range` error when `value` is above the bound or is not a decimal number.
+pub fn decimal_max<const MANTISSA: i128, const SCALE: u32>(value: &impl Display) -> Result<(), ValidationError> {
+  check(value, MANTISSA, SCALE, "max", |ord| ord != Ordering::Greater)
+}
+
+/// Checks `value > MANTISSA × 10^-SCALE`. See [`decimal_min`].
+///
+/// # Errors
+///
+/// Returns a `range` error when `value` is not above the bound or is not a decimal number.
+pub fn decimal_exclusive_min<const MANTISSA: i128, const SCALE: u32>(
+  value: &impl Display,
+) -> Result<(), ValidationError> {
+  check(value, MANTISSA, SCALE, "exclusive_min", |ord| ord == Ordering::Greater)
+}
+
+/// Checks `value < MANTISSA × 10^-SCALE`. See [`decimal_min`].
+///
+/// # Errors
+///
+/// Returns a `range` error when `value` is not below the bound or is not a decimal number.
+pub fn decimal_exclusive_max<const MANTISSA: i128, const SCALE: u32>(
+  value: &impl Display,
+) -> Result<(), ValidationError> {
+  check(value, MANTISSA, SCALE, "exclusive_max", |ord| ord == Ordering::Less)
+}
+
+fn check(
+  value: &impl Display,
+  mantissa: i128,
+  scale: u32,
+  param: &'static str,
+  accepts: impl Fn(Ordering) -> bool,
+) -> Result<(), ValidationError> {
+  let bound = Digits::from_parts(mantissa, scale);
+  if Digits::parse(&value.to_string()).is_some_and(|value| accepts(value.cmp(&bound))) {
+    return Ok(());
+  }
+  let mut err = ValidationError::new("range");
+  err.add_param(Cow::Borrowed(param), &bound.to_string());
+  Err(err)
+}
+
+/// A decimal number as `digits × 10^exponent`, with no leading or trailing zeros in
+/// `digits`, so equal numbers have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Digits {
+  negative: bool,
+  digits: String,
+  exponent: i64,
+}
+
+impl Digits {
+  fn from_parts(mantissa: i128, scale: u32) -> Self {
+    Self::normalized(mantissa < 0, mantissa.unsigned_abs().to_string(), -i64::from(scale))
+  }
+
+  /// Parses a decimal such as `-12.50` or `1.25E+3`, as displayed by decimal types.
+  fn parse(text: &str) -> Option<Self> {
+    let text = text.trim();
+    let (negative, text) = match text.as_bytes().first()? {
+      b'-' => (true, &text[1..]),
+      b'+' => (false, &text[1..]),
+      _ => (false, text),
+    };
+    let (number, exponent) = match text.split_once(['e', 'E']) {
+      Some((number, exponent)) => (number, exponent.parse::<i64>().ok()?),
+      None => (text, 0),
+    };
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit())
+    {
+      return None;
+    }
+    let fraction_len = i64::try_from(fraction.len()).ok()?;
+    Some(Self::normalized(
+      negative,
+      format!("{integer}{fraction}"),
+      exponent - fraction_len,
+    ))
+  }
+
+  fn normalized(negative: bool, digits: String, exponent: i64) -> Self {
+    let trimmed = digits.trim_start_matches('0');
+    let significant = trimmed.trim_end_matches('0');
+    if significant.is_empty() {
+      return Self {
+        negative: false,
+        digits: String::new(),
+        exponent: 0,
+      };
+    }
+    let trailing = (trimmed.len() - significant.len()) as i64;
+    Self {
+      negative,
+      digits: significant.to_string(),
+      exponent: exponent + trailing,
+    }
+  }
+
+  /// Power of ten just above the most significant digit.
+  fn magnitude(&self) -> i64 {
+    self.digits.len() as i64 + self.exponent
+  }
+
+  fn cmp_abs(&self, other: &Self) -> Ordering {
+    match (self.digits.is_empty(), other.digits.is_empty()) {
+      (true, true) => return Ordering::Equal,
+      (true, false) => return Ordering::Less,
+      (false, true) => return Ordering::Greater,
+      (false, false) => {}
+    }
+    self.magnitude().cmp(&other.magnitude()).then_with(|| {
+      let width = self.digits.len().max(other.digits.len());
+      format!("{:0<width$}", self.digits).cmp(&format!("{:0<width$}", other.digits))
+    })
+  }
+}
+
+impl Ord for Digits {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self.negative, other.negative) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => self.cmp_abs(other),
+      (true, true) => other.cmp_abs(self),
+    }
+  }
+}
+
+impl PartialOrd for Digits {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Display for Digits {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.digits.is_empty() {
+      return write!(f, "0");
+    }
+    let sign = if self.negative { "-" } else { "" };
+    match usize::try_from(-self.exponent) {
+      Err(_) | Ok(0) => write!(
+        f,
+        "{sign}{}{}",
+        self.digits,
+        "0".repeat(self.exponent.unsigned_abs() as usize)
+      ),
+      Ok(scale) if scale < self.digits.len() => {
+        let (integer, fraction) = self.digits.split_at(self.digits.len() - scale);
+        write!(f, "{sign}{integer}.{fraction}")
+      }
+      Ok(scale) => write!(f, "{sign}0.{}{}", "0".repeat(scale - self.digits.len()), self.digits),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decimal_bounds_compare_displayed_values() {
+    assert!(decimal_min::<1050, 2>(&"10.50").is_ok());
+    assert!(decimal_min::<1050, 2>(&"10.5000").is_ok());
+    assert!(decimal_min::<1050, 2>(&"10.49").is_err());
+    assert!(decimal_max::<1050, 2>(&"1.05E+1").is_ok());
+    assert!(decimal_max::<1050, 2>(&"1.0501e1").is_err());
+    assert!(decimal_exclusive_min::<0, 0>(&"0.00").is_err());
+    assert!(decimal_exclusive_min::<0, 0>(&"0.0001").is_ok());
+    assert!(decimal_exclusive_max::<{ -5 }, 0>(&"-5.1").is_ok());
+    assert!(decimal_exclusive_max::<{ -5 }, 0>(&"-4.9").is_err());
+    assert!(decimal_min::<{ -100 }, 0>(&"-99999999999999999999999999999999999999.5").is_err());
+    assert!(decimal_min::<0, 0>(&"not a number").is_err());
+  }
+
+  #[test]
+  fn test_decimal_bound_errors_name_the_bound() {
+    let err = decimal_min::<{ -1050 }, 3>(&"-2").unwrap_err();
+    assert_eq!(err.code, "range");
+    assert_eq!(err.params["min"], "-1.05");
+
+    let err = decimal_max::<12, 0>(&"120.5").unwrap_err();
+    assert_eq!(err.params["max"], "12");
+  }
+}
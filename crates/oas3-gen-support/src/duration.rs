@@ -0,0 +1,227 @@
+use chrono::TimeDelta;
+use serde::{Deserialize, Deserializer, Serializer, de::Error as _, ser::Error as _};
+use serde_with::{DeserializeAs, SerializeAs};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// De/Serialize a [`TimeDelta`] as an ISO 8601 duration string such as `PT5M` or `P1DT2H`.
+///
+/// Weeks, days, hours, minutes, and seconds are supported, and the smallest unit may carry
+/// a fraction (`PT1.5S`). Years and months are rejected because their length depends on a
+/// calendar date. Serialization uses the normalized seconds form produced by `chrono`
+/// (`PT300S`), which every ISO 8601 parser accepts.
+///
+/// ```ignore
+/// #[serde_with::serde_as]
+/// #[derive(serde::Deserialize)]
+/// struct Job {
+///   #[serde_as(as = "oas3_gen_support::Iso8601Duration")]
+///   timeout: chrono::Duration,
+/// }
+/// ```
+pub struct Iso8601Duration;
+
+impl SerializeAs<TimeDelta> for Iso8601Duration {
+  fn serialize_as<S>(source: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(source)
+  }
+}
+
+impl<'de> DeserializeAs<'de, TimeDelta> for Iso8601Duration {
+  fn deserialize_as<D>(deserializer: D) -> Result<TimeDelta, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+    parse_iso8601(&value).map_err(D::Error::custom)
+  }
+}
+
+/// De/Serialize a [`TimeDelta`] as a human-readable duration string such as `5m` or `1h 30m`.
+///
+/// Parsing and formatting follow the [`humantime`] crate. Negative durations cannot be
+/// represented and fail to serialize.
+pub struct HumantimeDuration;
+
+impl SerializeAs<TimeDelta> for HumantimeDuration {
+  fn serialize_as<S>(source: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let duration = source.to_std().map_err(S::Error::custom)?;
+    serializer.collect_str(&humantime::format_duration(duration))
+  }
+}
+
+impl<'de> DeserializeAs<'de, TimeDelta> for HumantimeDuration {
+  fn deserialize_as<D>(deserializer: D) -> Result<TimeDelta, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+    let duration = humantime::parse_duration(&value).map_err(D::Error::custom)?;
+    TimeDelta::from_std(duration).map_err(D::Error::custom)
+  }
+}
+
+fn parse_iso8601(input: &str) -> Result<TimeDelta, String> {
+  let invalid = |reason: &str| format!("invalid ISO 8601 duration `{input}`: {reason}");
+
+  let (negative, unsigned) = match input.strip_prefix('-') {
+    Some(rest) => (true, rest),
+    None => (false, input.strip_prefix('+').unwrap_or(input)),
+  };
+  let body = unsigned
+    .strip_prefix('P')
+    .ok_or_else(|| invalid("expected leading `P`"))?;
+  let (date, time) = match body.split_once('T') {
+    Some((_, "")) => return Err(invalid("expected a time component after `T`")),
+    Some((date, time)) => (date, Some(time)),
+    None => (body, None),
+  };
+  if date.is_empty() && time.is_none() {
+    return Err(invalid("expected at least one component"));
+  }
+
+  let date_nanos = sum_components(date, &[('W', 7 * 86_400), ('D', 86_400)]).map_err(|reason| invalid(&reason))?;
+  let time_nanos = time
+    .map(|time| sum_components(time, &[('H', 3_600), ('M', 60), ('S', 1)]))
+    .transpose()
+    .map_err(|reason| invalid(&reason))?
+    .unwrap_or_default();
+
+  let total = date_nanos + time_nanos;
+  let total = if negative { -total } else { total };
+  let seconds = i64::try_from(total.div_euclid(NANOS_PER_SECOND)).map_err(|_| invalid("out of range"))?;
+  let nanos = u32::try_from(total.rem_euclid(NANOS_PER_SECOND)).map_err(|_| invalid("out of range"))?;
+  TimeDelta::new(seconds, nanos).ok_or_else(|| invalid("out of range"))
+}
+
+fn sum_components(mut part: &str, units: &[(char, i128)]) -> Result<i128, String> {
+  let mut total = 0i128;
+  let mut next_unit = 0;
+  let mut saw_fraction = false;
+
+  while !part.is_empty() {
+    let end = part
+      .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+      .ok_or_else(|| format!("number `{part}` is missing a unit"))?;
+    let (number, rest) = part.split_at(end);
+    let designator = rest.chars().next().unwrap_or_default();
+
+    let Some(offset) = units[next_unit..].iter().position(|(unit, _)| *unit == designator) else {
+      return Err(match designator {
+        'Y' | 'M' if units[0].0 == 'W' => "years and months have no fixed length".to_string(),
+        _ => format!("unexpected designator `{designator}`"),
+      });
+    };
+    if saw_fraction {
+      return Err("only the smallest unit may have a fraction".to_string());
+    }
+    let (_, unit_seconds) = units[next_unit + offset];
+    next_unit += offset + 1;
+
+    let (whole, fraction) = number.split_once(['.', ',']).unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+      return Err(format!("designator `{designator}` has no value"));
+    }
+    saw_fraction = !fraction.is_empty();
+
+    let whole = if whole.is_empty() {
+      0
+    } else {
+      whole.parse::<i128>().map_err(|err| err.to_string())?
+    };
+    let fraction_nanos = format!("{fraction:0<9}")
+      .get(..9)
+      .ok_or_else(|| "invalid fraction".to_string())?
+      .parse::<i128>()
+      .map_err(|err| err.to_string())?;
+
+    total = whole
+      .checked_mul(unit_seconds * NANOS_PER_SECOND)
+      .and_then(|nanos| nanos.checked_add(fraction_nanos * unit_seconds))
+      .and_then(|nanos| total.checked_add(nanos))
+      .ok_or_else(|| "out of range".to_string())?;
+    part = &rest[designator.len_utf8()..];
+  }
+
+  Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_with::serde_as;
+
+  use super::*;
+
+  #[serde_as]
+  #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+  struct Iso {
+    #[serde_as(as = "Iso8601Duration")]
+    value: TimeDelta,
+  }
+
+  #[serde_as]
+  #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+  struct Human {
+    #[serde_as(as = "HumantimeDuration")]
+    value: TimeDelta,
+  }
+
+  #[test]
+  fn test_parse_iso8601_durations() {
+    let cases = [
+      ("PT5M", TimeDelta::minutes(5)),
+      ("P1DT2H", TimeDelta::days(1) + TimeDelta::hours(2)),
+      ("P2W", TimeDelta::weeks(2)),
+      ("PT1.5S", TimeDelta::milliseconds(1_500)),
+      ("PT0,25H", TimeDelta::minutes(15)),
+      ("P1DT1H1M1S", TimeDelta::seconds(90_061)),
+      ("-PT30S", TimeDelta::seconds(-30)),
+      ("P0D", TimeDelta::zero()),
+    ];
+
+    for (input, expected) in cases {
+      assert_eq!(parse_iso8601(input), Ok(expected), "{input}");
+    }
+  }
+
+  #[test]
+  fn test_parse_iso8601_rejects_invalid_durations() {
+    let cases = [
+      "", "5M", "P", "PT", "P1Y", "P2M", "PT5", "PT1M5H", "PT1.5M2S", "P1H", "PT1D",
+    ];
+
+    for input in cases {
+      assert!(parse_iso8601(input).is_err(), "{input} should be rejected");
+    }
+  }
+
+  #[test]
+  fn test_iso8601_round_trip() {
+    let decoded = serde_json::from_str::<Iso>(r#"{"value": "PT5M"}"#).unwrap();
+    assert_eq!(decoded.value, TimeDelta::minutes(5));
+    let encoded = serde_json::to_string(&decoded).unwrap();
+    assert_eq!(encoded, r#"{"value":"PT300S"}"#);
+    assert_eq!(serde_json::from_str::<Iso>(&encoded).unwrap(), decoded);
+  }
+
+  #[test]
+  fn test_humantime_round_trip() {
+    let decoded = serde_json::from_str::<Human>(r#"{"value": "1h 30m"}"#).unwrap();
+    assert_eq!(decoded.value, TimeDelta::minutes(90));
+    assert_eq!(serde_json::to_string(&decoded).unwrap(), r#"{"value":"1h 30m"}"#);
+  }
+
+  #[test]
+  fn test_humantime_rejects_negative_durations() {
+    let negative = Human {
+      value: TimeDelta::seconds(-1),
+    };
+    assert!(serde_json::to_string(&negative).is_err());
+  }
+}
@@ -1,5 +1,4 @@
 use std::{
-  marker::PhantomData,
   pin::Pin,
   task::{Context, Poll},
 };
@@ -17,6 +16,37 @@ pub enum EventStreamError {
   JsonDeserialize { path: String, inner: serde_json::Error },
 }
 
+/// An event payload whose type is chosen by the SSE `event:` name.
+///
+/// Generated clients implement this for enums over the named events of an event-stream
+/// response, with one variant per event and a fallback for names the spec does not list.
+pub trait NamedEvent: Sized {
+  /// Decode the `data` of an event received under the `event` name.
+  fn from_event(event: &str, data: &str) -> Result<Self, EventStreamError>;
+}
+
+/// Deserialize an event's `data` as JSON, reporting the path of the first mismatch.
+pub fn parse_event_data<T: DeserializeOwned>(data: &str) -> Result<T, EventStreamError> {
+  let mut de = serde_json::Deserializer::from_str(data);
+  serde_path_to_error::deserialize(&mut de).map_err(|err| EventStreamError::JsonDeserialize {
+    path: err.path().to_string(),
+    inner: err.into_inner(),
+  })
+}
+
+type EventDecoder<T> = fn(&str, &str) -> Result<T, EventStreamError>;
+
+type SseEvent = Result<eventsource_stream::Event, eventsource_stream::EventStreamError<reqwest::Error>>;
+
+/// Parsed events of a native response body, which `reqwest` reads on a `Send` stream.
+#[cfg(not(target_arch = "wasm32"))]
+type EventSource = Pin<Box<dyn Stream<Item = SseEvent> + Send>>;
+
+/// Parsed events of a browser `fetch` body, whose `ReadableStream` is bound to the
+/// JavaScript thread and so is not `Send`.
+#[cfg(target_arch = "wasm32")]
+type EventSource = Pin<Box<dyn Stream<Item = SseEvent>>>;
+
 /// A stream of Server-Sent Events (SSE) that deserializes each event's data as JSON.
 ///
 /// This wraps a `reqwest::Response` and parses the SSE event stream, deserializing
@@ -38,12 +68,8 @@ pub enum EventStreamError {
 /// }
 /// ```
 pub struct EventStream<T> {
-  inner: Pin<
-    Box<
-      dyn Stream<Item = Result<eventsource_stream::Event, eventsource_stream::EventStreamError<reqwest::Error>>> + Send,
-    >,
-  >,
-  _marker: PhantomData<T>,
+  inner: EventSource,
+  decode: EventDecoder<T>,
 }
 
 impl<T> std::fmt::Debug for EventStream<T> {
@@ -61,25 +87,40 @@ where
   /// The response should have content type `text/event-stream`.
   #[must_use]
   pub fn from_response(response: reqwest::Response) -> Self {
+    Self::with_decoder(response, |_, data| Self::parse_event(data))
+  }
+
+  fn parse_event(data: &str) -> Result<T, EventStreamError> {
+    parse_event_data(data)
+  }
+}
+
+impl<T> EventStream<T>
+where
+  T: NamedEvent,
+{
+  /// Create an `EventStream` that decodes each event by its SSE `event:` name.
+  ///
+  /// Events sent without a name are decoded under the SSE default name, `message`.
+  #[must_use]
+  pub fn from_named_events(response: reqwest::Response) -> Self {
+    Self::with_decoder(response, T::from_event)
+  }
+}
+
+impl<T> EventStream<T> {
+  fn with_decoder(response: reqwest::Response, decode: EventDecoder<T>) -> Self {
     let stream = response.bytes_stream().eventsource();
     Self {
       inner: Box::pin(stream),
-      _marker: PhantomData,
+      decode,
     }
   }
-
-  fn parse_event(data: &str) -> Result<T, EventStreamError> {
-    let mut de = serde_json::Deserializer::from_str(data);
-    serde_path_to_error::deserialize(&mut de).map_err(|err| EventStreamError::JsonDeserialize {
-      path: err.path().to_string(),
-      inner: err.into_inner(),
-    })
-  }
 }
 
 impl<T> Stream for EventStream<T>
 where
-  T: DeserializeOwned + Unpin,
+  T: Unpin,
 {
   type Item = Result<T, EventStreamError>;
 
@@ -91,7 +132,7 @@ where
             if event.data.is_empty() {
               continue;
             }
-            return Poll::Ready(Some(Self::parse_event(&event.data)));
+            return Poll::Ready(Some((self.decode)(&event.event, &event.data)));
           }
           Err(e) => return Poll::Ready(Some(Err(EventStreamError::SseParse(e)))),
         },
@@ -192,4 +233,42 @@ mod tests {
       EventStreamError::SseParse(err) => panic!("Expected JsonDeserialize error, got SseParse: {err}"),
     }
   }
+
+  #[derive(Debug, PartialEq)]
+  enum Named {
+    Test(TestEvent),
+    Unknown { event: String, data: String },
+  }
+
+  impl NamedEvent for Named {
+    fn from_event(event: &str, data: &str) -> Result<Self, EventStreamError> {
+      match event {
+        "test" => parse_event_data(data).map(Self::Test),
+        _ => Ok(Self::Unknown {
+          event: event.to_string(),
+          data: data.to_string(),
+        }),
+      }
+    }
+  }
+
+  #[test]
+  fn test_named_event_dispatches_on_event_name() {
+    let data = r#"{"id": 2, "message": "named"}"#;
+
+    assert_eq!(
+      Named::from_event("test", data).unwrap(),
+      Named::Test(TestEvent {
+        id: 2,
+        message: "named".to_string(),
+      })
+    );
+    assert_eq!(
+      Named::from_event("ping", "{}").unwrap(),
+      Named::Unknown {
+        event: "ping".to_string(),
+        data: "{}".to_string(),
+      }
+    );
+  }
 }
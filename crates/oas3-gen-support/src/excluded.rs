@@ -0,0 +1,65 @@
+use serde::Serialize;
+use serde_json::Value;
+use validator::ValidationError;
+
+/// Checks that `value` is none of the `excluded` JSON values, for schemas whose `not`
+/// rules out a `const` or `enum`. Generated code wraps it in a function per field, since
+/// `#[validate(custom(function = ...))]` takes no arguments:
+///
+/// ```ignore
+/// fn exclude_user_role<T: serde::Serialize>(value: T) -> Result<(), validator::ValidationError> {
+///   oas3_gen_support::exclude_values(&value, &[r#""admin""#, r#""root""#])
+/// }
+/// ```
+///
+/// Values are compared as JSON, with numbers compared by value, so `1` excludes `1.0`.
+///
+/// # Errors
+///
+/// Returns a `not` error when `value` equals an excluded value or cannot be serialized.
+pub fn exclude_values<T: Serialize + ?Sized>(value: &T, excluded: &[&str]) -> Result<(), ValidationError> {
+  let value = serde_json::to_value(value).map_err(|_| ValidationError::new("not"))?;
+  let matched = excluded
+    .iter()
+    .filter_map(|json| serde_json::from_str::<Value>(json).ok())
+    .any(|excluded| json_eq(&value, &excluded));
+  if matched {
+    let mut error = ValidationError::new("not");
+    error.message = Some("value is excluded by the schema".into());
+    return Err(error);
+  }
+  Ok(())
+}
+
+fn json_eq(a: &Value, b: &Value) -> bool {
+  match (a, b) {
+    (Value::Number(a), Value::Number(b)) => match (a.as_i64(), b.as_i64()) {
+      (Some(a), Some(b)) => a == b,
+      _ => a.as_f64() == b.as_f64(),
+    },
+    _ => a == b,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_exclude_values() {
+    let cases: [(Value, &[&str], bool); 5] = [
+      (Value::from("admin"), &[r#""admin""#, r#""root""#], false),
+      (Value::from("guest"), &[r#""admin""#, r#""root""#], true),
+      (Value::from(1.0), &["1"], false),
+      (Value::from(2), &["1", "3"], true),
+      (Value::Bool(false), &["false"], false),
+    ];
+    for (value, excluded, accepted) in cases {
+      let result = exclude_values(&value, excluded);
+      assert_eq!(result.is_ok(), accepted, "{value} against {excluded:?}");
+      if let Err(error) = result {
+        assert_eq!(error.code, "not");
+      }
+    }
+  }
+}
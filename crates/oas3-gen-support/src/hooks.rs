@@ -0,0 +1,322 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::runtime::{Instant, time_until};
+
+/// Error returned by a [`RequestHook`] to abort the request it was called with.
+#[derive(Debug)]
+pub struct HookError(Box<dyn std::error::Error + Send + Sync>);
+
+impl HookError {
+  /// Wraps `error`, which may be any error type or a message.
+  pub fn new(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+    Self(error.into())
+  }
+}
+
+impl fmt::Display for HookError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "request hook failed: {}", self.0)
+  }
+}
+
+impl std::error::Error for HookError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(self.0.as_ref())
+  }
+}
+
+/// Error returned by a generated client method whose [`RequestOptions::deadline`] had
+/// already passed, so the request was not sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadlineExceeded;
+
+impl fmt::Display for DeadlineExceeded {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("deadline exceeded before the request was sent")
+  }
+}
+
+impl std::error::Error for DeadlineExceeded {}
+
+/// Middleware run by generated clients around every request they send.
+///
+/// `before_send` sees the finished request, credentials included, and may change it,
+/// for example to add tracing headers. `after_receive` sees the response before its
+/// body is read. Returning an error from either aborts the call with that error. Both
+/// do nothing by default, so a hook only implements the side it needs:
+///
+/// ```ignore
+/// struct RequestId;
+///
+/// impl RequestHook for RequestId {
+///   async fn before_send(&self, request: &mut reqwest::Request) -> Result<(), HookError> {
+///     let id = HeaderValue::from_str(&uuid::Uuid::new_v4().to_string()).map_err(HookError::new)?;
+///     request.headers_mut().insert("x-request-id", id);
+///     Ok(())
+///   }
+/// }
+///
+/// let client = PetStoreClient::new().with_hook(RequestId);
+/// ```
+pub trait RequestHook: Send + Sync {
+  /// Called with each request just before it is sent.
+  #[cfg(not(target_arch = "wasm32"))]
+  fn before_send(&self, request: &mut reqwest::Request) -> impl Future<Output = Result<(), HookError>> + Send {
+    let _ = request;
+    async { Ok(()) }
+  }
+
+  /// Called with each response as soon as its status and headers arrive.
+  #[cfg(not(target_arch = "wasm32"))]
+  fn after_receive(&self, response: &reqwest::Response) -> impl Future<Output = Result<(), HookError>> + Send {
+    let _ = response;
+    async { Ok(()) }
+  }
+
+  /// Called with each request just before it is sent.
+  #[cfg(target_arch = "wasm32")]
+  fn before_send(&self, request: &mut reqwest::Request) -> impl Future<Output = Result<(), HookError>> {
+    let _ = request;
+    async { Ok(()) }
+  }
+
+  /// Called with each response as soon as its status and headers arrive.
+  #[cfg(target_arch = "wasm32")]
+  fn after_receive(&self, response: &reqwest::Response) -> impl Future<Output = Result<(), HookError>> {
+    let _ = response;
+    async { Ok(()) }
+  }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type HookFuture<'a> = Pin<Box<dyn Future<Output = Result<(), HookError>> + Send + 'a>>;
+/// Browser responses are not `Send`, so hooks awaiting them cannot be either.
+#[cfg(target_arch = "wasm32")]
+type HookFuture<'a> = Pin<Box<dyn Future<Output = Result<(), HookError>> + 'a>>;
+
+/// Object-safe form of [`RequestHook`], so hooks of different types can share a list.
+trait ErasedHook: Send + Sync {
+  fn before_send<'a>(&'a self, request: &'a mut reqwest::Request) -> HookFuture<'a>;
+  fn after_receive<'a>(&'a self, response: &'a reqwest::Response) -> HookFuture<'a>;
+}
+
+impl<H: RequestHook> ErasedHook for H {
+  fn before_send<'a>(&'a self, request: &'a mut reqwest::Request) -> HookFuture<'a> {
+    Box::pin(RequestHook::before_send(self, request))
+  }
+
+  fn after_receive<'a>(&'a self, response: &'a reqwest::Response) -> HookFuture<'a> {
+    Box::pin(RequestHook::after_receive(self, response))
+  }
+}
+
+/// The hooks of a generated client, run in the order they were added.
+#[derive(Clone, Default)]
+pub struct RequestHooks(Vec<Arc<dyn ErasedHook>>);
+
+impl fmt::Debug for RequestHooks {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("RequestHooks").field("len", &self.0.len()).finish()
+  }
+}
+
+impl RequestHooks {
+  /// Adds `hook` after the hooks already in the list.
+  pub fn push(&mut self, hook: impl RequestHook + 'static) {
+    self.0.push(Arc::new(hook));
+  }
+
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Builds `request` and runs every hook's `before_send` on it.
+  ///
+  /// Without hooks the builder is returned untouched, so it is never built early.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the request cannot be built or a hook rejects it.
+  pub async fn before_send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder, HookError> {
+    if self.0.is_empty() {
+      return Ok(request);
+    }
+    let (client, request) = request.build_split();
+    let mut request = request.map_err(HookError::new)?;
+    for hook in &self.0 {
+      hook.before_send(&mut request).await?;
+    }
+    Ok(reqwest::RequestBuilder::from_parts(client, request))
+  }
+
+  /// Runs every hook's `after_receive` on `response`.
+  ///
+  /// # Errors
+  ///
+  /// Returns the error of the first hook that rejects the response.
+  pub async fn after_receive(&self, response: &reqwest::Response) -> Result<(), HookError> {
+    for hook in &self.0 {
+      hook.after_receive(response).await?;
+    }
+    Ok(())
+  }
+}
+
+/// Settings for a single call of a generated client method, applied on top of the
+/// client's own configuration.
+///
+/// ```ignore
+/// let options = RequestOptions::builder()
+///   .timeout(Duration::from_secs(2))
+///   .deadline(budget_expires_at)
+///   .build()
+///   .with_header(HeaderName::from_static("x-tenant"), HeaderValue::from_static("acme"));
+/// let pets = client.list_pets_with_options(request, options).await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, bon::Builder)]
+pub struct RequestOptions {
+  /// Time allowed for the whole request, replacing the client's timeout.
+  pub timeout: Option<Duration>,
+  /// Point in time the call must finish by, such as the end of a budget propagated
+  /// from an upstream caller. The request times out when it is reached, or is not
+  /// sent at all when it has already passed.
+  pub deadline: Option<Instant>,
+  /// Headers sent with the request, replacing generated headers of the same name.
+  #[builder(default)]
+  pub headers: HeaderMap,
+}
+
+impl RequestOptions {
+  /// Adds `name: value` to the headers sent with the request.
+  #[must_use]
+  pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+    self.headers.append(name, value);
+    self
+  }
+
+  /// Applies the options to `request`, with the timeout shortened to the time left
+  /// before the deadline.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`DeadlineExceeded`] when the deadline has already passed.
+  pub fn apply(self, request: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder, DeadlineExceeded> {
+    let timeout = match self.deadline {
+      Some(deadline) => {
+        let remaining = time_until(deadline).ok_or(DeadlineExceeded)?;
+        Some(self.timeout.map_or(remaining, |timeout| timeout.min(remaining)))
+      }
+      None => self.timeout,
+    };
+    let request = match timeout {
+      Some(timeout) => request.timeout(timeout),
+      None => request,
+    };
+    if self.headers.is_empty() {
+      Ok(request)
+    } else {
+      Ok(request.headers(self.headers))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use super::*;
+
+  struct Tag(&'static str, Arc<Mutex<Vec<&'static str>>>);
+
+  impl RequestHook for Tag {
+    async fn before_send(&self, request: &mut reqwest::Request) -> Result<(), HookError> {
+      self.1.lock().unwrap().push(self.0);
+      request.headers_mut().append("x-hook", HeaderValue::from_static(self.0));
+      Ok(())
+    }
+  }
+
+  struct Reject;
+
+  impl RequestHook for Reject {
+    async fn before_send(&self, _request: &mut reqwest::Request) -> Result<(), HookError> {
+      Err(HookError::new("rejected"))
+    }
+  }
+
+  fn request() -> reqwest::RequestBuilder {
+    reqwest::Client::new().get("http://localhost/pets")
+  }
+
+  #[tokio::test]
+  async fn test_hooks_run_in_order_and_edit_the_request() {
+    let order = Arc::new(Mutex::new(vec![]));
+    let mut hooks = RequestHooks::default();
+    hooks.push(Tag("first", order.clone()));
+    hooks.push(Tag("second", order.clone()));
+
+    let request = hooks.before_send(request()).await.unwrap().build().unwrap();
+    let tags = request.headers().get_all("x-hook").iter().collect::<Vec<_>>();
+    assert_eq!(tags, ["first", "second"]);
+    assert_eq!(*order.lock().unwrap(), ["first", "second"]);
+  }
+
+  #[tokio::test]
+  async fn test_hook_error_aborts_the_request() {
+    let mut hooks = RequestHooks::default();
+    hooks.push(Reject);
+
+    let err = hooks.before_send(request()).await.unwrap_err();
+    assert_eq!(err.to_string(), "request hook failed: rejected");
+  }
+
+  #[test]
+  fn test_request_options_set_timeout_and_headers() {
+    let options = RequestOptions::builder()
+      .timeout(Duration::from_secs(2))
+      .build()
+      .with_header(HeaderName::from_static("x-tenant"), HeaderValue::from_static("acme"));
+    let request = options
+      .apply(request().header("x-tenant", "default"))
+      .unwrap()
+      .build()
+      .unwrap();
+
+    assert_eq!(request.timeout(), Some(&Duration::from_secs(2)));
+    assert_eq!(
+      request.headers()["x-tenant"],
+      "acme",
+      "options replace existing headers"
+    );
+  }
+
+  #[test]
+  fn test_request_options_deadline_shortens_timeout() {
+    let options = RequestOptions::builder()
+      .timeout(Duration::from_secs(60))
+      .deadline(Instant::now() + Duration::from_secs(5))
+      .build();
+    let request = options.apply(request()).unwrap().build().unwrap();
+
+    let timeout = *request.timeout().unwrap();
+    assert!(
+      timeout <= Duration::from_secs(5) && timeout > Duration::from_secs(4),
+      "timeout is the time left before the deadline, got {timeout:?}"
+    );
+  }
+
+  #[test]
+  fn test_request_options_past_deadline_is_rejected() {
+    let options = RequestOptions::builder().deadline(Instant::now()).build();
+
+    assert_eq!(options.apply(request()).unwrap_err(), DeadlineExceeded);
+  }
+}
@@ -0,0 +1,121 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::DiagnosticsError;
+
+/// Bodies at least this large are parsed with `simd-json` first, where its setup cost pays off.
+#[cfg(feature = "simd-json")]
+const SIMD_JSON_MIN_LEN: usize = 16 * 1024;
+
+/// Serializes a request body as JSON straight into a [`Bytes`] buffer.
+///
+/// Generated clients send the buffer as the body without copying it again, so peak
+/// memory stays near the size of the serialized body for bulk uploads.
+///
+/// # Errors
+///
+/// Returns an error when `value` cannot be serialized, such as a map with non-string keys.
+pub fn to_json_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Bytes, serde_json::Error> {
+  let mut writer = BytesMut::new().writer();
+  serde_json::to_writer(&mut writer, value)?;
+  Ok(writer.into_inner().freeze())
+}
+
+/// Deserialize a JSON body, reporting the path of the first mismatch on failure.
+pub(crate) fn from_str_with_diagnostics<T>(raw: &str) -> Result<T, DiagnosticsError>
+where
+  T: DeserializeOwned,
+{
+  let mut de = serde_json::Deserializer::from_str(raw);
+  serde_path_to_error::deserialize(&mut de).map_err(|err| DiagnosticsError::DeserializationError {
+    path: err.path().to_string(),
+    inner: err.into_inner(),
+  })
+}
+
+/// Deserialize a large JSON body with `simd-json`, falling back to
+/// [`from_str_with_diagnostics`] for small bodies and whenever the fast path fails, so
+/// errors keep their path diagnostics.
+#[cfg(feature = "simd-json")]
+pub(crate) fn from_slice_with_diagnostics<T>(raw: &[u8]) -> Result<T, DiagnosticsError>
+where
+  T: DeserializeOwned,
+{
+  if raw.len() >= SIMD_JSON_MIN_LEN {
+    let mut scratch = raw.to_vec();
+    if let Ok(value) = simd_json::serde::from_slice(&mut scratch) {
+      return Ok(value);
+    }
+  }
+  match std::str::from_utf8(raw) {
+    Ok(text) => from_str_with_diagnostics(text),
+    Err(_) => from_str_with_diagnostics(&String::from_utf8_lossy(raw)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde::Deserialize;
+
+  use super::*;
+
+  #[derive(Debug, Deserialize, PartialEq)]
+  struct Item {
+    id: u32,
+    name: String,
+  }
+
+  fn items_json(count: u32, last: &str) -> String {
+    let items = (0..count)
+      .map(|id| format!(r#"{{"id": {id}, "name": "item-{id}"}}"#))
+      .chain(std::iter::once(last.to_string()))
+      .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+  }
+
+  #[test]
+  fn test_to_json_bytes_matches_serde_json() {
+    let value = serde_json::json!({ "items": [1, 2], "name": "bulk" });
+    let bytes = to_json_bytes(&value).unwrap();
+    assert_eq!(&bytes[..], serde_json::to_vec(&value).unwrap().as_slice());
+    assert_eq!(&bytes[..], br#"{"items":[1,2],"name":"bulk"}"#);
+  }
+
+  #[test]
+  fn test_reports_path_of_mismatch() {
+    let raw = items_json(2, r#"{"id": "three", "name": "item-3"}"#);
+    let err = from_str_with_diagnostics::<Vec<Item>>(&raw).unwrap_err();
+    assert!(
+      matches!(&err, DiagnosticsError::DeserializationError { path, .. } if path == "[2].id"),
+      "unexpected error: {err}"
+    );
+  }
+
+  #[cfg(feature = "simd-json")]
+  #[test]
+  fn test_large_bodies_parse_with_simd_json() {
+    let raw = items_json(2_000, r#"{"id": 2000, "name": "last"}"#);
+    assert!(raw.len() >= SIMD_JSON_MIN_LEN);
+
+    let items = from_slice_with_diagnostics::<Vec<Item>>(raw.as_bytes()).unwrap();
+    assert_eq!(items.len(), 2_001);
+    assert_eq!(
+      items.last(),
+      Some(&Item {
+        id: 2000,
+        name: "last".to_string()
+      })
+    );
+  }
+
+  #[cfg(feature = "simd-json")]
+  #[test]
+  fn test_large_body_errors_fall_back_to_diagnostics() {
+    let raw = items_json(2_000, r#"{"id": "last", "name": "last"}"#);
+    let err = from_slice_with_diagnostics::<Vec<Item>>(raw.as_bytes()).unwrap_err();
+    assert!(
+      matches!(&err, DiagnosticsError::DeserializationError { path, .. } if path == "[2000].id"),
+      "unexpected error: {err}"
+    );
+  }
+}
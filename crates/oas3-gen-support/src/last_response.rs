@@ -0,0 +1,84 @@
+use std::{
+  sync::{Arc, Mutex, PoisonError},
+  time::Duration,
+};
+
+use http::{HeaderMap, StatusCode};
+
+use crate::runtime::{Instant, elapsed};
+
+/// Status, headers, and timing of a response a generated client received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMetadata {
+  /// Status code of the response.
+  pub status: StatusCode,
+  /// Response headers, as received.
+  pub headers: HeaderMap,
+  /// Time from sending the request to receiving the response headers, including retries.
+  pub elapsed: Duration,
+}
+
+/// The metadata of the most recent response a generated client received.
+///
+/// Clones share the slot, so a client and its clones report the same last response.
+/// When calls run concurrently, the last one to receive its response wins.
+///
+/// # Example
+///
+/// ```ignore
+/// let pets = client.list_pets(request).await?;
+/// if let Some(last) = client.last_response() {
+///   metrics.record(last.status, last.elapsed);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LastResponse(Arc<Mutex<Option<ResponseMetadata>>>);
+
+impl LastResponse {
+  /// Records the status and headers of `response`, received `started` after sending.
+  pub fn record(&self, response: &reqwest::Response, started: Instant) {
+    let metadata = ResponseMetadata {
+      status: response.status(),
+      headers: response.headers().clone(),
+      elapsed: elapsed(started),
+    };
+    *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(metadata);
+  }
+
+  /// Returns the metadata of the most recent response, or `None` before the first one.
+  #[must_use]
+  pub fn get(&self) -> Option<ResponseMetadata> {
+    self.0.lock().unwrap_or_else(PoisonError::into_inner).clone()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn response(status: StatusCode, request_id: &'static str) -> reqwest::Response {
+    let mut response = http::Response::new("");
+    *response.status_mut() = status;
+    response
+      .headers_mut()
+      .insert("x-request-id", http::HeaderValue::from_static(request_id));
+    reqwest::Response::from(response)
+  }
+
+  #[test]
+  fn test_record_keeps_latest_response_across_clones() {
+    let last = LastResponse::default();
+    assert_eq!(last.get(), None);
+
+    let started = Instant::now();
+    last.record(&response(StatusCode::OK, "first"), started);
+    last
+      .clone()
+      .record(&response(StatusCode::TOO_MANY_REQUESTS, "second"), started);
+
+    let metadata = last.get().expect("a response was recorded");
+    assert_eq!(metadata.status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(metadata.headers["x-request-id"], "second");
+    assert!(metadata.elapsed <= elapsed(started));
+  }
+}
@@ -0,0 +1,107 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error as _};
+use serde_with::{DeserializeAs, Same, SerializeAs, ser::SerializeAsWrap};
+
+/// De/Serialize an `Option<T>` that treats an empty string the same as `null`.
+///
+/// Many servers send `""` for absent optional values regardless of the declared type.
+/// Any other value is deserialized through `S`, which defaults to the field's own
+/// `Deserialize` implementation. Serialization is unchanged.
+///
+/// ```ignore
+/// #[serde_with::serde_as]
+/// #[derive(serde::Deserialize)]
+/// struct Pet {
+///   #[serde(default)]
+///   #[serde_as(as = "oas3_gen_support::EmptyStringAsNone")]
+///   born_on: Option<chrono::NaiveDate>,
+/// }
+/// ```
+pub struct EmptyStringAsNone<S = Same>(PhantomData<S>);
+
+impl<'de, T, S> DeserializeAs<'de, Option<T>> for EmptyStringAsNone<S>
+where
+  S: DeserializeAs<'de, T>,
+{
+  fn deserialize_as<D>(deserializer: D) -> Result<Option<T>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+      None => Ok(None),
+      Some(serde_json::Value::String(value)) if value.is_empty() => Ok(None),
+      Some(value) => S::deserialize_as(value).map(Some).map_err(D::Error::custom),
+    }
+  }
+}
+
+impl<T, S> SerializeAs<Option<T>> for EmptyStringAsNone<S>
+where
+  S: SerializeAs<T>,
+{
+  fn serialize_as<Ser>(source: &Option<T>, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+  where
+    Ser: Serializer,
+  {
+    match source {
+      Some(value) => serializer.serialize_some(&SerializeAsWrap::<T, S>::new(value)),
+      None => serializer.serialize_none(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_with::{DisplayFromStr, PickFirst, serde_as};
+
+  use super::*;
+
+  #[serde_as]
+  #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+  struct Record {
+    #[serde(default)]
+    #[serde_as(as = "EmptyStringAsNone")]
+    label: Option<String>,
+    #[serde(default)]
+    #[serde_as(as = "EmptyStringAsNone<PickFirst<(_, DisplayFromStr)>>")]
+    count: Option<i64>,
+  }
+
+  #[test]
+  fn test_empty_string_deserializes_as_none() {
+    let record = serde_json::from_str::<Record>(r#"{"label": "", "count": ""}"#).unwrap();
+    assert_eq!(
+      record,
+      Record {
+        label: None,
+        count: None
+      }
+    );
+  }
+
+  #[test]
+  fn test_values_delegate_to_inner_adapter() {
+    let cases = [
+      (r#"{"label": "a", "count": 3}"#, Some("a"), Some(3)),
+      (r#"{"label": "a", "count": "3"}"#, Some("a"), Some(3)),
+      (r#"{"label": null, "count": null}"#, None, None),
+      ("{}", None, None),
+    ];
+
+    for (json, label, count) in cases {
+      let record = serde_json::from_str::<Record>(json).unwrap_or_else(|err| panic!("{json}: {err}"));
+      assert_eq!(record.label.as_deref(), label, "{json}: label");
+      assert_eq!(record.count, count, "{json}: count");
+    }
+  }
+
+  #[test]
+  fn test_serialization_is_unchanged() {
+    let record = Record {
+      label: Some("a".to_string()),
+      count: Some(3),
+    };
+    assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"label":"a","count":3}"#);
+  }
+}
@@ -1,16 +1,87 @@
+#[cfg(feature = "reqwest")]
+mod auth;
+#[cfg(feature = "reqwest")]
+mod client_config;
+#[cfg(feature = "compression")]
+mod compression;
+mod decimal_range;
+mod duration;
 #[cfg(feature = "eventsource")]
 mod event_stream;
+mod excluded;
+#[cfg(feature = "reqwest")]
+mod hooks;
+#[cfg(feature = "reqwest")]
+mod json;
+#[cfg(feature = "reqwest")]
+mod last_response;
+mod lenient;
+mod link;
+mod malformed;
+#[cfg(feature = "decimal")]
+mod money;
+mod multipart;
+mod operation;
+mod problem;
+mod raw_body;
+mod response_class;
+#[cfg(feature = "reqwest")]
+mod retry;
+#[cfg(feature = "reqwest")]
+mod runtime;
+mod simple_style;
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+mod vcr;
+#[cfg(feature = "reqwest")]
+pub use auth::{AuthError, BasicAuth, ClientCredentials};
 pub use better_default::Default;
 pub use bon::bon;
+pub use bytes::Bytes;
+#[cfg(feature = "reqwest")]
+pub use client_config::ClientConfig;
+#[cfg(feature = "compression")]
+pub use compression::{CompressionError, RequestCompression};
+pub use decimal_range::{decimal_exclusive_max, decimal_exclusive_min, decimal_max, decimal_min};
+pub use duration::{HumantimeDuration, Iso8601Duration};
 #[cfg(feature = "eventsource")]
-pub use event_stream::{EventStream, EventStreamError};
+pub use event_stream::{EventStream, EventStreamError, NamedEvent, parse_event_data};
+pub use excluded::exclude_values;
+#[cfg(feature = "reqwest")]
+pub use hooks::{DeadlineExceeded, HookError, RequestHook, RequestHooks, RequestOptions};
 pub use http::Method;
 use http::{StatusCode, header::RETRY_AFTER};
+#[cfg(feature = "reqwest")]
+pub use json::to_json_bytes;
+#[cfg(feature = "reqwest")]
+pub use last_response::{LastResponse, ResponseMetadata};
+pub use lenient::EmptyStringAsNone;
+pub use link::{PageLinks, next_link};
+pub use malformed::MalformedResponse;
+#[cfg(feature = "decimal")]
+pub use money::{Money, MoneyError};
+pub use multipart::FilePart;
+pub use operation::{ExternalDocs, OperationMetadata};
+pub use problem::{PROBLEM_JSON, ProblemDetails, ProblemViolation};
+pub use raw_body::WithRawBody;
+pub use response_class::ResponseClass;
+#[cfg(feature = "reqwest")]
+pub use retry::{Backoff, RetryPolicy};
+/// The clock of [`RequestOptions::deadline`]: `std::time::Instant`, or a `Date.now()`
+/// reading on `wasm32`.
+#[cfg(feature = "reqwest")]
+pub use runtime::Instant;
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
 use serde::de::DeserializeOwned;
 use serde_with::{
   StringWithSeparator,
   formats::{CommaSeparator, Separator, SpaceSeparator},
 };
+pub use simple_style::{from_simple_style, simple_style};
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+pub use vcr::{
+  Cassette, Interaction, RecordedBody, RecordedRequest, RecordedResponse, VCR_MODE_ENV, Vcr, VcrError, VcrMode,
+};
 
 /// Pipe separator for `OpenAPI` pipeDelimited style
 pub struct PipeSeparator;
@@ -49,6 +120,10 @@ pub enum DiagnosticsError {
   #[cfg(feature = "quick-xml")]
   #[error(transparent)]
   XmlDeserializationError(#[from] quick_xml::DeError),
+
+  #[cfg(feature = "yaml")]
+  #[error(transparent)]
+  YamlDeserializationError(#[from] yaml_serde::Error),
 }
 
 #[allow(async_fn_in_trait)]
@@ -60,6 +135,9 @@ where
 
   #[cfg(feature = "quick-xml")]
   async fn xml_with_diagnostics(self) -> Result<T, DiagnosticsError>;
+
+  #[cfg(feature = "yaml")]
+  async fn yaml_with_diagnostics(self) -> Result<T, DiagnosticsError>;
 }
 
 #[cfg(feature = "reqwest")]
@@ -67,13 +145,21 @@ impl<T> Diagnostics<T> for reqwest::Response
 where
   T: serde::de::DeserializeOwned,
 {
+  /// Deserialize the body as JSON, reporting the path of the first mismatch on failure.
+  ///
+  /// With the `simd-json` feature, large bodies are parsed with `simd-json` first and
+  /// only fall back to the diagnostics parser when that fails.
   async fn json_with_diagnostics(self) -> Result<T, DiagnosticsError> {
-    let raw_body = self.text().await?;
-    let mut de = serde_json::Deserializer::from_str(&raw_body);
-    serde_path_to_error::deserialize(&mut de).map_err(|err| DiagnosticsError::DeserializationError {
-      path: err.path().to_string(),
-      inner: err.into_inner(),
-    })
+    #[cfg(feature = "simd-json")]
+    {
+      let raw_body = self.bytes().await?;
+      json::from_slice_with_diagnostics(&raw_body)
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+      let raw_body = self.text().await?;
+      json::from_str_with_diagnostics(&raw_body)
+    }
   }
 
   #[cfg(feature = "quick-xml")]
@@ -81,6 +167,33 @@ where
     let raw_body = self.bytes().await?;
     Ok(quick_xml::de::from_reader(std::io::Cursor::new(raw_body))?)
   }
+
+  #[cfg(feature = "yaml")]
+  async fn yaml_with_diagnostics(self) -> Result<T, DiagnosticsError> {
+    let raw_body = self.bytes().await?;
+    Ok(yaml_serde::from_slice(&raw_body)?)
+  }
+}
+
+/// Serializes a request body as YAML.
+///
+/// # Errors
+///
+/// Returns an error when `value` cannot be represented in YAML, such as a map with
+/// non-scalar keys.
+#[cfg(feature = "yaml")]
+pub fn to_yaml<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, yaml_serde::Error> {
+  yaml_serde::to_string(value)
+}
+
+/// Deserializes a YAML request body.
+///
+/// # Errors
+///
+/// Returns an error when `bytes` is not YAML or does not match `T`.
+#[cfg(feature = "yaml")]
+pub fn from_yaml<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, yaml_serde::Error> {
+  yaml_serde::from_slice(bytes)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -104,6 +217,15 @@ impl RateLimit {
     }
     Self::Exceeded
   }
+
+  /// Returns the delay the server asked for, or `None` when it gave none.
+  #[must_use]
+  pub fn retry_after(&self) -> Option<std::time::Duration> {
+    match self {
+      Self::Exceeded => None,
+      Self::TryAgainAfter(seconds) => Some(std::time::Duration::from_secs((*seconds).into())),
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
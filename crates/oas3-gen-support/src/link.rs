@@ -0,0 +1,147 @@
+use http::{HeaderMap, header::LINK};
+
+/// The `rel="next"` and `rel="prev"` links of a response's `Link` headers
+/// ([RFC 8288](https://www.rfc-editor.org/rfc/rfc8288)), as sent by APIs such as
+/// GitHub that page through results with links instead of body cursors.
+///
+/// Generated response enums carry these on variants whose response declares a `Link`
+/// header, and their `next()` and `prev()` methods turn them into follow-up requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageLinks {
+  /// Target of the `rel="next"` link.
+  pub next: Option<String>,
+  /// Target of the `rel="prev"` link, or the older `rel="previous"`.
+  pub prev: Option<String>,
+}
+
+impl PageLinks {
+  /// Collects the page links of every `Link` header in `headers`.
+  ///
+  /// ```ignore
+  /// // Link: <https://api.github.com/repos?page=3>; rel="next", <https://api.github.com/repos?page=1>; rel="prev"
+  /// let links = oas3_gen_support::PageLinks::from_headers(response.headers());
+  /// assert_eq!(links.next.as_deref(), Some("https://api.github.com/repos?page=3"));
+  /// ```
+  #[must_use]
+  pub fn from_headers(headers: &HeaderMap) -> Self {
+    Self {
+      next: find_link(headers, &["next"]),
+      prev: find_link(headers, &["prev", "previous"]),
+    }
+  }
+}
+
+/// Returns the target of the `rel="next"` link in a response's `Link` headers.
+/// See [`PageLinks`].
+///
+/// ```ignore
+/// // Link: <https://api.github.com/repos?page=2>; rel="next", <https://api.github.com/repos?page=5>; rel="last"
+/// assert_eq!(
+///   oas3_gen_support::next_link(response.headers()).as_deref(),
+///   Some("https://api.github.com/repos?page=2"),
+/// );
+/// ```
+pub fn next_link(headers: &HeaderMap) -> Option<String> {
+  find_link(headers, &["next"])
+}
+
+/// Finds the first non-empty link whose relation types include one of `rels`.
+fn find_link(headers: &HeaderMap, rels: &[&str]) -> Option<String> {
+  headers
+    .get_all(LINK)
+    .iter()
+    .filter_map(|value| value.to_str().ok())
+    .find_map(|value| link_in(value, rels))
+    .filter(|target| !target.is_empty())
+}
+
+/// Finds a link of one `Link` header value, which may list several links.
+fn link_in(value: &str, rels: &[&str]) -> Option<String> {
+  let mut rest = value;
+  while let Some(start) = rest.find('<') {
+    let end = start + rest[start..].find('>')?;
+    let target = &rest[start + 1..end];
+    let params_end = rest[end..].find('<').map_or(rest.len(), |offset| end + offset);
+    if rest[end + 1..params_end]
+      .split([';', ','])
+      .any(|param| has_rel(param, rels))
+    {
+      return Some(target.to_string());
+    }
+    rest = &rest[params_end..];
+  }
+  None
+}
+
+/// Returns `true` for a `rel` parameter whose relation types include one of `rels`.
+fn has_rel(param: &str, rels: &[&str]) -> bool {
+  param.split_once('=').is_some_and(|(name, value)| {
+    name.trim().eq_ignore_ascii_case("rel")
+      && value
+        .trim()
+        .trim_matches('"')
+        .split_ascii_whitespace()
+        .any(|rel| rels.iter().any(|wanted| rel.eq_ignore_ascii_case(wanted)))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use http::HeaderValue;
+
+  use super::*;
+
+  fn headers(values: &[&'static str]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for value in values {
+      headers.append(LINK, HeaderValue::from_static(value));
+    }
+    headers
+  }
+
+  #[test]
+  fn test_next_link_finds_next_relation() {
+    let cases = [
+      (
+        vec![
+          r#"<https://api.example.com/items?page=2>; rel="next", <https://api.example.com/items?page=9>; rel="last""#,
+        ],
+        Some("https://api.example.com/items?page=2"),
+      ),
+      (
+        vec![r#"<https://api.example.com/items?page=1>; rel="prev first", </items?page=3>; rel="next""#],
+        Some("/items?page=3"),
+      ),
+      (vec!["</items?page=4>; title=\"more\"; REL=Next"], Some("/items?page=4")),
+      (
+        vec![r#"</items?page=1>; rel="prev""#, r#"</items?page=3>; rel="next""#],
+        Some("/items?page=3"),
+      ),
+      (vec![r#"<https://api.example.com/items?page=1>; rel="first""#], None),
+      (vec![], None),
+    ];
+    for (values, expected) in cases {
+      assert_eq!(next_link(&headers(&values)).as_deref(), expected, "{values:?}");
+    }
+  }
+
+  #[test]
+  fn test_page_links_collect_next_and_prev() {
+    let links = PageLinks::from_headers(&headers(&[
+      r#"</items?page=3>; rel="next", </items?page=1>; rel="prev", </items?page=9>; rel="last""#,
+    ]));
+    assert_eq!(
+      links,
+      PageLinks {
+        next: Some("/items?page=3".to_string()),
+        prev: Some("/items?page=1".to_string()),
+      }
+    );
+
+    let links = PageLinks::from_headers(&headers(&[r#"</items?page=4>; rel="previous""#, r#"<>; rel="next""#]));
+    assert_eq!(links.prev.as_deref(), Some("/items?page=4"));
+    assert_eq!(links.next, None, "empty targets are ignored");
+
+    assert_eq!(PageLinks::from_headers(&HeaderMap::new()), PageLinks::default());
+  }
+}
@@ -0,0 +1,146 @@
+use http::StatusCode;
+
+/// A response whose body could not be deserialized into its declared schema.
+///
+/// Generated response enums carry this in a `Malformed` variant when malformed-response
+/// capture is enabled, so callers can degrade gracefully instead of failing the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedResponse {
+  /// The HTTP status code of the response.
+  pub status: StatusCode,
+  /// The raw response body, decoded as UTF-8 with invalid sequences replaced.
+  pub raw: String,
+  /// A description of why deserialization failed, including the JSON path when known.
+  pub error: String,
+}
+
+#[cfg(feature = "reqwest")]
+impl MalformedResponse {
+  /// Deserialize a JSON response body, returning `Err(MalformedResponse)` when the body
+  /// does not match `T`. Only transport failures while reading the body are returned as errors.
+  pub async fn json<T>(response: reqwest::Response) -> Result<Result<T, Self>, reqwest::Error>
+  where
+    T: serde::de::DeserializeOwned,
+  {
+    let status = response.status();
+    let raw = response.text().await?;
+    let mut de = serde_json::Deserializer::from_str(&raw);
+    let result = serde_path_to_error::deserialize::<_, T>(&mut de);
+    Ok(result.map_err(|err| {
+      let error = crate::DiagnosticsError::DeserializationError {
+        path: err.path().to_string(),
+        inner: err.into_inner(),
+      };
+      Self {
+        status,
+        raw,
+        error: error.to_string(),
+      }
+    }))
+  }
+
+  /// Deserialize an XML response body, returning `Err(MalformedResponse)` when the body
+  /// does not match `T`. Only transport failures while reading the body are returned as errors.
+  #[cfg(feature = "quick-xml")]
+  pub async fn xml<T>(response: reqwest::Response) -> Result<Result<T, Self>, reqwest::Error>
+  where
+    T: serde::de::DeserializeOwned,
+  {
+    let status = response.status();
+    let raw = response.bytes().await?;
+    Ok(
+      quick_xml::de::from_reader(std::io::Cursor::new(&raw)).map_err(|err: quick_xml::DeError| Self {
+        status,
+        raw: String::from_utf8_lossy(&raw).into_owned(),
+        error: err.to_string(),
+      }),
+    )
+  }
+
+  /// Deserialize a YAML response body, returning `Err(MalformedResponse)` when the body
+  /// does not match `T`. Only transport failures while reading the body are returned as errors.
+  #[cfg(feature = "yaml")]
+  pub async fn yaml<T>(response: reqwest::Response) -> Result<Result<T, Self>, reqwest::Error>
+  where
+    T: serde::de::DeserializeOwned,
+  {
+    let status = response.status();
+    let raw = response.bytes().await?;
+    Ok(yaml_serde::from_slice(&raw).map_err(|err| Self {
+      status,
+      raw: String::from_utf8_lossy(&raw).into_owned(),
+      error: err.to_string(),
+    }))
+  }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, serde::Deserialize, PartialEq)]
+  struct Pet {
+    id: i64,
+    name: String,
+  }
+
+  fn response(status: StatusCode, body: &'static str) -> reqwest::Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    reqwest::Response::from(response)
+  }
+
+  #[tokio::test]
+  async fn test_json_returns_value_when_body_matches() {
+    let result = MalformedResponse::json::<Pet>(response(StatusCode::OK, r#"{"id": 1, "name": "Rex"}"#))
+      .await
+      .expect("body should be readable");
+
+    assert_eq!(
+      result,
+      Ok(Pet {
+        id: 1,
+        name: "Rex".to_string()
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_json_captures_malformed_body() {
+    let body = r#"{"id": "one", "name": "Rex"}"#;
+    let result = MalformedResponse::json::<Pet>(response(StatusCode::BAD_GATEWAY, body))
+      .await
+      .expect("body should be readable");
+
+    let malformed = result.expect_err("body should not match schema");
+    assert_eq!(malformed.status, StatusCode::BAD_GATEWAY);
+    assert_eq!(malformed.raw, body);
+    assert!(
+      malformed.error.contains("'id'"),
+      "error should include the failing path: {}",
+      malformed.error
+    );
+  }
+
+  #[cfg(feature = "yaml")]
+  #[tokio::test]
+  async fn test_yaml_returns_value_or_captures_malformed_body() {
+    let result = MalformedResponse::yaml::<Pet>(response(StatusCode::OK, "id: 1\nname: Rex\n"))
+      .await
+      .expect("body should be readable");
+    assert_eq!(
+      result,
+      Ok(Pet {
+        id: 1,
+        name: "Rex".to_string()
+      })
+    );
+
+    let body = "id: one\nname: Rex\n";
+    let result = MalformedResponse::yaml::<Pet>(response(StatusCode::OK, body))
+      .await
+      .expect("body should be readable");
+    let malformed = result.expect_err("body should not match schema");
+    assert_eq!(malformed.raw, body);
+  }
+}
@@ -0,0 +1,181 @@
+use std::{fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+
+/// Error returned when a value cannot be represented as [`Money`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MoneyError {
+  #[error("invalid decimal amount `{value}`: {reason}")]
+  Parse { value: String, reason: String },
+
+  #[error("amount `{value}` has {actual} decimal places, at most {expected} are allowed")]
+  Scale { value: String, expected: u32, actual: u32 },
+}
+
+/// A fixed-scale decimal amount carried on the wire as a string, such as `"12.50"`.
+///
+/// Generated for string fields marked `x-format: money`. `SCALE` is the number of
+/// decimal places. Amounts with fewer places are padded, so `"12.5"` displays and
+/// serializes as `"12.50"` when `SCALE` is 2; amounts with more places are rejected
+/// rather than silently rounded. Arithmetic is available through the inner [`Decimal`].
+///
+/// ```ignore
+/// let price: Money<2> = "19.99".parse()?;
+/// assert_eq!(price.to_string(), "19.99");
+/// assert_eq!(price.amount() * Decimal::TWO, Decimal::new(3998, 2));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Money<const SCALE: u32>(Decimal);
+
+impl<const SCALE: u32> Money<SCALE> {
+  /// Wrap a decimal amount, padding it to `SCALE` decimal places.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`MoneyError::Scale`] when the amount has more than `SCALE` decimal places.
+  pub fn new(mut amount: Decimal) -> Result<Self, MoneyError> {
+    if amount.scale() > SCALE {
+      return Err(MoneyError::Scale {
+        value: amount.to_string(),
+        expected: SCALE,
+        actual: amount.scale(),
+      });
+    }
+    amount.rescale(SCALE);
+    Ok(Self(amount))
+  }
+
+  /// Returns the decimal amount.
+  #[must_use]
+  pub fn amount(&self) -> Decimal {
+    self.0
+  }
+
+  /// Consume the wrapper and return the decimal amount.
+  #[must_use]
+  pub fn into_inner(self) -> Decimal {
+    self.0
+  }
+}
+
+impl<const SCALE: u32> Default for Money<SCALE> {
+  fn default() -> Self {
+    Self(Decimal::new(0, SCALE))
+  }
+}
+
+impl<const SCALE: u32> fmt::Debug for Money<SCALE> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("Money").field(&format_args!("{}", self.0)).finish()
+  }
+}
+
+impl<const SCALE: u32> fmt::Display for Money<SCALE> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const SCALE: u32> FromStr for Money<SCALE> {
+  type Err = MoneyError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    let amount = Decimal::from_str_exact(value.trim()).map_err(|err| MoneyError::Parse {
+      value: value.to_string(),
+      reason: err.to_string(),
+    })?;
+    Self::new(amount)
+  }
+}
+
+impl<const SCALE: u32> TryFrom<Decimal> for Money<SCALE> {
+  type Error = MoneyError;
+
+  fn try_from(amount: Decimal) -> Result<Self, Self::Error> {
+    Self::new(amount)
+  }
+}
+
+impl<const SCALE: u32> From<Money<SCALE>> for Decimal {
+  fn from(money: Money<SCALE>) -> Self {
+    money.0
+  }
+}
+
+impl<const SCALE: u32> std::ops::Deref for Money<SCALE> {
+  type Target = Decimal;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<const SCALE: u32> Serialize for Money<SCALE> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(&self.0)
+  }
+}
+
+impl<'de, const SCALE: u32> Deserialize<'de> for Money<SCALE> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(D::Error::custom)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+  struct Invoice {
+    total: Money<2>,
+    rate: Money<4>,
+  }
+
+  #[test]
+  fn test_money_round_trips_exact_strings() {
+    let json = r#"{"total":"1234.50","rate":"0.0125"}"#;
+    let invoice = serde_json::from_str::<Invoice>(json).unwrap();
+    assert_eq!(invoice.total.amount(), Decimal::new(123_450, 2));
+    assert_eq!(invoice.rate.amount(), Decimal::new(125, 4));
+    assert_eq!(serde_json::to_string(&invoice).unwrap(), json);
+  }
+
+  #[test]
+  fn test_money_pads_to_scale() {
+    let cases = [
+      ("12", "12.00"),
+      ("12.5", "12.50"),
+      ("-0.1", "-0.10"),
+      (" 7.25 ", "7.25"),
+    ];
+
+    for (input, expected) in cases {
+      let money = input.parse::<Money<2>>().unwrap_or_else(|err| panic!("{input}: {err}"));
+      assert_eq!(money.to_string(), expected, "{input}");
+    }
+    assert_eq!(Money::<2>::default().to_string(), "0.00");
+  }
+
+  #[test]
+  fn test_money_rejects_invalid_amounts() {
+    assert_eq!(
+      "12.345".parse::<Money<2>>(),
+      Err(MoneyError::Scale {
+        value: "12.345".to_string(),
+        expected: 2,
+        actual: 3
+      })
+    );
+    assert!(matches!("twelve".parse::<Money<2>>(), Err(MoneyError::Parse { .. })));
+    assert!(serde_json::from_str::<Invoice>(r#"{"total":12.5,"rate":"0"}"#).is_err());
+  }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// A file sent as one part of a `multipart/form-data` request body.
+///
+/// Generated multipart request bodies use this for `format: binary` properties, so callers
+/// can set the file name and content type the server sees alongside the bytes.
+///
+/// # Example
+///
+/// ```ignore
+/// let image = FilePart::new(std::fs::read("rex.png")?)
+///   .with_filename("rex.png")
+///   .with_content_type("image/png");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FilePart {
+  /// The file name sent in the part's `Content-Disposition` header.
+  pub filename: Option<String>,
+  /// The file contents.
+  pub bytes: Vec<u8>,
+  /// The MIME type sent in the part's `Content-Type` header.
+  pub content_type: Option<String>,
+}
+
+impl FilePart {
+  /// Create a part from the file contents, without a file name or content type.
+  #[must_use]
+  pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+    Self {
+      filename: None,
+      bytes: bytes.into(),
+      content_type: None,
+    }
+  }
+
+  /// Set the file name sent with the part.
+  #[must_use]
+  pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+    self.filename = Some(filename.into());
+    self
+  }
+
+  /// Set the MIME type sent with the part.
+  #[must_use]
+  pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+    self.content_type = Some(content_type.into());
+    self
+  }
+
+  /// Build the `reqwest` multipart part, failing when the content type is not a valid MIME type.
+  #[cfg(feature = "reqwest")]
+  pub fn to_part(&self) -> Result<reqwest::multipart::Part, reqwest::Error> {
+    let part = reqwest::multipart::Part::bytes(self.bytes.clone());
+    let part = match &self.filename {
+      Some(filename) => part.file_name(filename.clone()),
+      None => part,
+    };
+    match &self.content_type {
+      Some(content_type) => part.mime_str(content_type),
+      None => Ok(part),
+    }
+  }
+}
+
+impl From<Vec<u8>> for FilePart {
+  fn from(bytes: Vec<u8>) -> Self {
+    Self::new(bytes)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_builder_methods_set_metadata() {
+    let part = FilePart::new(b"png".to_vec())
+      .with_filename("rex.png")
+      .with_content_type("image/png");
+
+    assert_eq!(
+      part,
+      FilePart {
+        filename: Some("rex.png".to_string()),
+        bytes: b"png".to_vec(),
+        content_type: Some("image/png".to_string()),
+      }
+    );
+    assert_eq!(FilePart::from(b"raw".to_vec()), FilePart::new(b"raw".to_vec()));
+  }
+
+  #[cfg(feature = "reqwest")]
+  #[test]
+  fn test_to_part_validates_content_type() {
+    assert!(
+      FilePart::new(b"png".to_vec())
+        .with_content_type("image/png")
+        .to_part()
+        .is_ok()
+    );
+    assert!(
+      FilePart::new(b"png".to_vec())
+        .with_content_type("not a mime")
+        .to_part()
+        .is_err()
+    );
+  }
+}
@@ -0,0 +1,70 @@
+/// An operation as declared in the OpenAPI spec, listed in the `OPERATIONS` table of a
+/// generated client so applications can route, meter, or label calls by tag or
+/// extension without the spec at hand.
+///
+/// ```ignore
+/// let billed = OPERATIONS.iter().filter(|op| op.has_tag("billing")).count();
+/// let limit = OPERATIONS[0].extension("x-rate-limit");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationMetadata {
+  /// The `operationId`, or the name generated for an operation without one.
+  pub operation_id: &'static str,
+  /// The HTTP method, uppercase.
+  pub method: &'static str,
+  /// The path template, such as `/pets/{petId}`.
+  pub path: &'static str,
+  /// The operation's tags, in spec order.
+  pub tags: &'static [&'static str],
+  /// The operation's `externalDocs` link.
+  pub external_docs: Option<ExternalDocs>,
+  /// The operation's `x-*` extensions as `(name, JSON)` pairs, with names including the
+  /// `x-` prefix, sorted by name.
+  pub extensions: &'static [(&'static str, &'static str)],
+}
+
+impl OperationMetadata {
+  /// Returns `true` when the operation carries `tag`.
+  #[must_use]
+  pub fn has_tag(&self, tag: &str) -> bool {
+    self.tags.contains(&tag)
+  }
+
+  /// Returns the JSON source of the extension named `name`, such as `x-rate-limit`.
+  #[must_use]
+  pub fn extension(&self, name: &str) -> Option<&'static str> {
+    self
+      .extensions
+      .iter()
+      .find_map(|(key, value)| (*key == name).then_some(*value))
+  }
+}
+
+/// An `externalDocs` link of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalDocs {
+  pub url: &'static str,
+  pub description: Option<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const LIST_PETS: OperationMetadata = OperationMetadata {
+    operation_id: "listPets",
+    method: "GET",
+    path: "/pets",
+    tags: &["pets", "public"],
+    external_docs: None,
+    extensions: &[("x-rate-limit", "100"), ("x-team", "\"pets\"")],
+  };
+
+  #[test]
+  fn test_operation_metadata_lookups() {
+    assert!(LIST_PETS.has_tag("public"));
+    assert!(!LIST_PETS.has_tag("admin"));
+    assert_eq!(LIST_PETS.extension("x-team"), Some("\"pets\""));
+    assert_eq!(LIST_PETS.extension("rate-limit"), None);
+  }
+}
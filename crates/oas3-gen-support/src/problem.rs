@@ -0,0 +1,119 @@
+use http::StatusCode;
+use serde::Serialize;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+/// Media type of an RFC 7807 problem details body.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// An RFC 7807 problem details body.
+///
+/// Generated servers answer with one when an incoming request breaks a constraint
+/// declared in the spec, listing each violation under the `errors` extension member.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProblemDetails {
+  /// A URI identifying the problem type, `about:blank` when the status says it all.
+  #[serde(rename = "type")]
+  pub problem_type: String,
+  /// A short summary of the problem type.
+  pub title: String,
+  /// The HTTP status code of the response.
+  pub status: u16,
+  /// An explanation of this occurrence of the problem.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub detail: Option<String>,
+  /// The constraint violations found in the request.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub errors: Vec<ProblemViolation>,
+}
+
+/// One constraint a request value broke.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProblemViolation {
+  /// A JSON pointer to the value within the request, such as `/body/name` or `/path/pet_id`.
+  pub pointer: String,
+  /// The validator that failed, such as `length`, `range`, or `regex`.
+  pub code: String,
+  /// The validator's message, when one was set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+}
+
+impl ProblemDetails {
+  /// Builds a `400 Bad Request` problem listing every violation in `errors`, sorted by pointer.
+  #[must_use]
+  pub fn from_validation_errors(errors: &ValidationErrors) -> Self {
+    let mut violations = vec![];
+    collect_violations("", errors, &mut violations);
+    violations.sort_by(|a, b| (&a.pointer, &a.code).cmp(&(&b.pointer, &b.code)));
+    let detail = match violations.len() {
+      1 => "The request broke 1 constraint.".to_string(),
+      count => format!("The request broke {count} constraints."),
+    };
+    Self {
+      problem_type: "about:blank".to_string(),
+      title: "Request validation failed".to_string(),
+      status: StatusCode::BAD_REQUEST.as_u16(),
+      detail: Some(detail),
+      errors: violations,
+    }
+  }
+
+  /// Returns [`Self::status`] as a status code, or `400 Bad Request` when it is not valid.
+  #[must_use]
+  pub fn status_code(&self) -> StatusCode {
+    StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST)
+  }
+}
+
+fn collect_violations(prefix: &str, errors: &ValidationErrors, violations: &mut Vec<ProblemViolation>) {
+  for (field, kind) in errors.errors() {
+    let pointer = format!("{prefix}/{field}");
+    match kind {
+      ValidationErrorsKind::Field(errors) => violations.extend(errors.iter().map(|error| ProblemViolation {
+        pointer: pointer.clone(),
+        code: error.code.to_string(),
+        message: error.message.as_ref().map(ToString::to_string),
+      })),
+      ValidationErrorsKind::Struct(nested) => collect_violations(&pointer, nested, violations),
+      ValidationErrorsKind::List(items) => {
+        for (index, nested) in items {
+          collect_violations(&format!("{pointer}/{index}"), nested, violations);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use validator::ValidationError;
+
+  use super::*;
+
+  #[test]
+  fn test_problem_from_nested_validation_errors() {
+    let mut body = ValidationErrors::new();
+    body.add("name", ValidationError::new("length"));
+    body.add("count", ValidationError::new("range").with_message("too many".into()));
+    let mut errors = ValidationErrors::new();
+    errors
+      .errors_mut()
+      .insert("body".into(), ValidationErrorsKind::Struct(Box::new(body)));
+
+    let problem = ProblemDetails::from_validation_errors(&errors);
+
+    assert_eq!(problem.status_code(), StatusCode::BAD_REQUEST);
+    let pointers = problem
+      .errors
+      .iter()
+      .map(|violation| (violation.pointer.as_str(), violation.code.as_str()))
+      .collect::<Vec<_>>();
+    assert_eq!(pointers, vec![("/body/count", "range"), ("/body/name", "length")]);
+    assert_eq!(problem.errors[0].message.as_deref(), Some("too many"));
+
+    let json = serde_json::to_value(&problem).expect("problem should serialize");
+    assert_eq!(json["type"], "about:blank");
+    assert_eq!(json["status"], 400);
+    assert_eq!(json["detail"], "The request broke 2 constraints.");
+  }
+}
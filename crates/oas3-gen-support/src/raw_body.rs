@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use bytes::Bytes;
+
+/// A typed response value paired with the raw body bytes it was deserialized from.
+///
+/// Generated clients return this wrapper when raw body retention is enabled, so callers
+/// can verify payload signatures, write audit logs, or show the exact wire response
+/// without issuing the request twice.
+///
+/// # Example
+///
+/// ```ignore
+/// let result = client.get_pet(request).await?;
+/// verify_signature(result.raw())?;
+/// let pet = result.into_inner();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithRawBody<T> {
+  value: T,
+  raw: Bytes,
+}
+
+impl<T> WithRawBody<T> {
+  /// Create a new wrapper from an already parsed value and its raw body.
+  #[must_use]
+  pub fn new(value: T, raw: Bytes) -> Self {
+    Self { value, raw }
+  }
+
+  /// Buffer the full response body, then hand an equivalent response to `parse`.
+  ///
+  /// The response passed to `parse` carries the original status, version, and headers,
+  /// with a body backed by the buffered bytes, so any existing parsing logic works unchanged.
+  #[cfg(feature = "reqwest")]
+  pub async fn capture<F, Fut, E>(response: reqwest::Response, parse: F) -> Result<Self, E>
+  where
+    F: FnOnce(reqwest::Response) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: From<reqwest::Error>,
+  {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let raw = response.bytes().await?;
+
+    let mut replay = http::Response::new(raw.clone());
+    *replay.status_mut() = status;
+    *replay.version_mut() = version;
+    *replay.headers_mut() = headers;
+
+    let value = parse(reqwest::Response::from(replay)).await?;
+    Ok(Self { value, raw })
+  }
+
+  /// Returns a reference to the parsed value.
+  pub fn value(&self) -> &T {
+    &self.value
+  }
+
+  /// Returns the raw body bytes exactly as received.
+  pub fn raw(&self) -> &Bytes {
+    &self.raw
+  }
+
+  /// Returns the raw body decoded as UTF-8, replacing invalid sequences.
+  pub fn raw_text(&self) -> Cow<'_, str> {
+    String::from_utf8_lossy(&self.raw)
+  }
+
+  /// Consume the wrapper and return the parsed value, discarding the raw body.
+  #[must_use]
+  pub fn into_inner(self) -> T {
+    self.value
+  }
+
+  /// Consume the wrapper and return the parsed value together with the raw body.
+  #[must_use]
+  pub fn into_parts(self) -> (T, Bytes) {
+    (self.value, self.raw)
+  }
+}
+
+impl<T> std::ops::Deref for WithRawBody<T> {
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    &self.value
+  }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, serde::Deserialize, PartialEq)]
+  struct Pet {
+    id: i64,
+    name: String,
+  }
+
+  fn json_response(body: &'static str) -> reqwest::Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = http::StatusCode::CREATED;
+    response.headers_mut().insert(
+      http::header::CONTENT_TYPE,
+      http::HeaderValue::from_static("application/json"),
+    );
+    reqwest::Response::from(response)
+  }
+
+  #[tokio::test]
+  async fn test_capture_retains_raw_body_and_parses_value() {
+    let body = r#"{"id": 7, "name": "Rex"}"#;
+    let result = WithRawBody::capture(json_response(body), |response| async move {
+      crate::Diagnostics::<Pet>::json_with_diagnostics(response).await
+    })
+    .await
+    .expect("capture should succeed");
+
+    assert_eq!(
+      result.value(),
+      &Pet {
+        id: 7,
+        name: "Rex".to_string()
+      }
+    );
+    assert_eq!(result.raw().as_ref(), body.as_bytes());
+    assert_eq!(result.raw_text(), body);
+  }
+
+  #[tokio::test]
+  async fn test_capture_preserves_status_and_headers() {
+    let result = WithRawBody::capture(json_response("{}"), |response| async move {
+      Ok::<_, reqwest::Error>((
+        response.status(),
+        response.headers().get(http::header::CONTENT_TYPE).cloned(),
+      ))
+    })
+    .await
+    .expect("capture should succeed");
+
+    let (status, content_type) = result.into_inner();
+    assert_eq!(status, http::StatusCode::CREATED);
+    assert_eq!(content_type.unwrap(), "application/json");
+  }
+}
@@ -0,0 +1,79 @@
+use http::StatusCode;
+
+/// How a response status should be handled, for retry, alerting, or metrics policy.
+///
+/// Generated response enums classify the statuses their operation documents with
+/// `classify`, and fall back to [`ResponseClass::from`] for the rest.
+///
+/// ```ignore
+/// if ListPetsResponse::classify(response.status()).is_retryable() {
+///   // schedule another attempt
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseClass {
+  /// A `1XX`, `2XX`, or `3XX` status.
+  Success,
+  /// A `4XX` status that sending the same request again will not fix.
+  ClientError,
+  /// A `5XX` status that sending the same request again will not fix.
+  ServerError,
+  /// A status signalling the request may succeed when sent again later.
+  Retryable,
+}
+
+impl ResponseClass {
+  /// Returns `true` for [`ResponseClass::Success`].
+  #[must_use]
+  pub fn is_success(self) -> bool {
+    self == Self::Success
+  }
+
+  /// Returns `true` for [`ResponseClass::Retryable`].
+  #[must_use]
+  pub fn is_retryable(self) -> bool {
+    self == Self::Retryable
+  }
+}
+
+impl From<StatusCode> for ResponseClass {
+  /// Classifies `status` by its code alone.
+  ///
+  /// `408 Request Timeout`, `425 Too Early`, `429 Too Many Requests`, `502 Bad Gateway`,
+  /// `503 Service Unavailable`, and `504 Gateway Timeout` are retryable.
+  fn from(status: StatusCode) -> Self {
+    match status {
+      StatusCode::REQUEST_TIMEOUT
+      | StatusCode::TOO_EARLY
+      | StatusCode::TOO_MANY_REQUESTS
+      | StatusCode::BAD_GATEWAY
+      | StatusCode::SERVICE_UNAVAILABLE
+      | StatusCode::GATEWAY_TIMEOUT => Self::Retryable,
+      status if status.is_server_error() => Self::ServerError,
+      status if status.is_client_error() => Self::ClientError,
+      _ => Self::Success,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_response_class_from_status() {
+    let cases = [
+      (StatusCode::OK, ResponseClass::Success),
+      (StatusCode::NOT_MODIFIED, ResponseClass::Success),
+      (StatusCode::NOT_FOUND, ResponseClass::ClientError),
+      (StatusCode::TOO_MANY_REQUESTS, ResponseClass::Retryable),
+      (StatusCode::INTERNAL_SERVER_ERROR, ResponseClass::ServerError),
+      (StatusCode::SERVICE_UNAVAILABLE, ResponseClass::Retryable),
+    ];
+    for (status, class) in cases {
+      assert_eq!(ResponseClass::from(status), class, "{status}");
+    }
+    assert!(ResponseClass::from(StatusCode::GATEWAY_TIMEOUT).is_retryable());
+    assert!(!ResponseClass::from(StatusCode::CREATED).is_retryable());
+  }
+}
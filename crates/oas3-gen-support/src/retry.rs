@@ -0,0 +1,241 @@
+use std::time::Duration;
+
+use http::{HeaderMap, StatusCode};
+
+use crate::{
+  RateLimit,
+  runtime::{is_connect_error, sleep},
+};
+
+/// How long to wait between retry attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+  /// Wait the same duration before every retry.
+  Constant(Duration),
+  /// Start at `initial` and double the delay after each retry, up to `max`.
+  Exponential { initial: Duration, max: Duration },
+}
+
+impl Backoff {
+  /// Returns the delay before retry number `attempt`, counting from zero.
+  #[must_use]
+  pub fn delay(&self, attempt: u32) -> Duration {
+    match *self {
+      Self::Constant(delay) => delay,
+      Self::Exponential { initial, max } => initial
+        .checked_mul(2u32.saturating_pow(attempt))
+        .map_or(max, |delay| delay.min(max)),
+    }
+  }
+}
+
+impl Default for Backoff {
+  fn default() -> Self {
+    Self::Exponential {
+      initial: Duration::from_millis(200),
+      max: Duration::from_secs(10),
+    }
+  }
+}
+
+/// Retry behavior for generated client requests.
+///
+/// A request is retried when the connection cannot be established or the server
+/// responds with one of `retry_statuses`. The defaults only retry `429 Too Many
+/// Requests` and `503 Service Unavailable`, which signal that the request was not
+/// processed, so retrying is safe for every HTTP method. When `respect_retry_after`
+/// is set, a `Retry-After` header given in seconds replaces the backoff delay. No
+/// delay exceeds `max_delay`, however long the server asks the client to wait.
+///
+/// ```ignore
+/// let client = PetStoreClient::new().with_retry(
+///   RetryPolicy::builder()
+///     .max_retries(5)
+///     .backoff(Backoff::Constant(Duration::from_secs(1)))
+///     .build(),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
+pub struct RetryPolicy {
+  /// Retries after the first attempt; `0` disables retrying.
+  #[builder(default = 3)]
+  pub max_retries: u32,
+  #[builder(default)]
+  pub backoff: Backoff,
+  #[builder(default = true)]
+  pub respect_retry_after: bool,
+  /// Upper bound on every delay, including one requested by `Retry-After`.
+  #[builder(default = Duration::from_secs(60))]
+  pub max_delay: Duration,
+  #[builder(default = vec![StatusCode::TOO_MANY_REQUESTS, StatusCode::SERVICE_UNAVAILABLE])]
+  pub retry_statuses: Vec<StatusCode>,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self::builder().build()
+  }
+}
+
+impl RetryPolicy {
+  /// Returns the delay before retry number `attempt`, honoring `Retry-After` in `headers`,
+  /// capped at `max_delay`.
+  #[must_use]
+  pub fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+    let delay = match headers.map(RateLimit::with_headers) {
+      Some(RateLimit::TryAgainAfter(seconds)) if self.respect_retry_after => Duration::from_secs(seconds.into()),
+      _ => self.backoff.delay(attempt),
+    };
+    delay.min(self.max_delay)
+  }
+
+  /// Sends `request`, retrying according to the policy.
+  ///
+  /// Requests whose body cannot be cloned, such as streaming uploads, are sent once.
+  /// The response of the final attempt is returned even when its status is retryable.
+  ///
+  /// # Errors
+  ///
+  /// Returns the error of the final attempt if it could not be sent.
+  pub async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, reqwest::Error> {
+    let mut attempt = 0;
+    loop {
+      let Some(retryable) = (attempt < self.max_retries).then(|| request.try_clone()).flatten() else {
+        return request.send().await;
+      };
+
+      let delay = match retryable.send().await {
+        Ok(response) if self.retry_statuses.contains(&response.status()) => {
+          self.delay(attempt, Some(response.headers()))
+        }
+        Err(err) if is_connect_error(&err) => self.delay(attempt, None),
+        result => return result,
+      };
+      sleep(delay).await;
+      attempt += 1;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  };
+
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+
+  use super::*;
+
+  async fn serve_sequence(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    tokio::spawn(async move {
+      loop {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = vec![0; 4096];
+        let _ = stream.read(&mut request).await.unwrap();
+        let index = counter.fetch_add(1, Ordering::SeqCst);
+        let head = responses[index.min(responses.len() - 1)];
+        let response = format!("HTTP/1.1 {head}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+        stream.write_all(response.as_bytes()).await.unwrap();
+      }
+    });
+    (url, hits)
+  }
+
+  fn fast_policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy::builder()
+      .max_retries(max_retries)
+      .backoff(Backoff::Constant(Duration::ZERO))
+      .build()
+  }
+
+  #[tokio::test]
+  async fn test_send_retries_retryable_statuses() {
+    let cases = [
+      (vec!["503 Service Unavailable", "200 OK"], 3, StatusCode::OK, 2),
+      (
+        vec![
+          "429 Too Many Requests\r\nretry-after: 0",
+          "429 Too Many Requests",
+          "200 OK",
+        ],
+        3,
+        StatusCode::OK,
+        3,
+      ),
+      (vec!["503 Service Unavailable"], 2, StatusCode::SERVICE_UNAVAILABLE, 3),
+      (
+        vec!["500 Internal Server Error", "200 OK"],
+        3,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        1,
+      ),
+    ];
+
+    for (responses, max_retries, expected_status, expected_hits) in cases {
+      let (url, hits) = serve_sequence(responses.clone()).await;
+      let client = reqwest::Client::new();
+      let response = fast_policy(max_retries).send(client.get(&url)).await.unwrap();
+      assert_eq!(response.status(), expected_status, "{responses:?}");
+      assert_eq!(hits.load(Ordering::SeqCst), expected_hits, "{responses:?}");
+    }
+  }
+
+  #[test]
+  fn test_delay_prefers_retry_after_and_caps_backoff() {
+    let policy = RetryPolicy::builder()
+      .backoff(Backoff::Exponential {
+        initial: Duration::from_millis(100),
+        max: Duration::from_secs(1),
+      })
+      .build();
+    let mut headers = HeaderMap::new();
+    headers.insert(http::header::RETRY_AFTER, "7".parse().unwrap());
+
+    assert_eq!(policy.delay(0, None), Duration::from_millis(100));
+    assert_eq!(policy.delay(2, None), Duration::from_millis(400));
+    assert_eq!(policy.delay(40, None), Duration::from_secs(1));
+    assert_eq!(policy.delay(0, Some(&headers)), Duration::from_secs(7));
+
+    let ignoring = RetryPolicy {
+      respect_retry_after: false,
+      ..policy.clone()
+    };
+    assert_eq!(ignoring.delay(0, Some(&headers)), Duration::from_millis(100));
+
+    headers.insert(http::header::RETRY_AFTER, "86400".parse().unwrap());
+    assert_eq!(policy.delay(0, Some(&headers)), Duration::from_secs(60));
+    let capped = RetryPolicy {
+      max_delay: Duration::from_secs(5),
+      ..policy
+    };
+    assert_eq!(capped.delay(0, Some(&headers)), Duration::from_secs(5));
+    assert_eq!(capped.delay(40, None), Duration::from_secs(1));
+  }
+
+  #[test]
+  fn test_rate_limit_retry_after_reads_seconds() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(RateLimit::with_headers(&headers).retry_after(), None);
+
+    headers.insert(
+      http::header::RETRY_AFTER,
+      "Wed, 21 Oct 2026 07:28:00 GMT".parse().unwrap(),
+    );
+    assert_eq!(RateLimit::with_headers(&headers).retry_after(), None);
+
+    headers.insert(http::header::RETRY_AFTER, "30".parse().unwrap());
+    assert_eq!(
+      RateLimit::with_headers(&headers).retry_after(),
+      Some(Duration::from_secs(30))
+    );
+  }
+}
@@ -0,0 +1,98 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::Instant;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use native::{elapsed, is_connect_error, sleep, time_until};
+#[cfg(target_arch = "wasm32")]
+pub use wasm::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasm::{elapsed, is_connect_error, sleep, time_until};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+  use std::time::Duration;
+  pub use std::time::Instant;
+
+  pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+  }
+
+  /// Returns the time left before `deadline`, or `None` once it has passed.
+  pub(crate) fn time_until(deadline: Instant) -> Option<Duration> {
+    deadline
+      .checked_duration_since(Instant::now())
+      .filter(|remaining| !remaining.is_zero())
+  }
+
+  /// Returns the time since `start`.
+  pub(crate) fn elapsed(start: Instant) -> Duration {
+    start.elapsed()
+  }
+
+  pub(crate) fn is_connect_error(err: &reqwest::Error) -> bool {
+    err.is_connect()
+  }
+}
+
+/// Browser replacements for the timer, clock, and connection checks, which `tokio`
+/// and `std::time::Instant` do not provide on `wasm32-unknown-unknown`.
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+  use std::{ops::Add, time::Duration};
+
+  use wasm_bindgen::prelude::*;
+
+  #[wasm_bindgen]
+  extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> JsValue;
+  }
+
+  /// A point in time read from `Date.now()`, in milliseconds.
+  #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+  pub struct Instant(f64);
+
+  /// `Date.now()` never returns `NaN`.
+  impl Eq for Instant {}
+
+  impl Instant {
+    #[must_use]
+    pub fn now() -> Self {
+      Self(js_sys::Date::now())
+    }
+  }
+
+  impl Add<Duration> for Instant {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+      Self(self.0 + duration.as_secs_f64() * 1000.0)
+    }
+  }
+
+  /// Resolves after `duration` through the global `setTimeout`, which both windows
+  /// and workers provide.
+  pub(crate) async fn sleep(duration: Duration) {
+    let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+      set_timeout(&resolve, timeout);
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+  }
+
+  /// Returns the time left before `deadline`, or `None` once it has passed.
+  pub(crate) fn time_until(deadline: Instant) -> Option<Duration> {
+    let remaining = deadline.0 - js_sys::Date::now();
+    (remaining > 0.0).then(|| Duration::from_secs_f64(remaining / 1000.0))
+  }
+
+  /// Returns the time since `start`, or zero if the clock went backwards.
+  pub(crate) fn elapsed(start: Instant) -> Duration {
+    Duration::from_secs_f64((js_sys::Date::now() - start.0).max(0.0) / 1000.0)
+  }
+
+  /// `fetch` reports unreachable hosts as request errors, as there is no separate
+  /// connect phase to fail.
+  pub(crate) fn is_connect_error(err: &reqwest::Error) -> bool {
+    err.is_request()
+  }
+}
@@ -0,0 +1,125 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+/// Writes a path or header parameter value in OpenAPI `simple` style.
+///
+/// Arrays become comma-separated items (`blue,black`). Objects become alternating
+/// keys and values (`role,admin,first,Alex`), or `key=value` pairs when `explode`
+/// is set (`role=admin,first=Alex`). Strings are written without quotes, `null`
+/// as an empty string, and values nested inside an array or object as JSON.
+///
+/// ```ignore
+/// let filter = serde_json::json!({ "role": "admin", "first": "Alex" });
+/// assert_eq!(oas3_gen_support::simple_style(&filter, true), "role=admin,first=Alex");
+/// ```
+pub fn simple_style<T: Serialize + ?Sized>(value: &T, explode: bool) -> String {
+  match serde_json::to_value(value).unwrap_or_default() {
+    Value::Array(items) => items.iter().map(scalar).collect::<Vec<_>>().join(","),
+    Value::Object(fields) => fields
+      .iter()
+      .map(|(key, value)| {
+        let separator = if explode { "=" } else { "," };
+        format!("{key}{separator}{}", scalar(value))
+      })
+      .collect::<Vec<_>>()
+      .join(","),
+    value => scalar(&value),
+  }
+}
+
+/// Reads an object written in OpenAPI `simple` style by [`simple_style`].
+///
+/// Values that look like numbers or booleans are tried as such first, then every
+/// value as a string, so both `{ "age": 34 }` and `{ "age": "34" }` shapes parse.
+/// Returns `None` when `input` is not a valid object for `T`.
+pub fn from_simple_style<T: DeserializeOwned>(input: &str, explode: bool) -> Option<T> {
+  let pairs = if explode {
+    input
+      .split(',')
+      .map(|pair| pair.split_once('='))
+      .collect::<Option<Vec<_>>>()?
+  } else {
+    let parts = input.split(',').collect::<Vec<_>>();
+    if parts.len() % 2 != 0 {
+      return None;
+    }
+    parts.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+  };
+
+  let object = |typed: bool| {
+    pairs
+      .iter()
+      .map(|(key, value)| {
+        let value = typed
+          .then(|| serde_json::from_str::<Value>(value).ok())
+          .flatten()
+          .filter(|value| value.is_number() || value.is_boolean())
+          .unwrap_or_else(|| Value::String((*value).to_string()));
+        ((*key).to_string(), value)
+      })
+      .collect::<Map<_, _>>()
+  };
+  serde_json::from_value(Value::Object(object(true)))
+    .or_else(|_| serde_json::from_value(Value::Object(object(false))))
+    .ok()
+}
+
+fn scalar(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(value) => value.clone(),
+    value => value.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_simple_style_arrays_and_scalars() {
+    assert_eq!(simple_style(&vec![3, 4, 5], false), "3,4,5");
+    assert_eq!(simple_style(&["blue", "black"], true), "blue,black");
+    assert_eq!(simple_style("plain", false), "plain");
+    assert_eq!(simple_style(&42, false), "42");
+    assert_eq!(simple_style(&Option::<i64>::None, false), "");
+  }
+
+  #[test]
+  fn test_simple_style_objects() {
+    let filter = json!({ "role": "admin", "first": "Alex", "age": 34 });
+    assert_eq!(simple_style(&filter, false), "role,admin,first,Alex,age,34");
+    assert_eq!(simple_style(&filter, true), "role=admin,first=Alex,age=34");
+
+    let map = BTreeMap::from([("tags", vec!["a", "b"])]);
+    assert_eq!(simple_style(&map, true), r#"tags=["a","b"]"#);
+  }
+
+  #[derive(Debug, PartialEq, serde::Deserialize)]
+  struct Filter {
+    role: String,
+    age: i64,
+  }
+
+  #[test]
+  fn test_from_simple_style_round_trips_objects() {
+    let expected = Filter {
+      role: "admin".to_string(),
+      age: 34,
+    };
+    assert_eq!(from_simple_style("role,admin,age,34", false), Some(expected));
+    assert_eq!(
+      from_simple_style::<BTreeMap<String, String>>("code=7,on=true", true),
+      Some(BTreeMap::from([
+        ("code".to_string(), "7".to_string()),
+        ("on".to_string(), "true".to_string()),
+      ]))
+    );
+    assert_eq!(from_simple_style::<Filter>("role,admin,age", false), None);
+    assert_eq!(from_simple_style::<Filter>("role=admin,age", true), None);
+  }
+}
@@ -0,0 +1,414 @@
+use std::{
+  collections::VecDeque,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable that selects the [`VcrMode`] used by [`Vcr::from_env`].
+pub const VCR_MODE_ENV: &str = "OAS3_VCR";
+
+/// Whether a [`Vcr`] talks to the real server or plays back a cassette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VcrMode {
+  /// Send requests to the server and append each interaction to the cassette.
+  Record,
+  /// Serve responses from the cassette without touching the network.
+  #[default]
+  Replay,
+}
+
+impl VcrMode {
+  /// Reads the mode from [`VCR_MODE_ENV`]: `record` selects [`VcrMode::Record`],
+  /// anything else (including unset) selects [`VcrMode::Replay`].
+  #[must_use]
+  pub fn from_env() -> Self {
+    match std::env::var(VCR_MODE_ENV) {
+      Ok(value) if value.eq_ignore_ascii_case("record") => Self::Record,
+      _ => Self::Replay,
+    }
+  }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VcrError {
+  #[error("reading cassette `{path}`: {source}")]
+  Io {
+    path: PathBuf,
+    #[source]
+    source: std::io::Error,
+  },
+
+  #[error("parsing cassette `{path}`: {source}")]
+  Format {
+    path: PathBuf,
+    #[source]
+    source: serde_json::Error,
+  },
+
+  #[error("no recorded interaction for {method} {url} in `{path}`; re-record with {VCR_MODE_ENV}=record")]
+  NoMatch { method: String, url: String, path: PathBuf },
+
+  #[error(transparent)]
+  Http(#[from] reqwest::Error),
+
+  #[error("rebuilding recorded response: {0}")]
+  Response(#[from] http::Error),
+}
+
+/// A request or response body stored in a cassette.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+  /// A UTF-8 body, stored verbatim for readable diffs.
+  Text(String),
+  /// A binary body, stored as base64.
+  Base64(#[serde(with = "base64_bytes")] Vec<u8>),
+}
+
+impl RecordedBody {
+  fn from_bytes(bytes: &[u8]) -> Self {
+    match std::str::from_utf8(bytes) {
+      Ok(text) => Self::Text(text.to_string()),
+      Err(_) => Self::Base64(bytes.to_vec()),
+    }
+  }
+
+  fn into_bytes(self) -> Bytes {
+    match self {
+      Self::Text(text) => Bytes::from(text),
+      Self::Base64(bytes) => Bytes::from(bytes),
+    }
+  }
+}
+
+/// The parts of a request used to match it against recorded interactions.
+///
+/// Request headers are never recorded, so credentials do not end up in cassettes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+  pub method: String,
+  pub url: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub body: Option<RecordedBody>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+  pub status: u16,
+  #[serde(default)]
+  pub headers: Vec<(String, String)>,
+  pub body: RecordedBody,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+  pub request: RecordedRequest,
+  pub response: RecordedResponse,
+}
+
+/// A JSON file holding recorded HTTP interactions in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+  pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+  /// Load a cassette from disk.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the file cannot be read or is not a valid cassette.
+  pub fn load(path: &Path) -> Result<Self, VcrError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| VcrError::Io {
+      path: path.to_path_buf(),
+      source,
+    })?;
+    serde_json::from_str(&contents).map_err(|source| VcrError::Format {
+      path: path.to_path_buf(),
+      source,
+    })
+  }
+
+  /// Write the cassette to disk as pretty-printed JSON, creating parent directories.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the file cannot be written.
+  pub fn save(&self, path: &Path) -> Result<(), VcrError> {
+    let io_error = |source| VcrError::Io {
+      path: path.to_path_buf(),
+      source,
+    };
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let contents = serde_json::to_string_pretty(self).map_err(|source| VcrError::Format {
+      path: path.to_path_buf(),
+      source,
+    })?;
+    std::fs::write(path, contents + "\n").map_err(io_error)
+  }
+}
+
+#[derive(Debug)]
+struct VcrState {
+  recorded: Vec<Interaction>,
+  pending: VecDeque<Interaction>,
+}
+
+/// Records HTTP interactions to a cassette file, or replays them without a network.
+///
+/// Generated clients built with cassette support route every request through
+/// [`Vcr::execute`]. Record a cassette once against a real server, commit it, and
+/// replay it deterministically in tests.
+///
+/// ```ignore
+/// #[tokio::test]
+/// async fn lists_pets() -> anyhow::Result<()> {
+///   let client = PetstoreClient::with_cassette(BASE_URL, Vcr::cassette_path("list_pets"))?;
+///   let pets = client.list_pets(ListPetsRequest::default()).await?;
+///   assert!(matches!(pets, ListPetsResponse::Ok(_)));
+///   Ok(())
+/// }
+/// ```
+///
+/// Run `OAS3_VCR=record cargo test` to (re-)record, and plain `cargo test` to replay.
+#[derive(Debug, Clone)]
+pub struct Vcr {
+  mode: VcrMode,
+  path: Arc<PathBuf>,
+  state: Arc<Mutex<VcrState>>,
+}
+
+impl Vcr {
+  /// Create a recorder for `path`.
+  ///
+  /// In record mode the cassette starts empty and is rewritten after every request.
+  /// In replay mode the cassette is loaded immediately.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error in replay mode if the cassette cannot be loaded.
+  pub fn new(mode: VcrMode, path: impl Into<PathBuf>) -> Result<Self, VcrError> {
+    let path = path.into();
+    let pending = match mode {
+      VcrMode::Record => VecDeque::new(),
+      VcrMode::Replay => Cassette::load(&path)?.interactions.into(),
+    };
+    Ok(Self {
+      mode,
+      path: Arc::new(path),
+      state: Arc::new(Mutex::new(VcrState {
+        recorded: Vec::new(),
+        pending,
+      })),
+    })
+  }
+
+  /// Create a recorder for `path` using the mode from [`VCR_MODE_ENV`].
+  ///
+  /// # Errors
+  ///
+  /// Returns an error in replay mode if the cassette cannot be loaded.
+  pub fn from_env(path: impl Into<PathBuf>) -> Result<Self, VcrError> {
+    Self::new(VcrMode::from_env(), path)
+  }
+
+  /// Returns `tests/cassettes/<name>.json` under the package being tested.
+  ///
+  /// Resolved from the `CARGO_MANIFEST_DIR` variable that `cargo test` sets at runtime,
+  /// falling back to the current directory.
+  #[must_use]
+  pub fn cassette_path(name: &str) -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+      .map_or_else(PathBuf::new, PathBuf::from)
+      .join("tests")
+      .join("cassettes")
+      .join(format!("{name}.json"))
+  }
+
+  /// Returns the active mode.
+  #[must_use]
+  pub fn mode(&self) -> VcrMode {
+    self.mode
+  }
+
+  /// Returns the cassette file path.
+  #[must_use]
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Send `request`, either through `client` (recording the result) or from the cassette.
+  ///
+  /// Replayed requests match the first unused interaction with the same method, URL,
+  /// and body, so repeated calls replay in recorded order.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the request fails, the cassette cannot be written, or no
+  /// recorded interaction matches in replay mode.
+  pub async fn execute(
+    &self,
+    client: &reqwest::Client,
+    request: reqwest::Request,
+  ) -> Result<reqwest::Response, VcrError> {
+    let recorded_request = RecordedRequest {
+      method: request.method().to_string(),
+      url: request.url().to_string(),
+      body: request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .map(RecordedBody::from_bytes),
+    };
+
+    match self.mode {
+      VcrMode::Replay => {
+        let response = self.take_match(&recorded_request)?;
+        Self::rebuild(response)
+      }
+      VcrMode::Record => {
+        let response = client.execute(request).await?;
+        let recorded_response = RecordedResponse {
+          status: response.status().as_u16(),
+          headers: response
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+            .collect(),
+          body: RecordedBody::from_bytes(&response.bytes().await?),
+        };
+        self.append(Interaction {
+          request: recorded_request,
+          response: recorded_response.clone(),
+        })?;
+        Self::rebuild(recorded_response)
+      }
+    }
+  }
+
+  fn take_match(&self, request: &RecordedRequest) -> Result<RecordedResponse, VcrError> {
+    let mut state = self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let index = state
+      .pending
+      .iter()
+      .position(|interaction| &interaction.request == request)
+      .ok_or_else(|| VcrError::NoMatch {
+        method: request.method.clone(),
+        url: request.url.clone(),
+        path: self.path.to_path_buf(),
+      })?;
+    let interaction = state.pending.remove(index).expect("index from position");
+    Ok(interaction.response)
+  }
+
+  fn append(&self, interaction: Interaction) -> Result<(), VcrError> {
+    let mut state = self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    state.recorded.push(interaction);
+    Cassette {
+      interactions: state.recorded.clone(),
+    }
+    .save(&self.path)
+  }
+
+  fn rebuild(recorded: RecordedResponse) -> Result<reqwest::Response, VcrError> {
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+      builder = builder.header(name, value);
+    }
+    Ok(reqwest::Response::from(builder.body(recorded.body.into_bytes())?))
+  }
+}
+
+mod base64_bytes {
+  use serde::{Deserializer, Serializer};
+  use serde_with::{
+    DeserializeAs, SerializeAs,
+    base64::{Base64, Standard},
+    formats::Padded,
+  };
+
+  pub(super) fn serialize<S: Serializer>(bytes: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+    Base64::<Standard, Padded>::serialize_as(bytes, serializer)
+  }
+
+  pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    Base64::<Standard, Padded>::deserialize_as(deserializer)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn interaction(url: &str, body: &str) -> Interaction {
+    Interaction {
+      request: RecordedRequest {
+        method: "GET".to_string(),
+        url: url.to_string(),
+        body: None,
+      },
+      response: RecordedResponse {
+        status: 200,
+        headers: vec![("content-type".to_string(), "application/json".to_string())],
+        body: RecordedBody::Text(body.to_string()),
+      },
+    }
+  }
+
+  #[tokio::test]
+  async fn test_replay_serves_interactions_in_recorded_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pets.json");
+    Cassette {
+      interactions: vec![
+        interaction("http://api.test/pets", "[1]"),
+        interaction("http://api.test/pets/1", r#"{"id":1}"#),
+        interaction("http://api.test/pets", "[1,2]"),
+      ],
+    }
+    .save(&path)
+    .unwrap();
+
+    let vcr = Vcr::new(VcrMode::Replay, &path).unwrap();
+    let client = reqwest::Client::new();
+    let mut bodies = vec![];
+    for url in ["http://api.test/pets", "http://api.test/pets", "http://api.test/pets/1"] {
+      let request = client.get(url).build().unwrap();
+      let response = vcr.execute(&client, request).await.unwrap();
+      assert_eq!(response.status(), http::StatusCode::OK);
+      assert_eq!(response.headers()[http::header::CONTENT_TYPE], "application/json");
+      bodies.push(response.text().await.unwrap());
+    }
+    assert_eq!(bodies, ["[1]", "[1,2]", r#"{"id":1}"#]);
+
+    let request = client.get("http://api.test/pets").build().unwrap();
+    let err = vcr.execute(&client, request).await.unwrap_err();
+    assert!(matches!(err, VcrError::NoMatch { .. }), "{err}");
+  }
+
+  #[test]
+  fn test_replay_requires_existing_cassette() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = Vcr::new(VcrMode::Replay, dir.path().join("missing.json")).unwrap_err();
+    assert!(matches!(err, VcrError::Io { .. }), "{err}");
+  }
+
+  #[test]
+  fn test_cassette_bodies_round_trip() {
+    let cases = [
+      (b"{\"id\":1}".as_slice(), r#"{"text":"{\"id\":1}"}"#),
+      (&[0xff, 0x00], r#"{"base64":"/wA="}"#),
+    ];
+
+    for (bytes, json) in cases {
+      let body = RecordedBody::from_bytes(bytes);
+      assert_eq!(serde_json::to_string(&body).unwrap(), json);
+      let decoded = serde_json::from_str::<RecordedBody>(json).unwrap();
+      assert_eq!(decoded.into_bytes().as_ref(), bytes);
+    }
+  }
+}
@@ -4,16 +4,35 @@
 //! Event Stream API
 //! Source: crates/oas3-gen/fixtures/event_stream.json
 //! Version: 1.0.0
+//! Spec hash: 79e9bba8522733e6a107654ab49b115c24d6934568af6dae12bbf319e65e858f
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! API with Server-Sent Events (SSE) endpoint
 
+use super::types::*;
 use anyhow::Context;
 use reqwest::{Client, Url};
 use validator::Validate;
-
-use super::types::*;
 pub const BASE_URL: &str = "https://api.example.com";
+/// Every operation this client calls, as declared in the spec.
+pub const OPERATIONS: &[oas3_gen_support::OperationMetadata] = &[
+  oas3_gen_support::OperationMetadata {
+    operation_id: "streamEvents",
+    method: "GET",
+    path: "/events",
+    tags: &[],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "streamTypedEvents",
+    method: "GET",
+    path: "/events/typed",
+    tags: &[],
+    external_docs: None,
+    extensions: &[],
+  },
+];
 #[derive(Debug, Clone)]
 pub struct EventStreamApiClient {
   pub client: Client,
@@ -15,6 +15,7 @@
 //! Event Stream API
 //! Source: crates/oas3-gen/fixtures/event_stream.json
 //! Version: 1.0.0
+//! Spec hash: 79e9bba8522733e6a107654ab49b115c24d6934568af6dae12bbf319e65e858f
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! API with Server-Sent Events (SSE) endpoint
@@ -4,6 +4,7 @@
 //! Event Stream API
 //! Source: crates/oas3-gen/fixtures/event_stream.json
 //! Version: 1.0.0
+//! Spec hash: 79e9bba8522733e6a107654ab49b115c24d6934568af6dae12bbf319e65e858f
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! API with Server-Sent Events (SSE) endpoint
@@ -117,3 +118,17 @@ impl core::fmt::Display for TypedEventType {
     }
   }
 }
+impl core::str::FromStr for TypedEventType {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "created" => Ok(Self::Created),
+      "updated" => Ok(Self::Updated),
+      "deleted" => Ok(Self::Deleted),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "created, updated, deleted"
+      )),
+    }
+  }
+}
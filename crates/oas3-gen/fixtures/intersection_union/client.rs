@@ -4,13 +4,15 @@
 //! Intersection Union Test
 //! Source: crates/oas3-gen/fixtures/intersection_union.json
 //! Version: 1.0.0
+//! Spec hash: a70eadd88db38200d41641bff1cb811c1c11321b54f79cf5002f9d4d85a24e38
 //! Generated by `oas3-gen v0.26.3`
 //!
 
 use anyhow::Context;
 use reqwest::{Client, Url};
-
 pub const BASE_URL: &str = "https://example.com/";
+/// Every operation this client calls, as declared in the spec.
+pub const OPERATIONS: &[oas3_gen_support::OperationMetadata] = &[];
 #[derive(Debug, Clone)]
 pub struct IntersectionUnionTestClient {
   pub client: Client,
@@ -4,6 +4,7 @@
 //! Intersection Union Test
 //! Source: crates/oas3-gen/fixtures/intersection_union.json
 //! Version: 1.0.0
+//! Spec hash: a70eadd88db38200d41641bff1cb811c1c11321b54f79cf5002f9d4d85a24e38
 //! Generated by `oas3-gen v0.26.3`
 //!
 
@@ -14,6 +15,7 @@ use serde::{Deserialize, Serialize};
   Debug, Clone, PartialEq, Serialize, Deserialize, validator::Validate, oas3_gen_support::Default, bon::Builder,
 )]
 pub struct Vehicle {
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub id: String,
   pub wheels: Option<i64>,
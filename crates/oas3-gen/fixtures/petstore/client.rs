@@ -4,19 +4,130 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 7960cd7d94544fe2c4c59e136b9823e4f431748042e544a9c1bb764da0be273a
 //! Generated by `oas3-gen v0.26.3`
 //!
 
+use super::types::*;
 use anyhow::Context;
 use reqwest::{Client, Url};
 use validator::Validate;
-
-use super::types::*;
 pub const BASE_URL: &str = "http://petstore.swagger.io/v1";
+/// Every operation this client calls, as declared in the spec.
+pub const OPERATIONS: &[oas3_gen_support::OperationMetadata] = &[
+  oas3_gen_support::OperationMetadata {
+    operation_id: "listPets",
+    method: "GET",
+    path: "/{api_version}/pets",
+    tags: &["pets"],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "createPets",
+    method: "POST",
+    path: "/{api_version}/pets",
+    tags: &["pets"],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "listCats",
+    method: "GET",
+    path: "/cats",
+    tags: &["pets"],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "showPetById",
+    method: "GET",
+    path: "/pets/{petId}",
+    tags: &["pets"],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "uploadPetImage",
+    method: "POST",
+    path: "/pets/{petId}/upload",
+    tags: &["pets"],
+    external_docs: None,
+    extensions: &[],
+  },
+];
 #[derive(Debug, Clone)]
 pub struct SwaggerPetstoreClient {
   pub client: Client,
   pub base_url: Url,
+  pub auth: Option<SwaggerPetstoreClientAuth>,
+}
+/// Credentials for the API's security schemes.
+///
+/// Each operation applies the first of its security requirements whose schemes
+/// all have credentials set, and is sent without credentials otherwise.
+#[derive(Clone, Default)]
+pub struct SwaggerPetstoreClientAuth {
+  /// API key sent in the `X-API-Key` header.
+  ///
+  /// Key issued from the developer portal.
+  pub api_key: Option<String>,
+  /// OAuth2 client credentials exchanged for a bearer token at `https://auth.petstore.swagger.io/oauth/token`.
+  pub petstore_auth: Option<oas3_gen_support::ClientCredentials>,
+}
+impl std::fmt::Debug for SwaggerPetstoreClientAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SwaggerPetstoreClientAuth")
+      .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+      .field("petstore_auth", &self.petstore_auth.as_ref().map(|_| "<redacted>"))
+      .finish()
+  }
+}
+impl SwaggerPetstoreClientAuth {
+  #[must_use]
+  pub fn with_api_key(mut self, value: impl Into<String>) -> Self {
+    self.api_key = Some(value.into());
+    self
+  }
+  #[must_use]
+  pub fn with_petstore_auth(mut self, client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+    self.petstore_auth = Some(oas3_gen_support::ClientCredentials::new(
+      "https://auth.petstore.swagger.io/oauth/token",
+      client_id,
+      client_secret,
+    ));
+    self
+  }
+  fn has(&self, scheme: &str) -> bool {
+    match scheme {
+      "api_key" => self.api_key.is_some(),
+      "petstore_auth" => self.petstore_auth.is_some(),
+      _ => false,
+    }
+  }
+  async fn apply(
+    &self,
+    client: &Client,
+    request: reqwest::RequestBuilder,
+    scheme: &str,
+  ) -> anyhow::Result<reqwest::RequestBuilder> {
+    Ok(match scheme {
+      "api_key" => match &self.api_key {
+        Some(credential) => request.header("X-API-Key", credential),
+        None => request,
+      },
+      "petstore_auth" => match &self.petstore_auth {
+        Some(credential) => request.bearer_auth(
+          credential
+            .access_token(client)
+            .await
+            .context("fetching access token for `petstore_auth`")?,
+        ),
+        None => request,
+      },
+      _ => request,
+    })
+  }
 }
 impl Default for SwaggerPetstoreClient {
   fn default() -> Self {
@@ -31,6 +142,7 @@ impl SwaggerPetstoreClient {
     Self {
       client: Client::builder().build().expect("client"),
       base_url: Url::parse(BASE_URL).expect("valid base url"),
+      auth: None,
     }
   }
   /// Create a client with a custom base URL.
@@ -38,16 +150,48 @@ impl SwaggerPetstoreClient {
     Ok(Self {
       client: Client::builder().build().context("building reqwest client")?,
       base_url: Url::parse(base_url.as_ref()).context("parsing base url")?,
+      auth: None,
     })
   }
   /// Create a client from an existing `reqwest::Client`.
   pub fn with_client(base_url: impl AsRef<str>, client: Client) -> anyhow::Result<Self> {
     let url = Url::parse(base_url.as_ref()).context("parsing base url")?;
-    Ok(Self { client, base_url: url })
+    Ok(Self {
+      client,
+      base_url: url,
+      auth: None,
+    })
+  }
+  /// Attach credentials for the API's security schemes.
+  #[must_use]
+  pub fn with_auth(mut self, auth: SwaggerPetstoreClientAuth) -> Self {
+    self.auth = Some(auth);
+    self
+  }
+  async fn authorize(
+    &self,
+    request: reqwest::RequestBuilder,
+    requirements: &[&[&str]],
+  ) -> anyhow::Result<reqwest::RequestBuilder> {
+    let Some(auth) = &self.auth else {
+      return Ok(request);
+    };
+    let Some(schemes) = requirements
+      .iter()
+      .find(|schemes| schemes.iter().all(|scheme| auth.has(scheme)))
+    else {
+      return Ok(request);
+    };
+    let mut request = request;
+    for scheme in *schemes {
+      request = auth.apply(&self.client, request, scheme).await?;
+    }
+    Ok(request)
   }
   /// List all pets
   ///
   /// * Path: `GET /{api_version}/pets`
+  /// * Tags: `pets`
   pub async fn list_pets(&self, request: ListPetsRequest) -> anyhow::Result<ListPetsResponse> {
     request.validate().context("parameter validation")?;
     let mut url = self.base_url.clone();
@@ -56,18 +200,19 @@ impl SwaggerPetstoreClient {
       .map_err(|()| anyhow::anyhow!("URL cannot be a base"))?
       .push(&request.path.api_version.clone())
       .push("pets");
-    let response = self
+    let req_builder = self
       .client
       .get(url)
       .query(&request.query)
-      .headers(http::HeaderMap::try_from(&request.header).context("building request headers")?)
-      .send()
-      .await?;
+      .headers(http::HeaderMap::try_from(&request.header).context("building request headers")?);
+    let req_builder = self.authorize(req_builder, &[&["api_key"]]).await?;
+    let response = req_builder.send().await?;
     ListPetsRequest::parse_response(response).await
   }
   /// Create a pet
   ///
   /// * Path: `POST /{api_version}/pets`
+  /// * Tags: `pets`
   pub async fn create_pets(&self, request: CreatePetsRequest) -> anyhow::Result<CreatePetsResponse> {
     request.validate().context("parameter validation")?;
     let mut url = self.base_url.clone();
@@ -76,12 +221,15 @@ impl SwaggerPetstoreClient {
       .map_err(|()| anyhow::anyhow!("URL cannot be a base"))?
       .push(&request.path.api_version.clone())
       .push("pets");
-    let response = self.client.post(url).send().await?;
+    let req_builder = self.client.post(url);
+    let req_builder = self.authorize(req_builder, &[&["api_key"]]).await?;
+    let response = req_builder.send().await?;
     CreatePetsRequest::parse_response(response).await
   }
   /// List all cats
   ///
   /// * Path: `GET /cats`
+  /// * Tags: `pets`
   pub async fn list_cats(&self, request: ListCatsRequest) -> anyhow::Result<ListCatsResponse> {
     request.validate().context("parameter validation")?;
     let mut url = self.base_url.clone();
@@ -101,6 +249,7 @@ impl SwaggerPetstoreClient {
   /// Info for a specific pet
   ///
   /// * Path: `GET /pets/{petId}`
+  /// * Tags: `pets`
   pub async fn show_pet_by_id(&self, request: ShowPetByIdRequest) -> anyhow::Result<ShowPetByIdResponse> {
     request.validate().context("parameter validation")?;
     let mut url = self.base_url.clone();
@@ -109,17 +258,18 @@ impl SwaggerPetstoreClient {
       .map_err(|()| anyhow::anyhow!("URL cannot be a base"))?
       .push("pets")
       .push(&request.path.pet_id.clone());
-    let response = self
+    let req_builder = self
       .client
       .get(url)
-      .headers(http::HeaderMap::try_from(&request.header).context("building request headers")?)
-      .send()
-      .await?;
+      .headers(http::HeaderMap::try_from(&request.header).context("building request headers")?);
+    let req_builder = self.authorize(req_builder, &[&["petstore_auth"], &["api_key"]]).await?;
+    let response = req_builder.send().await?;
     ShowPetByIdRequest::parse_response(response).await
   }
   /// Upload pet image and update name
   ///
   /// * Path: `POST /pets/{petId}/upload`
+  /// * Tags: `pets`
   pub async fn upload_pet_image(&self, request: UploadPetImageRequest) -> anyhow::Result<ShowPetByIdResponse> {
     request.validate().context("parameter validation")?;
     let mut url = self.base_url.clone();
@@ -133,11 +283,9 @@ impl SwaggerPetstoreClient {
     let body = &request.body;
     let mut form = reqwest::multipart::Form::new();
     form = form.part("name", reqwest::multipart::Part::text(body.name.clone()));
-    form = form.part(
-      "image",
-      reqwest::multipart::Part::bytes(std::borrow::Cow::from(body.image.clone())),
-    );
+    form = form.part("image", body.image.to_part()?);
     req_builder = req_builder.multipart(form);
+    let req_builder = self.authorize(req_builder, &[&["api_key"]]).await?;
     let response = req_builder.send().await?;
     UploadPetImageRequest::parse_response(response).await
   }
@@ -15,6 +15,7 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 7960cd7d94544fe2c4c59e136b9823e4f431748042e544a9c1bb764da0be273a
 //! Generated by `oas3-gen v0.26.3`
 //!
 
@@ -4,6 +4,7 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 7960cd7d94544fe2c4c59e136b9823e4f431748042e544a9c1bb764da0be273a
 //! Generated by `oas3-gen v0.26.3`
 //!
 
@@ -14,10 +15,14 @@ pub const X_ONLY: http::HeaderName = http::HeaderName::from_static("x-only");
 pub const X_COMPATIBILITY_DATE: http::HeaderName = http::HeaderName::from_static("x-compatibility-date");
 pub const X_API_VERSION: http::HeaderName = http::HeaderName::from_static("x-api-version");
 pub const X_API_KEY: http::HeaderName = http::HeaderName::from_static("x-api-key");
+pub mod media_types {
+  pub const APPLICATION_JSON: &str = "application/json";
+}
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
 #[serde(default)]
 pub struct Allergies {
   #[doc(hidden)]
+  /// - Constraints: default `allergies`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("allergies"))]
   #[builder(skip = Some("allergies"))]
@@ -27,6 +32,7 @@ pub struct Allergies {
 #[serde(default)]
 pub struct Diet {
   #[doc(hidden)]
+  /// - Constraints: default `diet`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("diet"))]
   #[builder(skip = Some("diet"))]
@@ -80,12 +86,7 @@ pub struct VaccineRecord {
   pub date_administered: chrono::NaiveDate,
   pub veterinarian: Option<String>,
 }
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, oas3_gen_support::Default, bon::Builder)]
-pub struct PetVaccinations {
-  /// Additional properties not defined in the schema.
-  #[serde(flatten)]
-  pub additional_properties: indexmap::IndexMap<String, Vec<VaccineRecord>>,
-}
+pub type PetVaccinations = indexmap::IndexMap<String, Vec<VaccineRecord>>;
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
 pub struct Pet {
   pub id: i64,
@@ -105,6 +106,17 @@ pub struct Cat {
   #[serde(rename = "favoriteToy")]
   pub favorite_toy: Option<String>,
 }
+impl From<&Cat> for Pet {
+  fn from(value: &Cat) -> Self {
+    Self {
+      id: value.id,
+      name: value.name.clone(),
+      allergies: value.allergies.clone(),
+      vaccinations: value.vaccinations.clone(),
+      tag: value.tag.clone(),
+    }
+  }
+}
 pub type Pets = Vec<Pet>;
 pub type Cats = Vec<Cat>;
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
@@ -112,9 +124,16 @@ pub struct Error {
   pub code: i32,
   pub message: String,
 }
+impl core::fmt::Display for Error {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+impl std::error::Error for Error {}
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct ListPetsRequestPath {
   /// Which version of the API to use.
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub api_version: String,
 }
@@ -122,6 +141,7 @@ pub struct ListPetsRequestPath {
 #[derive(Debug, Clone, PartialEq, Serialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ListPetsRequestQuery {
   /// How many items to return at one time (max 100)
+  /// - Constraints: 1–100
   #[validate(range(min = 1i32, max = 100i32))]
   pub limit: Option<i32>,
 }
@@ -130,8 +150,9 @@ pub struct ListPetsRequestHeader {
   /// Sort order for the results
   pub x_sort_order: Option<ListPetsRequestHeaderXSortOrder>,
   /// Only include pets with a tag
-  pub x_only: Option<Vec<ListPetsRequestHeaderXonly>>,
+  pub x_only: Option<Vec<ListPetsRequestHeaderXOnly>>,
   /// API compatibility date
+  /// - Constraints: default `2026-06-09`
   #[default(Default::default())]
   pub x_compatibility_date: chrono::NaiveDate,
 }
@@ -181,7 +202,7 @@ impl ListPetsRequest {
     api_version: String,
     limit: Option<i32>,
     x_sort_order: Option<ListPetsRequestHeaderXSortOrder>,
-    x_only: Option<Vec<ListPetsRequestHeaderXonly>>,
+    x_only: Option<Vec<ListPetsRequestHeaderXOnly>>,
     x_compatibility_date: chrono::NaiveDate,
   ) -> anyhow::Result<Self> {
     let request = Self {
@@ -206,7 +227,7 @@ impl ListPetsRequest {
         .headers()
         .get(reqwest::header::CONTENT_TYPE)
         .and_then(|v| v.to_str().ok())
-        .unwrap_or("application/json");
+        .unwrap_or(media_types::APPLICATION_JSON);
       if content_type_str.contains("json") {
         let data = oas3_gen_support::Diagnostics::<Pets>::json_with_diagnostics(req).await?;
         return Ok(ListPetsResponse::Ok(data));
@@ -216,8 +237,9 @@ impl ListPetsRequest {
         return Ok(ListPetsResponse::Ok(data));
       }
     }
-    let data = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
-    Ok(ListPetsResponse::Unknown(data))
+    let status = req.status();
+    let body = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
+    Ok(ListPetsResponse::Unknown { status, body })
   }
 }
 /// Response types for listPets
@@ -226,11 +248,12 @@ pub enum ListPetsResponse {
   ///200: A paged array of pets
   Ok(Pets),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct CreatePetsRequestPath {
   /// Which version of the API to use.
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub api_version: String,
 }
@@ -260,8 +283,9 @@ impl CreatePetsRequest {
       let _ = req.bytes().await?;
       return Ok(CreatePetsResponse::Created);
     }
-    let data = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
-    Ok(CreatePetsResponse::Unknown(data))
+    let status = req.status();
+    let body = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
+    Ok(CreatePetsResponse::Unknown { status, body })
   }
 }
 /// Response types for createPets
@@ -270,12 +294,13 @@ pub enum CreatePetsResponse {
   ///201: Null response
   Created,
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ListCatsRequestQuery {
   /// How many items to return at one time (max 100)
+  /// - Constraints: 1–100
   #[validate(range(min = 1i32, max = 100i32))]
   pub limit: Option<i32>,
 }
@@ -329,8 +354,9 @@ impl ListCatsRequest {
       let data = oas3_gen_support::Diagnostics::<Cats>::json_with_diagnostics(req).await?;
       return Ok(ListCatsResponse::Ok(data));
     }
-    let data = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
-    Ok(ListCatsResponse::Unknown(data))
+    let status = req.status();
+    let body = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
+    Ok(ListCatsResponse::Unknown { status, body })
   }
 }
 /// Response types for listCats
@@ -339,17 +365,19 @@ pub enum ListCatsResponse {
   ///200: A paged array of cats
   Ok(Cats),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct ShowPetByIdRequestPath {
   /// The id of the pet to retrieve
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub pet_id: String,
 }
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct ShowPetByIdRequestHeader {
   /// API version to use for this request
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub x_api_version: String,
 }
@@ -397,8 +425,9 @@ impl ShowPetByIdRequest {
       let data = oas3_gen_support::Diagnostics::<Pet>::json_with_diagnostics(req).await?;
       return Ok(ShowPetByIdResponse::Ok(data));
     }
-    let data = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
-    Ok(ShowPetByIdResponse::Unknown(data))
+    let status = req.status();
+    let body = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
+    Ok(ShowPetByIdResponse::Unknown { status, body })
   }
 }
 /// Response types for showPetById
@@ -407,19 +436,21 @@ pub enum ShowPetByIdResponse {
   ///200: Expected response to a valid request
   Ok(Pet),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 #[derive(Debug, Clone, PartialEq, Serialize, validator::Validate, oas3_gen_support::Default, bon::Builder)]
 pub struct UploadRequestBody {
   /// The pet's name
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub name: String,
   /// The pet's image file
-  pub image: Vec<u8>,
+  pub image: oas3_gen_support::FilePart,
 }
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct UploadPetImageRequestPath {
   /// The id of the pet to update
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub pet_id: String,
 }
@@ -452,8 +483,9 @@ impl UploadPetImageRequest {
       let data = oas3_gen_support::Diagnostics::<Pet>::json_with_diagnostics(req).await?;
       return Ok(ShowPetByIdResponse::Ok(data));
     }
-    let data = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
-    Ok(ShowPetByIdResponse::Unknown(data))
+    let status = req.status();
+    let body = oas3_gen_support::Diagnostics::<Error>::json_with_diagnostics(req).await?;
+    Ok(ShowPetByIdResponse::Unknown { status, body })
   }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, oas3_gen_support::Default)]
@@ -472,8 +504,18 @@ impl core::fmt::Display for ListPetsRequestHeaderXSortOrder {
     }
   }
 }
+impl core::str::FromStr for ListPetsRequestHeaderXSortOrder {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "asc" => Ok(Self::Asc),
+      "desc" => Ok(Self::Desc),
+      _ => Err(format!("unknown variant '{}', expected one of: {}", s, "asc, desc")),
+    }
+  }
+}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, oas3_gen_support::Default)]
-pub enum ListPetsRequestHeaderXonly {
+pub enum ListPetsRequestHeaderXOnly {
   #[serde(rename = "cat")]
   #[default]
   Cat,
@@ -484,7 +526,7 @@ pub enum ListPetsRequestHeaderXonly {
   #[serde(rename = "bird")]
   Bird,
 }
-impl core::fmt::Display for ListPetsRequestHeaderXonly {
+impl core::fmt::Display for ListPetsRequestHeaderXOnly {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     match self {
       Self::Cat => write!(f, "cat"),
@@ -494,3 +536,18 @@ impl core::fmt::Display for ListPetsRequestHeaderXonly {
     }
   }
 }
+impl core::str::FromStr for ListPetsRequestHeaderXOnly {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "cat" => Ok(Self::Cat),
+      "dog" => Ok(Self::Dog),
+      "fish" => Ok(Self::Fish),
+      "bird" => Ok(Self::Bird),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "cat, dog, fish, bird"
+      )),
+    }
+  }
+}
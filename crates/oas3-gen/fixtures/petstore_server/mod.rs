@@ -15,6 +15,7 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 621aba2da90d0ebe8d611b2c0219a7fb882f07c95c7b018b51947d4baf84ae17
 //! Generated by `oas3-gen v0.26.3`
 //!
 
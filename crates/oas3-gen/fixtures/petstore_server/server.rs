@@ -4,9 +4,11 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 621aba2da90d0ebe8d611b2c0219a7fb882f07c95c7b018b51947d4baf84ae17
 //! Generated by `oas3-gen v0.26.3`
 //!
 
+use super::types::*;
 use axum::{
   Router,
   extract::{Path, Query, State},
@@ -14,12 +16,11 @@ use axum::{
   response::IntoResponse,
   routing::{get, post},
 };
-
-use super::types::*;
 pub trait ApiServer: Send + Sync {
   /// List all pets
   ///
   /// * Path: `GET /{api_version}/pets`
+  /// * Tags: `pets`
   fn list_pets(
     &self,
     request: ListPetsRequest,
@@ -27,6 +28,7 @@ pub trait ApiServer: Send + Sync {
   /// Create a pet
   ///
   /// * Path: `POST /{api_version}/pets`
+  /// * Tags: `pets`
   fn create_pets(
     &self,
     request: CreatePetsRequest,
@@ -34,6 +36,7 @@ pub trait ApiServer: Send + Sync {
   /// List all cats
   ///
   /// * Path: `GET /cats`
+  /// * Tags: `pets`
   fn list_cats(
     &self,
     request: ListCatsRequest,
@@ -41,6 +44,7 @@ pub trait ApiServer: Send + Sync {
   /// Info for a specific pet
   ///
   /// * Path: `GET /pets/{petId}`
+  /// * Tags: `pets`
   fn show_pet_by_id(
     &self,
     request: ShowPetByIdRequest,
@@ -48,6 +52,7 @@ pub trait ApiServer: Send + Sync {
   /// Upload pet image and update name
   ///
   /// * Path: `POST /pets/{petId}/upload`
+  /// * Tags: `pets`
   fn upload_pet_image(
     &self,
     request: UploadPetImageRequest,
@@ -4,6 +4,7 @@
 //! Swagger Petstore
 //! Source: crates/oas3-gen/fixtures/petstore.json
 //! Version: 1.0.0
+//! Spec hash: 621aba2da90d0ebe8d611b2c0219a7fb882f07c95c7b018b51947d4baf84ae17
 //! Generated by `oas3-gen v0.26.3`
 //!
 
@@ -20,6 +21,7 @@ pub const X_API_KEY: http::HeaderName = http::HeaderName::from_static("x-api-key
 #[serde(default)]
 pub struct Allergies {
   #[doc(hidden)]
+  /// - Constraints: default `allergies`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("allergies"))]
   #[builder(skip = Some("allergies"))]
@@ -30,6 +32,7 @@ pub struct Allergies {
 #[serde(default)]
 pub struct Diet {
   #[doc(hidden)]
+  /// - Constraints: default `diet`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("diet"))]
   #[builder(skip = Some("diet"))]
@@ -73,12 +76,7 @@ pub struct VaccineRecord {
   pub date_administered: chrono::NaiveDate,
   pub veterinarian: Option<String>,
 }
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, oas3_gen_support::Default, bon::Builder)]
-pub struct PetVaccinations {
-  /// Additional properties not defined in the schema.
-  #[serde(flatten)]
-  pub additional_properties: indexmap::IndexMap<String, Vec<VaccineRecord>>,
-}
+pub type PetVaccinations = indexmap::IndexMap<String, Vec<VaccineRecord>>;
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize, oas3_gen_support::Default, bon::Builder)]
 pub struct Pet {
@@ -100,6 +98,17 @@ pub struct Cat {
   #[serde(rename = "favoriteToy")]
   pub favorite_toy: Option<String>,
 }
+impl From<&Cat> for Pet {
+  fn from(value: &Cat) -> Self {
+    Self {
+      id: value.id,
+      name: value.name.clone(),
+      allergies: value.allergies.clone(),
+      vaccinations: value.vaccinations.clone(),
+      tag: value.tag.clone(),
+    }
+  }
+}
 pub type Pets = Vec<Pet>;
 pub type Cats = Vec<Cat>;
 #[derive(Debug, Clone, PartialEq, Serialize, oas3_gen_support::Default, bon::Builder)]
@@ -107,15 +116,23 @@ pub struct Error {
   pub code: i32,
   pub message: String,
 }
+impl core::fmt::Display for Error {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+impl std::error::Error for Error {}
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ListPetsRequestPath {
   /// Which version of the API to use.
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub api_version: String,
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ListPetsRequestQuery {
   /// How many items to return at one time (max 100)
+  /// - Constraints: 1–100
   #[validate(range(min = 1i32, max = 100i32))]
   pub limit: Option<i32>,
 }
@@ -124,8 +141,9 @@ pub struct ListPetsRequestHeader {
   /// Sort order for the results
   pub x_sort_order: Option<ListPetsRequestHeaderXSortOrder>,
   /// Only include pets with a tag
-  pub x_only: Option<Vec<ListPetsRequestHeaderXonly>>,
+  pub x_only: Option<Vec<ListPetsRequestHeaderXOnly>>,
   /// API compatibility date
+  /// - Constraints: default `2026-06-09`
   #[default(Default::default())]
   pub x_compatibility_date: chrono::NaiveDate,
 }
@@ -201,7 +219,7 @@ impl ListPetsRequest {
     api_version: String,
     limit: Option<i32>,
     x_sort_order: Option<ListPetsRequestHeaderXSortOrder>,
-    x_only: Option<Vec<ListPetsRequestHeaderXonly>>,
+    x_only: Option<Vec<ListPetsRequestHeaderXOnly>>,
     x_compatibility_date: chrono::NaiveDate,
   ) -> anyhow::Result<Self> {
     let request = Self {
@@ -224,19 +242,20 @@ pub enum ListPetsResponse {
   ///200: A paged array of pets
   Ok(Pets),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 impl IntoResponse for ListPetsResponse {
   fn into_response(self) -> axum::response::Response {
     match self {
       Self::Ok(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
-      Self::Unknown(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
+      Self::Unknown { status, body } => (status, axum::Json(body)).into_response(),
     }
   }
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct CreatePetsRequestPath {
   /// Which version of the API to use.
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub api_version: String,
 }
@@ -265,19 +284,20 @@ pub enum CreatePetsResponse {
   ///201: Null response
   Created,
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 impl IntoResponse for CreatePetsResponse {
   fn into_response(self) -> axum::response::Response {
     match self {
       Self::Created => http::StatusCode::CREATED.into_response(),
-      Self::Unknown(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
+      Self::Unknown { status, body } => (status, axum::Json(body)).into_response(),
     }
   }
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ListCatsRequestQuery {
   /// How many items to return at one time (max 100)
+  /// - Constraints: 1–100
   #[validate(range(min = 1i32, max = 100i32))]
   pub limit: Option<i32>,
 }
@@ -347,25 +367,27 @@ pub enum ListCatsResponse {
   ///200: A paged array of cats
   Ok(Cats),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 impl IntoResponse for ListCatsResponse {
   fn into_response(self) -> axum::response::Response {
     match self {
       Self::Ok(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
-      Self::Unknown(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
+      Self::Unknown { status, body } => (status, axum::Json(body)).into_response(),
     }
   }
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct ShowPetByIdRequestPath {
   /// The id of the pet to retrieve
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub pet_id: String,
 }
 #[derive(Debug, Clone, PartialEq, validator::Validate, oas3_gen_support::Default)]
 pub struct ShowPetByIdRequestHeader {
   /// API version to use for this request
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub x_api_version: String,
 }
@@ -430,27 +452,29 @@ pub enum ShowPetByIdResponse {
   ///200: Expected response to a valid request
   Ok(Pet),
   ///default: unexpected error
-  Unknown(Error),
+  Unknown { status: http::StatusCode, body: Error },
 }
 impl IntoResponse for ShowPetByIdResponse {
   fn into_response(self) -> axum::response::Response {
     match self {
       Self::Ok(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
-      Self::Unknown(data) => (http::StatusCode::OK, axum::Json(data)).into_response(),
+      Self::Unknown { status, body } => (status, axum::Json(body)).into_response(),
     }
   }
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default, bon::Builder)]
 pub struct UploadRequestBody {
   /// The pet's name
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub name: String,
   /// The pet's image file
-  pub image: Vec<u8>,
+  pub image: oas3_gen_support::FilePart,
 }
 #[derive(Debug, Clone, PartialEq, Deserialize, validator::Validate, oas3_gen_support::Default)]
 pub struct UploadPetImageRequestPath {
   /// The id of the pet to update
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub pet_id: String,
 }
@@ -503,7 +527,7 @@ impl core::str::FromStr for ListPetsRequestHeaderXSortOrder {
   }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, oas3_gen_support::Default)]
-pub enum ListPetsRequestHeaderXonly {
+pub enum ListPetsRequestHeaderXOnly {
   #[serde(rename = "cat")]
   #[default]
   Cat,
@@ -514,7 +538,7 @@ pub enum ListPetsRequestHeaderXonly {
   #[serde(rename = "bird")]
   Bird,
 }
-impl core::fmt::Display for ListPetsRequestHeaderXonly {
+impl core::fmt::Display for ListPetsRequestHeaderXOnly {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     match self {
       Self::Cat => write!(f, "cat"),
@@ -524,7 +548,7 @@ impl core::fmt::Display for ListPetsRequestHeaderXonly {
     }
   }
 }
-impl core::str::FromStr for ListPetsRequestHeaderXonly {
+impl core::str::FromStr for ListPetsRequestHeaderXOnly {
   type Err = String;
   fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
     match s {
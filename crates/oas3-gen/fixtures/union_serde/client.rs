@@ -4,16 +4,35 @@
 //! Union Serialization Test API
 //! Source: crates/oas3-gen/fixtures/union_serde.json
 //! Version: 1.0.0
+//! Spec hash: 745d2bbfdf479c85603a3c03553e1296ded598eb61f9563848b9bd0427ad2293
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! Comprehensive test fixture for union serialization/deserialization
 
+use super::types::*;
 use anyhow::Context;
 use reqwest::{Client, Url};
 use validator::Validate;
-
-use super::types::*;
 pub const BASE_URL: &str = "https://example.com/";
+/// Every operation this client calls, as declared in the spec.
+pub const OPERATIONS: &[oas3_gen_support::OperationMetadata] = &[
+  oas3_gen_support::OperationMetadata {
+    operation_id: "sendContent",
+    method: "POST",
+    path: "/content",
+    tags: &[],
+    external_docs: None,
+    extensions: &[],
+  },
+  oas3_gen_support::OperationMetadata {
+    operation_id: "getEvents",
+    method: "GET",
+    path: "/events",
+    tags: &[],
+    external_docs: None,
+    extensions: &[],
+  },
+];
 #[derive(Debug, Clone)]
 pub struct UnionSerializationTestApiClient {
   pub client: Client,
@@ -56,7 +75,13 @@ impl UnionSerializationTestApiClient {
       .path_segments_mut()
       .map_err(|()| anyhow::anyhow!("URL cannot be a base"))?
       .push("content");
-    let response = self.client.post(url).json(&request.body).send().await?;
+    let response = self
+      .client
+      .post(url)
+      .header(reqwest::header::CONTENT_TYPE, media_types::APPLICATION_JSON)
+      .body(oas3_gen_support::to_json_bytes(&request.body)?)
+      .send()
+      .await?;
     SendContentRequest::parse_response(response).await
   }
   /// Get events with discriminated union
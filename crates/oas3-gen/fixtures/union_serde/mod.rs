@@ -15,6 +15,7 @@
 //! Union Serialization Test API
 //! Source: crates/oas3-gen/fixtures/union_serde.json
 //! Version: 1.0.0
+//! Spec hash: 745d2bbfdf479c85603a3c03553e1296ded598eb61f9563848b9bd0427ad2293
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! Comprehensive test fixture for union serialization/deserialization
@@ -4,12 +4,16 @@
 //! Union Serialization Test API
 //! Source: crates/oas3-gen/fixtures/union_serde.json
 //! Version: 1.0.0
+//! Spec hash: 745d2bbfdf479c85603a3c03553e1296ded598eb61f9563848b9bd0427ad2293
 //! Generated by `oas3-gen v0.26.3`
 //!
 //! Comprehensive test fixture for union serialization/deserialization
 
 use serde::{Deserialize, Serialize};
 use validator::Validate;
+pub mod media_types {
+  pub const APPLICATION_JSON: &str = "application/json";
+}
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize, oas3_gen_support::Default, bon::Builder)]
 #[serde(default)]
@@ -18,6 +22,7 @@ pub struct ContentRequest {
   /// Flexible metadata that can be string or object
   pub metadata: Option<Metadata>,
   /// The size of the generated image. Total pixels must be between 1024x1024 and 2048x2048, with aspect ratio between 1/16 and 16.
+  /// - Constraints: default `auto_2K`
   #[default(Some(Default::default()))]
   pub image_size: Option<ContentRequestImageSize>,
 }
@@ -116,10 +121,12 @@ impl ContentBlock {
 #[serde(default)]
 pub struct TextBlock {
   #[doc(hidden)]
+  /// - Constraints: default `text`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("text"))]
   #[builder(skip = Some("text"))]
   pub r#type: Option<&'static str>,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub text: String,
   pub annotations: Option<Vec<Annotation>>,
@@ -130,6 +137,7 @@ pub struct TextBlock {
 #[serde(default)]
 pub struct ImageBlock {
   #[doc(hidden)]
+  /// - Constraints: default `image`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("image"))]
   #[builder(skip = Some("image"))]
@@ -145,10 +153,12 @@ pub struct ImageBlock {
 #[serde(default)]
 pub struct CodeBlock {
   #[doc(hidden)]
+  /// - Constraints: default `code`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("code"))]
   #[builder(skip = Some("code"))]
   pub r#type: Option<&'static str>,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub code: String,
   pub language: Option<String>,
@@ -160,12 +170,15 @@ pub struct CodeBlock {
 #[serde(default)]
 pub struct ToolUseBlock {
   #[doc(hidden)]
+  /// - Constraints: default `tool_use`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("tool_use"))]
   #[builder(skip = Some("tool_use"))]
   pub r#type: Option<&'static str>,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub id: String,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub name: String,
   pub input: indexmap::IndexMap<String, serde_json::Value>,
@@ -177,10 +190,12 @@ pub struct ToolUseBlock {
 #[serde(default)]
 pub struct ToolResultBlock {
   #[doc(hidden)]
+  /// - Constraints: default `tool_result`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("tool_result"))]
   #[builder(skip = Some("tool_result"))]
   pub r#type: Option<&'static str>,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   pub tool_use_id: String,
   /// Tool result can be text or array of content blocks
@@ -315,6 +330,7 @@ impl ImageSource {
 #[serde(default)]
 pub struct Base64ImageSource {
   #[doc(hidden)]
+  /// - Constraints: default `base64`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("base64"))]
   #[builder(skip = Some("base64"))]
@@ -330,10 +346,12 @@ pub struct Base64ImageSource {
 #[serde(default)]
 pub struct UrlImageSource {
   #[doc(hidden)]
+  /// - Constraints: default `url`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("url"))]
   #[builder(skip = Some("url"))]
   pub r#type: Option<&'static str>,
+  /// - Constraints: URL, at least 1 char
   #[validate(url, length(min = 1u64))]
   pub url: String,
 }
@@ -359,6 +377,21 @@ impl core::fmt::Display for MediaType {
     }
   }
 }
+impl core::str::FromStr for MediaType {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "image/jpeg" => Ok(Self::ImageJpeg),
+      "image/png" => Ok(Self::ImagePng),
+      "image/gif" => Ok(Self::ImageGif),
+      "image/webp" => Ok(Self::ImageWebp),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "image/jpeg, image/png, image/gif, image/webp"
+      )),
+    }
+  }
+}
 /// Text annotation with different types
 #[derive(Debug, Clone, PartialEq)]
 pub enum Annotation {
@@ -412,12 +445,14 @@ impl<'de> serde::Deserialize<'de> for Annotation {
 #[serde(default)]
 pub struct CitationAnnotation {
   #[doc(hidden)]
+  /// - Constraints: default `citation`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("citation"))]
   #[builder(skip = Some("citation"))]
   pub r#type: Option<&'static str>,
   pub start: i64,
   pub end: i64,
+  /// - Constraints: at least 1 char
   #[validate(length(min = 1u64))]
   #[builder(name = build_value)]
   pub build: String,
@@ -429,12 +464,14 @@ pub struct CitationAnnotation {
 #[serde(default)]
 pub struct LinkAnnotation {
   #[doc(hidden)]
+  /// - Constraints: default `link`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("link"))]
   #[builder(skip = Some("link"))]
   pub r#type: Option<&'static str>,
   pub start: i64,
   pub end: i64,
+  /// - Constraints: URL, at least 1 char
   #[validate(url, length(min = 1u64))]
   pub url: String,
 }
@@ -517,6 +554,7 @@ impl Event {
 #[serde(default)]
 pub struct MessageStartEvent {
   #[doc(hidden)]
+  /// - Constraints: default `message_start`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("message_start"))]
   #[builder(skip = Some("message_start"))]
@@ -527,6 +565,7 @@ pub struct MessageStartEvent {
 #[serde(default)]
 pub struct ContentBlockStartEvent {
   #[doc(hidden)]
+  /// - Constraints: default `content_block_start`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("content_block_start"))]
   #[builder(skip = Some("content_block_start"))]
@@ -539,6 +578,7 @@ pub struct ContentBlockStartEvent {
 #[serde(default)]
 pub struct ContentBlockDeltaEvent {
   #[doc(hidden)]
+  /// - Constraints: default `content_block_delta`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("content_block_delta"))]
   #[builder(skip = Some("content_block_delta"))]
@@ -551,6 +591,7 @@ pub struct ContentBlockDeltaEvent {
 #[serde(default)]
 pub struct ContentBlockStopEvent {
   #[doc(hidden)]
+  /// - Constraints: default `content_block_stop`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("content_block_stop"))]
   #[builder(skip = Some("content_block_stop"))]
@@ -561,6 +602,7 @@ pub struct ContentBlockStopEvent {
 #[serde(default)]
 pub struct MessageStopEvent {
   #[doc(hidden)]
+  /// - Constraints: default `message_stop`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("message_stop"))]
   #[builder(skip = Some("message_stop"))]
@@ -570,6 +612,7 @@ pub struct MessageStopEvent {
 #[serde(default)]
 pub struct PingEvent {
   #[doc(hidden)]
+  /// - Constraints: default `ping`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("ping"))]
   #[builder(skip = Some("ping"))]
@@ -628,6 +671,7 @@ impl Delta {
 #[serde(default)]
 pub struct TextDelta {
   #[doc(hidden)]
+  /// - Constraints: default `text_delta`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("text_delta"))]
   #[builder(skip = Some("text_delta"))]
@@ -638,6 +682,7 @@ pub struct TextDelta {
 #[serde(default)]
 pub struct InputJsonDelta {
   #[doc(hidden)]
+  /// - Constraints: default `input_json_delta`
   #[serde(default, rename = "type", skip_deserializing)]
   #[default(Some("input_json_delta"))]
   #[builder(skip = Some("input_json_delta"))]
@@ -669,6 +714,19 @@ impl core::fmt::Display for Role {
     }
   }
 }
+impl core::str::FromStr for Role {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "user" => Ok(Self::User),
+      "assistant" => Ok(Self::Assistant),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "user, assistant"
+      )),
+    }
+  }
+}
 /// Audio sample rate in Hz (integer-backed enum)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, oas3_gen_support::Default)]
 pub enum SampleRate {
@@ -690,6 +748,22 @@ impl core::fmt::Display for SampleRate {
     }
   }
 }
+impl core::str::FromStr for SampleRate {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "8000" => Ok(Self::Value8000),
+      "16000" => Ok(Self::Value16000),
+      "24000" => Ok(Self::Value24000),
+      "44100" => Ok(Self::Value44100),
+      "48000" => Ok(Self::Value48000),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "8000, 16000, 24000, 44100, 48000"
+      )),
+    }
+  }
+}
 impl serde::Serialize for SampleRate {
   fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
   where
@@ -743,6 +817,21 @@ impl core::fmt::Display for PlaybackRate {
     }
   }
 }
+impl core::str::FromStr for PlaybackRate {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "0.5" => Ok(Self::Value0_5),
+      "1" => Ok(Self::Value1),
+      "1.5" => Ok(Self::Value1_5),
+      "2" => Ok(Self::Value2),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "0.5, 1, 1.5, 2"
+      )),
+    }
+  }
+}
 impl serde::Serialize for PlaybackRate {
   fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
   where
@@ -797,6 +886,21 @@ impl core::fmt::Display for StopReason {
     }
   }
 }
+impl core::str::FromStr for StopReason {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "end_turn" => Ok(Self::EndTurn),
+      "max_tokens" => Ok(Self::MaxTokens),
+      "stop_sequence" => Ok(Self::StopSequence),
+      "tool_use" => Ok(Self::ToolUse),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "end_turn, max_tokens, stop_sequence, tool_use"
+      )),
+    }
+  }
+}
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
 pub struct Usage {
   pub input_tokens: i64,
@@ -813,18 +917,35 @@ pub enum Metadata {
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
 #[serde(default)]
 pub struct ErrorResponse {
+  /// - Constraints: default `error`
   #[serde(rename = "type")]
   #[default("error".to_string())]
   #[builder(default = "error".to_string())]
   pub r#type: String,
   pub error: ErrorDetails,
 }
+impl core::fmt::Display for ErrorResponse {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.error.message)
+  }
+}
+impl std::error::Error for ErrorResponse {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    std::error::Error::source(&self.error)
+  }
+}
 #[derive(Debug, Clone, PartialEq, Deserialize, oas3_gen_support::Default, bon::Builder)]
 pub struct ErrorDetails {
   #[serde(rename = "type")]
   pub r#type: ErrorType,
   pub message: String,
 }
+impl core::fmt::Display for ErrorDetails {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+impl std::error::Error for ErrorDetails {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, oas3_gen_support::Default)]
 pub enum ErrorType {
   #[serde(rename = "invalid_request_error")]
@@ -856,6 +977,25 @@ impl core::fmt::Display for ErrorType {
     }
   }
 }
+impl core::str::FromStr for ErrorType {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "invalid_request_error" => Ok(Self::InvalidRequestError),
+      "authentication_error" => Ok(Self::AuthenticationError),
+      "permission_error" => Ok(Self::PermissionError),
+      "not_found_error" => Ok(Self::NotFoundError),
+      "rate_limit_error" => Ok(Self::RateLimitError),
+      "api_error" => Ok(Self::ApiError),
+      "overloaded_error" => Ok(Self::OverloadedError),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s,
+        "invalid_request_error, authentication_error, permission_error, not_found_error, rate_limit_error, api_error, overloaded_error"
+      )),
+    }
+  }
+}
 /// Test nullable union with primitives
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, oas3_gen_support::Default)]
 #[serde(untagged)]
@@ -886,6 +1026,7 @@ impl ArrayOrSingle {
 pub struct RecipeList {
   pub ingredients: Option<indexmap::IndexMap<String, serde_json::Value>>,
   pub required: Option<Vec<String>>,
+  /// - Constraints: default `object`
   #[serde(rename = "type")]
   #[default("object".to_string())]
   #[builder(default = "object".to_string())]
@@ -929,8 +1070,10 @@ pub enum HypotheticalVersion {
 )]
 #[serde(default)]
 pub struct UsageCounterA {
+  /// - Constraints: >= 0
   #[validate(range(min = 0i64))]
   pub output_tokens: i64,
+  /// - Constraints: default `counter_a`
   #[serde(rename = "type")]
   #[default(Some("counter_a".to_string()))]
   pub r#type: Option<String>,
@@ -941,8 +1084,10 @@ pub struct UsageCounterA {
 )]
 #[serde(default)]
 pub struct UsageCounterB {
+  /// - Constraints: >= 0
   #[validate(range(min = 0i64))]
   pub output_tokens: i64,
+  /// - Constraints: default `counter_b`
   #[serde(rename = "type")]
   #[default(Some("counter_b".to_string()))]
   pub r#type: Option<String>,
@@ -952,10 +1097,12 @@ pub struct UsageCounterB {
 #[serde(default)]
 pub struct ImageSize {
   /// The width of the generated image.
+  /// - Constraints: > 0 and <= 14142, default `512`
   #[validate(range(max = 14_142i64, exclusive_min = 0i64))]
   #[default(Some(512i64))]
   pub width: Option<i64>,
   /// The height of the generated image.
+  /// - Constraints: > 0 and <= 14142, default `512`
   #[validate(range(max = 14_142i64, exclusive_min = 0i64))]
   #[default(Some(512i64))]
   pub height: Option<i64>,
@@ -994,6 +1141,25 @@ impl core::fmt::Display for ImageSizePreset {
     }
   }
 }
+impl core::str::FromStr for ImageSizePreset {
+  type Err = String;
+  fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+    match s {
+      "square_hd" => Ok(Self::SquareHd),
+      "square" => Ok(Self::Square),
+      "portrait_4_3" => Ok(Self::Portrait43),
+      "portrait_16_9" => Ok(Self::Portrait169),
+      "landscape_4_3" => Ok(Self::Landscape43),
+      "landscape_16_9" => Ok(Self::Landscape169),
+      "auto_1K" => Ok(Self::Auto1k),
+      "auto_2K" => Ok(Self::Auto2k),
+      _ => Err(format!(
+        "unknown variant '{}', expected one of: {}",
+        s, "square_hd, square, portrait_4_3, portrait_16_9, landscape_4_3, landscape_16_9, auto_1K, auto_2K"
+      )),
+    }
+  }
+}
 /// Send content blocks
 #[derive(Debug, Clone, validator::Validate, oas3_gen_support::Default)]
 pub struct SendContentRequest {
@@ -0,0 +1,168 @@
+use std::{
+  collections::{BTreeMap, HashSet},
+  path::{Path, PathBuf},
+};
+
+use crate::{
+  generator::{
+    ClientModMode, ClientMode, CodegenConfig, GenerationMode, GenerationTarget, MockMode, PropertyTestsMode,
+    ServerModMode, ServerMode, TestsMode, TypesMode, WorkspaceMode,
+    codegen::{Visibility, workspace::WorkspaceCrates},
+    operation_registry::OperationFilter,
+    orchestrator::Orchestrator,
+  },
+  utils::spec::SpecFormat,
+};
+
+/// What [`generate`] produces, matching the modes of `oas3-gen generate`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OutputMode {
+  /// `types.rs` with the schema types only.
+  #[default]
+  Types,
+  /// `client.rs` with an HTTP client that imports the types from
+  /// [`CodegenConfig::types_import_path`], `super::types` by default.
+  Client,
+  /// `mod.rs`, `types.rs`, and `client.rs`.
+  ClientMod,
+  /// `server.rs` with the types and an axum server trait.
+  Server,
+  /// `mod.rs`, `types.rs`, and `server.rs`.
+  ServerMod,
+  /// `server.rs` with the server plus a mock implementation answering with spec examples.
+  Mock,
+  /// `types.rs` with a round-trip test for every schema example.
+  Tests,
+  /// `types.rs` with a `proptest` round-trip property for every schema type.
+  Proptests,
+  /// A Cargo workspace with `{crate_prefix}-types` and `{crate_prefix}-client` crates.
+  Workspace { crate_prefix: String },
+}
+
+impl OutputMode {
+  fn target(&self) -> GenerationTarget {
+    match self {
+      Self::Server | Self::ServerMod | Self::Mock => GenerationTarget::Server,
+      _ => GenerationTarget::Client,
+    }
+  }
+}
+
+/// Options for [`generate`].
+#[derive(Debug, Clone, bon::Builder)]
+pub struct GenOptions {
+  #[builder(default)]
+  pub mode: OutputMode,
+  /// Code generation settings, including acronym casing, type name overrides, the
+  /// naming hook, and doc comment formatting; `target` is set from [`Self::mode`].
+  #[builder(default = CodegenConfig::builder().build())]
+  pub config: CodegenConfig,
+  #[builder(default)]
+  pub visibility: Visibility,
+  /// Generates only these operation IDs, along with the schemas they reach.
+  pub only_operations: Option<HashSet<String>>,
+  /// Leaves out these operation IDs.
+  pub excluded_operations: Option<HashSet<String>>,
+  /// Generates only operations with at least one of these tags.
+  pub included_tags: Option<HashSet<String>>,
+  /// Generates only operations whose path starts with one of these prefixes, matched
+  /// on whole segments.
+  pub included_paths: Option<Vec<String>>,
+  /// Spec path recorded in the header of every generated file.
+  #[builder(into, default)]
+  pub source_path: String,
+}
+
+/// The files [`generate`] produced, keyed by path relative to the output directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedOutput {
+  files: BTreeMap<PathBuf, String>,
+  warnings: Vec<String>,
+}
+
+impl GeneratedOutput {
+  /// Returns every generated file keyed by its path relative to the output directory.
+  ///
+  /// Single-file modes produce one file, such as `types.rs` or `client.rs`.
+  #[must_use]
+  pub fn files(&self) -> &BTreeMap<PathBuf, String> {
+    &self.files
+  }
+
+  /// Returns the warnings raised while converting the spec, such as schemas that were skipped.
+  #[must_use]
+  pub fn warnings(&self) -> &[String] {
+    &self.warnings
+  }
+
+  /// Writes every file under `dir`, creating directories as needed and leaving files
+  /// whose contents are unchanged untouched, so `build.rs` does not trigger rebuilds.
+  pub fn write_to(&self, dir: impl AsRef<Path>) -> std::io::Result<()> {
+    let dir = dir.as_ref();
+    for (path, source) in &self.files {
+      let path = dir.join(path);
+      if std::fs::read_to_string(&path).is_ok_and(|existing| existing == *source) {
+        continue;
+      }
+      if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+      }
+      std::fs::write(path, source)?;
+    }
+    Ok(())
+  }
+}
+
+/// Reads a JSON or YAML spec, picking the format from the file extension.
+pub fn load_spec(path: impl AsRef<Path>) -> anyhow::Result<oas3::Spec> {
+  let path = path.as_ref();
+  SpecFormat::from_path(path).parse(&std::fs::read(path)?)
+}
+
+/// Generates Rust code from `spec`, as `oas3-gen generate` does without writing to disk.
+pub fn generate(spec: oas3::Spec, options: GenOptions) -> anyhow::Result<GeneratedOutput> {
+  let GenOptions {
+    mode,
+    mut config,
+    visibility,
+    only_operations,
+    excluded_operations,
+    included_tags,
+    included_paths,
+    source_path,
+  } = options;
+  config.target = mode.target();
+  let filter = OperationFilter::new(only_operations.as_ref(), excluded_operations.as_ref())
+    .with_tags(included_tags.as_ref())
+    .with_paths(included_paths.as_deref());
+  let orchestrator = Orchestrator::with_operation_filter(spec, visibility, config, filter);
+
+  let workspace_mode;
+  let generation_mode: &dyn GenerationMode = match &mode {
+    OutputMode::Types => &TypesMode,
+    OutputMode::Client => &ClientMode,
+    OutputMode::ClientMod => &ClientModMode,
+    OutputMode::Server => &ServerMode,
+    OutputMode::ServerMod => &ServerModMode,
+    OutputMode::Mock => &MockMode,
+    OutputMode::Tests => &TestsMode,
+    OutputMode::Proptests => &PropertyTestsMode,
+    OutputMode::Workspace { crate_prefix } => {
+      workspace_mode = WorkspaceMode {
+        crates: WorkspaceCrates::from_prefix(crate_prefix),
+      };
+      &workspace_mode
+    }
+  };
+  let output = orchestrator.generate(generation_mode, &source_path)?;
+
+  Ok(GeneratedOutput {
+    files: output
+      .code
+      .files()
+      .into_iter()
+      .map(|(path, source, _)| (path, source.clone()))
+      .collect(),
+    warnings: output.stats.warnings.iter().map(ToString::to_string).collect(),
+  })
+}
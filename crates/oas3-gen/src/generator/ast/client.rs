@@ -3,7 +3,10 @@ use oas3::{
   spec::{Info, Server},
 };
 
-use crate::generator::{ast::StructToken, naming::identifiers::to_rust_type_name};
+use crate::generator::{
+  ast::{SecuritySchemeDef, StructToken},
+  naming::identifiers::to_rust_type_name,
+};
 
 const DEFAULT_BASE_URL: &str = "https://example.com/";
 
@@ -14,12 +17,18 @@ pub struct ClientRootNode {
   pub version: String,
   pub description: Option<String>,
   pub base_url: String,
+  pub security_schemes: Vec<SecuritySchemeDef>,
 }
 
 #[bon::bon]
 impl ClientRootNode {
   #[builder]
-  pub fn new(name: StructToken, info: &Info, servers: &[Server]) -> Self {
+  pub fn new(
+    name: StructToken,
+    info: &Info,
+    servers: &[Server],
+    #[builder(default)] security_schemes: Vec<SecuritySchemeDef>,
+  ) -> Self {
     Self {
       name,
       title: info.title.clone(),
@@ -28,6 +37,7 @@ impl ClientRootNode {
       base_url: servers
         .first()
         .map_or_else(|| DEFAULT_BASE_URL.to_string(), |server| server.url.clone()),
+      security_schemes,
     }
   }
 }
@@ -42,6 +52,7 @@ impl From<&Spec> for ClientRootNode {
       }))
       .info(&value.info)
       .servers(&value.servers)
+      .security_schemes(SecuritySchemeDef::collect(value))
       .build()
   }
 }
@@ -1,4 +1,3 @@
-use inflections::Inflect as _;
 use quote::{ToTokens, quote};
 
 use crate::generator::{
@@ -6,7 +5,7 @@ use crate::generator::{
     RegexKey,
     tokens::{ConstToken, HeaderNameToken},
   },
-  naming::identifiers::sanitize,
+  naming::{casing::to_constant_case, identifiers::sanitize},
 };
 
 impl From<&RegexKey> for ConstToken {
@@ -18,7 +17,7 @@ impl From<&RegexKey> for ConstToken {
       .collect::<Vec<_>>()
       .join("_");
 
-    let mut ident = joined.to_constant_case();
+    let mut ident = to_constant_case(&joined);
 
     if ident.starts_with(|c: char| c.is_ascii_digit()) {
       ident.insert(0, '_');
@@ -56,3 +55,53 @@ impl ToTokens for HttpHeaderRef {
     tokens.extend(header);
   }
 }
+
+/// Module of generated code holding a `&str` constant for each media type it uses.
+pub const MEDIA_TYPES_MODULE: &str = "media_types";
+
+/// A media type the generated client sends or assumes, emitted as a constant in the
+/// [`MEDIA_TYPES_MODULE`] module.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MediaTypeRef {
+  pub const_token: ConstToken,
+  pub media_type: String,
+}
+
+impl MediaTypeRef {
+  fn new(media_type: &str) -> Self {
+    Self {
+      const_token: ConstToken::from_raw(media_type),
+      media_type: media_type.to_string(),
+    }
+  }
+
+  /// `application/json`, which response parsing assumes when a response has no
+  /// `Content-Type`.
+  pub fn json() -> Self {
+    Self::new("application/json")
+  }
+
+  /// Returns the constant for `media_type`, or `None` for a range such as `image/*`,
+  /// which cannot be sent as a `Content-Type`.
+  pub fn concrete(media_type: &str) -> Option<Self> {
+    (!media_type.contains('*')).then(|| Self::new(media_type))
+  }
+
+  /// Returns the path of the constant from a module that imports the media types module.
+  pub fn path(&self) -> proc_macro2::TokenStream {
+    let module = quote::format_ident!("{MEDIA_TYPES_MODULE}");
+    let const_token = &self.const_token;
+    quote! { #module::#const_token }
+  }
+}
+
+impl ToTokens for MediaTypeRef {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let const_token = &self.const_token;
+    let media_type = &self.media_type;
+
+    tokens.extend(quote! {
+      pub const #const_token: &str = #media_type;
+    });
+  }
+}
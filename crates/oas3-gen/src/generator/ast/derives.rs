@@ -23,6 +23,8 @@ pub enum DeriveTrait {
   PartialEq,
   Eq,
   Hash,
+  PartialOrd,
+  Ord,
   Serialize,
   Deserialize,
   #[strum(serialize = "validator::Validate")]
@@ -47,7 +49,11 @@ pub trait DerivesProvider {
 
 impl DerivesProvider for StructDef {
   fn derives(&self) -> BTreeSet<DeriveTrait> {
-    let mut derives = BTreeSet::from([DeriveTrait::Debug, DeriveTrait::Clone, DeriveTrait::Default]);
+    let mut derives = BTreeSet::from([DeriveTrait::Debug, DeriveTrait::Default]);
+
+    if !self.skip_clone {
+      derives.insert(DeriveTrait::Clone);
+    }
 
     if self.kind != StructKind::OperationRequest {
       derives.insert(DeriveTrait::PartialEq);
@@ -85,14 +91,13 @@ impl DerivesProvider for StructDef {
 
 impl DerivesProvider for EnumDef {
   fn derives(&self) -> BTreeSet<DeriveTrait> {
-    let mut derives = BTreeSet::from([
-      DeriveTrait::Debug,
-      DeriveTrait::Clone,
-      DeriveTrait::PartialEq,
-      DeriveTrait::Default,
-    ]);
-
-    if self.is_simple() {
+    let mut derives = BTreeSet::from([DeriveTrait::Debug, DeriveTrait::PartialEq, DeriveTrait::Default]);
+
+    if !self.skip_clone {
+      derives.insert(DeriveTrait::Clone);
+    }
+
+    if self.is_simple() || self.scalar_fallback().is_some() {
       derives.insert(DeriveTrait::Eq);
       derives.insert(DeriveTrait::Hash);
     }
@@ -104,6 +109,7 @@ impl DerivesProvider for EnumDef {
       derives.insert(DeriveTrait::Deserialize);
     }
 
+    derives.extend(&self.additional_derives);
     derives
   }
 
@@ -143,7 +149,11 @@ impl EnumDef {
 
 impl DerivesProvider for DiscriminatedEnumDef {
   fn derives(&self) -> BTreeSet<DeriveTrait> {
-    BTreeSet::from([DeriveTrait::Debug, DeriveTrait::Clone, DeriveTrait::PartialEq])
+    let mut derives = BTreeSet::from([DeriveTrait::Debug, DeriveTrait::PartialEq]);
+    if !self.skip_clone {
+      derives.insert(DeriveTrait::Clone);
+    }
+    derives
   }
 
   fn is_serializable(&self) -> SerdeImpl {
@@ -170,7 +180,7 @@ impl DerivesProvider for ResponseEnumDef {
       .iter()
       .any(|v| ResponseMediaType::has_event_stream(&v.media_types));
 
-    if !has_event_stream {
+    if !has_event_stream && !self.skip_clone {
       derives.insert(DeriveTrait::Clone);
     }
 
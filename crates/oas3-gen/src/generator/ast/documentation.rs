@@ -1,13 +1,22 @@
-use std::{process::Stdio, sync::OnceLock};
+use std::{cell::RefCell, process::Stdio};
 
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use tokio::{process::Command, runtime::Handle};
+use tokio::{
+  process::Command,
+  runtime::{Builder, Handle, RuntimeFlavor},
+};
 
-static DOC_FORMAT_ENABLED: OnceLock<bool> = OnceLock::new();
+use crate::{generator::converter::DocFormatPolicy, utils::scoped};
 
-pub fn init_doc_format(enabled: bool) {
-  DOC_FORMAT_ENABLED.set(enabled).ok();
+thread_local! {
+  static DOC_FORMAT: RefCell<DocFormatPolicy> = RefCell::new(DocFormatPolicy::default());
+}
+
+/// Runs `f` with doc comments formatted according to `policy` on the current thread,
+/// restoring the previous policy afterwards.
+pub(crate) fn scope_doc_format<R>(policy: DocFormatPolicy, f: impl FnOnce() -> R) -> R {
+  scoped(&DOC_FORMAT, policy, f)
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
@@ -95,7 +104,7 @@ impl Documentation {
   }
 
   fn process_doc_text(input: &str) -> String {
-    if *DOC_FORMAT_ENABLED.get().unwrap_or(&false) {
+    if DOC_FORMAT.with_borrow(|policy| *policy == DocFormatPolicy::Mdformat) {
       Self::wrap_format_with_mdformat(input).replace("\\n", "\n")
     } else {
       input.replace("\\n", "\n")
@@ -103,7 +112,22 @@ impl Documentation {
   }
 
   fn wrap_format_with_mdformat(input: &str) -> String {
-    tokio::task::block_in_place(|| Handle::current().block_on(Self::build_async_format_with_mdformat(input)))
+    match Handle::try_current() {
+      Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+        tokio::task::block_in_place(|| handle.block_on(Self::build_async_format_with_mdformat(input)))
+      }
+      _ => std::thread::scope(|scope| {
+        scope
+          .spawn(|| {
+            Builder::new_current_thread().enable_all().build().map_or_else(
+              |_| input.to_string(),
+              |runtime| runtime.block_on(Self::build_async_format_with_mdformat(input)),
+            )
+          })
+          .join()
+          .unwrap_or_else(|_| input.to_string())
+      }),
+    }
   }
 
   pub(crate) async fn build_async_format_with_mdformat(input: &str) -> String {
@@ -3,9 +3,13 @@ use std::collections::BTreeSet;
 use itertools::Itertools;
 use oas3::spec::{ObjectSchema, Parameter, ParameterStyle};
 
-use crate::generator::ast::{
-  Documentation, FieldNameToken, OuterAttr, ParameterLocation, RustPrimitive, SerdeAsFieldAttr, SerdeAsSeparator,
-  SerdeAttribute, TypeRef, ValidationAttribute, bon_attrs::BuilderAttribute,
+use crate::generator::{
+  ast::{
+    Documentation, EnumVariantToken, FieldAccess, FieldNameToken, ObjectStyle, OuterAttr, ParameterLocation,
+    RustPrimitive, SerdeAsFieldAttr, SerdeAsSeparator, SerdeAttribute, TypeRef, ValidationAttribute,
+    bon_attrs::BuilderAttribute,
+  },
+  naming::constants::PAGE_LINK_FIELD,
 };
 
 /// Rust struct field definition
@@ -25,11 +29,18 @@ pub struct FieldDef {
   #[builder(default)]
   pub builder_attrs: Vec<BuilderAttribute>,
   pub default_value: Option<serde_json::Value>,
+  /// The enum variant `default_value` names, when the field holds a value enum.
+  pub default_variant: Option<EnumVariantToken>,
   pub example_value: Option<serde_json::Value>,
   #[builder(into)]
   pub parameter_location: Option<ParameterLocation>,
+  /// Set for path and header parameters holding an object.
+  pub object_style: Option<ObjectStyle>,
   #[builder(default)]
   pub deprecated: bool,
+  /// Set from `readOnly` and `writeOnly` when `--read-write-only` is enabled.
+  #[builder(default)]
+  pub access: FieldAccess,
   pub multiple_of: Option<serde_json::Number>,
   #[builder(into)]
   pub original_name: Option<String>,
@@ -41,6 +52,24 @@ impl FieldDef {
     self.default_value.is_none() && !self.rust_type.nullable
   }
 
+  /// Whether the field's `Default` value is exactly its spec default, rather than
+  /// a fallback for a default that has no literal form, such as a date.
+  #[must_use]
+  pub fn has_exact_default(&self) -> bool {
+    let Some(default_value) = &self.default_value else {
+      return false;
+    };
+    if default_value.is_null() || self.rust_type.is_array {
+      return false;
+    }
+    self.default_variant.is_some()
+      || self.rust_type.base_type.is_numeric()
+      || matches!(
+        self.rust_type.base_type,
+        RustPrimitive::String | RustPrimitive::StaticStr | RustPrimitive::Bool
+      )
+  }
+
   #[must_use]
   pub fn with_discriminator_behavior(mut self, discriminator_value: Option<&str>, is_base: bool) -> Self {
     self.docs.clear();
@@ -273,10 +302,25 @@ where
 }
 
 impl FieldDef {
-  /// Renames the field to the specified new name.
+  /// Returns the name the field has on the wire: its `serde(rename)` value, or the field name.
+  #[must_use]
+  pub fn serialized_name(&self) -> &str {
+    self
+      .serde_attrs
+      .iter()
+      .find_map(|attr| match attr {
+        SerdeAttribute::Rename(name) => Some(name.as_str()),
+        _ => None,
+      })
+      .unwrap_or(self.name.as_str())
+  }
+
+  /// Renames the field, adding a `serde(rename)` so its serialized name is unchanged.
   #[must_use]
   pub fn renamed_to(self, new_name: &str) -> Self {
     let mut new_field = self;
+    let serialized_name = new_field.serialized_name().to_string();
+    new_field.serde_attrs.insert(SerdeAttribute::Rename(serialized_name));
     new_field.name = FieldNameToken::from_raw(new_name);
     new_field
   }
@@ -293,6 +337,20 @@ impl FieldDef {
     }
   }
 
+  /// Creates the `page_link` field of a request whose responses carry `Link` header links.
+  #[must_use]
+  pub fn page_link_field() -> Self {
+    Self {
+      name: FieldNameToken::from_raw(PAGE_LINK_FIELD),
+      docs: Documentation::from_lines([
+        "Page URL from a previous response's `Link` header, as set by its `next()` and `prev()`.",
+        "When set, the request is sent there instead of to the URL built from its parameters.",
+      ]),
+      rust_type: TypeRef::new(RustPrimitive::String).with_option(),
+      ..Default::default()
+    }
+  }
+
   /// Creates a field that references a nested struct (e.g., for parameter groups).
   #[must_use]
   pub fn nested_struct_field(field_name: &str, struct_name: &str) -> Self {
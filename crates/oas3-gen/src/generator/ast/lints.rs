@@ -41,6 +41,22 @@ impl ToTokens for LintAllow {
 pub struct GlobalLintsNode {
   #[builder(default)]
   pub allows: BTreeSet<LintAllow>,
+  /// Additional lint paths emitted as `#![allow(...)]`, e.g. `clippy::pedantic`.
+  #[builder(default)]
+  pub custom_allows: BTreeSet<String>,
+  /// Lint paths emitted as `#![deny(...)]`.
+  #[builder(default)]
+  pub denies: BTreeSet<String>,
+}
+
+impl GlobalLintsNode {
+  /// Adds the user-configured allow and deny lints to this lint set.
+  #[must_use]
+  pub fn with_config(mut self, config: &LintConfig) -> Self {
+    self.custom_allows.extend(config.allow.iter().cloned());
+    self.denies.extend(config.deny.iter().cloned());
+    self
+  }
 }
 
 impl Default for GlobalLintsNode {
@@ -59,6 +75,8 @@ impl Default for GlobalLintsNode {
         LintAllow::ClippyUnusedSelf,
         LintAllow::DeadCode,
       ]),
+      custom_allows: BTreeSet::new(),
+      denies: BTreeSet::new(),
     }
   }
 }
@@ -68,5 +86,75 @@ impl ToTokens for GlobalLintsNode {
     for allow in &self.allows {
       allow.to_tokens(tokens);
     }
+    for lint in self
+      .custom_allows
+      .iter()
+      .filter_map(|lint| syn::parse_str::<syn::Path>(lint).ok())
+    {
+      tokens.extend(quote! { #![allow(#lint)] });
+    }
+    for lint in self
+      .denies
+      .iter()
+      .filter_map(|lint| syn::parse_str::<syn::Path>(lint).ok())
+    {
+      tokens.extend(quote! { #![deny(#lint)] });
+    }
+  }
+}
+
+/// User configuration for the header written at the top of each generated file.
+///
+/// Controls the lint attributes added alongside the built-in allow list, an optional
+/// license comment, an `@generated` marker for tools that skip generated files, and
+/// whether the header records the input spec path.
+#[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
+pub struct LintConfig {
+  /// Lint paths to allow in addition to the built-in set.
+  #[builder(default)]
+  pub allow: Vec<String>,
+  /// Lint paths to deny.
+  #[builder(default)]
+  pub deny: Vec<String>,
+  /// Text written as `//` comment lines before everything else in the file.
+  pub license_header: Option<String>,
+  /// Whether to start the file with a `// @generated` comment.
+  #[builder(default)]
+  pub generated_marker: bool,
+  /// Whether the header records the path of the input spec.
+  #[builder(default = true)]
+  pub include_source_path: bool,
+  /// Hash of the effective spec and options, recorded in the header when set.
+  pub spec_hash: Option<String>,
+}
+
+impl Default for LintConfig {
+  fn default() -> Self {
+    Self::builder().build()
+  }
+}
+
+impl LintConfig {
+  /// Renders the comment lines written before the generated header, if any.
+  #[must_use]
+  pub fn preamble(&self) -> String {
+    let mut preamble = String::new();
+    if self.generated_marker {
+      preamble.push_str("// @generated\n");
+    }
+    if let Some(license) = &self.license_header {
+      for line in license.trim_end().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+          preamble.push_str("//\n");
+        } else {
+          preamble.push_str(&format!("// {line}\n"));
+        }
+      }
+    }
+    if !preamble.is_empty() {
+      preamble.push('\n');
+    }
+    preamble
   }
 }
@@ -7,6 +7,7 @@ pub mod fields;
 pub mod lints;
 mod outer_attrs;
 mod parsed_path;
+mod security;
 pub(super) mod serde_attrs;
 pub(crate) mod server;
 mod status_codes;
@@ -17,23 +18,25 @@ pub(super) mod validation_attrs;
 #[cfg(test)]
 mod tests;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub use client::ClientRootNode;
 pub use derives::{DeriveTrait, DerivesProvider, SerdeImpl};
 pub use documentation::Documentation;
 use http::Method;
-pub use lints::GlobalLintsNode;
+pub use lints::{GlobalLintsNode, LintConfig};
 use mediatype::MediaType;
 use oas3::spec::{ObjectSchema, ParameterIn};
-pub use outer_attrs::{OuterAttr, SerdeAsFieldAttr, SerdeAsSeparator};
-pub use parsed_path::ParsedPath;
+pub use outer_attrs::{EpochTimestamp, OuterAttr, SerdeAsFieldAttr, SerdeAsSeparator};
 #[cfg(test)]
-pub use parsed_path::{PathParseError, PathSegment};
+pub use parsed_path::PathParseError;
+pub use parsed_path::{ParsedPath, PathSegment, simple_style_value};
+pub(crate) use security::operation_security;
+pub use security::{ApiKeyLocation, SecuritySchemeDef, SecuritySchemeKind};
 pub use serde_attrs::SerdeAttribute;
 use serde_json::Value;
 pub use server::{HandlerBodyInfo, ServerRequestTraitDef, ServerTraitMethod};
-pub use status_codes::StatusCodeToken;
+pub use status_codes::{ResponseClass, StatusCodeToken};
 pub use tokens::{
   DefaultAtom, EnumToken, EnumVariantToken, FieldNameToken, MethodNameToken, StructToken, TraitToken, TypeAliasToken,
 };
@@ -42,7 +45,11 @@ pub use validation_attrs::{RegexKey, ValidationAttribute};
 
 pub use crate::generator::ast::fields::{FieldCollection, FieldDef};
 use crate::{
-  generator::{ast::constants::HttpHeaderRef, metrics::GenerationWarning, naming::inference::NormalizedVariant},
+  generator::{
+    ast::constants::{HttpHeaderRef, MediaTypeRef},
+    metrics::GenerationWarning,
+    naming::inference::NormalizedVariant,
+  },
   utils::schema_ext::SchemaIters,
 };
 
@@ -51,8 +58,11 @@ use crate::{
 pub struct FileHeaderNode {
   pub title: String,
   pub version: String,
-  pub source_path: String,
+  /// Path of the input spec, omitted from the header when `None`.
+  pub source_path: Option<String>,
   pub generator_version: String,
+  /// Hash of the effective spec and options, omitted from the header when `None`.
+  pub spec_hash: Option<String>,
   pub description: Option<Documentation>,
   pub lints: GlobalLintsNode,
 }
@@ -93,6 +103,9 @@ pub struct DiscriminatedEnumDef {
   pub serde_mode: SerdeMode,
   #[builder(default)]
   pub methods: Vec<EnumMethod>,
+  /// Whether `Clone` is left off, for types over the configured field limit and types containing them
+  #[builder(default)]
+  pub skip_clone: bool,
 }
 
 impl DiscriminatedEnumDef {
@@ -116,9 +129,29 @@ pub struct ResponseVariant {
   #[builder(default)]
   pub media_types: Vec<ResponseMediaType>,
   pub schema_type: Option<TypeRef>,
+  /// Whether the variant carries the response's `Link` header as `oas3_gen_support::PageLinks`.
+  #[builder(default)]
+  pub page_links: bool,
+  /// Whether the variant carries the response's `Retry-After` header as `Option<Duration>`.
+  #[builder(default)]
+  pub retry_after: bool,
 }
 
 impl ResponseVariant {
+  /// Returns `true` for a typed `default` response, whose variant carries the actual
+  /// status code alongside the body.
+  #[must_use]
+  pub fn captures_status(&self) -> bool {
+    self.status_code.is_default() && self.schema_type.is_some()
+  }
+
+  /// Returns `true` when the variant has named fields, `{ status, links, body }`,
+  /// `{ retry_after, body }`, or a subset, rather than wrapping its body alone.
+  #[must_use]
+  pub fn has_named_fields(&self) -> bool {
+    self.captures_status() || self.page_links || self.retry_after
+  }
+
   #[must_use]
   pub fn doc_line(&self) -> String {
     match &self.description {
@@ -166,6 +199,14 @@ pub struct ResponseEnumDef {
   pub request_type: Option<StructToken>,
   #[builder(default)]
   pub try_from: Vec<ImplTryFromNode>,
+  /// Variant that captures bodies failing to deserialize, when enabled.
+  pub malformed_variant: Option<EnumVariantToken>,
+  /// Class of each documented status, emitted as a `classify` function when non-empty.
+  #[builder(default)]
+  pub status_classes: BTreeMap<StatusCodeToken, ResponseClass>,
+  /// Whether `Clone` is left off, for types over the configured field limit and types containing them
+  #[builder(default)]
+  pub skip_clone: bool,
 }
 
 /// Top-level Rust type representation
@@ -227,6 +268,8 @@ impl RustType {
 pub enum OperationKind {
   Http,
   Webhook,
+  /// A request the API sends back, declared in an operation's `callbacks`.
+  Callback,
 }
 
 #[derive(Debug, Clone, bon::Builder)]
@@ -237,6 +280,9 @@ pub struct OperationInfo {
   pub operation_id: String,
   pub method: Method,
   pub path: ParsedPath,
+  /// The path as written in the spec, such as `/pets/{petId}`.
+  #[builder(default, into)]
+  pub path_template: String,
   pub kind: OperationKind,
   pub request_type: Option<StructToken>,
   pub response_type: Option<String>,
@@ -250,6 +296,113 @@ pub struct OperationInfo {
   pub body: Option<OperationBody>,
   #[builder(default)]
   pub documentation: Documentation,
+  /// Alternative sets of security scheme names; the first set with credentials is applied.
+  #[builder(default)]
+  pub security: Vec<Vec<String>>,
+  /// The operation's OpenAPI tags, in spec order.
+  #[builder(default)]
+  pub tags: Vec<String>,
+  /// The operation's `externalDocs` link.
+  pub external_docs: Option<ExternalDocs>,
+  /// The operation's `x-*` extensions, keyed by name without the `x-` prefix.
+  #[builder(default)]
+  pub extensions: BTreeMap<String, Value>,
+  /// For callbacks, the stable ID of the operation declaring them.
+  #[builder(into)]
+  pub callback_of: Option<String>,
+  /// Response enum variants that clients with typed errors return as `Err`.
+  #[builder(default)]
+  pub error_response_types: Vec<ErrorResponseType>,
+  /// Whether the operation accepts a compressed request body.
+  #[builder(default)]
+  pub request_compression: bool,
+  /// Whether the operation is marked `deprecated` in the spec.
+  #[builder(default)]
+  pub deprecated: bool,
+  /// The operation to use instead of a deprecated one, from `x-deprecated-replacement`.
+  #[builder(into)]
+  pub replaced_by: Option<String>,
+  /// Field names from the operation's `x-pagination` extension.
+  pub pagination_hint: Option<PaginationHint>,
+  /// Whether the request has a `page_link` field, filled from the `Link` header of a
+  /// previous response by the response enum's `next()` and `prev()`.
+  #[builder(default)]
+  pub page_link: bool,
+  /// How clients page through the operation's results, once resolved against its types.
+  pub pagination: Option<Pagination>,
+}
+
+/// An `externalDocs` link of an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalDocs {
+  pub url: String,
+  pub description: Option<String>,
+}
+
+/// The `x-pagination` extension of a list operation, naming fields as they appear in the spec.
+///
+/// ```yaml
+/// x-pagination:
+///   cursor: next_page_token
+///   param: page_token
+///   items: data
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct PaginationHint {
+  /// Response property holding the next-page cursor.
+  pub cursor: String,
+  /// Query parameter the cursor is sent back in, or `None` when the cursor is the next page's URL.
+  pub param: Option<String>,
+  /// Response property holding the page's items, or `None` for its only array property.
+  pub items: Option<String>,
+}
+
+/// How a client follows the pages of a list operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+  /// Response enum variant carrying a page.
+  pub page_variant: EnumVariantToken,
+  /// Whether the response enum has variants besides the page.
+  pub other_variants: bool,
+  /// Whether the page variant carries `Link` header links, making it `{ links, body }`.
+  pub page_links: bool,
+  /// The page's array field, or `None` when the page is the array itself.
+  pub items: Option<FieldNameToken>,
+  pub items_optional: bool,
+  /// Element type of the items array.
+  pub item_type: TypeRef,
+  pub cursor: PageCursor,
+  pub next_page: NextPage,
+}
+
+/// Where a page carries the cursor of the next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageCursor {
+  /// In this string field of the page.
+  Field { name: FieldNameToken, optional: bool },
+  /// In the `rel="next"` link of the response's `Link` header.
+  LinkHeader,
+}
+
+/// Where the next-page cursor goes in the following request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextPage {
+  /// Into this field of the request's query parameters.
+  Query { field: FieldNameToken, optional: bool },
+  /// The cursor is the URL of the next page, fetched with `GET`.
+  Link,
+}
+
+/// A response enum variant describing a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponseType {
+  pub variant: EnumVariantToken,
+  /// The type name of the error body, or `None` for a variant without a body
+  pub body: Option<DefaultAtom>,
+  /// Whether the variant has named fields, such as the status code next to the body
+  pub named_fields: bool,
+  /// Whether the body implements `std::error::Error`
+  pub error_impl: bool,
 }
 
 impl OperationInfo {
@@ -270,6 +423,30 @@ impl OperationInfo {
   }
 }
 
+/// Which direction a schema property travels in, from its `readOnly` and `writeOnly`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldAccess {
+  #[default]
+  ReadWrite,
+  /// Only sent in responses.
+  ReadOnly,
+  /// Only sent in requests.
+  WriteOnly,
+  /// A `readOnly` property kept as an ordinary field, left out when a client sends it.
+  ServerManaged,
+}
+
+impl FieldAccess {
+  #[must_use]
+  pub fn from_schema(schema: &ObjectSchema) -> Self {
+    match (schema.read_only.unwrap_or(false), schema.write_only.unwrap_or(false)) {
+      (true, false) => Self::ReadOnly,
+      (false, true) => Self::WriteOnly,
+      _ => Self::ReadWrite,
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ParameterLocation {
   #[default]
@@ -291,6 +468,15 @@ impl ParameterLocation {
   }
 }
 
+/// How a path or header parameter holding an object is written in OpenAPI `simple` style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectStyle {
+  /// Keys and values alternate: `role,admin,first,Alex`.
+  Flat,
+  /// Each key is joined to its value: `role=admin,first=Alex`.
+  Exploded,
+}
+
 impl From<ParameterIn> for ParameterLocation {
   fn from(value: ParameterIn) -> Self {
     match value {
@@ -311,6 +497,7 @@ pub enum ContentCategory {
   Text,
   Binary,
   Xml,
+  Yaml,
   EventStream,
 }
 
@@ -327,6 +514,7 @@ impl ContentCategory {
       ("multipart", _, _) => Self::Multipart,
       ("text", "event-stream", _) => Self::EventStream,
       ("text" | "application", "xml", _) | (_, _, Some("xml")) => Self::Xml,
+      ("text" | "application", "yaml" | "x-yaml", _) | (_, _, Some("yaml")) => Self::Yaml,
       ("application", "x-www-form-urlencoded", _) => Self::FormUrlEncoded,
       ("application", "json", _) | (_, _, Some("json")) => Self::Json,
       ("image" | "audio" | "video", _, _) | ("application", "pdf" | "octet-stream", _) => Self::Binary,
@@ -342,6 +530,7 @@ impl ContentCategory {
       Self::Binary => "Binary",
       Self::Text => "Text",
       Self::Xml => "Xml",
+      Self::Yaml => "Yaml",
       Self::EventStream => "EventStream",
       Self::FormUrlEncoded => "Form",
       Self::Multipart => "Multipart",
@@ -368,6 +557,9 @@ impl EnumVariantToken {
 pub struct ResponseMediaType {
   pub category: ContentCategory,
   pub schema_type: Option<TypeRef>,
+  pub named_events: Option<NamedEventsDef>,
+  /// Example body from the media type, or from its schema when the media type has none
+  pub example: Option<serde_json::Value>,
 }
 
 impl ResponseMediaType {
@@ -376,6 +568,8 @@ impl ResponseMediaType {
     Self {
       category: ContentCategory::from_content_type(content_type),
       schema_type: None,
+      named_events: None,
+      example: None,
     }
   }
 
@@ -384,9 +578,26 @@ impl ResponseMediaType {
     Self {
       category: ContentCategory::from_content_type(content_type),
       schema_type,
+      named_events: None,
+      example: None,
     }
   }
 
+  #[must_use]
+  pub fn with_example(mut self, example: Option<serde_json::Value>) -> Self {
+    self.example = example;
+    self
+  }
+
+  /// Decodes an event-stream response by SSE `event:` name, replacing the schema type
+  /// with the generated event enum.
+  #[must_use]
+  pub fn with_named_events(mut self, named_events: NamedEventsDef) -> Self {
+    self.schema_type = Some(TypeRef::new(named_events.name.to_string()));
+    self.named_events = Some(named_events);
+    self
+  }
+
   #[must_use]
   pub fn primary_category(media_types: &[Self]) -> ContentCategory {
     media_types.first().map_or(ContentCategory::Json, |m| m.category)
@@ -396,6 +607,28 @@ impl ResponseMediaType {
   pub fn has_event_stream(media_types: &[Self]) -> bool {
     media_types.iter().any(|m| m.category == ContentCategory::EventStream)
   }
+
+  #[must_use]
+  pub fn has_named_events(media_types: &[Self]) -> bool {
+    media_types.iter().any(|m| m.named_events.is_some())
+  }
+}
+
+/// The event enum for an event-stream response whose payload depends on the SSE `event:` name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedEventsDef {
+  pub name: EnumToken,
+  pub events: Vec<NamedEventVariant>,
+  /// Whether `Clone` is left off, for event enums holding a type without it
+  pub skip_clone: bool,
+}
+
+/// One named event of an event-stream response and the schema of its `data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedEventVariant {
+  pub event: String,
+  pub variant: EnumVariantToken,
+  pub schema_type: TypeRef,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, bon::Builder)]
@@ -405,6 +638,11 @@ pub struct MultipartFieldInfo {
   pub nullable: bool,
   #[builder(default)]
   pub is_bytes: bool,
+  /// Whether the field holds `oas3_gen_support::FilePart` values sent as file parts.
+  #[builder(default)]
+  pub is_file: bool,
+  #[builder(default)]
+  pub is_array: bool,
   #[builder(default)]
   pub requires_json: bool,
 }
@@ -417,6 +655,73 @@ pub struct OperationBody {
   pub optional: bool,
   #[builder(default)]
   pub content_category: ContentCategory,
+  /// The media type the spec declares for the body, such as `text/plain`.
+  #[builder(into)]
+  pub content_type: Option<String>,
+  pub multipart_fields: Option<Vec<MultipartFieldInfo>>,
+  /// The media types a body enum chooses between, empty when the body has a single media type.
+  #[builder(default)]
+  pub media_variants: Vec<BodyMediaVariant>,
+}
+
+impl OperationBody {
+  /// Returns the body as sent when its enum holds `variant`, bound to a present value.
+  #[must_use]
+  pub fn for_variant(&self, variant: &BodyMediaVariant) -> Self {
+    Self {
+      field_name: self.field_name.clone(),
+      body_type: self.body_type.clone(),
+      optional: false,
+      content_category: variant.content_category,
+      content_type: variant.content_type.clone(),
+      multipart_fields: variant.multipart_fields.clone(),
+      media_variants: vec![],
+    }
+  }
+
+  /// Returns the media type the client sends as the body's `Content-Type`.
+  ///
+  /// JSON bodies are sent as `application/json` whatever JSON media type they declare.
+  /// Form and multipart bodies get theirs from reqwest, so otherwise only text, binary,
+  /// XML, and YAML bodies declaring a concrete media type have one.
+  #[must_use]
+  pub fn content_type_header(&self) -> Option<MediaTypeRef> {
+    match self.content_category {
+      ContentCategory::Json => Some(MediaTypeRef::json()),
+      ContentCategory::Text
+      | ContentCategory::Binary
+      | ContentCategory::Xml
+      | ContentCategory::Yaml
+      | ContentCategory::EventStream => MediaTypeRef::concrete(self.content_type.as_deref()?),
+      ContentCategory::FormUrlEncoded | ContentCategory::Multipart => None,
+    }
+  }
+
+  /// Returns every `Content-Type` the client may send the body with, one per media variant
+  /// when the body is an enum.
+  #[must_use]
+  pub fn content_type_headers(&self) -> Vec<MediaTypeRef> {
+    if self.media_variants.is_empty() {
+      return self.content_type_header().into_iter().collect();
+    }
+    self
+      .media_variants
+      .iter()
+      .filter_map(|variant| self.for_variant(variant).content_type_header())
+      .collect()
+  }
+}
+
+/// One media type of a request body the operation accepts in several.
+///
+/// The body enum holds one variant per media type, and the client encodes the body
+/// and sets its `Content-Type` according to the variant supplied.
+#[derive(Debug, Clone, PartialEq, Eq, bon::Builder)]
+pub struct BodyMediaVariant {
+  pub name: EnumVariantToken,
+  pub content_category: ContentCategory,
+  #[builder(into)]
+  pub content_type: Option<String>,
   pub multipart_fields: Option<Vec<MultipartFieldInfo>>,
 }
 
@@ -457,6 +762,61 @@ pub struct StructDef {
   /// Additional traits to derive beyond the standard set (e.g., Builder), controlled by config options
   #[builder(default)]
   pub additional_derives: BTreeSet<DeriveTrait>,
+  /// Whether the struct is an error response body and implements `std::error::Error`
+  #[builder(default)]
+  pub error_impl: bool,
+  /// Dotted property path from `x-error-message-field` naming the error message
+  #[builder(into)]
+  pub error_message_field: Option<String>,
+  /// Field accesses leading to the message an error body displays, empty to display via `Debug`
+  #[builder(default)]
+  pub error_message_path: Vec<MessageFieldAccess>,
+  /// Nested error field returned from `std::error::Error::source`
+  pub error_source: Option<ErrorSourceField>,
+  /// Structs this struct extends through `allOf` references, in declaration order
+  #[builder(default)]
+  pub parents: Vec<StructToken>,
+  /// Conversions to and from parent structs whose fields this struct shares
+  #[builder(default)]
+  pub parent_conversions: Vec<ParentConversion>,
+  /// Whether `Clone` is left off, for types over the configured field limit and types containing them
+  #[builder(default)]
+  pub skip_clone: bool,
+}
+
+/// Conversions between an `allOf` child struct and a parent struct it was merged with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParentConversion {
+  pub parent: StructToken,
+  /// Parent fields copied between the two structs with their types; any others keep
+  /// their defaults
+  pub shared_fields: Vec<(FieldNameToken, TypeRef)>,
+  /// Whether the parent fills every parent field, so no `..Default::default()` is needed
+  pub covers_parent: bool,
+  /// Whether the child can be built from the parent, which holds when every
+  /// child-only field is optional or has a default
+  pub from_parent: bool,
+  /// Whether the shared fields cover every child field
+  pub covers_child: bool,
+}
+
+/// Field of an error body holding a nested error object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSourceField {
+  pub name: FieldNameToken,
+  /// Whether the field is an `Option` and may be absent
+  pub optional: bool,
+  pub boxed: bool,
+  /// Whether the message is read from this field, so `source` forwards to the field's own source
+  pub forwarded: bool,
+}
+
+/// One field access on the path from an error body to its message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageFieldAccess {
+  pub name: FieldNameToken,
+  /// Whether the field is an `Option` and may be absent
+  pub optional: bool,
 }
 
 impl StructDef {
@@ -486,6 +846,7 @@ pub enum MethodKind {
     response_enum: EnumToken,
     status_handlers: Vec<StatusHandler>,
     default_handler: Option<ResponseVariantCategory>,
+    malformed_variant: Option<EnumVariantToken>,
   },
   /// Method to convert the struct into an axum response
   IntoAxumResponse {
@@ -505,6 +866,13 @@ pub struct BuilderField {
   pub name: FieldNameToken,
   pub rust_type: TypeRef,
   pub owner_field: Option<FieldNameToken>,
+  /// Whether the builder takes the field's spec default from its owner's `Default` impl.
+  #[builder(default)]
+  pub has_default: bool,
+  /// Whether the builder leaves the field out of its parameters and starts it at
+  /// `Default::default()`.
+  #[builder(default)]
+  pub skipped: bool,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, bon::Builder)]
@@ -581,6 +949,12 @@ pub struct EnumDef {
   #[builder(default)]
   pub generate_display: bool,
   pub scalar_repr: Option<RustPrimitive>,
+  /// Whether `Clone` is left off, for types over the configured field limit and types containing them
+  #[builder(default)]
+  pub skip_clone: bool,
+  /// Additional traits to derive beyond the standard set, controlled by config options
+  #[builder(default)]
+  pub additional_derives: BTreeSet<DeriveTrait>,
 }
 
 impl EnumDef {
@@ -589,6 +963,33 @@ impl EnumDef {
     const FALLBACK_NAMES: &[&str] = &["Unknown", "Other"];
     self.variants.iter().find(|v| FALLBACK_NAMES.contains(&v.name.as_str()))
   }
+
+  /// Returns the variant of a scalar enum that holds values the spec does not list.
+  #[must_use]
+  pub fn scalar_fallback(&self) -> Option<&VariantDef> {
+    self.scalar_repr.as_ref()?;
+    self
+      .variants
+      .iter()
+      .find(|v| matches!(v.content, VariantContent::Tuple(_)))
+  }
+
+  /// Returns the unit variant that serializes as `value`, or has it as an alias.
+  #[must_use]
+  pub fn variant_for_value(&self, value: &serde_json::Value) -> Option<&VariantDef> {
+    let wire_value = value.as_str().map_or_else(|| value.to_string(), ToString::to_string);
+    self
+      .variants
+      .iter()
+      .filter(|variant| matches!(variant.content, VariantContent::Unit))
+      .find(|variant| {
+        variant.serde_name() == wire_value
+          || variant
+            .serde_attrs
+            .iter()
+            .any(|attr| matches!(attr, SerdeAttribute::Alias(alias) if *alias == wire_value))
+      })
+  }
 }
 
 /// Rust enum variant definition
@@ -617,6 +1018,20 @@ impl VariantDef {
       .unwrap_or_else(|| self.name.to_string())
   }
 
+  /// Renames the variant while keeping the value it serializes to.
+  #[must_use]
+  pub fn with_name(mut self, name: &str) -> Self {
+    let value = self.serde_name();
+    self.name = EnumVariantToken::from_raw(name);
+    self
+      .serde_attrs
+      .retain(|attr| !matches!(attr, SerdeAttribute::Rename(_)));
+    if self.name != value.as_str() {
+      self.serde_attrs.insert(0, SerdeAttribute::Rename(value));
+    }
+    self
+  }
+
   pub fn add_alias(&mut self, value: impl Into<String>) {
     self.serde_attrs.push(SerdeAttribute::Alias(value.into()));
   }
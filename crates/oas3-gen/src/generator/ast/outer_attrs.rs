@@ -38,11 +38,41 @@ pub enum SerdeAsSeparator {
   Pipe,
 }
 
+/// Numeric epoch encoding for timestamp fields.
+///
+/// Maps integer and number schemas marked `format: unix-time` or
+/// `x-timestamp-format` to the matching `serde_with` timestamp adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::AsRefStr)]
+pub enum EpochTimestamp {
+  #[strum(serialize = "serde_with::TimestampSeconds<i64>")]
+  Seconds,
+  #[strum(serialize = "serde_with::TimestampMilliSeconds<i64>")]
+  Milliseconds,
+  #[strum(serialize = "serde_with::TimestampSecondsWithFrac<f64>")]
+  FractionalSeconds,
+  #[strum(serialize = "serde_with::TimestampMilliSecondsWithFrac<f64>")]
+  FractionalMilliseconds,
+}
+
+impl EpochTimestamp {
+  /// Returns the encoding for an epoch unit, using fractional adapters for `number` schemas.
+  #[must_use]
+  pub fn new(millis: bool, fractional: bool) -> Self {
+    match (millis, fractional) {
+      (false, false) => Self::Seconds,
+      (true, false) => Self::Milliseconds,
+      (false, true) => Self::FractionalSeconds,
+      (true, true) => Self::FractionalMilliseconds,
+    }
+  }
+}
+
 /// Field-level `#[serde_as]` attribute for custom serialization.
 ///
 /// Used for non-exploded array query parameters that need custom
-/// serialization via separator-based string conversion, or for
-/// custom type overrides via the `--customize` CLI flag.
+/// serialization via separator-based string conversion, for
+/// custom type overrides via the `--customize` CLI flag, or for
+/// lenient deserialization adapters.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SerdeAsFieldAttr {
   SeparatedList {
@@ -54,6 +84,17 @@ pub enum SerdeAsFieldAttr {
     optional: bool,
     is_array: bool,
   },
+  /// Accepts quoted numbers via `PickFirst<(_, DisplayFromStr)>` when `coerce_number`
+  /// is set, and treats `""` as `None` via `EmptyStringAsNone` when `optional` is set.
+  Lenient { coerce_number: bool, optional: bool },
+  /// Accepts numeric values sent either as JSON numbers or as quoted strings.
+  CoerceNumber { optional: bool, is_array: bool },
+  /// Encodes `chrono::DateTime<chrono::Utc>` values as numeric epoch timestamps.
+  Timestamp {
+    epoch: EpochTimestamp,
+    optional: bool,
+    is_array: bool,
+  },
 }
 
 impl ToTokens for SerdeAsFieldAttr {
@@ -80,6 +121,41 @@ impl ToTokens for SerdeAsFieldAttr {
         }
         result
       }
+      Self::Lenient {
+        coerce_number,
+        optional,
+      } => {
+        let inner = coerce_number.then_some("serde_with::PickFirst<(_, serde_with::DisplayFromStr)>");
+        match (optional, inner) {
+          (true, Some(inner)) => format!("oas3_gen_support::EmptyStringAsNone<{inner}>"),
+          (true, None) => "oas3_gen_support::EmptyStringAsNone".to_string(),
+          (false, inner) => inner.unwrap_or("_").to_string(),
+        }
+      }
+      Self::CoerceNumber { optional, is_array } => {
+        let mut result = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>".to_string();
+        if *is_array {
+          result = format!("Vec<{result}>");
+        }
+        if *optional {
+          result = format!("Option<{result}>");
+        }
+        result
+      }
+      Self::Timestamp {
+        epoch,
+        optional,
+        is_array,
+      } => {
+        let mut result = epoch.as_ref().to_string();
+        if *is_array {
+          result = format!("Vec<{result}>");
+        }
+        if *optional {
+          result = format!("Option<{result}>");
+        }
+        result
+      }
     };
     let attr = quote! { #[serde_as(as = #type_str)] };
     tokens.extend(attr);
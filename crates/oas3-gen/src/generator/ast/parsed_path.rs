@@ -4,7 +4,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use strum::Display;
 
-use super::{FieldDef, ParameterLocation, tokens::FieldNameToken};
+use super::{FieldDef, ObjectStyle, ParameterLocation, tokens::FieldNameToken};
 
 #[derive(Debug, Clone, PartialEq, Eq, Display)]
 pub enum PathParseError {
@@ -28,6 +28,11 @@ pub enum PathSegment {
     format: String,
     params: Vec<FieldNameToken>,
   },
+  /// A parameter holding an array, or an object when `object_style` is set.
+  Serialized {
+    field: FieldNameToken,
+    object_style: Option<ObjectStyle>,
+  },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,7 +50,7 @@ impl PathSegment {
   pub fn to_axum_segment(&self) -> String {
     match self {
       Self::Literal(lit) => lit.clone(),
-      Self::Param(field) => format!("{{{}}}", field.as_str()),
+      Self::Param(field) | Self::Serialized { field, .. } => format!("{{{}}}", field.as_str()),
       Self::Mixed { format, params } => {
         let mut result = format.clone();
         for param in params {
@@ -170,6 +175,10 @@ impl ToTokens for PathSegment {
     let segment_tokens = match self {
       PathSegment::Literal(lit) => quote! { .push(#lit) },
       PathSegment::Param(field) => quote! { .push(&request.path.#field.to_string()) },
+      PathSegment::Serialized { field, object_style } => {
+        let value = simple_style_value(&quote! { &request.path.#field }, *object_style);
+        quote! { .push(&#value) }
+      }
       PathSegment::Mixed { format, params } => {
         let args = params.iter().map(|f| quote! { request.path.#f });
         quote! { .push(&format!(#format, #(#args),*)) }
@@ -179,6 +188,18 @@ impl ToTokens for PathSegment {
   }
 }
 
+/// Writes the array or object parameter value at `accessor` as a string in OpenAPI
+/// `simple` style.
+pub fn simple_style_value(accessor: &TokenStream, object_style: Option<ObjectStyle>) -> TokenStream {
+  match object_style {
+    None => quote! { #accessor.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",") },
+    Some(style) => {
+      let explode = style == ObjectStyle::Exploded;
+      quote! { oas3_gen_support::simple_style(#accessor, #explode) }
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct ParsedPath {
   pub segments: Vec<PathSegment>,
@@ -191,9 +212,12 @@ impl ParsedPath {
       .split_once('?')
       .map_or((path, None), |(p, q)| (p, Some(q.to_string())));
 
-    let param_map = parameters
+    let path_params = parameters
       .iter()
       .filter(|p| matches!(p.parameter_location, Some(ParameterLocation::Path)))
+      .collect::<Vec<_>>();
+    let param_map = path_params
+      .iter()
       .filter_map(|p| p.original_name.as_deref().map(|name| (name, &p.name)))
       .collect::<HashMap<_, _>>();
 
@@ -201,7 +225,23 @@ impl ParsedPath {
       .trim_start_matches('/')
       .split('/')
       .filter(|s| !s.is_empty())
-      .map(|segment| PathSegment::parse(segment, &param_map))
+      .map(|segment| {
+        let segment = PathSegment::parse(segment, &param_map)?;
+        let PathSegment::Param(field) = &segment else {
+          return Ok(segment);
+        };
+        let serialized = path_params
+          .iter()
+          .find(|p| p.name == *field)
+          .filter(|p| p.rust_type.is_array || p.object_style.is_some());
+        Ok(match serialized {
+          Some(param) => PathSegment::Serialized {
+            field: field.clone(),
+            object_style: param.object_style,
+          },
+          None => segment,
+        })
+      })
       .collect::<Result<Vec<_>, _>>()?;
 
     Ok(Self { segments, query_string })
@@ -223,6 +263,14 @@ impl ParsedPath {
       })
   }
 
+  /// Returns `true` when any segment is filled from a path parameter.
+  pub fn has_params(&self) -> bool {
+    self
+      .segments
+      .iter()
+      .any(|segment| !matches!(segment, PathSegment::Literal(_)))
+  }
+
   #[cfg(test)]
   pub fn has_mixed_segments(&self) -> bool {
     self.segments.iter().any(PathSegment::is_mixed)
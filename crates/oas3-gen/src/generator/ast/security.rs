@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+
+use oas3::{
+  Spec,
+  spec::{Operation, SecurityRequirement, SecurityScheme},
+};
+
+use crate::generator::{ast::FieldNameToken, naming::identifiers::ensure_unique};
+
+/// Where an API key credential is attached to a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyLocation {
+  Header,
+  Query,
+  Cookie,
+}
+
+/// How a security scheme attaches its credential to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecuritySchemeKind {
+  ApiKey { name: String, location: ApiKeyLocation },
+  Bearer,
+  Basic,
+  ClientCredentials { token_url: String },
+}
+
+/// A security scheme from `components.securitySchemes` that the generated client can apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecuritySchemeDef {
+  /// The scheme name as declared in the spec, used by security requirements.
+  pub name: String,
+  pub field: FieldNameToken,
+  pub kind: SecuritySchemeKind,
+  pub description: Option<String>,
+}
+
+impl SecuritySchemeDef {
+  /// Collects the supported security schemes declared by `spec`.
+  ///
+  /// OAuth2 schemes with a `clientCredentials` flow fetch their own tokens; other
+  /// OAuth2 and OpenID Connect schemes accept a caller-supplied bearer token.
+  /// Mutual TLS and HTTP schemes other than `bearer` and `basic` are skipped.
+  pub fn collect(spec: &Spec) -> Vec<Self> {
+    let Some(components) = &spec.components else {
+      return vec![];
+    };
+
+    let mut used_fields = BTreeSet::new();
+    components
+      .security_schemes
+      .iter()
+      .filter_map(|(name, scheme)| {
+        let scheme = scheme.resolve(spec).ok()?;
+        let (kind, description) = Self::kind(&scheme)?;
+        let field = ensure_unique(FieldNameToken::from_raw(name).as_str(), &used_fields);
+        used_fields.insert(field.clone());
+        Some(Self {
+          name: name.clone(),
+          field: FieldNameToken::new(field),
+          kind,
+          description: description.clone(),
+        })
+      })
+      .collect()
+  }
+
+  fn kind(scheme: &SecurityScheme) -> Option<(SecuritySchemeKind, &Option<String>)> {
+    let kind = match scheme {
+      SecurityScheme::ApiKey {
+        name,
+        location,
+        description,
+      } => {
+        let location = match location.as_str() {
+          "header" => ApiKeyLocation::Header,
+          "query" => ApiKeyLocation::Query,
+          "cookie" => ApiKeyLocation::Cookie,
+          _ => return None,
+        };
+        (
+          SecuritySchemeKind::ApiKey {
+            name: name.clone(),
+            location,
+          },
+          description,
+        )
+      }
+      SecurityScheme::Http {
+        scheme, description, ..
+      } if scheme.eq_ignore_ascii_case("bearer") => (SecuritySchemeKind::Bearer, description),
+      SecurityScheme::Http {
+        scheme, description, ..
+      } if scheme.eq_ignore_ascii_case("basic") => (SecuritySchemeKind::Basic, description),
+      SecurityScheme::OAuth2 { flows, description } => match &flows.client_credentials {
+        Some(flow) => (
+          SecuritySchemeKind::ClientCredentials {
+            token_url: flow.token_url.to_string(),
+          },
+          description,
+        ),
+        None => (SecuritySchemeKind::Bearer, description),
+      },
+      SecurityScheme::OpenIdConnect { description, .. } => (SecuritySchemeKind::Bearer, description),
+      SecurityScheme::Http { .. } | SecurityScheme::MutualTls { .. } => return None,
+    };
+    Some(kind)
+  }
+}
+
+/// Resolves the security requirements that apply to `operation`.
+///
+/// Each inner list is one alternative whose schemes must all be applied together.
+/// Operation-level requirements replace the top-level ones. Alternatives naming a
+/// scheme the client cannot apply are dropped, as are empty alternatives, since a
+/// request without usable credentials is always sent unauthenticated.
+pub(crate) fn operation_security(
+  spec: &Spec,
+  operation: &Operation,
+  schemes: &[SecuritySchemeDef],
+) -> Vec<Vec<String>> {
+  let requirements = if operation.security.is_empty() {
+    &spec.security
+  } else {
+    &operation.security
+  };
+
+  requirements
+    .iter()
+    .map(|SecurityRequirement(alternative)| alternative.keys().cloned().collect::<Vec<_>>())
+    .filter(|alternative| {
+      !alternative.is_empty() && alternative.iter().all(|name| schemes.iter().any(|s| &s.name == name))
+    })
+    .collect()
+}
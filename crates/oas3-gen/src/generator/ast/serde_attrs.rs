@@ -11,9 +11,11 @@ pub enum SerdeAttribute {
   Default,
   DenyUnknownFields,
   Flatten,
+  Other,
   Rename(String),
   Skip,
   SkipDeserializing,
+  SkipSerializing,
   Untagged,
 }
 
@@ -24,9 +26,11 @@ impl ToTokens for SerdeAttribute {
       Self::Default => quote! { default },
       Self::DenyUnknownFields => quote! { deny_unknown_fields },
       Self::Flatten => quote! { flatten },
+      Self::Other => quote! { other },
       Self::Rename(name) => quote! { rename = #name },
       Self::Skip => quote! { skip },
       Self::SkipDeserializing => quote! { skip_deserializing },
+      Self::SkipSerializing => quote! { skip_serializing },
       Self::Untagged => quote! { untagged },
     };
     tokens.extend(attr);
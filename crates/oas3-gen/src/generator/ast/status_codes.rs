@@ -4,8 +4,32 @@ use std::{
   str::FromStr,
 };
 
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, quote};
+
 use crate::generator::ast::EnumVariantToken;
 
+/// How a documented status is handled, rendered as `oas3_gen_support::ResponseClass`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseClass {
+  Success,
+  ClientError,
+  ServerError,
+  Retryable,
+}
+
+impl ToTokens for ResponseClass {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let variant = match self {
+      Self::Success => quote! { Success },
+      Self::ClientError => quote! { ClientError },
+      Self::ServerError => quote! { ServerError },
+      Self::Retryable => quote! { Retryable },
+    };
+    tokens.extend(quote! { oas3_gen_support::ResponseClass::#variant });
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub enum StatusCodeToken {
   // 1xx
@@ -162,6 +186,55 @@ impl StatusCodeToken {
     matches!(self, Self::Default)
   }
 
+  /// Returns `true` for `429 Too Many Requests`.
+  pub const fn is_rate_limited(self) -> bool {
+    matches!(self, Self::TooManyRequests429)
+  }
+
+  /// Returns `true` for `4XX` and `5XX` codes and ranges.
+  pub const fn is_error(self) -> bool {
+    match self {
+      Self::ClientError4XX | Self::ServerError5XX => true,
+      _ => matches!(self.code(), Some(400..=599)),
+    }
+  }
+
+  /// Returns the class of the status by its code, with the statuses
+  /// `oas3_gen_support::ResponseClass` treats as retryable, or `None` for `default`.
+  pub const fn response_class(self) -> Option<ResponseClass> {
+    match self {
+      Self::Default => None,
+      Self::ClientError4XX => Some(ResponseClass::ClientError),
+      Self::ServerError5XX => Some(ResponseClass::ServerError),
+      Self::Informational1XX | Self::Success2XX | Self::Redirection3XX => Some(ResponseClass::Success),
+      _ => match self.code() {
+        Some(408 | 425 | 429 | 502..=504) => Some(ResponseClass::Retryable),
+        Some(500..) => Some(ResponseClass::ServerError),
+        Some(400..) => Some(ResponseClass::ClientError),
+        _ => Some(ResponseClass::Success),
+      },
+    }
+  }
+
+  /// Returns the `u16` pattern matching the status: the code, or the range of a `1XX`
+  /// through `5XX` wildcard.
+  pub fn code_pattern(self) -> Option<TokenStream> {
+    let range = |first: u16| {
+      let (first, last) = (Literal::u16_unsuffixed(first), Literal::u16_unsuffixed(first + 99));
+      quote! { #first..=#last }
+    };
+    match self {
+      Self::Informational1XX => Some(range(100)),
+      Self::Success2XX => Some(range(200)),
+      Self::Redirection3XX => Some(range(300)),
+      Self::ClientError4XX => Some(range(400)),
+      Self::ServerError5XX => Some(range(500)),
+      _ => self
+        .code()
+        .map(|code| Literal::u16_unsuffixed(code).into_token_stream()),
+    }
+  }
+
   pub const fn is_success(self) -> bool {
     matches!(
       self,
@@ -24,6 +24,22 @@ fn vendor_xml_suffix() {
   );
 }
 
+#[test]
+fn yaml_types() {
+  for content_type in [
+    "application/yaml",
+    "application/x-yaml",
+    "text/yaml",
+    "application/vnd.oai.openapi+yaml",
+  ] {
+    assert_eq!(
+      ContentCategory::from_content_type(content_type),
+      ContentCategory::Yaml,
+      "{content_type}"
+    );
+  }
+}
+
 #[test]
 fn standard_types() {
   assert_eq!(
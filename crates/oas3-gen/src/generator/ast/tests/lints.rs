@@ -0,0 +1,38 @@
+use quote::ToTokens;
+
+use crate::generator::ast::{GlobalLintsNode, LintConfig};
+
+#[test]
+fn test_lint_config_adds_custom_allows_and_denies() {
+  let config = LintConfig::builder()
+    .allow(vec!["clippy::pedantic".to_string()])
+    .deny(vec!["unsafe_code".to_string()])
+    .build();
+
+  let code = GlobalLintsNode::default()
+    .with_config(&config)
+    .to_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("# ! [allow (dead_code)]"),
+    "should keep the built-in allows"
+  );
+  assert!(code.contains("# ! [allow (clippy :: pedantic)]"));
+  assert!(code.contains("# ! [deny (unsafe_code)]"));
+}
+
+#[test]
+fn test_lint_config_preamble() {
+  assert_eq!(LintConfig::default().preamble(), "");
+  assert!(LintConfig::default().include_source_path);
+
+  let config = LintConfig::builder()
+    .license_header("SPDX-License-Identifier: MIT\n\nCopyright Example\n".to_string())
+    .generated_marker(true)
+    .build();
+  assert_eq!(
+    config.preamble(),
+    "// @generated\n// SPDX-License-Identifier: MIT\n//\n// Copyright Example\n\n"
+  );
+}
@@ -1,7 +1,9 @@
 mod content_category;
 mod documentation;
+mod lints;
 mod outer_attrs;
 mod parsed_path;
+mod security;
 mod status_codes;
 mod types;
 mod validation_attrs;
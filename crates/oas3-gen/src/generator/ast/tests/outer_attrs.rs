@@ -1,6 +1,6 @@
 use quote::ToTokens;
 
-use crate::generator::ast::{SerdeAsFieldAttr, SerdeAsSeparator};
+use crate::generator::ast::{EpochTimestamp, SerdeAsFieldAttr, SerdeAsSeparator};
 
 #[test]
 fn test_serde_as_separated_list_non_optional() {
@@ -90,3 +90,66 @@ fn test_serde_as_custom_override_with_module_path() {
     "# [serde_as (as = \"my_crate::types::IsoDateTime\")]"
   );
 }
+
+#[test]
+fn test_serde_as_coerce_number() {
+  let pick_first = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>";
+  let cases = [
+    (false, false, pick_first.to_string()),
+    (true, false, format!("Option<{pick_first}>")),
+    (false, true, format!("Vec<{pick_first}>")),
+    (true, true, format!("Option<Vec<{pick_first}>>")),
+  ];
+
+  for (optional, is_array, expected) in cases {
+    let attr = SerdeAsFieldAttr::CoerceNumber { optional, is_array };
+    assert_eq!(
+      attr.to_token_stream().to_string(),
+      format!("# [serde_as (as = {expected:?})]"),
+      "optional={optional}, is_array={is_array}"
+    );
+  }
+}
+
+#[test]
+fn test_serde_as_epoch_timestamp() {
+  let cases = [
+    (
+      EpochTimestamp::Seconds,
+      false,
+      false,
+      "serde_with::TimestampSeconds<i64>",
+    ),
+    (
+      EpochTimestamp::Milliseconds,
+      true,
+      false,
+      "Option<serde_with::TimestampMilliSeconds<i64>>",
+    ),
+    (
+      EpochTimestamp::FractionalSeconds,
+      false,
+      true,
+      "Vec<serde_with::TimestampSecondsWithFrac<f64>>",
+    ),
+    (
+      EpochTimestamp::FractionalMilliseconds,
+      true,
+      true,
+      "Option<Vec<serde_with::TimestampMilliSecondsWithFrac<f64>>>",
+    ),
+  ];
+
+  for (epoch, optional, is_array, expected) in cases {
+    let attr = SerdeAsFieldAttr::Timestamp {
+      epoch,
+      optional,
+      is_array,
+    };
+    assert_eq!(
+      attr.to_token_stream().to_string(),
+      format!("# [serde_as (as = {expected:?})]"),
+      "{epoch:?}"
+    );
+  }
+}
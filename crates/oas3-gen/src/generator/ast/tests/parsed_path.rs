@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::generator::ast::{
-  FieldDef, ParameterLocation, ParsedPath, PathParseError, PathSegment, TypeRef, tokens::FieldNameToken,
+  FieldDef, ObjectStyle, ParameterLocation, ParsedPath, PathParseError, PathSegment, TypeRef, tokens::FieldNameToken,
 };
 
 #[test]
@@ -320,3 +320,40 @@ fn to_axum_path_ignores_query_string() {
   let path = ParsedPath::parse("/v1/messages?beta=true", &[]).unwrap();
   assert_eq!(path.to_axum_path(), "/v1/messages");
 }
+
+#[test]
+fn parse_marks_array_and_object_params_as_serialized() {
+  let ids = FieldDef {
+    rust_type: TypeRef::new("i64").with_vec(),
+    ..make_path_param("ids", "ids")
+  };
+  let filter = FieldDef {
+    rust_type: TypeRef::new("serde_json::Value"),
+    object_style: Some(ObjectStyle::Exploded),
+    ..make_path_param("filter", "filter")
+  };
+  let params = vec![ids, filter, make_path_param("name", "name")];
+  let path = ParsedPath::parse("/items/{ids}/{filter}/{name}", &params).unwrap();
+
+  assert_eq!(
+    path.segments[1..],
+    [
+      PathSegment::Serialized {
+        field: FieldNameToken::new("ids"),
+        object_style: None,
+      },
+      PathSegment::Serialized {
+        field: FieldNameToken::new("filter"),
+        object_style: Some(ObjectStyle::Exploded),
+      },
+      PathSegment::Param(FieldNameToken::new("name")),
+    ]
+  );
+  assert_eq!(path.to_axum_path(), "/items/{ids}/{filter}/{name}");
+
+  let tokens = quote::ToTokens::to_token_stream(&path.segments[2]).to_string();
+  assert_eq!(
+    tokens,
+    ". push (& oas3_gen_support :: simple_style (& request . path . filter , true))"
+  );
+}
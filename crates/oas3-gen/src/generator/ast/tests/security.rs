@@ -0,0 +1,103 @@
+use serde_json::json;
+
+use crate::generator::ast::{ApiKeyLocation, SecuritySchemeDef, SecuritySchemeKind, operation_security};
+
+fn security_spec() -> oas3::Spec {
+  serde_json::from_value(json!({
+    "openapi": "3.1.0",
+    "info": { "title": "Secure", "version": "1.0.0" },
+    "security": [{ "apiKey": [] }],
+    "paths": {
+      "/inherited": { "get": { "responses": {} } },
+      "/either": {
+        "get": {
+          "security": [{ "oauth": ["read"] }, { "bearer": [], "apiKey": [] }],
+          "responses": {}
+        }
+      },
+      "/optional": { "get": { "security": [{}], "responses": {} } },
+      "/mtls": { "get": { "security": [{ "mtls": [] }, { "basic": [] }], "responses": {} } }
+    },
+    "components": {
+      "securitySchemes": {
+        "apiKey": { "type": "apiKey", "name": "X-API-Key", "in": "header", "description": "Portal key" },
+        "session": { "type": "apiKey", "name": "sid", "in": "cookie" },
+        "basic": { "type": "http", "scheme": "basic" },
+        "bearer": { "type": "http", "scheme": "Bearer", "bearerFormat": "JWT" },
+        "digest": { "type": "http", "scheme": "digest" },
+        "oauth": {
+          "type": "oauth2",
+          "flows": { "clientCredentials": { "tokenUrl": "https://auth.test/token", "scopes": {} } }
+        },
+        "implicit": {
+          "type": "oauth2",
+          "flows": { "implicit": { "authorizationUrl": "https://auth.test/authorize", "scopes": {} } }
+        },
+        "oidc": { "type": "openIdConnect", "openIdConnectUrl": "https://auth.test/.well-known/openid-configuration" },
+        "mtls": { "type": "mutualTLS" }
+      }
+    }
+  }))
+  .unwrap()
+}
+
+#[test]
+fn test_collect_supported_security_schemes() {
+  let schemes = SecuritySchemeDef::collect(&security_spec());
+
+  let expected = [
+    (
+      "apiKey",
+      "api_key",
+      SecuritySchemeKind::ApiKey {
+        name: "X-API-Key".to_string(),
+        location: ApiKeyLocation::Header,
+      },
+    ),
+    (
+      "session",
+      "session",
+      SecuritySchemeKind::ApiKey {
+        name: "sid".to_string(),
+        location: ApiKeyLocation::Cookie,
+      },
+    ),
+    ("basic", "basic", SecuritySchemeKind::Basic),
+    ("bearer", "bearer", SecuritySchemeKind::Bearer),
+    (
+      "oauth",
+      "oauth",
+      SecuritySchemeKind::ClientCredentials {
+        token_url: "https://auth.test/token".to_string(),
+      },
+    ),
+    ("implicit", "implicit", SecuritySchemeKind::Bearer),
+    ("oidc", "oidc", SecuritySchemeKind::Bearer),
+  ];
+
+  let actual = schemes
+    .iter()
+    .map(|scheme| (scheme.name.as_str(), scheme.field.as_str(), scheme.kind.clone()))
+    .collect::<Vec<_>>();
+  assert_eq!(actual, expected);
+  assert_eq!(schemes[0].description.as_deref(), Some("Portal key"));
+}
+
+#[test]
+fn test_operation_security_resolution() {
+  let spec = security_spec();
+  let schemes = SecuritySchemeDef::collect(&spec);
+
+  let cases: [(&str, Vec<Vec<&str>>); 4] = [
+    ("/inherited", vec![vec!["apiKey"]]),
+    ("/either", vec![vec!["oauth"], vec!["bearer", "apiKey"]]),
+    ("/optional", vec![]),
+    ("/mtls", vec![vec!["basic"]]),
+  ];
+
+  for (path, expected) in cases {
+    let operation = spec.paths.as_ref().unwrap()[path].get.as_ref().unwrap();
+    let actual = operation_security(&spec, operation, &schemes);
+    assert_eq!(actual, expected, "failed for {path}");
+  }
+}
@@ -104,6 +104,35 @@ fn test_validation_attribute_range_display() {
   );
 }
 
+#[test]
+fn test_validation_attribute_decimal_range_display() {
+  let attr = ValidationAttribute::Range {
+    primitive: RustPrimitive::Decimal,
+    min: Some(serde_json::json!(10.5).as_number().unwrap().clone()),
+    max: Some(serde_json::json!(1e3).as_number().unwrap().clone()),
+    exclusive_min: Some(serde_json::json!(-2.25).as_number().unwrap().clone()),
+    exclusive_max: None,
+  };
+  assert_eq!(
+    attr.to_token_stream().to_string(),
+    "custom (function = \"oas3_gen_support::decimal_min::<105, 1>\") , \
+     custom (function = \"oas3_gen_support::decimal_max::<1000, 0>\") , \
+     custom (function = \"oas3_gen_support::decimal_exclusive_min::<{ -225 }, 2>\")"
+  );
+
+  let attr_big = ValidationAttribute::Range {
+    primitive: RustPrimitive::BigDecimal,
+    min: None,
+    max: None,
+    exclusive_min: None,
+    exclusive_max: Some(serde_json::json!(100).as_number().unwrap().clone()),
+  };
+  assert_eq!(
+    attr_big.to_token_stream().to_string(),
+    "custom (function = \"oas3_gen_support::decimal_exclusive_max::<100, 0>\")"
+  );
+}
+
 #[test]
 fn test_validation_attribute_nested_display() {
   let attr = ValidationAttribute::Nested;
@@ -57,6 +57,11 @@ impl TypeRef {
     self
   }
 
+  /// Returns `true` if the rendered type implements `Copy`.
+  pub fn is_copy(&self) -> bool {
+    !self.boxed && !self.is_array && self.base_type.is_copy()
+  }
+
   pub fn unwrap_option(mut self) -> Self {
     self.nullable = false;
     self
@@ -256,6 +261,12 @@ pub enum RustPrimitive {
   #[serde(rename = "uuid::Uuid")]
   #[strum(serialize = "uuid::Uuid")]
   Uuid,
+  #[serde(rename = "oas3_gen_support::Decimal")]
+  #[strum(serialize = "oas3_gen_support::Decimal")]
+  Decimal,
+  #[serde(rename = "bigdecimal::BigDecimal")]
+  #[strum(serialize = "bigdecimal::BigDecimal")]
+  BigDecimal,
   #[serde(rename = "serde_json::Value")]
   #[strum(serialize = "serde_json::Value")]
   Value,
@@ -267,10 +278,54 @@ pub enum RustPrimitive {
 }
 
 impl RustPrimitive {
+  /// Returns `true` for primitives that implement `Copy`.
+  pub fn is_copy(&self) -> bool {
+    self.is_numeric()
+      || matches!(
+        self,
+        RustPrimitive::Bool
+          | RustPrimitive::StaticStr
+          | RustPrimitive::Date
+          | RustPrimitive::DateTime
+          | RustPrimitive::Time
+          | RustPrimitive::Duration
+          | RustPrimitive::Uuid
+          | RustPrimitive::Decimal
+          | RustPrimitive::Unit
+      )
+  }
+
+  /// Returns `true` for primitives that implement `Eq`, `Hash`, and `Ord`.
+  pub fn is_totally_ordered(&self) -> bool {
+    !matches!(self, RustPrimitive::F32 | RustPrimitive::F64 | RustPrimitive::Value)
+  }
+
+  /// Returns `true` for arbitrary-precision decimals, which serialize as strings.
+  pub fn is_decimal(&self) -> bool {
+    matches!(self, RustPrimitive::Decimal | RustPrimitive::BigDecimal)
+  }
+
   pub fn is_float(&self) -> bool {
     matches!(self, RustPrimitive::F32 | RustPrimitive::F64)
   }
 
+  pub fn is_numeric(&self) -> bool {
+    self.is_float() || self.is_integer()
+  }
+
+  pub fn is_integer(&self) -> bool {
+    self.is_unsigned_integer()
+      || matches!(
+        self,
+        RustPrimitive::I8
+          | RustPrimitive::I16
+          | RustPrimitive::I32
+          | RustPrimitive::I64
+          | RustPrimitive::I128
+          | RustPrimitive::Isize
+      )
+  }
+
   pub fn is_unsigned_integer(&self) -> bool {
     matches!(
       self,
@@ -290,6 +345,32 @@ impl RustPrimitive {
     format.and_then(RustPrimitive::from_format).unwrap_or(default)
   }
 
+  /// Returns the 64-bit integer of the same signedness for narrower integers,
+  /// or `self` otherwise.
+  #[must_use]
+  pub fn widened(self) -> Self {
+    match self {
+      RustPrimitive::I8 | RustPrimitive::I16 | RustPrimitive::I32 | RustPrimitive::Isize => RustPrimitive::I64,
+      RustPrimitive::U8 | RustPrimitive::U16 | RustPrimitive::U32 | RustPrimitive::Usize => RustPrimitive::U64,
+      _ => self,
+    }
+  }
+
+  /// Returns the unsigned integer of the same width for signed integers, or `self`
+  /// otherwise.
+  #[must_use]
+  pub fn unsigned(self) -> Self {
+    match self {
+      RustPrimitive::I8 => RustPrimitive::U8,
+      RustPrimitive::I16 => RustPrimitive::U16,
+      RustPrimitive::I32 => RustPrimitive::U32,
+      RustPrimitive::I64 => RustPrimitive::U64,
+      RustPrimitive::I128 => RustPrimitive::U128,
+      RustPrimitive::Isize => RustPrimitive::Usize,
+      _ => self,
+    }
+  }
+
   pub fn from_format(format: &str) -> Option<Self> {
     match format {
       "int8" => Some(RustPrimitive::I8),
@@ -358,6 +439,11 @@ impl RustPrimitive {
     if self.is_float() {
       return self.format_number(num);
     }
+    if self.is_unsigned_integer()
+      && let Some(value) = num.as_u64()
+    {
+      return render_unsigned_integer(self, value);
+    }
     if let Some(value) = num.as_i64() {
       return render_integer(self, value);
     }
@@ -383,6 +469,7 @@ impl RustPrimitive {
       RustPrimitive::DateTime => format_datetime_constructor(s),
       RustPrimitive::Time => format_time_constructor(s),
       RustPrimitive::Uuid => format!("uuid::Uuid::parse_str(\"{}\")?", escape_string_literal(s)),
+      RustPrimitive::Decimal | RustPrimitive::BigDecimal => format!("\"{}\".parse()?", escape_string_literal(s)),
       _ => format!("\"{}\"", escape_string_literal(s)),
     }
   }
@@ -460,6 +547,8 @@ impl std::str::FromStr for RustPrimitive {
       "chrono::NaiveTime" => RustPrimitive::Time,
       "chrono::Duration" => RustPrimitive::Duration,
       "uuid::Uuid" => RustPrimitive::Uuid,
+      "oas3_gen_support::Decimal" => RustPrimitive::Decimal,
+      "bigdecimal::BigDecimal" => RustPrimitive::BigDecimal,
       "serde_json::Value" => RustPrimitive::Value,
       "()" => RustPrimitive::Unit,
       custom => RustPrimitive::Custom(custom.into()),
@@ -51,13 +51,34 @@ pub enum ValidationAttribute {
     exclusive_max: Option<Number>,
   },
   Regex(String),
+  /// Values a schema's `not` rules out. Codegen renders it as a [`Self::Custom`] call
+  /// of a function generated for the field.
+  Excludes(Vec<serde_json::Value>),
+  /// A `custom` validator calling the function at this path.
+  Custom(String),
 }
 
 impl ValidationAttribute {
+  /// Builds a `range` validator from the schema's bounds.
+  ///
+  /// Lower bounds an unsigned integer always satisfies, such as `minimum: 0`, are
+  /// dropped, and no validator is built when no bound remains.
   pub fn range(schema: &ObjectSchema, type_ref: &TypeRef) -> Option<Self> {
-    let exclusive_min = schema.exclusive_minimum.clone();
+    let unsigned = type_ref.base_type.is_unsigned_integer();
+    let always_met = |bound: &Number, inclusive: bool| {
+      bound
+        .as_f64()
+        .is_some_and(|b| if inclusive { b <= 0.0 } else { b < 0.0 })
+    };
+    let exclusive_min = schema
+      .exclusive_minimum
+      .clone()
+      .filter(|bound| !(unsigned && always_met(bound, false)));
     let exclusive_max = schema.exclusive_maximum.clone();
-    let min = schema.minimum.clone();
+    let min = schema
+      .minimum
+      .clone()
+      .filter(|bound| !(unsigned && always_met(bound, true)));
     let max = schema.maximum.clone();
 
     if exclusive_min.is_none() && exclusive_max.is_none() && min.is_none() && max.is_none() {
@@ -123,7 +144,8 @@ impl PartialEq for ValidationAttribute {
           && compare_numbers(emin1.as_ref(), emin2.as_ref())
           && compare_numbers(emax1.as_ref(), emax2.as_ref())
       }
-      (Self::Regex(s1), Self::Regex(s2)) => s1 == s2,
+      (Self::Regex(s1), Self::Regex(s2)) | (Self::Custom(s1), Self::Custom(s2)) => s1 == s2,
+      (Self::Excludes(v1), Self::Excludes(v2)) => v1 == v2,
       _ => false,
     }
   }
@@ -149,6 +171,24 @@ fn compare_numbers(n1: Option<&Number>, n2: Option<&Number>) -> bool {
   }
 }
 
+/// Splits a schema bound such as `10.5` or `1e3` into the `MANTISSA × 10^-SCALE` const
+/// arguments of the `oas3_gen_support::decimal_*` validators, or `None` when it does not fit.
+fn decimal_parts(bound: &Number) -> Option<(i128, u32)> {
+  let text = bound.to_string();
+  let (number, exponent) = match text.split_once(['e', 'E']) {
+    Some((number, exponent)) => (number, exponent.parse::<i32>().ok()?),
+    None => (text.as_str(), 0),
+  };
+  let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+  let fraction = fraction.trim_end_matches('0');
+  let mantissa = format!("{integer}{fraction}").parse::<i128>().ok()?;
+  let scale = i32::try_from(fraction.len()).ok()? - exponent;
+  match u32::try_from(scale) {
+    Ok(scale) => Some((mantissa, scale)),
+    Err(_) => Some((mantissa.checked_mul(10i128.checked_pow(scale.unsigned_abs())?)?, 0)),
+  }
+}
+
 impl ToTokens for ValidationAttribute {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let attr = match self {
@@ -156,6 +196,8 @@ impl ToTokens for ValidationAttribute {
       Self::Url => quote! { url },
       Self::Nested => quote! { nested },
       Self::Regex(path) => quote! { regex(path = #path) },
+      Self::Excludes(_) => quote! {},
+      Self::Custom(path) => quote! { custom(function = #path) },
       Self::Length { min, max } => {
         let min_part = min.map(|m| {
           let lit = render_unsigned_integer(&RustPrimitive::U64, m)
@@ -176,6 +218,32 @@ impl ToTokens for ValidationAttribute {
           (None, None) => quote! { length() },
         }
       }
+      Self::Range {
+        primitive,
+        min,
+        max,
+        exclusive_min,
+        exclusive_max,
+      } if primitive.is_decimal() => {
+        let parts = [
+          ("decimal_min", min),
+          ("decimal_max", max),
+          ("decimal_exclusive_min", exclusive_min),
+          ("decimal_exclusive_max", exclusive_max),
+        ]
+        .into_iter()
+        .filter_map(|(function, bound)| {
+          let (mantissa, scale) = decimal_parts(bound.as_ref()?)?;
+          let mantissa = if mantissa < 0 {
+            format!("{{ {mantissa} }}")
+          } else {
+            mantissa.to_string()
+          };
+          let path = format!("oas3_gen_support::{function}::<{mantissa}, {scale}>");
+          Some(quote! { custom(function = #path) })
+        });
+        quote! { #(#parts),* }
+      }
       Self::Range {
         primitive,
         min,
@@ -25,13 +25,88 @@ pub(crate) fn generate_docs_for_field(field: &FieldDef) -> Documentation {
     docs.push(format!("- Example: `{display_example}`"));
   }
 
-  if let Some(ref multiple_of) = field.multiple_of {
-    docs.push(format!("Validation: Must be a multiple of {multiple_of}"));
+  let constraints = constraint_summaries(field);
+  if !constraints.is_empty() {
+    docs.push(format!("- Constraints: {}", constraints.join(", ")));
   }
 
   docs
 }
 
+/// Summarizes a field's validation rules, `multipleOf`, and default value as short
+/// phrases such as `1–64 chars` for its `- Constraints:` doc line.
+fn constraint_summaries(field: &FieldDef) -> Vec<String> {
+  let unit = |count: &u64| match (field.rust_type.is_array, *count == 1) {
+    (true, true) => "item",
+    (true, false) => "items",
+    (false, true) => "char",
+    (false, false) => "chars",
+  };
+  let mut summaries = field
+    .validation_attrs
+    .iter()
+    .filter_map(|attr| match attr {
+      ValidationAttribute::Email => Some("email".to_string()),
+      ValidationAttribute::Url => Some("URL".to_string()),
+      ValidationAttribute::Nested => None,
+      ValidationAttribute::Length { min, max } => match (min, max) {
+        (Some(min), Some(max)) if min == max => Some(format!("exactly {min} {}", unit(min))),
+        (Some(min), Some(max)) => Some(format!("{min}–{max} {}", unit(max))),
+        (Some(min), None) => Some(format!("at least {min} {}", unit(min))),
+        (None, Some(max)) => Some(format!("at most {max} {}", unit(max))),
+        (None, None) => None,
+      },
+      ValidationAttribute::Range {
+        min,
+        max,
+        exclusive_min,
+        exclusive_max,
+        ..
+      } => {
+        let bounds = [
+          min.as_ref().map(|n| format!(">= {n}")),
+          exclusive_min.as_ref().map(|n| format!("> {n}")),
+          max.as_ref().map(|n| format!("<= {n}")),
+          exclusive_max.as_ref().map(|n| format!("< {n}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        match (min, max, exclusive_min, exclusive_max) {
+          (Some(min), Some(max), None, None) => Some(format!("{min}–{max}")),
+          _ if bounds.is_empty() => None,
+          _ => Some(bounds.join(" and ")),
+        }
+      }
+      ValidationAttribute::Regex(pattern) => Some(format!("pattern `{pattern}`")),
+      ValidationAttribute::Excludes(values) => Some(format!(
+        "not {}",
+        values
+          .iter()
+          .map(|value| match value {
+            serde_json::Value::String(value) => format!("`{value}`"),
+            value => format!("`{value}`"),
+          })
+          .collect::<Vec<_>>()
+          .join(", ")
+      )),
+      ValidationAttribute::Custom(_) => None,
+    })
+    .collect::<Vec<_>>();
+
+  if let Some(ref multiple_of) = field.multiple_of {
+    summaries.push(format!("multiple of {multiple_of}"));
+  }
+
+  match &field.default_value {
+    None | Some(serde_json::Value::Null) => {}
+    Some(serde_json::Value::String(value)) => summaries.push(format!("default `{value}`")),
+    Some(value) => summaries.push(format!("default `{value}`")),
+  }
+
+  summaries
+}
+
 #[derive(Clone, Debug)]
 pub struct DeriveAttribute<T>(BTreeSet<T>);
 
@@ -56,6 +131,7 @@ pub(crate) fn generate_derives_from_slice<'a>(derives: impl IntoIterator<Item =
   let derive_idents = derives
     .into_iter()
     .map(quote::ToTokens::to_token_stream)
+    .filter(|tokens| !tokens.is_empty())
     .collect::<Vec<_>>();
 
   if derive_idents.is_empty() {
@@ -169,6 +245,15 @@ pub(crate) fn generate_doc_hidden_attr(hidden: bool) -> TokenStream {
 }
 
 pub(crate) fn generate_field_default_attr(field: &FieldDef) -> TokenStream {
+  if let Some(variant) = &field.default_variant {
+    let enum_type = &field.rust_type.base_type;
+    let default_expr = quote! { #enum_type::#variant };
+    return if field.rust_type.nullable {
+      quote! { #[default(Some(#default_expr))] }
+    } else {
+      quote! { #[default(#default_expr)] }
+    };
+  }
   field.default_value.as_ref().map_or_else(
     || quote! {},
     |default_value| {
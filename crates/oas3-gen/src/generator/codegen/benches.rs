@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use serde_json::Value;
+
+use super::example_tests::raw_string;
+use crate::generator::{
+  ast::{DiscriminatedEnumDef, RustPrimitive, RustType, SerdeMode, StructKind},
+  naming::identifiers::to_rust_field_name,
+};
+
+/// File holding the benchmarks, named after the `[[bench]]` target it is meant for.
+pub(crate) const BENCHES_FILE: &str = "serde.rs";
+/// Number of structs, ranked by field count, that get a benchmark.
+const LARGEST_STRUCTS: usize = 5;
+
+/// A type benchmarked by (de)serializing `payload`, an example from the spec.
+#[derive(Clone, Debug)]
+pub(crate) struct Benchmark {
+  type_name: String,
+  payload: Value,
+}
+
+impl Benchmark {
+  /// One benchmark for each of the largest schema structs with an example, then one for
+  /// each discriminated enum with an example of its own or of one of its variants.
+  ///
+  /// `examples` maps Rust type names to the example of the schema they came from.
+  pub(crate) fn select(rust_types: &[RustType], examples: &BTreeMap<String, Value>) -> Vec<Self> {
+    let mut structs = rust_types
+      .iter()
+      .filter_map(|rust_type| match rust_type {
+        RustType::Struct(def) if def.kind == StructKind::Schema && def.serde_mode == SerdeMode::Both => {
+          let type_name = def.name.to_string();
+          let payload = examples.get(&type_name)?.clone();
+          Some((def.fields.len(), Self { type_name, payload }))
+        }
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    structs.sort_by(|(a_fields, a), (b_fields, b)| b_fields.cmp(a_fields).then_with(|| a.type_name.cmp(&b.type_name)));
+
+    let enums = rust_types.iter().filter_map(|rust_type| match rust_type {
+      RustType::DiscriminatedEnum(def) if def.serde_mode == SerdeMode::Both => Self::for_enum(def, examples),
+      _ => None,
+    });
+
+    structs
+      .into_iter()
+      .take(LARGEST_STRUCTS)
+      .map(|(_, benchmark)| benchmark)
+      .chain(enums)
+      .collect()
+  }
+
+  /// Uses the enum's own example, or else the first variant example that is an object,
+  /// tagged with the variant's discriminator value.
+  fn for_enum(def: &DiscriminatedEnumDef, examples: &BTreeMap<String, Value>) -> Option<Self> {
+    let type_name = def.name.to_string();
+    let payload = examples.get(&type_name).cloned().or_else(|| {
+      def.variants.iter().find_map(|variant| {
+        let RustPrimitive::Custom(variant_type) = &variant.type_name.base_type else {
+          return None;
+        };
+        let mut payload = examples.get(variant_type.as_ref())?.as_object()?.clone();
+        let tag = variant.discriminator_values.first()?;
+        payload.insert(def.discriminator_field.clone(), Value::String(tag.clone()));
+        Some(Value::Object(payload))
+      })
+    })?;
+    Some(Self { type_name, payload })
+  }
+
+  fn function_name(&self) -> syn::Ident {
+    format_ident!("{}", to_rust_field_name(&self.type_name))
+  }
+
+  fn function(&self) -> TokenStream {
+    let function_name = self.function_name();
+    let ty = format_ident!("{}", self.type_name);
+    let group = &self.type_name;
+    let payload = raw_string(&self.payload.to_string());
+    let parse = format!("example should deserialize as `{group}`");
+    let serialize = format!("`{group}` should serialize");
+    quote! {
+      fn #function_name(c: &mut Criterion) {
+        const PAYLOAD: &str = #payload;
+        let value = serde_json::from_str::<#ty>(PAYLOAD).expect(#parse);
+        let mut group = c.benchmark_group(#group);
+        group.bench_function("serialize", |b| {
+          b.iter(|| serde_json::to_vec(black_box(&value)).expect(#serialize));
+        });
+        group.bench_function("deserialize", |b| {
+          b.iter(|| serde_json::from_str::<#ty>(black_box(PAYLOAD)).expect(#parse));
+        });
+        group.finish();
+      }
+    }
+  }
+}
+
+/// Generates a criterion benchmark target (de)serializing an example payload of each
+/// selected type with `serde_json`.
+///
+/// Generated types are imported from `types_path`, the crate they live in, since a
+/// bench target is a crate root of its own.
+#[derive(Clone, Debug)]
+pub(crate) struct BenchesFragment<'a> {
+  benchmarks: &'a [Benchmark],
+  types_path: TokenStream,
+}
+
+impl<'a> BenchesFragment<'a> {
+  pub(crate) fn new(benchmarks: &'a [Benchmark], types_path: &syn::Path) -> Self {
+    Self {
+      benchmarks,
+      types_path: types_path.to_token_stream(),
+    }
+  }
+}
+
+impl ToTokens for BenchesFragment<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let types_path = &self.types_path;
+    let functions = self.benchmarks.iter().map(Benchmark::function);
+    let names = self.benchmarks.iter().map(Benchmark::function_name);
+    tokens.extend(quote! {
+      use std::hint::black_box;
+
+      use criterion::{Criterion, criterion_group, criterion_main};
+      use #types_path::*;
+
+      #(#functions)*
+
+      criterion_group!(benches, #(#names),*);
+      criterion_main!(benches);
+    });
+  }
+}
@@ -1,13 +1,23 @@
 use anyhow::Context as _;
 use http::Method;
+use indexmap::IndexMap;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 use syn::LitStr;
 
-use super::Visibility;
-use crate::generator::ast::{
-  ClientRootNode, ContentCategory, EnumToken, FieldDef, FieldNameToken, MultipartFieldInfo, OperationBody,
-  OperationInfo, OperationKind, ParameterLocation, ParsedPath, StructToken,
+use super::{
+  Visibility,
+  route_tests::RouteTestsFragment,
+  templates::{ClientTemplates, render},
+};
+use crate::generator::{
+  ast::{
+    ApiKeyLocation, ClientRootNode, ContentCategory, DefaultAtom, EnumToken, EnumVariantToken, FieldDef,
+    FieldNameToken, MultipartFieldInfo, NextPage, OperationBody, OperationInfo, OperationKind, PageCursor, Pagination,
+    ParameterLocation, ParsedPath, ResponseMediaType, SecuritySchemeDef, SecuritySchemeKind, StructToken, TypeRef,
+    constants::MediaTypeRef,
+  },
+  naming::constants::{API_ERROR_ALIAS, BATCH_METHOD, BATCH_METHOD_FALLBACK, PAGE_LINK_FIELD},
 };
 
 #[derive(Clone, Debug)]
@@ -65,7 +75,22 @@ impl ToTokens for MultipartFieldFragment {
     let ident = &self.field.name;
     let name = self.field.name.as_str();
 
-    let ts = if self.field.nullable {
+    let ts = if self.field.is_file {
+      match (self.field.is_array, self.field.nullable) {
+        (false, false) => quote! { form = form.part(#name, body.#ident.to_part()?); },
+        (false, true) => quote! {
+          if let Some(file) = &body.#ident { form = form.part(#name, file.to_part()?); }
+        },
+        (true, false) => quote! {
+          for file in &body.#ident { form = form.part(#name, file.to_part()?); }
+        },
+        (true, true) => quote! {
+          if let Some(files) = &body.#ident {
+            for file in files { form = form.part(#name, file.to_part()?); }
+          }
+        },
+      }
+    } else if self.field.nullable {
       let part = self.to_part(&quote! { val });
       quote! { if let Some(val) = &body.#ident { form = form.part(#name, #part); } }
     } else {
@@ -124,36 +149,63 @@ impl ToTokens for HeaderParamsFragment {
   }
 }
 
+/// Sets the request's `Content-Type` from a `media_types` constant, when the body has one.
+#[derive(Clone, Debug)]
+struct ContentTypeHeaderFragment(Option<MediaTypeRef>);
+
+impl ToTokens for ContentTypeHeaderFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if let Some(content_type) = &self.0 {
+      let path = content_type.path();
+      tokens.extend(quote! { .header(reqwest::header::CONTENT_TYPE, #path) });
+    }
+  }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct XmlBodyFragment {
   field: FieldNameToken,
   optional: bool,
+  content_type: Option<MediaTypeRef>,
 }
 
 impl XmlBodyFragment {
-  pub(crate) fn new(field: FieldNameToken, optional: bool) -> Self {
-    Self { field, optional }
+  pub(crate) fn new(field: FieldNameToken, optional: bool, content_type: Option<MediaTypeRef>) -> Self {
+    Self {
+      field,
+      optional,
+      content_type,
+    }
   }
 
   pub(crate) fn needs_conditional(&self) -> bool {
     self.optional
   }
+
+  fn apply(&self) -> TokenStream {
+    let content_type = ContentTypeHeaderFragment(self.content_type.clone());
+    quote! {
+      let xml_string = body.to_string();
+      req_builder = req_builder #content_type.body(xml_string);
+    }
+  }
 }
 
 impl ToTokens for XmlBodyFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let field = &self.field;
+    let content_type = ContentTypeHeaderFragment(self.content_type.clone());
 
     let ts = if self.optional {
+      let apply = self.apply();
       quote! {
         if let Some(body) = request.#field.as_ref() {
-          let xml_string = body.to_string();
-          req_builder = req_builder.header("Content-Type", "application/xml").body(xml_string);
+          #apply
         }
       }
     } else {
       quote! {
-        .header("Content-Type", "application/xml")
+        #content_type
         .body(request.#field.to_string())
       }
     };
@@ -162,6 +214,56 @@ impl ToTokens for XmlBodyFragment {
   }
 }
 
+/// Serializes the request body as YAML, since reqwest has no YAML body builder.
+#[derive(Clone, Debug)]
+pub(crate) struct YamlBodyFragment {
+  field: FieldNameToken,
+  optional: bool,
+  content_type: Option<MediaTypeRef>,
+}
+
+impl YamlBodyFragment {
+  pub(crate) fn new(field: FieldNameToken, optional: bool, content_type: Option<MediaTypeRef>) -> Self {
+    Self {
+      field,
+      optional,
+      content_type,
+    }
+  }
+
+  pub(crate) fn needs_conditional(&self) -> bool {
+    self.optional
+  }
+
+  fn apply(&self) -> TokenStream {
+    let content_type = ContentTypeHeaderFragment(self.content_type.clone());
+    quote! { req_builder = req_builder #content_type.body(oas3_gen_support::to_yaml(body)?); }
+  }
+}
+
+impl ToTokens for YamlBodyFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let field = &self.field;
+    let content_type = ContentTypeHeaderFragment(self.content_type.clone());
+
+    let ts = if self.optional {
+      let apply = self.apply();
+      quote! {
+        if let Some(body) = request.#field.as_ref() {
+          #apply
+        }
+      }
+    } else {
+      quote! {
+        #content_type
+        .body(oas3_gen_support::to_yaml(&request.#field)?)
+      }
+    };
+
+    tokens.extend(ts);
+  }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct MultipartFallbackFragment;
 
@@ -274,14 +376,16 @@ pub(crate) struct SimpleBodyFragment {
   field: FieldNameToken,
   optional: bool,
   kind: BodyChainKind,
+  content_type: Option<MediaTypeRef>,
 }
 
 impl SimpleBodyFragment {
-  fn json(field: FieldNameToken, optional: bool) -> Self {
+  fn json(field: FieldNameToken, optional: bool, content_type: Option<MediaTypeRef>) -> Self {
     Self {
       field,
       optional,
       kind: BodyChainKind::Json,
+      content_type,
     }
   }
 
@@ -290,37 +394,46 @@ impl SimpleBodyFragment {
       field,
       optional,
       kind: BodyChainKind::Form,
+      content_type: None,
     }
   }
 
-  fn text(field: FieldNameToken, optional: bool) -> Self {
+  fn text(field: FieldNameToken, optional: bool, content_type: Option<MediaTypeRef>) -> Self {
     Self {
       field,
       optional,
       kind: BodyChainKind::Text,
+      content_type,
     }
   }
 
-  fn binary(field: FieldNameToken, optional: bool) -> Self {
+  fn binary(field: FieldNameToken, optional: bool, content_type: Option<MediaTypeRef>) -> Self {
     Self {
       field,
       optional,
       kind: BodyChainKind::Binary,
+      content_type,
     }
   }
 
   fn make_chain(&self, expr: &TokenStream) -> TokenStream {
+    let content_type = ContentTypeHeaderFragment(self.content_type.clone());
     match self.kind {
-      BodyChainKind::Json => quote! { .json(#expr) },
+      BodyChainKind::Json => quote! { #content_type.body(oas3_gen_support::to_json_bytes(#expr)?) },
       BodyChainKind::Form => quote! { .form(#expr) },
-      BodyChainKind::Text => quote! { .body((#expr).to_string()) },
-      BodyChainKind::Binary => quote! { .body((#expr).clone()) },
+      BodyChainKind::Text => quote! { #content_type.body((#expr).to_string()) },
+      BodyChainKind::Binary => quote! { #content_type.body((#expr).clone()) },
     }
   }
 
   pub(crate) fn needs_conditional(&self) -> bool {
     self.optional
   }
+
+  fn apply(&self) -> TokenStream {
+    let chain = self.make_chain(&quote! { body });
+    quote! { req_builder = req_builder #chain; }
+  }
 }
 
 impl ToTokens for SimpleBodyFragment {
@@ -328,10 +441,10 @@ impl ToTokens for SimpleBodyFragment {
     let field = &self.field;
 
     let ts = if self.optional {
-      let chain = self.make_chain(&quote! { body });
+      let apply = self.apply();
       quote! {
         if let Some(body) = request.#field.as_ref() {
-          req_builder = req_builder #chain;
+          #apply
         }
       }
     } else {
@@ -347,7 +460,9 @@ pub(crate) enum RequestBodyFragment {
   None,
   Simple(SimpleBodyFragment),
   Xml(XmlBodyFragment),
+  Yaml(YamlBodyFragment),
   Multipart(MultipartFormFragment),
+  Variants(BodyVariantsFragment),
 }
 
 impl RequestBodyFragment {
@@ -356,15 +471,23 @@ impl RequestBodyFragment {
       return Self::None;
     };
 
+    if !body.media_variants.is_empty() {
+      return Self::Variants(BodyVariantsFragment::new(body));
+    }
+
     let field = body.field_name.clone();
     let optional = body.optional;
+    let content_type = body.content_type_header();
 
     match body.content_category {
-      ContentCategory::Json => Self::Simple(SimpleBodyFragment::json(field, optional)),
+      ContentCategory::Json => Self::Simple(SimpleBodyFragment::json(field, optional, content_type)),
       ContentCategory::FormUrlEncoded => Self::Simple(SimpleBodyFragment::form(field, optional)),
-      ContentCategory::Text | ContentCategory::EventStream => Self::Simple(SimpleBodyFragment::text(field, optional)),
-      ContentCategory::Binary => Self::Simple(SimpleBodyFragment::binary(field, optional)),
-      ContentCategory::Xml => Self::Xml(XmlBodyFragment::new(field, optional)),
+      ContentCategory::Text | ContentCategory::EventStream => {
+        Self::Simple(SimpleBodyFragment::text(field, optional, content_type))
+      }
+      ContentCategory::Binary => Self::Simple(SimpleBodyFragment::binary(field, optional, content_type)),
+      ContentCategory::Xml => Self::Xml(XmlBodyFragment::new(field, optional, content_type)),
+      ContentCategory::Yaml => Self::Yaml(YamlBodyFragment::new(field, optional, content_type)),
       ContentCategory::Multipart => Self::Multipart(MultipartFormFragment::new(body.clone())),
     }
   }
@@ -374,7 +497,20 @@ impl RequestBodyFragment {
       Self::None => false,
       Self::Simple(s) => s.needs_conditional(),
       Self::Xml(x) => x.needs_conditional(),
-      Self::Multipart(_) => true,
+      Self::Yaml(y) => y.needs_conditional(),
+      Self::Multipart(_) | Self::Variants(_) => true,
+    }
+  }
+
+  /// Returns the statements that encode a present `body` binding onto `req_builder`.
+  fn apply(&self) -> TokenStream {
+    match self {
+      Self::None => TokenStream::new(),
+      Self::Simple(s) => s.apply(),
+      Self::Xml(x) => x.apply(),
+      Self::Yaml(y) => y.apply(),
+      Self::Multipart(m) => m.inner_logic(),
+      Self::Variants(v) => v.apply(),
     }
   }
 }
@@ -385,11 +521,87 @@ impl ToTokens for RequestBodyFragment {
       Self::None => {}
       Self::Simple(s) => s.to_tokens(tokens),
       Self::Xml(x) => x.to_tokens(tokens),
+      Self::Yaml(y) => y.to_tokens(tokens),
       Self::Multipart(m) => m.to_tokens(tokens),
+      Self::Variants(v) => v.to_tokens(tokens),
+    }
+  }
+}
+
+/// Encodes a body enum according to the media type of the variant supplied.
+#[derive(Clone, Debug)]
+pub(crate) struct BodyVariantsFragment {
+  field: FieldNameToken,
+  optional: bool,
+  enum_name: EnumToken,
+  arms: Vec<(EnumVariantToken, RequestBodyFragment)>,
+}
+
+impl BodyVariantsFragment {
+  fn new(body: &OperationBody) -> Self {
+    let enum_name = EnumToken::new(
+      body
+        .body_type
+        .as_ref()
+        .map(TypeRef::unboxed_base_type_name)
+        .unwrap_or_default(),
+    );
+    let arms = body
+      .media_variants
+      .iter()
+      .map(|variant| {
+        let fragment = RequestBodyFragment::new(Some(&body.for_variant(variant)));
+        (variant.name.clone(), fragment)
+      })
+      .collect();
+    Self {
+      field: body.field_name.clone(),
+      optional: body.optional,
+      enum_name,
+      arms,
+    }
+  }
+
+  fn apply(&self) -> TokenStream {
+    let enum_name = &self.enum_name;
+    let arms = self.arms.iter().map(|(variant, fragment)| {
+      let apply = fragment.apply();
+      quote! {
+        #enum_name::#variant(body) => {
+          #apply
+        }
+      }
+    });
+    quote! {
+      match body {
+        #(#arms)*
+      }
     }
   }
 }
 
+impl ToTokens for BodyVariantsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let field = &self.field;
+    let apply = self.apply();
+
+    let ts = if self.optional {
+      quote! {
+        if let Some(body) = request.#field.as_ref() {
+          #apply
+        }
+      }
+    } else {
+      quote! {
+        let body = &request.#field;
+        #apply
+      }
+    };
+
+    tokens.extend(ts);
+  }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct UrlConstructionFragment {
   path: ParsedPath,
@@ -428,7 +640,7 @@ enum ResponseKind {
     request_type: String,
   },
   Typed {
-    resp_type: syn::Type,
+    resp_type: Box<syn::Type>,
     category: ContentCategory,
   },
   Raw,
@@ -458,28 +670,34 @@ impl std::fmt::Debug for ResponseKind {
 #[derive(Clone, Debug)]
 pub(crate) struct ResponseParsingFragment {
   kind: ResponseKind,
+  streaming: bool,
 }
 
 impl ResponseParsingFragment {
   pub(crate) fn new(op: &OperationInfo) -> Self {
+    let streaming = ResponseMediaType::has_event_stream(&op.response_media_types);
+
     if let Some(enum_token) = &op.response_enum {
       return Self {
         kind: ResponseKind::Enum {
           enum_token: enum_token.clone(),
           request_type: op.request_type.as_ref().unwrap().to_string(),
         },
+        streaming,
       };
     }
 
     let Some(resp_type_str) = &op.response_type else {
       return Self {
         kind: ResponseKind::Raw,
+        streaming,
       };
     };
 
     let Ok(resp_ty) = syn::parse_str::<syn::Type>(resp_type_str).context("parsing response type") else {
       return Self {
         kind: ResponseKind::Raw,
+        streaming,
       };
     };
 
@@ -490,9 +708,23 @@ impl ResponseParsingFragment {
 
     Self {
       kind: ResponseKind::Typed {
-        resp_type: resp_ty,
+        resp_type: Box::new(resp_ty),
         category,
       },
+      streaming,
+    }
+  }
+
+  /// Returns `true` if the response body is fully deserialized, so the raw bytes
+  /// can be buffered and retained without changing streaming semantics.
+  pub(crate) fn supports_raw_body(&self) -> bool {
+    if self.streaming {
+      return false;
+    }
+    match &self.kind {
+      ResponseKind::Enum { .. } => true,
+      ResponseKind::Typed { category, .. } => matches!(category, ContentCategory::Json | ContentCategory::Text),
+      ResponseKind::Raw => false,
     }
   }
 
@@ -530,11 +762,154 @@ impl ResponseParsingFragment {
 pub(crate) struct ClientMethodFragment {
   op: OperationInfo,
   visibility: Visibility,
+  retain_raw_body: bool,
+  vcr: bool,
+  retry: bool,
+  hooks: bool,
+  last_response: bool,
+  url_helper: bool,
+  errors: Option<ClientErrorFragment>,
+  templates: ClientTemplates,
 }
 
 impl ClientMethodFragment {
   pub(crate) fn new(op: OperationInfo, visibility: Visibility) -> Self {
-    Self { op, visibility }
+    Self {
+      op,
+      visibility,
+      retain_raw_body: false,
+      vcr: false,
+      retry: false,
+      hooks: false,
+      last_response: false,
+      url_helper: false,
+      errors: None,
+      templates: ClientTemplates::default(),
+    }
+  }
+
+  pub(crate) fn with_raw_body(mut self) -> Self {
+    self.retain_raw_body = true;
+    self
+  }
+
+  pub(crate) fn with_vcr(mut self) -> Self {
+    self.vcr = true;
+    self
+  }
+
+  pub(crate) fn with_retry(mut self) -> Self {
+    self.retry = true;
+    self
+  }
+
+  /// Sends through the client's request hooks and adds an `{operation}_with_options`
+  /// variant of the method taking per-call `oas3_gen_support::RequestOptions`.
+  pub(crate) fn with_request_hooks(mut self) -> Self {
+    self.hooks = true;
+    self
+  }
+
+  /// Sends through the client so it records the response's metadata.
+  pub(crate) fn with_last_response(mut self) -> Self {
+    self.last_response = true;
+    self
+  }
+
+  /// Builds the request URL with the method's [`Self::url_helper`] instead of inline.
+  pub(crate) fn with_url_helper(mut self) -> Self {
+    self.url_helper = true;
+    self
+  }
+
+  /// Returns the client's error enum instead of `anyhow::Error`.
+  pub(crate) fn with_typed_errors(mut self, errors: ClientErrorFragment) -> Self {
+    self.errors = Some(errors);
+    self
+  }
+
+  /// Renders the method and its body through the user's method and error handling templates.
+  pub(crate) fn with_templates(mut self, templates: ClientTemplates) -> Self {
+    self.templates = templates;
+    self
+  }
+
+  fn retains_raw_body(&self, response: &ResponseParsingFragment) -> bool {
+    self.retain_raw_body && response.supports_raw_body()
+  }
+
+  /// Returns the success type of the method's result.
+  fn return_type(&self, response: &ResponseParsingFragment) -> TokenStream {
+    let success_type = response.success_type();
+    if self.retains_raw_body(response) {
+      quote! { oas3_gen_support::WithRawBody<#success_type> }
+    } else {
+      success_type
+    }
+  }
+
+  /// Returns the method's error type.
+  fn error_type(&self) -> TokenStream {
+    match &self.errors {
+      Some(errors) => errors.name.to_token_stream(),
+      None => quote! { anyhow::Error },
+    }
+  }
+
+  /// Returns `true` when requests go through the client's `send_request` method.
+  fn sends_through_client(&self) -> bool {
+    self.vcr || self.retry || self.hooks || self.last_response
+  }
+
+  /// Returns the expression sending `req_builder` and awaiting its response.
+  fn send_call(&self) -> TokenStream {
+    if self.sends_through_client() {
+      quote! { self.send_request(req_builder).await? }
+    } else {
+      quote! { req_builder.send().await? }
+    }
+  }
+
+  fn authorize_call(&self) -> Option<TokenStream> {
+    if self.op.security.is_empty() {
+      return None;
+    }
+    let alternatives = self.op.security.iter().map(|schemes| quote! { &[#(#schemes),*] });
+    Some(quote! {
+      let req_builder = self.authorize(req_builder, &[#(#alternatives),*]).await?;
+    })
+  }
+
+  fn deprecated_attr(&self) -> Option<TokenStream> {
+    if !self.op.deprecated {
+      return None;
+    }
+    let note = self.op.replaced_by.as_ref().map_or_else(
+      || "deprecated by the API".to_string(),
+      |replacement| format!("use `{replacement}` instead"),
+    );
+    Some(quote! { #[deprecated(note = #note)] })
+  }
+
+  /// Generates a private `{operation}_url` method building the operation's URL from
+  /// its request, so the URL can be tested without sending anything.
+  pub(crate) fn url_helper(&self) -> Option<TokenStream> {
+    let request_ident = format_ident!("{}", self.op.request_type.as_ref()?);
+    let helper_name = format_ident!("{}_url", self.op.stable_id);
+    let doc = format!(" Builds the request URL for [`Self::{}`].", self.op.stable_id);
+    let request = if self.op.path.has_params() {
+      format_ident!("request")
+    } else {
+      format_ident!("_request")
+    };
+    let url_construction = UrlConstructionFragment::new(self.op.path.clone());
+    Some(quote! {
+      #[doc = #doc]
+      fn #helper_name(&self, #request: &#request_ident) -> anyhow::Result<Url> {
+        #url_construction
+        Ok(url)
+      }
+    })
   }
 
   pub(crate) fn generate(&self) -> anyhow::Result<TokenStream> {
@@ -542,116 +917,983 @@ impl ClientMethodFragment {
       anyhow::bail!("operation `{}` is missing request type", self.op.operation_id);
     };
 
-    let method_name = format_ident!("{}", self.op.stable_id);
-    let doc_attrs = &self.op.documentation;
-
     let http_init = HttpInitFragment::new(self.op.method.clone());
-    let url_construction = UrlConstructionFragment::new(self.op.path.clone());
+    let url_construction = if self.url_helper {
+      let helper_name = format_ident!("{}_url", self.op.stable_id);
+      quote! { let url = self.#helper_name(&request)?; }
+    } else {
+      UrlConstructionFragment::new(self.op.path.clone()).to_token_stream()
+    };
     let query_chain = QueryParamsFragment::new(&self.op.parameters);
     let header_chain = HeaderParamsFragment::new(&self.op.parameters);
     let body_fragment = RequestBodyFragment::new(self.op.body.as_ref());
     let response_fragment = ResponseParsingFragment::new(&self.op);
 
-    let vis = self.visibility.to_tokens();
-    let return_type = response_fragment.success_type();
-    let parse_block = response_fragment.parse_body();
+    let (url_construction, http_init) = if self.op.page_link {
+      let page_link = format_ident!("{PAGE_LINK_FIELD}");
+      let url_construction = quote! {
+        let req_builder = match &request.#page_link {
+          Some(link) => self.client.get(self.base_url.join(link).context("resolving page link")?),
+          None => {
+            #url_construction
+            #http_init #query_chain
+          }
+        };
+      };
+      (url_construction, quote! { req_builder })
+    } else {
+      (url_construction, quote! { #http_init #query_chain })
+    };
 
-    let request_chain = if body_fragment.needs_conditional() {
+    let retains_raw_body = self.retains_raw_body(&response_fragment);
+    let return_type = self.return_type(&response_fragment);
+    let parse_block = if retains_raw_body {
+      let parse_body = response_fragment.parse_body();
       quote! {
-        let mut req_builder = #http_init #query_chain #header_chain;
+        oas3_gen_support::WithRawBody::capture(response, |response| async move { #parse_body }).await
+      }
+    } else {
+      response_fragment.parse_body()
+    };
+
+    let authorize = self.authorize_call();
+    let compress = self.op.request_compression.then(|| {
+      quote! { let req_builder = self.compress_request(req_builder)?; }
+    });
+    let request_chain = if self.sends_through_client() || authorize.is_some() || compress.is_some() {
+      let builder = if body_fragment.needs_conditional() {
+        quote! {
+          let mut req_builder = #http_init #header_chain;
+          #body_fragment
+        }
+      } else {
+        quote! { let req_builder = #http_init #header_chain #body_fragment; }
+      };
+      let options = self
+        .hooks
+        .then(|| quote! { let req_builder = options.apply(req_builder)?; });
+      let send = self.send_call();
+      quote! {
+        #builder
+        #options
+        #compress
+        #authorize
+        let response = #send;
+      }
+    } else if body_fragment.needs_conditional() {
+      quote! {
+        let mut req_builder = #http_init #header_chain;
         #body_fragment
         let response = req_builder.send().await?;
       }
     } else {
       quote! {
-        let response = #http_init #query_chain #header_chain #body_fragment
+        let response = #http_init #header_chain #body_fragment
           .send()
           .await?;
       }
     };
 
-    Ok(quote! {
-      #doc_attrs
-      #vis async fn #method_name(&self, request: #request_ident) -> anyhow::Result<#return_type> {
+    let Some(errors) = &self.errors else {
+      let body = quote! {
         request.validate().context("parameter validation")?;
         #url_construction
         #request_chain
         #parse_block
-      }
-    })
-  }
-}
+      };
+      return Ok(self.method(&request_ident, &quote! { anyhow::Result<#return_type> }, &body));
+    };
 
-#[derive(Clone, Debug)]
-pub(crate) struct ClientStructFragment {
-  name: StructToken,
-  visibility: Visibility,
-}
+    let error_enum = &errors.name;
+    let body = match self.error_arms(errors) {
+      Some(error_arms) if !retains_raw_body => quote! {
+        let result: anyhow::Result<(http::StatusCode, #return_type)> = async {
+          request.validate().context("parameter validation")?;
+          #url_construction
+          #request_chain
+          let status = response.status();
+          let response = #request_ident::parse_response(response).await?;
+          Ok((status, response))
+        }
+        .await;
+        let (status, response) = result.map_err(#error_enum::from)?;
+        match response {
+          #(#error_arms,)*
+          response => Ok(response),
+        }
+      },
+      _ => quote! {
+        let result: anyhow::Result<#return_type> = async {
+          request.validate().context("parameter validation")?;
+          #url_construction
+          #request_chain
+          #parse_block
+        }
+        .await;
+        result.map_err(#error_enum::from)
+      },
+    };
 
-impl ClientStructFragment {
-  pub(crate) fn new(name: StructToken, visibility: Visibility) -> Self {
-    Self { name, visibility }
+    Ok(self.method(&request_ident, &quote! { Result<#return_type, #error_enum> }, &body))
   }
-}
 
-impl ToTokens for ClientStructFragment {
-  fn to_tokens(&self, tokens: &mut TokenStream) {
-    let name = &self.name;
+  /// Wraps `body` in the operation's method. With request hooks, `body` goes into the
+  /// `{operation}_with_options` variant and the plain method calls it with default options.
+  fn method(&self, request_ident: &proc_macro2::Ident, result: &TokenStream, body: &TokenStream) -> TokenStream {
+    let method_name = format_ident!("{}", self.op.stable_id);
+    let doc_attrs = &self.op.documentation;
+    let deprecated = self.deprecated_attr();
     let vis = self.visibility.to_tokens();
+    let operation_id = LitStr::new(&self.op.operation_id, Span::call_site());
+    let body = match &self.templates.error_handling {
+      Some(template) => render(
+        template,
+        &[
+          ("body", body.clone()),
+          ("result", result.clone()),
+          ("error", self.error_type()),
+          ("operation_id", operation_id.to_token_stream()),
+        ],
+      ),
+      None => body.clone(),
+    };
 
-    let ts = quote! {
-      #[derive(Debug, Clone)]
-      #vis struct #name {
-        #vis client: Client,
-        #vis base_url: Url,
+    let with_options = format_ident!("{}_with_options", self.op.stable_id);
+    let (name, params, docs) = if self.hooks {
+      let doc = format!(" Calls [`Self::{method_name}`] with `options` applied to the request.");
+      (
+        &with_options,
+        quote! { request: #request_ident, options: oas3_gen_support::RequestOptions, },
+        quote! { #[doc = #doc] #deprecated },
+      )
+    } else {
+      (
+        &method_name,
+        quote! { request: #request_ident },
+        quote! { #doc_attrs #deprecated },
+      )
+    };
+    let default = quote! {
+      #docs
+      #vis async fn #name(&self, #params) -> #result {
+        #body
+      }
+    };
+    let method = match &self.templates.method {
+      Some(template) => {
+        let http_method = LitStr::new(self.op.method.as_str(), Span::call_site());
+        render(
+          template,
+          &[
+            ("default", default),
+            ("docs", docs),
+            ("vis", vis.clone()),
+            ("name", name.to_token_stream()),
+            ("params", params),
+            ("result", result.clone()),
+            ("body", body),
+            ("operation_id", operation_id.to_token_stream()),
+            ("http_method", http_method.to_token_stream()),
+          ],
+        )
       }
+      None => default,
     };
+    if !self.hooks {
+      return method;
+    }
 
-    tokens.extend(ts);
+    quote! {
+      #doc_attrs
+      #deprecated
+      #vis async fn #method_name(&self, request: #request_ident) -> #result {
+        self.#with_options(request, oas3_gen_support::RequestOptions::default()).await
+      }
+
+      #method
+    }
+  }
+
+  /// Builds the match arms turning error responses into the client's error enum, or
+  /// `None` when the operation has no error responses to map.
+  fn error_arms(&self, errors: &ClientErrorFragment) -> Option<Vec<TokenStream>> {
+    let response_enum = self.op.response_enum.as_ref()?;
+    if self.op.error_response_types.is_empty() {
+      return None;
+    }
+
+    let error_enum = &errors.name;
+    let arms = self
+      .op
+      .error_response_types
+      .iter()
+      .map(|error| {
+        let variant = &error.variant;
+        let Some(error_variant) = error.body.as_ref().and_then(|body| errors.body_variant(body)) else {
+          let pattern = if error.named_fields {
+            quote! { #response_enum::#variant { .. } }
+          } else if error.body.is_some() {
+            quote! { #response_enum::#variant(_) }
+          } else {
+            quote! { #response_enum::#variant }
+          };
+          return quote! { #pattern => Err(#error_enum::UnexpectedStatus(status)) };
+        };
+        if error.named_fields {
+          quote! { #response_enum::#variant { body, .. } => Err(#error_enum::#error_variant { status, body }) }
+        } else {
+          quote! { #response_enum::#variant(body) => Err(#error_enum::#error_variant { status, body }) }
+        }
+      })
+      .collect();
+    Some(arms)
   }
 }
 
+/// Implements `tower::Service` over an operation's request type for the client, so
+/// timeouts, retries, and load shedding can be layered with standard middleware.
 #[derive(Clone, Debug)]
-pub(crate) struct ClientDefaultImplFragment {
-  name: StructToken,
+pub(crate) struct TowerServiceFragment {
+  client: StructToken,
+  method: ClientMethodFragment,
+  send: bool,
 }
 
-impl ClientDefaultImplFragment {
-  pub(crate) fn new(name: StructToken) -> Self {
-    Self { name }
+impl TowerServiceFragment {
+  pub(crate) fn new(client: StructToken, method: ClientMethodFragment) -> Self {
+    Self {
+      client,
+      method,
+      send: true,
+    }
+  }
+
+  /// Drops the `Send` bound from the boxed future, for runtimes whose requests are
+  /// bound to one thread.
+  pub(crate) fn with_local_futures(mut self) -> Self {
+    self.send = false;
+    self
   }
 }
 
-impl ToTokens for ClientDefaultImplFragment {
+impl ToTokens for TowerServiceFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    let name = &self.name;
+    let op = &self.method.op;
+    let Some(request_ident) = op.request_type.as_ref().map(|r| format_ident!("{r}")) else {
+      return;
+    };
+    let client = &self.client;
+    let method_name = format_ident!("{}", op.stable_id);
+    let response_type = self.method.return_type(&ResponseParsingFragment::new(op));
+    let error_type = self.method.error_type();
+    let send = self.send.then(|| quote! { + Send });
+    let allow_deprecated = op.deprecated.then(|| quote! { #[allow(deprecated)] });
 
-    let ts = quote! {
-      impl Default for #name {
-        fn default() -> Self {
-          Self::new()
+    quote! {
+      #allow_deprecated
+      impl tower::Service<#request_ident> for #client {
+        type Response = #response_type;
+        type Error = #error_type;
+        type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> #send>>;
+
+        fn poll_ready(&mut self, _cx: &mut std::task::Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+          std::task::Poll::Ready(Ok(()))
         }
-      }
-    };
 
-    tokens.extend(ts);
-  }
+        fn call(&mut self, request: #request_ident) -> Self::Future {
+          let client = self.clone();
+          Box::pin(async move { client.#method_name(request).await })
+        }
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+/// Wraps a paginated operation in a `{operation}_paginated` method that streams the
+/// items of every page, requesting the next page from the previous page's cursor.
+#[derive(Clone, Debug)]
+pub(crate) struct PaginatedMethodFragment<'a> {
+  method: &'a ClientMethodFragment,
+  pagination: &'a Pagination,
+}
+
+impl<'a> PaginatedMethodFragment<'a> {
+  pub(crate) fn new(method: &'a ClientMethodFragment) -> Option<Self> {
+    let pagination = method.op.pagination.as_ref()?;
+    Some(Self { method, pagination })
+  }
+
+  /// Fetches a page from the cursor of the previous one by following it as a URL,
+  /// resending the operation's headers and credentials.
+  fn follow_link(&self, request_ident: &proc_macro2::Ident, response_type: &TokenStream) -> TokenStream {
+    let header_chain = HeaderParamsFragment::new(&self.method.op.parameters);
+    let authorize = self.method.authorize_call();
+    let send = self.method.send_call();
+    quote! {
+      let response: anyhow::Result<#response_type> = async {
+        let req_builder = self.client.get(self.base_url.join(&link).context("resolving next page link")?) #header_chain;
+        #authorize
+        let response = #send;
+        #request_ident::parse_response(response).await
+      }
+      .await;
+      response?
+    }
+  }
+}
+
+impl ToTokens for PaginatedMethodFragment<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let op = &self.method.op;
+    let Some(request_ident) = op.request_type.as_ref().map(|r| format_ident!("{r}")) else {
+      return;
+    };
+    let Some(response_type) = op.response_enum.as_ref() else {
+      return;
+    };
+    let Pagination {
+      page_variant,
+      other_variants,
+      page_links,
+      items,
+      items_optional,
+      item_type,
+      cursor,
+      next_page,
+    } = self.pagination;
+
+    let vis = self.method.visibility.to_tokens();
+    let method_name = format_ident!("{}", op.stable_id);
+    let paginated_name = format_ident!("{}_paginated", op.stable_id);
+    let doc = format!(" Streams the items of every page of [`Self::{method_name}`], following each page's cursor.");
+    let allow_deprecated = op.deprecated.then(|| quote! { #[allow(deprecated)] });
+    let error_type = self.method.error_type();
+    let into_inner = self
+      .method
+      .retains_raw_body(&ResponseParsingFragment::new(op))
+      .then(|| quote! { .into_inner() });
+
+    let first_page = quote! { self.#method_name(request.clone()).await? #into_inner };
+    let page_pattern = if *page_links {
+      quote! { #response_type::#page_variant { body: page, .. } }
+    } else {
+      quote! { #response_type::#page_variant(page) }
+    };
+    let page = if *other_variants {
+      let into_error = self.method.errors.is_some().then(|| quote! { .into() });
+      let error = format!("{} returned a response without a page", op.operation_id);
+      quote! {
+        let page = match response {
+          #page_pattern => page,
+          _ => return Err(anyhow::anyhow!(#error) #into_error),
+        };
+      }
+    } else {
+      quote! { let #page_pattern = response; }
+    };
+    let items = match items {
+      Some(items) if *items_optional => quote! { page.#items.unwrap_or_default() },
+      Some(items) => quote! { page.#items },
+      None => quote! { page },
+    };
+    let next_cursor = match cursor {
+      PageCursor::Field { name, optional: true } => quote! { page.#name.filter(|cursor| !cursor.is_empty()) },
+      PageCursor::Field { name, optional: false } => quote! { Some(page.#name).filter(|cursor| !cursor.is_empty()) },
+      PageCursor::LinkHeader => quote! { next_request },
+    };
+
+    let (initial, fetch, next) = match next_page {
+      NextPage::Link if *cursor == PageCursor::LinkHeader => (
+        quote! { Some(request) },
+        quote! {
+          let Some(request) = state else {
+            return Ok(None);
+          };
+          let response = #first_page;
+          let next_request = response.next(request);
+        },
+        next_cursor,
+      ),
+      NextPage::Query { field, optional } => {
+        let value = if *optional {
+          quote! { Some(cursor) }
+        } else {
+          quote! { cursor }
+        };
+        (
+          quote! { Some(request) },
+          quote! {
+            let Some(mut request) = state else {
+              return Ok(None);
+            };
+            let response = #first_page;
+          },
+          quote! {
+            #next_cursor.map(|cursor| {
+              request.query.#field = #value;
+              request
+            })
+          },
+        )
+      }
+      NextPage::Link => {
+        let follow_link = self.follow_link(&request_ident, &quote! { #response_type });
+        (
+          quote! { Some((request, None::<String>)) },
+          quote! {
+            let Some((request, link)) = state else {
+              return Ok(None);
+            };
+            let response = match link {
+              None => #first_page,
+              Some(link) => { #follow_link }
+            };
+          },
+          quote! { #next_cursor.map(|link| (request, Some(link))) },
+        )
+      }
+    };
+
+    quote! {
+      #[doc = #doc]
+      #allow_deprecated
+      #vis fn #paginated_name(&self, request: #request_ident) -> impl futures::Stream<Item = Result<#item_type, #error_type>> + '_ {
+        let pages = futures::stream::try_unfold(#initial, move |state| async move {
+          #fetch
+          #page
+          let items = #items;
+          let next = #next;
+          Ok::<_, #error_type>(Some((futures::stream::iter(items.into_iter().map(Ok)), next)))
+        });
+        futures::TryStreamExt::try_flatten(pages)
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+/// The `OPERATIONS` table describing each operation the client calls: its id, method,
+/// path, tags, `externalDocs` link, and `x-*` extensions.
+#[derive(Clone, Debug)]
+pub(crate) struct OperationTableFragment {
+  operations: Vec<OperationInfo>,
+  visibility: Visibility,
+}
+
+impl OperationTableFragment {
+  pub(crate) fn new(operations: &[OperationInfo], visibility: Visibility) -> Self {
+    Self {
+      operations: operations
+        .iter()
+        .filter(|op| op.kind == OperationKind::Http)
+        .cloned()
+        .collect(),
+      visibility,
+    }
+  }
+}
+
+impl ToTokens for OperationTableFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let vis = self.visibility.to_tokens();
+    let entries = self.operations.iter().map(|op| {
+      let operation_id = &op.operation_id;
+      let method = op.method.as_str();
+      let path = &op.path_template;
+      let tags = &op.tags;
+      let external_docs = match &op.external_docs {
+        Some(docs) => {
+          let url = &docs.url;
+          let description = match &docs.description {
+            Some(description) => quote! { Some(#description) },
+            None => quote! { None },
+          };
+          quote! { Some(oas3_gen_support::ExternalDocs { url: #url, description: #description }) }
+        }
+        None => quote! { None },
+      };
+      let extensions = op.extensions.iter().map(|(name, value)| {
+        let name = format!("x-{name}");
+        let value = value.to_string();
+        quote! { (#name, #value) }
+      });
+      quote! {
+        oas3_gen_support::OperationMetadata {
+          operation_id: #operation_id,
+          method: #method,
+          path: #path,
+          tags: &[#(#tags),*],
+          external_docs: #external_docs,
+          extensions: &[#(#extensions),*],
+        }
+      }
+    });
+    quote! {
+      /// Every operation this client calls, as declared in the spec.
+      #vis const OPERATIONS: &[oas3_gen_support::OperationMetadata] = &[#(#entries),*];
+    }
+    .to_tokens(tokens);
+  }
+}
+
+/// A client method that runs one operation over many requests with bounded
+/// concurrency, returning each result in the order of its request.
+#[derive(Clone, Debug)]
+pub(crate) struct ClientBatchFragment {
+  name: proc_macro2::Ident,
+  visibility: Visibility,
+}
+
+impl ClientBatchFragment {
+  pub(crate) fn new(operations: &[OperationInfo], visibility: Visibility) -> Self {
+    let name = if operations.iter().any(|op| op.stable_id == BATCH_METHOD) {
+      BATCH_METHOD_FALLBACK
+    } else {
+      BATCH_METHOD
+    };
+    Self {
+      name: format_ident!("{name}"),
+      visibility,
+    }
+  }
+}
+
+impl ToTokens for ClientBatchFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Calls `call` with every request, keeping at most `concurrency` calls in flight,
+      /// and returns the results in the order of `requests`.
+      ///
+      /// `call` is usually one of the client's methods, passed by path.
+      #vis async fn #name<'a, R, F>(
+        &'a self,
+        requests: impl IntoIterator<Item = R>,
+        concurrency: usize,
+        call: impl Fn(&'a Self, R) -> F,
+      ) -> Vec<F::Output>
+      where
+        F: std::future::Future,
+      {
+        use futures::StreamExt as _;
+        futures::stream::iter(requests)
+          .map(|request| call(self, request))
+          .buffered(concurrency.max(1))
+          .collect()
+          .await
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ClientStructFragment {
+  name: StructToken,
+  visibility: Visibility,
+  vcr: bool,
+  retry: bool,
+  hooks: bool,
+  last_response: bool,
+  compression: bool,
+  auth: Option<StructToken>,
+  template: Option<String>,
+}
+
+impl ClientStructFragment {
+  pub(crate) fn new(name: StructToken, visibility: Visibility) -> Self {
+    Self {
+      name,
+      visibility,
+      vcr: false,
+      retry: false,
+      hooks: false,
+      last_response: false,
+      compression: false,
+      auth: None,
+      template: None,
+    }
+  }
+
+  pub(crate) fn with_request_hooks(mut self) -> Self {
+    self.hooks = true;
+    self
+  }
+
+  pub(crate) fn with_last_response(mut self) -> Self {
+    self.last_response = true;
+    self
+  }
+
+  /// Renders the struct through the user's client struct template.
+  pub(crate) fn with_template(mut self, template: String) -> Self {
+    self.template = Some(template);
+    self
+  }
+
+  pub(crate) fn with_request_compression(mut self) -> Self {
+    self.compression = true;
+    self
+  }
+
+  pub(crate) fn with_auth(mut self, auth: StructToken) -> Self {
+    self.auth = Some(auth);
+    self
+  }
+
+  pub(crate) fn with_vcr(mut self) -> Self {
+    self.vcr = true;
+    self
+  }
+
+  pub(crate) fn with_retry(mut self) -> Self {
+    self.retry = true;
+    self
+  }
+}
+
+impl ToTokens for ClientStructFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let vis = self.visibility.to_tokens();
+    let vcr_field = self.vcr.then(|| quote! { #vis vcr: Option<oas3_gen_support::Vcr>, });
+    let retry_field = self
+      .retry
+      .then(|| quote! { #vis retry: Option<oas3_gen_support::RetryPolicy>, });
+    let hooks_field = self
+      .hooks
+      .then(|| quote! { #vis hooks: oas3_gen_support::RequestHooks, });
+    let last_response_field = self
+      .last_response
+      .then(|| quote! { #vis last_response: oas3_gen_support::LastResponse, });
+    let compression_field = self
+      .compression
+      .then(|| quote! { #vis request_compression: Option<oas3_gen_support::RequestCompression>, });
+    let auth_field = self.auth.as_ref().map(|auth| quote! { #vis auth: Option<#auth>, });
+
+    let fields = quote! {
+      #vis client: Client,
+      #vis base_url: Url,
+      #vcr_field
+      #retry_field
+      #hooks_field
+      #last_response_field
+      #compression_field
+      #auth_field
+    };
+    let ts = quote! {
+      #[derive(Debug, Clone)]
+      #vis struct #name {
+        #fields
+      }
+    };
+
+    match &self.template {
+      Some(template) => tokens.extend(render(
+        template,
+        &[
+          ("default", ts),
+          ("vis", vis),
+          ("name", name.to_token_stream()),
+          ("fields", fields),
+        ],
+      )),
+      None => tokens.extend(ts),
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ClientDefaultImplFragment {
+  name: StructToken,
+}
+
+impl ClientDefaultImplFragment {
+  pub(crate) fn new(name: StructToken) -> Self {
+    Self { name }
+  }
+}
+
+impl ToTokens for ClientDefaultImplFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+
+    let ts = quote! {
+      impl Default for #name {
+        fn default() -> Self {
+          Self::new()
+        }
+      }
+    };
+
+    tokens.extend(ts);
+  }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct ClientConstructorsFragment {
   visibility: Visibility,
+  vcr: bool,
+  retry: bool,
+  hooks: bool,
+  last_response: bool,
+  compression: bool,
+  auth: Option<StructToken>,
 }
 
 impl ClientConstructorsFragment {
   pub(crate) fn new(visibility: Visibility) -> Self {
-    Self { visibility }
+    Self {
+      visibility,
+      vcr: false,
+      retry: false,
+      hooks: false,
+      last_response: false,
+      compression: false,
+      auth: None,
+    }
+  }
+
+  pub(crate) fn with_request_compression(mut self) -> Self {
+    self.compression = true;
+    self
+  }
+
+  fn compression_methods(&self) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Compress the bodies of operations that accept compressed uploads.
+      ///
+      /// Requires the `compression` feature of `oas3-gen-support`.
+      #[must_use]
+      #vis fn with_request_compression(mut self, compression: oas3_gen_support::RequestCompression) -> Self {
+        self.request_compression = Some(compression);
+        self
+      }
+
+      fn compress_request(&self, request: reqwest::RequestBuilder) -> anyhow::Result<reqwest::RequestBuilder> {
+        match self.request_compression {
+          Some(compression) => Ok(compression.apply(request)?),
+          None => Ok(request),
+        }
+      }
+    }
+  }
+
+  pub(crate) fn with_auth(mut self, auth: StructToken) -> Self {
+    self.auth = Some(auth);
+    self
+  }
+
+  fn auth_methods(&self, auth: &StructToken) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Attach credentials for the API's security schemes.
+      #[must_use]
+      #vis fn with_auth(mut self, auth: #auth) -> Self {
+        self.auth = Some(auth);
+        self
+      }
+
+      async fn authorize(
+        &self,
+        request: reqwest::RequestBuilder,
+        requirements: &[&[&str]],
+      ) -> anyhow::Result<reqwest::RequestBuilder> {
+        let Some(auth) = &self.auth else {
+          return Ok(request);
+        };
+        let Some(schemes) = requirements
+          .iter()
+          .find(|schemes| schemes.iter().all(|scheme| auth.has(scheme)))
+        else {
+          return Ok(request);
+        };
+        let mut request = request;
+        for scheme in *schemes {
+          request = auth.apply(&self.client, request, scheme).await?;
+        }
+        Ok(request)
+      }
+    }
+  }
+
+  pub(crate) fn with_vcr(mut self) -> Self {
+    self.vcr = true;
+    self
+  }
+
+  fn vcr_methods(&self) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Create a client that records to or replays from a cassette file.
+      ///
+      /// Requests are replayed from `cassette` unless `OAS3_VCR=record` is set, in which
+      /// case they are sent to `base_url` and the cassette is rewritten.
+      #vis fn with_cassette(base_url: impl AsRef<str>, cassette: impl Into<std::path::PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+          vcr: Some(oas3_gen_support::Vcr::from_env(cassette).context("loading cassette")?),
+          ..Self::with_base_url(base_url)?
+        })
+      }
+    }
+  }
+
+  pub(crate) fn with_retry(mut self) -> Self {
+    self.retry = true;
+    self
+  }
+
+  fn retry_methods(&self) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Retry requests that fail to connect or are rejected with a retryable status.
+      ///
+      /// See `oas3_gen_support::RetryPolicy` for the defaults and for how `Retry-After`
+      /// responses are honored.
+      #[must_use]
+      #vis fn with_retry(mut self, retry: oas3_gen_support::RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+      }
+
+      /// Apply the settings of `config`, replacing the retry policy.
+      #[must_use]
+      #vis fn with_config(mut self, config: oas3_gen_support::ClientConfig) -> Self {
+        self.retry = config.retry;
+        self
+      }
+    }
+  }
+
+  pub(crate) fn with_request_hooks(mut self) -> Self {
+    self.hooks = true;
+    self
+  }
+
+  fn hook_methods(&self) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Run `hook` around every request, after the hooks added before it.
+      ///
+      /// See `oas3_gen_support::RequestHook` for when its methods are called.
+      #[must_use]
+      #vis fn with_hook(mut self, hook: impl oas3_gen_support::RequestHook + 'static) -> Self {
+        self.hooks.push(hook);
+        self
+      }
+    }
+  }
+
+  pub(crate) fn with_last_response(mut self) -> Self {
+    self.last_response = true;
+    self
+  }
+
+  fn last_response_methods(&self) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    quote! {
+      /// Returns the status, headers, and timing of the most recent response this client
+      /// or one of its clones received, or `None` before the first one.
+      #[must_use]
+      #vis fn last_response(&self) -> Option<oas3_gen_support::ResponseMetadata> {
+        self.last_response.get()
+      }
+    }
+  }
+
+  /// Sends requests through the cassette when one is loaded, otherwise through the
+  /// retry policy when one is set, running the request hooks around either and
+  /// recording the last response's metadata around all of them.
+  fn send_request_method(&self) -> Option<TokenStream> {
+    if !self.vcr && !self.retry && !self.hooks && !self.last_response {
+      return None;
+    }
+    let wrapped = self.hooks || self.last_response;
+    let done = |response: TokenStream| {
+      if wrapped {
+        response
+      } else {
+        quote! { Ok(#response) }
+      }
+    };
+    let send = if self.retry {
+      let retried = done(quote! { retry.send(request).await? });
+      let sent = done(quote! { request.send().await? });
+      quote! {
+        match &self.retry {
+          Some(retry) => #retried,
+          None => #sent,
+        }
+      }
+    } else {
+      done(quote! { request.send().await? })
+    };
+    let send = if self.vcr {
+      let replayed = done(quote! { vcr.execute(&self.client, request.build()?).await? });
+      quote! {
+        match &self.vcr {
+          Some(vcr) => #replayed,
+          None => #send,
+        }
+      }
+    } else {
+      send
+    };
+    let send = if wrapped {
+      let before_send = self
+        .hooks
+        .then(|| quote! { let request = self.hooks.before_send(request).await?; });
+      let started = self
+        .last_response
+        .then(|| quote! { let started = oas3_gen_support::Instant::now(); });
+      let record = self
+        .last_response
+        .then(|| quote! { self.last_response.record(&response, started); });
+      let after_receive = self
+        .hooks
+        .then(|| quote! { self.hooks.after_receive(&response).await?; });
+      quote! {
+        #before_send
+        #started
+        let response = #send;
+        #record
+        #after_receive
+        Ok(response)
+      }
+    } else {
+      send
+    };
+    Some(quote! {
+      async fn send_request(&self, request: reqwest::RequestBuilder) -> anyhow::Result<reqwest::Response> {
+        #send
+      }
+    })
   }
 }
 
 impl ToTokens for ClientConstructorsFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let vis = self.visibility.to_tokens();
+    let vcr_init = self.vcr.then(|| quote! { vcr: None, });
+    let vcr_methods = self.vcr.then(|| self.vcr_methods());
+    let retry_init = self.retry.then(|| quote! { retry: None, });
+    let retry_methods = self.retry.then(|| self.retry_methods());
+    let hooks_init = self
+      .hooks
+      .then(|| quote! { hooks: oas3_gen_support::RequestHooks::default(), });
+    let hook_methods = self.hooks.then(|| self.hook_methods());
+    let last_response_init = self
+      .last_response
+      .then(|| quote! { last_response: oas3_gen_support::LastResponse::default(), });
+    let last_response_methods = self.last_response.then(|| self.last_response_methods());
+    let compression_init = self.compression.then(|| quote! { request_compression: None, });
+    let compression_methods = self.compression.then(|| self.compression_methods());
+    let send_request = self.send_request_method();
+    let auth_init = self.auth.is_some().then(|| quote! { auth: None, });
+    let auth_methods = self.auth.as_ref().map(|auth| self.auth_methods(auth));
 
     let ts = quote! {
       /// Create a client using the OpenAPI `servers[0]` URL.
@@ -661,6 +1903,12 @@ impl ToTokens for ClientConstructorsFragment {
         Self {
           client: Client::builder().build().expect("client"),
           base_url: Url::parse(BASE_URL).expect("valid base url"),
+          #vcr_init
+          #retry_init
+          #hooks_init
+          #last_response_init
+          #compression_init
+          #auth_init
         }
       }
 
@@ -669,14 +1917,446 @@ impl ToTokens for ClientConstructorsFragment {
         Ok(Self {
           client: Client::builder().build().context("building reqwest client")?,
           base_url: Url::parse(base_url.as_ref()).context("parsing base url")?,
+          #vcr_init
+          #retry_init
+          #hooks_init
+          #last_response_init
+          #compression_init
+          #auth_init
         })
       }
 
       /// Create a client from an existing `reqwest::Client`.
       #vis fn with_client(base_url: impl AsRef<str>, client: Client) -> anyhow::Result<Self> {
         let url = Url::parse(base_url.as_ref()).context("parsing base url")?;
-        Ok(Self { client, base_url: url })
+        Ok(Self {
+          client,
+          base_url: url,
+          #vcr_init
+          #retry_init
+          #hooks_init
+          #last_response_init
+          #compression_init
+          #auth_init
+        })
       }
+
+      #vcr_methods
+
+      #retry_methods
+
+      #hook_methods
+
+      #last_response_methods
+
+      #send_request
+
+      #compression_methods
+
+      #auth_methods
+    };
+
+    tokens.extend(ts);
+  }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ClientAuthFragment {
+  name: StructToken,
+  schemes: Vec<SecuritySchemeDef>,
+  visibility: Visibility,
+}
+
+impl ClientAuthFragment {
+  pub(crate) fn new(client: &StructToken, schemes: &[SecuritySchemeDef], visibility: Visibility) -> Self {
+    Self {
+      name: StructToken::new(format!("{client}Auth")),
+      schemes: schemes.to_vec(),
+      visibility,
+    }
+  }
+
+  fn field_doc(scheme: &SecuritySchemeDef) -> String {
+    match &scheme.kind {
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Header,
+      } => format!("API key sent in the `{name}` header."),
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Query,
+      } => format!("API key sent as the `{name}` query parameter."),
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Cookie,
+      } => format!("API key sent as the `{name}` cookie."),
+      SecuritySchemeKind::Bearer => "Bearer token sent in the `Authorization` header.".to_string(),
+      SecuritySchemeKind::Basic => "Username and password for HTTP basic authentication.".to_string(),
+      SecuritySchemeKind::ClientCredentials { token_url } => {
+        format!("OAuth2 client credentials exchanged for a bearer token at `{token_url}`.")
+      }
+    }
+  }
+
+  fn field_type(kind: &SecuritySchemeKind) -> TokenStream {
+    match kind {
+      SecuritySchemeKind::ApiKey { .. } | SecuritySchemeKind::Bearer => quote! { String },
+      SecuritySchemeKind::Basic => quote! { oas3_gen_support::BasicAuth },
+      SecuritySchemeKind::ClientCredentials { .. } => quote! { oas3_gen_support::ClientCredentials },
+    }
+  }
+
+  fn apply_credential(scheme: &SecuritySchemeDef) -> TokenStream {
+    match &scheme.kind {
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Header,
+      } => quote! { request.header(#name, credential) },
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Query,
+      } => quote! { request.query(&[(#name, credential)]) },
+      SecuritySchemeKind::ApiKey {
+        name,
+        location: ApiKeyLocation::Cookie,
+      } => {
+        let cookie = format!("{}={{credential}}", name.replace('{', "{{").replace('}', "}}"));
+        quote! { request.header(reqwest::header::COOKIE, format!(#cookie)) }
+      }
+      SecuritySchemeKind::Bearer => quote! { request.bearer_auth(credential) },
+      SecuritySchemeKind::Basic => quote! { request.basic_auth(&credential.username, credential.password.as_ref()) },
+      SecuritySchemeKind::ClientCredentials { .. } => {
+        let context = format!("fetching access token for `{}`", scheme.name);
+        quote! { request.bearer_auth(credential.access_token(client).await.context(#context)?) }
+      }
+    }
+  }
+
+  fn setter(&self, scheme: &SecuritySchemeDef) -> TokenStream {
+    let vis = self.visibility.to_tokens();
+    let field = &scheme.field;
+    let method = format_ident!("with_{}", field.as_str().trim_start_matches("r#"));
+    match &scheme.kind {
+      SecuritySchemeKind::ApiKey { .. } | SecuritySchemeKind::Bearer => quote! {
+        #[must_use]
+        #vis fn #method(mut self, value: impl Into<String>) -> Self {
+          self.#field = Some(value.into());
+          self
+        }
+      },
+      SecuritySchemeKind::Basic => quote! {
+        #[must_use]
+        #vis fn #method(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+          self.#field = Some(oas3_gen_support::BasicAuth::new(username, password));
+          self
+        }
+      },
+      SecuritySchemeKind::ClientCredentials { token_url } => quote! {
+        #[must_use]
+        #vis fn #method(mut self, client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+          self.#field = Some(oas3_gen_support::ClientCredentials::new(#token_url, client_id, client_secret));
+          self
+        }
+      },
+    }
+  }
+}
+
+impl ToTokens for ClientAuthFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let name_str = name.as_str();
+    let vis = self.visibility.to_tokens();
+
+    let fields = self.schemes.iter().map(|scheme| {
+      let field = &scheme.field;
+      let ty = Self::field_type(&scheme.kind);
+      let doc = format!(" {}", Self::field_doc(scheme));
+      let description = scheme.description.iter().flat_map(|description| {
+        std::iter::once(String::new()).chain(description.lines().map(|line| format!(" {line}")))
+      });
+      quote! {
+        #[doc = #doc]
+        #(#[doc = #description])*
+        #vis #field: Option<#ty>,
+      }
+    });
+    let debug_fields = self.schemes.iter().map(|scheme| {
+      let field = &scheme.field;
+      let label = field.as_str().trim_start_matches("r#");
+      quote! { .field(#label, &self.#field.as_ref().map(|_| "<redacted>")) }
+    });
+    let setters = self.schemes.iter().map(|scheme| self.setter(scheme));
+    let has_arms = self.schemes.iter().map(|scheme| {
+      let scheme_name = &scheme.name;
+      let field = &scheme.field;
+      quote! { #scheme_name => self.#field.is_some(), }
+    });
+    let apply_arms = self.schemes.iter().map(|scheme| {
+      let scheme_name = &scheme.name;
+      let field = &scheme.field;
+      let apply = Self::apply_credential(scheme);
+      quote! {
+        #scheme_name => match &self.#field {
+          Some(credential) => #apply,
+          None => request,
+        },
+      }
+    });
+    let client_param = if self
+      .schemes
+      .iter()
+      .any(|scheme| matches!(scheme.kind, SecuritySchemeKind::ClientCredentials { .. }))
+    {
+      quote! { client }
+    } else {
+      quote! { _client }
+    };
+
+    let ts = quote! {
+      /// Credentials for the API's security schemes.
+      ///
+      /// Each operation applies the first of its security requirements whose schemes
+      /// all have credentials set, and is sent without credentials otherwise.
+      #[derive(Clone, Default)]
+      #vis struct #name {
+        #(#fields)*
+      }
+
+      impl std::fmt::Debug for #name {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+          f.debug_struct(#name_str)
+            #(#debug_fields)*
+            .finish()
+        }
+      }
+
+      impl #name {
+        #(#setters)*
+
+        fn has(&self, scheme: &str) -> bool {
+          match scheme {
+            #(#has_arms)*
+            _ => false,
+          }
+        }
+
+        async fn apply(
+          &self,
+          #client_param: &Client,
+          request: reqwest::RequestBuilder,
+          scheme: &str,
+        ) -> anyhow::Result<reqwest::RequestBuilder> {
+          Ok(match scheme {
+            #(#apply_arms)*
+            _ => request,
+          })
+        }
+      }
+    };
+
+    tokens.extend(ts);
+  }
+}
+
+/// The error enum returned by client methods when typed errors are enabled.
+///
+/// Besides transport, deserialization and unexpected-status failures, it has one
+/// variant per schema used as an error response body, carrying the body with its status.
+#[derive(Clone, Debug)]
+pub(crate) struct ClientErrorFragment {
+  name: EnumToken,
+  bodies: IndexMap<DefaultAtom, ErrorBodyVariant>,
+  api_error_alias: bool,
+  deadlines: bool,
+  visibility: Visibility,
+}
+
+#[derive(Clone, Debug)]
+struct ErrorBodyVariant {
+  variant: EnumVariantToken,
+  error_impl: bool,
+}
+
+impl ClientErrorFragment {
+  const FIXED_VARIANTS: &[&str] = &[
+    "Request",
+    "Validation",
+    "Transport",
+    "Deserialize",
+    "UnexpectedStatus",
+    "DeadlineExceeded",
+  ];
+
+  pub(crate) fn new(client: &StructToken, operations: &[OperationInfo], visibility: Visibility) -> Self {
+    let mut bodies = IndexMap::new();
+    for error in operations.iter().flat_map(|op| &op.error_response_types) {
+      let Some(body) = &error.body else {
+        continue;
+      };
+      bodies.entry(body.clone()).or_insert_with(|| {
+        let variant = if Self::FIXED_VARIANTS.contains(&body.as_ref()) {
+          EnumVariantToken::new(format!("{body}Body"))
+        } else {
+          EnumVariantToken::new(body)
+        };
+        ErrorBodyVariant {
+          variant,
+          error_impl: error.error_impl,
+        }
+      });
+    }
+
+    Self {
+      name: Self::name(client),
+      bodies,
+      api_error_alias: false,
+      deadlines: false,
+      visibility,
+    }
+  }
+
+  /// Adds a `DeadlineExceeded` variant for calls whose per-call deadline had passed.
+  pub(crate) fn with_deadlines(mut self) -> Self {
+    self.deadlines = true;
+    self
+  }
+
+  /// Also emits an `ApiError` alias, naming the error type without the client's name.
+  pub(crate) fn with_api_error_alias(mut self) -> Self {
+    self.api_error_alias = true;
+    self
+  }
+
+  pub(crate) fn name(client: &StructToken) -> EnumToken {
+    EnumToken::new(format!("{client}Error"))
+  }
+
+  fn body_variant(&self, body: &DefaultAtom) -> Option<&EnumVariantToken> {
+    self.bodies.get(body).map(|body| &body.variant)
+  }
+}
+
+impl ToTokens for ClientErrorFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.name;
+    let vis = self.visibility.to_tokens();
+
+    let variants = self.bodies.iter().map(|(body, variant)| {
+      let body = format_ident!("{body}");
+      let variant = &variant.variant;
+      let doc = format!(" An error response with a `{body}` body.");
+      quote! {
+        #[doc = #doc]
+        #variant { status: http::StatusCode, body: #body }
+      }
+    });
+    let display_arms = self.bodies.values().map(|variant| {
+      let format = if variant.error_impl { "{}: {}" } else { "{}: {:?}" };
+      let variant = &variant.variant;
+      quote! { Self::#variant { status, body } => write!(f, #format, status, body) }
+    });
+    let source_arms = self
+      .bodies
+      .values()
+      .filter(|variant| variant.error_impl)
+      .map(|variant| {
+        let variant = &variant.variant;
+        quote! { Self::#variant { body, .. } => Some(body) }
+      });
+
+    let deadline_variant = self.deadlines.then(|| {
+      quote! {
+        /// The call's deadline passed before the request was sent.
+        DeadlineExceeded(oas3_gen_support::DeadlineExceeded),
+      }
+    });
+    let deadline_display = self
+      .deadlines
+      .then(|| quote! { Self::DeadlineExceeded(error) => write!(f, "{error}"), });
+    let deadline_downcast = self.deadlines.then(|| {
+      quote! {
+        let error = match error.downcast::<oas3_gen_support::DeadlineExceeded>() {
+          Ok(error) => return Self::DeadlineExceeded(error),
+          Err(error) => error,
+        };
+      }
+    });
+
+    let alias = self.api_error_alias.then(|| {
+      let alias = format_ident!("{API_ERROR_ALIAS}");
+      quote! {
+        /// The error type of every client method, for code that does not care which
+        /// operation failed.
+        #vis type #alias = #name;
+      }
+    });
+
+    let ts = quote! {
+      /// Errors returned by the client's methods.
+      #[derive(Debug)]
+      #vis enum #name {
+        /// The request could not be built.
+        Request(anyhow::Error),
+        /// The request broke its schema's validation rules, so it was not sent.
+        Validation(validator::ValidationErrors),
+        /// The request could not be sent or its response could not be read.
+        Transport(reqwest::Error),
+        /// The response body did not match its schema.
+        Deserialize(oas3_gen_support::DiagnosticsError),
+        /// The server answered with a status the operation does not describe.
+        UnexpectedStatus(http::StatusCode),
+        #deadline_variant
+        #(#variants,)*
+      }
+
+      impl std::fmt::Display for #name {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+          match self {
+            Self::Request(error) => write!(f, "invalid request: {error}"),
+            Self::Validation(error) => write!(f, "invalid request: {error}"),
+            Self::Transport(_) => f.write_str("transport error"),
+            Self::Deserialize(_) => f.write_str("invalid response body"),
+            Self::UnexpectedStatus(status) => write!(f, "unexpected status {status}"),
+            #deadline_display
+            #(#display_arms,)*
+          }
+        }
+      }
+
+      impl std::error::Error for #name {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+          match self {
+            Self::Request(error) => Some(error.as_ref()),
+            Self::Validation(error) => Some(error),
+            Self::Transport(error) => Some(error),
+            Self::Deserialize(error) => Some(error),
+            #(#source_arms,)*
+            _ => None,
+          }
+        }
+      }
+
+      impl From<anyhow::Error> for #name {
+        fn from(error: anyhow::Error) -> Self {
+          let error = match error.downcast::<validator::ValidationErrors>() {
+            Ok(error) => return Self::Validation(error),
+            Err(error) => error,
+          };
+          let error = match error.downcast::<reqwest::Error>() {
+            Ok(error) => return Self::Transport(error),
+            Err(error) => error,
+          };
+          #deadline_downcast
+          match error.downcast::<oas3_gen_support::DiagnosticsError>() {
+            Ok(oas3_gen_support::DiagnosticsError::BodyReadError(error)) => Self::Transport(error),
+            Ok(error) => Self::Deserialize(error),
+            Err(error) => Self::Request(error),
+          }
+        }
+      }
+
+      #alias
     };
 
     tokens.extend(ts);
@@ -688,7 +2368,21 @@ pub struct ClientFragment {
   def: ClientRootNode,
   operations: Vec<OperationInfo>,
   visibility: Visibility,
-  use_types_import: bool,
+  types_modules: Vec<String>,
+  types_crate: Option<String>,
+  types_path: Option<TokenStream>,
+  retain_raw_body: bool,
+  vcr: bool,
+  retry: bool,
+  request_hooks: bool,
+  last_response: bool,
+  typed_errors: bool,
+  api_error_alias: bool,
+  tower_services: bool,
+  local_futures: bool,
+  batch: bool,
+  route_tests: bool,
+  templates: ClientTemplates,
 }
 
 impl ClientFragment {
@@ -697,12 +2391,116 @@ impl ClientFragment {
       def: def.clone(),
       operations: operations.to_vec(),
       visibility,
-      use_types_import: false,
+      types_modules: vec![],
+      types_crate: None,
+      types_path: None,
+      retain_raw_body: false,
+      vcr: false,
+      retry: false,
+      request_hooks: false,
+      last_response: false,
+      typed_errors: false,
+      api_error_alias: false,
+      tower_services: false,
+      local_futures: false,
+      batch: false,
+      route_tests: false,
+      templates: ClientTemplates::default(),
     }
   }
 
   pub fn with_types_import(mut self) -> Self {
-    self.use_types_import = true;
+    self.types_modules = vec!["types".to_string()];
+    self
+  }
+
+  /// Imports types from the named sibling modules instead of just `types`.
+  pub fn with_types_modules(mut self, modules: Vec<String>) -> Self {
+    self.types_modules = modules;
+    self
+  }
+
+  /// Imports types from the named crate instead of the sibling `types` module.
+  pub fn with_types_crate(mut self, crate_name: impl Into<String>) -> Self {
+    self.types_crate = Some(crate_name.into());
+    self
+  }
+
+  /// Imports types from `path`, such as `super::types`, for a client written to a
+  /// file of its own.
+  pub fn with_types_path(mut self, path: &syn::Path) -> Self {
+    self.types_path = Some(path.to_token_stream());
+    self
+  }
+
+  pub fn with_raw_body(mut self) -> Self {
+    self.retain_raw_body = true;
+    self
+  }
+
+  pub fn with_vcr(mut self) -> Self {
+    self.vcr = true;
+    self
+  }
+
+  pub fn with_retry(mut self) -> Self {
+    self.retry = true;
+    self
+  }
+
+  /// Lets the client run `oas3_gen_support::RequestHook`s and take per-call
+  /// `oas3_gen_support::RequestOptions`.
+  pub fn with_request_hooks(mut self) -> Self {
+    self.request_hooks = true;
+    self
+  }
+
+  /// Adds a `last_response` method returning the metadata of the client's most recent response.
+  pub fn with_last_response(mut self) -> Self {
+    self.last_response = true;
+    self
+  }
+
+  /// Returns a generated `{Client}Error` enum from client methods instead of `anyhow::Error`.
+  pub fn with_typed_errors(mut self) -> Self {
+    self.typed_errors = true;
+    self
+  }
+
+  /// Implements `tower::Service` on the client for each operation's request type.
+  pub fn with_tower_services(mut self) -> Self {
+    self.tower_services = true;
+    self
+  }
+
+  /// Generates `tower::Service` futures without a `Send` bound, as `wasm32` requires.
+  pub fn with_local_futures(mut self) -> Self {
+    self.local_futures = true;
+    self
+  }
+
+  /// Adds a `batch` method running many calls of one operation with bounded concurrency.
+  pub fn with_batch(mut self) -> Self {
+    self.batch = true;
+    self
+  }
+
+  /// Builds each method's URL in a private helper and tests the helpers in a
+  /// `route_tests` module.
+  pub fn with_route_tests(mut self) -> Self {
+    self.route_tests = true;
+    self
+  }
+
+  /// Aliases the typed error enum as `ApiError`. Only takes effect with typed errors.
+  pub fn with_api_error_alias(mut self) -> Self {
+    self.api_error_alias = true;
+    self
+  }
+
+  /// Replaces the client struct, method wrappers, and method bodies with the user's templates.
+  pub fn with_templates(mut self, templates: ClientTemplates) -> Self {
+    self.templates = templates;
     self
   }
 }
@@ -712,22 +2510,137 @@ impl ToTokens for ClientFragment {
     let client_ident = &self.def.name;
     let vis = self.visibility.to_tokens();
     let base_url = LitStr::new(&self.def.base_url, Span::call_site());
+    let errors = self.typed_errors.then(|| {
+      let errors = ClientErrorFragment::new(client_ident, &self.operations, self.visibility);
+      let errors = if self.request_hooks {
+        errors.with_deadlines()
+      } else {
+        errors
+      };
+      if self.api_error_alias {
+        errors.with_api_error_alias()
+      } else {
+        errors
+      }
+    });
 
-    let methods = self
+    let method_fragments = self
       .operations
       .iter()
       .filter(|op| op.kind == OperationKind::Http)
-      .filter_map(|op| ClientMethodFragment::new(op.clone(), self.visibility).generate().ok());
+      .map(|op| {
+        let method = ClientMethodFragment::new(op.clone(), self.visibility).with_templates(self.templates.clone());
+        let method = if self.retain_raw_body {
+          method.with_raw_body()
+        } else {
+          method
+        };
+        let method = if self.vcr { method.with_vcr() } else { method };
+        let method = if self.retry { method.with_retry() } else { method };
+        let method = if self.request_hooks {
+          method.with_request_hooks()
+        } else {
+          method
+        };
+        let method = if self.last_response {
+          method.with_last_response()
+        } else {
+          method
+        };
+        let method = if self.route_tests {
+          method.with_url_helper()
+        } else {
+          method
+        };
+        match &errors {
+          Some(errors) => method.with_typed_errors(errors.clone()),
+          None => method,
+        }
+      })
+      .collect::<Vec<_>>();
+    let methods = method_fragments.iter().filter_map(|method| {
+      let generated = method.generate().ok()?;
+      let paginated = PaginatedMethodFragment::new(method);
+      let url_helper = self.route_tests.then(|| method.url_helper()).flatten();
+      Some(quote! { #generated #url_helper #paginated })
+    });
+    let services = method_fragments.iter().filter(|_| self.tower_services).map(|method| {
+      let service = TowerServiceFragment::new(client_ident.clone(), method.clone());
+      if self.local_futures {
+        service.with_local_futures()
+      } else {
+        service
+      }
+    });
 
-    let types_import = if self.use_types_import {
-      quote! { use super::types::*; }
-    } else {
-      quote! {}
+    let batch = self
+      .batch
+      .then(|| ClientBatchFragment::new(&self.operations, self.visibility));
+    let route_tests = self
+      .route_tests
+      .then(|| RouteTestsFragment::new(client_ident, &self.operations));
+    let operation_table = OperationTableFragment::new(&self.operations, self.visibility);
+
+    let types_import = match (&self.types_crate, &self.types_path) {
+      (Some(crate_name), _) => {
+        let crate_ident = format_ident!("{}", crate_name.replace('-', "_"));
+        quote! { use #crate_ident::*; }
+      }
+      (None, Some(path)) => quote! { use #path::*; },
+      (None, None) => {
+        let modules = self.types_modules.iter().map(|module| format_ident!("{module}"));
+        quote! { #(use super::#modules::*;)* }
+      }
     };
 
     let client_struct = ClientStructFragment::new(client_ident.clone(), self.visibility);
+    let client_struct = match &self.templates.client_struct {
+      Some(template) => client_struct.with_template(template.clone()),
+      None => client_struct,
+    };
     let default_impl = ClientDefaultImplFragment::new(client_ident.clone());
     let constructors = ClientConstructorsFragment::new(self.visibility);
+    let (client_struct, constructors) = if self.vcr {
+      (client_struct.with_vcr(), constructors.with_vcr())
+    } else {
+      (client_struct, constructors)
+    };
+    let (client_struct, constructors) = if self.retry {
+      (client_struct.with_retry(), constructors.with_retry())
+    } else {
+      (client_struct, constructors)
+    };
+    let (client_struct, constructors) = if self.request_hooks {
+      (client_struct.with_request_hooks(), constructors.with_request_hooks())
+    } else {
+      (client_struct, constructors)
+    };
+    let (client_struct, constructors) = if self.last_response {
+      (client_struct.with_last_response(), constructors.with_last_response())
+    } else {
+      (client_struct, constructors)
+    };
+    let compresses = self
+      .operations
+      .iter()
+      .any(|op| op.kind == OperationKind::Http && op.request_compression);
+    let (client_struct, constructors) = if compresses {
+      (
+        client_struct.with_request_compression(),
+        constructors.with_request_compression(),
+      )
+    } else {
+      (client_struct, constructors)
+    };
+    let auth = (!self.def.security_schemes.is_empty())
+      .then(|| ClientAuthFragment::new(client_ident, &self.def.security_schemes, self.visibility));
+    let (client_struct, constructors) = match &auth {
+      Some(auth) => (
+        client_struct.with_auth(auth.name.clone()),
+        constructors.with_auth(auth.name.clone()),
+      ),
+      None => (client_struct, constructors),
+    };
 
     quote! {
       use anyhow::Context;
@@ -738,14 +2651,25 @@ impl ToTokens for ClientFragment {
 
       #vis const BASE_URL: &str = #base_url;
 
+      #operation_table
+
       #client_struct
 
+      #auth
+
+      #errors
+
       #default_impl
 
       impl #client_ident {
         #constructors
         #(#methods)*
+        #batch
       }
+
+      #(#services)*
+
+      #route_tests
     }
     .to_tokens(tokens);
   }
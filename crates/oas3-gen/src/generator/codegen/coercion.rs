@@ -24,7 +24,7 @@ fn typed_literal(value: impl std::fmt::Display, type_suffix: &str) -> TokenStrea
     .unwrap_or_else(|_| quote! { Default::default() })
 }
 
-fn coerce_to_rust_type(value: &serde_json::Value, rust_type: &RustPrimitive) -> TokenStream {
+pub(crate) fn coerce_to_rust_type(value: &serde_json::Value, rust_type: &RustPrimitive) -> TokenStream {
   match rust_type {
     RustPrimitive::String => coerce_to_string(value),
     RustPrimitive::StaticStr => coerce_to_static_str(value),
@@ -42,6 +42,7 @@ fn coerce_to_rust_type(value: &serde_json::Value, rust_type: &RustPrimitive) ->
     | RustPrimitive::Usize => coerce_to_uint(value, rust_type),
     RustPrimitive::F32 | RustPrimitive::F64 => coerce_to_float(value, rust_type),
     RustPrimitive::Bool => coerce_to_bool(value),
+    RustPrimitive::Decimal | RustPrimitive::BigDecimal => coerce_to_decimal(value),
     _ => quote! { Default::default() },
   }
 }
@@ -62,6 +63,15 @@ fn coerce_to_string(value: &Value) -> TokenStream {
   }
 }
 
+fn coerce_to_decimal(value: &Value) -> TokenStream {
+  let text = match value {
+    Value::String(s) => s.clone(),
+    Value::Number(n) => n.to_string(),
+    _ => return quote! { Default::default() },
+  };
+  quote! { #text.parse().unwrap_or_default() }
+}
+
 fn coerce_to_static_str(value: &Value) -> TokenStream {
   match value {
     Value::String(s) => quote! { #s },
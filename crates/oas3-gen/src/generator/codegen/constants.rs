@@ -1,9 +1,18 @@
 use std::collections::{BTreeMap, btree_map::Entry};
 
 use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
-
-use crate::generator::ast::{RegexKey, RustType, ValidationAttribute, constants::HttpHeaderRef, tokens::ConstToken};
+use quote::{ToTokens, format_ident, quote};
+
+use crate::generator::{
+  ast::{
+    MethodKind, OperationBody, OperationInfo, RegexKey, ResponseStatusCategory, RustType, StructToken,
+    ValidationAttribute,
+    constants::{HttpHeaderRef, MEDIA_TYPES_MODULE, MediaTypeRef},
+    tokens::ConstToken,
+  },
+  converter::GenerationTarget,
+  naming::casing::to_snake_case,
+};
 
 #[derive(Clone, Debug)]
 pub(crate) struct RegexConstantFragment {
@@ -84,6 +93,61 @@ impl ToTokens for RegexConstantsResult {
   }
 }
 
+/// Names the function checking the values a field's `not` schema excludes.
+pub(crate) fn exclusion_validator_name(type_name: &StructToken, field: &str) -> String {
+  format!(
+    "exclude_{}_{}",
+    to_snake_case(type_name.as_str()),
+    field.trim_start_matches("r#")
+  )
+}
+
+/// The functions backing the `custom` validators of fields whose `not` schema excludes
+/// values, one per field, since `#[validate(custom(function = ...))]` takes no arguments.
+///
+/// Each function is generic over its argument, as `validator` passes `Copy` fields by
+/// value and others by reference.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ExclusionValidatorsFragment(Vec<(String, Vec<String>)>);
+
+impl ExclusionValidatorsFragment {
+  pub(crate) fn from_types(types: &[RustType]) -> Self {
+    let validators = types
+      .iter()
+      .filter_map(|rust_type| match rust_type {
+        RustType::Struct(def) => Some(def),
+        _ => None,
+      })
+      .flat_map(|def| {
+        def.fields.iter().filter_map(|field| {
+          let values = field.validation_attrs.iter().find_map(|attr| match attr {
+            ValidationAttribute::Excludes(values) => Some(values),
+            _ => None,
+          })?;
+          Some((
+            exclusion_validator_name(&def.name, field.name.as_str()),
+            values.iter().map(ToString::to_string).collect::<Vec<_>>(),
+          ))
+        })
+      })
+      .collect::<Vec<_>>();
+    Self(validators)
+  }
+}
+
+impl ToTokens for ExclusionValidatorsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    for (name, values) in &self.0 {
+      let name = format_ident!("{name}");
+      tokens.extend(quote! {
+        fn #name<T: serde::Serialize>(value: T) -> Result<(), validator::ValidationError> {
+          oas3_gen_support::exclude_values(&value, &[#(#values),*])
+        }
+      });
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default)]
 pub(crate) struct HeaderConstantsFragment(Vec<HttpHeaderRef>);
 
@@ -100,3 +164,66 @@ impl ToTokens for HeaderConstantsFragment {
     }
   }
 }
+
+/// The `media_types` module, holding a constant for each media type the generated
+/// client names, so request and response code shares them instead of repeating literals.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MediaTypeConstantsFragment(BTreeMap<ConstToken, MediaTypeRef>);
+
+impl MediaTypeConstantsFragment {
+  /// Collects `application/json` when a `parse_response` method in `types` picks a
+  /// variant by `Content-Type`, assuming JSON when the header is missing, and the
+  /// `Content-Type` of each request body in `operations`.
+  ///
+  /// Server code names no media types, so the module is empty for server output.
+  pub(crate) fn new(types: &[RustType], operations: &[OperationInfo], target: GenerationTarget) -> Self {
+    if target == GenerationTarget::Server {
+      return Self::default();
+    }
+
+    let dispatches_on_content_type = types
+      .iter()
+      .filter_map(|rust_type| match rust_type {
+        RustType::Struct(def) => Some(&def.methods),
+        _ => None,
+      })
+      .flatten()
+      .any(|method| match &method.kind {
+        MethodKind::ParseResponse { status_handlers, .. } => status_handlers
+          .iter()
+          .any(|handler| matches!(handler.dispatch, ResponseStatusCategory::ContentDispatch { .. })),
+        _ => false,
+      });
+    let media_types = dispatches_on_content_type.then(MediaTypeRef::json).into_iter().chain(
+      operations
+        .iter()
+        .filter_map(|op| op.body.as_ref())
+        .flat_map(OperationBody::content_type_headers),
+    );
+
+    let mut constants = BTreeMap::new();
+    for media_type in media_types {
+      constants.entry(media_type.const_token.clone()).or_insert(media_type);
+    }
+    Self(constants)
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl ToTokens for MediaTypeConstantsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if self.is_empty() {
+      return;
+    }
+    let module = format_ident!("{MEDIA_TYPES_MODULE}");
+    let constants = self.0.values();
+    tokens.extend(quote! {
+      pub mod #module {
+        #(#constants)*
+      }
+    });
+  }
+}
@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generator::ast::{ParentConversion, StructDef, StructToken};
+
+/// Generates conversions between an `allOf` child struct and the parents it extends.
+///
+/// `From<&Child> for Parent` clones the shared fields into the parent. The child is
+/// built back from an owned parent with its own fields left at their defaults: children
+/// with validation rules implement `TryFrom` and validate the result, others `From`.
+pub(crate) fn generate_parent_conversions(def: &StructDef) -> TokenStream {
+  let validate = def.has_validation_attrs();
+  def
+    .parent_conversions
+    .iter()
+    .map(|conversion| {
+      let to_parent = to_parent(&def.name, conversion);
+      let from_parent = conversion
+        .from_parent
+        .then(|| from_parent(&def.name, conversion, validate));
+      quote! {
+        #to_parent
+        #from_parent
+      }
+    })
+    .collect()
+}
+
+fn to_parent(child: &StructToken, conversion: &ParentConversion) -> TokenStream {
+  let parent = &conversion.parent;
+  let fields = conversion.shared_fields.iter().map(|(name, rust_type)| {
+    if rust_type.is_copy() {
+      quote! { #name: value.#name, }
+    } else {
+      quote! { #name: value.#name.clone(), }
+    }
+  });
+  let rest = (!conversion.covers_parent).then(|| quote! { ..Default::default() });
+  quote! {
+    impl From<&#child> for #parent {
+      fn from(value: &#child) -> Self {
+        Self {
+          #(#fields)*
+          #rest
+        }
+      }
+    }
+  }
+}
+
+fn from_parent(child: &StructToken, conversion: &ParentConversion, validate: bool) -> TokenStream {
+  let parent = &conversion.parent;
+  let fields = conversion.shared_fields.iter().map(|(name, _)| name);
+  let rest = (!conversion.covers_child).then(|| quote! { ..Default::default() });
+  let build = quote! {
+    Self {
+      #(#fields: value.#fields,)*
+      #rest
+    }
+  };
+
+  if validate {
+    quote! {
+      impl TryFrom<#parent> for #child {
+        type Error = validator::ValidationErrors;
+
+        fn try_from(value: #parent) -> core::result::Result<Self, Self::Error> {
+          let child = #build;
+          validator::Validate::validate(&child)?;
+          Ok(child)
+        }
+      }
+    }
+  } else {
+    quote! {
+      impl From<#parent> for #child {
+        fn from(value: #parent) -> Self {
+          #build
+        }
+      }
+    }
+  }
+}
@@ -1,5 +1,8 @@
+use std::collections::BTreeSet;
+
+use itertools::Itertools as _;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
-use quote::{ToTokens, TokenStreamExt as _, quote};
+use quote::{ToTokens, TokenStreamExt as _, format_ident, quote};
 
 use super::{
   Visibility,
@@ -8,14 +11,14 @@ use super::{
 use crate::generator::{
   ast::{
     DeriveTrait, DerivesProvider, DiscriminatedEnumDef, DiscriminatedVariant, EnumDef, EnumMethod, EnumMethodKind,
-    EnumToken, EnumVariantToken, FieldDef, ResponseEnumDef, ResponseVariant, RustPrimitive, SerdeMode, TypeRef,
-    VariantContent, VariantDef,
+    EnumToken, EnumVariantToken, FieldDef, NamedEventsDef, ResponseClass, ResponseEnumDef, ResponseVariant,
+    RustPrimitive, SerdeAttribute, SerdeMode, TypeRef, VariantContent, VariantDef,
   },
   codegen::{
     attributes::DeriveAttribute,
     methods::{FieldFunctionParameterFragment, HelperMethodFragment, HelperMethodParts, StructConstructorFragment},
   },
-  converter::GenerationTarget,
+  naming::constants::{PAGE_LINK_FIELD, UNKNOWN_EVENT_VARIANT},
 };
 
 #[derive(Clone, Debug)]
@@ -286,15 +289,25 @@ impl ToTokens for DisplayImplFragment {
 #[derive(Clone, Debug)]
 pub(crate) struct FromStrImplArmFragment {
   variant_name: EnumVariantToken,
-  serde_name: String,
+  values: Vec<String>,
 }
 
 impl FromStrImplArmFragment {
-  pub(crate) fn new(variant: VariantDef) -> Self {
-    let serde_name = variant.serde_name();
+  /// Matches the serialized value and its serde aliases, or only the lowercased
+  /// value when `case_insensitive`.
+  pub(crate) fn new(variant: VariantDef, case_insensitive: bool) -> Self {
+    let values = if case_insensitive {
+      vec![variant.serde_name().to_ascii_lowercase()]
+    } else {
+      let aliases = variant.serde_attrs.iter().filter_map(|attr| match attr {
+        SerdeAttribute::Alias(alias) => Some(alias.clone()),
+        _ => None,
+      });
+      std::iter::once(variant.serde_name()).chain(aliases).collect()
+    };
     Self {
       variant_name: variant.name,
-      serde_name,
+      values,
     }
   }
 }
@@ -302,28 +315,40 @@ impl FromStrImplArmFragment {
 impl ToTokens for FromStrImplArmFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let variant_name = &self.variant_name;
-    let serde_name = &self.serde_name;
+    let values = &self.values;
 
-    let ts = quote! { #serde_name => Ok(Self::#variant_name), };
+    let ts = quote! { #(#values)|* => Ok(Self::#variant_name), };
     tokens.extend(ts);
   }
 }
 
+/// Parses a unit-variant enum from the string it serializes as, matching values
+/// the same way its `Deserialize` impl does.
 #[derive(Clone, Debug)]
 pub(crate) struct FromStrImplFragment {
   name: EnumToken,
   arms: Vec<FromStrImplArmFragment>,
   serde_names: Vec<String>,
+  case_insensitive: bool,
+  fallback_variant: Option<EnumVariantToken>,
+  scalar_fallback: Option<EnumVariantToken>,
 }
 
 impl FromStrImplFragment {
-  pub(crate) fn new(name: EnumToken, variants: Vec<VariantDef>) -> Self {
+  /// With `case_insensitive`, matches values ignoring ASCII case. Unknown values
+  /// parse to `fallback_variant` when there is one.
+  pub(crate) fn new(
+    name: EnumToken,
+    variants: Vec<VariantDef>,
+    case_insensitive: bool,
+    fallback_variant: Option<VariantDef>,
+  ) -> Self {
     let (arms, serde_names): (Vec<_>, Vec<_>) = variants
       .into_iter()
       .filter(|v| matches!(v.content, VariantContent::Unit))
       .map(|v| {
         let serde_name = v.serde_name();
-        (FromStrImplArmFragment::new(v), serde_name)
+        (FromStrImplArmFragment::new(v, case_insensitive), serde_name)
       })
       .unzip();
 
@@ -331,8 +356,18 @@ impl FromStrImplFragment {
       name,
       arms,
       serde_names,
+      case_insensitive,
+      fallback_variant: fallback_variant.map(|v| v.name),
+      scalar_fallback: None,
     }
   }
+
+  /// Parses values no arm matches into `scalar_fallback`, the variant holding unlisted
+  /// integers, rejecting only text that is not an integer.
+  pub(crate) fn with_scalar_fallback(mut self, scalar_fallback: Option<EnumVariantToken>) -> Self {
+    self.scalar_fallback = scalar_fallback;
+    self
+  }
 }
 
 impl ToTokens for FromStrImplFragment {
@@ -342,14 +377,30 @@ impl ToTokens for FromStrImplFragment {
     let serde_names = &self.serde_names;
     let expected = serde_names.join(", ");
 
+    let scrutinee = if self.case_insensitive {
+      quote! { s.to_ascii_lowercase().as_str() }
+    } else {
+      quote! { s }
+    };
+    let fallback_arm = match (&self.scalar_fallback, &self.fallback_variant) {
+      (Some(fallback), _) => quote! {
+        _ => s
+          .parse()
+          .map(Self::#fallback)
+          .map_err(|_| format!("unknown variant '{}', expected one of: {}", s, #expected)),
+      },
+      (None, Some(fallback)) => quote! { _ => Ok(Self::#fallback), },
+      (None, None) => quote! { _ => Err(format!("unknown variant '{}', expected one of: {}", s, #expected)), },
+    };
+
     let ts = quote! {
       impl core::str::FromStr for #name {
         type Err = String;
 
         fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
-          match s {
+          match #scrutinee {
             #(#arms)*
-            _ => Err(format!("unknown variant '{}', expected one of: {}", s, #expected)),
+            #fallback_arm
           }
         }
       }
@@ -450,16 +501,25 @@ pub(crate) struct NumericEnumSerdeImplFragment {
   is_float: bool,
   wire_type: Ident,
   serialize_method: Ident,
-  arms: Vec<(EnumVariantToken, Literal)>,
+  arms: Vec<(EnumVariantToken, TokenStream)>,
   expected: String,
+  fallback: Option<EnumVariantToken>,
 }
 
 impl NumericEnumSerdeImplFragment {
-  pub(crate) fn new(name: EnumToken, primitive: &RustPrimitive, variants: Vec<VariantDef>) -> Self {
+  pub(crate) fn new(
+    name: EnumToken,
+    primitive: &RustPrimitive,
+    variants: Vec<VariantDef>,
+    fallback: Option<EnumVariantToken>,
+  ) -> Self {
     let is_float = primitive.is_float();
     let is_unsigned = primitive.is_unsigned_integer();
+    let is_bool = *primitive == RustPrimitive::Bool;
 
-    let (wire_name, serialize_name) = if is_float {
+    let (wire_name, serialize_name) = if is_bool {
+      ("bool", "serialize_bool")
+    } else if is_float {
       ("f64", "serialize_f64")
     } else if is_unsigned {
       ("u64", "serialize_u64")
@@ -471,12 +531,23 @@ impl NumericEnumSerdeImplFragment {
     let mut expected_values = vec![];
     for variant in variants {
       let raw = variant.serde_name();
-      let literal = if is_float {
-        raw.parse::<f64>().ok().map(Literal::f64_suffixed)
+      let literal = if is_bool {
+        raw.parse::<bool>().ok().map(|value| quote! { #value })
+      } else if is_float {
+        raw
+          .parse::<f64>()
+          .ok()
+          .map(|value| Literal::f64_suffixed(value).into_token_stream())
       } else if is_unsigned {
-        raw.parse::<u64>().ok().map(Literal::u64_suffixed)
+        raw
+          .parse::<u64>()
+          .ok()
+          .map(|value| Literal::u64_suffixed(value).into_token_stream())
       } else {
-        raw.parse::<i64>().ok().map(Literal::i64_suffixed)
+        raw
+          .parse::<i64>()
+          .ok()
+          .map(|value| Literal::i64_suffixed(value).into_token_stream())
       };
       if let Some(literal) = literal {
         expected_values.push(raw);
@@ -491,6 +562,7 @@ impl NumericEnumSerdeImplFragment {
       serialize_method: Ident::new(serialize_name, Span::call_site()),
       arms,
       expected: expected_values.join(", "),
+      fallback,
     }
   }
 }
@@ -506,6 +578,12 @@ impl ToTokens for NumericEnumSerdeImplFragment {
       .arms
       .iter()
       .map(|(variant_name, literal)| quote! { Self::#variant_name => #literal, })
+      .chain(
+        self
+          .fallback
+          .iter()
+          .map(|fallback| quote! { Self::#fallback(value) => *value, }),
+      )
       .collect::<Vec<TokenStream>>();
 
     let deserialize_body = if self.is_float {
@@ -527,11 +605,20 @@ impl ToTokens for NumericEnumSerdeImplFragment {
         .iter()
         .map(|(variant_name, literal)| quote! { #literal => Ok(Self::#variant_name), })
         .collect::<Vec<TokenStream>>();
+      let covers_all_bools = *wire_type == "bool" && self.arms.len() == 2;
+      let fallback = match &self.fallback {
+        Some(fallback) => Some(quote! { other => Ok(Self::#fallback(other)), }),
+        None => (!covers_all_bools).then(|| {
+          quote! {
+            _ => Err(serde::de::Error::custom(format!("unknown variant {}, expected one of: {}", value, #expected))),
+          }
+        }),
+      };
       quote! {
         let value = #wire_type::deserialize(deserializer)?;
         match value {
           #(#arms)*
-          _ => Err(serde::de::Error::custom(format!("unknown variant {}, expected one of: {}", value, #expected))),
+          #fallback
         }
       }
     };
@@ -567,16 +654,11 @@ impl ToTokens for NumericEnumSerdeImplFragment {
 pub(crate) struct EnumFragment {
   def: EnumDef,
   vis: Visibility,
-  target: GenerationTarget,
 }
 
 impl EnumFragment {
-  pub(crate) fn new(def: EnumDef, visibility: Visibility, target: GenerationTarget) -> Self {
-    Self {
-      def,
-      vis: visibility,
-      target,
-    }
+  pub(crate) fn new(def: EnumDef, visibility: Visibility) -> Self {
+    Self { def, vis: visibility }
   }
 }
 
@@ -624,15 +706,23 @@ impl ToTokens for EnumFragment {
       quote! {}
     };
 
-    let from_str_impl = if self.def.generate_display && self.def.is_simple() && self.target == GenerationTarget::Server
-    {
-      FromStrImplFragment::new(name.clone(), self.def.variants.clone()).to_token_stream()
+    let scalar_fallback = self.def.scalar_fallback().map(|v| v.name.clone());
+    let from_str_impl = if self.def.generate_display && (self.def.is_simple() || scalar_fallback.is_some()) {
+      FromStrImplFragment::new(
+        name.clone(),
+        self.def.variants.clone(),
+        self.def.case_insensitive,
+        self.def.fallback_variant().cloned(),
+      )
+      .with_scalar_fallback(scalar_fallback.clone())
+      .to_token_stream()
     } else {
       quote! {}
     };
 
     let ts = if let Some(primitive) = &self.def.scalar_repr {
-      let serde_impl = NumericEnumSerdeImplFragment::new(name.clone(), primitive, self.def.variants.clone());
+      let serde_impl =
+        NumericEnumSerdeImplFragment::new(name.clone(), primitive, self.def.variants.clone(), scalar_fallback);
       quote! {
         #enum_def
         #display_impl
@@ -987,6 +1077,108 @@ impl ResponseEnumFragment {
       .map(ResponseVariantFragment::new)
       .collect::<Vec<ResponseVariantFragment>>()
   }
+
+  /// Generates `links()`, `next()`, and `prev()` for a response whose variants carry
+  /// `Link` header page links, turning them into follow-up requests for the same operation.
+  fn page_links(&self) -> Option<TokenStream> {
+    let request = self.def.request_type.as_ref()?;
+    let linked = self
+      .def
+      .variants
+      .iter()
+      .filter(|variant| variant.page_links)
+      .map(|variant| &variant.variant_name)
+      .collect::<Vec<_>>();
+    if linked.is_empty() {
+      return None;
+    }
+    let name = &self.def.name;
+    let vis = &self.vis;
+    let page_link = format_ident!("{PAGE_LINK_FIELD}");
+    let unlinked =
+      (linked.len() < self.def.variants.len() || self.def.malformed_variant.is_some()).then(|| quote! { _ => None, });
+    let follow = |rel: &str| {
+      let method = format_ident!("{rel}");
+      let doc = format!(" Returns `request` sent to the `rel=\"{rel}\"` link of this response, if it has one.");
+      quote! {
+        #[doc = #doc]
+        #[must_use]
+        #vis fn #method(&self, request: #request) -> Option<#request> {
+          let link = self.links()?.#method.clone()?;
+          Some(#request {
+            #page_link: Some(link),
+            ..request
+          })
+        }
+      }
+    };
+    let next = follow("next");
+    let prev = follow("prev");
+    Some(quote! {
+      impl #name {
+        #[doc = " Returns the page links of this response's `Link` header, if its status declares one."]
+        #[must_use]
+        #vis fn links(&self) -> Option<&oas3_gen_support::PageLinks> {
+          match self {
+            #(Self::#linked { links, .. } => Some(links),)*
+            #unlinked
+          }
+        }
+
+        #next
+
+        #prev
+      }
+    })
+  }
+
+  /// Generates `classify`, returning the class of each status the operation documents,
+  /// with specific codes taking precedence over `1XX` through `5XX` wildcards.
+  ///
+  /// Wildcards only get an arm when retryable, so statuses such as `503` keep their
+  /// retryable class from `ResponseClass::from` under a documented `5XX`.
+  fn classify(&self) -> Option<TokenStream> {
+    if self.def.status_classes.is_empty() {
+      return None;
+    }
+    let name = &self.def.name;
+    let vis = &self.vis;
+    let arms = self
+      .def
+      .status_classes
+      .iter()
+      .filter(|(status_code, class)| status_code.code().is_some() || **class == ResponseClass::Retryable)
+      .sorted_by_key(|(status_code, _)| status_code.code().is_none())
+      .filter_map(|(status_code, class)| {
+        let pattern = status_code.code_pattern()?;
+        Some(quote! { #pattern => #class, })
+      });
+    Some(quote! {
+      impl #name {
+        #[doc = " Returns the class of `status` for this operation: its documented class, or"]
+        #[doc = " `ResponseClass::from(status)` for a status the spec does not list."]
+        #[must_use]
+        #vis fn classify(status: http::StatusCode) -> oas3_gen_support::ResponseClass {
+          match status.as_u16() {
+            #(#arms)*
+            _ => oas3_gen_support::ResponseClass::from(status),
+          }
+        }
+      }
+    })
+  }
+
+  fn named_events(&self) -> Vec<NamedEventsFragment> {
+    self
+      .def
+      .variants
+      .iter()
+      .flat_map(|variant| &variant.media_types)
+      .filter_map(|media_type| media_type.named_events.clone())
+      .unique_by(|named_events| named_events.name.clone())
+      .map(|named_events| NamedEventsFragment::new(self.vis, named_events))
+      .collect()
+  }
 }
 
 impl ToTokens for ResponseEnumFragment {
@@ -996,12 +1188,97 @@ impl ToTokens for ResponseEnumFragment {
     let variants = EnumVariants::new(self.variants());
     let derives = DeriveAttribute::new(self.def.derives());
     let vis = &self.vis;
+    let malformed = self.def.malformed_variant.as_ref().map(|variant| {
+      quote! {
+        ,
+        #[doc = "Response body did not match its declared schema"]
+        #variant(oas3_gen_support::MalformedResponse)
+      }
+    });
+
+    let named_events = self.named_events();
+    let page_links = self.page_links();
+    let classify = self.classify();
 
     let ts = quote! {
       #docs
       #derives
       #vis enum #name {
         #variants
+        #malformed
+      }
+
+      #page_links
+
+      #classify
+
+      #(#named_events)*
+    };
+
+    tokens.extend(ts);
+  }
+}
+
+/// Generates the enum over the named events of an event-stream response, decoding
+/// each event's `data` by its SSE `event:` name.
+#[derive(Clone, Debug)]
+pub(crate) struct NamedEventsFragment {
+  vis: Visibility,
+  def: NamedEventsDef,
+}
+
+impl NamedEventsFragment {
+  pub(crate) fn new(vis: Visibility, def: NamedEventsDef) -> Self {
+    Self { vis, def }
+  }
+}
+
+impl ToTokens for NamedEventsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let name = &self.def.name;
+    let vis = &self.vis;
+    let mut derives = BTreeSet::from([DeriveTrait::Debug]);
+    if !self.def.skip_clone {
+      derives.insert(DeriveTrait::Clone);
+    }
+    let derives = DeriveAttribute::new(derives);
+    let unknown = EnumVariantToken::from_raw(UNKNOWN_EVENT_VARIANT);
+
+    let variants = self.def.events.iter().map(|event| {
+      let doc = format!("The `{}` event.", event.event);
+      let variant = &event.variant;
+      let schema_type = &event.schema_type;
+      quote! {
+        #[doc = #doc]
+        #variant(#schema_type)
+      }
+    });
+
+    let arms = self.def.events.iter().map(|event| {
+      let event_name = &event.event;
+      let variant = &event.variant;
+      quote! { #event_name => oas3_gen_support::parse_event_data(data).map(Self::#variant) }
+    });
+
+    let ts = quote! {
+      #[doc = "Server-sent events, decoded by their `event:` name."]
+      #derives
+      #vis enum #name {
+        #(#variants,)*
+        #[doc = "An event whose name the spec does not list."]
+        #unknown { event: String, data: String },
+      }
+
+      impl oas3_gen_support::NamedEvent for #name {
+        fn from_event(event: &str, data: &str) -> Result<Self, oas3_gen_support::EventStreamError> {
+          match event {
+            #(#arms,)*
+            _ => Ok(Self::#unknown {
+              event: event.to_string(),
+              data: data.to_string(),
+            }),
+          }
+        }
       }
     };
 
@@ -1044,9 +1321,24 @@ impl ToTokens for ResponseVariantFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let variant_name = &self.variant.variant_name;
     let doc_line = self.variant.doc_line();
-    let content = self.variant.schema_type.as_ref().map(|schema| {
-      quote! { (#schema) }
-    });
+    let content = if self.variant.has_named_fields() {
+      let status = self
+        .variant
+        .captures_status()
+        .then(|| quote! { status: http::StatusCode, });
+      let links = self
+        .variant
+        .page_links
+        .then(|| quote! { links: oas3_gen_support::PageLinks, });
+      let retry_after = self
+        .variant
+        .retry_after
+        .then(|| quote! { retry_after: Option<std::time::Duration>, });
+      let body = self.variant.schema_type.as_ref().map(|schema| quote! { body: #schema });
+      Some(quote! { { #status #links #retry_after #body } })
+    } else {
+      self.variant.schema_type.as_ref().map(|schema| quote! { (#schema) })
+    };
 
     let ts = quote! {
       #[doc = #doc_line]
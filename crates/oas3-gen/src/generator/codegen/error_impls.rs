@@ -0,0 +1,94 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generator::ast::{ErrorSourceField, MessageFieldAccess, StructDef};
+
+/// Generates `Display` and `std::error::Error` impls for an error response body.
+///
+/// `Display` writes the field named by the struct's message path, falling back to the
+/// `Debug` output when the path is empty or an optional field on it is absent. `source`
+/// returns the nested error object when the struct has one.
+/// Returns an empty stream for structs not flagged as errors.
+pub(crate) fn generate_error_impl(def: &StructDef) -> TokenStream {
+  if !def.error_impl {
+    return quote! {};
+  }
+
+  let name = &def.name;
+  let body = display_body(&def.error_message_path);
+  let source = def.error_source.as_ref().map(source_method);
+  quote! {
+    impl core::fmt::Display for #name {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #body
+      }
+    }
+
+    impl std::error::Error for #name {
+      #source
+    }
+  }
+}
+
+/// Builds the `source` method returning the nested error object, or forwarding to its
+/// own source when the message is read from inside it.
+fn source_method(source: &ErrorSourceField) -> TokenStream {
+  let ErrorSourceField {
+    name,
+    optional,
+    boxed,
+    forwarded,
+  } = source;
+
+  let field = match (optional, boxed) {
+    (false, false) => quote! { &self.#name },
+    (false, true) => quote! { &*self.#name },
+    (true, false) => quote! { self.#name.as_ref() },
+    (true, true) => quote! { self.#name.as_deref() },
+  };
+  let body = match (optional, forwarded) {
+    (false, false) => quote! { Some(#field) },
+    (false, true) => quote! { std::error::Error::source(#field) },
+    (true, false) => quote! { #field.map(|source| source as &(dyn std::error::Error + 'static)) },
+    (true, true) => quote! { #field.and_then(std::error::Error::source) },
+  };
+
+  quote! {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+      #body
+    }
+  }
+}
+
+/// Builds the `Display` body for a message path.
+///
+/// Required fields are accessed directly. Once an optional field is reached the access
+/// continues as an `Option` chain, and the `Debug` output covers the `None` case.
+fn display_body(path: &[MessageFieldAccess]) -> TokenStream {
+  if path.is_empty() {
+    return quote! { write!(f, "{self:?}") };
+  }
+
+  let mut access = quote! { self };
+  let mut in_option = false;
+  for MessageFieldAccess { name, optional } in path {
+    access = match (in_option, optional) {
+      (false, false) => quote! { #access.#name },
+      (false, true) => quote! { #access.#name.as_ref() },
+      (true, false) => quote! { #access.map(|value| &value.#name) },
+      (true, true) => quote! { #access.and_then(|value| value.#name.as_ref()) },
+    };
+    in_option |= optional;
+  }
+
+  if in_option {
+    quote! {
+      match #access {
+        Some(message) => write!(f, "{message}"),
+        None => write!(f, "{self:?}"),
+      }
+    }
+  } else {
+    quote! { write!(f, "{}", #access) }
+  }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use serde_json::Value;
+
+use crate::generator::{
+  ast::{RustType, StructKind},
+  naming::identifiers::to_rust_field_name,
+};
+
+/// Generates a `#[cfg(test)] mod example_tests` with one round-trip test per schema
+/// type that has an `example` in the spec.
+///
+/// Each test deserializes the example into the type, serializes the value, and
+/// deserializes that output again, asserting both values are equal. A failing test
+/// means the generated type no longer matches what the spec documents.
+#[derive(Clone, Debug)]
+pub(crate) struct ExampleTestsFragment {
+  tests: Vec<TokenStream>,
+}
+
+impl ExampleTestsFragment {
+  /// `examples` maps Rust type names to the example of the schema they came from.
+  pub(crate) fn new(rust_types: &[RustType], examples: &BTreeMap<String, Value>) -> Self {
+    let tests = rust_types
+      .iter()
+      .filter(|rust_type| has_serde(rust_type))
+      .filter_map(|rust_type| {
+        let name = rust_type.type_name();
+        Some(example_test(&name, examples.get(name.as_ref())?))
+      })
+      .collect();
+    Self { tests }
+  }
+}
+
+impl ToTokens for ExampleTestsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if self.tests.is_empty() {
+      return;
+    }
+    let tests = &self.tests;
+    quote! {
+      #[cfg(test)]
+      mod example_tests {
+        use super::*;
+
+        #(#tests)*
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+/// Returns `true` for the types a schema example can describe.
+pub(super) fn has_serde(rust_type: &RustType) -> bool {
+  match rust_type {
+    RustType::Struct(def) => def.kind == StructKind::Schema,
+    RustType::Enum(_) | RustType::TypeAlias(_) | RustType::DiscriminatedEnum(_) => true,
+    RustType::ResponseEnum(_) => false,
+  }
+}
+
+fn example_test(type_name: &str, example: &Value) -> TokenStream {
+  let ty = format_ident!("{type_name}");
+  let test_name = format_ident!("{}", to_rust_field_name(&format!("{type_name}ExampleRoundTrips")));
+  let example = raw_string(&example.to_string());
+  let parse_example = format!("example should deserialize as `{type_name}`");
+  let serialize = format!("`{type_name}` should serialize");
+  let parse_output = format!("`{type_name}` should deserialize its own output");
+  quote! {
+    #[tokio::test]
+    async fn #test_name() {
+      let value: #ty = serde_json::from_str(#example).expect(#parse_example);
+      let json = serde_json::to_string(&value).expect(#serialize);
+      let round_tripped: #ty = serde_json::from_str(&json).expect(#parse_output);
+      assert_eq!(round_tripped, value);
+    }
+  }
+}
+
+/// Writes `text` as a raw string literal, so JSON quotes need no escaping.
+pub(super) fn raw_string(text: &str) -> Literal {
+  let hashes = (0..)
+    .map(|count| "#".repeat(count))
+    .find(|hashes| !text.contains(&format!("\"{hashes}")))
+    .unwrap_or_default();
+  format!("r{hashes}\"{text}\"{hashes}")
+    .parse()
+    .unwrap_or_else(|_| Literal::string(text))
+}
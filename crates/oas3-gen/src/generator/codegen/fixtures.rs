@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use serde_json::Value;
+
+use crate::generator::{
+  ast::{ContentCategory, OperationInfo, RustType},
+  naming::identifiers::to_rust_field_name,
+};
+
+/// Directory, relative to the fixtures output, holding one file per schema example.
+const SCHEMAS_DIR: &str = "schemas";
+/// Directory, relative to the fixtures output, holding one file per response example.
+const RESPONSES_DIR: &str = "responses";
+/// File holding the tests that load every fixture.
+pub(crate) const FIXTURE_TESTS_FILE: &str = "tests.rs";
+
+/// An example payload from the spec, written to `path` and deserialized into `rust_type`
+/// by its test.
+#[derive(Clone, Debug)]
+pub(crate) struct Fixture {
+  pub(crate) path: String,
+  pub(crate) body: Value,
+  rust_type: TokenStream,
+  test_name: String,
+}
+
+impl Fixture {
+  /// One fixture for each component schema example, keyed in `examples` by Rust type name.
+  pub(crate) fn for_schemas(examples: &BTreeMap<String, Value>) -> Vec<Self> {
+    examples
+      .iter()
+      .map(|(type_name, example)| {
+        let ty = format_ident!("{type_name}");
+        Self {
+          path: format!("{SCHEMAS_DIR}/{type_name}.json"),
+          body: example.clone(),
+          rust_type: quote! { #ty },
+          test_name: to_rust_field_name(&format!("{type_name}FixtureDeserializes")),
+        }
+      })
+      .collect()
+  }
+
+  /// One fixture for each status of an operation whose JSON response has an example.
+  pub(crate) fn for_responses(operations: &[OperationInfo], rust_types: &[RustType]) -> Vec<Self> {
+    let response_enums = rust_types
+      .iter()
+      .filter_map(|rust_type| match rust_type {
+        RustType::ResponseEnum(def) => Some((def.name.to_string(), def)),
+        _ => None,
+      })
+      .collect::<BTreeMap<_, _>>();
+
+    operations
+      .iter()
+      .filter_map(|op| Some((op, *response_enums.get(&op.response_enum.as_ref()?.to_string())?)))
+      .flat_map(|(op, def)| {
+        def.variants.iter().filter_map(move |variant| {
+          let (schema_type, example) = variant
+            .media_types
+            .iter()
+            .filter(|media_type| media_type.category == ContentCategory::Json)
+            .find_map(|media_type| Some((media_type.schema_type.as_ref()?, media_type.example.as_ref()?)))?;
+          let name = format!("{}_{}", op.stable_id, variant.status_code).to_lowercase();
+          Some(Self {
+            path: format!("{RESPONSES_DIR}/{name}.json"),
+            body: example.clone(),
+            rust_type: schema_type.to_token_stream(),
+            test_name: to_rust_field_name(&format!("{name}_response_fixture_deserializes")),
+          })
+        })
+      })
+      .collect()
+  }
+
+  /// Returns the fixture file contents, pretty-printed with a trailing newline.
+  pub(crate) fn contents(&self) -> anyhow::Result<String> {
+    Ok(format!("{}\n", serde_json::to_string_pretty(&self.body)?))
+  }
+
+  fn test(&self) -> TokenStream {
+    let test_name = format_ident!("{}", self.test_name);
+    let rust_type = &self.rust_type;
+    let path = &self.path;
+    let message = format!("`{path}` should deserialize");
+    quote! {
+      #[test]
+      fn #test_name() {
+        serde_json::from_str::<#rust_type>(include_str!(#path)).expect(#message);
+      }
+    }
+  }
+}
+
+/// Generates the tests loading every fixture, each deserializing one file into the
+/// type generated for it.
+///
+/// Paths are relative to the tests file, so it can be included from anywhere with
+/// `#[path]`; generated types are imported from `types_path`.
+#[derive(Clone, Debug)]
+pub(crate) struct FixtureTestsFragment<'a> {
+  fixtures: &'a [Fixture],
+  types_path: TokenStream,
+}
+
+impl<'a> FixtureTestsFragment<'a> {
+  pub(crate) fn new(fixtures: &'a [Fixture], types_path: &syn::Path) -> Self {
+    Self {
+      fixtures,
+      types_path: types_path.to_token_stream(),
+    }
+  }
+}
+
+impl ToTokens for FixtureTestsFragment<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let types_path = &self.types_path;
+    let tests = self.fixtures.iter().map(Fixture::test);
+    tokens.extend(quote! {
+      use #types_path::*;
+
+      #(#tests)*
+    });
+  }
+}
@@ -0,0 +1,156 @@
+use std::{
+  cell::{Cell, RefCell},
+  io::Write as _,
+  num::NonZeroUsize,
+  process::{Command, Stdio},
+  thread,
+  time::{Duration, Instant},
+};
+
+use super::{GeneratedFileType, SourceText};
+use crate::generator::{converter::OutputFormatPolicy, metrics::GenerationWarning};
+
+/// Turns generated sources into text with the configured [`OutputFormatPolicy`].
+///
+/// A source the formatter rejects is kept as it was before that formatting step
+/// rather than failing generation. The time spent formatting and each rejected file
+/// are recorded for the generation stats.
+#[derive(Debug, Default)]
+pub struct SourceFormatter {
+  policy: OutputFormatPolicy,
+  elapsed: Cell<Duration>,
+  failures: RefCell<Vec<GenerationWarning>>,
+}
+
+impl SourceFormatter {
+  #[must_use]
+  pub fn new(policy: OutputFormatPolicy) -> Self {
+    Self {
+      policy,
+      ..Self::default()
+    }
+  }
+
+  #[must_use]
+  pub fn policy(&self) -> OutputFormatPolicy {
+    self.policy
+  }
+
+  /// Formats the source of `file`.
+  pub(super) fn format(&self, file: &str, text: &SourceText) -> String {
+    let start = Instant::now();
+    let (source, error) = format_text(self.policy, text);
+    self.record(start, [(file.to_string(), error)]);
+    source
+  }
+
+  /// Formats every source, spreading them across threads when there are several, since
+  /// formatting dominates generation time once the output is split into many modules.
+  pub(super) fn format_all(&self, sources: Vec<(GeneratedFileType, SourceText)>) -> Vec<(GeneratedFileType, String)> {
+    let start = Instant::now();
+    let workers = thread::available_parallelism()
+      .map_or(1, NonZeroUsize::get)
+      .min(sources.len());
+    let (file_types, texts) = sources.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+    let results = if workers > 1 {
+      let mut results = texts.iter().map(|_| None).collect::<Vec<_>>();
+      thread::scope(|scope| {
+        let handles = (0..workers)
+          .map(|worker| {
+            let texts = &texts;
+            let policy = self.policy;
+            scope.spawn(move || {
+              (worker..texts.len())
+                .step_by(workers)
+                .map(|index| (index, format_text(policy, &texts[index])))
+                .collect::<Vec<_>>()
+            })
+          })
+          .collect::<Vec<_>>();
+        for handle in handles {
+          let formatted = handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+          for (index, result) in formatted {
+            results[index] = Some(result);
+          }
+        }
+      });
+      results.into_iter().flatten().collect::<Vec<_>>()
+    } else {
+      texts
+        .iter()
+        .map(|text| format_text(self.policy, text))
+        .collect::<Vec<_>>()
+    };
+
+    let mut formatted = vec![];
+    let mut errors = vec![];
+    for (file_type, (source, error)) in file_types.into_iter().zip(results) {
+      errors.push((file_type.file_name().into_owned(), error));
+      formatted.push((file_type, source));
+    }
+    self.record(start, errors);
+    formatted
+  }
+
+  /// Returns the time spent formatting since the last call, and resets it.
+  pub fn take_elapsed(&self) -> Duration {
+    self.elapsed.take()
+  }
+
+  /// Returns the files left unformatted since the last call, and resets them.
+  pub fn take_failures(&self) -> Vec<GenerationWarning> {
+    self.failures.take()
+  }
+
+  fn record(&self, start: Instant, errors: impl IntoIterator<Item = (String, Option<String>)>) {
+    self.elapsed.set(self.elapsed.get() + start.elapsed());
+    self.failures.borrow_mut().extend(
+      errors
+        .into_iter()
+        .filter_map(|(file, error)| Some(GenerationWarning::FormattingFailed { file, error: error? })),
+    );
+  }
+}
+
+/// Formats `text` with `policy`, returning the source and, when a formatting step
+/// failed, its error alongside the source as it was before that step.
+fn format_text(policy: OutputFormatPolicy, text: &SourceText) -> (String, Option<String>) {
+  let raw = format!("{}{}\n{}\n", text.preamble, text.header, text.code);
+  if policy == OutputFormatPolicy::None {
+    return (raw, None);
+  }
+
+  let pretty = match text.format() {
+    Ok(source) => source,
+    Err(e) => return (raw, Some(e.to_string())),
+  };
+  if policy == OutputFormatPolicy::Prettyplease {
+    return (pretty, None);
+  }
+
+  match rustfmt(&pretty) {
+    Ok(source) => (source, None),
+    Err(e) => (pretty, Some(e.to_string())),
+  }
+}
+
+/// Formats `source` with the `rustfmt` found on `PATH`.
+fn rustfmt(source: &str) -> anyhow::Result<String> {
+  let mut child = Command::new("rustfmt")
+    .args(["--edition", "2024"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|e| anyhow::anyhow!("failed to run rustfmt: {e}"))?;
+  child
+    .stdin
+    .take()
+    .ok_or_else(|| anyhow::anyhow!("failed to open rustfmt stdin"))?
+    .write_all(source.as_bytes())?;
+  let output = child.wait_with_output()?;
+  if !output.status.success() {
+    anyhow::bail!("rustfmt failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+  }
+  Ok(String::from_utf8(output.stdout)?)
+}
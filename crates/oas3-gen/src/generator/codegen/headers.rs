@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
-use crate::generator::ast::{FieldDef, StructDef, StructKind, TypeRef, tokens::ConstToken};
+use crate::generator::ast::{FieldDef, ObjectStyle, StructDef, StructKind, simple_style_value, tokens::ConstToken};
 
 #[derive(Clone, Debug)]
 pub(crate) struct HeaderMapFragment {
@@ -75,10 +75,8 @@ impl ToTokens for HeaderFieldInsertionFragment {
     };
 
     let header_const = ConstToken::from_raw(original_name);
-    let ty = &self.field.rust_type;
-
     let insertion = if self.field.rust_type.nullable {
-      let header_value = header_value_expr(ty, quote! { value });
+      let header_value = header_value_expr(&self.field, quote! { value });
       quote! {
         if let Some(value) = &headers.#field_name {
           let header_value = http::HeaderValue::try_from(#header_value)?;
@@ -86,7 +84,7 @@ impl ToTokens for HeaderFieldInsertionFragment {
         }
       }
     } else {
-      let header_value = header_value_expr(ty, quote! { &headers.#field_name });
+      let header_value = header_value_expr(&self.field, quote! { &headers.#field_name });
       quote! {
         let header_value = http::HeaderValue::try_from(#header_value)?;
         map.insert(#header_const, header_value);
@@ -97,11 +95,12 @@ impl ToTokens for HeaderFieldInsertionFragment {
   }
 }
 
-fn header_value_expr(ty: &TypeRef, accessor: TokenStream) -> TokenStream {
+fn header_value_expr(field: &FieldDef, accessor: TokenStream) -> TokenStream {
+  let ty = &field.rust_type;
   if ty.is_string_like() {
     accessor
-  } else if ty.is_array {
-    quote! { #accessor.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",") }
+  } else if ty.is_array || field.object_style.is_some() {
+    simple_style_value(&accessor, field.object_style)
   } else {
     quote! { #accessor.to_string() }
   }
@@ -179,7 +178,7 @@ impl ToTokens for HeaderFieldExtractionFragment {
     };
 
     let header_const = ConstToken::from_raw(original_name);
-    let parse_expr = header_parse_expr(&self.field.rust_type, &quote! { value });
+    let parse_expr = header_parse_expr(&self.field, &quote! { value });
     let default_suffix = (!self.field.rust_type.nullable).then(|| quote! { .unwrap_or_default() });
 
     tokens.extend(quote! {
@@ -192,8 +191,12 @@ impl ToTokens for HeaderFieldExtractionFragment {
   }
 }
 
-fn header_parse_expr(ty: &TypeRef, accessor: &TokenStream) -> TokenStream {
-  if ty.is_string_like() {
+fn header_parse_expr(field: &FieldDef, accessor: &TokenStream) -> TokenStream {
+  let ty = &field.rust_type;
+  if let Some(style) = field.object_style {
+    let explode = style == ObjectStyle::Exploded;
+    quote! { oas3_gen_support::from_simple_style(#accessor, #explode).unwrap_or_default() }
+  } else if ty.is_string_like() {
     quote! { #accessor.to_string() }
   } else if ty.is_array {
     quote! { #accessor.split(',').map(|s| s.trim()).filter_map(|s| s.parse().ok()).collect() }
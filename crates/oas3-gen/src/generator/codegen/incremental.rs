@@ -0,0 +1,31 @@
+use std::{cell::Cell, collections::HashMap};
+
+/// Formatted module sources from a previous run, keyed by the fingerprint of the
+/// unformatted source they were produced from.
+///
+/// Formatting dominates code generation time on large specs, so a module whose
+/// fingerprint is unchanged reuses its previous source instead of being formatted
+/// again.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleCache {
+  sources: HashMap<String, String>,
+  reused: Cell<usize>,
+}
+
+impl ModuleCache {
+  pub fn insert(&mut self, fingerprint: impl Into<String>, source: impl Into<String>) {
+    self.sources.insert(fingerprint.into(), source.into());
+  }
+
+  /// Returns the previous source for `fingerprint`, counting it as reused.
+  pub(crate) fn get(&self, fingerprint: &str) -> Option<String> {
+    let source = self.sources.get(fingerprint).cloned()?;
+    self.reused.set(self.reused.get() + 1);
+    Some(source)
+  }
+
+  /// Returns how many modules were reused since the last call, and resets the count.
+  pub fn take_reused(&self) -> usize {
+    self.reused.take()
+  }
+}
@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use serde_json::Value;
+
+use super::{Visibility, coercion};
+use crate::generator::ast::{
+  EnumDef, ResponseEnumDef, ResponseMediaType, ResponseVariant, RustPrimitive, RustType, ServerRequestTraitDef,
+  ServerTraitMethod, StructDef, TypeRef,
+};
+
+/// Nesting depth past which mock values fall back to `Default::default()`.
+const MAX_DEPTH: usize = 8;
+
+/// Generates `MockServer`, an `ApiServer` implementation answering every operation
+/// with its first success response, built from the `example` values in the spec.
+///
+/// Fields without an example keep their defaults, so the mock always compiles; pass
+/// it to `router` to serve it for client integration tests. Event-stream operations
+/// answer with an error, since a stream cannot be built from an example.
+#[derive(Clone, Debug)]
+pub(crate) struct MockServerFragment<'a> {
+  server_trait: Option<&'a ServerRequestTraitDef>,
+  types: HashMap<String, &'a RustType>,
+  vis: Visibility,
+}
+
+impl<'a> MockServerFragment<'a> {
+  pub(crate) fn new(
+    server_trait: Option<&'a ServerRequestTraitDef>,
+    rust_types: &'a [RustType],
+    vis: Visibility,
+  ) -> Self {
+    let types = rust_types.iter().map(|ty| (ty.type_name().to_string(), ty)).collect();
+    Self {
+      server_trait,
+      types,
+      vis,
+    }
+  }
+
+  fn method(&self, method: &ServerTraitMethod) -> TokenStream {
+    let name = &method.name;
+    let request_param = method.request_type.as_ref().map(|req| quote! { _request: #req });
+    let (return_type, body) = match &method.response_type {
+      Some(resp) => {
+        let value = match self.types.get(resp.as_str()) {
+          Some(RustType::ResponseEnum(def)) => self.response_value(def),
+          _ => Some(quote! { Default::default() }),
+        };
+        let body = match value {
+          Some(value) => quote! { Ok(#value) },
+          None => {
+            let message = format!("MockServer has no example event stream for `{name}`");
+            quote! { Err(anyhow::anyhow!(#message)) }
+          }
+        };
+        (quote! { #resp }, body)
+      }
+      None => (quote! { () }, quote! { Ok(()) }),
+    };
+
+    quote! {
+      async fn #name(&self, #request_param) -> anyhow::Result<#return_type> {
+        #body
+      }
+    }
+  }
+
+  /// Builds the response to answer with, or `None` for an event stream, which has no
+  /// value to build without a live connection.
+  fn response_value(&self, def: &ResponseEnumDef) -> Option<TokenStream> {
+    let name = &def.name;
+    let Some(variant) = preferred_variant(&def.variants) else {
+      return Some(quote! { Default::default() });
+    };
+    if ResponseMediaType::has_event_stream(&variant.media_types) {
+      return None;
+    }
+    let variant_name = &variant.variant_name;
+    let links = variant.page_links.then(|| quote! { links: Default::default(), });
+    let retry_after = variant.retry_after.then(|| quote! { retry_after: None, });
+    let Some(schema) = &variant.schema_type else {
+      return Some(if variant.has_named_fields() {
+        quote! { #name::#variant_name { #links #retry_after } }
+      } else {
+        quote! { #name::#variant_name }
+      });
+    };
+
+    let example = variant
+      .media_types
+      .iter()
+      .find_map(|media_type| media_type.example.as_ref());
+    let body = self
+      .value(schema, example, &mut HashSet::new())
+      .unwrap_or_else(|| quote! { Default::default() });
+    if variant.has_named_fields() {
+      let status = variant
+        .captures_status()
+        .then(|| quote! { status: http::StatusCode::OK, });
+      Some(quote! { #name::#variant_name { #status #links #retry_after body: #body } })
+    } else {
+      Some(quote! { #name::#variant_name(#body) })
+    }
+  }
+
+  /// Builds an expression of type `rust_type` from `example`, or `None` when the
+  /// type's default is all there is to offer.
+  fn value(&self, rust_type: &TypeRef, example: Option<&Value>, visiting: &mut HashSet<String>) -> Option<TokenStream> {
+    if matches!(example, Some(Value::Null)) {
+      return rust_type.nullable.then(|| quote! { None });
+    }
+
+    let expr = if rust_type.is_array {
+      let items = match example {
+        Some(Value::Array(items)) => items
+          .iter()
+          .map(|item| self.element(rust_type, Some(item), visiting))
+          .collect::<Option<Vec<_>>>()?,
+        Some(_) => return None,
+        None => vec![self.element(rust_type, None, visiting)?],
+      };
+      if rust_type.unique_items {
+        quote! { indexmap::IndexSet::from_iter([#(#items),*]) }
+      } else {
+        quote! { Vec::from([#(#items),*]) }
+      }
+    } else {
+      self.element(rust_type, example, visiting)?
+    };
+
+    Some(if rust_type.nullable {
+      quote! { Some(#expr) }
+    } else {
+      expr
+    })
+  }
+
+  fn element(
+    &self,
+    rust_type: &TypeRef,
+    example: Option<&Value>,
+    visiting: &mut HashSet<String>,
+  ) -> Option<TokenStream> {
+    let expr = self.base_value(&rust_type.base_type, example, visiting)?;
+    Some(if rust_type.boxed {
+      quote! { Box::new(#expr) }
+    } else {
+      expr
+    })
+  }
+
+  fn base_value(
+    &self,
+    base_type: &RustPrimitive,
+    example: Option<&Value>,
+    visiting: &mut HashSet<String>,
+  ) -> Option<TokenStream> {
+    let RustPrimitive::Custom(name) = base_type else {
+      let supported = base_type.is_numeric() || matches!(base_type, RustPrimitive::String | RustPrimitive::Bool);
+      return example
+        .filter(|_| supported)
+        .map(|value| coercion::coerce_to_rust_type(value, base_type));
+    };
+
+    if visiting.len() >= MAX_DEPTH || !visiting.insert(name.to_string()) {
+      return None;
+    }
+    let expr = match self.types.get(name.as_ref())? {
+      RustType::Struct(def) => self.struct_value(def, example, visiting),
+      RustType::Enum(def) => enum_value(def, example?),
+      RustType::TypeAlias(def) => self.value(&def.target, example, visiting),
+      RustType::DiscriminatedEnum(_) | RustType::ResponseEnum(_) => None,
+    };
+    visiting.remove(name.as_ref());
+    expr
+  }
+
+  fn struct_value(
+    &self,
+    def: &StructDef,
+    example: Option<&Value>,
+    visiting: &mut HashSet<String>,
+  ) -> Option<TokenStream> {
+    let name = &def.name;
+    let fields = def
+      .user_fields()
+      .filter_map(|field| {
+        let wire_name = field.original_name.as_deref().unwrap_or(field.name.as_str());
+        let example = match example {
+          Some(Value::Object(object)) => Some(object.get(wire_name)?),
+          _ => field.example_value.as_ref(),
+        };
+        let value = self.value(&field.rust_type, example, visiting)?;
+        let field_name = &field.name;
+        Some(quote! { #field_name: #value, })
+      })
+      .collect::<Vec<_>>();
+
+    if fields.is_empty() {
+      return None;
+    }
+    let rest = (fields.len() < def.fields.len()).then(|| quote! { ..Default::default() });
+    Some(quote! {
+      #name {
+        #(#fields)*
+        #rest
+      }
+    })
+  }
+}
+
+impl ToTokens for MockServerFragment<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let Some(def) = self.server_trait else {
+      return;
+    };
+    let vis = self.vis.to_tokens();
+    let trait_name = &def.name;
+    let methods = def.methods.iter().map(|method| self.method(method));
+
+    tokens.extend(quote! {
+      /// Serves the examples from the OpenAPI spec; pass it to `router` to run it.
+      #[derive(Debug, Clone, Copy, Default)]
+      #vis struct MockServer;
+
+      impl #trait_name for MockServer {
+        #(#methods)*
+      }
+    });
+  }
+}
+
+/// Picks the first success response, then a `default` response, then whatever comes first.
+fn preferred_variant(variants: &[ResponseVariant]) -> Option<&ResponseVariant> {
+  variants
+    .iter()
+    .find(|variant| variant.status_code.is_success())
+    .or_else(|| variants.iter().find(|variant| variant.status_code.is_default()))
+    .or_else(|| variants.first())
+}
+
+/// Matches an example to the value enum variant it serializes as.
+fn enum_value(def: &EnumDef, example: &Value) -> Option<TokenStream> {
+  let name = &def.name;
+  let variant_name = &def.variant_for_value(example)?.name;
+  Some(quote! { #name::#variant_name })
+}
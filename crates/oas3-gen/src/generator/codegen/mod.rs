@@ -1,5 +1,7 @@
 use std::{
-  collections::{BTreeSet, HashMap},
+  borrow::Cow,
+  collections::{BTreeMap, BTreeSet, HashMap},
+  path::PathBuf,
   rc::Rc,
 };
 
@@ -7,26 +9,60 @@ use clap::ValueEnum;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
-use self::{client::ClientFragment, mod_file::ModFileFragment, server::ServerGenerator, types::TypesFragment};
-use super::ast::{ClientRootNode, GlobalLintsNode, OperationInfo, RustType, ServerRequestTraitDef};
+use self::{
+  benches::{BENCHES_FILE, BenchesFragment, Benchmark},
+  client::ClientFragment,
+  constants::MediaTypeConstantsFragment,
+  example_tests::ExampleTestsFragment,
+  fixtures::{FIXTURE_TESTS_FILE, Fixture, FixtureTestsFragment},
+  formatting::SourceFormatter,
+  incremental::ModuleCache,
+  mock::MockServerFragment,
+  mod_file::ModFileFragment,
+  prelude::PreludeFragment,
+  proptests::PropertyTestsFragment,
+  server::ServerGenerator,
+  tag_modules::{SHARED_MODULE, TagModulesFragment},
+  types::TypesFragment,
+  workspace::WorkspaceCrates,
+};
+use super::ast::{ClientRootNode, GlobalLintsNode, LintConfig, OperationInfo, RustType, ServerRequestTraitDef};
 use crate::generator::{
   ast::{Documentation, FileHeaderNode, constants::HttpHeaderRef},
-  converter::CodegenConfig,
+  converter::{CodegenConfig, OutputFormatPolicy, hashing::content_hash},
+  naming::constants::API_ERROR_ALIAS,
+  postprocess::TagModules,
 };
 
 pub mod attributes;
+pub(crate) mod benches;
 pub mod client;
 pub mod coercion;
 pub mod constants;
+pub(crate) mod conversions;
 pub mod enums;
+pub(crate) mod error_impls;
+pub(crate) mod example_tests;
+pub(crate) mod fixtures;
+pub mod formatting;
 pub(crate) mod headers;
 pub mod http;
+pub mod incremental;
 pub mod methods;
+pub(crate) mod mock;
 pub mod mod_file;
+pub mod prelude;
+pub(crate) mod proptests;
+pub mod public_api;
+pub(crate) mod route_tests;
 pub mod server;
 pub mod structs;
+pub(crate) mod tag_modules;
+pub mod templates;
 pub mod type_aliases;
 pub mod types;
+pub mod verify;
+pub mod workspace;
 
 #[cfg(test)]
 mod tests;
@@ -60,9 +96,16 @@ impl Visibility {
   }
 }
 
-pub fn format(code: &TokenStream) -> anyhow::Result<String> {
-  let syntax_tree = syn::parse2(code.clone())?;
-  Ok(prettyplease::unparse(&syntax_tree))
+/// Label of the header line recording the spec hash.
+const SPEC_HASH_LABEL: &str = "Spec hash: ";
+
+/// Returns the spec hash recorded in the header of a generated `source` file.
+#[must_use]
+pub fn header_spec_hash(source: &str) -> Option<&str> {
+  source
+    .lines()
+    .take_while(|line| line.is_empty() || line.starts_with("//") || line.starts_with("#!"))
+    .find_map(|line| line.strip_prefix("//!")?.trim().strip_prefix(SPEC_HASH_LABEL))
 }
 
 impl ToTokens for FileHeaderNode {
@@ -70,8 +113,17 @@ impl ToTokens for FileHeaderNode {
     self.lints.to_tokens(tokens);
 
     let title_line = format!(" {}", self.title);
-    let source_line = format!(" Source: {}", self.source_path);
+    let source_line = self
+      .source_path
+      .as_ref()
+      .map(|path| format!(" Source: {path}"))
+      .map(|line| quote! { #![doc = #line] });
     let version_line = format!(" Version: {}", self.version);
+    let hash_line = self
+      .spec_hash
+      .as_ref()
+      .map(|hash| format!(" {SPEC_HASH_LABEL}{hash}"))
+      .map(|line| quote! { #![doc = #line] });
     let generator_line = format!(" Generated by `oas3-gen v{}`", self.generator_version);
 
     let header = quote! {
@@ -79,8 +131,9 @@ impl ToTokens for FileHeaderNode {
       #![doc = " AUTO-GENERATED CODE - DO NOT EDIT!"]
       #![doc = ""]
       #![doc = #title_line]
-      #![doc = #source_line]
+      #source_line
       #![doc = #version_line]
+      #hash_line
       #![doc = #generator_line]
       #![doc = ""]
     };
@@ -99,47 +152,168 @@ fn generate_source(
   code: &TokenStream,
   metadata: &ClientRootNode,
   lint_config: Option<&GlobalLintsNode>,
+  header_config: &LintConfig,
   source_path: &str,
   gen_version: &str,
-) -> anyhow::Result<String> {
-  let lints = lint_config.map_or_else(|| GlobalLintsNode::builder().build(), std::clone::Clone::clone);
+  formatter: &SourceFormatter,
+) -> String {
+  let parts = source_parts(code, metadata, lint_config, header_config, source_path, gen_version);
+  formatter.format(&GeneratedFileType::Module.file_name(), &parts.into_text())
+}
+
+/// The unformatted pieces of a generated source file.
+struct SourceParts {
+  preamble: String,
+  header: TokenStream,
+  code: TokenStream,
+}
+
+impl SourceParts {
+  /// Hashes everything the source formatted with `policy` is built from, without formatting it.
+  fn fingerprint(&self, policy: OutputFormatPolicy) -> String {
+    content_hash(&format!(
+      "{policy:?}\n{}\n{}\n{}",
+      self.preamble, self.header, self.code
+    ))
+  }
+
+  /// Renders the token streams as text, which unlike tokens can be sent to another thread.
+  fn into_text(self) -> SourceText {
+    SourceText {
+      preamble: self.preamble,
+      header: self.header.to_string(),
+      code: self.code.to_string(),
+    }
+  }
+}
+
+/// The unformatted pieces of a generated source file, as text.
+struct SourceText {
+  preamble: String,
+  header: String,
+  code: String,
+}
+
+impl SourceText {
+  fn format(&self) -> anyhow::Result<String> {
+    let header_formatted = prettyplease::unparse(&syn::parse_str(&self.header)?);
+    let code_formatted = prettyplease::unparse(&syn::parse_str(&self.code)?);
+    Ok(format!("{}{header_formatted}\n{code_formatted}\n", self.preamble))
+  }
+}
+
+fn source_parts(
+  code: &TokenStream,
+  metadata: &ClientRootNode,
+  lint_config: Option<&GlobalLintsNode>,
+  header_config: &LintConfig,
+  source_path: &str,
+  gen_version: &str,
+) -> SourceParts {
+  let lints = lint_config.map_or_else(
+    || GlobalLintsNode::builder().build(),
+    |lints| lints.clone().with_config(header_config),
+  );
   let header_node = FileHeaderNode::builder()
     .title(metadata.title.clone())
     .version(metadata.version.clone())
-    .source_path(source_path.to_string())
+    .maybe_source_path(header_config.include_source_path.then(|| source_path.to_string()))
     .generator_version(gen_version.to_string())
+    .maybe_spec_hash(header_config.spec_hash.clone())
     .description(Documentation::from_optional(metadata.description.as_ref()))
     .lints(lints)
     .build();
 
-  let header_formatted = format(&header_node.to_token_stream())?;
-  let code_formatted = format(code)?;
-  Ok(format!("{header_formatted}\n{code_formatted}\n"))
+  SourceParts {
+    preamble: header_config.preamble(),
+    header: header_node.to_token_stream(),
+    code: code.clone(),
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GeneratedFileType {
   Client,
+  Library,
+  Manifest,
   Module,
   Server,
+  /// A module holding the types of one OpenAPI tag.
+  Tag(String),
   Types,
 }
 
+impl GeneratedFileType {
+  /// Returns the file name this output is written to inside a module directory.
+  #[must_use]
+  pub fn file_name(&self) -> Cow<'static, str> {
+    match self {
+      Self::Client => "client.rs".into(),
+      Self::Library => "src/lib.rs".into(),
+      Self::Manifest => "Cargo.toml".into(),
+      Self::Module => "mod.rs".into(),
+      Self::Server => "server.rs".into(),
+      Self::Tag(name) => format!("{name}.rs").into(),
+      Self::Types => "types.rs".into(),
+    }
+  }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GeneratedResult {
   pub code: HashMap<GeneratedFileType, String>,
+  /// Submodules written to directories of the same name next to `mod.rs`.
+  pub modules: BTreeMap<String, GeneratedResult>,
+  /// Fingerprint of the unformatted source of each file a [`ModuleCache`] can reuse.
+  pub fingerprints: HashMap<GeneratedFileType, String>,
 }
 
 impl GeneratedResult {
+  /// Lists every file in the module tree with its path relative to the tree's root
+  /// and the fingerprint it was generated from, if any.
+  #[must_use]
+  pub fn files(&self) -> Vec<(PathBuf, &String, Option<&String>)> {
+    let mut files = vec![];
+    let mut pending = vec![(PathBuf::new(), self)];
+    while let Some((dir, module)) = pending.pop() {
+      files.extend(module.code.iter().map(|(file_type, source)| {
+        (
+          dir.join(&*file_type.file_name()),
+          source,
+          module.fingerprints.get(file_type),
+        )
+      }));
+      pending.extend(
+        module
+          .modules
+          .iter()
+          .map(|(name, submodule)| (dir.join(name), submodule)),
+      );
+    }
+    files
+  }
+
   pub fn client(code: String) -> Self {
     Self {
       code: HashMap::from([(GeneratedFileType::Client, code)]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
+    }
+  }
+
+  pub fn server(code: String) -> Self {
+    Self {
+      code: HashMap::from([(GeneratedFileType::Server, code)]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
     }
   }
 
   pub fn types(code: String) -> Self {
     Self {
       code: HashMap::from([(GeneratedFileType::Types, code)]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
     }
   }
 
@@ -150,6 +324,8 @@ impl GeneratedResult {
         (GeneratedFileType::Client, client),
         (GeneratedFileType::Types, types),
       ]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
     }
   }
 
@@ -160,9 +336,64 @@ impl GeneratedResult {
         (GeneratedFileType::Server, server),
         (GeneratedFileType::Types, types),
       ]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
+    }
+  }
+
+  pub fn types_mod(mod_file: String, types: String) -> Self {
+    Self {
+      code: HashMap::from([(GeneratedFileType::Module, mod_file), (GeneratedFileType::Types, types)]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
     }
   }
 
+  pub fn nested(mod_file: String, modules: BTreeMap<String, GeneratedResult>) -> Self {
+    Self {
+      code: HashMap::from([(GeneratedFileType::Module, mod_file)]),
+      modules,
+      fingerprints: HashMap::new(),
+    }
+  }
+
+  pub fn package(manifest: String, library: String) -> Self {
+    Self {
+      code: HashMap::from([
+        (GeneratedFileType::Manifest, manifest),
+        (GeneratedFileType::Library, library),
+      ]),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
+    }
+  }
+
+  pub fn workspace(manifest: String, packages: BTreeMap<String, GeneratedResult>) -> Self {
+    Self {
+      code: HashMap::from([(GeneratedFileType::Manifest, manifest)]),
+      modules: packages,
+      fingerprints: HashMap::new(),
+    }
+  }
+
+  /// Adds one file per tag module, keyed by module name.
+  #[must_use]
+  pub fn with_tag_modules(mut self, modules: impl IntoIterator<Item = (String, String)>) -> Self {
+    self.code.extend(
+      modules
+        .into_iter()
+        .map(|(name, code)| (GeneratedFileType::Tag(name), code)),
+    );
+    self
+  }
+
+  /// Records the fingerprints of the files built from [`TypesModSources`].
+  #[must_use]
+  fn with_fingerprints(mut self, fingerprints: HashMap<GeneratedFileType, String>) -> Self {
+    self.fingerprints.extend(fingerprints);
+    self
+  }
+
   pub fn code(&self, file_type: &GeneratedFileType) -> Option<&String> {
     self.code.get(file_type)
   }
@@ -177,11 +408,25 @@ pub struct SchemaCodeGenerator {
   uses: Rc<BTreeSet<String>>,
   client: Rc<ClientRootNode>,
   server_trait: Option<ServerRequestTraitDef>,
+  webhook_trait: Option<ServerRequestTraitDef>,
+  callback_traits: Rc<Vec<ServerRequestTraitDef>>,
+  schema_examples: Rc<BTreeMap<String, serde_json::Value>>,
+  tag_modules: Option<Rc<TagModules>>,
+  module_cache: Option<Rc<ModuleCache>>,
+  formatter: Rc<SourceFormatter>,
   visibility: Visibility,
   source_path: String,
   gen_version: String,
 }
 
+/// Formatted sources of the `types` module and the tag modules split from it.
+struct TypesModSources {
+  types: String,
+  tags: BTreeMap<String, String>,
+  /// Set for each module when generating with a [`ModuleCache`].
+  fingerprints: HashMap<GeneratedFileType, String>,
+}
+
 #[bon::bon]
 impl SchemaCodeGenerator {
   #[builder]
@@ -193,6 +438,12 @@ impl SchemaCodeGenerator {
     uses: BTreeSet<String>,
     client: ClientRootNode,
     server_trait: Option<ServerRequestTraitDef>,
+    webhook_trait: Option<ServerRequestTraitDef>,
+    #[builder(default)] callback_traits: Vec<ServerRequestTraitDef>,
+    #[builder(default)] schema_examples: BTreeMap<String, serde_json::Value>,
+    tag_modules: Option<TagModules>,
+    module_cache: Option<Rc<ModuleCache>>,
+    #[builder(default)] formatter: Rc<SourceFormatter>,
     visibility: Visibility,
     source_path: String,
     gen_version: String,
@@ -205,6 +456,12 @@ impl SchemaCodeGenerator {
       uses: Rc::new(uses),
       client: Rc::new(client),
       server_trait,
+      webhook_trait,
+      callback_traits: Rc::new(callback_traits),
+      schema_examples: Rc::new(schema_examples),
+      tag_modules: tag_modules.map(Rc::new),
+      module_cache,
+      formatter,
       visibility,
       source_path,
       gen_version,
@@ -217,50 +474,237 @@ impl SchemaCodeGenerator {
   ///
   /// Includes structs, enums, type aliases, and their serde/validation derives.
   pub fn generate_types(&self) -> anyhow::Result<GeneratedResult> {
-    let code = self.format_tokens_with_lints(&self.types_fragment())?;
+    let code = self.format_tokens_with_lints(&GeneratedFileType::Types.file_name(), &self.types_fragment());
     Ok(GeneratedResult::types(code))
   }
 
-  /// Generates a standalone `client.rs` file with types and HTTP client combined.
+  /// Generates a standalone `client.rs` file holding the HTTP client.
   ///
-  /// The client struct includes methods for each API operation.
+  /// The client struct includes methods for each API operation, and imports the
+  /// generated types from the configured types import path.
   pub fn generate_client(&self) -> anyhow::Result<GeneratedResult> {
-    let code = self.format_tokens_with_lints(&self.client_fragment(false))?;
+    let path = self.config.types_import_path();
+    let types_path =
+      syn::parse_str::<syn::Path>(path).map_err(|e| anyhow::anyhow!("Invalid types import path `{path}`: {e}"))?;
+    let code = self.format_tokens_with_lints(
+      &GeneratedFileType::Client.file_name(),
+      &self.client_fragment(false).with_types_path(&types_path),
+    );
     Ok(GeneratedResult::client(code))
   }
 
+  /// Generates a standalone `server.rs` file with types and the axum server combined.
+  ///
+  /// The file holds the handler trait, one axum handler per operation, and the router.
+  pub fn generate_server(&self) -> anyhow::Result<GeneratedResult> {
+    let types = self.types_fragment();
+    let server = self.server_generator().with_inline_types();
+    let code = self.format_tokens_with_lints(&GeneratedFileType::Server.file_name(), &quote! { #types #server });
+    Ok(GeneratedResult::server(code))
+  }
+
+  /// Generates a server like [`Self::generate_server`] plus a `MockServer` implementing
+  /// its trait with responses built from the spec's examples.
+  pub fn generate_mock(&self) -> anyhow::Result<GeneratedResult> {
+    let types = self.types_fragment();
+    let server = self.server_generator().with_inline_types();
+    let mock = MockServerFragment::new(self.server_trait.as_ref(), &self.rust_types, self.visibility);
+    let code = self.format_tokens_with_lints(&GeneratedFileType::Server.file_name(), &quote! { #types #server #mock });
+    Ok(GeneratedResult::server(code))
+  }
+
+  /// Generates the types like [`Self::generate_types`] plus an `example_tests` module
+  /// round-tripping the `example` of every schema that has one through its type.
+  pub fn generate_example_tests(&self) -> anyhow::Result<GeneratedResult> {
+    let types = self.types_fragment();
+    let tests = ExampleTestsFragment::new(&self.rust_types, &self.schema_examples);
+    let code = self.format_tokens_with_lints(&GeneratedFileType::Types.file_name(), &quote! { #types #tests });
+    Ok(GeneratedResult::types(code))
+  }
+
+  /// Generates the types like [`Self::generate_types`] plus a `proptests` module with a
+  /// `proptest` strategy for each schema type and a serde round-trip property per type.
+  pub fn generate_property_tests(&self) -> anyhow::Result<GeneratedResult> {
+    let types = self.types_fragment();
+    let tests = PropertyTestsFragment::new(&self.rust_types);
+    let code = self.format_tokens_with_lints(&GeneratedFileType::Types.file_name(), &quote! { #types #tests });
+    Ok(GeneratedResult::types(code))
+  }
+
+  /// Generates the example payloads of the spec's schemas and JSON responses as files,
+  /// keyed by path, plus a tests file deserializing each into the type generated for it.
+  ///
+  /// The tests import the generated types from `types_path`.
+  pub fn generate_fixtures(&self, types_path: &syn::Path) -> anyhow::Result<BTreeMap<String, String>> {
+    let fixtures = Fixture::for_schemas(&self.schema_examples)
+      .into_iter()
+      .chain(Fixture::for_responses(&self.operations, &self.rust_types))
+      .collect::<Vec<_>>();
+    let tests = self.format_tokens(FIXTURE_TESTS_FILE, &FixtureTestsFragment::new(&fixtures, types_path));
+    fixtures
+      .iter()
+      .map(|fixture| Ok((fixture.path.clone(), fixture.contents()?)))
+      .chain([Ok((FIXTURE_TESTS_FILE.to_string(), tests))])
+      .collect()
+  }
+
+  /// Generates a criterion benchmark target (de)serializing the spec example of each of
+  /// the largest schema structs and of every discriminated enum, keyed by file name.
+  ///
+  /// The benchmarks import the generated types from `types_path`.
+  pub fn generate_benches(&self, types_path: &syn::Path) -> anyhow::Result<BTreeMap<String, String>> {
+    let benchmarks = Benchmark::select(&self.rust_types, &self.schema_examples);
+    if benchmarks.is_empty() {
+      anyhow::bail!("No schema struct or discriminated enum has an example to benchmark");
+    }
+    let benches = self.format_tokens(BENCHES_FILE, &BenchesFragment::new(&benchmarks, types_path));
+    Ok(BTreeMap::from([(BENCHES_FILE.to_string(), benches)]))
+  }
+
+  /// Generates a Cargo workspace with a types crate and a client crate that depends on it.
+  ///
+  /// Each crate's `Cargo.toml` lists the dependencies its generated code references.
+  pub fn generate_workspace(&self, crates: &WorkspaceCrates) -> anyhow::Result<GeneratedResult> {
+    let library = GeneratedFileType::Library.file_name();
+    let types_code = self.format_tokens_with_lints(&format!("{}/{library}", crates.types), &self.types_fragment());
+    let client_code = self.format_tokens_with_lints(
+      &format!("{}/{library}", crates.client),
+      &self.client_fragment(false).with_types_crate(&crates.types),
+    );
+
+    let types = GeneratedResult::package(
+      crates.types_manifest(&types_code, &self.gen_version, self.config.client_runtime),
+      types_code,
+    );
+    let client = GeneratedResult::package(
+      crates.client_manifest(&client_code, &self.gen_version, self.config.client_runtime),
+      client_code,
+    );
+    Ok(GeneratedResult::workspace(
+      crates.workspace_manifest(),
+      BTreeMap::from([(crates.types.clone(), types), (crates.client.clone(), client)]),
+    ))
+  }
+
   /// Generates a modular client with separate `mod.rs`, `client.rs`, and `types.rs` files.
   ///
-  /// The client imports types from the sibling `types` module.
+  /// The client imports types from the sibling `types` module. When split by tag,
+  /// each tag also gets its own module and the client imports the ones it uses.
   pub fn generate_client_mod(&self) -> anyhow::Result<GeneratedResult> {
-    let types_code = self.format_tokens(&self.types_fragment())?;
-    let client_code = self.format_tokens(&self.client_fragment(true))?;
+    let tag_modules = self.tag_modules_fragment();
+    let sources = self.types_mod_sources(tag_modules.as_ref(), false)?;
+    let client = self.client_fragment(true);
+    let client = match &tag_modules {
+      Some(modules) => {
+        let imports = modules.imports_for(&client.to_token_stream(), None);
+        client.with_types_modules(imports)
+      }
+      None => client,
+    };
+    let client_code = self.format_tokens(&GeneratedFileType::Client.file_name(), &client);
     let mod_fragment = ModFileFragment::for_client(
       (*self.client).clone(),
       self.visibility,
       self.source_path.clone(),
       self.gen_version.clone(),
-    );
-    let mod_code = mod_fragment.generate()?;
+    )
+    .with_lint_config(self.config.lints.clone())
+    .with_tag_modules(sources.tags.keys().cloned().collect());
+    let mod_fragment = if self.config.generate_prelude() {
+      mod_fragment.with_prelude(PreludeFragment::new(
+        &self.client,
+        &self.operations,
+        &self.config,
+        self.visibility,
+      ))
+    } else {
+      mod_fragment
+    };
+    let mod_code = mod_fragment.generate(&self.formatter);
 
-    Ok(GeneratedResult::full_client(mod_code, client_code, types_code))
+    Ok(
+      GeneratedResult::full_client(mod_code, client_code, sources.types)
+        .with_tag_modules(sources.tags)
+        .with_fingerprints(sources.fingerprints),
+    )
   }
 
   /// Generates a modular server with separate `mod.rs`, `server.rs`, and `types.rs` files.
   ///
-  /// The server trait imports types from the sibling `types` module.
+  /// The server trait imports types from the sibling `types` module, and from the
+  /// tag modules it uses when split by tag.
   pub fn generate_server_mod(&self) -> anyhow::Result<GeneratedResult> {
-    let types_code = self.format_tokens(&self.types_fragment())?;
-    let server_code = self.format_tokens(&self.server_fragment())?;
+    let tag_modules = self.tag_modules_fragment();
+    let sources = self.types_mod_sources(tag_modules.as_ref(), false)?;
+    let server = self.server_fragment();
+    let server = match &tag_modules {
+      Some(modules) => {
+        let imports = modules.imports_for(&server.to_token_stream(), None);
+        server.with_types_modules(imports)
+      }
+      None => server,
+    };
+    let server_code = self.format_tokens(&GeneratedFileType::Server.file_name(), &server);
     let mod_fragment = ModFileFragment::for_server(
       (*self.client).clone(),
       self.visibility,
       self.source_path.clone(),
       self.gen_version.clone(),
-    );
-    let mod_code = mod_fragment.generate()?;
+    )
+    .with_lint_config(self.config.lints.clone())
+    .with_tag_modules(sources.tags.keys().cloned().collect());
+    let mod_code = mod_fragment.generate(&self.formatter);
+
+    Ok(
+      GeneratedResult::full_server(mod_code, server_code, sources.types)
+        .with_tag_modules(sources.tags)
+        .with_fingerprints(sources.fingerprints),
+    )
+  }
 
-    Ok(GeneratedResult::full_server(mod_code, server_code, types_code))
+  /// Generates a types-only module with separate `mod.rs` and `types.rs` files.
+  pub fn generate_types_mod(&self) -> anyhow::Result<GeneratedResult> {
+    let sources = self.types_mod_sources(self.tag_modules_fragment().as_ref(), true)?;
+    let mod_fragment = ModFileFragment::for_types(
+      (*self.client).clone(),
+      self.visibility,
+      self.source_path.clone(),
+      self.gen_version.clone(),
+    )
+    .with_lint_config(self.config.lints.clone())
+    .with_tag_modules(sources.tags.keys().cloned().collect());
+    let mod_code = mod_fragment.generate(&self.formatter);
+
+    Ok(
+      GeneratedResult::types_mod(mod_code, sources.types)
+        .with_tag_modules(sources.tags)
+        .with_fingerprints(sources.fingerprints),
+    )
+  }
+
+  /// Generates a `mod.rs` that declares already generated submodules.
+  ///
+  /// Each submodule is written to a directory of the same name, so a client and a
+  /// server can live side by side with their own `types.rs`.
+  pub fn generate_nested_mod<'a>(
+    &self,
+    modules: impl IntoIterator<Item = (&'a str, GeneratedResult)>,
+  ) -> anyhow::Result<GeneratedResult> {
+    let modules = modules
+      .into_iter()
+      .map(|(name, result)| (name.to_string(), result))
+      .collect::<BTreeMap<_, _>>();
+    let mod_fragment = ModFileFragment::for_modules(
+      (*self.client).clone(),
+      self.visibility,
+      modules.keys().cloned().collect(),
+      self.source_path.clone(),
+      self.gen_version.clone(),
+    )
+    .with_lint_config(self.config.lints.clone());
+    let mod_code = mod_fragment.generate(&self.formatter);
+
+    Ok(GeneratedResult::nested(mod_code, modules))
   }
 
   /// Creates a types generator fragment for all Rust type definitions.
@@ -272,11 +716,137 @@ impl SchemaCodeGenerator {
       self.visibility,
       self.config.target,
     )
+    .with_callback_traits(self.callback_traits.clone())
+    .with_media_types(self.media_types_fragment())
+  }
+
+  /// Creates the per-tag type fragments when the output is split by tag.
+  fn tag_modules_fragment(&self) -> Option<TagModulesFragment> {
+    self.tag_modules.as_deref().map(|modules| {
+      TagModulesFragment::new(modules, self.header_refs.clone(), self.visibility, self.config.target)
+        .with_callback_traits(self.callback_traits.clone())
+        .with_media_types(self.media_types_fragment())
+    })
+  }
+
+  fn media_types_fragment(&self) -> MediaTypeConstantsFragment {
+    MediaTypeConstantsFragment::new(&self.rust_types, &self.operations, self.config.target)
+  }
+
+  /// Formats the `types` module source and, when split by tag, each tag module's
+  /// source keyed by module name, formatting the modules concurrently.
+  ///
+  /// With a module cache, modules whose fingerprint matches the previous run reuse
+  /// their previous source instead of being formatted.
+  fn types_mod_sources(
+    &self,
+    tag_modules: Option<&TagModulesFragment>,
+    with_lints: bool,
+  ) -> anyhow::Result<TypesModSources> {
+    let modules = match tag_modules {
+      Some(tag_modules) => tag_modules
+        .modules()
+        .map(|(name, fragment)| {
+          let file_type = if name == SHARED_MODULE {
+            GeneratedFileType::Types
+          } else {
+            GeneratedFileType::Tag(name.to_string())
+          };
+          (file_type, fragment)
+        })
+        .collect::<Vec<_>>(),
+      None => vec![(GeneratedFileType::Types, self.types_fragment())],
+    };
+
+    let mut fingerprints = HashMap::new();
+    let mut sources = vec![];
+    let mut pending = vec![];
+    for (file_type, fragment) in modules {
+      let parts = self.source_parts(&fragment, with_lints);
+      if let Some(cache) = &self.module_cache {
+        let fingerprint = parts.fingerprint(self.formatter.policy());
+        let cached = cache.get(&fingerprint);
+        fingerprints.insert(file_type.clone(), fingerprint);
+        if let Some(source) = cached {
+          sources.push((file_type, source));
+          continue;
+        }
+      }
+      pending.push((file_type, parts.into_text()));
+    }
+    sources.extend(self.formatter.format_all(pending));
+
+    let mut types = String::new();
+    let mut tags = BTreeMap::new();
+    for (file_type, source) in sources {
+      match file_type {
+        GeneratedFileType::Tag(name) => {
+          tags.insert(name, source);
+        }
+        _ => types = source,
+      }
+    }
+    Ok(TypesModSources {
+      types,
+      tags,
+      fingerprints,
+    })
   }
 
   /// Creates a client fragment for HTTP client code generation.
   fn client_fragment(&self, with_types_import: bool) -> ClientFragment {
     let fragment = ClientFragment::new(&self.client, &self.operations, self.visibility);
+    let fragment = if self.config.retain_raw_body() {
+      fragment.with_raw_body()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.vcr_enabled() {
+      fragment.with_vcr()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.retry_enabled() {
+      fragment.with_retry()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.request_hooks_enabled() {
+      fragment.with_request_hooks()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.last_response_enabled() {
+      fragment.with_last_response()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.typed_client_errors() {
+      fragment.with_typed_errors()
+    } else {
+      fragment
+    };
+    let fragment = match (self.config.tower_services(), self.config.wasm_runtime()) {
+      (false, _) => fragment,
+      (true, false) => fragment.with_tower_services(),
+      (true, true) => fragment.with_tower_services().with_local_futures(),
+    };
+    let fragment = if self.config.batch_helper() {
+      fragment.with_batch()
+    } else {
+      fragment
+    };
+    let fragment = if self.config.route_tests() {
+      fragment.with_route_tests()
+    } else {
+      fragment
+    };
+    let fragment = fragment.with_templates(self.config.client_templates.clone());
+    let fragment = if self.rust_types.iter().any(|ty| &*ty.type_name() == API_ERROR_ALIAS) {
+      fragment
+    } else {
+      fragment.with_api_error_alias()
+    };
     if with_types_import {
       fragment.with_types_import()
     } else {
@@ -286,27 +856,41 @@ impl SchemaCodeGenerator {
 
   /// Creates a server fragment for axum server trait generation.
   fn server_fragment(&self) -> ServerGenerator {
-    ServerGenerator::new(self.server_trait.clone(), self.visibility).with_types_import()
+    self.server_generator().with_types_import()
   }
 
-  /// Formats tokens into source code with a file header (no lint attributes).
-  fn format_tokens(&self, fragment: &impl ToTokens) -> anyhow::Result<String> {
-    generate_source(
-      &fragment.to_token_stream(),
-      &self.client,
-      None,
-      &self.source_path,
-      &self.gen_version,
-    )
+  /// Creates the axum server generator, validating requests when configured.
+  fn server_generator(&self) -> ServerGenerator {
+    let server =
+      ServerGenerator::new(self.server_trait.clone(), self.visibility).with_webhook_trait(self.webhook_trait.clone());
+    if self.config.validate_requests() {
+      server.with_request_validation()
+    } else {
+      server
+    }
+  }
+
+  /// Formats tokens into the source of `file` with a file header (no lint attributes).
+  fn format_tokens(&self, file: &str, fragment: &impl ToTokens) -> String {
+    self
+      .formatter
+      .format(file, &self.source_parts(fragment, false).into_text())
+  }
+
+  /// Formats tokens into the source of `file` with a file header and default lint configuration.
+  fn format_tokens_with_lints(&self, file: &str, fragment: &impl ToTokens) -> String {
+    self
+      .formatter
+      .format(file, &self.source_parts(fragment, true).into_text())
   }
 
-  /// Formats tokens into source code with a file header and default lint configuration.
-  fn format_tokens_with_lints(&self, fragment: &impl ToTokens) -> anyhow::Result<String> {
-    let lints = GlobalLintsNode::default();
-    generate_source(
+  fn source_parts(&self, fragment: &impl ToTokens, with_lints: bool) -> SourceParts {
+    let lints = with_lints.then(GlobalLintsNode::default);
+    source_parts(
       &fragment.to_token_stream(),
       &self.client,
-      Some(&lints),
+      lints.as_ref(),
+      &self.config.lints,
       &self.source_path,
       &self.gen_version,
     )
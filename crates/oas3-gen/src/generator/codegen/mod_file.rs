@@ -1,25 +1,19 @@
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 
-use super::Visibility;
+use super::{Visibility, prelude::PreludeFragment};
 use crate::generator::{
-  ast::{ClientRootNode, GlobalLintsNode},
-  codegen::generate_source,
+  ast::{ClientRootNode, GlobalLintsNode, LintConfig},
+  codegen::{formatting::SourceFormatter, generate_source},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ModFileKind {
   Client,
   Server,
-}
-
-impl ModFileKind {
-  const fn secondary_module_name(self) -> &'static str {
-    match self {
-      Self::Client => "client",
-      Self::Server => "server",
-    }
-  }
+  Types,
+  /// Declares each named submodule without re-exporting its items.
+  Modules(Vec<String>),
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +21,9 @@ pub struct ModFileFragment {
   metadata: ClientRootNode,
   visibility: Visibility,
   kind: ModFileKind,
+  prelude: Option<PreludeFragment>,
+  tag_modules: Vec<String>,
+  lint_config: LintConfig,
   source_path: String,
   gen_version: String,
 }
@@ -43,6 +40,9 @@ impl ModFileFragment {
       metadata,
       visibility,
       kind,
+      prelude: None,
+      tag_modules: vec![],
+      lint_config: LintConfig::default(),
       source_path,
       gen_version,
     }
@@ -66,14 +66,53 @@ impl ModFileFragment {
     Self::new(metadata, visibility, ModFileKind::Server, source_path, gen_version)
   }
 
-  pub fn generate(&self) -> anyhow::Result<String> {
+  pub fn for_types(metadata: ClientRootNode, visibility: Visibility, source_path: String, gen_version: String) -> Self {
+    Self::new(metadata, visibility, ModFileKind::Types, source_path, gen_version)
+  }
+
+  pub fn for_modules(
+    metadata: ClientRootNode,
+    visibility: Visibility,
+    modules: Vec<String>,
+    source_path: String,
+    gen_version: String,
+  ) -> Self {
+    Self::new(
+      metadata,
+      visibility,
+      ModFileKind::Modules(modules),
+      source_path,
+      gen_version,
+    )
+  }
+
+  pub fn with_prelude(mut self, prelude: PreludeFragment) -> Self {
+    self.prelude = Some(prelude);
+    self
+  }
+
+  /// Applies the configured lints and header options to the generated `mod.rs`.
+  pub fn with_lint_config(mut self, lint_config: LintConfig) -> Self {
+    self.lint_config = lint_config;
+    self
+  }
+
+  /// Declares and re-exports one module per OpenAPI tag next to `types`.
+  pub fn with_tag_modules(mut self, names: Vec<String>) -> Self {
+    self.tag_modules = names;
+    self
+  }
+
+  pub fn generate(&self, formatter: &SourceFormatter) -> String {
     let lint_config = GlobalLintsNode::default();
     generate_source(
       &self.to_token_stream(),
       &self.metadata,
       Some(&lint_config),
+      &self.lint_config,
       &self.source_path,
       &self.gen_version,
+      formatter,
     )
   }
 }
@@ -81,14 +120,30 @@ impl ModFileFragment {
 impl ToTokens for ModFileFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let vis = &self.visibility;
-    let secondary_mod = syn::Ident::new(self.kind.secondary_module_name(), proc_macro2::Span::call_site());
+    let ident = |name: &str| syn::Ident::new(name, proc_macro2::Span::call_site());
+
+    let entry_module = match &self.kind {
+      ModFileKind::Client => Some(ident("client")),
+      ModFileKind::Server => Some(ident("server")),
+      ModFileKind::Types => None,
+      ModFileKind::Modules(names) => {
+        let names = names.iter().map(|name| ident(name));
+        tokens.extend(quote! { #(#vis mod #names;)* });
+        return;
+      }
+    };
+    let modules = std::iter::once(ident("types"))
+      .chain(self.tag_modules.iter().map(|name| ident(name)))
+      .chain(entry_module)
+      .collect::<Vec<_>>();
 
+    let prelude = &self.prelude;
     tokens.extend(quote! {
-      mod types;
-      mod #secondary_mod;
+      #(mod #modules;)*
+
+      #(#vis use #modules::*;)*
 
-      #vis use types::*;
-      #vis use #secondary_mod::*;
+      #prelude
     });
   }
 }
@@ -0,0 +1,111 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+
+use super::Visibility;
+use crate::generator::{
+  ast::{ClientRootNode, OperationInfo, OperationKind, ResponseMediaType, SecuritySchemeKind},
+  converter::CodegenConfig,
+};
+
+/// A `prelude` module that re-exports the items most client code needs.
+///
+/// Covers the client and its credentials, the request and response types of every
+/// HTTP operation, and the `oas3_gen_support` types the client can return or accept.
+#[derive(Debug, Clone)]
+pub struct PreludeFragment {
+  local_items: Vec<String>,
+  support_items: BTreeSet<&'static str>,
+  visibility: Visibility,
+}
+
+impl PreludeFragment {
+  pub fn new(
+    client: &ClientRootNode,
+    operations: &[OperationInfo],
+    config: &CodegenConfig,
+    visibility: Visibility,
+  ) -> Self {
+    let mut local_items = vec![client.name.to_string()];
+    if !client.security_schemes.is_empty() {
+      local_items.push(format!("{}Auth", client.name));
+    }
+    if config.typed_client_errors() {
+      local_items.push(format!("{}Error", client.name));
+    }
+
+    let mut seen = BTreeSet::new();
+    let operation_items = operations
+      .iter()
+      .filter(|op| op.kind == OperationKind::Http)
+      .flat_map(|op| {
+        [
+          op.request_type.as_ref().map(ToString::to_string),
+          op.response_enum.as_ref().map(ToString::to_string),
+        ]
+      })
+      .flatten()
+      .filter(|name| seen.insert(name.clone()));
+    local_items.extend(operation_items);
+
+    let mut support_items = BTreeSet::new();
+    for scheme in &client.security_schemes {
+      match scheme.kind {
+        SecuritySchemeKind::Basic => {
+          support_items.insert("BasicAuth");
+        }
+        SecuritySchemeKind::ClientCredentials { .. } => {
+          support_items.extend(["AuthError", "ClientCredentials"]);
+        }
+        SecuritySchemeKind::ApiKey { .. } | SecuritySchemeKind::Bearer => {}
+      }
+    }
+    if config.capture_malformed_responses() {
+      support_items.insert("MalformedResponse");
+    }
+    if config.vcr_enabled() {
+      support_items.extend(["Vcr", "VcrError"]);
+    }
+    if config.retry_enabled() {
+      support_items.extend(["Backoff", "ClientConfig", "RetryPolicy"]);
+    }
+    if config.request_hooks_enabled() {
+      support_items.extend(["HookError", "RequestHook", "RequestOptions"]);
+    }
+    if config.last_response_enabled() {
+      support_items.insert("ResponseMetadata");
+    }
+    if operations
+      .iter()
+      .any(|op| ResponseMediaType::has_event_stream(&op.response_media_types))
+    {
+      support_items.extend(["EventStream", "EventStreamError"]);
+    }
+
+    Self {
+      local_items,
+      support_items,
+      visibility,
+    }
+  }
+}
+
+impl ToTokens for PreludeFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let vis = &self.visibility;
+    let local_items = self.local_items.iter().map(|name| format_ident!("{name}"));
+    let support_import = (!self.support_items.is_empty()).then(|| {
+      let support_items = self.support_items.iter().map(|name| format_ident!("{name}"));
+      quote! { #vis use oas3_gen_support::{#(#support_items),*}; }
+    });
+
+    tokens.extend(quote! {
+      #[doc = " Commonly used client, request, response, and error types."]
+      #vis mod prelude {
+        #vis use super::{#(#local_items),*};
+        #support_import
+      }
+    });
+  }
+}
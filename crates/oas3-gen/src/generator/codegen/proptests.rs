@@ -0,0 +1,517 @@
+use std::collections::BTreeSet;
+
+use petgraph::{algo::tarjan_scc, graphmap::DiGraphMap};
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+
+use super::example_tests::has_serde;
+use crate::generator::{
+  ast::{
+    DiscriminatedEnumDef, EnumDef, RustPrimitive, RustType, SerdeAttribute, StructDef, TypeRef, ValidationAttribute,
+    VariantContent,
+  },
+  naming::identifiers::to_rust_field_name,
+};
+
+/// Most strategies combined in one tuple; larger structs nest tuples of tuples.
+const MAX_TUPLE: usize = 10;
+
+/// Length of generated strings and arrays past their `min_length`/`min_items` when the
+/// schema sets no maximum.
+const EXTRA_LENGTH: u64 = 16;
+const EXTRA_ITEMS: u64 = 3;
+
+/// Generates a `#[cfg(test)] mod proptests` with a `proptest` strategy for every schema
+/// type, and one test per type running a `TestRunner` that serializes each generated
+/// value, deserializes the output, and asserts both values are equal.
+///
+/// Strings, arrays, and numbers stay within the `length` and `range` bounds of their
+/// field. A type reached again through a reference cycle is generated empty or `None`
+/// at that point. Types with a required field no strategy can build, such as a
+/// `x-rust-type` override, get no strategy and no test.
+#[derive(Clone, Debug)]
+pub(crate) struct PropertyTestsFragment {
+  strategies: Vec<TokenStream>,
+  tests: Vec<TokenStream>,
+}
+
+impl PropertyTestsFragment {
+  pub(crate) fn new(rust_types: &[RustType]) -> Self {
+    let candidates = rust_types
+      .iter()
+      .filter(|rust_type| has_serde(rust_type))
+      .collect::<Vec<_>>();
+    let names = candidates
+      .iter()
+      .map(|rust_type| rust_type.type_name().to_string())
+      .collect::<BTreeSet<_>>();
+    let mut builder = StrategyBuilder {
+      cyclic: cyclic_references(&candidates, &names),
+      supported: names,
+    };
+    loop {
+      let unsupported = candidates
+        .iter()
+        .filter(|rust_type| builder.supported.contains(rust_type.type_name().as_ref()))
+        .filter(|rust_type| builder.strategy(rust_type).is_none())
+        .map(|rust_type| rust_type.type_name().to_string())
+        .collect::<Vec<_>>();
+      if unsupported.is_empty() {
+        break;
+      }
+      for name in unsupported {
+        builder.supported.remove(&name);
+      }
+    }
+
+    let mut strategies = vec![];
+    let mut tests = vec![];
+    for rust_type in candidates {
+      let Some(strategy) = builder.strategy(rust_type) else {
+        continue;
+      };
+      let name = rust_type.type_name();
+      let ty = format_ident!("{}", name.as_ref());
+      let function = strategy_function(&name);
+      strategies.push(quote! {
+        fn #function() -> impl Strategy<Value = #ty> {
+          #strategy
+        }
+      });
+      tests.push(round_trip_test(&name, &function));
+    }
+    Self { strategies, tests }
+  }
+}
+
+impl ToTokens for PropertyTestsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if self.tests.is_empty() {
+      return;
+    }
+    let strategies = &self.strategies;
+    let tests = &self.tests;
+    quote! {
+      #[cfg(test)]
+      mod proptests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #(#strategies)*
+
+        #(#tests)*
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+fn strategy_function(type_name: &str) -> proc_macro2::Ident {
+  format_ident!("{}", to_rust_field_name(&format!("Arb{type_name}")))
+}
+
+fn round_trip_test(type_name: &str, function: &proc_macro2::Ident) -> TokenStream {
+  let ty = format_ident!("{type_name}");
+  let test_name = format_ident!("{}", to_rust_field_name(&format!("{type_name}RoundTrips")));
+  let serialize = format!("`{type_name}` should serialize");
+  let parse_output = format!("`{type_name}` should deserialize its own output");
+  let round_trip = format!("`{type_name}` should round-trip through JSON");
+  quote! {
+    #[test]
+    fn #test_name() {
+      proptest::test_runner::TestRunner::default()
+        .run(&#function(), |value| {
+          let json = serde_json::to_string(&value).expect(#serialize);
+          let round_tripped: #ty = serde_json::from_str(&json).expect(#parse_output);
+          prop_assert_eq!(round_tripped, value);
+          Ok(())
+        })
+        .expect(#round_trip);
+    }
+  }
+}
+
+/// Returns the `(from, to)` references between types that lead back to `from`.
+fn cyclic_references(rust_types: &[&RustType], names: &BTreeSet<String>) -> BTreeSet<(String, String)> {
+  let mut graph = DiGraphMap::<&str, ()>::new();
+  for rust_type in rust_types {
+    let Some(from) = names.get(rust_type.type_name().as_ref()) else {
+      continue;
+    };
+    let from = graph.add_node(from.as_str());
+    for type_ref in referenced_types(rust_type) {
+      if let RustPrimitive::Custom(to) = &type_ref.base_type
+        && let Some(to) = names.get(to.as_ref())
+      {
+        graph.add_edge(from, to.as_str(), ());
+      }
+    }
+  }
+  let mut cyclic = BTreeSet::new();
+  for component in tarjan_scc(&graph) {
+    let members = component.iter().copied().collect::<BTreeSet<_>>();
+    for from in &component {
+      for to in graph.neighbors(from) {
+        if members.contains(to) {
+          cyclic.insert(((*from).to_string(), to.to_string()));
+        }
+      }
+    }
+  }
+  cyclic
+}
+
+fn referenced_types(rust_type: &RustType) -> Vec<&TypeRef> {
+  match rust_type {
+    RustType::Struct(def) => def.fields.iter().map(|field| &field.rust_type).collect(),
+    RustType::Enum(def) => def
+      .variants
+      .iter()
+      .filter_map(|variant| variant.content.tuple_types())
+      .flatten()
+      .collect(),
+    RustType::DiscriminatedEnum(def) => def.all_variants().map(|variant| &variant.type_name).collect(),
+    RustType::TypeAlias(def) => vec![&def.target],
+    RustType::ResponseEnum(_) => vec![],
+  }
+}
+
+struct StrategyBuilder {
+  /// Types that have a strategy.
+  supported: BTreeSet<String>,
+  /// References that lead back to the type holding them.
+  cyclic: BTreeSet<(String, String)>,
+}
+
+impl StrategyBuilder {
+  fn strategy(&self, rust_type: &RustType) -> Option<TokenStream> {
+    let owner = rust_type.type_name();
+    match rust_type {
+      RustType::Struct(def) => self.struct_strategy(&owner, def),
+      RustType::Enum(def) => self.enum_strategy(&owner, def),
+      RustType::DiscriminatedEnum(def) => self.discriminated_strategy(&owner, def),
+      RustType::TypeAlias(def) => self.type_ref(&owner, &def.target, &[]),
+      RustType::ResponseEnum(_) => None,
+    }
+  }
+
+  /// Builds the struct from its fields' strategies. Fields serde never reads back, such
+  /// as skipped or flattened ones, keep their `Default` value.
+  fn struct_strategy(&self, owner: &str, def: &StructDef) -> Option<TokenStream> {
+    let name = &def.name;
+    let mut fields = vec![];
+    let mut defaulted = false;
+    for field in &def.fields {
+      let skipped = field.serde_attrs.iter().any(|attr| {
+        matches!(
+          attr,
+          SerdeAttribute::Skip | SerdeAttribute::SkipDeserializing | SerdeAttribute::Flatten
+        )
+      });
+      if skipped || field.rust_type.base_type == RustPrimitive::StaticStr {
+        defaulted = true;
+        continue;
+      }
+      let strategy = self.type_ref(owner, &field.rust_type, &field.validation_attrs)?;
+      fields.push((field.name.to_token_stream(), strategy));
+    }
+    if fields.is_empty() {
+      return Some(quote! { proptest::strategy::LazyJust::new(#name::default) });
+    }
+
+    let names = fields.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>();
+    let rest = defaulted.then(|| quote! { ..Default::default() });
+    let (pattern, strategy) = nest(fields);
+    Some(quote! { #strategy.prop_map(|#pattern| #name { #(#names,)* #rest }) })
+  }
+
+  /// Picks one of the enum's variants. Catch-all variants for unknown values are left
+  /// out, since a generated value would read back as the variant it matches.
+  fn enum_strategy(&self, owner: &str, def: &EnumDef) -> Option<TokenStream> {
+    let name = &def.name;
+    let fallback = def
+      .scalar_fallback()
+      .or_else(|| def.fallback_variant())
+      .map(|variant| &variant.name);
+    let variants = def
+      .variants
+      .iter()
+      .filter(|variant| Some(&variant.name) != fallback && !variant.serde_attrs.contains(&SerdeAttribute::Other))
+      .filter_map(|variant| {
+        let variant_name = &variant.name;
+        match &variant.content {
+          VariantContent::Unit if def.skip_clone => {
+            Some(quote! { proptest::strategy::LazyJust::new(|| #name::#variant_name) })
+          }
+          VariantContent::Unit => Some(quote! { Just(#name::#variant_name) }),
+          VariantContent::Tuple(types) => {
+            let items = types
+              .iter()
+              .enumerate()
+              .map(|(index, type_ref)| {
+                Some((
+                  format_ident!("item{index}").to_token_stream(),
+                  self.type_ref(owner, type_ref, &[])?,
+                ))
+              })
+              .collect::<Option<Vec<_>>>()?;
+            if let [(_, strategy)] = items.as_slice() {
+              return Some(quote! { #strategy.prop_map(#name::#variant_name) });
+            }
+            let bindings = items.iter().map(|(binding, _)| binding.clone()).collect::<Vec<_>>();
+            let (pattern, strategy) = nest(items);
+            Some(quote! { #strategy.prop_map(|#pattern| #name::#variant_name(#(#bindings),*)) })
+          }
+        }
+      })
+      .collect::<Vec<_>>();
+    one_of(variants)
+  }
+
+  /// Picks one of the mapped variants; the fallback variant is left out.
+  fn discriminated_strategy(&self, owner: &str, def: &DiscriminatedEnumDef) -> Option<TokenStream> {
+    let name = &def.name;
+    let variants = def
+      .variants
+      .iter()
+      .filter_map(|variant| {
+        let variant_name = &variant.variant_name;
+        let strategy = self.type_ref(owner, &variant.type_name, &[])?;
+        Some(quote! { #strategy.prop_map(#name::#variant_name) })
+      })
+      .collect::<Vec<_>>();
+    one_of(variants)
+  }
+
+  /// Wraps the strategy for the base type in the type's `Box`, `Vec`, and `Option`.
+  ///
+  /// When the base type has no strategy, optional and array types fall back to `None`
+  /// and an empty array.
+  fn type_ref(&self, owner: &str, type_ref: &TypeRef, attrs: &[ValidationAttribute]) -> Option<TokenStream> {
+    let base_attrs = if type_ref.is_array { &[][..] } else { attrs };
+    let strategy = self.base(owner, &type_ref.base_type, base_attrs).map(|mut strategy| {
+      if type_ref.boxed {
+        strategy = quote! { #strategy.prop_map(Box::new) };
+      }
+      if type_ref.is_array {
+        let size = size_range(attrs, EXTRA_ITEMS);
+        strategy = quote! { proptest::collection::vec(#strategy, #size) };
+        if type_ref.unique_items {
+          strategy = quote! { #strategy.prop_map(|items| items.into_iter().collect::<indexmap::IndexSet<_>>()) };
+        }
+      }
+      if type_ref.nullable {
+        strategy = quote! { proptest::option::of(#strategy) };
+      }
+      strategy
+    });
+    strategy.or_else(|| {
+      (type_ref.nullable || type_ref.is_array)
+        .then(|| quote! { proptest::strategy::LazyJust::new(<#type_ref>::default) })
+    })
+  }
+
+  fn base(&self, owner: &str, primitive: &RustPrimitive, attrs: &[ValidationAttribute]) -> Option<TokenStream> {
+    let strategy = match primitive {
+      primitive if primitive.is_integer() => integer_strategy(primitive, attrs),
+      primitive if primitive.is_float() => float_strategy(primitive, attrs),
+      RustPrimitive::Bool => quote! { any::<bool>() },
+      RustPrimitive::String => string_strategy(attrs),
+      RustPrimitive::Bytes => quote! { any::<Vec<u8>>() },
+      RustPrimitive::DateTime => quote! {
+        (0i64..4_102_444_800).prop_map(|secs| chrono::DateTime::from_timestamp(secs, 0).unwrap_or_default())
+      },
+      RustPrimitive::Uuid => quote! { any::<u128>().prop_map(uuid::Uuid::from_u128) },
+      RustPrimitive::Value => quote! { any::<i32>().prop_map(serde_json::Value::from) },
+      RustPrimitive::Unit => quote! { Just(()) },
+      RustPrimitive::Date
+      | RustPrimitive::Time
+      | RustPrimitive::Duration
+      | RustPrimitive::Decimal
+      | RustPrimitive::BigDecimal => quote! { proptest::strategy::LazyJust::new(<#primitive>::default) },
+      RustPrimitive::Custom(name)
+        if self.supported.contains(name.as_ref()) && !self.cyclic.contains(&(owner.to_string(), name.to_string())) =>
+      {
+        let function = strategy_function(name);
+        quote! { #function() }
+      }
+      RustPrimitive::Custom(name) if name.contains("Map<") => {
+        quote! { proptest::strategy::LazyJust::new(<#primitive>::default) }
+      }
+      _ => return None,
+    };
+    Some(strategy)
+  }
+}
+
+/// Combines `(binding, strategy)` pairs into one tuple strategy and the pattern that
+/// destructures its values, nesting tuples past [`MAX_TUPLE`] elements.
+fn nest(items: Vec<(TokenStream, TokenStream)>) -> (TokenStream, TokenStream) {
+  if items.len() > MAX_TUPLE {
+    let groups = items
+      .chunks(MAX_TUPLE)
+      .map(|group| nest(group.to_vec()))
+      .collect::<Vec<_>>();
+    return nest(groups);
+  }
+  let (patterns, strategies) = items.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+  match (patterns.as_slice(), strategies.as_slice()) {
+    ([pattern], [strategy]) => (pattern.clone(), strategy.clone()),
+    _ => (quote! { (#(#patterns),*) }, quote! { (#(#strategies),*) }),
+  }
+}
+
+fn one_of(strategies: Vec<TokenStream>) -> Option<TokenStream> {
+  match strategies.as_slice() {
+    [] => None,
+    [strategy] => Some(strategy.clone()),
+    _ => Some(quote! { proptest::strategy::Union::new([#(#strategies.boxed()),*]) }),
+  }
+}
+
+fn length_bounds(attrs: &[ValidationAttribute]) -> (u64, Option<u64>) {
+  attrs
+    .iter()
+    .find_map(|attr| match attr {
+      ValidationAttribute::Length { min, max } => Some((min.unwrap_or(0), *max)),
+      _ => None,
+    })
+    .unwrap_or((0, None))
+}
+
+/// A `min..=max` length range within the `length` bounds in `attrs`.
+fn size_range(attrs: &[ValidationAttribute], extra: u64) -> TokenStream {
+  let (min, max) = length_bounds(attrs);
+  let max = max.unwrap_or(min + extra).max(min);
+  let min = Literal::u64_unsuffixed(min);
+  let max = Literal::u64_unsuffixed(max);
+  quote! { #min..=#max }
+}
+
+/// Generates ASCII letters when the field has length bounds, so the length `validator`
+/// counts matches the generated one, and any string otherwise.
+fn string_strategy(attrs: &[ValidationAttribute]) -> TokenStream {
+  if length_bounds(attrs) == (0, None) {
+    return quote! { any::<String>() };
+  }
+  let size = size_range(attrs, EXTRA_LENGTH);
+  quote! { proptest::collection::vec(proptest::char::range('a', 'z'), #size).prop_map(String::from_iter) }
+}
+
+/// Returns the smallest and largest values the `range` validator in `attrs` accepts.
+fn range_bounds(attrs: &[ValidationAttribute]) -> (Option<f64>, Option<f64>, bool, bool) {
+  attrs
+    .iter()
+    .find_map(|attr| match attr {
+      ValidationAttribute::Range {
+        min,
+        max,
+        exclusive_min,
+        exclusive_max,
+        ..
+      } => {
+        let lower = exclusive_min
+          .as_ref()
+          .or(min.as_ref())
+          .and_then(serde_json::Number::as_f64);
+        let upper = exclusive_max
+          .as_ref()
+          .or(max.as_ref())
+          .and_then(serde_json::Number::as_f64);
+        Some((lower, upper, exclusive_min.is_some(), exclusive_max.is_some()))
+      }
+      _ => None,
+    })
+    .unwrap_or((None, None, false, false))
+}
+
+/// Returns the whole numbers within the `range` bounds in `attrs`, clamped to `limits`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn whole_range(attrs: &[ValidationAttribute], limits: (i128, i128)) -> Option<(i128, i128)> {
+  let (lower, upper, exclusive_lower, exclusive_upper) = range_bounds(attrs);
+  let low = lower.map_or(limits.0, |bound| {
+    let whole = bound.ceil();
+    let whole = if exclusive_lower && whole == bound {
+      whole + 1.0
+    } else {
+      whole
+    };
+    (whole as i128).max(limits.0)
+  });
+  let high = upper.map_or(limits.1, |bound| {
+    let whole = bound.floor();
+    let whole = if exclusive_upper && whole == bound {
+      whole - 1.0
+    } else {
+      whole
+    };
+    (whole as i128).min(limits.1)
+  });
+  (low <= high).then_some((low, high))
+}
+
+fn integer_limits(primitive: &RustPrimitive) -> (i128, i128) {
+  match primitive {
+    RustPrimitive::I8 => (i8::MIN.into(), i8::MAX.into()),
+    RustPrimitive::I16 => (i16::MIN.into(), i16::MAX.into()),
+    RustPrimitive::I32 | RustPrimitive::Isize => (i32::MIN.into(), i32::MAX.into()),
+    RustPrimitive::U8 => (0, u8::MAX.into()),
+    RustPrimitive::U16 => (0, u16::MAX.into()),
+    RustPrimitive::U32 | RustPrimitive::Usize => (0, u32::MAX.into()),
+    RustPrimitive::U64 | RustPrimitive::U128 => (0, u64::MAX.into()),
+    _ => (i64::MIN.into(), i64::MAX.into()),
+  }
+}
+
+fn typed_literal(value: i128, primitive: &RustPrimitive) -> TokenStream {
+  format!("{value}{primitive}").parse().unwrap_or_default()
+}
+
+/// Generates integers within the field's `range` bounds, or any value of the type.
+///
+/// 128-bit and pointer-sized integers stay within the 64-bit and 32-bit ranges that
+/// every JSON parser and target handles.
+fn integer_strategy(primitive: &RustPrimitive, attrs: &[ValidationAttribute]) -> TokenStream {
+  let wide = matches!(
+    primitive,
+    RustPrimitive::I128 | RustPrimitive::U128 | RustPrimitive::Isize | RustPrimitive::Usize
+  );
+  if !wide && range_bounds(attrs).0.is_none() && range_bounds(attrs).1.is_none() {
+    return quote! { any::<#primitive>() };
+  }
+  let limits = integer_limits(primitive);
+  let (low, high) = whole_range(attrs, limits).unwrap_or(limits);
+  let low = typed_literal(low, primitive);
+  let high = typed_literal(high, primitive);
+  quote! { (#low..=#high) }
+}
+
+/// Generates whole numbers, which survive a trip through JSON text exactly, within the
+/// field's `range` bounds. Bounds with no whole number between them yield their midpoint.
+fn float_strategy(primitive: &RustPrimitive, attrs: &[ValidationAttribute]) -> TokenStream {
+  let (whole, limits) = match primitive {
+    RustPrimitive::F32 => (RustPrimitive::I16, (i16::MIN.into(), i16::MAX.into())),
+    _ => (RustPrimitive::I32, (i32::MIN.into(), i32::MAX.into())),
+  };
+  let (lower, upper, ..) = range_bounds(attrs);
+  if lower.is_none() && upper.is_none() {
+    return quote! { any::<#whole>().prop_map(#primitive::from) };
+  }
+  match whole_range(attrs, limits) {
+    Some((low, high)) => {
+      let low = typed_literal(low, &whole);
+      let high = typed_literal(high, &whole);
+      quote! { (#low..=#high).prop_map(#primitive::from) }
+    }
+    None => {
+      let midpoint = (lower.unwrap_or_default() + upper.unwrap_or_default()) / 2.0;
+      let midpoint = match primitive {
+        #[allow(clippy::cast_possible_truncation)]
+        RustPrimitive::F32 => Literal::f32_suffixed(midpoint as f32),
+        _ => Literal::f64_suffixed(midpoint),
+      };
+      quote! { Just(#midpoint) }
+    }
+  }
+}
@@ -0,0 +1,374 @@
+use std::{collections::BTreeSet, sync::LazyLock};
+
+use quote::ToTokens;
+use regex::Regex;
+use syn::{
+  Attribute, Fields, ImplItem, Item, ItemMod, ItemUse, Signature, TraitItem, Type, UseTree, Visibility,
+  punctuated::Punctuated,
+};
+
+use super::{GeneratedFileType, GeneratedResult};
+
+/// Lists every public item in the generated code, one per line, so the report can be
+/// diffed between generator and spec versions.
+///
+/// Lines are sorted by item path, each item followed by its fields, variants, methods, and
+/// trait implementations. Items are named by the path they are reachable at from the output root: modules that
+/// `mod.rs` re-exports with a glob are flattened into their parent, and workspace crates are
+/// named after their library. Items marked `#[doc(hidden)]` are left out.
+pub fn public_api_report(result: &GeneratedResult) -> anyhow::Result<String> {
+  let mut report = PublicApiReport::default();
+  report.collect_module(result, &[])?;
+  Ok(
+    report
+      .lines
+      .into_iter()
+      .map(|(_, _, line)| format!("{line}\n"))
+      .collect(),
+  )
+}
+
+#[derive(Debug, Default)]
+struct PublicApiReport {
+  lines: BTreeSet<(String, Rank, String)>,
+}
+
+/// Orders the lines describing one item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Rank {
+  Item,
+  Member,
+  Impl,
+}
+
+/// How a `mod.rs` exposes one of its child modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exposure {
+  Public,
+  Reexported,
+  Private,
+}
+
+impl PublicApiReport {
+  fn collect_module(&mut self, result: &GeneratedResult, path: &[String]) -> anyhow::Result<()> {
+    let module_file = result
+      .code
+      .get(&GeneratedFileType::Module)
+      .map(|code| syn::parse_file(code))
+      .transpose()?;
+
+    for (file_type, code) in &result.code {
+      let name = match file_type {
+        GeneratedFileType::Manifest => continue,
+        GeneratedFileType::Module | GeneratedFileType::Library => None,
+        GeneratedFileType::Client => Some("client"),
+        GeneratedFileType::Server => Some("server"),
+        GeneratedFileType::Types => Some("types"),
+        GeneratedFileType::Tag(name) => Some(name.as_str()),
+      };
+      let Some(module_path) = child_path(module_file.as_ref(), path, name) else {
+        continue;
+      };
+      self.collect_items(&syn::parse_file(code)?.items, &module_path);
+    }
+
+    for (name, submodule) in &result.modules {
+      let submodule_path = if submodule.code.contains_key(&GeneratedFileType::Library) {
+        Some(vec![name.replace('-', "_")])
+      } else {
+        child_path(module_file.as_ref(), path, Some(name))
+      };
+      if let Some(submodule_path) = submodule_path {
+        self.collect_module(submodule, &submodule_path)?;
+      }
+    }
+    Ok(())
+  }
+
+  fn collect_items(&mut self, items: &[Item], path: &[String]) {
+    for item in items {
+      match item {
+        Item::Struct(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(
+            &name,
+            Rank::Item,
+            format!("pub struct {name}{}", render(&item.generics)),
+          );
+          self.push_derives(&item.attrs, &name);
+          for (index, field) in item.fields.iter().enumerate() {
+            if is_public(&field.vis, &field.attrs) {
+              let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), ToString::to_string);
+              self.push(
+                &name,
+                Rank::Member,
+                format!("pub {name}::{field_name}: {}", render(&field.ty)),
+              );
+            }
+          }
+        }
+        Item::Enum(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(&name, Rank::Item, format!("pub enum {name}{}", render(&item.generics)));
+          self.push_derives(&item.attrs, &name);
+          for variant in item.variants.iter().filter(|variant| !is_hidden(&variant.attrs)) {
+            let fields = match &variant.fields {
+              Fields::Unit => String::new(),
+              fields @ Fields::Unnamed(_) => render(fields),
+              fields @ Fields::Named(_) => format!(" {}", render(fields)),
+            };
+            self.push(&name, Rank::Member, format!("pub {name}::{}{fields}", variant.ident));
+          }
+        }
+        Item::Type(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          let line = format!("pub type {name}{} = {}", render(&item.generics), render(&item.ty));
+          self.push(&name, Rank::Item, line);
+        }
+        Item::Const(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(&name, Rank::Item, format!("pub const {name}: {}", render(&item.ty)));
+        }
+        Item::Static(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(&name, Rank::Item, format!("pub static {name}: {}", render(&item.ty)));
+        }
+        Item::Fn(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.sig.ident.to_string());
+          self.push(
+            &name,
+            Rank::Item,
+            format!("pub {}", render_signature(&item.sig, &qualify(path, ""))),
+          );
+        }
+        Item::Trait(item) if is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(&name, Rank::Item, format!("pub trait {name}{}", render(&item.generics)));
+          for trait_item in &item.items {
+            match trait_item {
+              TraitItem::Fn(method) if !is_hidden(&method.attrs) => {
+                self.push(&name, Rank::Member, render_signature(&method.sig, &format!("{name}::")));
+              }
+              TraitItem::Type(ty) if !is_hidden(&ty.attrs) => {
+                self.push(&name, Rank::Member, format!("type {name}::{}", ty.ident));
+              }
+              TraitItem::Const(constant) if !is_hidden(&constant.attrs) => {
+                let line = format!("const {name}::{}: {}", constant.ident, render(&constant.ty));
+                self.push(&name, Rank::Member, line);
+              }
+              _ => {}
+            }
+          }
+        }
+        Item::Impl(item) if !is_hidden(&item.attrs) => {
+          let self_ty = qualify_type(path, &item.self_ty);
+          if let Some((trait_path, _)) = &item.trait_ {
+            let line = format!("impl{} {} for {self_ty}", render(&item.generics), render(trait_path));
+            self.push(&self_ty, Rank::Impl, line);
+            continue;
+          }
+          for impl_item in &item.items {
+            match impl_item {
+              ImplItem::Fn(method) if is_public(&method.vis, &method.attrs) => {
+                let line = format!("pub {}", render_signature(&method.sig, &format!("{self_ty}::")));
+                self.push(&self_ty, Rank::Member, line);
+              }
+              ImplItem::Const(constant) if is_public(&constant.vis, &constant.attrs) => {
+                let line = format!("pub const {self_ty}::{}: {}", constant.ident, render(&constant.ty));
+                self.push(&self_ty, Rank::Member, line);
+              }
+              _ => {}
+            }
+          }
+        }
+        Item::Mod(ItemMod {
+          vis,
+          attrs,
+          ident,
+          content: Some((_, items)),
+          ..
+        }) if is_public(vis, attrs) => {
+          let mut module_path = path.to_vec();
+          module_path.push(ident.to_string());
+          let name = module_path.join("::");
+          self.push(&name, Rank::Item, format!("pub mod {name}"));
+          self.collect_items(items, &module_path);
+        }
+        Item::Mod(item) if item.content.is_none() && is_public(&item.vis, &item.attrs) => {
+          let name = qualify(path, &item.ident.to_string());
+          self.push(&name, Rank::Item, format!("pub mod {name}"));
+        }
+        Item::Use(item) if is_public(&item.vis, &item.attrs) && reexported_module(item).is_none() => {
+          let mut leaves = vec![];
+          use_leaves(&item.tree, String::new(), &mut leaves);
+          for (source, name) in leaves {
+            let name = if name == "*" {
+              format!("{}*", qualify(path, &source))
+            } else {
+              qualify(path, &name)
+            };
+            self.push(&name, Rank::Item, format!("pub use {name}"));
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn push_derives(&mut self, attrs: &[Attribute], name: &str) {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+      let Ok(derives) = attr.parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated) else {
+        continue;
+      };
+      for derive in derives {
+        self.push(name, Rank::Impl, format!("impl {} for {name}", render(&derive)));
+      }
+    }
+  }
+
+  fn push(&mut self, item: &str, rank: Rank, line: String) {
+    self.lines.insert((item.to_string(), rank, line));
+  }
+}
+
+/// Returns the path items of the child module `name` are reachable at, or `None` when the
+/// module is private. Without a `mod.rs`, the file is the output root.
+fn child_path(module_file: Option<&syn::File>, path: &[String], name: Option<&str>) -> Option<Vec<String>> {
+  let (Some(module_file), Some(name)) = (module_file, name) else {
+    return Some(path.to_vec());
+  };
+  match exposure(module_file, name) {
+    Exposure::Public => {
+      let mut child = path.to_vec();
+      child.push(name.to_string());
+      Some(child)
+    }
+    Exposure::Reexported => Some(path.to_vec()),
+    Exposure::Private => None,
+  }
+}
+
+fn exposure(module_file: &syn::File, name: &str) -> Exposure {
+  let mut exposure = Exposure::Private;
+  for item in &module_file.items {
+    match item {
+      Item::Mod(item) if item.ident == name && matches!(item.vis, Visibility::Public(_)) => return Exposure::Public,
+      Item::Use(item)
+        if matches!(item.vis, Visibility::Public(_)) && reexported_module(item).as_deref() == Some(name) =>
+      {
+        exposure = Exposure::Reexported;
+      }
+      _ => {}
+    }
+  }
+  exposure
+}
+
+/// Returns the module a `pub use module::*;` item re-exports.
+fn reexported_module(item: &ItemUse) -> Option<String> {
+  match &item.tree {
+    UseTree::Path(path) if matches!(*path.tree, UseTree::Glob(_)) => Some(path.ident.to_string()),
+    _ => None,
+  }
+}
+
+fn use_leaves(tree: &UseTree, prefix: String, leaves: &mut Vec<(String, String)>) {
+  match tree {
+    UseTree::Path(path) => use_leaves(&path.tree, format!("{prefix}{}::", path.ident), leaves),
+    UseTree::Name(name) => leaves.push((prefix, name.ident.to_string())),
+    UseTree::Rename(rename) => leaves.push((format!("{prefix}{}", rename.ident), rename.rename.to_string())),
+    UseTree::Glob(_) => leaves.push((prefix, "*".to_string())),
+    UseTree::Group(group) => {
+      for tree in &group.items {
+        use_leaves(tree, prefix.clone(), leaves);
+      }
+    }
+  }
+}
+
+fn is_public(vis: &Visibility, attrs: &[Attribute]) -> bool {
+  matches!(vis, Visibility::Public(_)) && !is_hidden(attrs)
+}
+
+fn is_hidden(attrs: &[Attribute]) -> bool {
+  attrs
+    .iter()
+    .any(|attr| attr.path().is_ident("doc") && attr.parse_args::<syn::Ident>().is_ok_and(|ident| ident == "hidden"))
+}
+
+fn qualify(path: &[String], name: &str) -> String {
+  if path.is_empty() {
+    name.to_string()
+  } else {
+    format!("{}::{name}", path.join("::"))
+  }
+}
+
+/// Prefixes a type declared in the current module with its path.
+fn qualify_type(path: &[String], ty: &Type) -> String {
+  match ty {
+    Type::Path(type_path) if type_path.qself.is_none() && type_path.path.segments.len() == 1 => {
+      qualify(path, &render(ty))
+    }
+    _ => render(ty),
+  }
+}
+
+fn render_signature(sig: &Signature, prefix: &str) -> String {
+  let qualifiers = [
+    sig.constness.map(|_| "const "),
+    sig.asyncness.map(|_| "async "),
+    matches!(sig.safety, syn::Safety::Unsafe(_)).then_some("unsafe "),
+  ]
+  .into_iter()
+  .flatten()
+  .collect::<String>();
+  let output = match &sig.output {
+    syn::ReturnType::Default => String::new(),
+    syn::ReturnType::Type(_, ty) => format!(" -> {}", render(ty)),
+  };
+  let where_clause = sig
+    .generics
+    .where_clause
+    .as_ref()
+    .map(|clause| format!(" {}", render(clause).trim_end_matches(',')))
+    .unwrap_or_default();
+  format!(
+    "{qualifiers}fn {prefix}{}{}({}){output}{where_clause}",
+    sig.ident,
+    render(&sig.generics),
+    sig.inputs.iter().map(render).collect::<Vec<_>>().join(", ")
+  )
+}
+
+/// Matches the space `to_string` leaves between a name and its parentheses, as in `Fn (T)`.
+static CALL_PAREN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\w) \(").unwrap());
+
+/// Renders tokens the way they are usually written, e.g. `Option<Vec<String>>` rather
+/// than `Option < Vec < String > >`.
+fn render(tokens: &impl ToTokens) -> String {
+  let mut text = tokens.to_token_stream().to_string();
+  for (from, to) in [
+    (" :: ", "::"),
+    (":: ", "::"),
+    (" ::", "::"),
+    (" ,", ","),
+    (" ;", ";"),
+    (" :", ":"),
+    ("< ", "<"),
+    (" <", "<"),
+    (" >", ">"),
+    ("( ", "("),
+    (" )", ")"),
+    ("[ ", "["),
+    (" ]", "]"),
+    ("& ", "&"),
+    (" ?", "?"),
+  ] {
+    text = text.replace(from, to);
+  }
+  CALL_PAREN_RE.replace_all(&text, "$1(").into_owned()
+}
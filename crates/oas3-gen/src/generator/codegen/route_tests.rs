@@ -0,0 +1,231 @@
+use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
+use proc_macro2::{Literal, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+
+use crate::generator::{
+  ast::{FieldDef, FieldNameToken, OperationInfo, OperationKind, ParameterLocation, PathSegment, RustPrimitive},
+  naming::constants::{PATH_PARAMS_FIELD, PATH_PARAMS_SUFFIX},
+};
+
+/// Bytes `url` percent-encodes when pushing a path segment onto an `http(s)` URL.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+  .add(b' ')
+  .add(b'"')
+  .add(b'#')
+  .add(b'%')
+  .add(b'/')
+  .add(b'<')
+  .add(b'>')
+  .add(b'?')
+  .add(b'\\')
+  .add(b'`')
+  .add(b'{')
+  .add(b'}');
+
+/// Bytes `url` percent-encodes in the query of an `http(s)` URL.
+const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'\'').add(b'<').add(b'>');
+
+/// Base URL the generated tests build routes on. Its own path checks that operation paths
+/// are appended to it rather than replacing it.
+const TEST_BASE_URL: &str = "http://localhost/api";
+const TEST_BASE_PATH: &str = "/api";
+
+/// A path parameter value written into a generated test, and how the client renders it.
+struct Sample {
+  value: TokenStream,
+  rendered: String,
+}
+
+impl Sample {
+  /// Picks a sample for a path parameter, or `None` when its type has no fixed sample,
+  /// such as an enum or an object.
+  ///
+  /// `index` keeps the samples of an operation's parameters distinct.
+  fn for_field(field: &FieldDef, index: usize) -> Option<Self> {
+    let ty = &field.rust_type;
+    if ty.nullable || ty.boxed || field.object_style.is_some() {
+      return None;
+    }
+    let name = field.original_name.as_deref().unwrap_or(field.name.as_str());
+    if !ty.is_array {
+      return Self::scalar(&ty.base_type, name, index);
+    }
+
+    let items = [
+      Self::scalar(&ty.base_type, name, index)?,
+      Self::scalar(&ty.base_type, name, index + 1)?,
+    ];
+    let values = items.iter().map(|item| &item.value);
+    Some(Self {
+      value: quote! { [#(#values),*].into_iter().collect() },
+      rendered: items.map(|item| item.rendered).join(","),
+    })
+  }
+
+  /// Strings carry characters the client must percent-encode, so the tests cover encoding.
+  fn scalar(base_type: &RustPrimitive, name: &str, index: usize) -> Option<Self> {
+    let sample = match base_type {
+      RustPrimitive::String => {
+        let text = format!("{name}/{index} ?#%é");
+        Self {
+          value: quote! { #text.to_string() },
+          rendered: text,
+        }
+      }
+      primitive if primitive.is_numeric() && !matches!(primitive, RustPrimitive::F32 | RustPrimitive::F64) => Self {
+        value: Literal::usize_unsuffixed(index + 10).to_token_stream(),
+        rendered: (index + 10).to_string(),
+      },
+      RustPrimitive::F32 | RustPrimitive::F64 => {
+        let rendered = format!("{index}.5");
+        Self {
+          value: rendered.parse().ok()?,
+          rendered,
+        }
+      }
+      RustPrimitive::Bool => Self {
+        value: quote! { true },
+        rendered: "true".to_string(),
+      },
+      RustPrimitive::Uuid => Self {
+        value: quote! { uuid::Uuid::nil() },
+        rendered: "00000000-0000-0000-0000-000000000000".to_string(),
+      },
+      RustPrimitive::Date => Self {
+        value: quote! { chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap() },
+        rendered: "2024-02-29".to_string(),
+      },
+      _ => return None,
+    };
+    Some(sample)
+  }
+}
+
+/// Generates a `route_tests` module checking the URL each operation's `{operation}_url`
+/// helper builds from sample path parameters, including their percent-encoding.
+///
+/// Expected paths are rendered at generation time, so a change to how the client builds
+/// URLs fails these tests instead of silently changing the requests it sends.
+#[derive(Clone, Debug)]
+pub(crate) struct RouteTestsFragment {
+  tests: Vec<TokenStream>,
+}
+
+impl RouteTestsFragment {
+  pub(crate) fn new(client: &impl ToTokens, operations: &[OperationInfo]) -> Self {
+    let tests = operations
+      .iter()
+      .filter(|op| op.kind == OperationKind::Http)
+      .filter_map(|op| route_test(client, op))
+      .collect();
+    Self { tests }
+  }
+}
+
+impl ToTokens for RouteTestsFragment {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if self.tests.is_empty() {
+      return;
+    }
+    let tests = &self.tests;
+    quote! {
+      #[cfg(test)]
+      mod route_tests {
+        use super::*;
+
+        #(#tests)*
+      }
+    }
+    .to_tokens(tokens);
+  }
+}
+
+/// Generates the test for one operation, or `None` when a path parameter has no sample.
+fn route_test(client: &impl ToTokens, op: &OperationInfo) -> Option<TokenStream> {
+  let request = format_ident!("{}", op.request_type.as_ref()?);
+  let samples = op
+    .parameters
+    .iter()
+    .filter(|param| param.parameter_location == Some(ParameterLocation::Path))
+    .enumerate()
+    .map(|(index, param)| Some((&param.name, Sample::for_field(param, index)?)))
+    .collect::<Option<Vec<_>>>()?;
+  let rendered = |field: &FieldNameToken| {
+    samples
+      .iter()
+      .find(|(name, _)| *name == field)
+      .map(|(_, sample)| sample.rendered.as_str())
+  };
+
+  let mut expected_path = TEST_BASE_PATH.to_string();
+  for segment in &op.path.segments {
+    let text = match segment {
+      PathSegment::Literal(lit) => lit.clone(),
+      PathSegment::Param(field)
+      | PathSegment::Serialized {
+        field,
+        object_style: None,
+      } => rendered(field)?.to_string(),
+      PathSegment::Serialized { .. } => return None,
+      PathSegment::Mixed { format, params } => {
+        let mut text = format.clone();
+        for param in params {
+          let pos = text.find("{}")?;
+          text.replace_range(pos..pos + 2, rendered(param)?);
+        }
+        text
+      }
+    };
+    // `url` drops dot segments instead of pushing them.
+    if matches!(text.as_str(), "." | "..") {
+      return None;
+    }
+    expected_path.push('/');
+    expected_path.extend(utf8_percent_encode(&text, PATH_SEGMENT));
+  }
+  let expected_query = match &op.path.query_string {
+    Some(query) => {
+      let query = utf8_percent_encode(query, QUERY).to_string();
+      quote! { Some(#query) }
+    }
+    None => quote! { None },
+  };
+
+  let request_value = if samples.is_empty() {
+    quote! { #request::default() }
+  } else {
+    let path_field = format_ident!("{PATH_PARAMS_FIELD}");
+    let path_struct = format_ident!("{request}{PATH_PARAMS_SUFFIX}");
+    let fields = samples.iter().map(|(name, sample)| {
+      let value = &sample.value;
+      quote! { #name: #value }
+    });
+    let has_other_fields = op.body.is_some()
+      || op.parameters.iter().any(|param| {
+        matches!(
+          param.parameter_location,
+          Some(ParameterLocation::Query | ParameterLocation::Header)
+        )
+      });
+    let rest = has_other_fields.then(|| quote! { ..Default::default() });
+    quote! {
+      #request {
+        #path_field: #path_struct { #(#fields),* },
+        #rest
+      }
+    }
+  };
+
+  let test_name = format_ident!("{}", op.stable_id);
+  let helper = format_ident!("{}_url", op.stable_id);
+  Some(quote! {
+    #[test]
+    fn #test_name() {
+      let client = #client::with_base_url(#TEST_BASE_URL).unwrap();
+      let request = #request_value;
+      let url = client.#helper(&request).unwrap();
+      assert_eq!(url.path(), #expected_path);
+      assert_eq!(url.query(), #expected_query);
+    }
+  })
+}
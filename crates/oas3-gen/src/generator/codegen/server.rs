@@ -5,77 +5,130 @@ use quote::{ToTokens, format_ident, quote};
 
 use super::{Visibility, enums::ResponseEnumFragment};
 use crate::generator::{
-  ast::{ContentCategory, HandlerBodyInfo, ResponseEnumDef, ResponseVariant, ServerRequestTraitDef, ServerTraitMethod},
+  ast::{
+    ContentCategory, HandlerBodyInfo, ResponseEnumDef, ResponseMediaType, ResponseVariant, ServerRequestTraitDef,
+    ServerTraitMethod, TraitToken,
+  },
   codegen::http::HttpStatusCode,
 };
 
 pub struct ServerGenerator {
   server_trait: Option<ServerRequestTraitDef>,
+  webhook_trait: Option<ServerRequestTraitDef>,
   visibility: Visibility,
-  with_types_import: bool,
+  types_modules: Vec<String>,
+  with_inline_types: bool,
+  validate_requests: bool,
 }
 
 impl ServerGenerator {
   pub fn new(server_trait: Option<ServerRequestTraitDef>, visibility: Visibility) -> Self {
     Self {
       server_trait,
+      webhook_trait: None,
       visibility,
-      with_types_import: false,
+      types_modules: vec![],
+      with_inline_types: false,
+      validate_requests: false,
     }
   }
 
   pub fn with_types_import(mut self) -> Self {
-    self.with_types_import = true;
+    self.types_modules = vec!["types".to_string()];
+    self
+  }
+
+  /// Imports types from the named sibling modules instead of just `types`.
+  pub fn with_types_modules(mut self, modules: Vec<String>) -> Self {
+    self.types_modules = modules;
+    self
+  }
+
+  /// Emits the server into the same file as the types, which already import `IntoResponse`.
+  pub fn with_inline_types(mut self) -> Self {
+    self.with_inline_types = true;
+    self
+  }
+
+  /// Emits the `WebhookHandler` trait with its handlers and a `webhook_router`.
+  pub fn with_webhook_trait(mut self, webhook_trait: Option<ServerRequestTraitDef>) -> Self {
+    self.webhook_trait = webhook_trait;
+    self
+  }
+
+  /// Makes handlers validate each request before calling the server trait.
+  pub fn with_request_validation(mut self) -> Self {
+    self.validate_requests = true;
     self
   }
 }
 
 impl ToTokens for ServerGenerator {
   fn to_tokens(&self, tokens: &mut TokenStream) {
-    let types_import = self.with_types_import.then(|| quote! { use super::types::*; });
+    let types_modules = self.types_modules.iter().map(|module| format_ident!("{module}"));
+    let types_import = quote! { #(use super::#types_modules::*;)* };
 
-    let Some(def) = self.server_trait.as_ref() else {
+    if self.server_trait.is_none() && self.webhook_trait.is_none() {
       return;
-    };
-
-    let trait_fragment = ServerTraitFragment::new(def.clone(), self.visibility);
+    }
 
-    let handlers = def
-      .methods
-      .iter()
-      .map(|m| HandlerFunctionFragment::new(m.clone(), self.visibility))
-      .collect::<Vec<_>>();
+    let server = self.server_trait.as_ref().map(|def| self.receiver(def, "router"));
+    let webhooks = self
+      .webhook_trait
+      .as_ref()
+      .map(|def| self.receiver(def, "webhook_router"));
 
-    let router = RouterFragment::new(def.methods.clone(), self.visibility);
+    let into_response_import = (!self.with_inline_types).then(|| quote! { response::IntoResponse, });
 
     tokens.extend(quote! {
       use axum::{
         Router,
         extract::{Path, Query, State},
         http::HeaderMap,
-        response::IntoResponse,
+        #into_response_import
         routing::{delete, get, head, options, patch, post, put, trace},
       };
 
       #types_import
 
+      #server
+      #webhooks
+    });
+  }
+}
+
+impl ServerGenerator {
+  /// Emits the trait `def`, one handler per method, and a router named `router_name`
+  /// dispatching each path to its handler.
+  fn receiver(&self, def: &ServerRequestTraitDef, router_name: &str) -> TokenStream {
+    let trait_fragment = ServerTraitFragment::new(def.clone(), self.visibility);
+
+    let handlers = def
+      .methods
+      .iter()
+      .map(|m| HandlerFunctionFragment::new(m.clone(), &def.name, self.visibility, self.validate_requests))
+      .collect::<Vec<_>>();
+
+    let router = RouterFragment::new(def.methods.clone(), &def.name, router_name, self.visibility);
+
+    quote! {
       #trait_fragment
 
       #(#handlers)*
 
       #router
-    });
+    }
   }
 }
 
 #[derive(Clone, Debug)]
-struct ServerTraitFragment {
+pub(crate) struct ServerTraitFragment {
   def: ServerRequestTraitDef,
   vis: Visibility,
 }
 
 impl ServerTraitFragment {
-  fn new(def: ServerRequestTraitDef, vis: Visibility) -> Self {
+  pub(crate) fn new(def: ServerRequestTraitDef, vis: Visibility) -> Self {
     Self { def, vis }
   }
 }
@@ -84,9 +137,11 @@ impl ToTokens for ServerTraitFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let vis = self.vis.to_tokens();
     let name = &self.def.name;
+    let docs = &self.def.docs;
     let methods = self.def.methods.iter().cloned().map(ServerTraitMethodFragment);
 
     tokens.extend(quote! {
+      #docs
       #vis trait #name: Send + Sync {
         #(#methods)*
       }
@@ -163,7 +218,28 @@ impl ToTokens for AxumIntoResponseVariant {
     let variant = &self.0.variant_name;
     let status_code = HttpStatusCode::new(self.0.status_code);
 
-    let ts = if self.0.schema_type.is_some() {
+    let ts = if self.0.retry_after {
+      let retry_after = quote! {
+        retry_after.map(|delay| [(http::header::RETRY_AFTER, delay.as_secs().to_string())])
+      };
+      if self.0.schema_type.is_some() {
+        quote! {
+          Self::#variant { retry_after, body } => (#status_code, #retry_after, axum::Json(body)).into_response()
+        }
+      } else {
+        quote! {
+          Self::#variant { retry_after } => (#status_code, #retry_after, ()).into_response()
+        }
+      }
+    } else if self.0.captures_status() {
+      quote! {
+        Self::#variant { status, body } => (status, axum::Json(body)).into_response()
+      }
+    } else if ResponseMediaType::has_event_stream(&self.0.media_types) {
+      quote! {
+        Self::#variant(response) => (#status_code, response).into_response()
+      }
+    } else if self.0.schema_type.is_some() {
       quote! {
         Self::#variant(data) => (#status_code, axum::Json(data)).into_response()
       }
@@ -206,12 +282,19 @@ impl ToTokens for AxumResponseEnumFragment {
 #[derive(Clone, Debug)]
 struct HandlerFunctionFragment {
   method: ServerTraitMethod,
+  trait_name: TraitToken,
   vis: Visibility,
+  validate_request: bool,
 }
 
 impl HandlerFunctionFragment {
-  fn new(method: ServerTraitMethod, vis: Visibility) -> Self {
-    Self { method, vis }
+  fn new(method: ServerTraitMethod, trait_name: &TraitToken, vis: Visibility, validate_request: bool) -> Self {
+    Self {
+      method,
+      trait_name: trait_name.clone(),
+      vis,
+      validate_request,
+    }
   }
 }
 
@@ -219,7 +302,7 @@ impl ToTokens for HandlerFunctionFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let vis = self.vis.to_tokens();
     let fn_name = &self.method.name;
-    let trait_name = format_ident!("ApiServer");
+    let trait_name = &self.trait_name;
 
     let extractors = ExtractorsFragment::new(self.method.clone());
     let request_construction = RequestConstructionFragment::new(self.method.clone());
@@ -230,6 +313,20 @@ impl ToTokens for HandlerFunctionFragment {
       .as_ref()
       .map_or_else(|| quote! { impl IntoResponse }, |resp| quote! { #resp });
 
+    let validation = (self.validate_request && self.method.request_type.is_some()).then(|| {
+      quote! {
+        if let Err(errors) = validator::Validate::validate(&request) {
+          let problem = oas3_gen_support::ProblemDetails::from_validation_errors(&errors);
+          return (
+            problem.status_code(),
+            [(axum::http::header::CONTENT_TYPE, oas3_gen_support::PROBLEM_JSON)],
+            axum::Json(problem),
+          )
+            .into_response();
+        }
+      }
+    });
+
     let service_call = if self.method.request_type.is_some() {
       quote! { service.#fn_name(request).await }
     } else {
@@ -254,6 +351,7 @@ impl ToTokens for HandlerFunctionFragment {
         S: #trait_name + Clone + Send + Sync + 'static,
       {
         #request_construction
+        #validation
         let result: anyhow::Result<#return_type> = #service_call;
         #error_handling
       }
@@ -335,6 +433,7 @@ impl ToTokens for BodyExtractorFragment {
           quote! { body: String }
         }
       }
+      ContentCategory::Yaml => quote! { body: axum::body::Bytes },
       ContentCategory::Binary => {
         if self.body_info.optional {
           quote! { body: Option<axum::body::Bytes> }
@@ -381,6 +480,13 @@ impl ToTokens for RequestConstructionFragment {
       });
     }
 
+    let yaml_decoding = self
+      .method
+      .body_info
+      .as_ref()
+      .filter(|body_info| body_info.content_category == ContentCategory::Yaml)
+      .map(yaml_body_decoding);
+
     if let Some(body_info) = &self.method.body_info {
       let needs_unwrap = matches!(
         body_info.content_category,
@@ -395,6 +501,7 @@ impl ToTokens for RequestConstructionFragment {
     }
 
     tokens.extend(quote! {
+      #yaml_decoding
       let request = #request_type {
         #(#field_assignments),*
       };
@@ -402,15 +509,45 @@ impl ToTokens for RequestConstructionFragment {
   }
 }
 
+/// Parses a YAML request body read as bytes, answering `400 Bad Request` when it does not
+/// match its schema. An empty optional body becomes `None`.
+fn yaml_body_decoding(body_info: &HandlerBodyInfo) -> TokenStream {
+  let body_type = &body_info.body_type;
+  let decode = |on_success: TokenStream| {
+    quote! {
+      match oas3_gen_support::from_yaml::<#body_type>(&body) {
+        Ok(body) => #on_success,
+        Err(e) => {
+          return (axum::http::StatusCode::BAD_REQUEST, format!("Invalid YAML body: {e}")).into_response();
+        }
+      }
+    }
+  };
+  if body_info.optional {
+    let decode = decode(quote! { Some(body) });
+    quote! { let body = if body.is_empty() { None } else { #decode }; }
+  } else {
+    let decode = decode(quote! { body });
+    quote! { let body = #decode; }
+  }
+}
+
 #[derive(Clone, Debug)]
 struct RouterFragment {
   methods: Vec<ServerTraitMethod>,
+  trait_name: TraitToken,
+  fn_name: syn::Ident,
   vis: Visibility,
 }
 
 impl RouterFragment {
-  fn new(methods: Vec<ServerTraitMethod>, vis: Visibility) -> Self {
-    Self { methods, vis }
+  fn new(methods: Vec<ServerTraitMethod>, trait_name: &TraitToken, fn_name: &str, vis: Visibility) -> Self {
+    Self {
+      methods,
+      trait_name: trait_name.clone(),
+      fn_name: format_ident!("{fn_name}"),
+      vis,
+    }
   }
 }
 
@@ -437,10 +574,13 @@ impl ToTokens for RouterFragment {
       quote! { .route(#path, #chained) }
     });
 
+    let fn_name = &self.fn_name;
+    let trait_name = &self.trait_name;
+
     tokens.extend(quote! {
-      #vis fn router<S>(service: S) -> Router
+      #vis fn #fn_name<S>(service: S) -> Router
       where
-        S: ApiServer + Clone + Send + Sync + 'static,
+        S: #trait_name + Clone + Send + Sync + 'static,
       {
         Router::new()
           #(#route_definitions)*
@@ -14,12 +14,16 @@ use super::{
 use crate::generator::{
   ast::{
     BuilderField, BuilderNestedStruct, ContentCategory, DerivesProvider, Documentation, FieldDef, MethodKind,
-    MethodNameToken, RegexKey, ResponseStatusCategory, ResponseVariantCategory, RustPrimitive, StatusCodeToken,
-    StatusHandler, StructDef, StructKind, StructMethod, TypeRef, ValidationAttribute,
+    MethodNameToken, RegexKey, ResponseMediaType, ResponseStatusCategory, ResponseVariantCategory, RustPrimitive,
+    StatusCodeToken, StatusHandler, StructDef, StructKind, StructMethod, TypeRef, ValidationAttribute,
+    constants::MediaTypeRef,
     tokens::{ConstToken, EnumToken, EnumVariantToken},
   },
   codegen::{
     attributes::generate_derives_from_slice,
+    constants::exclusion_validator_name,
+    conversions::generate_parent_conversions,
+    error_impls::generate_error_impl,
     headers::{HeaderFromMapFragment, HeaderMapFragment},
     http::HttpStatusCode,
   },
@@ -55,12 +59,18 @@ impl ToTokens for StructFragment {
     let definition = StructDefinitionFragment::new(self.def.clone(), self.regex_lookup.clone(), self.visibility);
     let impl_block = StructImplBlockFragment::new(self.def.clone(), self.visibility);
     let header_map = HeaderMapFragment::new(self.def.clone());
+    let error_impl = generate_error_impl(&self.def);
+    let parent_conversions = generate_parent_conversions(&self.def);
 
     tokens.extend(quote! {
       #definition
 
       #impl_block
 
+      #error_impl
+
+      #parent_conversions
+
       #header_map
 
     });
@@ -157,6 +167,10 @@ impl StructFieldFragment {
             |const_token| ValidationAttribute::Regex(const_token.to_string()),
           )
         }
+        ValidationAttribute::Excludes(_) => ValidationAttribute::Custom(exclusion_validator_name(
+          &self.struct_def.name,
+          self.field.name.as_str(),
+        )),
         _ => attr.clone(),
       })
       .collect();
@@ -274,10 +288,12 @@ impl ToTokens for StructMethodFragment {
         response_enum,
         status_handlers,
         default_handler,
+        malformed_variant,
       } => ParseResponseMethodFragment::new(
         response_enum.clone(),
         status_handlers.clone(),
         default_handler.clone(),
+        malformed_variant.clone(),
         self.visibility,
         self.method.name.clone(),
         self.method.docs.clone(),
@@ -302,6 +318,7 @@ pub(crate) struct ParseResponseMethodFragment {
   response_enum: EnumToken,
   status_handlers: Vec<StatusHandler>,
   default_handler: Option<ResponseVariantCategory>,
+  malformed_variant: Option<EnumVariantToken>,
   visibility: Visibility,
   method_name: MethodNameToken,
   docs: Documentation,
@@ -312,6 +329,7 @@ impl ParseResponseMethodFragment {
     response_enum: EnumToken,
     status_handlers: Vec<StatusHandler>,
     default_handler: Option<ResponseVariantCategory>,
+    malformed_variant: Option<EnumVariantToken>,
     visibility: Visibility,
     method_name: MethodNameToken,
     docs: Documentation,
@@ -320,6 +338,7 @@ impl ParseResponseMethodFragment {
       response_enum,
       status_handlers,
       default_handler,
+      malformed_variant,
       visibility,
       method_name,
       docs,
@@ -332,10 +351,14 @@ impl ToTokens for ParseResponseMethodFragment {
     let status_checks: Vec<StatusCheckFragment> = self
       .status_handlers
       .iter()
-      .map(|h| StatusCheckFragment::new(h.clone(), self.response_enum.clone()))
+      .map(|h| StatusCheckFragment::new(h.clone(), self.response_enum.clone(), self.malformed_variant.clone()))
       .collect();
 
-    let fallback = FallbackFragment::new(self.response_enum.clone(), self.default_handler.clone());
+    let fallback = FallbackFragment::new(
+      self.response_enum.clone(),
+      self.default_handler.clone(),
+      self.malformed_variant.clone(),
+    );
     let status_decl = if status_checks.is_empty() {
       quote! {}
     } else {
@@ -362,18 +385,31 @@ impl ToTokens for ParseResponseMethodFragment {
 pub(crate) struct StatusCheckFragment {
   handler: StatusHandler,
   response_enum: EnumToken,
+  malformed_variant: Option<EnumVariantToken>,
 }
 
 impl StatusCheckFragment {
-  pub(crate) fn new(handler: StatusHandler, response_enum: EnumToken) -> Self {
-    Self { handler, response_enum }
+  pub(crate) fn new(
+    handler: StatusHandler,
+    response_enum: EnumToken,
+    malformed_variant: Option<EnumVariantToken>,
+  ) -> Self {
+    Self {
+      handler,
+      response_enum,
+      malformed_variant,
+    }
   }
 }
 
 impl ToTokens for StatusCheckFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let cond = StatusConditionFragment::new(self.handler.status_code);
-    let body = ResponseDispatchFragment::new(self.handler.dispatch.clone(), self.response_enum.clone());
+    let body = ResponseDispatchFragment::new(
+      self.handler.dispatch.clone(),
+      self.response_enum.clone(),
+      self.malformed_variant.clone(),
+    );
 
     tokens.extend(quote! {
       if #cond {
@@ -418,13 +454,19 @@ impl ToTokens for StatusConditionFragment {
 pub(crate) struct ResponseDispatchFragment {
   dispatch: ResponseStatusCategory,
   response_enum: EnumToken,
+  malformed_variant: Option<EnumVariantToken>,
 }
 
 impl ResponseDispatchFragment {
-  pub(crate) fn new(dispatch: ResponseStatusCategory, response_enum: EnumToken) -> Self {
+  pub(crate) fn new(
+    dispatch: ResponseStatusCategory,
+    response_enum: EnumToken,
+    malformed_variant: Option<EnumVariantToken>,
+  ) -> Self {
     Self {
       dispatch,
       response_enum,
+      malformed_variant,
     }
   }
 }
@@ -433,11 +475,16 @@ impl ToTokens for ResponseDispatchFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let ts = match &self.dispatch {
       ResponseStatusCategory::Single(case) => {
-        ResponseCaseFragment::new(case.clone(), self.response_enum.clone()).into_token_stream()
-      }
-      ResponseStatusCategory::ContentDispatch { streams, variants } => {
-        ContentDispatchFragment::new(streams.clone(), variants.clone(), self.response_enum.clone()).into_token_stream()
+        ResponseCaseFragment::new(case.clone(), self.response_enum.clone(), self.malformed_variant.clone())
+          .into_token_stream()
       }
+      ResponseStatusCategory::ContentDispatch { streams, variants } => ContentDispatchFragment::new(
+        streams.clone(),
+        variants.clone(),
+        self.response_enum.clone(),
+        self.malformed_variant.clone(),
+      )
+      .into_token_stream(),
     };
 
     tokens.extend(ts);
@@ -449,6 +496,7 @@ pub(crate) struct ContentDispatchFragment {
   event_streams: Vec<ResponseVariantCategory>,
   others: Vec<ResponseVariantCategory>,
   response_enum: EnumToken,
+  malformed_variant: Option<EnumVariantToken>,
 }
 
 impl ContentDispatchFragment {
@@ -456,29 +504,36 @@ impl ContentDispatchFragment {
     event_streams: Vec<ResponseVariantCategory>,
     others: Vec<ResponseVariantCategory>,
     response_enum: EnumToken,
+    malformed_variant: Option<EnumVariantToken>,
   ) -> Self {
     Self {
       event_streams,
       others,
       response_enum,
+      malformed_variant,
     }
   }
+
+  fn case_block(&self, case: &ResponseVariantCategory) -> ResponseCaseFragment {
+    ResponseCaseFragment::new(case.clone(), self.response_enum.clone(), self.malformed_variant.clone())
+  }
 }
 
 impl ToTokens for ContentDispatchFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
+    let default_content_type = MediaTypeRef::json().path();
     let content_type_header = quote! {
       let content_type_str = req.headers()
         .get(reqwest::header::CONTENT_TYPE)
         .and_then(|v| v.to_str().ok())
-        .unwrap_or("application/json");
+        .unwrap_or(#default_content_type);
     };
 
     let stream_checks: Vec<TokenStream> = self
       .event_streams
       .iter()
       .map(|case| {
-        let block = ResponseCaseFragment::new(case.clone(), self.response_enum.clone());
+        let block = self.case_block(case);
         quote! {
           if content_type_str.contains("event-stream") {
             #block
@@ -492,7 +547,7 @@ impl ToTokens for ContentDispatchFragment {
       .iter()
       .map(|case| {
         let check = ContentCheckFragment::new(case.category);
-        let block = ResponseCaseFragment::new(case.clone(), self.response_enum.clone());
+        let block = self.case_block(case);
         quote! {
           if #check {
             #block
@@ -525,6 +580,7 @@ impl ToTokens for ContentCheckFragment {
     let ts = match self.category {
       ContentCategory::Json => quote! { content_type_str.contains("json") },
       ContentCategory::Xml => quote! { content_type_str.contains("xml") },
+      ContentCategory::Yaml => quote! { content_type_str.contains("yaml") },
       ContentCategory::Text => quote! { content_type_str.starts_with("text/") && !content_type_str.contains("xml") },
       ContentCategory::Binary => {
         quote! { content_type_str.starts_with("application/octet-stream") || content_type_str.starts_with("image/") || content_type_str.starts_with("audio/") || content_type_str.starts_with("video/") }
@@ -542,11 +598,20 @@ impl ToTokens for ContentCheckFragment {
 pub(crate) struct ResponseCaseFragment {
   case: ResponseVariantCategory,
   response_enum: EnumToken,
+  malformed_variant: Option<EnumVariantToken>,
 }
 
 impl ResponseCaseFragment {
-  pub(crate) fn new(case: ResponseVariantCategory, response_enum: EnumToken) -> Self {
-    Self { case, response_enum }
+  pub(crate) fn new(
+    case: ResponseVariantCategory,
+    response_enum: EnumToken,
+    malformed_variant: Option<EnumVariantToken>,
+  ) -> Self {
+    Self {
+      case,
+      response_enum,
+      malformed_variant,
+    }
   }
 }
 
@@ -554,13 +619,67 @@ impl ToTokens for ResponseCaseFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let variant_name = &self.case.variant.variant_name;
     let response_enum = &self.response_enum;
+    let links = self
+      .case
+      .variant
+      .page_links
+      .then(|| quote! { let links = oas3_gen_support::PageLinks::from_headers(req.headers()); });
+    let retry_after = self.case.variant.retry_after.then(|| {
+      quote! { let retry_after = oas3_gen_support::RateLimit::with_headers(req.headers()).retry_after(); }
+    });
 
     let ts = match self.case.variant.schema_type.as_ref() {
       Some(ty) => {
         let data = ResponseExtractionFragment::new(ty.clone(), self.case.category);
+        let data = if ResponseMediaType::has_named_events(&self.case.variant.media_types) {
+          data.with_named_events()
+        } else {
+          data
+        };
+        let data = match &self.malformed_variant {
+          Some(variant) => data.with_malformed_variant(response_enum.clone(), variant.clone()),
+          None => data,
+        };
+        if self.case.variant.has_named_fields() {
+          let status = self
+            .case
+            .variant
+            .captures_status()
+            .then(|| quote! { let status = req.status(); });
+          let fields = [
+            self.case.variant.captures_status().then(|| quote! { status }),
+            self.case.variant.page_links.then(|| quote! { links }),
+            self.case.variant.retry_after.then(|| quote! { retry_after }),
+            Some(quote! { body }),
+          ]
+          .into_iter()
+          .flatten();
+          quote! {
+            #status
+            #links
+            #retry_after
+            let body = #data;
+            return Ok(#response_enum::#variant_name { #(#fields),* });
+          }
+        } else {
+          quote! {
+            let data = #data;
+            return Ok(#response_enum::#variant_name(data));
+          }
+        }
+      }
+      None if self.case.variant.page_links => {
+        quote! {
+          #links
+          let _ = req.bytes().await?;
+          return Ok(#response_enum::#variant_name { links });
+        }
+      }
+      None if self.case.variant.retry_after => {
         quote! {
-          let data = #data;
-          return Ok(#response_enum::#variant_name(data));
+          #retry_after
+          let _ = req.bytes().await?;
+          return Ok(#response_enum::#variant_name { retry_after });
         }
       }
       None => {
@@ -579,11 +698,70 @@ impl ToTokens for ResponseCaseFragment {
 pub(crate) struct ResponseExtractionFragment {
   schema_type: TypeRef,
   category: ContentCategory,
+  malformed: Option<(EnumToken, EnumVariantToken)>,
+  named_events: bool,
 }
 
 impl ResponseExtractionFragment {
   pub(crate) fn new(schema_type: TypeRef, category: ContentCategory) -> Self {
-    Self { schema_type, category }
+    Self {
+      schema_type,
+      category,
+      malformed: None,
+      named_events: false,
+    }
+  }
+
+  /// Decodes an event stream by SSE `event:` name rather than as a single payload type.
+  pub(crate) fn with_named_events(mut self) -> Self {
+    self.named_events = true;
+    self
+  }
+
+  /// Captures deserialization failures in the given response enum variant instead of
+  /// propagating them as errors.
+  pub(crate) fn with_malformed_variant(mut self, response_enum: EnumToken, variant: EnumVariantToken) -> Self {
+    self.malformed = Some((response_enum, variant));
+    self
+  }
+
+  fn json(&self) -> TokenStream {
+    let schema_type = &self.schema_type;
+    match &self.malformed {
+      Some((response_enum, variant)) => quote! {
+        match oas3_gen_support::MalformedResponse::json::<#schema_type>(req).await? {
+          Ok(data) => data,
+          Err(malformed) => return Ok(#response_enum::#variant(malformed)),
+        }
+      },
+      None => quote! { oas3_gen_support::Diagnostics::<#schema_type>::json_with_diagnostics(req).await? },
+    }
+  }
+
+  fn xml(&self) -> TokenStream {
+    let schema_type = &self.schema_type;
+    match &self.malformed {
+      Some((response_enum, variant)) => quote! {
+        match oas3_gen_support::MalformedResponse::xml::<#schema_type>(req).await? {
+          Ok(data) => data,
+          Err(malformed) => return Ok(#response_enum::#variant(malformed)),
+        }
+      },
+      None => quote! { oas3_gen_support::Diagnostics::<#schema_type>::xml_with_diagnostics(req).await? },
+    }
+  }
+
+  fn yaml(&self) -> TokenStream {
+    let schema_type = &self.schema_type;
+    match &self.malformed {
+      Some((response_enum, variant)) => quote! {
+        match oas3_gen_support::MalformedResponse::yaml::<#schema_type>(req).await? {
+          Ok(data) => data,
+          Err(malformed) => return Ok(#response_enum::#variant(malformed)),
+        }
+      },
+      None => quote! { oas3_gen_support::Diagnostics::<#schema_type>::yaml_with_diagnostics(req).await? },
+    }
   }
 }
 
@@ -596,7 +774,7 @@ impl ToTokens for ResponseExtractionFragment {
         if self.schema_type.is_string_like() {
           quote! { req.text().await? }
         } else if matches!(self.schema_type.base_type, RustPrimitive::Custom(_)) {
-          quote! { oas3_gen_support::Diagnostics::<#schema_type>::json_with_diagnostics(req).await? }
+          self.json()
         } else {
           quote! { req.text().await?.parse::<#schema_type>()? }
         }
@@ -605,16 +783,18 @@ impl ToTokens for ResponseExtractionFragment {
         if matches!(self.schema_type.base_type, RustPrimitive::Bytes) {
           quote! { req.bytes().await?.to_vec() }
         } else {
-          quote! { oas3_gen_support::Diagnostics::<#schema_type>::json_with_diagnostics(req).await? }
+          self.json()
         }
       }
+      ContentCategory::EventStream if self.named_events => {
+        quote! { <#schema_type>::from_named_events(req) }
+      }
       ContentCategory::EventStream => {
         quote! { <#schema_type>::from_response(req) }
       }
-      ContentCategory::Xml => {
-        quote! { oas3_gen_support::Diagnostics::<#schema_type>::xml_with_diagnostics(req).await? }
-      }
-      _ => quote! { oas3_gen_support::Diagnostics::<#schema_type>::json_with_diagnostics(req).await? },
+      ContentCategory::Xml => self.xml(),
+      ContentCategory::Yaml => self.yaml(),
+      _ => self.json(),
     };
 
     tokens.extend(ts);
@@ -625,13 +805,19 @@ impl ToTokens for ResponseExtractionFragment {
 pub(crate) struct FallbackFragment {
   response_enum: EnumToken,
   default_handler: Option<ResponseVariantCategory>,
+  malformed_variant: Option<EnumVariantToken>,
 }
 
 impl FallbackFragment {
-  pub(crate) fn new(response_enum: EnumToken, default_handler: Option<ResponseVariantCategory>) -> Self {
+  pub(crate) fn new(
+    response_enum: EnumToken,
+    default_handler: Option<ResponseVariantCategory>,
+    malformed_variant: Option<EnumVariantToken>,
+  ) -> Self {
     Self {
       response_enum,
       default_handler,
+      malformed_variant,
     }
   }
 }
@@ -639,7 +825,8 @@ impl FallbackFragment {
 impl ToTokens for FallbackFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let ts = if let Some(case) = &self.default_handler {
-      ResponseCaseFragment::new(case.clone(), self.response_enum.clone()).into_token_stream()
+      ResponseCaseFragment::new(case.clone(), self.response_enum.clone(), self.malformed_variant.clone())
+        .into_token_stream()
     } else {
       let response_enum = &self.response_enum;
       let unknown_variant = EnumVariantToken::from("Unknown");
@@ -675,6 +862,19 @@ impl BuilderMethodFragment {
       docs,
     }
   }
+
+  /// Reads a field's spec default from the `Default` impl of the parameter struct
+  /// holding it, so the builder and `Default` always agree.
+  fn owner_default(&self, field: &BuilderField) -> Option<TokenStream> {
+    if !field.has_default {
+      return None;
+    }
+    let owner = field.owner_field.as_ref()?;
+    let nested = self.nested_structs.iter().find(|nested| nested.field_name == *owner)?;
+    let struct_name = &nested.struct_name;
+    let name = &field.name;
+    Some(quote! { #struct_name::default().#name })
+  }
 }
 
 impl ToTokens for BuilderMethodFragment {
@@ -682,12 +882,27 @@ impl ToTokens for BuilderMethodFragment {
     let params: Vec<TokenStream> = self
       .fields
       .iter()
+      .filter(|f| !f.skipped)
       .map(|f| {
         let name = &f.name;
         let ty = &f.rust_type;
-        quote! { #name: #ty }
+        let default_attr = (!f.rust_type.nullable)
+          .then(|| self.owner_default(f))
+          .flatten()
+          .map(|default| quote! { #[builder(default = #default)] });
+        quote! { #default_attr #name: #ty }
       })
       .collect();
+    let optional_defaults = self
+      .fields
+      .iter()
+      .filter(|f| f.rust_type.nullable && !f.skipped)
+      .filter_map(|f| {
+        let name = &f.name;
+        let default = self.owner_default(f)?;
+        Some(quote! { let #name = #name.or(#default); })
+      })
+      .collect::<Vec<_>>();
 
     let construction = BuilderConstructionFragment::new(self.fields.clone(), self.nested_structs.clone());
     let vis = &self.visibility;
@@ -697,6 +912,7 @@ impl ToTokens for BuilderMethodFragment {
       #docs
       #[builder]
       #vis fn new(#(#params),*) -> anyhow::Result<Self> {
+        #(#optional_defaults)*
         let request = #construction;
         request.validate()?;
         Ok(request)
@@ -748,6 +964,10 @@ impl ToTokens for BuilderConstructionFragment {
             });
           }
         }
+        None if field.skipped => {
+          let name = &field.name;
+          assignments.push(quote! { #name: Default::default() });
+        }
         None => {
           assignments.push(field.name.to_token_stream());
         }
@@ -0,0 +1,165 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  rc::Rc,
+};
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use crate::generator::{
+  ast::{
+    ServerRequestTraitDef,
+    constants::{HttpHeaderRef, MEDIA_TYPES_MODULE},
+  },
+  codegen::{Visibility, constants::MediaTypeConstantsFragment, types::TypesFragment},
+  converter::GenerationTarget,
+  postprocess::{TagModules, TypeModule},
+};
+
+/// Module holding the types shared between tags, along with the header and media type
+/// constants.
+pub(crate) const SHARED_MODULE: &str = "types";
+
+/// Type fragments for output split into one module per OpenAPI tag.
+///
+/// Sibling modules are glob-imported only where their items are referenced, so the
+/// generated modules compile without unused import warnings.
+#[derive(Clone, Debug)]
+pub(crate) struct TagModulesFragment {
+  modules: BTreeMap<String, TypesFragment>,
+  exports: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl TagModulesFragment {
+  pub(crate) fn new(
+    tag_modules: &TagModules,
+    header_refs: Rc<Vec<HttpHeaderRef>>,
+    visibility: Visibility,
+    target: GenerationTarget,
+  ) -> Self {
+    let module = |module: &TypeModule, header_refs: Rc<Vec<HttpHeaderRef>>| {
+      TypesFragment::new(
+        Rc::new(module.types.clone()),
+        header_refs,
+        module.uses.clone(),
+        visibility,
+        target,
+      )
+    };
+    let exported_types = |module: &TypeModule| {
+      module
+        .types
+        .iter()
+        .map(|rust_type| rust_type.type_name().to_string())
+        .collect::<BTreeSet<_>>()
+    };
+
+    let mut shared_exports = exported_types(&tag_modules.shared);
+    shared_exports.extend(header_refs.iter().map(|header| header.const_token.to_string()));
+
+    let mut modules = BTreeMap::from([(SHARED_MODULE.to_string(), module(&tag_modules.shared, header_refs))]);
+    let mut exports = BTreeMap::from([(SHARED_MODULE.to_string(), shared_exports)]);
+    for (name, tagged) in &tag_modules.tagged {
+      modules.insert(name.clone(), module(tagged, Rc::default()));
+      exports.insert(name.clone(), exported_types(tagged));
+    }
+
+    Self { modules, exports }
+  }
+
+  /// Emits the `media_types` module in the shared module.
+  pub(crate) fn with_media_types(mut self, media_types: MediaTypeConstantsFragment) -> Self {
+    if media_types.is_empty() {
+      return self;
+    }
+    if let Some(shared) = self.modules.remove(SHARED_MODULE) {
+      self
+        .modules
+        .insert(SHARED_MODULE.to_string(), shared.with_media_types(media_types));
+    }
+    if let Some(exports) = self.exports.get_mut(SHARED_MODULE) {
+      exports.insert(MEDIA_TYPES_MODULE.to_string());
+    }
+    self
+  }
+
+  /// Appends the `<Operation>Callbacks` traits to the shared module.
+  pub(crate) fn with_callback_traits(mut self, callback_traits: Rc<Vec<ServerRequestTraitDef>>) -> Self {
+    if let Some(shared) = self.modules.remove(SHARED_MODULE) {
+      self
+        .modules
+        .insert(SHARED_MODULE.to_string(), shared.with_callback_traits(callback_traits));
+    }
+    self
+  }
+
+  /// Returns the modules other than `own` whose items `tokens` reference.
+  pub(crate) fn imports_for(&self, tokens: &TokenStream, own: Option<&str>) -> Vec<String> {
+    let mut idents = BTreeSet::new();
+    collect_idents(tokens.clone(), &mut idents);
+
+    self
+      .exports
+      .iter()
+      .filter(|(name, _)| Some(name.as_str()) != own)
+      .filter(|(_, items)| !items.is_disjoint(&idents))
+      .map(|(name, _)| name.clone())
+      .collect()
+  }
+
+  /// Returns every module, shared module included, with its sibling imports.
+  pub(crate) fn modules(&self) -> impl Iterator<Item = (&str, TypesFragment)> {
+    self.modules.iter().map(|(name, fragment)| {
+      let imports = self.imports_for(&fragment.to_token_stream(), Some(name));
+      (name.as_str(), fragment.clone().with_module_imports(imports))
+    })
+  }
+}
+
+/// Collects the identifiers in `tokens` that may name an item of a sibling module.
+///
+/// Path segments after `::` and the variant names of enum definitions are skipped,
+/// so a variant sharing its name with a type does not count as a reference.
+fn collect_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+  let mut joint_colon = false;
+  let mut after_path_separator = false;
+  let mut enum_body_pending = false;
+  for tree in tokens {
+    match tree {
+      TokenTree::Ident(ident) => {
+        let name = ident.to_string();
+        enum_body_pending |= name == "enum";
+        if !after_path_separator {
+          idents.insert(name);
+        }
+        after_path_separator = false;
+      }
+      TokenTree::Punct(punct) => {
+        after_path_separator = joint_colon && punct.as_char() == ':';
+        joint_colon = punct.as_char() == ':' && punct.spacing() == Spacing::Joint;
+        continue;
+      }
+      TokenTree::Group(group) if enum_body_pending && group.delimiter() == Delimiter::Brace => {
+        collect_variant_idents(group.stream(), idents);
+        enum_body_pending = false;
+      }
+      TokenTree::Group(group) => collect_idents(group.stream(), idents),
+      TokenTree::Literal(_) => {}
+    }
+    joint_colon = false;
+  }
+}
+
+/// Collects identifiers from an enum body, skipping each variant's own name.
+fn collect_variant_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+  let mut variant_name_pending = true;
+  let mut payload = TokenStream::new();
+  for tree in tokens {
+    match &tree {
+      TokenTree::Ident(_) if variant_name_pending => variant_name_pending = false,
+      TokenTree::Punct(punct) if punct.as_char() == ',' => variant_name_pending = true,
+      _ => payload.extend([tree]),
+    }
+  }
+  collect_idents(payload, idents);
+}
@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Placeholders filled in a client struct template.
+pub const CLIENT_STRUCT_PLACEHOLDERS: &[&str] = &["default", "vis", "name", "fields"];
+
+/// Placeholders filled in a client method template.
+pub const CLIENT_METHOD_PLACEHOLDERS: &[&str] = &[
+  "default",
+  "docs",
+  "vis",
+  "name",
+  "params",
+  "result",
+  "body",
+  "operation_id",
+  "http_method",
+];
+
+/// Placeholders filled in a client error handling template.
+pub const CLIENT_ERROR_PLACEHOLDERS: &[&str] = &["body", "result", "error", "operation_id"];
+
+/// User-supplied Rust source replacing parts of the generated client.
+///
+/// Each template is Rust source in which `{{placeholder}}`s are replaced by the code
+/// the generator would have written there. Unset templates keep the built-in output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientTemplates {
+  /// Replaces the client struct definition.
+  pub client_struct: Option<String>,
+  /// Replaces each operation method.
+  pub method: Option<String>,
+  /// Replaces the body of each operation method, which must evaluate to `{{result}}`.
+  pub error_handling: Option<String>,
+}
+
+impl ClientTemplates {
+  /// Checks that each template only uses its own placeholders and lexes as Rust.
+  pub fn validate(&self) -> anyhow::Result<()> {
+    let templates = [
+      ("client-struct", &self.client_struct, CLIENT_STRUCT_PLACEHOLDERS),
+      ("client-method", &self.method, CLIENT_METHOD_PLACEHOLDERS),
+      ("client-error", &self.error_handling, CLIENT_ERROR_PLACEHOLDERS),
+    ];
+    for (kind, template, placeholders) in templates {
+      let Some(template) = template else {
+        continue;
+      };
+      if let Some(unknown) = placeholder_names(template).find(|name| !placeholders.contains(name)) {
+        anyhow::bail!(
+          "Unknown placeholder '{{{{{unknown}}}}}' in {kind} template: expected one of {}",
+          placeholders.join(", ")
+        );
+      }
+      let values = placeholders
+        .iter()
+        .map(|name| (*name, quote! { x }))
+        .collect::<Vec<_>>();
+      substitute(template, &values).map_err(|e| anyhow::anyhow!("Invalid {kind} template: {e}"))?;
+    }
+    Ok(())
+  }
+}
+
+/// Fills `template`'s placeholders with `values` and parses the result, emitting a
+/// `compile_error!` in place of source that does not lex.
+pub(crate) fn render(template: &str, values: &[(&str, TokenStream)]) -> TokenStream {
+  substitute(template, values).unwrap_or_else(|e| {
+    let message = format!("invalid client template: {e}");
+    quote! { compile_error!(#message); }
+  })
+}
+
+fn substitute(template: &str, values: &[(&str, TokenStream)]) -> Result<TokenStream, proc_macro2::LexError> {
+  let source = values.iter().fold(template.to_string(), |source, (name, value)| {
+    source.replace(&format!("{{{{{name}}}}}"), &value.to_string())
+  });
+  TokenStream::from_str(&source)
+}
+
+fn placeholder_names(template: &str) -> impl Iterator<Item = &str> {
+  template.split("{{").skip(1).filter_map(|rest| {
+    let (name, _) = rest.split_once("}}")?;
+    (!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')).then_some(name)
+  })
+}
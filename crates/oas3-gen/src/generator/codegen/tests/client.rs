@@ -1,16 +1,22 @@
+use std::collections::BTreeMap;
+
 use http::Method;
 use quote::ToTokens;
 use reqwest::Url;
 
 use crate::generator::{
   ast::{
-    ClientRootNode, ContentCategory, Documentation, EnumToken, FieldDef, FieldNameToken, MultipartFieldInfo,
-    OperationBody, OperationInfo, OperationKind, ParameterLocation, ParsedPath, PathSegment, ResponseMediaType,
-    StructToken, TypeRef,
+    ApiKeyLocation, BodyMediaVariant, ClientRootNode, ContentCategory, DefaultAtom, Documentation, EnumToken,
+    EnumVariantToken, ErrorResponseType, ExternalDocs, FieldDef, FieldNameToken, MultipartFieldInfo, NextPage,
+    OperationBody, OperationInfo, OperationKind, PageCursor, Pagination, ParameterLocation, ParsedPath, PathSegment,
+    ResponseMediaType, SecuritySchemeDef, SecuritySchemeKind, StructToken, TypeRef,
   },
   codegen::{
     Visibility,
-    client::{ClientFragment, ClientMethodFragment, MultipartFormFragment},
+    client::{
+      ClientFragment, ClientMethodFragment, MultipartFormFragment, OperationTableFragment, RequestBodyFragment,
+    },
+    templates::ClientTemplates,
   },
 };
 
@@ -191,6 +197,7 @@ fn test_response_handling_content_categories() {
       ContentCategory::Xml => "application/xml",
       ContentCategory::FormUrlEncoded => "application/x-www-form-urlencoded",
       ContentCategory::Multipart => "multipart/form-data",
+      ContentCategory::Yaml => "application/yaml",
     };
     let operation = TestOperation {
       response_media_types: Some(vec![ResponseMediaType::new(content_type)]),
@@ -240,6 +247,471 @@ fn test_response_handling_with_response_enum() {
   );
 }
 
+#[test]
+fn test_raw_body_retention_wraps_buffered_responses() {
+  struct Case {
+    label: &'static str,
+    content_type: &'static str,
+    response_enum: Option<&'static str>,
+    expected_return_ty: &'static str,
+    expect_capture: bool,
+  }
+
+  let cases = [
+    Case {
+      label: "json",
+      content_type: "application/json",
+      response_enum: None,
+      expected_return_ty: "oas3_gen_support :: WithRawBody < TestResponse >",
+      expect_capture: true,
+    },
+    Case {
+      label: "text",
+      content_type: "text/plain",
+      response_enum: None,
+      expected_return_ty: "oas3_gen_support :: WithRawBody < String >",
+      expect_capture: true,
+    },
+    Case {
+      label: "response enum",
+      content_type: "application/json",
+      response_enum: Some("TestResponseEnum"),
+      expected_return_ty: "oas3_gen_support :: WithRawBody < TestResponseEnum >",
+      expect_capture: true,
+    },
+    Case {
+      label: "event stream",
+      content_type: "text/event-stream",
+      response_enum: None,
+      expected_return_ty: "oas3_gen_support :: EventStream < TestResponse >",
+      expect_capture: false,
+    },
+    Case {
+      label: "binary",
+      content_type: "application/octet-stream",
+      response_enum: None,
+      expected_return_ty: "reqwest :: Response",
+      expect_capture: false,
+    },
+  ];
+
+  for case in cases {
+    let operation = TestOperation {
+      response_media_types: Some(vec![ResponseMediaType::new(case.content_type)]),
+      response_enum: case.response_enum.map(str::to_string),
+      ..Default::default()
+    }
+    .build();
+    let method = ClientMethodFragment::new(operation, Visibility::Public)
+      .with_raw_body()
+      .generate()
+      .unwrap()
+      .to_string();
+
+    let expected_return = format!("-> anyhow :: Result < {} >", case.expected_return_ty);
+    assert!(
+      method.contains(&expected_return),
+      "{}: return type mismatch. Got code: {method}",
+      case.label
+    );
+    assert_eq!(
+      method.contains("WithRawBody :: capture (response"),
+      case.expect_capture,
+      "{}: unexpected capture behavior. Got code: {method}",
+      case.label
+    );
+  }
+}
+
+#[test]
+fn test_vcr_routes_requests_through_cassette() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+
+  let cases = [(false, "default"), (true, "vcr")];
+  for (vcr, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
+    let generator = if vcr { generator.with_vcr() } else { generator };
+    let output = generator.to_token_stream().to_string();
+
+    let expectations = [
+      ("pub vcr : Option < oas3_gen_support :: Vcr >", vcr),
+      ("pub fn with_cassette (base_url : impl AsRef < str >", vcr),
+      ("oas3_gen_support :: Vcr :: from_env (cassette)", vcr),
+      ("self . send_request (req_builder) . await ?", vcr),
+      ("let response = self . client . get (url) . send () . await ?", !vcr),
+    ];
+    for (snippet, expected) in expectations {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_retry_wraps_requests_in_retry_policy() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+
+  let cases = [
+    (false, false, "default"),
+    (true, false, "retry"),
+    (true, true, "retry with vcr"),
+  ];
+  for (retry, vcr, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
+    let generator = if retry { generator.with_retry() } else { generator };
+    let generator = if vcr { generator.with_vcr() } else { generator };
+    let output = generator.to_token_stream().to_string();
+
+    let expectations = [
+      ("pub retry : Option < oas3_gen_support :: RetryPolicy >", retry),
+      ("retry : None ,", retry),
+      (
+        "pub fn with_retry (mut self , retry : oas3_gen_support :: RetryPolicy) -> Self",
+        retry,
+      ),
+      (
+        "pub fn with_config (mut self , config : oas3_gen_support :: ClientConfig) -> Self { self . retry = config . retry ; self }",
+        retry,
+      ),
+      ("Some (retry) => Ok (retry . send (request) . await ?)", retry),
+      ("self . send_request (req_builder) . await ?", retry),
+      (
+        "None => match & self . retry { Some (retry) => Ok (retry . send (request) . await ?)",
+        retry && vcr,
+      ),
+      ("let response = self . client . get (url) . send () . await ?", !retry),
+    ];
+    for (snippet, expected) in expectations {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_request_hooks_wrap_sends_and_add_option_methods() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+
+  let cases = [
+    (false, false, "default"),
+    (true, false, "hooks"),
+    (true, true, "hooks with retry"),
+  ];
+  for (hooks, retry, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
+    let generator = if hooks {
+      generator.with_request_hooks()
+    } else {
+      generator
+    };
+    let generator = if retry { generator.with_retry() } else { generator };
+    let output = generator.to_token_stream().to_string();
+
+    let expectations = [
+      ("pub hooks : oas3_gen_support :: RequestHooks ,", hooks),
+      ("hooks : oas3_gen_support :: RequestHooks :: default () ,", hooks),
+      (
+        "pub fn with_hook (mut self , hook : impl oas3_gen_support :: RequestHook + 'static) -> Self",
+        hooks,
+      ),
+      ("let request = self . hooks . before_send (request) . await ? ;", hooks),
+      (
+        "self . hooks . after_receive (& response) . await ? ; Ok (response)",
+        hooks,
+      ),
+      ("Some (retry) => retry . send (request) . await ? ,", hooks && retry),
+      (
+        "self . test_operation_with_options (request , oas3_gen_support :: RequestOptions :: default ()) . await",
+        hooks,
+      ),
+      (
+        "pub async fn test_operation_with_options (& self , request : TestRequest , options : oas3_gen_support :: RequestOptions ,)",
+        hooks,
+      ),
+      ("let req_builder = options . apply (req_builder) ? ;", hooks),
+      ("self . send_request (req_builder) . await ?", hooks),
+    ];
+    for (snippet, expected) in expectations {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_last_response_records_metadata_around_sends() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+
+  let cases = [
+    (false, false, "default"),
+    (true, false, "last response"),
+    (true, true, "last response with hooks"),
+  ];
+  for (last_response, hooks, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
+    let generator = if last_response {
+      generator.with_last_response()
+    } else {
+      generator
+    };
+    let generator = if hooks {
+      generator.with_request_hooks()
+    } else {
+      generator
+    };
+    let output = generator.to_token_stream().to_string();
+
+    let expectations = [
+      ("pub last_response : oas3_gen_support :: LastResponse ,", last_response),
+      (
+        "last_response : oas3_gen_support :: LastResponse :: default () ,",
+        last_response,
+      ),
+      (
+        "pub fn last_response (& self) -> Option < oas3_gen_support :: ResponseMetadata >",
+        last_response,
+      ),
+      (
+        "let started = oas3_gen_support :: Instant :: now () ; let response = request . send () . await ? ; self . last_response . record (& response , started) ;",
+        last_response,
+      ),
+      (
+        "self . last_response . record (& response , started) ; self . hooks . after_receive (& response) . await ? ;",
+        last_response && hooks,
+      ),
+      ("self . send_request (req_builder) . await ?", last_response || hooks),
+    ];
+    for (snippet, expected) in expectations {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_deprecated_operations_get_deprecated_methods() {
+  let cases = [
+    (false, None, None),
+    (true, None, Some("# [deprecated (note = \"deprecated by the API\")]")),
+    (
+      true,
+      Some("listPetsV2"),
+      Some("# [deprecated (note = \"use `listPetsV2` instead\")]"),
+    ),
+  ];
+  for (deprecated, replaced_by, expected) in cases {
+    let mut operation = TestOperation::default().build();
+    operation.deprecated = deprecated;
+    operation.replaced_by = replaced_by.map(str::to_string);
+    let method = ClientMethodFragment::new(operation, Visibility::Public)
+      .generate()
+      .unwrap()
+      .to_string();
+
+    match expected {
+      Some(attr) => assert!(
+        method.contains(&format!("{attr} pub async fn test_operation")),
+        "missing `{attr}`. Got code: {method}"
+      ),
+      None => assert!(
+        !method.contains("deprecated"),
+        "unexpected attribute. Got code: {method}"
+      ),
+    }
+  }
+}
+
+#[test]
+fn test_request_compression_compresses_opted_in_bodies() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let mut compressed = TestOperation::default().build();
+  compressed.body = Some(
+    OperationBody::builder()
+      .field_name(FieldNameToken::new("body"))
+      .content_category(ContentCategory::Json)
+      .build(),
+  );
+  compressed.request_compression = true;
+
+  let cases = [
+    (
+      vec![TestOperation::default().build()],
+      false,
+      "no compressible operations",
+    ),
+    (vec![compressed], true, "compressible operation"),
+  ];
+  for (operations, expected, label) in cases {
+    let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+      .to_token_stream()
+      .to_string();
+
+    let expectations = [
+      "pub request_compression : Option < oas3_gen_support :: RequestCompression >",
+      "request_compression : None ,",
+      "pub fn with_request_compression (mut self , compression : oas3_gen_support :: RequestCompression) -> Self",
+      "Some (compression) => Ok (compression . apply (request) ?)",
+      "let req_builder = self . compress_request (req_builder) ? ;",
+    ];
+    for snippet in expectations {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_security_schemes_generate_auth_support() {
+  let scheme = |name: &str, kind: SecuritySchemeKind| SecuritySchemeDef {
+    name: name.to_string(),
+    field: FieldNameToken::from_raw(name),
+    kind,
+    description: None,
+  };
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![
+      scheme(
+        "apiKey",
+        SecuritySchemeKind::ApiKey {
+          name: "X-API-Key".to_string(),
+          location: ApiKeyLocation::Header,
+        },
+      ),
+      scheme(
+        "token",
+        SecuritySchemeKind::ApiKey {
+          name: "token".to_string(),
+          location: ApiKeyLocation::Query,
+        },
+      ),
+      scheme(
+        "session",
+        SecuritySchemeKind::ApiKey {
+          name: "sid".to_string(),
+          location: ApiKeyLocation::Cookie,
+        },
+      ),
+      scheme("bearer", SecuritySchemeKind::Bearer),
+      scheme("basic", SecuritySchemeKind::Basic),
+      scheme(
+        "oauth",
+        SecuritySchemeKind::ClientCredentials {
+          token_url: "https://auth.example.com/token".to_string(),
+        },
+      ),
+    ],
+  };
+  let mut operation = TestOperation::default().build();
+  operation.security = vec![
+    vec!["oauth".to_string()],
+    vec!["apiKey".to_string(), "basic".to_string()],
+  ];
+
+  let output = ClientFragment::new(&metadata, &[operation], Visibility::Public)
+    .to_token_stream()
+    .to_string();
+
+  let expected = [
+    "pub auth : Option < TestClientAuth >",
+    "pub struct TestClientAuth",
+    "pub api_key : Option < String >",
+    "pub basic : Option < oas3_gen_support :: BasicAuth >",
+    "pub oauth : Option < oas3_gen_support :: ClientCredentials >",
+    "pub fn with_api_key (mut self , value : impl Into < String >)",
+    "pub fn with_basic (mut self , username : impl Into < String > , password : impl Into < String >)",
+    "oas3_gen_support :: ClientCredentials :: new (\"https://auth.example.com/token\" , client_id , client_secret)",
+    "\"apiKey\" => self . api_key . is_some ()",
+    "request . header (\"X-API-Key\" , credential)",
+    "request . query (& [(\"token\" , credential)])",
+    "request . header (reqwest :: header :: COOKIE , format ! (\"sid={credential}\"))",
+    "request . bearer_auth (credential)",
+    "request . basic_auth (& credential . username , credential . password . as_ref ())",
+    "credential . access_token (client) . await",
+    "pub fn with_auth (mut self , auth : TestClientAuth) -> Self",
+    "auth : None",
+    "self . authorize (req_builder , & [& [\"oauth\"] , & [\"apiKey\" , \"basic\"]]) . await ?",
+  ];
+  for snippet in expected {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let unsecured = ClientFragment::new(
+    &ClientRootNode {
+      security_schemes: vec![],
+      ..metadata
+    },
+    &[TestOperation::default().build()],
+    Visibility::Public,
+  )
+  .to_token_stream()
+  .to_string();
+  for snippet in ["Auth", "authorize", "auth :"] {
+    assert!(
+      !unsecured.contains(snippet),
+      "unexpected `{snippet}`. Got code: {unsecured}"
+    );
+  }
+}
+
 #[test]
 fn test_event_stream_response_handling() {
   let operation = TestOperation {
@@ -400,6 +872,7 @@ fn test_client_filters_webhook_operations() {
     base_url: "https://api.example.com".to_string(),
     version: "1.0.0".to_string(),
     description: None,
+    security_schemes: vec![],
   };
 
   let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
@@ -792,3 +1265,649 @@ fn test_demonstrates_query_string_fix() {
     "correct URL with query param"
   );
 }
+
+#[test]
+fn test_typed_errors_generate_client_error_enum() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let mut operation = TestOperation {
+    response_enum: Some("TestResponse".to_string()),
+    ..Default::default()
+  }
+  .build();
+  operation.error_response_types = vec![
+    ErrorResponseType {
+      variant: EnumVariantToken::new("NotFound"),
+      body: Some(DefaultAtom::from("Problem")),
+      named_fields: false,
+      error_impl: true,
+    },
+    ErrorResponseType {
+      variant: EnumVariantToken::new("Unknown"),
+      body: Some(DefaultAtom::from("Request")),
+      named_fields: true,
+      error_impl: false,
+    },
+    ErrorResponseType {
+      variant: EnumVariantToken::new("ServiceUnavailable"),
+      body: None,
+      named_fields: false,
+      error_impl: false,
+    },
+  ];
+  let operations = vec![operation];
+
+  let default = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  assert!(!default.contains("TestClientError"), "errors stay anyhow by default");
+
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_typed_errors()
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "pub enum TestClientError",
+    "Validation (validator :: ValidationErrors)",
+    "Ok (error) => return Self :: Validation (error)",
+    "Transport (reqwest :: Error)",
+    "Deserialize (oas3_gen_support :: DiagnosticsError)",
+    "UnexpectedStatus (http :: StatusCode)",
+    "Problem { status : http :: StatusCode , body : Problem }",
+    "RequestBody { status : http :: StatusCode , body : Request }",
+    "Self :: Problem { body , .. } => Some (body)",
+    "Self :: RequestBody { status , body } => write ! (f , \"{}: {:?}\" , status , body)",
+    "impl From < anyhow :: Error > for TestClientError",
+    "-> Result < TestResponse , TestClientError >",
+    "TestResponse :: NotFound (body) => Err (TestClientError :: Problem { status , body })",
+    "TestResponse :: Unknown { body , .. } => Err (TestClientError :: RequestBody { status , body })",
+    "TestResponse :: ServiceUnavailable => Err (TestClientError :: UnexpectedStatus (status))",
+    "response => Ok (response)",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+  assert!(
+    !output.contains("Self :: RequestBody { body , .. } => Some (body)"),
+    "bodies without Error impls have no source"
+  );
+}
+
+#[test]
+fn test_api_error_alias_names_client_error() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+  let alias = "pub type ApiError = TestClientError ;";
+
+  let cases = [
+    (true, true, true, "typed errors with alias"),
+    (true, false, false, "typed errors without alias"),
+    (false, true, false, "alias without typed errors"),
+  ];
+  for (typed_errors, api_error_alias, expected, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public);
+    let generator = if typed_errors {
+      generator.with_typed_errors()
+    } else {
+      generator
+    };
+    let generator = if api_error_alias {
+      generator.with_api_error_alias()
+    } else {
+      generator
+    };
+    let output = generator.to_token_stream().to_string();
+    assert_eq!(output.contains(alias), expected, "{label}. Got code: {output}");
+  }
+}
+
+#[test]
+fn test_request_hooks_add_deadline_exceeded_error() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+  let snippets = [
+    "DeadlineExceeded (oas3_gen_support :: DeadlineExceeded) ,",
+    "Self :: DeadlineExceeded (error) => write ! (f , \"{error}\") ,",
+    "error . downcast :: < oas3_gen_support :: DeadlineExceeded > ()",
+  ];
+
+  let cases = [
+    (true, true, "typed errors with hooks"),
+    (false, false, "typed errors without hooks"),
+  ];
+  for (hooks, expected, label) in cases {
+    let generator = ClientFragment::new(&metadata, &operations, Visibility::Public).with_typed_errors();
+    let generator = if hooks {
+      generator.with_request_hooks()
+    } else {
+      generator
+    };
+    let output = generator.to_token_stream().to_string();
+    for snippet in snippets {
+      assert_eq!(
+        output.contains(snippet),
+        expected,
+        "{label}: `{snippet}` presence mismatch. Got code: {output}"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_tower_services_wrap_client_methods() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+  let service_impl = "impl tower :: Service < TestRequest > for TestClient";
+
+  let default = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  assert!(
+    !default.contains("tower ::"),
+    "services are opt-in. Got code: {default}"
+  );
+
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_tower_services()
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    service_impl,
+    "type Response = TestResponse ;",
+    "type Error = anyhow :: Error ;",
+    "Output = Result < Self :: Response , Self :: Error >> + Send >>",
+    "std :: task :: Poll :: Ready (Ok (()))",
+    "let client = self . clone () ; Box :: pin (async move { client . test_operation (request) . await })",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let typed = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_tower_services()
+    .with_typed_errors()
+    .with_local_futures()
+    .to_token_stream()
+    .to_string();
+  assert!(typed.contains("type Error = TestClientError ;"), "Got code: {typed}");
+  assert!(
+    typed.contains("Output = Result < Self :: Response , Self :: Error >> >>"),
+    "local futures drop the Send bound. Got code: {typed}"
+  );
+}
+
+#[test]
+fn test_paginated_methods_stream_items_across_pages() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let paginated = |next_page: NextPage| {
+    let mut op = TestOperation {
+      response_enum: Some("TestResponse".to_string()),
+      ..Default::default()
+    }
+    .build();
+    op.pagination = Some(Pagination {
+      page_variant: EnumVariantToken::new("Ok"),
+      other_variants: false,
+      page_links: false,
+      items: Some(FieldNameToken::new("pets")),
+      items_optional: false,
+      item_type: TypeRef::new("Pet"),
+      cursor: PageCursor::Field {
+        name: FieldNameToken::new("next_page_token"),
+        optional: true,
+      },
+      next_page,
+    });
+    vec![op]
+  };
+
+  let default = ClientFragment::new(&metadata, &[TestOperation::default().build()], Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  assert!(
+    !default.contains("_paginated"),
+    "only paginated operations get helpers. Got code: {default}"
+  );
+
+  let query = paginated(NextPage::Query {
+    field: FieldNameToken::new("page_token"),
+    optional: true,
+  });
+  let output = ClientFragment::new(&metadata, &query, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "pub fn test_operation_paginated (& self , request : TestRequest) -> impl futures :: Stream < Item = Result < Pet , anyhow :: Error >> + '_",
+    "let response = self . test_operation (request . clone ()) . await ? ;",
+    "let TestResponse :: Ok (page) = response ;",
+    "let items = page . pets ;",
+    "page . next_page_token . filter (| cursor | ! cursor . is_empty ())",
+    "request . query . page_token = Some (cursor) ;",
+    "futures :: TryStreamExt :: try_flatten (pages)",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let link = paginated(NextPage::Link);
+  let output = ClientFragment::new(&metadata, &link, Visibility::Public)
+    .with_typed_errors()
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "Item = Result < Pet , TestClientError >>",
+    "Some ((request , None :: < String >))",
+    "self . client . get (self . base_url . join (& link)",
+    "TestRequest :: parse_response (response) . await",
+    ". map (| link | (request , Some (link)))",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let mut link_header = paginated(NextPage::Link);
+  let pagination = link_header[0].pagination.as_mut().unwrap();
+  pagination.items = None;
+  pagination.page_links = true;
+  pagination.cursor = PageCursor::LinkHeader;
+  link_header[0].page_link = true;
+  let output = ClientFragment::new(&metadata, &link_header, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "let req_builder = match & request . page_link { Some (link) => self . client . get (self . base_url . join (link) . context (\"resolving page link\") ?) ,",
+    "let response = self . test_operation (request . clone ()) . await ? ;",
+    "let next_request = response . next (request) ;",
+    "let TestResponse :: Ok { body : page , .. } = response ;",
+    "let items = page ;",
+    "let next = next_request ;",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+}
+
+#[test]
+fn test_batch_method_runs_requests_in_order() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+
+  let default = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  assert!(!default.contains("fn batch"), "batch is opt-in. Got code: {default}");
+
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_batch()
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "pub async fn batch < 'a , R , F > (& 'a self , requests : impl IntoIterator < Item = R > , concurrency : usize , call : impl Fn (& 'a Self , R) -> F ,) -> Vec < F :: Output >",
+    ". buffered (concurrency . max (1))",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let mut colliding = TestOperation::default().build();
+  colliding.stable_id = "batch".to_string();
+  let output = ClientFragment::new(&metadata, &[colliding], Visibility::Public)
+    .with_batch()
+    .to_token_stream()
+    .to_string();
+  assert!(
+    output.contains("pub async fn batch_requests <"),
+    "an operation named batch keeps its name. Got code: {output}"
+  );
+}
+
+#[test]
+fn test_client_templates_replace_struct_method_and_body() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let operations = vec![TestOperation::default().build()];
+  let templates = ClientTemplates {
+    client_struct: Some("#[derive(Debug, Clone)] #[non_exhaustive] {{vis}} struct {{name}} { {{fields}} }".to_string()),
+    method: Some(
+      "#[tracing::instrument(skip_all, fields(operation = {{operation_id}}, method = {{http_method}}))] {{default}}"
+        .to_string(),
+    ),
+    error_handling: Some(
+      "let result: {{result}} = async { {{body}} }.await; if let Err(e) = &result { tracing::warn!(%e); } result"
+        .to_string(),
+    ),
+  };
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_templates(templates)
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "# [non_exhaustive] pub struct TestClient { pub client : Client , pub base_url : Url , }",
+    "# [tracing :: instrument (skip_all , fields (operation = \"testOperation\" , method = \"GET\"))]",
+    "pub async fn test_operation (& self , request : TestRequest) -> anyhow :: Result < TestResponse > { let result : anyhow :: Result < TestResponse > = async {",
+    "tracing :: warn ! (% e) ;",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let broken = ClientTemplates {
+    method: Some("{{default}} }".to_string()),
+    ..ClientTemplates::default()
+  };
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_templates(broken)
+    .to_token_stream()
+    .to_string();
+  assert!(
+    output.contains("compile_error ! (\"invalid client template"),
+    "templates that do not lex become compile errors. Got code: {output}"
+  );
+}
+
+#[test]
+fn test_client_templates_validate_placeholders() {
+  let templates = ClientTemplates {
+    error_handling: Some("{{body}}".to_string()),
+    ..ClientTemplates::default()
+  };
+  assert!(templates.validate().is_ok());
+
+  let unknown = ClientTemplates {
+    client_struct: Some("{{default}} impl {{name}} { {{body}} }".to_string()),
+    ..ClientTemplates::default()
+  };
+  let err = unknown.validate().unwrap_err().to_string();
+  assert!(
+    err.contains("Unknown placeholder '{{body}}' in client-struct template"),
+    "{err}"
+  );
+
+  let unbalanced = ClientTemplates {
+    method: Some("{{default}} }".to_string()),
+    ..ClientTemplates::default()
+  };
+  let err = unbalanced.validate().unwrap_err().to_string();
+  assert!(err.starts_with("Invalid client-method template"), "{err}");
+}
+
+#[test]
+fn test_route_tests_check_encoded_paths() {
+  let metadata = ClientRootNode {
+    name: StructToken::new("TestClient"),
+    title: "Test".to_string(),
+    base_url: "https://api.example.com".to_string(),
+    version: "1.0.0".to_string(),
+    description: None,
+    security_schemes: vec![],
+  };
+  let path_param = |name: &str, ty: &str| {
+    FieldDef::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(TypeRef::new(ty))
+      .parameter_location(ParameterLocation::Path)
+      .original_name(name.to_string())
+      .build()
+  };
+  let mut operation = TestOperation::default().build();
+  operation.path = ParsedPath {
+    segments: vec![
+      PathSegment::Literal("users".to_string()),
+      PathSegment::Param(FieldNameToken::new("user_id")),
+      PathSegment::Mixed {
+        format: "v{}.json".to_string(),
+        params: vec![FieldNameToken::new("version")],
+      },
+    ],
+    query_string: Some("format=full text".to_string()),
+  };
+  operation.parameters = vec![path_param("user_id", "String"), path_param("version", "i32")];
+  let operations = vec![operation];
+
+  let default = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .to_token_stream()
+    .to_string();
+  assert!(
+    !default.contains("route_tests") && !default.contains("fn test_operation_url"),
+    "route tests are opt-in. Got code: {default}"
+  );
+
+  let output = ClientFragment::new(&metadata, &operations, Visibility::Public)
+    .with_route_tests()
+    .to_token_stream()
+    .to_string();
+  let expectations = [
+    "fn test_operation_url (& self , request : & TestRequest) -> anyhow :: Result < Url >",
+    "let url = self . test_operation_url (& request) ? ;",
+    "# [cfg (test)] mod route_tests",
+    "TestRequest { path : TestRequestPath { user_id : \"user_id/0 ?#%é\" . to_string () , version : 11 } , }",
+    "assert_eq ! (url . path () , \"/api/users/user_id%2F0%20%3F%23%25%C3%A9/v11.json\")",
+    "assert_eq ! (url . query () , Some (\"format=full%20text\"))",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+
+  let mut literal = operations[0].clone();
+  literal.path.segments.truncate(1);
+  literal.parameters.clear();
+  let output = ClientFragment::new(&metadata, &[literal], Visibility::Public)
+    .with_route_tests()
+    .to_token_stream()
+    .to_string();
+  assert!(
+    output.contains("fn test_operation_url (& self , _request : & TestRequest) -> anyhow :: Result < Url >"),
+    "paths without parameters leave the request unused. Got code: {output}"
+  );
+
+  let mut untestable = operations[0].clone();
+  untestable.parameters[1].rust_type = TypeRef::new("Version");
+  let output = ClientFragment::new(&metadata, &[untestable], Visibility::Public)
+    .with_route_tests()
+    .to_token_stream()
+    .to_string();
+  assert!(
+    !output.contains("route_tests"),
+    "operations with a path parameter lacking a sample get no test. Got code: {output}"
+  );
+}
+
+#[test]
+fn test_multipart_file_fields_use_file_parts() {
+  let file = |name: &str, nullable: bool, is_array: bool| {
+    MultipartFieldInfo::builder()
+      .name(FieldNameToken::new(name))
+      .nullable(nullable)
+      .is_file(true)
+      .is_array(is_array)
+      .build()
+  };
+  let body = OperationBody::builder()
+    .field_name(FieldNameToken::new("body"))
+    .content_category(ContentCategory::Multipart)
+    .multipart_fields(vec![
+      file("image", false, false),
+      file("thumbnail", true, false),
+      file("attachments", false, true),
+      file("extras", true, true),
+    ])
+    .build();
+
+  let output = MultipartFormFragment::new(body).into_token_stream().to_string();
+
+  let expectations = [
+    "form = form . part (\"image\" , body . image . to_part () ?) ;",
+    "if let Some (file) = & body . thumbnail { form = form . part (\"thumbnail\" , file . to_part () ?) ; }",
+    "for file in & body . attachments { form = form . part (\"attachments\" , file . to_part () ?) ; }",
+    "if let Some (files) = & body . extras { for file in files { form = form . part (\"extras\" , file . to_part () ?) ; } }",
+  ];
+  for snippet in expectations {
+    assert!(output.contains(snippet), "missing `{snippet}`. Got code: {output}");
+  }
+}
+
+#[test]
+fn test_request_bodies_set_content_type() {
+  let cases = [
+    (
+      ContentCategory::Text,
+      Some("text/plain"),
+      Some("media_types :: TEXT_PLAIN"),
+    ),
+    (
+      ContentCategory::Binary,
+      Some("application/octet-stream"),
+      Some("media_types :: APPLICATION_OCTET_STREAM"),
+    ),
+    (ContentCategory::Xml, Some("text/xml"), Some("media_types :: TEXT_XML")),
+    (
+      ContentCategory::Yaml,
+      Some("application/x-yaml"),
+      Some("media_types :: APPLICATION_X_YAML"),
+    ),
+    (ContentCategory::Binary, Some("image/*"), None),
+    (
+      ContentCategory::Json,
+      Some("application/vnd.pet+json"),
+      Some("media_types :: APPLICATION_JSON"),
+    ),
+  ];
+  for (category, content_type, expected) in cases {
+    let body = OperationBody::builder()
+      .field_name(FieldNameToken::new("body"))
+      .content_category(category)
+      .maybe_content_type(content_type)
+      .build();
+    let code = match RequestBodyFragment::new(Some(&body)) {
+      RequestBodyFragment::Simple(fragment) => fragment.into_token_stream(),
+      RequestBodyFragment::Xml(fragment) => fragment.into_token_stream(),
+      RequestBodyFragment::Yaml(fragment) => fragment.into_token_stream(),
+      _ => panic!("{category:?} should be a JSON, text, binary, XML, or YAML body"),
+    }
+    .to_string();
+
+    match expected {
+      Some(path) => assert!(
+        code.contains(&format!(". header (reqwest :: header :: CONTENT_TYPE , {path})")),
+        "{category:?}: missing Content-Type. Got code: {code}"
+      ),
+      None => assert!(
+        !code.contains("CONTENT_TYPE"),
+        "{category:?}: should not set Content-Type. Got code: {code}"
+      ),
+    }
+  }
+}
+
+#[test]
+fn test_body_variants_switch_encoding_per_variant() {
+  let variant = |name: &str, category: ContentCategory, content_type: &str| {
+    BodyMediaVariant::builder()
+      .name(EnumVariantToken::new(name))
+      .content_category(category)
+      .content_type(content_type)
+      .build()
+  };
+  let body = OperationBody::builder()
+    .field_name(FieldNameToken::new("body"))
+    .body_type(TypeRef::new("CreatePetRequestBody"))
+    .optional(true)
+    .media_variants(vec![
+      variant("Json", ContentCategory::Json, "application/json"),
+      variant("Multipart", ContentCategory::Multipart, "multipart/form-data"),
+      variant("Text", ContentCategory::Text, "text/plain"),
+    ])
+    .build();
+
+  let fragment = RequestBodyFragment::new(Some(&body));
+  assert!(fragment.needs_conditional());
+  let code = fragment.into_token_stream().to_string();
+
+  for expected in [
+    "if let Some (body) = request . body . as_ref () { match body {",
+    "CreatePetRequestBody :: Json (body) => { req_builder = req_builder . header (reqwest :: header :: CONTENT_TYPE , media_types :: APPLICATION_JSON) . body (oas3_gen_support :: to_json_bytes (body) ?) ; }",
+    "CreatePetRequestBody :: Multipart (body) => { let json_value = serde_json :: to_value (body) ? ;",
+    "req_builder = req_builder . multipart (form) ;",
+    "CreatePetRequestBody :: Text (body) => { req_builder = req_builder . header (reqwest :: header :: CONTENT_TYPE , media_types :: TEXT_PLAIN) . body ((body) . to_string ()) ; }",
+  ] {
+    assert!(
+      code.contains(expected),
+      "expected to contain '{expected}'. Got code: {code}"
+    );
+  }
+}
+
+#[test]
+fn test_operation_table_lists_tags_external_docs_and_extensions() {
+  let mut operation = TestOperation::default().build();
+  operation.path_template = "/test".to_string();
+  operation.tags = vec!["pets".to_string()];
+  operation.external_docs = Some(ExternalDocs {
+    url: "https://example.com/pets".to_string(),
+    description: None,
+  });
+  operation.extensions = BTreeMap::from([("rate-limit".to_string(), serde_json::json!(100))]);
+  let mut webhook = TestOperation::default().build();
+  webhook.kind = OperationKind::Webhook;
+
+  let code = OperationTableFragment::new(&[operation, webhook], Visibility::Public)
+    .into_token_stream()
+    .to_string();
+
+  assert!(code.contains("pub const OPERATIONS"), "{code}");
+  assert_eq!(code.matches("operation_id : \"testOperation\"").count(), 1, "{code}");
+  assert!(code.contains("path : \"/test\""), "{code}");
+  assert!(code.contains("tags : & [\"pets\"]"), "{code}");
+  assert!(
+    code.contains("url : \"https://example.com/pets\" , description : None"),
+    "{code}"
+  );
+  assert!(code.contains("(\"x-rate-limit\" , \"100\")"), "{code}");
+}
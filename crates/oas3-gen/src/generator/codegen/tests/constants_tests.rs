@@ -2,10 +2,11 @@ use quote::ToTokens as _;
 
 use crate::generator::{
   ast::{
-    FieldDef, FieldNameToken, RustType, StructDef, StructToken, TypeAliasDef, TypeAliasToken, TypeRef,
-    ValidationAttribute, constants::HttpHeaderRef,
+    ContentCategory, FieldDef, FieldNameToken, OperationBody, OperationInfo, OperationKind, RustType, StructDef,
+    StructToken, TypeAliasDef, TypeAliasToken, TypeRef, ValidationAttribute, constants::HttpHeaderRef,
   },
-  codegen::constants::{HeaderConstantsFragment, RegexConstantsResult},
+  codegen::constants::{HeaderConstantsFragment, MediaTypeConstantsFragment, RegexConstantsResult},
+  converter::GenerationTarget,
 };
 
 fn make_field(name: &str, pattern: Option<&str>) -> FieldDef {
@@ -148,3 +149,45 @@ fn test_header_constants_fragment_multiple() {
   assert!(code.contains("X_CORRELATION_ID"));
   assert!(code.contains("CONTENT_TYPE"));
 }
+
+#[test]
+fn test_media_type_constants_from_request_bodies() {
+  let operation = |id: &str, category, content_type: &str| {
+    OperationInfo::builder()
+      .stable_id(id)
+      .operation_id(id)
+      .method(http::Method::POST)
+      .path(Default::default())
+      .kind(OperationKind::Http)
+      .documentation(Default::default())
+      .body(
+        OperationBody::builder()
+          .field_name(FieldNameToken::new("body"))
+          .content_category(category)
+          .content_type(content_type)
+          .build(),
+      )
+      .build()
+  };
+  let operations = vec![
+    operation("create_note", ContentCategory::Text, "text/plain"),
+    operation("update_note", ContentCategory::Text, "text/plain"),
+    operation("upload", ContentCategory::Binary, "application/octet-stream"),
+    operation("upload_image", ContentCategory::Binary, "image/*"),
+    operation("create_pet", ContentCategory::Json, "application/json"),
+  ];
+
+  let code = MediaTypeConstantsFragment::new(&[], &operations, GenerationTarget::Client)
+    .into_token_stream()
+    .to_string();
+  assert_eq!(
+    code,
+    "pub mod media_types { \
+     pub const APPLICATION_JSON : & str = \"application/json\" ; \
+     pub const APPLICATION_OCTET_STREAM : & str = \"application/octet-stream\" ; \
+     pub const TEXT_PLAIN : & str = \"text/plain\" ; }"
+  );
+
+  let server = MediaTypeConstantsFragment::new(&[], &operations, GenerationTarget::Server);
+  assert!(server.into_token_stream().is_empty());
+}
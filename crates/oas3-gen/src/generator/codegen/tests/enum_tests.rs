@@ -1,16 +1,18 @@
+use std::collections::BTreeMap;
+
 use quote::ToTokens;
 
 use crate::generator::{
   ast::{
     DiscriminatedEnumDef, DiscriminatedVariant, Documentation, EnumDef, EnumMethod, EnumMethodKind, EnumToken,
-    EnumVariantToken, OuterAttr, ResponseEnumDef, ResponseMediaType, ResponseVariant, RustPrimitive, SerdeAttribute,
-    SerdeMode, StatusCodeToken, StructToken, TypeRef, VariantContent, VariantDef,
+    EnumVariantToken, NamedEventVariant, NamedEventsDef, OuterAttr, ResponseClass, ResponseEnumDef, ResponseMediaType,
+    ResponseVariant, RustPrimitive, SerdeAttribute, SerdeMode, StatusCodeToken, StructToken, TypeRef, VariantContent,
+    VariantDef,
   },
   codegen::{
     Visibility,
     enums::{DiscriminatedEnumFragment, EnumFragment, ResponseEnumFragment},
   },
-  converter::GenerationTarget,
   naming::constants::{KNOWN_ENUM_VARIANT, OTHER_ENUM_VARIANT},
 };
 
@@ -45,7 +47,7 @@ fn test_basic_enum_generation() {
     ],
   );
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -76,7 +78,7 @@ fn test_simple_enum_display_impl() {
     ],
   );
 
-  let code = EnumFragment::new(simple_def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(simple_def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -122,7 +124,7 @@ fn test_simple_enum_display_impl_with_serde_rename() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(renamed_def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(renamed_def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -140,6 +142,92 @@ fn test_simple_enum_display_impl_with_serde_rename() {
   );
 }
 
+#[test]
+fn test_simple_enum_from_str_impl() {
+  let renamed = |name: &str, value: &str| {
+    VariantDef::builder()
+      .name(EnumVariantToken::new(name))
+      .content(VariantContent::Unit)
+      .serde_attrs(vec![SerdeAttribute::Rename(value.to_string())])
+      .build()
+  };
+  let mut in_progress = renamed("InProgress", "In_Progress");
+  in_progress.add_alias("in-progress");
+  let def = EnumDef {
+    name: EnumToken::new("Status"),
+    variants: vec![in_progress, renamed("Unknown", "unknown")],
+    generate_display: true,
+    ..Default::default()
+  };
+
+  let code = EnumFragment::new(def.clone(), Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    code.contains("impl core :: str :: FromStr for Status"),
+    "should have FromStr impl"
+  );
+  assert!(
+    code.contains("match s { \"In_Progress\" | \"in-progress\" => Ok (Self :: InProgress) ,"),
+    "should match the serialized value and its aliases exactly"
+  );
+  assert!(
+    code.contains("\"unknown\" => Ok (Self :: Unknown) , _ => Ok (Self :: Unknown) ,"),
+    "should fall back to Unknown for unlisted values like Deserialize"
+  );
+
+  let closed = EnumDef {
+    variants: vec![renamed("InProgress", "In_Progress"), renamed("Done", "done")],
+    ..def.clone()
+  };
+  let code = EnumFragment::new(closed, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    code.contains("_ => Err (format ! (\"unknown variant '{}', expected one of: {}\" , s , \"In_Progress, done\"))"),
+    "should reject unknown values without a fallback variant"
+  );
+
+  let case_insensitive = EnumDef {
+    case_insensitive: true,
+    ..def
+  };
+  let code = EnumFragment::new(case_insensitive, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    code.contains("match s . to_ascii_lowercase () . as_str () { \"in_progress\" => Ok (Self :: InProgress) ,"),
+    "should match ignoring case like Deserialize"
+  );
+  assert!(
+    code.contains("\"unknown\" => Ok (Self :: Unknown) , _ => Ok (Self :: Unknown) ,"),
+    "should fall back to Unknown like Deserialize"
+  );
+}
+
+#[test]
+fn test_forward_compatible_enum_from_str_falls_back_to_unknown() {
+  let mut unknown = make_unit_variant("Unknown");
+  unknown.serde_attrs.push(SerdeAttribute::Other);
+  let def = make_simple_enum(
+    "Status",
+    vec![make_unit_variant("Active"), make_unit_variant("Retired"), unknown],
+  );
+
+  let code = EnumFragment::new(def, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    code.contains("match s { \"Active\" => Ok (Self :: Active) ,"),
+    "should match values case-sensitively"
+  );
+  assert!(
+    code.contains("_ => Ok (Self :: Unknown) ,"),
+    "should parse unlisted values to Unknown like #[serde(other)]"
+  );
+  assert!(!code.contains("unknown variant"), "should not reject unlisted values");
+}
+
 #[test]
 fn test_tuple_enum_no_display_impl() {
   let tuple_def = EnumDef {
@@ -161,7 +249,7 @@ fn test_tuple_enum_no_display_impl() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(tuple_def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(tuple_def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -184,7 +272,7 @@ fn test_enum_with_docs() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -237,7 +325,7 @@ fn test_enum_tuple_variants() {
 
   for (case_name, variants, expected_content) in cases {
     let def = make_simple_enum("Value", variants);
-    let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+    let code = EnumFragment::new(def, Visibility::Public)
       .into_token_stream()
       .to_string();
 
@@ -266,7 +354,7 @@ fn test_enum_variant_attributes() {
     ..Default::default()
   };
 
-  let deprecated_code = EnumFragment::new(deprecated_def, Visibility::Public, GenerationTarget::Client)
+  let deprecated_code = EnumFragment::new(deprecated_def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(
@@ -284,7 +372,7 @@ fn test_enum_variant_attributes() {
     ..Default::default()
   };
 
-  let outer_attrs_code = EnumFragment::new(outer_attrs_def, Visibility::Public, GenerationTarget::Client)
+  let outer_attrs_code = EnumFragment::new(outer_attrs_def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(
@@ -339,7 +427,7 @@ fn test_enum_serde_attributes() {
   ];
 
   for (case_name, def, expected_attrs) in cases {
-    let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+    let code = EnumFragment::new(def, Visibility::Public)
       .into_token_stream()
       .to_string()
       .clone();
@@ -372,7 +460,7 @@ fn test_case_insensitive_enum() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(base_def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(base_def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -413,7 +501,7 @@ fn test_case_insensitive_enum() {
     ..Default::default()
   };
 
-  let fallback_code = EnumFragment::new(fallback_def, Visibility::Public, GenerationTarget::Client)
+  let fallback_code = EnumFragment::new(fallback_def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(
@@ -422,6 +510,77 @@ fn test_case_insensitive_enum() {
   );
 }
 
+#[test]
+fn test_boolean_enum_serializes_as_bool() {
+  let bool_variant = |name: &str, value: &str| {
+    VariantDef::builder()
+      .name(EnumVariantToken::new(name))
+      .content(VariantContent::Unit)
+      .serde_attrs(vec![SerdeAttribute::Rename(value.to_string())])
+      .build()
+  };
+
+  let single = EnumDef {
+    name: EnumToken::new("Enabled"),
+    variants: vec![bool_variant("True", "true")],
+    scalar_repr: Some(RustPrimitive::Bool),
+    ..Default::default()
+  };
+  let code = EnumFragment::new(single, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(code.contains("let value : bool = match self { Self :: True => true , }"));
+  assert!(code.contains("serializer . serialize_bool (value)"));
+  assert!(code.contains("true => Ok (Self :: True) , _ => Err"));
+  assert!(!code.contains("# [serde (rename"));
+
+  let both = EnumDef {
+    name: EnumToken::new("Toggle"),
+    variants: vec![bool_variant("True", "true"), bool_variant("False", "false")],
+    scalar_repr: Some(RustPrimitive::Bool),
+    ..Default::default()
+  };
+  let code = EnumFragment::new(both, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    code.contains("match value { true => Ok (Self :: True) , false => Ok (Self :: False) , }"),
+    "should omit the unreachable fallback arm when both values are covered"
+  );
+}
+
+#[test]
+fn test_open_integer_enum_round_trips_unlisted_values() {
+  let def = EnumDef {
+    name: EnumToken::new("Level"),
+    variants: vec![
+      VariantDef::builder()
+        .name(EnumVariantToken::new("Value1"))
+        .content(VariantContent::Unit)
+        .serde_attrs(vec![SerdeAttribute::Rename("1".to_string())])
+        .build(),
+      VariantDef::builder()
+        .name(EnumVariantToken::new("Other"))
+        .content(VariantContent::Tuple(vec![TypeRef::new(RustPrimitive::I64)]))
+        .build(),
+    ],
+    scalar_repr: Some(RustPrimitive::I64),
+    generate_display: true,
+    ..Default::default()
+  };
+  let code = EnumFragment::new(def, Visibility::Public)
+    .into_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("let value : i64 = match self { Self :: Value1 => 1i64 , Self :: Other (value) => * value , }")
+  );
+  assert!(code.contains("match value { 1i64 => Ok (Self :: Value1) , other => Ok (Self :: Other (other)) , }"));
+  assert!(code.contains("\"1\" => Ok (Self :: Value1) , _ => s . parse () . map (Self :: Other)"));
+  assert!(code.contains("Self :: Other (v) => write ! (f , \"{v}\")"));
+  assert!(!code.contains("unknown variant {}"));
+}
+
 #[test]
 fn test_case_insensitive_enum_deserialize_only() {
   let def = EnumDef {
@@ -443,7 +602,7 @@ fn test_case_insensitive_enum_deserialize_only() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -504,9 +663,7 @@ fn test_enum_visibility() {
       Visibility::Public => "Public",
     };
     let def = make_simple_enum(name, vec![make_unit_variant("A"), make_unit_variant("B")]);
-    let code = EnumFragment::new(def, visibility, GenerationTarget::Client)
-      .into_token_stream()
-      .to_string();
+    let code = EnumFragment::new(def, visibility).into_token_stream().to_string();
 
     if should_contain {
       assert!(code.contains(pattern), "should have {msg}");
@@ -542,7 +699,7 @@ fn test_enum_constructor_methods() {
     ..Default::default()
   };
 
-  let simple_code = EnumFragment::new(simple_def, Visibility::Public, GenerationTarget::Client)
+  let simple_code = EnumFragment::new(simple_def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(simple_code.contains("impl RequestBody"), "should have impl block");
@@ -581,7 +738,7 @@ fn test_enum_constructor_methods() {
     ..Default::default()
   };
 
-  let param_code = EnumFragment::new(param_def, Visibility::Public, GenerationTarget::Client)
+  let param_code = EnumFragment::new(param_def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(
@@ -620,7 +777,7 @@ fn test_enum_constructor_methods_without_docs() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(code.contains("pub fn json () -> Self"), "should have json constructor");
@@ -674,7 +831,7 @@ fn test_known_value_constructor_methods() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
   assert!(
@@ -880,6 +1037,9 @@ fn test_response_enum_generation() {
     ],
     request_type: Some(StructToken::new("GetUserRequest")),
     try_from: vec![],
+    malformed_variant: None,
+    status_classes: BTreeMap::new(),
+    skip_clone: false,
   };
 
   let code = ResponseEnumFragment::new(Visibility::Public, def)
@@ -910,6 +1070,222 @@ fn test_response_enum_generation() {
   }
 }
 
+#[test]
+fn test_response_enum_with_malformed_variant() {
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("GetUserResponse"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Ok200)
+        .variant_name(EnumVariantToken::new("Ok"))
+        .media_types(vec![ResponseMediaType::new("application/json")])
+        .schema_type(TypeRef::new(RustPrimitive::Custom("User".into())))
+        .build(),
+    ])
+    .malformed_variant(EnumVariantToken::new("Malformed"))
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("Ok (User) , # [doc = \"Response body did not match its declared schema\"] Malformed (oas3_gen_support :: MalformedResponse)"),
+    "should append Malformed variant after declared variants: {code}"
+  );
+}
+
+#[test]
+fn test_response_enum_classifies_documented_statuses() {
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("GetUserResponse"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Ok200)
+        .variant_name(EnumVariantToken::new("Ok"))
+        .build(),
+    ])
+    .status_classes(BTreeMap::from([
+      (StatusCodeToken::Ok200, ResponseClass::Success),
+      (StatusCodeToken::Conflict409, ResponseClass::Retryable),
+      (StatusCodeToken::ServerError5XX, ResponseClass::ServerError),
+      (StatusCodeToken::ClientError4XX, ResponseClass::Retryable),
+      (StatusCodeToken::Default, ResponseClass::Success),
+    ]))
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  let assertions = [
+    (
+      "pub fn classify (status : http :: StatusCode) -> oas3_gen_support :: ResponseClass",
+      "should declare classify",
+    ),
+    (
+      "match status . as_u16 () { 200 => oas3_gen_support :: ResponseClass :: Success , 409 => oas3_gen_support :: ResponseClass :: Retryable , 400 ..= 499 => oas3_gen_support :: ResponseClass :: Retryable , _ => oas3_gen_support :: ResponseClass :: from (status) , }",
+      "should list codes before retryable wildcards and fall back to the status",
+    ),
+  ];
+  for (expected, msg) in assertions {
+    assert!(code.contains(expected), "{msg}: {code}");
+  }
+  assert!(
+    !code.contains("500 ..= 599"),
+    "should leave non-retryable wildcards to the fallback"
+  );
+}
+
+#[test]
+fn test_response_enum_emits_named_event_enum() {
+  let named_events = NamedEventsDef {
+    name: EnumToken::new("MessagesEvent"),
+    events: vec![NamedEventVariant {
+      event: "message_start".to_string(),
+      variant: EnumVariantToken::new("MessageStart"),
+      schema_type: TypeRef::new(RustPrimitive::Custom("MessageStart".into())),
+    }],
+    skip_clone: false,
+  };
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("MessagesResponse"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Ok200)
+        .variant_name(EnumVariantToken::new("Ok"))
+        .media_types(vec![
+          ResponseMediaType::new("text/event-stream").with_named_events(named_events),
+        ])
+        .schema_type(TypeRef::new("oas3_gen_support::EventStream<MessagesEvent>"))
+        .build(),
+    ])
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  let assertions = [
+    ("pub enum MessagesEvent", "should declare the event enum"),
+    ("MessageStart (MessageStart)", "should have a variant per named event"),
+    (
+      "Unknown { event : String , data : String }",
+      "should keep unlisted events",
+    ),
+    (
+      "impl oas3_gen_support :: NamedEvent for MessagesEvent",
+      "should decode by event name",
+    ),
+    (
+      "\"message_start\" => oas3_gen_support :: parse_event_data (data) . map (Self :: MessageStart)",
+      "should parse each event's data as its schema",
+    ),
+  ];
+  for (expected, msg) in assertions {
+    assert!(code.contains(expected), "{msg}: {code}");
+  }
+}
+
+#[test]
+fn test_response_enum_default_variant_carries_status() {
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("GetUserResponse"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Default)
+        .variant_name(EnumVariantToken::new("Unknown"))
+        .media_types(vec![ResponseMediaType::new("application/json")])
+        .schema_type(TypeRef::new(RustPrimitive::Custom("Error".into())))
+        .build(),
+    ])
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("Unknown { status : http :: StatusCode , body : Error }"),
+    "typed default variant should carry the status code: {code}"
+  );
+}
+
+#[test]
+fn test_response_enum_page_links_follow_request() {
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("ListReposResponse"))
+    .request_type(StructToken::new("ListReposRequest"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Ok200)
+        .variant_name(EnumVariantToken::new("Ok"))
+        .media_types(vec![ResponseMediaType::new("application/json")])
+        .schema_type(TypeRef::new(RustPrimitive::Custom("Repo".into())).with_vec())
+        .page_links(true)
+        .build(),
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::NotFound404)
+        .variant_name(EnumVariantToken::new("NotFound"))
+        .build(),
+    ])
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  let assertions = [
+    (
+      "Ok { links : oas3_gen_support :: PageLinks , body : Vec < Repo > }",
+      "the linked variant should carry its page links",
+    ),
+    (
+      "Self :: Ok { links , .. } => Some (links) , _ => None ,",
+      "links() should fall back to None for other statuses",
+    ),
+    (
+      "pub fn next (& self , request : ListReposRequest) -> Option < ListReposRequest >",
+      "next() should build the follow-up request",
+    ),
+    (
+      "Some (ListReposRequest { page_link : Some (link) , .. request })",
+      "the follow-up request should target the link",
+    ),
+    (
+      "pub fn prev (& self , request : ListReposRequest)",
+      "prev() should mirror next()",
+    ),
+  ];
+  for (expected, msg) in assertions {
+    assert!(code.contains(expected), "{msg}: {code}");
+  }
+}
+
+#[test]
+fn test_rate_limited_variant_carries_retry_after() {
+  let def = ResponseEnumDef::builder()
+    .name(EnumToken::new("GetItemResponse"))
+    .variants(vec![
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::TooManyRequests429)
+        .variant_name(EnumVariantToken::new("RateLimited"))
+        .schema_type(TypeRef::new(RustPrimitive::Custom("Error".into())))
+        .retry_after(true)
+        .build(),
+    ])
+    .build();
+
+  let code = ResponseEnumFragment::new(Visibility::Public, def)
+    .into_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("RateLimited { retry_after : Option < std :: time :: Duration > , body : Error }"),
+    "the rate-limited variant should carry the Retry-After delay: {code}"
+  );
+}
+
 #[test]
 fn test_relaxed_wrapper_enum_generates_display() {
   let def = EnumDef {
@@ -928,7 +1304,7 @@ fn test_relaxed_wrapper_enum_generates_display() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -970,7 +1346,7 @@ fn test_non_simple_enum_without_generate_display_has_no_display() {
     ..Default::default()
   };
 
-  let code = EnumFragment::new(def, Visibility::Public, GenerationTarget::Client)
+  let code = EnumFragment::new(def, Visibility::Public)
     .into_token_stream()
     .to_string();
 
@@ -0,0 +1,49 @@
+use crate::generator::{
+  codegen::{GeneratedFileType, SourceText, formatting::SourceFormatter},
+  converter::OutputFormatPolicy,
+  metrics::GenerationWarning,
+};
+
+fn source(code: &str) -> SourceText {
+  SourceText {
+    preamble: String::new(),
+    header: String::new(),
+    code: code.to_string(),
+  }
+}
+
+#[test]
+fn test_output_format_policies() {
+  let cases = [
+    (OutputFormatPolicy::None, "\npub struct Pet { pub id : i64 , }\n"),
+    (
+      OutputFormatPolicy::Prettyplease,
+      "\npub struct Pet {\n    pub id: i64,\n}\n\n",
+    ),
+  ];
+  for (policy, expected) in cases {
+    let formatter = SourceFormatter::new(policy);
+    let formatted = formatter.format("types.rs", &source("pub struct Pet { pub id : i64 , }"));
+    assert_eq!(formatted, expected, "{policy:?}");
+    assert!(formatter.take_failures().is_empty(), "{policy:?}");
+  }
+}
+
+#[test]
+fn test_unparsable_source_is_written_unformatted() {
+  let formatter = SourceFormatter::new(OutputFormatPolicy::Prettyplease);
+  let formatted = formatter.format_all(vec![
+    (GeneratedFileType::Types, source("pub struct Pet { pub id : i64 , }")),
+    (GeneratedFileType::Tag("pets".to_string()), source("pub struct {")),
+  ]);
+
+  assert_eq!(formatted[0].1, "\npub struct Pet {\n    pub id: i64,\n}\n\n");
+  assert_eq!(formatted[1].1, "\npub struct {\n");
+  let failures = formatter.take_failures();
+  assert_eq!(failures.len(), 1);
+  assert!(
+    matches!(&failures[0], GenerationWarning::FormattingFailed { file, .. } if file == "pets.rs"),
+    "{failures:?}"
+  );
+  assert!(formatter.take_failures().is_empty(), "failures are reset once taken");
+}
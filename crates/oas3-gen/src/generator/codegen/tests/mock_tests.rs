@@ -0,0 +1,217 @@
+use quote::ToTokens;
+use serde_json::json;
+
+use crate::generator::{
+  ast::{
+    EnumDef, EnumToken, EnumVariantToken, FieldDef, FieldNameToken, MethodNameToken, ResponseEnumDef,
+    ResponseMediaType, ResponseVariant, RustType, SerdeAttribute, ServerRequestTraitDef, ServerTraitMethod,
+    StatusCodeToken, StructDef, StructToken, TraitToken, TypeRef, VariantContent, VariantDef,
+  },
+  codegen::{Visibility, mock::MockServerFragment},
+};
+
+fn format_tokens(tokens: proc_macro2::TokenStream) -> String {
+  prettyplease::unparse(&syn::parse2(tokens).unwrap())
+}
+
+fn pet_types(response_example: Option<serde_json::Value>) -> Vec<RustType> {
+  let pet = StructDef {
+    name: StructToken::new("Pet"),
+    fields: vec![
+      FieldDef::builder()
+        .name(FieldNameToken::new("id"))
+        .rust_type(TypeRef::new("i64"))
+        .example_value(json!(7))
+        .build(),
+      FieldDef::builder()
+        .name(FieldNameToken::new("name"))
+        .rust_type(TypeRef::new("String"))
+        .example_value(json!("Fido"))
+        .build(),
+      FieldDef::builder()
+        .name(FieldNameToken::new("status"))
+        .rust_type(TypeRef::new("PetStatus").with_option())
+        .example_value(json!("sold"))
+        .build(),
+      FieldDef::builder()
+        .name(FieldNameToken::new("tag"))
+        .rust_type(TypeRef::new("String").with_option())
+        .build(),
+    ],
+    ..Default::default()
+  };
+  let status = EnumDef {
+    name: EnumToken::new("PetStatus"),
+    variants: [("Available", "available"), ("Sold", "sold")]
+      .into_iter()
+      .map(|(name, value)| {
+        VariantDef::builder()
+          .name(EnumVariantToken::new(name))
+          .content(VariantContent::Unit)
+          .serde_attrs(vec![SerdeAttribute::Rename(value.to_string())])
+          .build()
+      })
+      .collect(),
+    ..Default::default()
+  };
+  let response = ResponseEnumDef {
+    name: EnumToken::new("GetPetResponse"),
+    variants: vec![
+      ResponseVariant::builder()
+        .variant_name(EnumVariantToken::new("NotFound"))
+        .status_code(StatusCodeToken::NotFound404)
+        .build(),
+      ResponseVariant::builder()
+        .variant_name(EnumVariantToken::new("Ok"))
+        .status_code(StatusCodeToken::Ok200)
+        .media_types(vec![
+          ResponseMediaType::with_schema("application/json", Some(TypeRef::new("Pet"))).with_example(response_example),
+        ])
+        .schema_type(TypeRef::new("Pet"))
+        .build(),
+    ],
+    ..Default::default()
+  };
+  let delete = ResponseEnumDef {
+    name: EnumToken::new("DeletePetResponse"),
+    variants: vec![
+      ResponseVariant::builder()
+        .variant_name(EnumVariantToken::new("NoContent"))
+        .status_code(StatusCodeToken::NoContent204)
+        .build(),
+    ],
+    ..Default::default()
+  };
+  vec![
+    RustType::Struct(pet),
+    RustType::Enum(status),
+    RustType::ResponseEnum(response),
+    RustType::ResponseEnum(delete),
+  ]
+}
+
+fn server_trait() -> ServerRequestTraitDef {
+  ServerRequestTraitDef::builder()
+    .name(TraitToken::new("ApiServer"))
+    .methods(vec![
+      ServerTraitMethod::builder()
+        .name(MethodNameToken::new("get_pet"))
+        .request_type(StructToken::new("GetPetRequest"))
+        .response_type(EnumToken::new("GetPetResponse"))
+        .http_method(http::Method::GET)
+        .path(Default::default())
+        .build(),
+      ServerTraitMethod::builder()
+        .name(MethodNameToken::new("delete_pet"))
+        .response_type(EnumToken::new("DeletePetResponse"))
+        .http_method(http::Method::DELETE)
+        .path(Default::default())
+        .build(),
+    ])
+    .build()
+}
+
+#[test]
+fn test_mock_server_builds_success_responses_from_field_examples() {
+  let types = pet_types(None);
+  let def = server_trait();
+  let code = format_tokens(MockServerFragment::new(Some(&def), &types, Visibility::Public).into_token_stream());
+
+  assert!(code.contains("pub struct MockServer;"), "missing mock struct:\n{code}");
+  assert!(
+    code.contains("impl ApiServer for MockServer"),
+    "missing trait impl:\n{code}"
+  );
+  assert!(
+    code.contains("async fn get_pet(&self, _request: GetPetRequest) -> anyhow::Result<GetPetResponse>"),
+    "missing get_pet signature:\n{code}"
+  );
+  assert!(
+    code.contains("GetPetResponse::Ok(Pet {"),
+    "should answer with the 200 response:\n{code}"
+  );
+  assert!(code.contains("id: 7i64,"), "missing id example:\n{code}");
+  assert!(
+    code.contains("name: \"Fido\".to_string(),"),
+    "missing name example:\n{code}"
+  );
+  assert!(
+    code.contains("status: Some(PetStatus::Sold),"),
+    "enum example should map to its variant:\n{code}"
+  );
+  assert!(
+    !code.contains("tag:"),
+    "fields without examples keep their defaults:\n{code}"
+  );
+  assert!(code.contains("..Default::default()"), "missing default fill:\n{code}");
+  assert!(
+    code.contains("Ok(DeletePetResponse::NoContent)"),
+    "bodiless response should be a unit variant:\n{code}"
+  );
+}
+
+#[test]
+fn test_mock_server_prefers_response_example() {
+  let types = pet_types(Some(json!({ "id": 1, "name": "Rex", "tag": "good" })));
+  let def = server_trait();
+  let code = format_tokens(MockServerFragment::new(Some(&def), &types, Visibility::Public).into_token_stream());
+
+  assert!(code.contains("id: 1i64,"), "response example should win:\n{code}");
+  assert!(
+    code.contains("name: \"Rex\".to_string(),"),
+    "response example should win:\n{code}"
+  );
+  assert!(
+    code.contains("tag: Some(\"good\".to_string()),"),
+    "missing optional field:\n{code}"
+  );
+  assert!(
+    !code.contains("status:"),
+    "fields absent from the response example keep their defaults:\n{code}"
+  );
+}
+
+#[test]
+fn test_mock_server_answers_event_streams_with_an_error() {
+  let mut types = pet_types(None);
+  types.push(RustType::ResponseEnum(ResponseEnumDef {
+    name: EnumToken::new("WatchPetsResponse"),
+    variants: vec![
+      ResponseVariant::builder()
+        .variant_name(EnumVariantToken::new("Ok"))
+        .status_code(StatusCodeToken::Ok200)
+        .media_types(vec![ResponseMediaType::with_schema(
+          "text/event-stream",
+          Some(TypeRef::new("Pet")),
+        )])
+        .schema_type(TypeRef::new("axum::response::Response"))
+        .build(),
+    ],
+    ..Default::default()
+  }));
+  let def = ServerRequestTraitDef::builder()
+    .name(TraitToken::new("ApiServer"))
+    .methods(vec![
+      ServerTraitMethod::builder()
+        .name(MethodNameToken::new("watch_pets"))
+        .response_type(EnumToken::new("WatchPetsResponse"))
+        .http_method(http::Method::GET)
+        .path(Default::default())
+        .build(),
+    ])
+    .build();
+  let code = format_tokens(MockServerFragment::new(Some(&def), &types, Visibility::Public).into_token_stream());
+
+  assert!(
+    code.contains(r#"Err(anyhow::anyhow!("MockServer has no example event stream for `watch_pets`"))"#),
+    "event streams cannot be built from examples:\n{code}"
+  );
+  assert!(!code.contains("Default::default()"), "{code}");
+}
+
+#[test]
+fn test_mock_server_without_server_trait_is_empty() {
+  let types = pet_types(None);
+  let tokens = MockServerFragment::new(None, &types, Visibility::Public).into_token_stream();
+  assert!(tokens.is_empty());
+}
@@ -2,6 +2,10 @@ mod client;
 mod coercion_tests;
 mod constants_tests;
 mod enum_tests;
+mod formatting_tests;
+mod mock_tests;
 mod module_uses_tests;
+mod public_api_tests;
 mod struct_tests;
 mod type_alias_tests;
+mod workspace_tests;
@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::generator::codegen::{GeneratedFileType, GeneratedResult, public_api::public_api_report};
+
+const MOD_FILE: &str = r"
+mod types;
+mod client;
+pub use types::*;
+pub use client::*;
+pub mod prelude {
+  pub use super::{Client, Pet};
+}
+";
+
+const TYPES_FILE: &str = r#"
+#[derive(Debug, Clone)]
+pub struct Pet {
+  pub id: i64,
+  pub tags: Option<Vec<String>>,
+  #[doc(hidden)]
+  pub r#type: Option<&'static str>,
+  secret: String,
+}
+#[derive(Debug)]
+pub enum Status {
+  Available,
+  Sold(Pet),
+  #[doc(hidden)]
+  Hidden,
+}
+pub type Pets = Vec<Pet>;
+impl From<&Pet> for Status {
+  fn from(value: &Pet) -> Self {
+    Self::Sold(value.clone())
+  }
+}
+pub(crate) struct Internal;
+"#;
+
+const CLIENT_FILE: &str = r#"
+pub const BASE_URL: &str = "https://example.com";
+pub struct Client;
+impl Client {
+  pub async fn get_pet(&self, id: i64) -> anyhow::Result<Pet> {
+    todo!()
+  }
+  fn helper(&self) {}
+}
+"#;
+
+#[test]
+fn test_public_api_report_lists_reachable_items() {
+  let result = GeneratedResult::full_client(MOD_FILE.to_string(), CLIENT_FILE.to_string(), TYPES_FILE.to_string());
+  let report = public_api_report(&result).unwrap();
+
+  assert_eq!(
+    report,
+    "\
+pub const BASE_URL: &str
+pub struct Client
+pub async fn Client::get_pet(&self, id: i64) -> anyhow::Result<Pet>
+pub struct Pet
+pub Pet::id: i64
+pub Pet::tags: Option<Vec<String>>
+impl Clone for Pet
+impl Debug for Pet
+pub type Pets = Vec<Pet>
+pub enum Status
+pub Status::Available
+pub Status::Sold(Pet)
+impl Debug for Status
+impl From<&Pet> for Status
+pub mod prelude
+pub use prelude::Client
+pub use prelude::Pet
+"
+  );
+}
+
+#[test]
+fn test_public_api_report_names_public_modules_and_crates() {
+  let mut root = GeneratedResult::full_client(
+    "pub mod types;\nmod client;".to_string(),
+    "pub struct Client;".to_string(),
+    "pub struct Pet;".to_string(),
+  );
+  root.modules = BTreeMap::from([(
+    "api-types".to_string(),
+    GeneratedResult {
+      code: [(GeneratedFileType::Library, "pub struct Order;".to_string())].into(),
+      modules: BTreeMap::new(),
+      fingerprints: HashMap::new(),
+    },
+  )]);
+
+  let report = public_api_report(&root).unwrap();
+  assert_eq!(
+    report,
+    "pub struct api_types::Order\npub mod types\npub struct types::Pet\n"
+  );
+}
@@ -4,11 +4,17 @@ use quote::ToTokens as _;
 
 use crate::generator::{
   ast::{
-    ContentCategory, DeriveTrait, Documentation, EnumToken, EnumVariantToken, FieldDef, FieldNameToken, MethodKind,
-    MethodNameToken, ResponseMediaType, ResponseStatusCategory, ResponseVariant, ResponseVariantCategory,
-    StatusCodeToken, StatusHandler, StructDef, StructKind, StructMethod, StructToken, TypeRef, ValidationAttribute,
+    BuilderField, BuilderNestedStruct, ContentCategory, DeriveTrait, Documentation, EnumToken, EnumVariantToken,
+    ErrorSourceField, FieldDef, FieldNameToken, MessageFieldAccess, MethodKind, MethodNameToken, NamedEventsDef,
+    ObjectStyle, ParentConversion, ResponseMediaType, ResponseStatusCategory, ResponseVariant, ResponseVariantCategory,
+    RustPrimitive, StatusCodeToken, StatusHandler, StructDef, StructKind, StructMethod, StructToken, TypeRef,
+    ValidationAttribute,
+  },
+  codegen::{
+    Visibility,
+    attributes::generate_docs_for_field,
+    structs::{BuilderMethodFragment, StructFragment},
   },
-  codegen::{Visibility, structs::StructFragment},
   converter::GenerationTarget,
 };
 
@@ -52,6 +58,7 @@ fn make_response_parser_struct(variant: ResponseVariant) -> StructDef {
         dispatch: ResponseStatusCategory::Single(ResponseVariantCategory { category, variant }),
       }],
       default_handler: None,
+      malformed_variant: None,
     },
   });
   def
@@ -88,6 +95,99 @@ fn test_validation_attribute_generation() {
   }
 }
 
+#[test]
+fn test_field_docs_summarize_constraints() {
+  let number = |n: i64| Some(serde_json::Number::from(n));
+  let cases = [
+    (
+      TypeRef::new("String"),
+      vec![
+        ValidationAttribute::Length {
+          min: Some(1),
+          max: Some(64),
+        },
+        ValidationAttribute::Regex("^[a-z]+$".to_string()),
+      ],
+      None,
+      Some(serde_json::json!("foo")),
+      "- Constraints: 1–64 chars, pattern `^[a-z]+$`, default `foo`",
+    ),
+    (
+      TypeRef::new("String").with_vec(),
+      vec![ValidationAttribute::Length {
+        min: None,
+        max: Some(5),
+      }],
+      None,
+      None,
+      "- Constraints: at most 5 items",
+    ),
+    (
+      TypeRef::new("String"),
+      vec![ValidationAttribute::Length {
+        min: Some(1),
+        max: None,
+      }],
+      None,
+      None,
+      "- Constraints: at least 1 char",
+    ),
+    (
+      TypeRef::new("i64"),
+      vec![ValidationAttribute::Range {
+        primitive: RustPrimitive::I64,
+        min: number(1),
+        max: number(100),
+        exclusive_min: None,
+        exclusive_max: None,
+      }],
+      number(5),
+      Some(serde_json::json!(10)),
+      "- Constraints: 1–100, multiple of 5, default `10`",
+    ),
+    (
+      TypeRef::new("f64"),
+      vec![ValidationAttribute::Range {
+        primitive: RustPrimitive::F64,
+        min: None,
+        max: number(1),
+        exclusive_min: number(0),
+        exclusive_max: None,
+      }],
+      None,
+      None,
+      "- Constraints: > 0 and <= 1",
+    ),
+    (
+      TypeRef::new("String"),
+      vec![ValidationAttribute::Email],
+      None,
+      None,
+      "- Constraints: email",
+    ),
+  ];
+  for (rust_type, validation_attrs, multiple_of, default_value, expected) in cases {
+    let field = FieldDef::builder()
+      .name(FieldNameToken::new("field"))
+      .rust_type(rust_type)
+      .validation_attrs(validation_attrs)
+      .maybe_multiple_of(multiple_of)
+      .maybe_default_value(default_value)
+      .build();
+    assert_eq!(
+      generate_docs_for_field(&field),
+      vec![expected.to_string()],
+      "{expected}"
+    );
+  }
+
+  let unconstrained = FieldDef::builder()
+    .name(FieldNameToken::new("field"))
+    .rust_type(TypeRef::new("String"))
+    .build();
+  assert_eq!(generate_docs_for_field(&unconstrained), Vec::<String>::new());
+}
+
 #[test]
 fn renders_response_parser_method() {
   let def = make_response_parser_struct(
@@ -161,6 +261,46 @@ fn renders_json_parser_for_custom_struct() {
   assert!(code.contains("MyStruct"), "missing MyStruct type");
 }
 
+#[test]
+fn renders_malformed_capture_for_json_xml_and_yaml_bodies() {
+  let cases = [
+    ("application/json", "MalformedResponse :: json :: < MyStruct > (req)"),
+    ("application/xml", "MalformedResponse :: xml :: < MyStruct > (req)"),
+    ("application/yaml", "MalformedResponse :: yaml :: < MyStruct > (req)"),
+  ];
+  for (content_type, expected_call) in cases {
+    let mut def = make_response_parser_struct(
+      ResponseVariant::builder()
+        .status_code(StatusCodeToken::Ok200)
+        .variant_name(EnumVariantToken::new("Ok"))
+        .media_types(vec![ResponseMediaType::with_schema(
+          content_type,
+          Some(TypeRef::new("MyStruct")),
+        )])
+        .schema_type(TypeRef::new("MyStruct"))
+        .build(),
+    );
+    if let MethodKind::ParseResponse { malformed_variant, .. } = &mut def.methods[0].kind {
+      *malformed_variant = Some(EnumVariantToken::new("Malformed"));
+    }
+    let code = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client)
+      .into_token_stream()
+      .to_string();
+    assert!(
+      code.contains(expected_call),
+      "{content_type}: missing malformed-capturing call in {code}"
+    );
+    assert!(
+      code.contains("Err (malformed) => return Ok (ResponseEnum :: Malformed (malformed))"),
+      "{content_type}: missing Malformed variant return in {code}"
+    );
+    assert!(
+      !code.contains("_with_diagnostics"),
+      "{content_type}: diagnostics call should be replaced in {code}"
+    );
+  }
+}
+
 #[test]
 fn test_binary_response_parsing() {
   let def = make_response_parser_struct(
@@ -231,6 +371,32 @@ fn test_event_stream_response_generates_from_response() {
   );
 }
 
+#[test]
+fn test_named_event_stream_response_generates_from_named_events() {
+  let named_events = NamedEventsDef {
+    name: EnumToken::new("StreamEventsEvent"),
+    events: vec![],
+    skip_clone: false,
+  };
+  let def = make_response_parser_struct(
+    ResponseVariant::builder()
+      .status_code(StatusCodeToken::Ok200)
+      .variant_name(EnumVariantToken::new("Ok"))
+      .media_types(vec![
+        ResponseMediaType::new("text/event-stream").with_named_events(named_events),
+      ])
+      .schema_type(TypeRef::new("oas3_gen_support::EventStream<StreamEventsEvent>"))
+      .build(),
+  );
+  let tokens =
+    StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client).into_token_stream();
+  let code = tokens.to_string();
+  assert!(
+    code.contains("from_named_events (req)"),
+    "named event stream should decode by event name: {code}"
+  );
+}
+
 #[test]
 fn test_struct_generates_debug_and_clone() {
   let def = base_struct(StructKind::Schema);
@@ -286,6 +452,62 @@ fn test_header_params_struct_generates_try_from_header_map() {
   );
 }
 
+#[test]
+fn test_header_params_use_simple_style_for_arrays_and_objects() {
+  let header = |name: &str, original: &str, rust_type: TypeRef, object_style: Option<ObjectStyle>| {
+    FieldDef::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(rust_type.with_option())
+      .original_name(original)
+      .maybe_object_style(object_style)
+      .build()
+  };
+  let def = StructDef {
+    name: StructToken::new("RequestHeader"),
+    fields: vec![
+      header("x_tags", "X-Tags", TypeRef::new("String").with_vec(), None),
+      header("x_filter", "X-Filter", TypeRef::new("Filter"), Some(ObjectStyle::Flat)),
+      header(
+        "x_labels",
+        "X-Labels",
+        TypeRef::new("std::collections::HashMap<String, String>"),
+        Some(ObjectStyle::Exploded),
+      ),
+    ],
+    kind: StructKind::HeaderParams,
+    ..Default::default()
+  };
+
+  let client = StructFragment::new(
+    def.clone(),
+    BTreeMap::new(),
+    Visibility::Public,
+    GenerationTarget::Client,
+  )
+  .into_token_stream()
+  .to_string();
+  assert!(
+    client.contains(". join (\",\")"),
+    "arrays should be comma-joined: {client}"
+  );
+  assert!(
+    client.contains("oas3_gen_support :: simple_style (value , false)"),
+    "objects should alternate keys and values: {client}"
+  );
+  assert!(
+    client.contains("oas3_gen_support :: simple_style (value , true)"),
+    "exploded objects should be written as pairs: {client}"
+  );
+
+  let server = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Server)
+    .into_token_stream()
+    .to_string();
+  assert!(
+    server.contains("oas3_gen_support :: from_simple_style (value , true) . unwrap_or_default ()"),
+    "objects should be read back in simple style: {server}"
+  );
+}
+
 #[test]
 fn test_required_header_with_default_uses_unconditional_insert() {
   let def = StructDef {
@@ -439,3 +661,176 @@ fn test_builder_skipped_field_named_build_not_renamed() {
     "skipped field should not be renamed: {code}"
   );
 }
+
+#[test]
+fn test_builder_applies_parameter_defaults() {
+  let param = |name: &str, rust_type: TypeRef, has_default: bool| {
+    BuilderField::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(rust_type)
+      .owner_field(FieldNameToken::new("query"))
+      .has_default(has_default)
+      .build()
+  };
+  let fields = vec![
+    param("sort", TypeRef::new("PetSort"), true),
+    param("limit", TypeRef::new("i32").with_option(), true),
+    param("q", TypeRef::new("String").with_option(), false),
+  ];
+  let nested = vec![
+    BuilderNestedStruct::builder()
+      .field_name(FieldNameToken::new("query"))
+      .struct_name(StructToken::new("ListPetsRequestQuery"))
+      .field_names(vec![
+        FieldNameToken::new("sort"),
+        FieldNameToken::new("limit"),
+        FieldNameToken::new("q"),
+      ])
+      .build(),
+  ];
+  let code = BuilderMethodFragment::new(fields, nested, Visibility::Public, Documentation::default())
+    .into_token_stream()
+    .to_string();
+
+  assert!(
+    code.contains("# [builder (default = ListPetsRequestQuery :: default () . sort)] sort : PetSort"),
+    "required parameters with a default become optional builder arguments: {code}"
+  );
+  assert!(
+    code.contains("let limit = limit . or (ListPetsRequestQuery :: default () . limit) ;"),
+    "optional parameters fall back to their default: {code}"
+  );
+  assert!(
+    !code.contains("q . or"),
+    "parameters without a default stay unset: {code}"
+  );
+}
+
+#[test]
+fn test_error_impl_displays_message_field() {
+  let access = |name: &str, optional: bool| MessageFieldAccess {
+    name: FieldNameToken::new(name),
+    optional,
+  };
+  let cases = [
+    (vec![], "write ! (f , \"{self:?}\")"),
+    (
+      vec![access("error", false), access("message", false)],
+      "write ! (f , \"{}\" , self . error . message)",
+    ),
+    (
+      vec![access("error", false), access("detail", true)],
+      "match self . error . detail . as_ref () { Some (message) => write ! (f , \"{message}\") , None => write ! (f , \"{self:?}\") , }",
+    ),
+    (
+      vec![access("info", true), access("reason", false)],
+      "match self . info . as_ref () . map (| value | & value . reason)",
+    ),
+    (
+      vec![access("info", true), access("reason", true)],
+      "match self . info . as_ref () . and_then (| value | value . reason . as_ref ())",
+    ),
+  ];
+
+  for (error_message_path, expected) in cases {
+    let mut def = base_struct(StructKind::Schema);
+    def.error_impl = true;
+    def.error_message_path = error_message_path;
+    let code = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client)
+      .into_token_stream()
+      .to_string();
+
+    assert!(code.contains(expected), "expected `{expected}` in: {code}");
+    assert!(
+      code.contains("impl std :: error :: Error for Sample { }"),
+      "missing Error impl: {code}"
+    );
+  }
+}
+
+#[test]
+fn test_error_impl_returns_nested_source() {
+  let source = |optional: bool, boxed: bool, forwarded: bool| ErrorSourceField {
+    name: FieldNameToken::new("inner"),
+    optional,
+    boxed,
+    forwarded,
+  };
+  let cases = [
+    (source(false, false, false), "Some (& self . inner)"),
+    (source(false, true, false), "Some (& * self . inner)"),
+    (
+      source(true, false, false),
+      "self . inner . as_ref () . map (| source | source as & (dyn std :: error :: Error + 'static))",
+    ),
+    (
+      source(true, true, false),
+      "self . inner . as_deref () . map (| source | source as & (dyn std :: error :: Error + 'static))",
+    ),
+    (
+      source(false, false, true),
+      "std :: error :: Error :: source (& self . inner)",
+    ),
+    (
+      source(true, false, true),
+      "self . inner . as_ref () . and_then (std :: error :: Error :: source)",
+    ),
+  ];
+
+  for (error_source, expected) in cases {
+    let mut def = base_struct(StructKind::Schema);
+    def.error_impl = true;
+    def.error_source = Some(error_source);
+    let code = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client)
+      .into_token_stream()
+      .to_string();
+
+    assert!(
+      code.contains("fn source (& self) -> Option < & (dyn std :: error :: Error + 'static) >"),
+      "missing source method: {code}"
+    );
+    assert!(code.contains(expected), "expected `{expected}` in: {code}");
+  }
+}
+
+#[test]
+fn test_parent_conversions_generate_from_impls() {
+  let conversion = ParentConversion {
+    parent: StructToken::new("Parent"),
+    shared_fields: vec![
+      (FieldNameToken::new("id"), TypeRef::new("i64")),
+      (FieldNameToken::new("field"), TypeRef::new("String")),
+    ],
+    covers_parent: true,
+    from_parent: true,
+    covers_child: false,
+  };
+  let mut def = base_struct(StructKind::Schema);
+  def.parent_conversions = vec![conversion.clone()];
+
+  let code = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client)
+    .into_token_stream()
+    .to_string();
+  assert!(code.contains("impl From < & Sample > for Parent"));
+  assert!(code.contains("Self { id : value . id , field : value . field . clone () , }"));
+  assert!(
+    code.contains("impl TryFrom < Parent > for Sample { type Error = validator :: ValidationErrors ;"),
+    "children with validation rules should validate when built from the parent"
+  );
+  assert!(code.contains("Self { id : value . id , field : value . field , .. Default :: default () }"));
+
+  let mut def = base_struct(StructKind::Schema);
+  def.fields[0].validation_attrs.clear();
+  def.parent_conversions = vec![ParentConversion {
+    from_parent: false,
+    ..conversion
+  }];
+  let code = StructFragment::new(def, BTreeMap::new(), Visibility::Public, GenerationTarget::Client)
+    .into_token_stream()
+    .to_string();
+  assert!(code.contains("impl From < & Sample > for Parent"));
+  assert!(
+    !code.contains("for Sample {"),
+    "should not build the child without from_parent"
+  );
+}
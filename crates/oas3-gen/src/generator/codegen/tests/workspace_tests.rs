@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use crate::generator::{
+  ClientRuntime,
+  codegen::{GeneratedResult, verify::verification_files, workspace::WorkspaceCrates},
+};
+
+#[test]
+fn test_manifest_lists_only_referenced_crate_roots() {
+  let crates = WorkspaceCrates::from_prefix("api");
+  let cases = [
+    ("use serde::{Deserialize, Serialize};", vec!["serde"]),
+    ("fn f() -> serde_json::Value { todo!() }", vec!["serde_json"]),
+    ("use axum::http::HeaderMap;", vec![]),
+    ("struct S(my_uuid::Uuid);", vec![]),
+    (
+      "fn f() -> anyhow::Result<()> { oas3_gen_support::Diagnostics::default(); Ok(()) }",
+      vec!["anyhow", "oas3-gen-support"],
+    ),
+  ];
+
+  for (code, expected) in cases {
+    let manifest = crates.types_manifest(code, "1.2.3", ClientRuntime::Native);
+    let (_, dependencies) = manifest.split_once("[dependencies]\n").unwrap();
+    let names = dependencies
+      .lines()
+      .map(|line| line.split_once(" = ").unwrap().0)
+      .collect::<Vec<_>>();
+    assert_eq!(names, expected, "{code}");
+  }
+}
+
+#[test]
+fn test_wasm_runtime_drops_native_support_features() {
+  let crates = WorkspaceCrates::from_prefix("api");
+  let code = "fn f() -> oas3_gen_support::Diagnostics { todo!() }";
+
+  let native = crates.client_manifest(code, "1.2.3", ClientRuntime::Native);
+  assert!(native.contains("oas3-gen-support = \"1.2.3\"\n"), "{native}");
+
+  let wasm = crates.client_manifest(code, "1.2.3", ClientRuntime::Wasm);
+  assert!(
+    wasm.contains(
+      r#"oas3-gen-support = { version = "1.2.3", default-features = false, features = ["eventsource", "quick-xml", "reqwest"] }"#
+    ),
+    "{wasm}"
+  );
+}
+
+#[test]
+fn test_manifest_enables_opt_in_support_features_the_code_uses() {
+  let crates = WorkspaceCrates::from_prefix("api");
+  let code = "struct C { compression: Option<oas3_gen_support::RequestCompression> }";
+
+  let native = crates.client_manifest(code, "1.2.3", ClientRuntime::Native);
+  assert!(
+    native.contains(r#"oas3-gen-support = { version = "1.2.3", features = ["compression"] }"#),
+    "{native}"
+  );
+
+  let code = "struct Invoice { total: oas3_gen_support::Money<2>, rate: oas3_gen_support::Decimal }";
+  let native = crates.types_manifest(code, "1.2.3", ClientRuntime::Native);
+  assert!(
+    native.contains(r#"oas3-gen-support = { version = "1.2.3", features = ["decimal"] }"#),
+    "{native}"
+  );
+
+  let code = "let body = oas3_gen_support::Diagnostics::<Pet>::yaml_with_diagnostics(req).await?;";
+  let wasm = crates.client_manifest(code, "1.2.3", ClientRuntime::Wasm);
+  assert!(
+    wasm.contains(
+      r#"oas3-gen-support = { version = "1.2.3", default-features = false, features = ["eventsource", "quick-xml", "reqwest", "yaml"] }"#
+    ),
+    "{wasm}"
+  );
+}
+
+#[test]
+fn test_manifest_adds_dev_dependencies_for_generated_tests() {
+  let crates = WorkspaceCrates::from_prefix("api");
+
+  let plain = crates.types_manifest("use serde::Serialize;", "1.2.3", ClientRuntime::Native);
+  assert!(!plain.contains("[dev-dependencies]"), "{plain}");
+
+  let code =
+    "#[cfg(test)] mod t { #[tokio::test] async fn a() {} fn s() { proptest::test_runner::TestRunner::default(); } }";
+  let manifest = crates.types_manifest(code, "1.2.3", ClientRuntime::Native);
+  let (_, dev_dependencies) = manifest.split_once("\n[dev-dependencies]\n").unwrap();
+  assert_eq!(
+    dev_dependencies,
+    "proptest = \"1.7\"\ntokio = { version = \"1.53\", features = [\"macros\", \"rt\"] }\n"
+  );
+}
+
+#[test]
+fn test_verification_crate_lists_dev_dependencies() {
+  let code = GeneratedResult::types(
+    "#[cfg(test)] mod example_tests { #[tokio::test] async fn pet_example_round_trips() {} }".to_string(),
+  );
+
+  let files = verification_files(&code, "1.2.3", ClientRuntime::Native);
+
+  let (_, manifest) = files.iter().find(|(path, _)| path == Path::new("Cargo.toml")).unwrap();
+  assert!(
+    manifest.contains("\n[dev-dependencies]\ntokio = { version = \"1.53\", features = [\"macros\", \"rt\"] }\n"),
+    "{manifest}"
+  );
+}
@@ -4,16 +4,18 @@ use std::{
 };
 
 use proc_macro2::TokenStream;
-use quote::{ToTokens, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::Path;
 
 use crate::generator::{
-  ast::{RegexKey, RustType, constants::HttpHeaderRef, tokens::ConstToken},
+  ast::{RegexKey, RustType, ServerRequestTraitDef, constants::HttpHeaderRef, tokens::ConstToken},
   codegen::{
     Visibility,
-    constants::{HeaderConstantsFragment, RegexConstantsResult},
+    constants::{
+      ExclusionValidatorsFragment, HeaderConstantsFragment, MediaTypeConstantsFragment, RegexConstantsResult,
+    },
     enums::{DiscriminatedEnumFragment, EnumFragment, ResponseEnumFragment},
-    server::AxumResponseEnumFragment,
+    server::{AxumResponseEnumFragment, ServerTraitFragment},
     structs::StructFragment,
     type_aliases::TypeAliasFragment,
   },
@@ -50,7 +52,7 @@ impl ToTokens for TypeFragment {
       RustType::Struct(def) => {
         StructFragment::new(def.clone(), self.regex_lookup.clone(), self.visibility, self.target).into_token_stream()
       }
-      RustType::Enum(def) => EnumFragment::new(def.clone(), self.visibility, self.target).into_token_stream(),
+      RustType::Enum(def) => EnumFragment::new(def.clone(), self.visibility).into_token_stream(),
       RustType::TypeAlias(def) => TypeAliasFragment::new(def.clone(), self.visibility).into_token_stream(),
       RustType::DiscriminatedEnum(def) => {
         DiscriminatedEnumFragment::new(def.clone(), self.visibility).into_token_stream()
@@ -68,7 +70,10 @@ impl ToTokens for TypeFragment {
 pub(crate) struct TypesFragment {
   rust_types: Rc<Vec<RustType>>,
   header_refs: Rc<Vec<HttpHeaderRef>>,
+  media_types: MediaTypeConstantsFragment,
   uses: BTreeSet<String>,
+  module_imports: Vec<String>,
+  callback_traits: Rc<Vec<ServerRequestTraitDef>>,
   visibility: Visibility,
   target: GenerationTarget,
 }
@@ -84,32 +89,64 @@ impl TypesFragment {
     Self {
       rust_types,
       header_refs,
+      media_types: MediaTypeConstantsFragment::default(),
       uses,
+      module_imports: vec![],
+      callback_traits: Rc::default(),
       visibility,
       target,
     }
   }
+
+  /// Glob-imports the named sibling modules.
+  pub(crate) fn with_module_imports(mut self, modules: Vec<String>) -> Self {
+    self.module_imports = modules;
+    self
+  }
+
+  /// Emits the `media_types` module next to the header constants.
+  pub(crate) fn with_media_types(mut self, media_types: MediaTypeConstantsFragment) -> Self {
+    self.media_types = media_types;
+    self
+  }
+
+  /// Appends the `<Operation>Callbacks` traits after the type definitions.
+  pub(crate) fn with_callback_traits(mut self, callback_traits: Rc<Vec<ServerRequestTraitDef>>) -> Self {
+    self.callback_traits = callback_traits;
+    self
+  }
 }
 
 impl ToTokens for TypesFragment {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let use_statements = ModuleUsesFragment::new(self.uses.clone());
+    let module_imports = self.module_imports.iter().map(|module| format_ident!("{module}"));
     let regex_result = RegexConstantsResult::from_types(&self.rust_types);
+    let exclusion_validators = ExclusionValidatorsFragment::from_types(&self.rust_types);
     let header_consts = HeaderConstantsFragment::new((*self.header_refs).clone());
+    let media_types = &self.media_types;
 
     let type_tokens = self
       .rust_types
       .iter()
       .map(|ty| TypeFragment::new(ty.clone(), regex_result.lookup.clone(), self.visibility, self.target))
       .collect::<Vec<_>>();
+    let callback_traits = self
+      .callback_traits
+      .iter()
+      .map(|def| ServerTraitFragment::new(def.clone(), self.visibility));
 
     let ts = quote! {
       #use_statements
+      #(use super::#module_imports::*;)*
 
       #regex_result
+      #exclusion_validators
       #header_consts
+      #media_types
 
       #(#type_tokens)*
+      #(#callback_traits)*
     };
 
     tokens.extend(ts);
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+  GeneratedFileType, GeneratedResult,
+  workspace::{package_manifest, referenced_dependencies, referenced_dev_dependencies},
+};
+use crate::generator::converter::ClientRuntime;
+
+/// Package name of the crate that single-file and module output is checked in.
+pub const VERIFY_CRATE: &str = "oas3-gen-verify";
+
+/// Server output depends on axum, which workspace crates never reference.
+const AXUM_DEPENDENCY: &str = r#"axum = "0.8""#;
+
+/// Lays out generated output as the files of a crate that `cargo check` can build.
+///
+/// Workspace output already is one. Anything else becomes a library crate whose
+/// `lib.rs` is the `mod.rs`, or the only generated file, next to a manifest listing
+/// the dependencies the code references and the dev-dependencies its tests need. The manifest declares its own workspace so
+/// the crate builds on its own wherever it is written.
+pub fn verification_files(code: &GeneratedResult, gen_version: &str, runtime: ClientRuntime) -> Vec<(PathBuf, String)> {
+  if code.code.contains_key(&GeneratedFileType::Manifest) {
+    let mut files = tree_files(code, PathBuf::new());
+    files.sort();
+    return files;
+  }
+
+  let src = Path::new("src");
+  let root_file = if code.code.contains_key(&GeneratedFileType::Module) {
+    Some(src.join(&*GeneratedFileType::Module.file_name()))
+  } else if code.code.len() == 1 {
+    code
+      .code
+      .keys()
+      .next()
+      .map(|file_type| src.join(&*file_type.file_name()))
+  } else {
+    None
+  };
+
+  let mut files = tree_files(code, src.to_path_buf())
+    .into_iter()
+    .map(|(path, source)| {
+      if Some(&path) == root_file.as_ref() {
+        (src.join("lib.rs"), source)
+      } else {
+        (path, source)
+      }
+    })
+    .collect::<Vec<_>>();
+  let all_code = files.iter().map(|(_, source)| source.as_str()).collect::<String>();
+  let mut dependencies = referenced_dependencies(&all_code, gen_version, runtime);
+  if all_code.contains("axum::") {
+    dependencies.push(AXUM_DEPENDENCY.to_string());
+    dependencies.sort();
+  }
+  let mut manifest = package_manifest(VERIFY_CRATE, &dependencies, &referenced_dev_dependencies(&all_code));
+  manifest.push_str("\n[workspace]\n");
+  files.push((PathBuf::from("Cargo.toml"), manifest));
+  files.sort();
+  files
+}
+
+fn tree_files(code: &GeneratedResult, dir: PathBuf) -> Vec<(PathBuf, String)> {
+  let mut files = code
+    .code
+    .iter()
+    .map(|(file_type, source)| (dir.join(&*file_type.file_name()), source.clone()))
+    .collect::<Vec<_>>();
+  for (name, module) in &code.modules {
+    files.extend(tree_files(module, dir.join(name)));
+  }
+  files
+}
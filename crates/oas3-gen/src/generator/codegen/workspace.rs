@@ -0,0 +1,187 @@
+use std::fmt::Write as _;
+
+use crate::generator::converter::ClientRuntime;
+
+/// Crates that generated code may reference, with the manifest entry each one needs.
+///
+/// Versions and features match what the generated code is tested against.
+const DEPENDENCIES: &[(&str, &str)] = &[
+  ("anyhow", r#"anyhow = "1.0""#),
+  (
+    "bigdecimal",
+    r#"bigdecimal = { version = "0.4", features = ["serde"] }"#,
+  ),
+  ("bon", r#"bon = "3.9""#),
+  ("chrono", r#"chrono = { version = "0.4", features = ["serde"] }"#),
+  ("futures", r#"futures = "0.3""#),
+  ("http", r#"http = "1.4""#),
+  ("indexmap", r#"indexmap = { version = "2.14", features = ["serde"] }"#),
+  ("regex", r#"regex = "1.13""#),
+  (
+    "reqwest",
+    r#"reqwest = { version = "0.13", features = ["json", "multipart", "query", "stream"] }"#,
+  ),
+  ("serde", r#"serde = { version = "1.0", features = ["derive"] }"#),
+  ("serde_json", r#"serde_json = "1.0""#),
+  (
+    "serde_with",
+    r#"serde_with = { version = "3.21", features = ["base64", "chrono"] }"#,
+  ),
+  ("tower", r#"tower = "0.5""#),
+  ("uuid", r#"uuid = { version = "1.24", features = ["serde"] }"#),
+  (
+    "validator",
+    r#"validator = { version = "0.20", features = ["derive"] }"#,
+  ),
+];
+
+/// Crates that generated tests may reference, with the dev-dependency entry each one needs.
+const DEV_DEPENDENCIES: &[(&str, &str)] = &[
+  ("proptest", r#"proptest = "1.7""#),
+  ("tokio", r#"tokio = { version = "1.53", features = ["macros", "rt"] }"#),
+];
+
+const SUPPORT_CRATE: &str = "oas3_gen_support";
+
+/// `oas3-gen-support` features that build for `wasm32-unknown-unknown`, leaving out the
+/// native compression codecs.
+const WASM_SUPPORT_FEATURES: &[&str] = &["eventsource", "quick-xml", "reqwest"];
+
+/// Opt-in `oas3-gen-support` features, with the paths whose use in generated code needs each one.
+const OPT_IN_SUPPORT_FEATURES: &[(&str, &[&str])] = &[
+  ("compression", &["oas3_gen_support::RequestCompression"]),
+  ("decimal", &["oas3_gen_support::Decimal", "oas3_gen_support::Money"]),
+  (
+    "yaml",
+    &[
+      "yaml_with_diagnostics",
+      "MalformedResponse::yaml",
+      "oas3_gen_support::to_yaml",
+      "oas3_gen_support::from_yaml",
+    ],
+  ),
+];
+
+/// Names of the two crates generated by workspace mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceCrates {
+  pub types: String,
+  pub client: String,
+}
+
+impl WorkspaceCrates {
+  /// Derives `<prefix>-types` and `<prefix>-client` from a crate name prefix.
+  pub fn from_prefix(prefix: &str) -> Self {
+    Self {
+      types: format!("{prefix}-types"),
+      client: format!("{prefix}-client"),
+    }
+  }
+
+  /// Renders the root `Cargo.toml` that lists both crates as workspace members.
+  pub fn workspace_manifest(&self) -> String {
+    format!(
+      "[workspace]\nmembers = [\"{}\", \"{}\"]\nresolver = \"3\"\n",
+      self.types, self.client
+    )
+  }
+
+  /// Renders the `Cargo.toml` of the types crate.
+  pub fn types_manifest(&self, code: &str, gen_version: &str, runtime: ClientRuntime) -> String {
+    package_manifest(
+      &self.types,
+      &referenced_dependencies(code, gen_version, runtime),
+      &referenced_dev_dependencies(code),
+    )
+  }
+
+  /// Renders the `Cargo.toml` of the client crate, which depends on the types crate by path.
+  pub fn client_manifest(&self, code: &str, gen_version: &str, runtime: ClientRuntime) -> String {
+    let mut dependencies = vec![format!(r#"{} = {{ path = "../{}" }}"#, self.types, self.types)];
+    dependencies.extend(referenced_dependencies(code, gen_version, runtime));
+    package_manifest(&self.client, &dependencies, &referenced_dev_dependencies(code))
+  }
+}
+
+pub(super) fn package_manifest(name: &str, dependencies: &[String], dev_dependencies: &[String]) -> String {
+  let mut manifest =
+    format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n");
+  for dependency in dependencies {
+    let _ = writeln!(manifest, "{dependency}");
+  }
+  if !dev_dependencies.is_empty() {
+    manifest.push_str("\n[dev-dependencies]\n");
+    for dependency in dev_dependencies {
+      let _ = writeln!(manifest, "{dependency}");
+    }
+  }
+  manifest
+}
+
+/// Returns dev-dependency entries for every crate the generated tests reference in `code`.
+pub(super) fn referenced_dev_dependencies(code: &str) -> Vec<String> {
+  DEV_DEPENDENCIES
+    .iter()
+    .filter(|(name, _)| references_crate(code, name))
+    .map(|(_, entry)| (*entry).to_string())
+    .collect()
+}
+
+/// Returns manifest entries for every known crate whose path appears in `code`.
+///
+/// `oas3-gen-support` enables the opt-in features the code uses. For the wasm runtime,
+/// it drops its default features for the browser-compatible set.
+pub(super) fn referenced_dependencies(code: &str, gen_version: &str, runtime: ClientRuntime) -> Vec<String> {
+  let mut dependencies = DEPENDENCIES
+    .iter()
+    .filter(|(name, _)| references_crate(code, name))
+    .map(|(_, entry)| (*entry).to_string())
+    .collect::<Vec<_>>();
+  if references_crate(code, SUPPORT_CRATE) {
+    let opt_in = OPT_IN_SUPPORT_FEATURES
+      .iter()
+      .filter(|(_, paths)| paths.iter().any(|path| code.contains(path)))
+      .map(|(feature, _)| *feature);
+    dependencies.push(match runtime {
+      ClientRuntime::Native => {
+        let features = opt_in.collect::<Vec<_>>();
+        if features.is_empty() {
+          format!(r#"oas3-gen-support = "{gen_version}""#)
+        } else {
+          format!(
+            r#"oas3-gen-support = {{ version = "{gen_version}", features = {} }}"#,
+            feature_list(features)
+          )
+        }
+      }
+      ClientRuntime::Wasm => format!(
+        r#"oas3-gen-support = {{ version = "{gen_version}", default-features = false, features = {} }}"#,
+        feature_list(WASM_SUPPORT_FEATURES.iter().copied().chain(opt_in).collect())
+      ),
+    });
+    dependencies.sort();
+  }
+  dependencies
+}
+
+/// Renders features as a sorted, deduplicated TOML array.
+fn feature_list(mut features: Vec<&str>) -> String {
+  features.sort_unstable();
+  features.dedup();
+  let quoted = features
+    .iter()
+    .map(|feature| format!("\"{feature}\""))
+    .collect::<Vec<_>>();
+  format!("[{}]", quoted.join(", "))
+}
+
+/// Returns whether `code` contains a path rooted at `crate_name`, such as `serde::Serialize`.
+fn references_crate(code: &str, crate_name: &str) -> bool {
+  let needle = format!("{crate_name}::");
+  code.match_indices(&needle).any(|(index, _)| {
+    !code[..index]
+      .chars()
+      .next_back()
+      .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ':')
+  })
+}
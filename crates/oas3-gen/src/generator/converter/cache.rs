@@ -463,6 +463,21 @@ impl SharedSchemaCache {
     Ok(!self.schemas.has_mapping(&canonical, name))
   }
 
+  /// Adds the names, schema mappings, enums, unions, structs, and type references
+  /// `other` registered, keeping this cache's entry wherever both have one.
+  ///
+  /// Types are left out; take them from `other` first.
+  pub(crate) fn absorb(&mut self, other: Self) {
+    self.names.used_names.extend(other.names.used_names);
+    absorb_entries(&mut self.schemas.schema_to_type, other.schemas.schema_to_type);
+    absorb_entries(&mut self.enums.value_sets_to_type, other.enums.value_sets_to_type);
+    for (key, name) in other.unions.union_keys_to_type {
+      self.unions.union_keys_to_type.entry(key).or_insert(name);
+    }
+    absorb_entries(&mut self.structs.structs, other.structs.structs);
+    absorb_entries(&mut self.type_refs.resolved_types, other.type_refs.resolved_types);
+  }
+
   /// Takes all accumulated type definitions, leaving the cache empty for reuse.
   pub(crate) fn take_types(&mut self) -> Vec<RustType> {
     self.types.take_types()
@@ -537,3 +552,9 @@ impl SharedSchemaCache {
     &self.union_fingerprints
   }
 }
+
+fn absorb_entries<K: Ord, V>(into: &mut BTreeMap<K, V>, from: BTreeMap<K, V>) {
+  for (key, value) in from {
+    into.entry(key).or_insert(value);
+  }
+}
@@ -92,7 +92,8 @@ impl DiscriminatorConverter {
   /// redundancy (e.g., parent "Pet" with child "PetCat" yields variant "Cat").
   ///
   /// Uses the effective mapping from the schema registry, which handles both
-  /// explicit mappings and implicit mappings synthesized from `const` values.
+  /// explicit mappings and implicit mappings synthesized from `const` values or
+  /// schema names.
   ///
   /// Unreachable schemas (filtered by operation selection) are excluded.
   /// Returns an empty vector if no effective mapping is available.
@@ -142,7 +143,7 @@ impl DiscriminatorConverter {
   ///
   /// Returns `Some(RustType::DiscriminatedEnum)` if:
   /// - The schema has a discriminator
-  /// - An effective mapping is available (explicit or synthesized from `const`)
+  /// - An effective mapping is available (explicit or implicit)
   /// - Every mapping entry has a corresponding variant in the provided list
   ///
   /// Returns `None` if conversion is not possible, indicating the original union
@@ -12,13 +12,15 @@ use super::{ConversionOutput, type_resolver::TypeResolver};
 use crate::{
   generator::{
     ast::{
-      FieldDef, FieldNameToken, RustPrimitive, RustType, SerdeAsFieldAttr, SerdeAttribute, StructKind, TypeRef,
-      ValidationAttribute,
+      FieldAccess, FieldDef, FieldNameToken, RustPrimitive, RustType, SerdeAsFieldAttr, SerdeAttribute, StructKind,
+      TypeRef, ValidationAttribute,
     },
     converter::ConverterContext,
+    metrics::GenerationWarning,
+    naming::constants::DECIMAL_FORMAT,
     schema_registry::DiscriminatorMapping,
   },
-  utils::SchemaExt,
+  utils::{SchemaExt, spec::NOT_EXTENSION},
 };
 
 /// Contains resolved field information including type, inline definitions, and validation.
@@ -77,7 +79,8 @@ impl FieldConverter {
       (self.type_resolver.resolve_type(&schema)?, vec![])
     };
 
-    let validation_attrs = Self::extract_all_validation(prop_name, is_required, &schema, &type_ref);
+    let mut validation_attrs = Self::extract_all_validation(prop_name, is_required, &schema, &type_ref);
+    validation_attrs.extend(self.exclusion_validation(parent_name, prop_name, &schema, &type_ref));
 
     Ok(ResolvedFieldData {
       type_ref,
@@ -116,18 +119,24 @@ impl FieldConverter {
         .type_resolver
         .resolve_property(parent_name, prop_name, &prop_schema, prop_schema_ref)?;
 
-      fields.push(self.convert_field(
+      let mut field = self.convert_field(
         prop_name,
         schema,
         &prop_schema,
         resolved.result,
         required.contains(prop_name),
         discriminator_mapping,
-      ));
+      );
+      field
+        .validation_attrs
+        .extend(self.exclusion_validation(parent_name, prop_name, &prop_schema, &field.rust_type));
+      fields.push(field);
       inline_types.extend(resolved.inline_types);
     }
 
-    fields = Self::deduplicate_names(fields);
+    let (deduplicated, warnings) = Self::deduplicate_names(parent_name, fields);
+    fields = deduplicated;
+    self.context.record_warnings(warnings);
 
     if let Some(ref additional) = schema.additional_properties {
       match additional {
@@ -144,7 +153,7 @@ impl FieldConverter {
       }
     }
 
-    if matches!(kind, StructKind::Schema) && self.context.config().enable_builders() {
+    if matches!(kind, StructKind::Schema) && self.context.config().schema_builder(&fields) {
       fields = fields.into_iter().map(FieldDef::with_builder_attrs).collect::<Vec<_>>();
     }
 
@@ -178,10 +187,18 @@ impl FieldConverter {
       && parent_schema.discriminator.is_none()
       && !parent_schema.has_intersection();
 
+    let config = self.context.config();
+    let access = match FieldAccess::from_schema(prop_schema) {
+      access if config.honor_property_access() => access,
+      FieldAccess::ReadOnly if config.skip_read_only() => FieldAccess::ServerManaged,
+      _ => FieldAccess::ReadWrite,
+    };
+
     let should_be_optional = !is_required
       || prop_schema.default.is_some()
       || (is_discriminator && !discriminator_has_enum)
-      || is_odata_optional;
+      || is_odata_optional
+      || matches!(access, FieldAccess::ReadOnly | FieldAccess::WriteOnly);
 
     let final_type = if should_be_optional && !resolved_type.nullable {
       resolved_type.with_option()
@@ -193,13 +210,28 @@ impl FieldConverter {
     let default_value = Self::extract_default_value(prop_schema);
 
     let rust_field_name = FieldNameToken::from_raw(prop_name);
-    let serde_attrs = if rust_field_name == prop_name {
+    let mut serde_attrs = if rust_field_name == prop_name {
       BTreeSet::new()
     } else {
       BTreeSet::from([SerdeAttribute::Rename(prop_name.to_string())])
     };
 
-    let serde_as_attr = self.customization_for_type(&final_type, prop_schema);
+    let should_hide = is_discriminator && !discriminator_has_enum;
+    let serde_as_attr = self
+      .timestamp_for_type(&final_type, prop_schema)
+      .or_else(|| self.customization_for_type(&final_type, prop_schema))
+      .or_else(|| {
+        (!should_hide)
+          .then(|| {
+            self
+              .lenient_for_type(&final_type)
+              .or_else(|| self.coercion_for_type(&final_type))
+          })
+          .flatten()
+      });
+    if matches!(serde_as_attr, Some(SerdeAsFieldAttr::Lenient { optional: true, .. })) {
+      serde_attrs.insert(SerdeAttribute::Default);
+    }
 
     let field = FieldDef::builder()
       .schema(prop_schema)
@@ -209,9 +241,9 @@ impl FieldConverter {
       .rust_type(final_type)
       .serde_attrs(serde_attrs)
       .validation_attrs(validation_attrs)
+      .access(access)
       .build();
 
-    let should_hide = is_discriminator && !discriminator_has_enum;
     if should_hide {
       field.with_discriminator_behavior(discriminator_value, is_base)
     } else {
@@ -219,6 +251,25 @@ impl FieldConverter {
     }
   }
 
+  /// Returns the epoch timestamp adapter for `DateTime` fields backed by numeric schemas.
+  fn timestamp_for_type(&self, type_ref: &TypeRef, prop_schema: &ObjectSchema) -> Option<SerdeAsFieldAttr> {
+    if type_ref.base_type != RustPrimitive::DateTime {
+      return None;
+    }
+    let epoch = if type_ref.is_array {
+      prop_schema
+        .inline_array_items(self.context.graph().spec())?
+        .epoch_timestamp()?
+    } else {
+      prop_schema.epoch_timestamp()?
+    };
+    Some(SerdeAsFieldAttr::Timestamp {
+      epoch,
+      optional: type_ref.nullable,
+      is_array: type_ref.is_array,
+    })
+  }
+
   fn customization_for_type(&self, type_ref: &TypeRef, prop_schema: &ObjectSchema) -> Option<SerdeAsFieldAttr> {
     let is_base64_byte =
       matches!(type_ref.base_type, RustPrimitive::Bytes) && prop_schema.format.as_deref() == Some("byte");
@@ -229,6 +280,7 @@ impl FieldConverter {
       RustPrimitive::Time => "time",
       RustPrimitive::Duration => "duration",
       RustPrimitive::Uuid => "uuid",
+      RustPrimitive::Decimal | RustPrimitive::BigDecimal => DECIMAL_FORMAT,
       RustPrimitive::Bytes if is_base64_byte => "byte",
       RustPrimitive::Custom(name) => name,
       _ => return None,
@@ -240,7 +292,17 @@ impl FieldConverter {
       .customizations
       .get(key)
       .cloned()
-      .or_else(|| is_base64_byte.then(|| "serde_with::base64::Base64".to_string()))?;
+      .or_else(|| is_base64_byte.then(|| "serde_with::base64::Base64".to_string()))
+      .or_else(|| {
+        (type_ref.base_type == RustPrimitive::Duration)
+          .then(|| self.context.config().duration_adapter_path().to_string())
+      })
+      .or_else(|| {
+        type_ref
+          .base_type
+          .is_decimal()
+          .then(|| "serde_with::DisplayFromStr".to_string())
+      })?;
 
     Some(SerdeAsFieldAttr::CustomOverride {
       custom_type,
@@ -249,6 +311,37 @@ impl FieldConverter {
     })
   }
 
+  /// Returns the lenient deserialization adapter for a field, when the lenient profile is active.
+  ///
+  /// Scalar numeric fields accept quoted numbers, and optional scalar fields treat `""` as `None`.
+  /// Arrays and untyped JSON values are left untouched.
+  fn lenient_for_type(&self, type_ref: &TypeRef) -> Option<SerdeAsFieldAttr> {
+    if !self.context.config().lenient_deserialization()
+      || type_ref.is_array
+      || matches!(type_ref.base_type, RustPrimitive::Value | RustPrimitive::Bytes)
+    {
+      return None;
+    }
+
+    let coerce_number = type_ref.base_type.is_numeric();
+    let optional = type_ref.nullable;
+    (coerce_number || optional).then_some(SerdeAsFieldAttr::Lenient {
+      coerce_number,
+      optional,
+    })
+  }
+
+  /// Returns the quoted-number adapter for numeric fields and arrays of numbers,
+  /// when number coercion is enabled.
+  fn coercion_for_type(&self, type_ref: &TypeRef) -> Option<SerdeAsFieldAttr> {
+    (self.context.config().coerce_numbers() && type_ref.base_type.is_numeric() && !type_ref.unique_items).then_some(
+      SerdeAsFieldAttr::CoerceNumber {
+        optional: type_ref.nullable,
+        is_array: type_ref.is_array,
+      },
+    )
+  }
+
   /// Extracts a default value from schema `default`, `const`, or single-value `enum`.
   ///
   /// Prefers `default` over `const` over single-element enum arrays.
@@ -281,14 +374,19 @@ impl FieldConverter {
       }
     }
 
-    if schema.is_numeric() {
+    if type_ref.base_type.is_decimal() {
+      attrs.extend(ValidationAttribute::range(schema, type_ref));
+      return attrs;
+    }
+
+    if schema.is_numeric() && schema.epoch_timestamp().is_none() {
       if let Some(range_attr) = ValidationAttribute::range(schema, type_ref) {
         attrs.push(range_attr);
       }
       return attrs;
     }
 
-    if schema.is_unconstrained_string() {
+    if schema.is_unconstrained_string() && schema.money_scale().is_none() {
       let is_non_string_format = schema.format.as_ref().is_some_and(|f| {
         matches!(
           f.as_str(),
@@ -329,13 +427,55 @@ impl FieldConverter {
     attrs
   }
 
+  /// Builds a validator rejecting the values a property's `not` schema excludes.
+  ///
+  /// Only a `not` made of `const` or `enum` on a string, number, or boolean property is
+  /// enforced. Any other `not` is recorded as a warning, since the type alone cannot
+  /// express it.
+  fn exclusion_validation(
+    &self,
+    parent_name: &str,
+    prop_name: &str,
+    schema: &ObjectSchema,
+    type_ref: &TypeRef,
+  ) -> Option<ValidationAttribute> {
+    let not = schema.extensions.get(NOT_EXTENSION)?;
+    let excluded = serde_json::from_value::<Schema>(not.clone())
+      .ok()
+      .filter(|not| matches!(not, Schema::Object(_)))
+      .and_then(|not| self.type_resolver.resolve(&not).ok())
+      .map(|not| not.const_value.into_iter().chain(not.enum_values).collect::<Vec<_>>())
+      .unwrap_or_default();
+
+    let base = &type_ref.base_type;
+    let supported_type =
+      !type_ref.is_array && (type_ref.is_string_like() || base.is_numeric() || *base == RustPrimitive::Bool);
+    let reason = if excluded.is_empty() {
+      "only `const` and `enum` exclusions are supported"
+    } else if !supported_type {
+      "only string, number, and boolean properties are supported"
+    } else {
+      return Some(ValidationAttribute::Excludes(excluded));
+    };
+    self.context.record_warnings([GenerationWarning::UnsupportedNotSchema {
+      location: format!("property '{prop_name}' of '{parent_name}'"),
+      reason: reason.to_string(),
+    }]);
+    None
+  }
+
   /// Resolves field name collisions by appending numeric suffixes.
   ///
-  /// When multiple properties map to the same Rust field name (e.g., `foo-bar`
-  /// and `foo_bar` both become `foo_bar`), this removes deprecated duplicates
-  /// when a non-deprecated version exists, then appends `_2`, `_3`, etc.
-  /// to remaining duplicates.
-  pub(crate) fn deduplicate_names(fields: Vec<FieldDef>) -> Vec<FieldDef> {
+  /// When multiple properties map to the same Rust field name (e.g., `userId`
+  /// and `user_id` both become `user_id`), this removes deprecated duplicates
+  /// when a non-deprecated version exists, then renames the remaining duplicates.
+  ///
+  /// The property whose name already matches the field keeps it, falling back to
+  /// the lowest property name; the others get `_2`, `_3`, etc. in property name
+  /// order, skipping names used by other fields, so the result does not depend
+  /// on the order of properties in the spec. Renamed fields always carry an
+  /// explicit `serde(rename)`, and each rename is reported as a warning.
+  pub(crate) fn deduplicate_names(type_name: &str, fields: Vec<FieldDef>) -> (Vec<FieldDef>, Vec<GenerationWarning>) {
     let duplicate_names = fields
       .iter()
       .counts_by(|f| f.name.clone())
@@ -345,7 +485,7 @@ impl FieldConverter {
       .collect::<BTreeSet<_>>();
 
     if duplicate_names.is_empty() {
-      return fields;
+      return (fields, vec![]);
     }
 
     let (deprecated, non_deprecated): (BTreeSet<_>, BTreeSet<_>) = fields
@@ -360,25 +500,55 @@ impl FieldConverter {
         }
       });
 
-    let mut occurrence = BTreeMap::<FieldNameToken, usize>::new();
-
-    fields
+    let mut fields = fields
       .into_iter()
-      .filter_map(|field| {
-        let name = field.name.clone();
+      .filter(|f| !(f.deprecated && deprecated.contains(&f.name) && non_deprecated.contains(&f.name)))
+      .collect::<Vec<_>>();
 
-        if field.deprecated && deprecated.contains(&name) && non_deprecated.contains(&name) {
-          return None;
-        }
+    let mut groups = BTreeMap::<FieldNameToken, Vec<usize>>::new();
+    for (index, field) in fields.iter().enumerate() {
+      if duplicate_names.contains(&field.name) {
+        groups.entry(field.name.clone()).or_default().push(index);
+      }
+    }
 
-        let n = *occurrence.entry(name.clone()).and_modify(|n| *n += 1).or_insert(1);
+    let mut used_names = fields.iter().map(|f| f.name.to_string()).collect::<BTreeSet<_>>();
+    let mut warnings = vec![];
 
-        if n > 1 {
-          Some(field.renamed_to(&format!("{name}_{n}")))
-        } else {
-          Some(field)
-        }
-      })
-      .collect()
+    for (name, mut indices) in groups {
+      if indices.len() < 2 {
+        continue;
+      }
+      indices.sort_by_key(|&index| {
+        let property = fields[index].serialized_name();
+        (property != name.as_str(), property.to_string())
+      });
+
+      let kept = fields[indices[0]].serialized_name().to_string();
+      let mut counter = 2;
+      for &index in &indices[1..] {
+        let new_name = loop {
+          let candidate = format!("{name}_{counter}");
+          counter += 1;
+          if !used_names.contains(&candidate) {
+            break candidate;
+          }
+        };
+        used_names.insert(new_name.clone());
+
+        let field = std::mem::take(&mut fields[index]);
+        let property = field.serialized_name().to_string();
+        fields[index] = field.renamed_to(&new_name);
+
+        warnings.push(GenerationWarning::FieldNameCollision {
+          type_name: type_name.to_string(),
+          property,
+          conflicts_with: kept.clone(),
+          field: new_name,
+        });
+      }
+    }
+
+    (fields, warnings)
   }
 }
@@ -63,6 +63,13 @@ impl Hash for CanonicalSchema {
   }
 }
 
+/// Returns the hex-encoded BLAKE3 hash of `content`.
+///
+/// Used to tell whether generated modules changed between runs.
+pub(crate) fn content_hash(content: &str) -> String {
+  blake3::hash(content.as_bytes()).to_hex().to_string()
+}
+
 /// Normalizes JSON Schema values for canonical comparison.
 ///
 /// Recursively traverses the JSON value and:
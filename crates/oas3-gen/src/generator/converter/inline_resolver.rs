@@ -1,7 +1,6 @@
 use std::rc::Rc;
 
 use anyhow::Result;
-use inflections::Inflect;
 use oas3::spec::ObjectSchema;
 
 use super::{
@@ -14,7 +13,7 @@ use crate::{
   generator::{
     ast::{RustType, TypeRef},
     converter::{ConverterContext, SchemaConverter, cache::SharedSchemaCache},
-    naming::identifiers::strip_parent_prefix,
+    naming::{casing::to_pascal_case, identifiers::strip_parent_prefix},
   },
   utils::{SchemaExt, UnionFingerprint},
 };
@@ -48,7 +47,7 @@ impl InlineTypeResolver {
     property_name: &str,
     schema: &ObjectSchema,
   ) -> Result<ConversionOutput<TypeRef>> {
-    let prop_pascal = property_name.to_pascal_case();
+    let prop_pascal = to_pascal_case(property_name);
     let base_name = format!("{parent_name}{}", strip_parent_prefix(parent_name, &prop_pascal));
 
     self.resolve_with_cache(
@@ -92,7 +91,7 @@ impl InlineTypeResolver {
     schema: &ObjectSchema,
     enum_values: &[String],
   ) -> Result<ConversionOutput<TypeRef>> {
-    let base_name = format!("{parent_name}{}", property_name.to_pascal_case());
+    let base_name = format!("{parent_name}{}", to_pascal_case(property_name));
     let forced_name = self.context.cache().get_enum_name(enum_values);
 
     self.resolve_with_cache(
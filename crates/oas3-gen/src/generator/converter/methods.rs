@@ -336,20 +336,29 @@ impl MethodGenerator {
   ///
   /// Flattens nested parameter structs (path, query, header) into the method
   /// signature, allowing callers to pass individual parameters rather than
-  /// constructing nested types manually. Returns `None` if there are no
-  /// fields to include in the builder.
-  pub(crate) fn build_builder_method(nested_structs: &[StructDef], main_fields: &[FieldDef]) -> Option<StructMethod> {
+  /// constructing nested types manually. `skipped_fields` are set to their
+  /// `Default` value rather than taken as parameters. Returns `None` if there are
+  /// no fields to include in the builder.
+  pub(crate) fn build_builder_method(
+    nested_structs: &[StructDef],
+    main_fields: &[FieldDef],
+    skipped_fields: &[FieldDef],
+  ) -> Option<StructMethod> {
     let (fields, nested): BuilderFieldTuple = main_fields
       .iter()
       .map(|field| Self::resolve_field_components(field, nested_structs))
       .unzip();
 
-    let fields = fields.into_iter().flatten().collect::<Vec<_>>();
+    let mut fields = fields.into_iter().flatten().collect::<Vec<_>>();
     let nested = nested.into_iter().flatten().collect::<Vec<_>>();
 
     if fields.is_empty() {
       return None;
     }
+    fields.extend(skipped_fields.iter().map(|field| BuilderField {
+      skipped: true,
+      ..BuilderField::from(field)
+    }));
 
     Some(
       StructMethod::builder()
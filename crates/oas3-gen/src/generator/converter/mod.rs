@@ -23,18 +23,24 @@ use std::{
   collections::HashMap,
   rc::Rc,
   sync::Arc,
+  thread,
 };
 
 use anyhow::Result;
 pub(crate) use common::ConversionOutput;
-use oas3::spec::ObjectSchema;
-pub(crate) use operations::{OperationsProcessor, build_server_trait};
-pub(crate) use type_resolver::TypeResolver;
+use itertools::Itertools;
+use oas3::spec::{ObjectSchema, SchemaType};
+pub(crate) use operations::{OperationsProcessor, build_callback_traits, build_server_trait, build_webhook_trait};
+pub(crate) use type_resolver::{TypeResolver, external_type};
 pub(crate) use type_usage_recorder::SerdeUsageRecorder;
 
 use crate::{
   generator::{
-    ast::{Documentation, EnumToken, RustType, TypeAliasDef, TypeAliasToken, TypeRef},
+    ast::{
+      Documentation, EnumToken, FieldDef, LintConfig, RustPrimitive, RustType, TypeAliasDef, TypeAliasToken, TypeRef,
+      documentation::scope_doc_format,
+    },
+    codegen::templates::ClientTemplates,
     converter::{
       cache::SharedSchemaCache,
       discriminator::DiscriminatorConverter,
@@ -42,10 +48,15 @@ use crate::{
       unions::{EnumConverter, UnionConverter},
     },
     metrics::{GenerationStats, GenerationWarning},
-    naming::{constants::DISCRIMINATED_BASE_SUFFIX, identifiers::to_rust_type_name},
+    naming::{
+      casing::Casing,
+      constants::DISCRIMINATED_BASE_SUFFIX,
+      hooks::{NamingHook, scope_naming_hook},
+      identifiers::{scope_type_name_overrides, to_rust_type_name},
+    },
     schema_registry::SchemaRegistry,
   },
-  utils::{SchemaExt, SchemaSet},
+  utils::{SchemaExt, SchemaSet, spec::NOT_EXTENSION},
 };
 
 /// Policy for handling enum variant name collisions.
@@ -78,6 +89,26 @@ pub enum EnumDeserializePolicy {
   CaseInsensitive,
 }
 
+/// Policy for integer values an integer enum does not list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegerEnumPolicy {
+  /// Unlisted integers fail to deserialize.
+  #[default]
+  Closed,
+  /// Integer enums gain an `Other` variant holding unlisted integers.
+  Open,
+}
+
+/// Policy for nullability annotations OpenAPI 3.1 does not define.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullabilityPolicy {
+  /// Only `null` in a schema's types or union variants makes a value nullable.
+  #[default]
+  Standard,
+  /// `nullable: true` and `x-nullable: true` also make a value nullable, wherever they appear.
+  VendorExtensions,
+}
+
 /// Policy for OData-specific schema support.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ODataPolicy {
@@ -156,6 +187,387 @@ pub enum CollectionTypePolicy {
   Hashed,
 }
 
+/// Policy for retaining the raw response body alongside the typed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RawBodyPolicy {
+  /// Client methods return only the deserialized value.
+  #[default]
+  Discard,
+  /// Client methods return `oas3_gen_support::WithRawBody<T>`, which keeps the
+  /// exact body bytes for signature verification, auditing, or debugging.
+  Retain,
+}
+
+/// Policy for routing generated client requests through a cassette recorder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VcrPolicy {
+  /// Client methods send requests directly with `reqwest`.
+  #[default]
+  Disabled,
+  /// Clients gain a `with_cassette` constructor that records interactions to, or
+  /// replays them from, an `oas3_gen_support::Vcr` cassette file.
+  Enabled,
+}
+
+/// Policy for retrying generated client requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RetryPolicy {
+  /// Client methods send each request once.
+  #[default]
+  Disabled,
+  /// Clients gain a `with_retry` method taking an `oas3_gen_support::RetryPolicy`
+  /// that retries rate-limited and unavailable responses, honoring `Retry-After`.
+  Enabled,
+}
+
+/// Policy for running user middleware around generated client requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestHookPolicy {
+  /// Client methods send requests exactly as built.
+  #[default]
+  Disabled,
+  /// Clients gain a `with_hook` method taking an `oas3_gen_support::RequestHook`, and
+  /// every operation a `{operation}_with_options` method taking per-call
+  /// `oas3_gen_support::RequestOptions`.
+  Enabled,
+}
+
+/// Policy for recording the metadata of the last response a generated client received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LastResponsePolicy {
+  /// Responses are only seen through the typed results.
+  #[default]
+  Disabled,
+  /// Clients gain a `last_response` method returning the status, headers, and timing of
+  /// the most recent response as `oas3_gen_support::ResponseMetadata`.
+  Enabled,
+}
+
+/// Policy for implementing `tower::Service` on generated clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TowerServicePolicy {
+  /// Operations are only callable through client methods.
+  #[default]
+  Disabled,
+  /// The client implements `tower::Service<{Op}Request>` for every operation, so
+  /// calls can be wrapped in tower middleware.
+  Generate,
+}
+
+/// Policy for generating a `batch` method on clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchPolicy {
+  /// Callers run concurrent requests themselves.
+  #[default]
+  Disabled,
+  /// Clients get a `batch` method running many calls of one operation with bounded
+  /// concurrency.
+  Generate,
+}
+
+/// Policy for generating tests of the URLs client methods build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RouteTestPolicy {
+  /// Client methods build their URLs inline.
+  #[default]
+  Disabled,
+  /// Client methods build their URLs in private `{operation}_url` helpers, checked by a
+  /// generated `route_tests` module.
+  Generate,
+}
+
+/// Policy for validating incoming requests in generated servers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestValidationPolicy {
+  /// Handlers pass requests to the server trait as extracted.
+  #[default]
+  Disabled,
+  /// Handlers check requests against the spec's constraints first, answering violations
+  /// with an RFC 7807 problem response.
+  Problem,
+}
+
+/// Policy for generating status classification helpers on response enums.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseClassPolicy {
+  /// Response enums carry no classification.
+  #[default]
+  Disabled,
+  /// Each response enum gets a `classify` function mapping the statuses its operation
+  /// documents to an `oas3_gen_support::ResponseClass`.
+  Generate,
+}
+
+/// Policy for request bodies an operation accepts in several media types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestBodyPolicy {
+  /// The body takes the schema of the first media type the spec lists.
+  #[default]
+  FirstMediaType,
+  /// The client body is an enum with one variant per media type, encoded according to
+  /// the variant supplied.
+  Variants,
+}
+
+/// Module the standalone client imports generated types from, unless configured otherwise.
+pub const DEFAULT_TYPES_IMPORT_PATH: &str = "super::types";
+
+/// Response cursor fields that mark a list operation as paginated when detection is on.
+///
+/// Names match ignoring case and punctuation, so `next_page_token` also covers
+/// `nextPageToken` and `next_token` covers AWS `NextToken`.
+pub const DEFAULT_PAGINATION_CURSORS: &[&str] = &[
+  "next_page_token",
+  "next_cursor",
+  "next_token",
+  "next_continuation_token",
+  "next_marker",
+  "next_link",
+  "@odata.nextLink",
+];
+
+/// Policy for finding paginated list operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaginationPolicy {
+  /// Only operations with an `x-pagination` extension get paginated wrappers.
+  #[default]
+  Extension,
+  /// Operations whose success body has an items array and a cursor field named in
+  /// [`DEFAULT_PAGINATION_CURSORS`] or `pagination_cursors` are paginated too, as are
+  /// `GET` operations whose success response declares a `Link` header.
+  Detect,
+}
+
+/// Runtime that generated clients are compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClientRuntime {
+  /// Clients run on a native target, where `oas3-gen-support` sleeps with `tokio`
+  /// and may compress request bodies.
+  #[default]
+  Native,
+  /// Clients run on `wasm32-unknown-unknown` under `wasm-bindgen-futures`. Request
+  /// compression is not generated, and manifests enable only the `oas3-gen-support`
+  /// features that build for the browser.
+  Wasm,
+}
+
+/// Policy for the error type of generated client methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClientErrorPolicy {
+  /// Client methods return `anyhow::Result`.
+  #[default]
+  Anyhow,
+  /// Clients gain a typed error enum separating request, transport, deserialization,
+  /// and unexpected status failures from each error response schema. Responses with
+  /// error bodies are returned as `Err` instead of response enum variants.
+  Typed,
+}
+
+/// Policy for generating a `prelude` module in client modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreludePolicy {
+  /// Generated items are only reachable through the module's glob re-exports.
+  #[default]
+  Disabled,
+  /// `mod.rs` gains a `prelude` module re-exporting the client, operation request
+  /// and response types, and the support types the client uses.
+  Generate,
+}
+
+/// Schema structs need at least this many optional fields to count as large for
+/// [`BuilderPolicy::Large`].
+pub const LARGE_STRUCT_OPTIONAL_FIELDS: usize = 5;
+
+/// Policy for generating `bon` builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuilderPolicy {
+  /// Structs are constructed with struct literals only.
+  #[default]
+  Disabled,
+  /// Operation request structs gain a validating builder, and schema structs with at
+  /// least [`LARGE_STRUCT_OPTIONAL_FIELDS`] optional fields derive `bon::Builder`.
+  Large,
+  /// Operation request structs gain a validating builder, and every schema struct
+  /// derives `bon::Builder`.
+  All,
+}
+
+/// Policy for turning inline object schemas into Rust types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InlineObjectPolicy {
+  /// Every inline object becomes a named struct.
+  #[default]
+  Promote,
+  /// Inline objects whose properties are all scalars become maps of `serde_json::Value`
+  /// when they have at most this many properties; larger ones become named structs.
+  MapUpTo(usize),
+}
+
+/// Policy for deriving `Clone` on generated types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClonePolicy {
+  /// Every type that can derive `Clone` does.
+  #[default]
+  Always,
+  /// Structs with more than this many fields, and every type holding one, leave
+  /// `Clone` off.
+  UpToFields(usize),
+}
+
+/// Policy for laying out the types of generated modules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModuleSplitPolicy {
+  /// All types are written to a single `types` module.
+  #[default]
+  Single,
+  /// Types used by a single OpenAPI tag are written to a module named after the
+  /// tag, and types shared between tags stay in `types`.
+  Tag,
+}
+
+/// Policy for response bodies that fail to deserialize into their declared schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MalformedResponsePolicy {
+  /// Return an error from `parse_response`.
+  #[default]
+  Fail,
+  /// Return a `Malformed(oas3_gen_support::MalformedResponse)` variant carrying the
+  /// status, raw body, and deserialization error.
+  Capture,
+}
+
+/// Policy for numeric fields whose values may arrive as quoted strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberCoercionPolicy {
+  /// Numeric fields accept only JSON numbers.
+  #[default]
+  Strict,
+  /// Numeric fields, including arrays of numbers, also accept quoted strings such as `"42"`.
+  Coerce,
+}
+
+/// Policy for schema properties marked `readOnly` or `writeOnly`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PropertyAccessPolicy {
+  /// Treat them as ordinary properties.
+  Ignore,
+  /// Treat them as ordinary properties, except that clients never send `readOnly` ones.
+  #[default]
+  SkipReadOnly,
+  /// Make them optional, leave them out of types used in only the direction they
+  /// never travel, and skip them in that direction in types used both ways.
+  PerDirection,
+}
+
+/// Policy for inline types that come out identical apart from their names and docs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypeMergePolicy {
+  /// Merge them into one type and point every reference at it.
+  #[default]
+  MergeIdentical,
+  /// Keep one type per inline schema.
+  KeepAll,
+}
+
+/// Policy for deriving ordering traits on generated types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtraDerivePolicy {
+  /// Only string enums derive `Eq` and `Hash`.
+  #[default]
+  Standard,
+  /// Schema structs and enums whose contents allow it also derive `Eq`, `Hash`,
+  /// `PartialOrd`, and `Ord`.
+  Ordering,
+}
+
+/// How generated token streams are turned into source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormatPolicy {
+  /// Write the token streams unformatted.
+  None,
+  /// Format with `prettyplease`, then pass the result through the external `rustfmt`.
+  Rustfmt,
+  /// Format with `prettyplease`.
+  #[default]
+  Prettyplease,
+}
+
+/// How doc comments taken from the spec are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocFormatPolicy {
+  /// Keep descriptions as written in the spec.
+  #[default]
+  Preserve,
+  /// Rewrap descriptions longer than 100 characters with the external `mdformat`.
+  Mdformat,
+}
+
+/// How many threads convert component schemas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConversionPolicy {
+  /// Convert every schema on the calling thread.
+  #[default]
+  Sequential,
+  /// Split the schemas into this many contiguous chunks, each converted on its own thread.
+  Parallel(usize),
+}
+
+/// Wire format for `format: duration` strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DurationFormatPolicy {
+  /// ISO 8601 durations such as `PT5M`, via `oas3_gen_support::Iso8601Duration`.
+  #[default]
+  Iso8601,
+  /// Human-readable durations such as `5m` or `1h 30m`, via `oas3_gen_support::HumantimeDuration`.
+  Humantime,
+}
+
+/// Rust type generated for `type: string, format: decimal` schemas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalPolicy {
+  /// Decimal strings stay `String`.
+  #[default]
+  String,
+  /// `rust_decimal::Decimal`, re-exported as `oas3_gen_support::Decimal`.
+  RustDecimal,
+  /// `bigdecimal::BigDecimal`, for values beyond the 28 digits `rust_decimal` holds.
+  BigDecimal,
+}
+
+/// Width of the Rust integers generated for `type: integer` schemas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegerWidthPolicy {
+  /// Honor the schema's `format`, so `int32` becomes `i32`.
+  #[default]
+  Spec,
+  /// Widen every integer to `i64`, or `u64` for unsigned formats, so values a
+  /// server sends outside the declared `format` range still deserialize.
+  Wide,
+}
+
+/// Whether integer schemas that cannot be negative become unsigned Rust integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnsignedIntegerPolicy {
+  /// Only unsigned formats such as `uint32` produce unsigned integers.
+  #[default]
+  Format,
+  /// Integers with `minimum: 0` or above, or `exclusiveMinimum` of 0 or above,
+  /// become the unsigned integer of the same width, so `int32` becomes `u32`.
+  FromMinimum,
+}
+
+/// Profile controlling how forgiving generated types are when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeserializeProfile {
+  /// Deserialize exactly as the spec declares.
+  #[default]
+  Strict,
+  /// Absorb common spec violations: numbers sent as quoted strings are accepted,
+  /// empty strings deserialize as `None` for optional fields, and string enums
+  /// gain an `Unknown` variant that catches unrecognized values.
+  Lenient,
+}
+
 /// Configuration for code generation.
 ///
 /// Uses typed enums instead of booleans to make intent explicit at call sites
@@ -169,6 +581,10 @@ pub struct CodegenConfig {
   #[builder(default)]
   pub enum_deserialize: EnumDeserializePolicy,
   #[builder(default)]
+  pub integer_enums: IntegerEnumPolicy,
+  #[builder(default)]
+  pub nullability: NullabilityPolicy,
+  #[builder(default)]
   pub odata: ODataPolicy,
   #[builder(default)]
   pub target: GenerationTarget,
@@ -181,12 +597,118 @@ pub struct CodegenConfig {
   #[builder(default)]
   pub enum_layout: EnumLayoutPolicy,
   #[builder(default)]
-  pub enable_builders: bool,
+  pub raw_body: RawBodyPolicy,
+  #[builder(default)]
+  pub vcr: VcrPolicy,
+  #[builder(default)]
+  pub retry: RetryPolicy,
+  #[builder(default)]
+  pub request_hooks: RequestHookPolicy,
+  #[builder(default)]
+  pub last_response: LastResponsePolicy,
+  #[builder(default)]
+  pub client_runtime: ClientRuntime,
+  #[builder(default)]
+  pub tower_services: TowerServicePolicy,
+  #[builder(default)]
+  pub batch: BatchPolicy,
+  #[builder(default)]
+  pub route_tests: RouteTestPolicy,
+  #[builder(default)]
+  pub request_validation: RequestValidationPolicy,
+  #[builder(default)]
+  pub response_classes: ResponseClassPolicy,
+  #[builder(default)]
+  pub request_bodies: RequestBodyPolicy,
+  #[builder(default)]
+  pub pagination: PaginationPolicy,
+  /// Cursor field names detected in addition to [`DEFAULT_PAGINATION_CURSORS`].
+  #[builder(default)]
+  pub pagination_cursors: Vec<String>,
+  /// Schemas whose structs reject unknown fields whatever their `additionalProperties`.
+  #[builder(default)]
+  pub strict_types: Vec<String>,
+  /// Schemas whose structs accept unknown fields even with `additionalProperties: false`.
+  #[builder(default)]
+  pub lenient_types: Vec<String>,
+  #[builder(default)]
+  pub client_errors: ClientErrorPolicy,
+  #[builder(default)]
+  pub prelude: PreludePolicy,
+  #[builder(default)]
+  pub module_split: ModuleSplitPolicy,
+  #[builder(default)]
+  pub malformed_responses: MalformedResponsePolicy,
+  #[builder(default)]
+  pub deserialize_profile: DeserializeProfile,
+  #[builder(default)]
+  pub number_coercion: NumberCoercionPolicy,
+  #[builder(default)]
+  pub property_access: PropertyAccessPolicy,
+  #[builder(default)]
+  pub type_merge: TypeMergePolicy,
+  #[builder(default)]
+  pub extra_derives: ExtraDerivePolicy,
+  #[builder(default)]
+  pub duration_format: DurationFormatPolicy,
+  #[builder(default)]
+  pub output_format: OutputFormatPolicy,
+  #[builder(default)]
+  pub doc_format: DocFormatPolicy,
+  #[builder(default)]
+  pub conversion: ConversionPolicy,
+  #[builder(default)]
+  pub decimal: DecimalPolicy,
+  #[builder(default)]
+  pub integer_width: IntegerWidthPolicy,
+  #[builder(default)]
+  pub unsigned_integers: UnsignedIntegerPolicy,
+  #[builder(default)]
+  pub builders: BuilderPolicy,
+  #[builder(default)]
+  pub inline_objects: InlineObjectPolicy,
+  #[builder(default)]
+  pub clone_derives: ClonePolicy,
   #[builder(default)]
   pub customizations: HashMap<String, String>,
+  /// Existing Rust types used in place of schemas, keyed by schema name.
+  #[builder(default)]
+  pub rust_types: HashMap<String, String>,
+  #[builder(default)]
+  pub lints: LintConfig,
+  /// User templates replacing parts of the generated client.
+  #[builder(default)]
+  pub client_templates: ClientTemplates,
+  /// Path the standalone client imports generated types from, [`DEFAULT_TYPES_IMPORT_PATH`]
+  /// when unset.
+  pub types_import_path: Option<String>,
+  /// Acronym rules for every type, field, constant, and method name.
+  #[builder(default)]
+  pub casing: Casing,
+  /// Rust type names used in place of converted schema names, keyed by schema name.
+  #[builder(default)]
+  pub type_names: HashMap<String, String>,
+  /// Hook asked for type, field, and method names before the built-in rules.
+  pub naming_hook: Option<NamingHook>,
 }
 
 impl CodegenConfig {
+  /// Runs `f` with this config's naming rules applied to identifier conversions on
+  /// the current thread.
+  pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+    self.casing.scope(|| {
+      scope_type_name_overrides(&self.type_names, || {
+        scope_naming_hook(self.naming_hook.as_ref(), || scope_doc_format(self.doc_format, f))
+      })
+    })
+  }
+
+  /// Returns the path the standalone client imports generated types from.
+  #[must_use]
+  pub fn types_import_path(&self) -> &str {
+    self.types_import_path.as_deref().unwrap_or(DEFAULT_TYPES_IMPORT_PATH)
+  }
+
   /// Returns `true` if enum variant name collisions should be resolved by appending
   /// numeric suffixes (e.g., `Value`, `Value1`) rather than merging with serde aliases.
   #[must_use]
@@ -227,11 +749,22 @@ impl CodegenConfig {
     self.header_scope == HeaderScope::All
   }
 
-  /// Returns `true` if bon builder derives should be generated for schema structs
-  /// and builder methods for request structs.
+  /// Returns `true` if operation request structs should get builder methods.
+  #[must_use]
+  pub fn request_builders(&self) -> bool {
+    self.builders != BuilderPolicy::Disabled
+  }
+
+  /// Returns `true` if a schema struct with `fields` should derive `bon::Builder`.
   #[must_use]
-  pub fn enable_builders(&self) -> bool {
-    self.enable_builders
+  pub fn schema_builder(&self, fields: &[FieldDef]) -> bool {
+    match self.builders {
+      BuilderPolicy::Disabled => false,
+      BuilderPolicy::Large => {
+        fields.iter().filter(|field| !field.is_required()).count() >= LARGE_STRUCT_OPTIONAL_FIELDS
+      }
+      BuilderPolicy::All => true,
+    }
   }
 
   /// Returns `true` when generated map and unique-array fields should emit
@@ -248,6 +781,262 @@ impl CodegenConfig {
     self.enum_layout == EnumLayoutPolicy::Sorted
   }
 
+  /// Returns `true` when generated client methods should return the raw response
+  /// body bytes alongside the deserialized value.
+  #[must_use]
+  pub fn retain_raw_body(&self) -> bool {
+    self.raw_body == RawBodyPolicy::Retain
+  }
+
+  /// Returns `true` when generated clients should support recording and replaying
+  /// HTTP interactions through cassette files.
+  #[must_use]
+  pub fn vcr_enabled(&self) -> bool {
+    self.vcr == VcrPolicy::Enabled
+  }
+
+  /// Returns `true` when generated clients should accept a retry policy.
+  #[must_use]
+  pub fn retry_enabled(&self) -> bool {
+    self.retry == RetryPolicy::Enabled
+  }
+
+  /// Returns `true` when generated clients should run request hooks and accept
+  /// per-call request options.
+  #[must_use]
+  pub fn request_hooks_enabled(&self) -> bool {
+    self.request_hooks == RequestHookPolicy::Enabled
+  }
+
+  /// Returns `true` when generated clients should record the metadata of their last response.
+  #[must_use]
+  pub fn last_response_enabled(&self) -> bool {
+    self.last_response == LastResponsePolicy::Enabled
+  }
+
+  /// Returns `true` when generated clients target the browser rather than a native runtime.
+  #[must_use]
+  pub fn wasm_runtime(&self) -> bool {
+    self.client_runtime == ClientRuntime::Wasm
+  }
+
+  /// Returns `true` when generated clients should implement `tower::Service` per operation.
+  #[must_use]
+  pub fn tower_services(&self) -> bool {
+    self.tower_services == TowerServicePolicy::Generate
+  }
+
+  /// Returns `true` when generated clients should have a `batch` method.
+  #[must_use]
+  pub fn batch_helper(&self) -> bool {
+    self.batch == BatchPolicy::Generate
+  }
+
+  /// Returns `true` when generated clients should test the URLs their methods build.
+  #[must_use]
+  pub fn route_tests(&self) -> bool {
+    self.route_tests == RouteTestPolicy::Generate
+  }
+
+  /// Returns `true` when response enums classify their documented statuses.
+  #[must_use]
+  pub fn response_classes(&self) -> bool {
+    self.response_classes == ResponseClassPolicy::Generate
+  }
+
+  /// Returns `true` when client request bodies accepting several media types become an
+  /// enum with one variant per media type.
+  #[must_use]
+  pub fn request_body_variants(&self) -> bool {
+    self.request_bodies == RequestBodyPolicy::Variants && self.target == GenerationTarget::Client
+  }
+
+  /// Returns `true` when generated server handlers validate requests before calling the trait.
+  #[must_use]
+  pub fn validate_requests(&self) -> bool {
+    self.request_validation == RequestValidationPolicy::Problem
+  }
+
+  /// Returns `true` when client success responses declaring a `Link` header keep its
+  /// page links, so `GET` requests can follow them with `next()` and `prev()`.
+  #[must_use]
+  pub fn page_links(&self) -> bool {
+    self.pagination == PaginationPolicy::Detect && self.target == GenerationTarget::Client
+  }
+
+  /// Returns the cursor field names that mark an operation as paginated, or none when
+  /// only `x-pagination` extensions are honored.
+  #[must_use]
+  pub fn pagination_cursors(&self) -> Vec<String> {
+    match self.pagination {
+      PaginationPolicy::Extension => vec![],
+      PaginationPolicy::Detect => DEFAULT_PAGINATION_CURSORS
+        .iter()
+        .map(ToString::to_string)
+        .chain(self.pagination_cursors.iter().cloned())
+        .collect(),
+    }
+  }
+
+  /// Returns `true` when a struct should reject unknown fields.
+  ///
+  /// `spec_denies` is what the schema's `additionalProperties` asks for; listing any of
+  /// `names` in `lenient_types` or `strict_types` overrides it, lenient first.
+  #[must_use]
+  pub fn deny_unknown_fields(&self, names: &[&str], spec_denies: bool) -> bool {
+    let listed = |types: &[String]| types.iter().any(|ty| names.contains(&ty.as_str()));
+    !listed(&self.lenient_types) && (spec_denies || listed(&self.strict_types))
+  }
+
+  /// Returns `true` when generated client methods should return a typed error enum.
+  #[must_use]
+  pub fn typed_client_errors(&self) -> bool {
+    self.client_errors == ClientErrorPolicy::Typed
+  }
+
+  /// Returns `true` when generated modules should write one types module per OpenAPI tag.
+  #[must_use]
+  pub fn split_by_tag(&self) -> bool {
+    self.module_split == ModuleSplitPolicy::Tag
+  }
+
+  /// Returns `true` when client modules should include a `prelude` module.
+  #[must_use]
+  pub fn generate_prelude(&self) -> bool {
+    self.prelude == PreludePolicy::Generate
+  }
+
+  /// Returns `true` when response enums should gain a `Malformed` variant that
+  /// captures bodies which fail to deserialize instead of returning an error.
+  #[must_use]
+  pub fn capture_malformed_responses(&self) -> bool {
+    self.malformed_responses == MalformedResponsePolicy::Capture
+  }
+
+  /// Returns `true` when integer enums keep unlisted values in an `Other` variant.
+  #[must_use]
+  pub fn open_integer_enums(&self) -> bool {
+    self.integer_enums == IntegerEnumPolicy::Open
+  }
+
+  /// Returns `true` when `nullable` and `x-nullable` annotations make values nullable.
+  #[must_use]
+  pub fn vendor_nullable(&self) -> bool {
+    self.nullability == NullabilityPolicy::VendorExtensions
+  }
+
+  /// Returns `true` when generated types should tolerate common spec violations
+  /// (quoted numbers, empty strings for absent values, unknown enum values).
+  #[must_use]
+  pub fn lenient_deserialization(&self) -> bool {
+    self.deserialize_profile == DeserializeProfile::Lenient
+  }
+
+  /// Returns `true` when numeric fields should accept quoted numbers.
+  /// Always enabled by the lenient deserialization profile.
+  #[must_use]
+  pub fn coerce_numbers(&self) -> bool {
+    self.number_coercion == NumberCoercionPolicy::Coerce || self.lenient_deserialization()
+  }
+
+  /// Returns `true` when `readOnly` and `writeOnly` properties should only travel in
+  /// their own direction.
+  #[must_use]
+  pub fn honor_property_access(&self) -> bool {
+    self.property_access == PropertyAccessPolicy::PerDirection
+  }
+
+  /// Returns `true` when clients should leave `readOnly` properties out of what they send.
+  #[must_use]
+  pub fn skip_read_only(&self) -> bool {
+    self.property_access == PropertyAccessPolicy::SkipReadOnly
+  }
+
+  /// Returns `true` when structurally identical inline types should be merged.
+  #[must_use]
+  pub fn merge_identical_types(&self) -> bool {
+    self.type_merge == TypeMergePolicy::MergeIdentical
+  }
+
+  /// Returns `true` if types that can should derive `Eq`, `Hash`, `PartialOrd`, and `Ord`.
+  #[must_use]
+  pub fn ordering_derives(&self) -> bool {
+    self.extra_derives == ExtraDerivePolicy::Ordering
+  }
+
+  /// Returns the existing Rust type to use for a schema instead of generating one: the
+  /// configured entry for `schema_name`, or else the schema's `x-rust-type`.
+  #[must_use]
+  pub fn rust_type_override<'a>(&'a self, schema_name: Option<&str>, schema: &'a ObjectSchema) -> Option<&'a str> {
+    schema_name
+      .and_then(|name| self.rust_types.get(name))
+      .map(String::as_str)
+      .or_else(|| schema.rust_type_extension())
+  }
+
+  /// Returns the `serde_as` adapter path used for `format: duration` fields.
+  #[must_use]
+  pub fn duration_adapter_path(&self) -> &'static str {
+    match self.duration_format {
+      DurationFormatPolicy::Iso8601 => "oas3_gen_support::Iso8601Duration",
+      DurationFormatPolicy::Humantime => "oas3_gen_support::HumantimeDuration",
+    }
+  }
+
+  /// Returns the type generated for `format: decimal` strings, or `None` when they
+  /// stay `String`.
+  #[must_use]
+  pub fn decimal_primitive(&self) -> Option<RustPrimitive> {
+    match self.decimal {
+      DecimalPolicy::String => None,
+      DecimalPolicy::RustDecimal => Some(RustPrimitive::Decimal),
+      DecimalPolicy::BigDecimal => Some(RustPrimitive::BigDecimal),
+    }
+  }
+
+  /// Returns the integer type generated for a schema whose format maps to `primitive`.
+  /// `non_negative` is whether the schema's minimum rules out negative values.
+  /// Non-integer primitives are returned unchanged.
+  #[must_use]
+  pub fn integer_primitive(&self, primitive: RustPrimitive, non_negative: bool) -> RustPrimitive {
+    let primitive = match self.unsigned_integers {
+      UnsignedIntegerPolicy::FromMinimum if non_negative => primitive.unsigned(),
+      _ => primitive,
+    };
+    match self.integer_width {
+      IntegerWidthPolicy::Spec => primitive,
+      IntegerWidthPolicy::Wide => primitive.widened(),
+    }
+  }
+
+  /// Returns `true` if a scalar-only inline object with `property_count` properties
+  /// should be generated as a map instead of a named struct.
+  #[must_use]
+  pub fn inline_object_as_map(&self, property_count: usize) -> bool {
+    match self.inline_objects {
+      InlineObjectPolicy::Promote => false,
+      InlineObjectPolicy::MapUpTo(max) => property_count <= max,
+    }
+  }
+
+  /// Returns the number of threads that convert component schemas.
+  #[must_use]
+  pub fn conversion_jobs(&self) -> usize {
+    match self.conversion {
+      ConversionPolicy::Sequential => 1,
+      ConversionPolicy::Parallel(jobs) => jobs.max(1),
+    }
+  }
+
+  /// Returns the field count above which structs leave `Clone` off, if any.
+  #[must_use]
+  pub fn max_clone_fields(&self) -> Option<usize> {
+    match self.clone_derives {
+      ClonePolicy::Always => None,
+      ClonePolicy::UpToFields(max) => Some(max),
+    }
+  }
+
   /// Returns the fully qualified Rust path used for map-like fields
   /// (`additionalProperties` and standalone object maps).
   #[must_use]
@@ -266,6 +1055,7 @@ pub(crate) struct ConverterContext {
   pub(crate) cache: RefCell<SharedSchemaCache>,
   pub(crate) type_usage: RefCell<SerdeUsageRecorder>,
   pub(crate) reachable_schemas: Option<Arc<SchemaSet>>,
+  pub(crate) warnings: RefCell<Vec<GenerationWarning>>,
 }
 
 impl ConverterContext {
@@ -286,6 +1076,7 @@ impl ConverterContext {
       cache: RefCell::new(cache),
       type_usage: RefCell::new(SerdeUsageRecorder::new()),
       reachable_schemas,
+      warnings: RefCell::default(),
     }
   }
 
@@ -308,6 +1099,19 @@ impl ConverterContext {
     self.type_usage.take()
   }
 
+  /// Records warnings raised while converting, such as renamed colliding fields.
+  pub(crate) fn record_warnings(&self, warnings: impl IntoIterator<Item = GenerationWarning>) {
+    self.warnings.borrow_mut().extend(warnings);
+  }
+
+  /// Extracts the accumulated conversion warnings, replacing them with an empty list.
+  ///
+  /// Warnings raised more than once, for schemas converted from several places,
+  /// are returned once.
+  pub(crate) fn take_warnings(&self) -> Vec<GenerationWarning> {
+    self.warnings.take().into_iter().unique().collect()
+  }
+
   /// Records that the named type appears in an HTTP request context (request body or parameter).
   ///
   /// Types used only in requests may derive `Serialize` without `Deserialize`.
@@ -340,6 +1144,11 @@ impl ConverterContext {
     self.type_usage.borrow_mut().mark_response_type_ref(type_ref);
   }
 
+  /// Extracts custom type names from a [`TypeRef`] and records them as request types.
+  pub(crate) fn mark_request_type_ref(&self, type_ref: &TypeRef) {
+    self.type_usage.borrow_mut().mark_request_type_ref(type_ref);
+  }
+
   /// Combines type usage data from another recorder into this context's recorder.
   ///
   /// Used when sub-converters track usage independently and results must be aggregated.
@@ -403,6 +1212,7 @@ impl SchemaConverter {
   /// - `allOf` schemas become merged structs
   /// - `oneOf`/`anyOf` schemas become tagged or untagged enums
   /// - Schemas with `enum` values become string enums
+  /// - Integer, number, and boolean `const` schemas become single-variant enums
   /// - Schemas with `properties` become structs
   /// - Array schemas become `Vec<T>` type aliases
   /// - Primitive schemas become type aliases
@@ -410,10 +1220,22 @@ impl SchemaConverter {
   /// Returns multiple types when inline definitions are extracted (e.g., nested
   /// anonymous objects become separate struct definitions).
   pub(crate) fn convert_schema(&self, name: &str, schema: &ObjectSchema) -> Result<Vec<RustType>> {
+    if let Some(path) = self.context.config().rust_type_override(Some(name), schema) {
+      return Ok(vec![RustType::TypeAlias(TypeAliasDef {
+        name: TypeAliasToken::from_raw(name),
+        docs: Documentation::from_optional(schema.description.as_ref()),
+        target: external_type(path)?,
+      })]);
+    }
+
     if schema.has_intersection() {
       return self.struct_converter.convert_all_of_schema(name);
     }
 
+    if self.enum_converter.is_scalar_literal_union(schema) {
+      return Ok(vec![self.enum_converter.convert_value_enum(name, schema)]);
+    }
+
     if schema.union_variants_with_kind().is_some() {
       if schema.discriminator.is_none() && self.type_resolver.is_wrapper_union(schema)? {
         return self.convert_nullable_enum(name, schema);
@@ -432,10 +1254,18 @@ impl SchemaConverter {
         .map(ConversionOutput::into_vec);
     }
 
-    if !schema.enum_values.is_empty() {
+    if !schema.enum_values.is_empty() || schema.is_scalar_const() {
       return Ok(vec![self.enum_converter.convert_value_enum(name, schema)]);
     }
 
+    if let Some(target) = self.try_map_alias(schema)? {
+      return Ok(vec![RustType::TypeAlias(TypeAliasDef {
+        name: TypeAliasToken::from_raw(name),
+        docs: Documentation::from_optional(schema.description.as_ref()),
+        target,
+      })]);
+    }
+
     if !schema.properties.is_empty() || schema.additional_properties.is_some() {
       let result = self.struct_converter.convert_struct(name, schema, None)?;
       return self.finalize_struct_types(name, schema, result.result, result.inline_types);
@@ -543,6 +1373,22 @@ impl SchemaConverter {
     Ok(None)
   }
 
+  /// Resolves a schema that is only a typed map, an object with `additionalProperties`
+  /// and no `properties`, to the map type its alias targets.
+  fn try_map_alias(&self, schema: &ObjectSchema) -> Result<Option<TypeRef>> {
+    if schema.schema_type.is_some() && schema.single_type_or_nullable() != Some(SchemaType::Object) {
+      return Ok(None);
+    }
+    let Some(map) = self.type_resolver.try_map_type(schema)? else {
+      return Ok(None);
+    };
+    Ok(Some(if schema.non_null_type().is_some() {
+      map.with_option()
+    } else {
+      map
+    }))
+  }
+
   /// Converts all schemas from the OpenAPI spec to Rust types.
   ///
   /// Processes schemas in two phases: first registers all top-level schemas to enable
@@ -568,6 +1414,23 @@ impl SchemaConverter {
       }
     }
 
+    self.context.record_warnings(
+      ordered
+        .iter()
+        .filter(|(_, schema)| schema.extensions.contains_key(NOT_EXTENSION) && schema.is_object())
+        .map(|(name, _)| GenerationWarning::UnsupportedNotSchema {
+          location: format!("schema '{name}'"),
+          reason: "object schemas are not checked against `not`".to_string(),
+        }),
+    );
+
+    let jobs = self.context.config().conversion_jobs().min(ordered.len());
+    if jobs > 1
+      && let Some(types) = self.convert_in_parallel(&ordered, jobs, stats)
+    {
+      return types;
+    }
+
     ordered.into_iter().fold(vec![], |mut acc, (name, schema)| {
       match self.convert_schema(name, schema) {
         Ok(types) => acc.extend(types),
@@ -579,6 +1442,133 @@ impl SchemaConverter {
       acc
     })
   }
+
+  /// Converts `schemas` on `jobs` threads, each converting a contiguous chunk with its
+  /// own copy of the context, then merges the types, cache entries, usage, and warnings
+  /// back in schema order so the output does not depend on thread timing.
+  ///
+  /// Returns `None`, leaving the context untouched, when two chunks gave different
+  /// types the same name, so the caller can convert sequentially instead.
+  fn convert_in_parallel(
+    &self,
+    schemas: &[(&String, &ObjectSchema)],
+    jobs: usize,
+    stats: &mut GenerationStats,
+  ) -> Option<Vec<RustType>> {
+    let snapshot = (*self.context).clone();
+    let chunks = thread::scope(|scope| {
+      let handles = schemas
+        .chunks(schemas.len().div_ceil(jobs))
+        .map(|chunk| {
+          let snapshot = snapshot.clone();
+          thread::Builder::new()
+            .stack_size(CONVERSION_STACK_SIZE)
+            .spawn_scoped(scope, move || ConvertedChunk::convert(snapshot, chunk))
+            .ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+      Some(
+        handles
+          .into_iter()
+          .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+          .collect::<Vec<_>>(),
+      )
+    })?;
+
+    let mut seen = self
+      .context
+      .cache()
+      .types
+      .types
+      .iter()
+      .map(|rust_type| (rust_type.type_name().to_string(), rust_type.clone()))
+      .collect::<HashMap<_, _>>();
+    let mut merged = vec![];
+    for mut chunk in chunks {
+      let mut chunk_names = HashMap::new();
+      let mut keep = |rust_type: &RustType| match seen.get(rust_type.type_name().as_ref()) {
+        Some(existing) if existing == rust_type => Some(false),
+        Some(_) => None,
+        None => {
+          chunk_names.insert(rust_type.type_name().to_string(), rust_type.clone());
+          Some(true)
+        }
+      };
+      let mut results = vec![];
+      for (name, result) in chunk.results.drain(..) {
+        results.push((name, result.map(|types| filter_types(types, &mut keep)).transpose()?));
+      }
+      let cached = filter_types(chunk.cache.take_types(), &mut keep)?;
+      seen.extend(chunk_names);
+      merged.push((results, cached, chunk));
+    }
+
+    let mut rust_types = vec![];
+    for (results, cached, chunk) in merged {
+      for (name, result) in results {
+        match result {
+          Ok(types) => rust_types.extend(types),
+          Err(error) => stats.record_warning(GenerationWarning::SchemaConversionFailed {
+            schema_name: name,
+            error,
+          }),
+        }
+      }
+      let mut cache = self.context.cache_mut();
+      cache.absorb(chunk.cache);
+      cache.types.types.extend(cached);
+      drop(cache);
+      self.context.merge_usage(chunk.usage);
+      self.context.record_warnings(chunk.warnings);
+    }
+    Some(rust_types)
+  }
+}
+
+/// Stack size of conversion worker threads, matching the main thread's so deeply nested
+/// schemas convert on a worker whenever they convert sequentially.
+const CONVERSION_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The outcome of converting one chunk of schemas on a worker thread.
+struct ConvertedChunk {
+  results: Vec<(String, Result<Vec<RustType>, String>)>,
+  cache: SharedSchemaCache,
+  usage: SerdeUsageRecorder,
+  warnings: Vec<GenerationWarning>,
+}
+
+impl ConvertedChunk {
+  fn convert(context: ConverterContext, schemas: &[(&String, &ObjectSchema)]) -> Self {
+    let context = Rc::new(context);
+    context.config().scope(|| {
+      let converter = SchemaConverter::new(&context);
+      let results = schemas
+        .iter()
+        .map(|(name, schema)| {
+          let result = converter.convert_schema(name, schema).map_err(|e| e.to_string());
+          ((*name).clone(), result)
+        })
+        .collect::<Vec<_>>();
+      Self {
+        results,
+        cache: context.cache.replace(SharedSchemaCache::new()),
+        usage: context.take_type_usage(),
+        warnings: context.warnings.take(),
+      }
+    })
+  }
+}
+
+/// Drops the types `keep` has already seen, or returns `None` when it finds a name
+/// already taken by a different type.
+fn filter_types(types: Vec<RustType>, keep: &mut impl FnMut(&RustType) -> Option<bool>) -> Option<Vec<RustType>> {
+  let mut kept = vec![];
+  for rust_type in types {
+    if keep(&rust_type)? {
+      kept.push(rust_type);
+    }
+  }
+  Some(kept)
 }
 
 #[cfg(test)]
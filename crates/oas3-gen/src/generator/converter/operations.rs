@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use oas3::spec::ParameterIn;
 
 use super::{
@@ -11,12 +11,17 @@ use super::{
 use crate::{
   generator::{
     ast::{
-      Documentation, EnumToken, FieldDef, HandlerBodyInfo, MethodNameToken, OperationInfo, ParameterLocation,
-      ParsedPath, ResponseEnumDef, RustType, ServerRequestTraitDef, ServerTraitMethod, StructMethod, StructToken,
-      TraitToken, constants::HttpHeaderRef,
+      ContentCategory, Documentation, EnumToken, ExternalDocs, FieldDef, HandlerBodyInfo, MethodNameToken,
+      OperationInfo, OperationKind, PaginationHint, ParameterLocation, ParsedPath, ResponseEnumDef, RustType,
+      SecuritySchemeDef, ServerRequestTraitDef, ServerTraitMethod, StructMethod, StructToken, TraitToken,
+      constants::HttpHeaderRef, operation_security,
     },
     metrics::GenerationWarning,
     naming::{
+      constants::{
+        CONTENT_ENCODING_HEADER, DEPRECATED_REPLACEMENT_EXTENSION, PAGINATION_EXTENSION, REQUEST_COMPRESSION_EXTENSION,
+        WEBHOOK_HANDLER_TRAIT,
+      },
       identifiers::to_rust_type_name,
       operations::{generate_unique_request_name, generate_unique_response_name},
     },
@@ -121,15 +126,47 @@ impl OperationsProcessor {
 /// Builds the server trait definition from converted operations.
 ///
 /// Creates an `ApiServer` trait with one method per operation, including
-/// typed path, query, and header parameter structs. Returns `None` if
-/// there are no operations to include.
+/// typed path, query, and header parameter structs. Callbacks are sent by the
+/// server rather than handled, and webhooks get their own [`build_webhook_trait`],
+/// so both are left out. Returns `None` if there are no operations to include.
 pub(crate) fn build_server_trait(operations: &[OperationInfo]) -> Option<ServerRequestTraitDef> {
-  if operations.is_empty() {
+  let methods = server_trait_methods(operations, OperationKind::Http);
+  if methods.is_empty() {
     return None;
   }
 
-  let methods = operations
+  Some(
+    ServerRequestTraitDef::builder()
+      .name(TraitToken::new("ApiServer"))
+      .methods(methods)
+      .build(),
+  )
+}
+
+/// Builds the `WebhookHandler` trait with one method per webhook event, taking the
+/// typed payload and returning the typed response. Returns `None` if the spec has no
+/// webhooks to include.
+pub(crate) fn build_webhook_trait(operations: &[OperationInfo]) -> Option<ServerRequestTraitDef> {
+  let methods = server_trait_methods(operations, OperationKind::Webhook);
+  if methods.is_empty() {
+    return None;
+  }
+
+  Some(
+    ServerRequestTraitDef::builder()
+      .name(TraitToken::new(WEBHOOK_HANDLER_TRAIT))
+      .docs(Documentation::from_lines([
+        "Webhook events the API sends, one method per event.",
+      ]))
+      .methods(methods)
+      .build(),
+  )
+}
+
+fn server_trait_methods(operations: &[OperationInfo], kind: OperationKind) -> Vec<ServerTraitMethod> {
+  operations
     .iter()
+    .filter(|info| info.kind == kind)
     .map(|info| {
       let path_params_type = extract_nested_type(&info.parameters, ParameterLocation::Path, info.request_type.as_ref());
       let query_params_type =
@@ -160,14 +197,43 @@ pub(crate) fn build_server_trait(operations: &[OperationInfo]) -> Option<ServerR
         .maybe_body_info(body_info)
         .build()
     })
-    .collect::<Vec<_>>();
+    .collect()
+}
 
-  Some(
-    ServerRequestTraitDef::builder()
-      .name(TraitToken::new("ApiServer"))
-      .methods(methods)
-      .build(),
-  )
+/// Builds one `<Operation>Callbacks` trait per operation declaring callbacks.
+///
+/// Each trait has one method per callback request, taking the typed request and
+/// returning the typed response, for clients implementing the webhook receiver.
+pub(crate) fn build_callback_traits(operations: &[OperationInfo]) -> Vec<ServerRequestTraitDef> {
+  let mut callbacks = IndexMap::<&str, Vec<ServerTraitMethod>>::new();
+  for info in operations {
+    let Some(parent) = info.callback_of.as_deref() else {
+      continue;
+    };
+    callbacks.entry(parent).or_default().push(
+      ServerTraitMethod::builder()
+        .name(MethodNameToken::from_raw(&info.stable_id))
+        .docs(info.documentation.clone())
+        .maybe_request_type(info.request_type.clone())
+        .maybe_response_type(info.response_enum.clone())
+        .http_method(info.method.clone())
+        .path(info.path.clone())
+        .build(),
+    );
+  }
+
+  callbacks
+    .into_iter()
+    .map(|(parent, methods)| {
+      ServerRequestTraitDef::builder()
+        .name(TraitToken::new(to_rust_type_name(&format!("{parent}_callbacks"))))
+        .docs(Documentation::from_lines([format!(
+          "Callbacks the API sends for the `{parent}` operation."
+        )]))
+        .methods(methods)
+        .build()
+    })
+    .collect()
 }
 
 /// Extracts the nested parameter struct type for a specific location.
@@ -201,6 +267,7 @@ pub(crate) struct OperationConverter {
   schema_converter: SchemaConverter,
   response_converter: ResponseConverter,
   request_converter: RequestConverter,
+  security_schemes: Vec<SecuritySchemeDef>,
 }
 
 impl OperationConverter {
@@ -208,12 +275,14 @@ impl OperationConverter {
   pub(crate) fn new(context: Rc<ConverterContext>, schema_converter: SchemaConverter) -> Self {
     let response_converter = ResponseConverter::new(context.clone());
     let request_converter = RequestConverter::new(&context);
+    let security_schemes = SecuritySchemeDef::collect(context.graph().spec());
 
     Self {
       context,
       schema_converter,
       response_converter,
       request_converter,
+      security_schemes,
     }
   }
 
@@ -226,15 +295,23 @@ impl OperationConverter {
     let body_info = BodyInfo::new(&self.context, entry)?;
 
     self.context.mark_request_iter(&body_info.type_usage);
+    if entry.kind == OperationKind::Callback {
+      for type_name in &body_info.type_usage {
+        self.context.mark_response(type_name);
+      }
+    }
 
     let (response_def, parse_method) = self.response_definition(&base_name, entry);
-    let request_output = self.request(&base_name, entry, &body_info, parse_method)?;
+    let page_link = response_def
+      .as_ref()
+      .is_some_and(|def| def.variants.iter().any(|variant| variant.page_links));
+    let request_output = self.request(&base_name, entry, &body_info, parse_method, page_link)?;
 
     let warnings = request_output.warnings.clone();
     let parameters = request_output.parameter_fields.clone();
 
     let (request_types, request_type) = self.request_types(request_output, response_def.is_some());
-    let (response_types, response_enum_token) = self.response_types(response_def, request_type.as_ref());
+    let (response_types, response_enum_token) = self.response_types(response_def, request_type.as_ref(), entry.kind);
 
     let types = Self::collect_types(&body_info, request_types, response_types);
 
@@ -246,6 +323,7 @@ impl OperationConverter {
       &body_info,
       warnings,
       parameters,
+      page_link,
     )?;
 
     Ok(ConversionResult { types, operation_info })
@@ -256,7 +334,7 @@ impl OperationConverter {
     let response_name = generate_unique_response_name(base_name, |n| self.schema_converter.contains(n));
     let response_def = self
       .response_converter
-      .build_enum(&response_name, &entry.operation, &entry.path);
+      .build_enum(&response_name, &entry.operation, &entry.path, &entry.method);
 
     let parse_method = response_def.as_ref().map(|def| {
       self
@@ -274,11 +352,12 @@ impl OperationConverter {
     entry: &OperationEntry,
     body_info: &BodyInfo,
     parse_method: Option<StructMethod>,
+    page_link: bool,
   ) -> anyhow::Result<RequestOutput> {
     let request_name = generate_unique_request_name(base_name, |n| self.schema_converter.contains(n));
     self
       .request_converter
-      .build(&request_name, entry, body_info, parse_method)
+      .build(&request_name, entry, body_info, parse_method, page_link)
   }
 
   /// Assembles request types and marks them as request-context types.
@@ -304,7 +383,15 @@ impl OperationConverter {
   }
 
   /// Assembles response types and marks them as response-context types.
-  fn response_types(&self, response_def: Option<ResponseEnumDef>, request_type: Option<&StructToken>) -> ResponseTypes {
+  ///
+  /// A callback's client receives the request and sends the response, so its
+  /// payloads are marked for both directions.
+  fn response_types(
+    &self,
+    response_def: Option<ResponseEnumDef>,
+    request_type: Option<&StructToken>,
+    kind: OperationKind,
+  ) -> ResponseTypes {
     let Some(mut def) = response_def else {
       return (vec![], None);
     };
@@ -314,6 +401,9 @@ impl OperationConverter {
     self.context.mark_response(def.name.clone());
     for schema_type in def.variants.iter().filter_map(|v| v.schema_type.as_ref()) {
       self.context.mark_response_type_ref(schema_type);
+      if kind == OperationKind::Callback {
+        self.context.mark_request_type_ref(schema_type);
+      }
     }
 
     let token = EnumToken::new(def.name.to_string());
@@ -340,11 +430,65 @@ impl OperationConverter {
     request_type: Option<StructToken>,
     response_enum: Option<EnumToken>,
     body_info: &BodyInfo,
-    warnings: Vec<String>,
+    mut warnings: Vec<String>,
     parameters: Vec<FieldDef>,
+    page_link: bool,
   ) -> anyhow::Result<OperationInfo> {
     let response_metadata = self.response_converter.extract_metadata(&entry.operation);
     self.context.merge_usage(response_metadata.usage);
+    let body = body_info.to_operation_body();
+    let request_compression = !self.context.config().wasm_runtime()
+      && body.as_ref().is_some_and(|body| {
+        body.content_category != ContentCategory::Multipart
+          && body
+            .media_variants
+            .iter()
+            .all(|variant| variant.content_category != ContentCategory::Multipart)
+      })
+      && accepts_compressed_body(entry, &parameters);
+    let deprecated = entry.operation.deprecated.unwrap_or(false);
+    let replaced_by = deprecated
+      .then(|| entry.operation.extensions.get(DEPRECATED_REPLACEMENT_EXTENSION))
+      .flatten()
+      .and_then(serde_json::Value::as_str)
+      .map(ToString::to_string);
+
+    let pagination_hint = entry.operation.extensions.get(PAGINATION_EXTENSION).and_then(|value| {
+      match serde_json::from_value::<PaginationHint>(value.clone()) {
+        Ok(hint) => Some(hint),
+        Err(err) => {
+          warnings.push(format!("Ignoring x-{PAGINATION_EXTENSION}: {err}"));
+          None
+        }
+      }
+    });
+
+    let mut documentation = Documentation::documentation()
+      .maybe_summary(entry.operation.summary.as_deref())
+      .maybe_description(entry.operation.description.as_deref())
+      .method(&entry.method)
+      .path(&entry.path)
+      .call();
+    if !entry.operation.tags.is_empty() {
+      let tags = entry
+        .operation
+        .tags
+        .iter()
+        .map(|tag| format!("`{tag}`"))
+        .collect::<Vec<_>>();
+      documentation.push(format!("* Tags: {}", tags.join(", ")));
+    }
+    let external_docs = entry.operation.external_docs.as_ref().map(|docs| ExternalDocs {
+      url: docs.url.to_string(),
+      description: docs.description.clone(),
+    });
+    if let Some(docs) = &external_docs {
+      let label = docs.description.as_deref().unwrap_or(&docs.url);
+      documentation.push(format!("* See: [{label}]({})", docs.url));
+    }
+    if let Some(replacement) = &replaced_by {
+      documentation.push(format!("* Deprecated: use `{replacement}` instead"));
+    }
 
     Ok(
       OperationInfo::builder()
@@ -358,6 +502,7 @@ impl OperationConverter {
         )
         .method(entry.method.clone())
         .path(ParsedPath::parse(&entry.path, &parameters)?)
+        .path_template(&entry.path)
         .kind(entry.kind)
         .maybe_request_type(request_type)
         .maybe_response_type(response_metadata.metadata.type_name)
@@ -365,16 +510,49 @@ impl OperationConverter {
         .response_media_types(response_metadata.metadata.media_types)
         .warnings(warnings)
         .parameters(parameters)
-        .maybe_body(body_info.to_operation_body())
-        .documentation(
-          Documentation::documentation()
-            .maybe_summary(entry.operation.summary.as_deref())
-            .maybe_description(entry.operation.description.as_deref())
-            .method(&entry.method)
-            .path(&entry.path)
-            .call(),
+        .maybe_body(body)
+        .request_compression(request_compression)
+        .deprecated(deprecated)
+        .maybe_replaced_by(replaced_by)
+        .maybe_pagination_hint(pagination_hint)
+        .page_link(page_link)
+        .tags(entry.operation.tags.clone())
+        .maybe_external_docs(external_docs)
+        .extensions(
+          entry
+            .operation
+            .extensions
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect(),
         )
+        .maybe_callback_of(entry.callback_of.clone())
+        .security(operation_security(
+          self.context.graph().spec(),
+          &entry.operation,
+          &self.security_schemes,
+        ))
+        .documentation(documentation)
         .build(),
     )
   }
 }
+
+/// Returns `true` when the operation declares a `Content-Encoding` header parameter
+/// or opts in with `x-request-compression: true`.
+fn accepts_compressed_body(entry: &OperationEntry, parameters: &[FieldDef]) -> bool {
+  let declares_header = parameters.iter().any(|param| {
+    matches!(param.parameter_location, Some(ParameterLocation::Header))
+      && param
+        .original_name
+        .as_deref()
+        .is_some_and(|name| name.eq_ignore_ascii_case(CONTENT_ENCODING_HEADER))
+  });
+  declares_header
+    || entry
+      .operation
+      .extensions
+      .get(REQUEST_COMPRESSION_EXTENSION)
+      .and_then(serde_json::Value::as_bool)
+      .unwrap_or(false)
+}
@@ -1,14 +1,14 @@
 use std::{collections::HashSet, rc::Rc};
 
 use itertools::Itertools;
-use oas3::spec::{Operation, Parameter, ParameterStyle};
+use oas3::spec::{Operation, Parameter, ParameterStyle, SchemaType};
 
 use super::fields::FieldConverter;
 use crate::{
   generator::{
     ast::{
-      FieldCollection as _, FieldDef, FieldNameToken, OuterAttr, ParameterLocation, ParsedPath, RustType, StructDef,
-      StructKind, StructToken, TypeRef,
+      FieldCollection as _, FieldDef, FieldNameToken, ObjectStyle, OuterAttr, ParameterLocation, ParsedPath, RustType,
+      StructDef, StructKind, StructToken, TypeRef,
     },
     converter::ConverterContext,
     naming::constants::{
@@ -16,7 +16,7 @@ use crate::{
       QUERY_PARAMS_SUFFIX,
     },
   },
-  utils::schema_ext::SchemaExtIters,
+  utils::{SchemaExt as _, schema_ext::SchemaExtIters},
 };
 
 /// Result of converting all parameters for an operation.
@@ -129,7 +129,8 @@ impl ParameterConverter {
   /// Converts a single OpenAPI parameter into a field definition.
   ///
   /// Resolves the parameter schema, extracts validation attributes, and
-  /// applies query parameter serialization attributes (explode, style).
+  /// applies query parameter serialization attributes (explode, style). Path and
+  /// header parameters holding an object record how `simple` style writes it.
   fn convert_parameter(
     &self,
     param: &Parameter,
@@ -175,6 +176,17 @@ impl ParameterConverter {
       field = field.with_serde_attributes(explode, param.style);
     }
 
+    if matches!(location, ParameterLocation::Path | ParameterLocation::Header)
+      && !field.rust_type.is_array
+      && resolved.schema.single_type_or_nullable() == Some(SchemaType::Object)
+    {
+      field.object_style = Some(if param.explode.unwrap_or(false) {
+        ObjectStyle::Exploded
+      } else {
+        ObjectStyle::Flat
+      });
+    }
+
     Ok((field, resolved.inline_types))
   }
 }
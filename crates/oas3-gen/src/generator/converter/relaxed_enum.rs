@@ -39,7 +39,7 @@ impl RelaxedEnumBuilder {
     let sort_variants = context.config().sort_enum_variants();
     Self {
       context,
-      value_enum_builder: ValueEnumBuilder::new(case_insensitive, sort_variants),
+      value_enum_builder: ValueEnumBuilder::new(case_insensitive, sort_variants, false, false),
     }
   }
 
@@ -10,12 +10,13 @@ use super::{
 use crate::{
   generator::{
     ast::{
-      ContentCategory, Documentation, FieldDef, FieldNameToken, MultipartFieldInfo, OperationBody, RustPrimitive,
-      RustType, StructDef, StructKind, StructMethod, StructToken, TypeRef,
+      BodyMediaVariant, ContentCategory, Documentation, EnumVariantToken, FieldDef, FieldNameToken, MultipartFieldInfo,
+      OperationBody, RustPrimitive, RustType, StructDef, StructKind, StructMethod, StructToken, TypeRef,
+      VariantContent, VariantDef,
     },
     converter::ConverterContext,
     naming::{
-      constants::{BODY_FIELD_NAME, REQUEST_BODY_SUFFIX},
+      constants::{BODY_FIELD_NAME, FILE_PART_TYPE, JSON_BODY_VARIANT, REQUEST_BODY_SUFFIX},
       identifiers::to_rust_type_name,
     },
     operation_registry::OperationEntry,
@@ -51,19 +52,22 @@ impl RequestConverter {
   pub(crate) fn new(context: &Rc<ConverterContext>) -> Self {
     Self {
       param_converter: ParameterConverter::new(context),
-      enable_builders: context.config().enable_builders(),
+      enable_builders: context.config().request_builders(),
     }
   }
 
   /// Builds a request struct for an operation.
   ///
-  /// Converts parameters, resolves request body, and generates builder methods.
+  /// Converts parameters, resolves request body, and generates builder methods. With
+  /// `page_link`, the struct also gets an optional `page_link` URL that replaces the
+  /// one built from its parameters.
   pub(crate) fn build(
     &self,
     name: &str,
     entry: &OperationEntry,
     body_info: &BodyInfo,
     extra_method: Option<StructMethod>,
+    page_link: bool,
   ) -> anyhow::Result<RequestOutput> {
     let params = self.param_converter.convert_all(name, &entry.path, &entry.operation)?;
 
@@ -79,14 +83,18 @@ impl RequestConverter {
       main_fields.push(body_field);
     }
 
+    let page_link_field = page_link.then(FieldDef::page_link_field);
+
     let builder_method = if self.enable_builders {
-      MethodGenerator::build_builder_method(&nested_structs, &main_fields)
+      MethodGenerator::build_builder_method(&nested_structs, &main_fields, page_link_field.as_slice())
     } else {
       None
     };
 
     let methods = extra_method.into_iter().chain(builder_method).collect::<Vec<_>>();
 
+    main_fields.extend(page_link_field);
+
     let main_struct = StructDef::builder()
       .name(StructToken::new(name))
       .docs(Documentation::from_optional(
@@ -121,7 +129,9 @@ pub(crate) struct BodyInfo {
   pub(crate) description: Option<String>,
   pub(crate) optional: bool,
   pub(crate) content_category: ContentCategory,
+  pub(crate) content_type: Option<String>,
   pub(crate) multipart_fields: Option<Vec<MultipartFieldInfo>>,
+  pub(crate) media_variants: Vec<BodyMediaVariant>,
 }
 
 impl BodyInfo {
@@ -131,6 +141,11 @@ impl BodyInfo {
   /// category (JSON, form, multipart, binary), and collects any inline types
   /// generated during schema resolution. Returns an empty body info if no
   /// request body is defined.
+  ///
+  /// When [`CodegenConfig::request_body_variants`](super::CodegenConfig::request_body_variants)
+  /// is enabled and the body accepts media types of more than one category, the body
+  /// becomes an untagged enum with one variant per category, taking the first media
+  /// type listed for each.
   pub(crate) fn new(context: &Rc<ConverterContext>, entry: &OperationEntry) -> anyhow::Result<Self> {
     let spec = context.graph().spec();
     let Some(body_ref) = entry.operation.request_body.as_ref() else {
@@ -140,35 +155,37 @@ impl BodyInfo {
     let body = body_ref.resolve(spec)?;
     let is_required = body.required.unwrap_or(false);
 
-    let Some((content_type, media_type)) = body.content.iter().next() else {
-      return Ok(Self::empty(!is_required));
+    let accepted = if context.config().request_body_variants() {
+      body.content.len()
+    } else {
+      1
     };
+    let mut media_types = vec![];
+    for (content_type, media_type) in body.content.iter().take(accepted) {
+      let category = ContentCategory::from_content_type(content_type);
+      if media_types.iter().any(|(_, existing, _)| *existing == category) {
+        continue;
+      }
+      let Some(schema_ref) = media_type.schema.as_ref() else {
+        continue;
+      };
+      media_types.push((content_type, category, schema_ref));
+    }
+
+    if let [_, _, ..] = media_types.as_slice() {
+      return Self::media_variants(context, entry, &media_types, body.description.clone(), !is_required);
+    }
 
-    let Some(schema_ref) = media_type.schema.as_ref() else {
+    let Some(&(content_type, content_category, schema_ref)) = media_types.first() else {
       return Ok(Self::empty(!is_required));
     };
 
-    let inline_resolver = InlineTypeResolver::new(context.clone());
-    let (generated_types, type_name) = if matches!(schema_ref, Schema::Boolean(_)) {
-      (vec![], RustPrimitive::Value.to_string())
-    } else if let Some(ref_path) = schema_ref.ref_path() {
-      let Some(name) = parse_schema_ref_path(ref_path) else {
-        return Ok(Self::empty(!is_required));
-      };
-      (vec![], to_rust_type_name(&name))
-    } else if let Some(schema) = schema_ref.as_inline() {
-      let base_name = schema.infer_name_from_context(&entry.path, REQUEST_BODY_SUFFIX);
-      let Some(output) = inline_resolver.try_inline_schema(schema, &base_name)? else {
-        return Ok(Self::empty(!is_required));
-      };
-      (output.inline_types, output.result)
-    } else {
+    let Some((mut generated_types, type_name)) = Self::resolve_schema(context, entry, schema_ref)? else {
       return Ok(Self::empty(!is_required));
     };
 
     let body_type = TypeRef::new(&type_name);
-    let content_category = ContentCategory::from_content_type(content_type);
-    let multipart_fields = Self::resolve_multipart_fields(content_category, &body_type, &generated_types);
+    let multipart_fields = Self::resolve_multipart_fields(content_category, &body_type, &mut generated_types);
 
     Ok(Self {
       generated_types,
@@ -178,19 +195,128 @@ impl BodyInfo {
       description: body.description.clone(),
       optional: !is_required,
       content_category,
+      content_type: Some(content_type.clone()),
       multipart_fields,
+      media_variants: vec![],
     })
   }
 
+  /// Builds the body enum for a body accepted in several media types.
+  ///
+  /// Each variant wraps the schema of its media type and is named after its category,
+  /// such as `Json` or `Multipart`.
+  fn media_variants(
+    context: &Rc<ConverterContext>,
+    entry: &OperationEntry,
+    media_types: &[(&String, ContentCategory, &Schema)],
+    description: Option<String>,
+    optional: bool,
+  ) -> anyhow::Result<Self> {
+    let mut generated_types = vec![];
+    let mut type_usage = vec![];
+    let mut variants = vec![];
+    let mut media_variants = vec![];
+
+    for &(content_type, category, schema_ref) in media_types {
+      let Some((types, type_name)) = Self::resolve_schema(context, entry, schema_ref)? else {
+        return Ok(Self::empty(optional));
+      };
+      generated_types.extend(types);
+      let variant_type = TypeRef::new(&type_name);
+      let name = EnumVariantToken::new(match category {
+        ContentCategory::Json => JSON_BODY_VARIANT,
+        category => category.variant_suffix(),
+      });
+      let multipart_fields = Self::resolve_multipart_fields(category, &variant_type, &mut generated_types);
+
+      variants.push(
+        VariantDef::builder()
+          .name(name.clone())
+          .docs(Documentation::from_lines([format!("Sent as `{content_type}`.")]))
+          .content(VariantContent::Tuple(vec![variant_type]))
+          .build(),
+      );
+      media_variants.push(
+        BodyMediaVariant::builder()
+          .name(name)
+          .content_category(category)
+          .content_type(content_type.clone())
+          .maybe_multipart_fields(multipart_fields)
+          .build(),
+      );
+      type_usage.push(type_name);
+    }
+
+    let base_name = format!("{}{REQUEST_BODY_SUFFIX}", to_rust_type_name(&entry.stable_id));
+    let enum_name = {
+      let mut cache = context.cache_mut();
+      let name = cache.make_unique_name(&base_name);
+      cache.mark_name_used(name.clone());
+      name
+    };
+
+    generated_types.push(
+      RustType::untagged_enum()
+        .name(&enum_name)
+        .docs(Documentation::from_lines([
+          "Request body, encoded according to the media type of its variant.",
+        ]))
+        .variants(variants)
+        .methods(vec![])
+        .call(),
+    );
+    type_usage.push(enum_name.clone());
+
+    let first = &media_variants[0];
+    Ok(Self {
+      generated_types,
+      type_usage,
+      field_name: Some(FieldNameToken::new(BODY_FIELD_NAME)),
+      body_type: Some(TypeRef::new(&enum_name)),
+      description,
+      optional,
+      content_category: first.content_category,
+      content_type: first.content_type.clone(),
+      multipart_fields: None,
+      media_variants,
+    })
+  }
+
+  /// Resolves the type a media type's schema maps to, with any inline types it generates.
+  fn resolve_schema(
+    context: &Rc<ConverterContext>,
+    entry: &OperationEntry,
+    schema_ref: &Schema,
+  ) -> anyhow::Result<Option<(Vec<RustType>, String)>> {
+    let inline_resolver = InlineTypeResolver::new(context.clone());
+    if matches!(schema_ref, Schema::Boolean(_)) {
+      return Ok(Some((vec![], RustPrimitive::Value.to_string())));
+    }
+    if let Some(ref_path) = schema_ref.ref_path() {
+      return Ok(parse_schema_ref_path(ref_path).map(|name| (vec![], to_rust_type_name(&name))));
+    }
+    let Some(schema) = schema_ref.as_inline() else {
+      return Ok(None);
+    };
+    let base_name = schema.infer_name_from_context(&entry.path, REQUEST_BODY_SUFFIX);
+    Ok(
+      inline_resolver
+        .try_inline_schema(schema, &base_name)?
+        .map(|output| (output.inline_types, output.result)),
+    )
+  }
+
   /// Extracts field information for multipart form data bodies.
   ///
   /// Returns `None` for non-multipart content types. For multipart bodies,
   /// inspects the body struct to determine which fields are binary, nullable,
-  /// or require JSON serialization.
+  /// or require JSON serialization. Raw binary fields (`format: binary`) are
+  /// retyped as `oas3_gen_support::FilePart` so they carry a file name and
+  /// content type; base64 fields keep their bytes.
   fn resolve_multipart_fields(
     category: ContentCategory,
     body_type: &TypeRef,
-    generated_types: &[RustType],
+    generated_types: &mut [RustType],
   ) -> Option<Vec<MultipartFieldInfo>> {
     if category != ContentCategory::Multipart {
       return None;
@@ -198,7 +324,7 @@ impl BodyInfo {
 
     let body_type_name = body_type.unboxed_base_type_name();
 
-    let struct_def = generated_types.iter().find_map(|t| {
+    let struct_def = generated_types.iter_mut().find_map(|t| {
       if let RustType::Struct(def) = t
         && def.name.as_str() == body_type_name
       {
@@ -209,12 +335,20 @@ impl BodyInfo {
 
     let fields = struct_def
       .fields
-      .iter()
-      .map(|f| MultipartFieldInfo {
-        name: f.name.clone(),
-        nullable: f.rust_type.nullable,
-        is_bytes: matches!(f.rust_type.base_type, RustPrimitive::Bytes),
-        requires_json: f.rust_type.requires_json_serialization(),
+      .iter_mut()
+      .map(|f| {
+        let is_file = f.rust_type.base_type == RustPrimitive::Bytes && f.serde_as_attr.is_none();
+        if is_file {
+          f.rust_type.base_type = RustPrimitive::Custom(FILE_PART_TYPE.into());
+        }
+        MultipartFieldInfo {
+          name: f.name.clone(),
+          nullable: f.rust_type.nullable,
+          is_bytes: f.rust_type.base_type == RustPrimitive::Bytes,
+          is_file,
+          is_array: f.rust_type.is_array,
+          requires_json: f.rust_type.requires_json_serialization(),
+        }
       })
       .collect();
 
@@ -242,7 +376,9 @@ impl BodyInfo {
         .maybe_body_type(self.body_type.clone())
         .optional(self.optional)
         .content_category(self.content_category)
+        .maybe_content_type(self.content_type.clone())
         .maybe_multipart_fields(self.multipart_fields.clone())
+        .media_variants(self.media_variants.clone())
         .build(),
     )
   }
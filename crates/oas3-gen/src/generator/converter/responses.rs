@@ -1,25 +1,33 @@
-use std::rc::Rc;
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  rc::Rc,
+};
 
+use http::Method;
 use indexmap::IndexMap;
 use itertools::Itertools;
-use oas3::spec::{MediaType, ObjectSchema, Operation, Response, Schema};
+use oas3::spec::{MediaType, MediaTypeExamples, ObjectSchema, Operation, Response, Schema};
 
 use super::{ConverterContext, SerdeUsageRecorder, TypeResolver, inline_resolver::InlineTypeResolver};
 use crate::{
   generator::{
     ast::{
-      ContentCategory, Documentation, EnumToken, EnumVariantToken, MethodKind, MethodNameToken, ResponseEnumDef,
-      ResponseMediaType, ResponseStatusCategory, ResponseVariant, ResponseVariantCategory, RustPrimitive,
-      StatusCodeToken, StatusHandler, StructMethod, TypeRef,
+      ContentCategory, Documentation, EnumToken, EnumVariantToken, MethodKind, MethodNameToken, NamedEventVariant,
+      NamedEventsDef, ResponseClass, ResponseEnumDef, ResponseMediaType, ResponseStatusCategory, ResponseVariant,
+      ResponseVariantCategory, RustPrimitive, StatusCodeToken, StatusHandler, StructMethod, TypeRef,
     },
     converter::GenerationTarget,
     naming::{
-      constants::{DEFAULT_MEDIA_TYPE, DEFAULT_RESPONSE_DESCRIPTION, DEFAULT_RESPONSE_VARIANT},
-      identifiers::to_rust_type_name,
+      constants::{
+        DEFAULT_MEDIA_TYPE, DEFAULT_RESPONSE_DESCRIPTION, DEFAULT_RESPONSE_VARIANT, EVENT_ENUM_SUFFIX, LINK_HEADER,
+        MALFORMED_RESPONSE_VARIANT, RATE_LIMITED_VARIANT, RESPONSE_SUFFIX, RETRY_AFTER_HEADER,
+        SERVER_EVENT_STREAM_TYPE, UNKNOWN_EVENT_VARIANT,
+      },
+      identifiers::{ensure_unique, to_rust_type_name},
       responses as naming_responses,
     },
   },
-  utils::{SchemaExt as _, SchemaInspect, parse_schema_ref_path, schema_ext::SchemaExtIters},
+  utils::{SchemaExt as _, SchemaInspect, SchemaResolveExt as _, parse_schema_ref_path, schema_ext::SchemaExtIters},
 };
 
 /// Extracted metadata about operation responses for code generation.
@@ -60,11 +68,28 @@ impl ResponseConverter {
 
   /// Builds a response enum for an operation.
   ///
+  /// Success variants of `GET` operations whose response declares a `Link` header carry
+  /// its page links when [`CodegenConfig::page_links`](super::CodegenConfig::page_links) is set.
+  ///
+  /// A `429` response becomes a `RateLimited` variant carrying the `Retry-After` delay.
+  ///
+  /// When [`CodegenConfig::response_classes`](super::CodegenConfig::response_classes) is
+  /// set, records the class of each documented status. A response declaring a
+  /// `Retry-After` header is retryable whatever its code.
+  ///
   /// Returns `None` if the operation has no responses or only empty responses.
-  pub(crate) fn build_enum(&self, name: &str, operation: &Operation, path: &str) -> Option<ResponseEnumDef> {
+  pub(crate) fn build_enum(
+    &self,
+    name: &str,
+    operation: &Operation,
+    path: &str,
+    method: &Method,
+  ) -> Option<ResponseEnumDef> {
     let spec = self.context.graph().spec();
     let responses = operation.responses.as_ref()?;
     let base_name = to_rust_type_name(name);
+    let events_stem = base_name.strip_suffix(RESPONSE_SUFFIX).unwrap_or(&base_name);
+    let page_links = self.context.config.page_links() && *method == Method::GET;
 
     let variants = responses
       .iter()
@@ -73,18 +98,34 @@ impl ResponseConverter {
         let status_code = status_str
           .parse::<StatusCodeToken>()
           .unwrap_or(StatusCodeToken::Default);
+        let events_enum = self.events_enum_name(events_stem, &base_name, status_code);
         let media_types = Self::with_default_media_type(
           self
-            .extract_media_types(&response, path, status_code)
+            .extract_media_types(&response, path, status_code, &events_enum)
             .unwrap_or_default(),
         );
 
+        let links = page_links && status_code.is_success() && naming_responses::declares_header(&response, LINK_HEADER);
+
+        let variant_name = if status_code.is_rate_limited() {
+          EnumVariantToken::from_raw(RATE_LIMITED_VARIANT)
+        } else {
+          status_code.to_variant_token()
+        };
+
         Self::split_variants_by_content_type(
           status_code,
-          &status_code.to_variant_token(),
+          &variant_name,
           response.description.as_ref(),
           &media_types,
+          self.context.config.target,
         )
+        .into_iter()
+        .map(move |variant| ResponseVariant {
+          page_links: links,
+          retry_after: status_code.is_rate_limited(),
+          ..variant
+        })
       })
       .collect_vec();
 
@@ -94,6 +135,12 @@ impl ResponseConverter {
       return None;
     }
 
+    let status_classes = if self.context.config.response_classes() {
+      Self::status_classes(responses.iter().resolve_all(spec))
+    } else {
+      BTreeMap::new()
+    };
+
     Some(
       ResponseEnumDef::builder()
         .name(EnumToken::new(&base_name))
@@ -102,10 +149,50 @@ impl ResponseConverter {
           operation.operation_id.as_deref().unwrap_or(&base_name)
         )]))
         .variants(variants)
+        .maybe_malformed_variant(self.malformed_variant())
+        .status_classes(status_classes)
         .build(),
     )
   }
 
+  fn status_classes<'a>(
+    responses: impl Iterator<Item = (&'a String, Response)>,
+  ) -> BTreeMap<StatusCodeToken, ResponseClass> {
+    responses
+      .filter_map(|(status_str, response)| {
+        let status_code = status_str.parse::<StatusCodeToken>().ok()?;
+        let class = status_code.response_class()?;
+        let retryable = naming_responses::declares_header(&response, RETRY_AFTER_HEADER);
+        Some((status_code, if retryable { ResponseClass::Retryable } else { class }))
+      })
+      .collect()
+  }
+
+  /// Names the event enum of an event-stream response, qualifying non-success statuses
+  /// and falling back to the response name when a schema already uses the short name.
+  fn events_enum_name(&self, stem: &str, response_name: &str, status_code: StatusCodeToken) -> EnumToken {
+    let status = if status_code.is_success() {
+      String::new()
+    } else {
+      status_code.to_variant_token().to_string()
+    };
+    let name = format!("{stem}{status}{EVENT_ENUM_SUFFIX}");
+    if self.context.cache().contains_schema_name(&name) {
+      EnumToken::new(format!("{response_name}{status}{EVENT_ENUM_SUFFIX}"))
+    } else {
+      EnumToken::new(name)
+    }
+  }
+
+  /// Returns the variant used to capture undeserializable bodies, if enabled.
+  ///
+  /// Only client code parses responses, so server generation never adds it.
+  fn malformed_variant(&self) -> Option<EnumVariantToken> {
+    let config = &self.context.config;
+    (config.target == GenerationTarget::Client && config.capture_malformed_responses())
+      .then(|| EnumVariantToken::from_raw(MALFORMED_RESPONSE_VARIANT))
+  }
+
   /// Builds the `parse_response` method for a request struct.
   ///
   /// For client generation, creates a method that parses HTTP responses
@@ -126,6 +213,7 @@ impl ResponseConverter {
           response_enum: response_enum.clone(),
           status_handlers,
           default_handler,
+          malformed_variant: self.malformed_variant(),
         })
         .build(),
       GenerationTarget::Server => StructMethod::builder()
@@ -173,23 +261,113 @@ impl ResponseConverter {
   /// Extracts media type information from a response definition.
   ///
   /// Resolves schemas for each content type and maps binary responses
-  /// to `Bytes` for success status codes.
+  /// to `Bytes` for success status codes. Event streams with named events
+  /// are typed as `events_enum`.
   fn extract_media_types(
     &self,
     response: &Response,
     path: &str,
     status_code: StatusCodeToken,
+    events_enum: &EnumToken,
   ) -> anyhow::Result<Vec<ResponseMediaType>> {
     response
       .content
       .iter()
       .map(|(content_type, media_type)| {
         let schema_type = self.resolve_media_schema(content_type, media_type, path, status_code)?;
-        Ok(ResponseMediaType::with_schema(content_type, schema_type))
+        let resolved =
+          ResponseMediaType::with_schema(content_type, schema_type).with_example(self.media_example(media_type));
+        Ok(match self.named_events(&resolved, media_type, events_enum) {
+          Some(named_events) => resolved.with_named_events(named_events),
+          None => resolved,
+        })
       })
       .collect()
   }
 
+  /// Picks the example body of a response: the media type's `example`, its first
+  /// `examples` entry with a value, then the `example` of its schema.
+  fn media_example(&self, media_type: &MediaType) -> Option<serde_json::Value> {
+    let spec = self.context.graph().spec();
+    let from_media = match media_type.examples.as_ref() {
+      Some(MediaTypeExamples::Example { example }) => Some(example.clone()),
+      Some(MediaTypeExamples::Examples { examples }) => {
+        examples.values().find_map(|example| example.resolve(spec).ok()?.value)
+      }
+      None => None,
+    };
+    from_media.or_else(|| media_type.schema.as_ref()?.resolve_object(spec).ok()?.example)
+  }
+
+  /// Collects the named events of a client event-stream response.
+  ///
+  /// Event names come from `x-event-name` on the `$ref` members of a `oneOf`/`anyOf`
+  /// schema, falling back to the keys of its discriminator mapping. Returns `None`
+  /// when the schema names no events, leaving every event decoded as the schema type.
+  fn named_events(
+    &self,
+    resolved: &ResponseMediaType,
+    media_type: &MediaType,
+    name: &EnumToken,
+  ) -> Option<NamedEventsDef> {
+    if resolved.category != ContentCategory::EventStream || self.context.config.target != GenerationTarget::Client {
+      return None;
+    }
+
+    let spec = self.context.graph().spec();
+    let schema = media_type.schema.as_ref()?.resolve_object(spec).ok()?;
+
+    let tagged = schema
+      .union_variants()
+      .filter_map(|member| {
+        let schema_name = parse_schema_ref_path(member.ref_path()?)?;
+        let member = member.resolve_object(spec).ok()?;
+        Some((member.event_name()?.to_string(), schema_name))
+      })
+      .collect_vec();
+
+    let events = if tagged.is_empty() {
+      schema
+        .discriminator
+        .as_ref()?
+        .mapping
+        .as_ref()?
+        .iter()
+        .map(|(event, reference)| {
+          let schema_name = parse_schema_ref_path(reference).unwrap_or_else(|| reference.clone());
+          (event.clone(), schema_name)
+        })
+        .collect_vec()
+    } else {
+      tagged
+    };
+
+    if events.is_empty() {
+      return None;
+    }
+
+    let mut used = BTreeSet::from([UNKNOWN_EVENT_VARIANT.to_string()]);
+    let events = events
+      .into_iter()
+      .unique_by(|(event, _)| event.clone())
+      .map(|(event, schema_name)| {
+        let variant = ensure_unique(&to_rust_type_name(&event), &used);
+        used.insert(variant.clone());
+        NamedEventVariant {
+          variant: EnumVariantToken::new(variant),
+          schema_type: TypeRef::new(to_rust_type_name(&schema_name)),
+          event,
+        }
+      })
+      .collect();
+
+    Some(NamedEventsDef {
+      name: name.clone(),
+      events,
+      skip_clone: false,
+    })
+  }
+
   /// Resolves the schema type for a specific media type in a response.
   ///
   /// Returns `Bytes` for binary content types on success responses,
@@ -296,8 +474,9 @@ impl ResponseConverter {
     base_name: &EnumVariantToken,
     description: Option<&String>,
     media_types: &[ResponseMediaType],
+    target: GenerationTarget,
   ) -> Vec<ResponseVariant> {
-    let grouped = Self::group_media_types_by_schema(media_types);
+    let grouped = Self::group_media_types_by_schema(media_types, target);
 
     if grouped.is_empty() {
       return vec![
@@ -343,13 +522,22 @@ impl ResponseConverter {
   }
 
   /// Groups media types by their schema type for variant splitting.
-  fn group_media_types_by_schema(media_types: &[ResponseMediaType]) -> Vec<(String, Vec<ResponseMediaType>)> {
+  ///
+  /// Event streams are decoded into `oas3_gen_support::EventStream` by clients, while
+  /// server handlers answer them with a ready `axum` response, such as an `Sse`.
+  fn group_media_types_by_schema(
+    media_types: &[ResponseMediaType],
+    target: GenerationTarget,
+  ) -> Vec<(String, Vec<ResponseMediaType>)> {
     media_types
       .iter()
       .filter_map(|media_type| {
         let schema = media_type.schema_type.as_ref()?;
-        let key = match media_type.category {
-          ContentCategory::EventStream => format!("oas3_gen_support::EventStream<{}>", schema.to_rust_type()),
+        let key = match (media_type.category, target) {
+          (ContentCategory::EventStream, GenerationTarget::Client) => {
+            format!("oas3_gen_support::EventStream<{}>", schema.to_rust_type())
+          }
+          (ContentCategory::EventStream, GenerationTarget::Server) => SERVER_EVENT_STREAM_TYPE.to_string(),
           _ => schema.to_rust_type(),
         };
         Some((key, media_type.clone()))
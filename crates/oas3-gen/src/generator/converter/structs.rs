@@ -11,7 +11,7 @@ use crate::{
     converter::ConverterContext,
     naming::{constants::DISCRIMINATED_BASE_SUFFIX, identifiers::to_rust_type_name},
   },
-  utils::SchemaExt,
+  utils::{SchemaExt, SchemaRefName},
 };
 
 #[derive(Clone, Debug)]
@@ -68,7 +68,12 @@ impl StructConverter {
 
     let fields = field_result.result;
 
-    let deny_unknown = matches!(&schema.additional_properties, Some(Schema::Boolean(b)) if !b.0);
+    let spec_denies = matches!(&schema.additional_properties, Some(Schema::Boolean(b)) if !b.0);
+    let names = [Some(name.as_str()), schema_name]
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>();
+    let deny_unknown = self.context.config().deny_unknown_fields(&names, spec_denies);
     let serde_attrs = fields.struct_serde_attrs(
       deny_unknown
         .then_some(SerdeAttribute::DenyUnknownFields)
@@ -76,7 +81,7 @@ impl StructConverter {
         .collect(),
     );
 
-    let enable_builders = matches!(kind, StructKind::Schema) && self.context.config().enable_builders();
+    let enable_builders = matches!(kind, StructKind::Schema) && self.context.config().schema_builder(&fields);
     let additional_derives = if enable_builders {
       BTreeSet::from([DeriveTrait::Builder])
     } else {
@@ -91,6 +96,7 @@ impl StructConverter {
       .fields(fields)
       .kind(kind)
       .additional_derives(additional_derives)
+      .maybe_error_message_field(schema.error_message_field())
       .build();
 
     Ok(ConversionOutput::with_inline_types(
@@ -114,16 +120,41 @@ impl StructConverter {
       anyhow::bail!("Parent schema for discriminated child '{name}' is not a valid discriminator base");
     }
 
-    let result = self.build_struct(
+    let mut result = self.build_struct(
       StructToken::from_raw(name),
       merged_schema,
       Some(name),
       StructKind::Schema,
     )?;
+    self.assign_parents(name, &mut result.result);
 
     Ok(std::iter::once(result.result).chain(result.inline_types).collect())
   }
 
+  /// Records the structs a schema extends through `allOf` references.
+  ///
+  /// Postprocessing compares the fields of each parent with the merged child to
+  /// generate conversions between them.
+  fn assign_parents(&self, name: &str, struct_type: &mut RustType) {
+    let RustType::Struct(def) = struct_type else {
+      return;
+    };
+    let graph = self.context.graph();
+    let Some(schema) = graph.get(name) else {
+      return;
+    };
+    def.parents = schema
+      .all_of
+      .iter()
+      .filter_map(SchemaRefName::schema_ref_name)
+      .filter_map(|parent| {
+        graph
+          .resolved(&parent)
+          .map(|parent_schema| Self::struct_name(&parent, parent_schema))
+      })
+      .collect();
+  }
+
   /// Converts an OpenAPI object schema into a Rust struct definition.
   ///
   /// Routes to [`build_struct`] for field extraction and registers the
@@ -176,12 +207,13 @@ impl StructConverter {
 
     let effective_schema = graph.resolved(name).unwrap_or(&merged_info.schema);
 
-    let result = self.build_struct(
+    let mut result = self.build_struct(
       Self::struct_name(name, effective_schema),
       effective_schema,
       Some(name),
       StructKind::Schema,
     )?;
+    self.assign_parents(name, &mut result.result);
     self.finalize_struct_types(name, effective_schema, result.result, result.inline_types)
   }
 
@@ -9,7 +9,7 @@ use crate::{
       RustPrimitive, RustType, SerdeAttribute, TypeRef, VariantContent, VariantDef,
     },
     converter::{
-      SchemaConverter,
+      CodegenConfig, DeserializeProfile, EnumDeserializePolicy, IntegerEnumPolicy, SchemaConverter,
       union_types::CollisionStrategy,
       unions::{EnumConverter, UnionConverter},
     },
@@ -206,6 +206,46 @@ fn test_anyof_with_discriminator_no_untagged() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_oneof_discriminator_without_mapping_uses_schema_names() -> anyhow::Result<()> {
+  let variant = json!({
+    "type": "object",
+    "required": ["petType"],
+    "properties": {
+      "petType": { "type": "string" }
+    }
+  });
+  let graph = create_test_graph(parse_schemas(vec![
+    (
+      "Pet",
+      json!({
+        "oneOf": [
+          { "$ref": "#/components/schemas/Cat" },
+          { "$ref": "#/components/schemas/Dog" }
+        ],
+        "discriminator": { "propertyName": "petType" }
+      }),
+    ),
+    ("Cat", variant.clone()),
+    ("Dog", variant),
+  ]));
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Pet", graph.get("Pet").unwrap())?;
+
+  let RustType::DiscriminatedEnum(enum_def) = result.last().unwrap() else {
+    panic!("Expected DiscriminatedEnum as last type, got {:?}", result.last())
+  };
+  assert_eq!(enum_def.discriminator_field, "petType");
+  let values = enum_def
+    .variants
+    .iter()
+    .map(|variant| variant.discriminator_values.clone())
+    .collect::<Vec<_>>();
+  assert_eq!(values, vec![vec!["Cat".to_string()], vec!["Dog".to_string()]]);
+  Ok(())
+}
+
 #[test]
 fn test_empty_enum_converts_to_string() -> anyhow::Result<()> {
   let graph = create_test_graph(parse_schemas(vec![(
@@ -373,6 +413,164 @@ fn test_integer_enum_values() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_open_integer_enums_gain_other_variant() -> anyhow::Result<()> {
+  let graph = create_test_graph(parse_schemas(vec![
+    ("Level", json!({ "type": "integer", "enum": [1, 2] })),
+    (
+      "Size",
+      json!({ "type": "integer", "format": "uint32", "enum": [8, 16] }),
+    ),
+    ("Ratio", json!({ "type": "number", "enum": [0.5, 1.5] })),
+    ("Color", json!({ "type": "string", "enum": ["red"] })),
+  ]));
+  let config = CodegenConfig::builder().integer_enums(IntegerEnumPolicy::Open).build();
+  let context = create_test_context(graph.clone(), config);
+  let converter = SchemaConverter::new(&context);
+  let convert_enum = |name: &str| -> anyhow::Result<EnumDef> {
+    match converter.convert_schema(name, graph.get(name).unwrap())?.remove(0) {
+      RustType::Enum(def) => Ok(def),
+      other => panic!("Expected enum for {name}, got {other:?}"),
+    }
+  };
+
+  let level = convert_enum("Level")?;
+  let other = level.scalar_fallback().expect("integer enums should be open");
+  assert_eq!(other.name, EnumVariantToken::new("Other"));
+  assert_eq!(
+    other.content,
+    VariantContent::Tuple(vec![TypeRef::new(RustPrimitive::I64)])
+  );
+  assert!(level.derives().contains(&DeriveTrait::Hash));
+
+  let size = convert_enum("Size")?;
+  assert_eq!(
+    size.scalar_fallback().map(|v| v.content.clone()),
+    Some(VariantContent::Tuple(vec![TypeRef::new(RustPrimitive::U64)]))
+  );
+
+  assert!(convert_enum("Ratio")?.scalar_fallback().is_none());
+  assert_eq!(convert_enum("Color")?.variants.len(), 1);
+
+  let closed_context = create_test_context(graph.clone(), default_config());
+  let closed = SchemaConverter::new(&closed_context).convert_schema("Level", graph.get("Level").unwrap())?;
+  let RustType::Enum(closed) = &closed[0] else {
+    panic!("Expected enum")
+  };
+  assert!(closed.scalar_fallback().is_none());
+  Ok(())
+}
+
+#[test]
+fn test_scalar_const_schemas_become_single_variant_enums() -> anyhow::Result<()> {
+  let graph = create_test_graph(parse_schemas(vec![
+    ("Version", json!({ "type": "integer", "const": 2 })),
+    ("Enabled", json!({ "const": true })),
+    ("Level", json!({ "oneOf": [{ "const": 1 }, { "const": 2 }] })),
+    ("Kind", json!({ "type": "string", "const": "dog" })),
+    (
+      "Payload",
+      json!({
+        "type": "object",
+        "required": ["version"],
+        "properties": { "version": { "$ref": "#/components/schemas/Version" } }
+      }),
+    ),
+  ]));
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+
+  let convert_enum = |name: &str| -> anyhow::Result<EnumDef> {
+    match converter.convert_schema(name, graph.get(name).unwrap())?.remove(0) {
+      RustType::Enum(def) => Ok(def),
+      other => panic!("Expected enum for {name}, got {other:?}"),
+    }
+  };
+
+  let version = convert_enum("Version")?;
+  assert_eq!(version.scalar_repr, Some(RustPrimitive::I64));
+  assert_eq!(version.variants.len(), 1);
+  assert_eq!(version.variants[0].serde_name(), "2");
+
+  let enabled = convert_enum("Enabled")?;
+  assert_eq!(enabled.scalar_repr, Some(RustPrimitive::Bool));
+  assert_eq!(enabled.variants[0].name, EnumVariantToken::new("True"));
+
+  let level = convert_enum("Level")?;
+  assert_eq!(level.scalar_repr, Some(RustPrimitive::I64));
+  assert_eq!(level.variants.len(), 2);
+
+  let kind = converter.convert_schema("Kind", graph.get("Kind").unwrap())?;
+  assert!(
+    matches!(kind[0], RustType::TypeAlias(_)),
+    "string consts should stay type aliases"
+  );
+
+  let payload = converter.convert_schema("Payload", graph.get("Payload").unwrap())?;
+  let RustType::Struct(payload) = &payload[0] else {
+    panic!("Expected struct")
+  };
+  assert_eq!(payload.fields[0].rust_type.to_rust_type(), "Version");
+  Ok(())
+}
+
+#[test]
+fn test_inline_scalar_const_properties_become_single_variant_enums() -> anyhow::Result<()> {
+  let graph = create_test_graph(parse_schemas(vec![(
+    "Payload",
+    json!({
+      "type": "object",
+      "required": ["version"],
+      "properties": {
+        "version": { "type": "integer", "const": 3 },
+        "enabled": { "const": true },
+        "kind": { "type": "string", "const": "dog" }
+      }
+    }),
+  )]));
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+
+  let types = converter.convert_schema("Payload", graph.get("Payload").unwrap())?;
+  let payload = types
+    .iter()
+    .find_map(|ty| match ty {
+      RustType::Struct(def) if def.name == "Payload" => Some(def),
+      _ => None,
+    })
+    .expect("Payload struct");
+  let field_type = |name: &str| {
+    payload
+      .fields
+      .iter()
+      .find(|field| field.name == name)
+      .map(|field| field.rust_type.to_rust_type())
+      .unwrap()
+  };
+  assert_eq!(field_type("version"), "PayloadVersion");
+  assert_eq!(field_type("enabled"), "Option<PayloadEnabled>");
+  assert_eq!(field_type("kind"), "Option<String>");
+
+  let binding = context.cache.borrow();
+  let all_types = types.iter().chain(binding.types.types.iter()).collect::<Vec<_>>();
+  let value_enum = |name: &str| {
+    all_types
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Enum(def) if def.name == name => Some(def),
+        _ => None,
+      })
+      .unwrap_or_else(|| panic!("{name} enum"))
+  };
+  let version = value_enum("PayloadVersion");
+  assert_eq!(version.scalar_repr, Some(RustPrimitive::I64));
+  assert_eq!(version.variants[0].serde_name(), "3");
+  let enabled = value_enum("PayloadEnabled");
+  assert_eq!(enabled.scalar_repr, Some(RustPrimitive::Bool));
+  assert_eq!(enabled.variants[0].name, EnumVariantToken::new("True"));
+  Ok(())
+}
+
 #[test]
 fn test_case_insensitive_duplicates_with_preservation() -> anyhow::Result<()> {
   let graph = create_test_graph(parse_schemas(vec![(
@@ -1760,6 +1958,73 @@ fn test_sorted_layout_value_enum_sorts_variants_alphabetically() -> anyhow::Resu
   Ok(())
 }
 
+#[test]
+fn test_lenient_profile_adds_unknown_fallback_variant() -> anyhow::Result<()> {
+  let lenient = CodegenConfig {
+    deserialize_profile: DeserializeProfile::Lenient,
+    ..Default::default()
+  };
+
+  struct Case {
+    enum_values: Vec<&'static str>,
+    config: CodegenConfig,
+    expected: Vec<&'static str>,
+    other_attr: bool,
+  }
+
+  let cases = [
+    Case {
+      enum_values: vec!["active", "retired"],
+      config: default_config(),
+      expected: vec!["Active", "Retired"],
+      other_attr: false,
+    },
+    Case {
+      enum_values: vec!["active", "retired"],
+      config: lenient.clone(),
+      expected: vec!["Active", "Retired", "Unknown"],
+      other_attr: true,
+    },
+    Case {
+      enum_values: vec!["unknown", "active"],
+      config: lenient.clone(),
+      expected: vec!["Unknown", "Active"],
+      other_attr: true,
+    },
+    Case {
+      enum_values: vec!["active", "retired"],
+      config: CodegenConfig {
+        enum_deserialize: EnumDeserializePolicy::CaseInsensitive,
+        ..lenient
+      },
+      expected: vec!["Active", "Retired", "Unknown"],
+      other_attr: false,
+    },
+  ];
+
+  for case in cases {
+    let graph = create_test_graph(parse_schemas(vec![(
+      "Status",
+      json!({ "type": "string", "enum": case.enum_values }),
+    )]));
+    let context = create_test_context(graph.clone(), case.config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Status", graph.get("Status").unwrap())?;
+
+    let RustType::Enum(enum_def) = &result[0] else {
+      panic!("Expected enum")
+    };
+    let names = enum_def.variants.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, case.expected, "{:?}", case.enum_values);
+    let has_other = enum_def
+      .variants
+      .iter()
+      .any(|v| v.serde_attrs.contains(&SerdeAttribute::Other));
+    assert_eq!(has_other, case.other_attr, "{:?}", case.enum_values);
+  }
+  Ok(())
+}
+
 #[test]
 fn test_default_layout_value_enum_preserves_spec_order() -> anyhow::Result<()> {
   let graph = create_test_graph(parse_schemas(vec![(
@@ -1825,6 +2090,124 @@ fn test_sorted_layout_value_enum_permutation_invariance() -> anyhow::Result<()>
   Ok(())
 }
 
+#[test]
+fn test_symbol_collisions_spell_symbols_independently_of_order() -> anyhow::Result<()> {
+  let orders = [vec!["A/B", "AB", "a/b"], vec!["a/b", "AB", "A/B"]];
+
+  for values in orders {
+    let graph = create_test_graph(parse_schemas(vec![(
+      "Ratio",
+      json!({ "type": "string", "enum": values }),
+    )]));
+    let context = create_test_context(graph.clone(), default_config());
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Ratio", graph.get("Ratio").unwrap())?;
+    let RustType::Enum(enum_def) = &result[0] else {
+      panic!("Expected enum")
+    };
+
+    let variants = enum_def
+      .variants
+      .iter()
+      .map(|v| (v.name.to_string(), v.serde_attrs.clone()))
+      .collect::<BTreeSet<_>>();
+    let slash = variants
+      .iter()
+      .find(|(name, _)| name == "ASlashB")
+      .unwrap_or_else(|| panic!("{values:?} should spell the slash: {variants:?}"));
+    assert!(
+      slash.1.contains(&SerdeAttribute::Alias("a/b".to_string()))
+        || slash.1.contains(&SerdeAttribute::Alias("A/B".to_string())),
+      "{values:?}: case variants of a spelled value still merge"
+    );
+    assert!(
+      variants.iter().any(|(name, _)| name == "Ab"),
+      "{values:?}: value without symbols keeps its name"
+    );
+    assert_eq!(variants.len(), 2, "{values:?}");
+  }
+  Ok(())
+}
+
+#[test]
+fn test_enum_varnames_extension_overrides_variant_names() -> anyhow::Result<()> {
+  let graph = create_test_graph(parse_schemas(vec![(
+    "Grade",
+    json!({
+      "type": "string",
+      "enum": ["a+", "a-", "b"],
+      "x-enum-varnames": ["APlus", "AMinus", "B"]
+    }),
+  )]));
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Grade", graph.get("Grade").unwrap())?;
+  let RustType::Enum(enum_def) = &result[0] else {
+    panic!("Expected enum")
+  };
+
+  let variants = enum_def
+    .variants
+    .iter()
+    .map(|v| (v.name.as_str(), v.serde_name()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    variants,
+    vec![
+      ("APlus", "a+".to_string()),
+      ("AMinus", "a-".to_string()),
+      ("B", "b".to_string())
+    ]
+  );
+  Ok(())
+}
+
+#[test]
+fn test_enum_names_extension_names_numeric_variants() -> anyhow::Result<()> {
+  let graph = create_test_graph(parse_schemas(vec![
+    (
+      "Priority",
+      json!({
+        "type": "integer",
+        "enum": [1, 2, 3],
+        "x-enumNames": ["Low", "Medium", "high priority"]
+      }),
+    ),
+    (
+      "Level",
+      json!({
+        "type": "integer",
+        "enum": [1, 2],
+        "x-enum-varnames": ["Low"],
+        "x-enumNames": ["Debug", "Info"]
+      }),
+    ),
+  ]));
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+
+  for (schema, expected) in [
+    ("Priority", vec![("Low", 1), ("Medium", 2), ("HighPriority", 3)]),
+    ("Level", vec![("Debug", 1), ("Info", 2)]),
+  ] {
+    let result = converter.convert_schema(schema, graph.get(schema).unwrap())?;
+    let RustType::Enum(enum_def) = &result[0] else {
+      panic!("Expected enum")
+    };
+    let variants = enum_def
+      .variants
+      .iter()
+      .map(|v| (v.name.as_str(), v.serde_name()))
+      .collect::<Vec<_>>();
+    let expected = expected
+      .into_iter()
+      .map(|(name, value)| (name, value.to_string()))
+      .collect::<Vec<_>>();
+    assert_eq!(variants, expected, "{schema}");
+  }
+  Ok(())
+}
+
 #[test]
 fn test_sorted_layout_union_variants_sorted_alphabetically() -> anyhow::Result<()> {
   let graph = create_test_graph(parse_schemas(vec![
@@ -1,11 +1,15 @@
 use std::collections::{BTreeMap, HashMap};
 
 use oas3::spec::{ObjectOrReference, ObjectSchema, Schema, SchemaType, SchemaTypeSet};
+use quote::ToTokens;
 
 use crate::{
   generator::{
-    ast::{OuterAttr, RustPrimitive, RustType, SerdeAsFieldAttr},
-    converter::{CodegenConfig, SchemaConverter, fields::FieldConverter},
+    ast::{EpochTimestamp, OuterAttr, RustPrimitive, RustType, SerdeAsFieldAttr, SerdeAttribute},
+    converter::{
+      CodegenConfig, DecimalPolicy, DeserializeProfile, DurationFormatPolicy, IntegerWidthPolicy, NumberCoercionPolicy,
+      SchemaConverter, UnsignedIntegerPolicy, fields::FieldConverter,
+    },
   },
   tests::common::{create_test_context, create_test_graph, make_field},
 };
@@ -329,6 +333,719 @@ fn test_no_serde_as_attr_without_customization() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_lenient_profile_relaxes_scalar_fields() -> anyhow::Result<()> {
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  for (name, schema_type) in [
+    ("count", SchemaType::Integer),
+    ("weight", SchemaType::Number),
+    ("label", SchemaType::String),
+    ("name", SchemaType::String),
+    ("tags", SchemaType::Array),
+  ] {
+    schema.properties.insert(
+      name.to_string(),
+      object_schema(ObjectSchema {
+        schema_type: Some(SchemaTypeSet::Single(schema_type)),
+        items: (schema_type == SchemaType::Array).then(|| {
+          Box::new(object_schema(ObjectSchema {
+            schema_type: Some(SchemaTypeSet::Single(SchemaType::Integer)),
+            ..Default::default()
+          }))
+        }),
+        ..Default::default()
+      }),
+    );
+  }
+  schema.required = vec!["count".to_string(), "name".to_string()];
+
+  let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+  let config = CodegenConfig {
+    deserialize_profile: DeserializeProfile::Lenient,
+    ..Default::default()
+  };
+  let context = create_test_context(graph.clone(), config);
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+  let struct_def = result
+    .iter()
+    .find_map(|ty| match ty {
+      RustType::Struct(def) => Some(def),
+      _ => None,
+    })
+    .expect("Struct should be present");
+
+  let cases = [
+    (
+      "count",
+      Some(SerdeAsFieldAttr::Lenient {
+        coerce_number: true,
+        optional: false,
+      }),
+    ),
+    (
+      "weight",
+      Some(SerdeAsFieldAttr::Lenient {
+        coerce_number: true,
+        optional: true,
+      }),
+    ),
+    (
+      "label",
+      Some(SerdeAsFieldAttr::Lenient {
+        coerce_number: false,
+        optional: true,
+      }),
+    ),
+    ("name", None),
+    (
+      "tags",
+      Some(SerdeAsFieldAttr::CoerceNumber {
+        optional: true,
+        is_array: true,
+      }),
+    ),
+  ];
+  for (name, expected) in cases {
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == name)
+      .unwrap_or_else(|| panic!("{name} field should exist"));
+    assert_eq!(field.serde_as_attr, expected, "{name}");
+    let optional = matches!(expected, Some(SerdeAsFieldAttr::Lenient { optional: true, .. }));
+    assert_eq!(
+      field.serde_attrs.contains(&SerdeAttribute::Default),
+      optional,
+      "{name}: serde(default)"
+    );
+  }
+  assert!(struct_def.outer_attrs.contains(&OuterAttr::SerdeAs));
+
+  Ok(())
+}
+
+#[test]
+fn test_number_coercion_applies_to_numeric_fields_and_arrays() -> anyhow::Result<()> {
+  let integer_schema = || {
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Integer)),
+      ..Default::default()
+    })
+  };
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  schema.properties.insert("count".to_string(), integer_schema());
+  schema.properties.insert(
+    "weight".to_string(),
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Number)),
+      ..Default::default()
+    }),
+  );
+  schema.properties.insert(
+    "label".to_string(),
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::String)),
+      ..Default::default()
+    }),
+  );
+  schema.properties.insert(
+    "ids".to_string(),
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Array)),
+      items: Some(Box::new(integer_schema())),
+      ..Default::default()
+    }),
+  );
+  schema.properties.insert(
+    "unique_ids".to_string(),
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Array)),
+      items: Some(Box::new(integer_schema())),
+      unique_items: Some(true),
+      ..Default::default()
+    }),
+  );
+  schema.required = vec!["count".to_string(), "ids".to_string(), "unique_ids".to_string()];
+
+  let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+  let config = CodegenConfig {
+    number_coercion: NumberCoercionPolicy::Coerce,
+    ..Default::default()
+  };
+  let context = create_test_context(graph.clone(), config);
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+  let struct_def = result
+    .iter()
+    .find_map(|ty| match ty {
+      RustType::Struct(def) => Some(def),
+      _ => None,
+    })
+    .expect("Struct should be present");
+
+  let cases = [
+    (
+      "count",
+      Some(SerdeAsFieldAttr::CoerceNumber {
+        optional: false,
+        is_array: false,
+      }),
+    ),
+    (
+      "weight",
+      Some(SerdeAsFieldAttr::CoerceNumber {
+        optional: true,
+        is_array: false,
+      }),
+    ),
+    (
+      "ids",
+      Some(SerdeAsFieldAttr::CoerceNumber {
+        optional: false,
+        is_array: true,
+      }),
+    ),
+    ("unique_ids", None),
+    ("label", None),
+  ];
+  for (name, expected) in cases {
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == name)
+      .unwrap_or_else(|| panic!("{name} field should exist"));
+    assert_eq!(field.serde_as_attr, expected, "{name}");
+  }
+  assert!(struct_def.outer_attrs.contains(&OuterAttr::SerdeAs));
+
+  Ok(())
+}
+
+#[test]
+fn test_epoch_timestamp_formats_map_to_datetime() -> anyhow::Result<()> {
+  let timestamp_schema = |schema_type: SchemaType, format: Option<&str>, extension: Option<&str>| {
+    let mut schema = ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(schema_type)),
+      format: format.map(str::to_string),
+      minimum: Some(0.into()),
+      ..Default::default()
+    };
+    if let Some(extension) = extension {
+      schema
+        .extensions
+        .insert("timestamp-format".to_string(), serde_json::json!(extension));
+    }
+    schema
+  };
+
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  schema.properties.insert(
+    "created_at".to_string(),
+    object_schema(timestamp_schema(SchemaType::Integer, Some("unix-time"), None)),
+  );
+  schema.properties.insert(
+    "updated_at".to_string(),
+    object_schema(timestamp_schema(SchemaType::Integer, None, Some("epoch-millis"))),
+  );
+  schema.properties.insert(
+    "observed_at".to_string(),
+    object_schema(timestamp_schema(SchemaType::Number, Some("unix-time"), None)),
+  );
+  schema.properties.insert(
+    "history".to_string(),
+    object_schema(ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Array)),
+      items: Some(Box::new(object_schema(timestamp_schema(
+        SchemaType::Integer,
+        Some("unix-time"),
+        None,
+      )))),
+      ..Default::default()
+    }),
+  );
+  schema.properties.insert(
+    "count".to_string(),
+    object_schema(timestamp_schema(SchemaType::Integer, Some("int64"), None)),
+  );
+  schema.required = vec!["created_at".to_string(), "history".to_string()];
+
+  let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+  let context = create_test_context(graph.clone(), CodegenConfig::default());
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+  let struct_def = result
+    .iter()
+    .find_map(|ty| match ty {
+      RustType::Struct(def) => Some(def),
+      _ => None,
+    })
+    .expect("Struct should be present");
+
+  let timestamp = |epoch, optional, is_array| {
+    Some(SerdeAsFieldAttr::Timestamp {
+      epoch,
+      optional,
+      is_array,
+    })
+  };
+  let cases = [
+    ("created_at", timestamp(EpochTimestamp::Seconds, false, false)),
+    ("updated_at", timestamp(EpochTimestamp::Milliseconds, true, false)),
+    ("observed_at", timestamp(EpochTimestamp::FractionalSeconds, true, false)),
+    ("history", timestamp(EpochTimestamp::Seconds, false, true)),
+    ("count", None),
+  ];
+  for (name, expected) in cases {
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == name)
+      .unwrap_or_else(|| panic!("{name} field should exist"));
+    assert_eq!(field.serde_as_attr, expected, "{name}");
+    let expected_type = if expected.is_some() {
+      RustPrimitive::DateTime
+    } else {
+      RustPrimitive::I64
+    };
+    assert_eq!(field.rust_type.base_type, expected_type, "{name}");
+    assert_eq!(
+      field.validation_attrs.is_empty(),
+      expected.is_some(),
+      "{name}: validation"
+    );
+  }
+
+  Ok(())
+}
+
+#[test]
+fn test_duration_format_emits_duration_adapter() -> anyhow::Result<()> {
+  struct Case {
+    config: CodegenConfig,
+    expected: &'static str,
+  }
+
+  let cases = [
+    Case {
+      config: CodegenConfig::default(),
+      expected: "oas3_gen_support::Iso8601Duration",
+    },
+    Case {
+      config: CodegenConfig {
+        duration_format: DurationFormatPolicy::Humantime,
+        ..Default::default()
+      },
+      expected: "oas3_gen_support::HumantimeDuration",
+    },
+    Case {
+      config: config_with_customizations(HashMap::from([(
+        "duration".to_string(),
+        "crate::MyDuration".to_string(),
+      )])),
+      expected: "crate::MyDuration",
+    },
+  ];
+
+  for case in cases {
+    let mut schema = ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+      ..Default::default()
+    };
+    schema.properties.insert(
+      "timeout".to_string(),
+      object_schema(ObjectSchema {
+        schema_type: Some(SchemaTypeSet::Single(SchemaType::String)),
+        format: Some("duration".to_string()),
+        ..Default::default()
+      }),
+    );
+
+    let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+    let context = create_test_context(graph.clone(), case.config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+    let struct_def = result
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) => Some(def),
+        _ => None,
+      })
+      .expect("Struct should be present");
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == "timeout")
+      .expect("timeout field should exist");
+
+    assert_eq!(field.rust_type.base_type, RustPrimitive::Duration);
+    assert_eq!(
+      field.serde_as_attr,
+      Some(SerdeAsFieldAttr::CustomOverride {
+        custom_type: case.expected.to_string(),
+        optional: true,
+        is_array: false,
+      }),
+      "{}",
+      case.expected
+    );
+  }
+
+  Ok(())
+}
+
+#[test]
+fn test_integer_width_widens_formatted_integers() -> anyhow::Result<()> {
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  for (name, schema_type, format) in [
+    ("count", SchemaType::Integer, "int32"),
+    ("size", SchemaType::Integer, "uint16"),
+    ("id", SchemaType::Integer, "int64"),
+    ("ratio", SchemaType::Number, "float"),
+  ] {
+    schema.properties.insert(
+      name.to_string(),
+      object_schema(ObjectSchema {
+        schema_type: Some(SchemaTypeSet::Single(schema_type)),
+        format: Some(format.to_string()),
+        ..Default::default()
+      }),
+    );
+  }
+
+  let cases = [
+    (
+      IntegerWidthPolicy::Spec,
+      [
+        ("count", RustPrimitive::I32),
+        ("size", RustPrimitive::U16),
+        ("id", RustPrimitive::I64),
+        ("ratio", RustPrimitive::F32),
+      ],
+    ),
+    (
+      IntegerWidthPolicy::Wide,
+      [
+        ("count", RustPrimitive::I64),
+        ("size", RustPrimitive::U64),
+        ("id", RustPrimitive::I64),
+        ("ratio", RustPrimitive::F32),
+      ],
+    ),
+  ];
+  for (integer_width, expected) in cases {
+    let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema.clone())]));
+    let config = CodegenConfig {
+      integer_width,
+      ..Default::default()
+    };
+    let context = create_test_context(graph.clone(), config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+    let struct_def = result
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) => Some(def),
+        _ => None,
+      })
+      .expect("Struct should be present");
+    for (name, primitive) in expected {
+      let field = struct_def
+        .fields
+        .iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("{name} field should exist"));
+      assert_eq!(field.rust_type.base_type, primitive, "{integer_width:?}: {name}");
+    }
+  }
+
+  Ok(())
+}
+
+#[test]
+fn test_unsigned_from_minimum_maps_non_negative_integers() -> anyhow::Result<()> {
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  for (name, format, minimum, exclusive_minimum, maximum) in [
+    ("count", None, Some(0), None, None),
+    ("limit", Some("int32"), Some(1), None, Some(100)),
+    ("page", Some("int16"), None, Some(0), None),
+    ("offset", None, Some(-1), None, None),
+  ] {
+    schema.properties.insert(
+      name.to_string(),
+      object_schema(ObjectSchema {
+        schema_type: Some(SchemaTypeSet::Single(SchemaType::Integer)),
+        format: format.map(ToString::to_string),
+        minimum: minimum.map(serde_json::Number::from),
+        exclusive_minimum: exclusive_minimum.map(serde_json::Number::from),
+        maximum: maximum.map(serde_json::Number::from),
+        ..Default::default()
+      }),
+    );
+  }
+
+  let cases = [
+    (
+      UnsignedIntegerPolicy::Format,
+      [
+        ("count", RustPrimitive::I64, Some("range (min = 0i64)")),
+        ("limit", RustPrimitive::I32, Some("range (min = 1i32 , max = 100i32)")),
+        ("page", RustPrimitive::I16, Some("range (exclusive_min = 0i16)")),
+        ("offset", RustPrimitive::I64, Some("range (min = - 1i64)")),
+      ],
+    ),
+    (
+      UnsignedIntegerPolicy::FromMinimum,
+      [
+        ("count", RustPrimitive::U64, None),
+        ("limit", RustPrimitive::U32, Some("range (min = 1u32 , max = 100u32)")),
+        ("page", RustPrimitive::U16, Some("range (exclusive_min = 0u16)")),
+        ("offset", RustPrimitive::I64, Some("range (min = - 1i64)")),
+      ],
+    ),
+  ];
+  for (unsigned_integers, expected) in cases {
+    let graph = create_test_graph(BTreeMap::from([("Usage".to_string(), schema.clone())]));
+    let config = CodegenConfig {
+      unsigned_integers,
+      ..Default::default()
+    };
+    let context = create_test_context(graph.clone(), config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Usage", graph.get("Usage").unwrap())?;
+
+    let struct_def = result
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) => Some(def),
+        _ => None,
+      })
+      .expect("Struct should be present");
+    for (name, primitive, range) in expected {
+      let field = struct_def
+        .fields
+        .iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("{name} field should exist"));
+      assert_eq!(field.rust_type.base_type, primitive, "{unsigned_integers:?}: {name}");
+      let validation = field
+        .validation_attrs
+        .iter()
+        .map(|attr| attr.to_token_stream().to_string())
+        .collect::<Vec<_>>();
+      assert_eq!(
+        validation,
+        range.map(ToString::to_string).into_iter().collect::<Vec<_>>(),
+        "{unsigned_integers:?}: {name}"
+      );
+    }
+  }
+
+  Ok(())
+}
+
+#[test]
+fn test_money_format_maps_to_fixed_scale_decimal() -> anyhow::Result<()> {
+  let money_schema = |schema_type: SchemaType, scale: Option<u64>| {
+    let mut schema = ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(schema_type)),
+      max_length: Some(20),
+      ..Default::default()
+    };
+    schema
+      .extensions
+      .insert("format".to_string(), serde_json::json!("money"));
+    if let Some(scale) = scale {
+      schema.extensions.insert("scale".to_string(), serde_json::json!(scale));
+    }
+    object_schema(schema)
+  };
+
+  let mut schema = ObjectSchema {
+    schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+    ..Default::default()
+  };
+  schema
+    .properties
+    .insert("total".to_string(), money_schema(SchemaType::String, None));
+  schema
+    .properties
+    .insert("rate".to_string(), money_schema(SchemaType::String, Some(4)));
+  schema
+    .properties
+    .insert("huge".to_string(), money_schema(SchemaType::String, Some(40)));
+  schema
+    .properties
+    .insert("weight".to_string(), money_schema(SchemaType::Number, None));
+  schema.required = vec!["total".to_string(), "rate".to_string(), "huge".to_string()];
+
+  let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+  let context = create_test_context(graph.clone(), CodegenConfig::default());
+  let converter = SchemaConverter::new(&context);
+  let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+  let struct_def = result
+    .iter()
+    .find_map(|ty| match ty {
+      RustType::Struct(def) => Some(def),
+      _ => None,
+    })
+    .expect("Struct should be present");
+
+  let cases = [
+    ("total", "oas3_gen_support::Money<2>"),
+    ("rate", "oas3_gen_support::Money<4>"),
+    ("huge", "oas3_gen_support::Money<2>"),
+    ("weight", "f64"),
+  ];
+  for (name, expected) in cases {
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == name)
+      .unwrap_or_else(|| panic!("{name} field should exist"));
+    assert_eq!(field.rust_type.base_type.to_string(), expected, "{name}");
+    assert!(field.serde_as_attr.is_none(), "{name}");
+    if expected != "f64" {
+      assert!(
+        field.validation_attrs.is_empty(),
+        "{name}: money fields skip length validation"
+      );
+    }
+  }
+
+  Ok(())
+}
+
+#[test]
+fn test_decimal_format_follows_decimal_policy() -> anyhow::Result<()> {
+  struct Case {
+    config: CodegenConfig,
+    expected_type: &'static str,
+    expected_adapter: Option<&'static str>,
+  }
+
+  let cases = [
+    Case {
+      config: CodegenConfig::default(),
+      expected_type: "String",
+      expected_adapter: None,
+    },
+    Case {
+      config: CodegenConfig {
+        decimal: DecimalPolicy::RustDecimal,
+        ..Default::default()
+      },
+      expected_type: "oas3_gen_support::Decimal",
+      expected_adapter: Some("serde_with::DisplayFromStr"),
+    },
+    Case {
+      config: CodegenConfig {
+        decimal: DecimalPolicy::BigDecimal,
+        ..Default::default()
+      },
+      expected_type: "bigdecimal::BigDecimal",
+      expected_adapter: Some("serde_with::DisplayFromStr"),
+    },
+    Case {
+      config: CodegenConfig {
+        decimal: DecimalPolicy::RustDecimal,
+        customizations: HashMap::from([("decimal".to_string(), "crate::DecimalAsNumber".to_string())]),
+        ..Default::default()
+      },
+      expected_type: "oas3_gen_support::Decimal",
+      expected_adapter: Some("crate::DecimalAsNumber"),
+    },
+  ];
+
+  for case in cases {
+    let mut schema = ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+      required: vec!["price".to_string()],
+      ..Default::default()
+    };
+    schema.properties.insert(
+      "price".to_string(),
+      object_schema(ObjectSchema {
+        schema_type: Some(SchemaTypeSet::Single(SchemaType::String)),
+        format: Some("decimal".to_string()),
+        minimum: Some(serde_json::Number::from(0)),
+        max_length: Some(20),
+        ..Default::default()
+      }),
+    );
+
+    let graph = create_test_graph(BTreeMap::from([("Frappe".to_string(), schema)]));
+    let context = create_test_context(graph.clone(), case.config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Frappe", graph.get("Frappe").unwrap())?;
+
+    let struct_def = result
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) => Some(def),
+        _ => None,
+      })
+      .expect("Struct should be present");
+    let field = struct_def
+      .fields
+      .iter()
+      .find(|f| f.name == "price")
+      .expect("price field should exist");
+
+    assert_eq!(field.rust_type.base_type.to_string(), case.expected_type);
+    assert_eq!(
+      field.serde_as_attr,
+      case
+        .expected_adapter
+        .map(|custom_type| SerdeAsFieldAttr::CustomOverride {
+          custom_type: custom_type.to_string(),
+          optional: false,
+          is_array: false,
+        }),
+      "{}",
+      case.expected_type
+    );
+    let validation = field
+      .validation_attrs
+      .iter()
+      .map(|attr| attr.to_token_stream().to_string())
+      .collect::<Vec<_>>();
+    if case.expected_adapter.is_some() {
+      assert_eq!(
+        validation,
+        ["custom (function = \"oas3_gen_support::decimal_min::<0, 0>\")"],
+        "{}: decimal fields validate bounds, not length",
+        case.expected_type
+      );
+    } else {
+      assert_eq!(validation, ["length (max = 20u64)"]);
+    }
+  }
+
+  Ok(())
+}
+
 #[test]
 fn test_byte_format_emits_base64_serde_as() -> anyhow::Result<()> {
   let mut schema = ObjectSchema {
@@ -513,7 +1230,7 @@ fn field_name_deduplication() {
 
   for case in cases {
     let fields = case.fields.iter().map(|(n, d)| make_field(n, *d)).collect::<Vec<_>>();
-    let result = FieldConverter::deduplicate_names(fields);
+    let (result, _) = FieldConverter::deduplicate_names("Loaf", fields);
     let names = result.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
 
     assert_eq!(names.len(), case.expected_names.len(), "{}: length mismatch", case.name);
@@ -524,6 +1241,67 @@ fn field_name_deduplication() {
   }
 }
 
+#[test]
+fn test_case_folding_collisions_resolve_independently_of_property_order() -> anyhow::Result<()> {
+  let orders = [
+    ["userId", "user_id", "UserID", "user_id_2"],
+    ["user_id_2", "UserID", "user_id", "userId"],
+  ];
+
+  for order in orders {
+    let mut schema = ObjectSchema {
+      schema_type: Some(SchemaTypeSet::Single(SchemaType::Object)),
+      ..Default::default()
+    };
+    for property in order {
+      schema.properties.insert(
+        property.to_string(),
+        object_schema(ObjectSchema {
+          schema_type: Some(SchemaTypeSet::Single(SchemaType::String)),
+          ..Default::default()
+        }),
+      );
+    }
+
+    let graph = create_test_graph(BTreeMap::from([("User".to_string(), schema)]));
+    let context = create_test_context(graph.clone(), CodegenConfig::default());
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("User", graph.get("User").unwrap())?;
+
+    let Some(RustType::Struct(struct_def)) = result.first() else {
+      panic!("{order:?}: expected a struct");
+    };
+    let fields = struct_def
+      .fields
+      .iter()
+      .map(|f| (f.serialized_name(), f.name.as_str()))
+      .collect::<BTreeMap<_, _>>();
+    let expected = BTreeMap::from([
+      ("UserID", "user_id_3"),
+      ("userId", "user_id_4"),
+      ("user_id", "user_id"),
+      ("user_id_2", "user_id_2"),
+    ]);
+    assert_eq!(fields, expected, "{order:?}: field names");
+
+    let warnings = context
+      .take_warnings()
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      warnings,
+      [
+        "Type 'User': property 'UserID' collides with 'user_id' and was renamed to field 'user_id_3'",
+        "Type 'User': property 'userId' collides with 'user_id' and was renamed to field 'user_id_4'",
+      ],
+      "{order:?}: warnings"
+    );
+  }
+
+  Ok(())
+}
+
 #[test]
 fn test_string_field_no_customization() -> anyhow::Result<()> {
   let mut schema = ObjectSchema {
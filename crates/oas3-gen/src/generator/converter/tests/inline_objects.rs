@@ -1,7 +1,10 @@
 use serde_json::json;
 
 use crate::{
-  generator::{ast::RustType, converter::SchemaConverter},
+  generator::{
+    ast::RustType,
+    converter::{CodegenConfig, InlineObjectPolicy, SchemaConverter},
+  },
   tests::common::{create_test_context, create_test_graph, default_config, parse_schemas},
 };
 
@@ -228,3 +231,102 @@ fn anyof_mixing_object_and_enum_ref_generates_union_not_enum() -> anyhow::Result
 
   Ok(())
 }
+
+#[test]
+fn test_inline_object_threshold_keeps_small_scalar_objects_as_maps() -> anyhow::Result<()> {
+  let schemas = parse_schemas(vec![(
+    "Crate",
+    json!({
+      "type": "object",
+      "properties": {
+        "size": {
+          "type": "object",
+          "properties": {
+            "width": { "type": "integer" },
+            "height": { "type": ["integer", "null"] }
+          }
+        },
+        "label": {
+          "type": "object",
+          "properties": {
+            "text": { "type": "string" },
+            "color": { "type": "string" },
+            "bold": { "type": "boolean" }
+          }
+        },
+        "owner": {
+          "type": "object",
+          "properties": {
+            "name": { "type": "string" },
+            "tags": { "type": "array", "items": { "type": "string" } }
+          }
+        }
+      }
+    }),
+  )]);
+
+  let cases = [
+    (
+      InlineObjectPolicy::Promote,
+      ["Option<CrateSize>", "Option<CrateLabel>", "Option<CrateOwner>"],
+    ),
+    (
+      InlineObjectPolicy::MapUpTo(2),
+      [
+        "Option<indexmap::IndexMap<String, serde_json::Value>>",
+        "Option<CrateLabel>",
+        "Option<CrateOwner>",
+      ],
+    ),
+    (
+      InlineObjectPolicy::MapUpTo(3),
+      [
+        "Option<indexmap::IndexMap<String, serde_json::Value>>",
+        "Option<indexmap::IndexMap<String, serde_json::Value>>",
+        "Option<CrateOwner>",
+      ],
+    ),
+  ];
+  for (policy, expected) in cases {
+    let graph = create_test_graph(schemas.clone());
+    let config = CodegenConfig {
+      inline_objects: policy,
+      ..default_config()
+    };
+    let context = create_test_context(graph.clone(), config);
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema("Crate", graph.get("Crate").unwrap())?;
+
+    let crate_struct = result
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) if def.name == "Crate" => Some(def),
+        _ => None,
+      })
+      .expect("Crate struct should be present");
+    let field_types = ["size", "label", "owner"].map(|name| {
+      crate_struct
+        .fields
+        .iter()
+        .find(|f| f.name == name)
+        .map(|f| f.rust_type.to_rust_type())
+        .unwrap_or_default()
+    });
+    assert_eq!(field_types, expected, "{policy:?}");
+
+    let generated = context
+      .cache
+      .borrow()
+      .types
+      .types
+      .iter()
+      .map(|ty| ty.type_name().to_string())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      generated.iter().any(|name| name == "CrateSize"),
+      policy == InlineObjectPolicy::Promote,
+      "{policy:?}: {generated:?}"
+    );
+  }
+  Ok(())
+}
@@ -2,12 +2,21 @@ use std::{collections::BTreeMap, rc::Rc};
 
 use http::Method;
 use oas3::spec::{ObjectOrReference, ObjectSchema, Operation, Parameter};
+use quote::ToTokens;
 use serde_json::json;
 
 use crate::{
   generator::{
-    ast::{ContentCategory, OperationKind, RustPrimitive, RustType, StructDef, StructToken},
-    converter::{SchemaConverter, SerdeUsageRecorder, operations::OperationConverter},
+    ast::{
+      ContentCategory, EnumVariantToken, OperationKind, ResponseClass, RustPrimitive, RustType, StatusCodeToken,
+      StructDef, StructToken,
+    },
+    codegen::{Visibility, structs::StructFragment},
+    converter::{
+      BuilderPolicy, ClientRuntime, CodegenConfig, GenerationTarget, PaginationPolicy, RequestBodyPolicy,
+      ResponseClassPolicy, SchemaConverter, SerdeUsageRecorder, operations::OperationConverter,
+    },
+    naming::casing::to_snake_case,
     operation_registry::OperationEntry,
   },
   tests::common::{create_test_context, create_test_graph, default_config},
@@ -39,6 +48,7 @@ fn make_entry(stable_id: &str, method: Method, path: &str, operation: Operation)
     path: path.to_string(),
     operation: Rc::new(operation),
     kind: OperationKind::Http,
+    callback_of: None,
   }
 }
 
@@ -174,6 +184,116 @@ fn test_operation_with_request_body_ref() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_request_compression_detected_from_header_or_extension() -> anyhow::Result<()> {
+  let body = json!({
+    "content": {
+      "application/json": {
+        "schema": { "type": "object", "properties": { "title": { "type": "string" } } }
+      }
+    }
+  });
+  let content_encoding = json!([{ "name": "Content-Encoding", "in": "header", "schema": { "type": "string" } }]);
+
+  let cases = [
+    (
+      json!({ "parameters": content_encoding, "requestBody": body }),
+      true,
+      "Content-Encoding header",
+    ),
+    (
+      json!({ "x-request-compression": true, "requestBody": body }),
+      true,
+      "extension",
+    ),
+    (json!({ "requestBody": body }), false, "no opt-in"),
+    (json!({ "parameters": content_encoding }), false, "no body"),
+  ];
+  for (operation_json, expected, label) in cases {
+    let (converter, _usage) = setup_converter(BTreeMap::new());
+    let operation = serde_json::from_value::<Operation>(operation_json)?;
+    let entry = make_entry("create_doc", Method::POST, "/docs", operation);
+    let result = converter.convert(&entry)?;
+
+    assert_eq!(result.operation_info.request_compression, expected, "{label}");
+  }
+  Ok(())
+}
+
+#[test]
+fn test_wasm_runtime_skips_request_compression() -> anyhow::Result<()> {
+  let graph = create_test_graph(BTreeMap::new());
+  let config = CodegenConfig::builder().client_runtime(ClientRuntime::Wasm).build();
+  let context = create_test_context(graph, config);
+  let converter = OperationConverter::new(context.clone(), SchemaConverter::new(&context));
+  let operation = serde_json::from_value::<Operation>(json!({
+    "x-request-compression": true,
+    "requestBody": {
+      "content": { "application/json": { "schema": { "type": "object" } } }
+    }
+  }))?;
+  let entry = make_entry("create_doc", Method::POST, "/docs", operation);
+
+  assert!(!converter.convert(&entry)?.operation_info.request_compression);
+  Ok(())
+}
+
+#[test]
+fn test_deprecated_operation_records_replacement() -> anyhow::Result<()> {
+  let cases = [
+    (json!({}), false, None),
+    (json!({ "deprecated": true }), true, None),
+    (
+      json!({ "deprecated": true, "x-deprecated-replacement": "listPetsV2" }),
+      true,
+      Some("listPetsV2"),
+    ),
+    (json!({ "x-deprecated-replacement": "listPetsV2" }), false, None),
+  ];
+  for (operation_json, deprecated, replaced_by) in cases {
+    let (converter, _usage) = setup_converter(BTreeMap::new());
+    let operation = serde_json::from_value::<Operation>(operation_json.clone())?;
+    let entry = make_entry("list_pets", Method::GET, "/pets", operation);
+    let info = converter.convert(&entry)?.operation_info;
+
+    assert_eq!(info.deprecated, deprecated, "{operation_json}");
+    assert_eq!(info.replaced_by.as_deref(), replaced_by, "{operation_json}");
+    let docs = info.documentation.to_token_stream().to_string();
+    assert_eq!(
+      docs.contains("Deprecated: use `listPetsV2` instead"),
+      replaced_by.is_some(),
+      "{operation_json}: {docs}"
+    );
+  }
+  Ok(())
+}
+
+#[test]
+fn test_operation_records_tags_external_docs_and_extensions() -> anyhow::Result<()> {
+  let (converter, _usage) = setup_converter(BTreeMap::new());
+  let operation = serde_json::from_value::<Operation>(json!({
+    "tags": ["pets", "public"],
+    "externalDocs": { "url": "https://example.com/pets", "description": "Pet guide" },
+    "x-rate-limit": 100,
+    "x-team": "pets"
+  }))?;
+  let entry = make_entry("get_pet", Method::GET, "/pets/{petId}", operation);
+  let info = converter.convert(&entry)?.operation_info;
+
+  assert_eq!(info.path_template, "/pets/{petId}");
+  assert_eq!(info.tags, vec!["pets".to_string(), "public".to_string()]);
+  let external_docs = info.external_docs.as_ref().expect("external docs should be recorded");
+  assert_eq!(external_docs.url, "https://example.com/pets");
+  assert_eq!(external_docs.description.as_deref(), Some("Pet guide"));
+  assert_eq!(info.extensions.get("rate-limit"), Some(&json!(100)));
+  assert_eq!(info.extensions.get("team"), Some(&json!("pets")));
+
+  let docs = info.documentation.to_token_stream().to_string();
+  assert!(docs.contains("Tags: `pets`, `public`"), "{docs}");
+  assert!(docs.contains("See: [Pet guide](https://example.com/pets)"), "{docs}");
+  Ok(())
+}
+
 #[test]
 fn test_operation_with_response_type() -> anyhow::Result<()> {
   let corgi_schema: ObjectSchema = serde_json::from_value::<ObjectSchema>(json!({
@@ -264,7 +384,7 @@ fn test_path_parameter_type_mapping() -> anyhow::Result<()> {
       )?));
 
     let path = format!("/items/{{{param_name}}}");
-    let snake_op_id = inflections::case::to_snake_case(op_id);
+    let snake_op_id = to_snake_case(op_id);
 
     let entry = make_entry(&snake_op_id, Method::GET, &path, operation);
     let result = converter.convert(&entry)?;
@@ -356,6 +476,73 @@ fn test_operation_with_multiple_path_parameters() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_response_media_types_capture_examples() -> anyhow::Result<()> {
+  let corgi_schema: ObjectSchema = serde_json::from_value::<ObjectSchema>(json!({
+    "type": "object",
+    "example": { "name": "schema" }
+  }))?;
+
+  let (converter, _usage) = setup_converter(BTreeMap::from([("Corgi".to_string(), corgi_schema)]));
+
+  let operation_json = json!({
+    "operationId": "getCorgi",
+    "responses": {
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": { "$ref": "#/components/schemas/Corgi" },
+            "examples": { "first": { "value": { "name": "named" } } }
+          }
+        }
+      },
+      "201": {
+        "content": {
+          "application/json": {
+            "schema": { "$ref": "#/components/schemas/Corgi" },
+            "example": { "name": "inline" }
+          }
+        }
+      },
+      "202": {
+        "content": {
+          "application/json": {
+            "schema": { "$ref": "#/components/schemas/Corgi" }
+          }
+        }
+      }
+    }
+  });
+
+  let operation = serde_json::from_value::<Operation>(operation_json)?;
+  let entry = make_entry("get_corgi", Method::GET, "/corgi", operation);
+  let result = converter.convert(&entry)?;
+
+  let response_enum = result
+    .types
+    .iter()
+    .find_map(|t| match t {
+      RustType::ResponseEnum(e) => Some(e),
+      _ => None,
+    })
+    .expect("Response enum not found");
+  let examples = response_enum
+    .variants
+    .iter()
+    .filter_map(|v| Some((v.variant_name.to_string(), v.media_types.first()?.example.clone()?)))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    examples,
+    vec![
+      ("Ok".to_string(), json!({ "name": "named" })),
+      ("Created".to_string(), json!({ "name": "inline" })),
+      ("Accepted".to_string(), json!({ "name": "schema" })),
+    ]
+  );
+  Ok(())
+}
+
 #[test]
 fn test_binary_response_uses_bytes_type() -> anyhow::Result<()> {
   let content_types = [
@@ -551,6 +738,86 @@ fn test_event_stream_response_splits_variants() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_event_stream_named_events_become_event_enum() -> anyhow::Result<()> {
+  let started = serde_json::from_value::<ObjectSchema>(json!({
+    "type": "object",
+    "x-event-name": "job.started",
+    "properties": { "id": { "type": "string" } }
+  }))?;
+  let finished = serde_json::from_value::<ObjectSchema>(json!({
+    "type": "object",
+    "x-event-name": "job.finished",
+    "properties": { "code": { "type": "integer" } }
+  }))?;
+  let job_event = serde_json::from_value::<ObjectSchema>(json!({
+    "oneOf": [
+      { "$ref": "#/components/schemas/Started" },
+      { "$ref": "#/components/schemas/Finished" }
+    ]
+  }))?;
+
+  let (converter, _usage) = setup_converter(BTreeMap::from([
+    ("Started".to_string(), started),
+    ("Finished".to_string(), finished),
+    ("JobEvent".to_string(), job_event),
+  ]));
+
+  let operation = serde_json::from_value::<Operation>(json!({
+    "operationId": "getEvents",
+    "responses": {
+      "200": {
+        "content": {
+          "text/event-stream": {
+            "schema": { "$ref": "#/components/schemas/JobEvent" }
+          }
+        }
+      }
+    }
+  }))?;
+
+  let entry = make_entry("get_events", Method::GET, "/events", operation);
+  let result = converter.convert(&entry)?;
+
+  let response_enum = result
+    .types
+    .iter()
+    .find_map(|t| match t {
+      RustType::ResponseEnum(e) if e.name == "GetEventsResponse" => Some(e),
+      _ => None,
+    })
+    .expect("Response enum not found");
+  let ok_variant = &response_enum.variants[0];
+
+  assert_eq!(
+    ok_variant
+      .schema_type
+      .as_ref()
+      .expect("Event stream variant should have schema")
+      .to_rust_type(),
+    "oas3_gen_support::EventStream<GetEventsEvent>",
+  );
+
+  let named_events = ok_variant.media_types[0]
+    .named_events
+    .as_ref()
+    .expect("event stream should carry named events");
+  assert_eq!(named_events.name, "GetEventsEvent");
+  let events = named_events
+    .events
+    .iter()
+    .map(|e| (e.event.as_str(), e.variant.to_string(), e.schema_type.to_rust_type()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    events,
+    vec![
+      ("job.started", "JobStarted".to_string(), "Started".to_string()),
+      ("job.finished", "JobFinished".to_string(), "Finished".to_string()),
+    ]
+  );
+  Ok(())
+}
+
 #[test]
 fn test_response_enum_adds_default_variant() -> anyhow::Result<()> {
   let (converter, _usage) = setup_converter(BTreeMap::new());
@@ -594,6 +861,86 @@ fn test_response_enum_adds_default_variant() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn test_response_classes_follow_codes_and_retry_after() -> anyhow::Result<()> {
+  let operation = serde_json::from_value::<Operation>(json!({
+    "operationId": "getItem",
+    "responses": {
+      "200": { "description": "ok" },
+      "404": { "description": "missing" },
+      "409": { "description": "busy", "headers": { "Retry-After": { "schema": { "type": "integer" } } } },
+      "503": { "description": "down" },
+      "5XX": { "description": "failed" },
+      "default": { "description": "other" }
+    }
+  }))?;
+  let entry = make_entry("get_item", Method::GET, "/items", operation);
+  let classes_of = |config: CodegenConfig| -> anyhow::Result<_> {
+    let context = create_test_context(create_test_graph(BTreeMap::new()), config);
+    let converter = OperationConverter::new(context.clone(), SchemaConverter::new(&context));
+    let result = converter.convert(&entry)?;
+    Ok(result.types.into_iter().find_map(|t| match t {
+      RustType::ResponseEnum(e) => Some(e.status_classes),
+      _ => None,
+    }))
+  };
+
+  let enabled = CodegenConfig::builder()
+    .response_classes(ResponseClassPolicy::Generate)
+    .build();
+  assert_eq!(
+    classes_of(enabled)?,
+    Some(BTreeMap::from([
+      (StatusCodeToken::Ok200, ResponseClass::Success),
+      (StatusCodeToken::NotFound404, ResponseClass::ClientError),
+      (StatusCodeToken::Conflict409, ResponseClass::Retryable),
+      (StatusCodeToken::ServiceUnavailable503, ResponseClass::Retryable),
+      (StatusCodeToken::ServerError5XX, ResponseClass::ServerError),
+    ]))
+  );
+  assert_eq!(classes_of(default_config())?, Some(BTreeMap::new()));
+  Ok(())
+}
+
+#[test]
+fn test_too_many_requests_becomes_rate_limited_variant() -> anyhow::Result<()> {
+  let operation = serde_json::from_value::<Operation>(json!({
+    "operationId": "getItem",
+    "responses": {
+      "200": { "description": "ok" },
+      "429": { "description": "slow down" }
+    }
+  }))?;
+  let entry = make_entry("get_item", Method::GET, "/items", operation);
+  let context = create_test_context(create_test_graph(BTreeMap::new()), default_config());
+  let converter = OperationConverter::new(context.clone(), SchemaConverter::new(&context));
+  let response_enum = converter
+    .convert(&entry)?
+    .types
+    .into_iter()
+    .find_map(|t| match t {
+      RustType::ResponseEnum(e) => Some(e),
+      _ => None,
+    })
+    .expect("response enum");
+
+  let rate_limited = response_enum
+    .variants
+    .iter()
+    .find(|variant| variant.status_code == StatusCodeToken::TooManyRequests429)
+    .expect("429 variant");
+  assert_eq!(rate_limited.variant_name, EnumVariantToken::new("RateLimited"));
+  assert!(rate_limited.retry_after);
+  assert!(
+    response_enum
+      .variants
+      .iter()
+      .filter(|variant| variant.status_code != StatusCodeToken::TooManyRequests429)
+      .all(|variant| !variant.retry_after)
+  );
+  Ok(())
+}
+
 #[test]
 fn test_response_enum_preserves_existing_default() -> anyhow::Result<()> {
   let error_schema = serde_json::from_value::<ObjectSchema>(json!({
@@ -838,3 +1185,202 @@ fn test_operation_with_oneof_request_body() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[test]
+fn test_multipart_binary_fields_become_file_parts() -> anyhow::Result<()> {
+  let (converter, _usage) = setup_converter(BTreeMap::new());
+
+  let operation_json = json!({
+    "requestBody": {
+      "required": true,
+      "content": {
+        "multipart/form-data": {
+          "schema": {
+            "type": "object",
+            "required": ["file"],
+            "properties": {
+              "file": { "type": "string", "format": "binary" },
+              "attachments": { "type": "array", "items": { "type": "string", "format": "binary" } },
+              "checksum": { "type": "string", "format": "byte" },
+              "name": { "type": "string" }
+            }
+          }
+        }
+      }
+    }
+  });
+
+  let operation = serde_json::from_value::<Operation>(operation_json)?;
+  let entry = make_entry("upload_file", Method::POST, "/files", operation);
+  let result = converter.convert(&entry)?;
+
+  let body = result.operation_info.body.as_ref().expect("Should have body metadata");
+  let body_type = body.body_type.as_ref().expect("Should have body type").to_rust_type();
+  let body_struct = extract_request_struct(&result.types, &body_type);
+  let field_type = |name: &str| {
+    body_struct
+      .fields
+      .iter()
+      .find(|f| f.name == name)
+      .map(|f| f.rust_type.to_rust_type())
+      .unwrap()
+  };
+
+  assert_eq!(field_type("file"), "oas3_gen_support::FilePart");
+  assert_eq!(field_type("attachments"), "Option<Vec<oas3_gen_support::FilePart>>");
+  assert_eq!(
+    field_type("checksum"),
+    "Option<Vec<u8>>",
+    "base64 fields keep their bytes"
+  );
+
+  let parts = body.multipart_fields.as_ref().expect("Should have multipart fields");
+  let flags = parts
+    .iter()
+    .map(|f| (f.name.as_str(), f.is_file, f.is_array, f.is_bytes))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    flags,
+    vec![
+      ("file", true, false, false),
+      ("attachments", true, true, false),
+      ("checksum", false, false, true),
+      ("name", false, false, false),
+    ]
+  );
+  Ok(())
+}
+
+#[test]
+fn test_request_builder_initializes_page_link() -> anyhow::Result<()> {
+  let operation = serde_json::from_value::<Operation>(json!({
+    "operationId": "listPets",
+    "parameters": [{ "name": "limit", "in": "query", "schema": { "type": "integer" } }],
+    "responses": {
+      "200": {
+        "description": "ok",
+        "headers": { "Link": { "schema": { "type": "string" } } },
+        "content": { "application/json": { "schema": { "type": "array", "items": { "type": "string" } } } }
+      }
+    }
+  }))?;
+  let entry = make_entry("list_pets", Method::GET, "/pets", operation);
+  let config = CodegenConfig::builder()
+    .pagination(PaginationPolicy::Detect)
+    .builders(BuilderPolicy::Large)
+    .build();
+  let context = create_test_context(create_test_graph(BTreeMap::new()), config);
+  let converter = OperationConverter::new(context.clone(), SchemaConverter::new(&context));
+  let result = converter.convert(&entry)?;
+
+  let request = extract_request_struct(&result.types, "ListPetsRequest");
+  assert!(request.fields.iter().any(|f| f.name == "page_link"));
+  let code = StructFragment::new(
+    request.clone(),
+    BTreeMap::new(),
+    Visibility::Public,
+    GenerationTarget::Client,
+  )
+  .into_token_stream()
+  .to_string();
+  assert!(
+    code.contains("Self { query : ListPetsRequestQuery { limit } , page_link : Default :: default () }"),
+    "{code}"
+  );
+  assert!(!code.contains("page_link : Option < String > ,"), "{code}");
+  Ok(())
+}
+
+#[test]
+fn test_request_body_variants_cover_each_media_type() -> anyhow::Result<()> {
+  let pet_schema = serde_json::from_value::<ObjectSchema>(json!({
+    "type": "object",
+    "properties": { "name": { "type": "string" } }
+  }))?;
+  let operation = serde_json::from_value::<Operation>(json!({
+    "operationId": "createPet",
+    "requestBody": {
+      "required": true,
+      "content": {
+        "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } },
+        "multipart/form-data": {
+          "schema": {
+            "type": "object",
+            "properties": { "photo": { "type": "string", "format": "binary" } }
+          }
+        },
+        "application/vnd.pet+json": { "schema": { "$ref": "#/components/schemas/Pet" } }
+      }
+    },
+    "responses": { "201": { "description": "created" } }
+  }))?;
+  let entry = make_entry("create_pet", Method::POST, "/pets", operation);
+  let convert = |config: CodegenConfig| {
+    let graph = create_test_graph(BTreeMap::from([("Pet".to_string(), pet_schema.clone())]));
+    let context = create_test_context(graph, config);
+    OperationConverter::new(context.clone(), SchemaConverter::new(&context)).convert(&entry)
+  };
+
+  let result = convert(
+    CodegenConfig::builder()
+      .request_bodies(RequestBodyPolicy::Variants)
+      .build(),
+  )?;
+  let body = result.operation_info.body.as_ref().expect("Should have body metadata");
+  assert_eq!(body.body_type.as_ref().unwrap().to_rust_type(), "CreatePetRequestBody");
+  let variants = body
+    .media_variants
+    .iter()
+    .map(|variant| {
+      (
+        variant.name.to_string(),
+        variant.content_category,
+        variant.content_type.clone().unwrap_or_default(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    variants,
+    vec![
+      (
+        "Json".to_string(),
+        ContentCategory::Json,
+        "application/json".to_string()
+      ),
+      (
+        "Multipart".to_string(),
+        ContentCategory::Multipart,
+        "multipart/form-data".to_string()
+      ),
+    ]
+  );
+  assert!(body.media_variants[1].multipart_fields.as_ref().unwrap()[0].is_file);
+  let body_enum = result
+    .types
+    .iter()
+    .find_map(|t| match t {
+      RustType::Enum(def) if def.name == "CreatePetRequestBody" => Some(def),
+      _ => None,
+    })
+    .expect("Should generate the body enum");
+  let variant_types = body_enum
+    .variants
+    .iter()
+    .map(|variant| variant.content.tuple_types().unwrap()[0].to_rust_type())
+    .collect::<Vec<_>>();
+  assert_eq!(variant_types, vec!["Pet", "PhotoRequestBody"]);
+
+  for config in [
+    default_config(),
+    CodegenConfig::builder()
+      .request_bodies(RequestBodyPolicy::Variants)
+      .target(GenerationTarget::Server)
+      .build(),
+  ] {
+    let result = convert(config)?;
+    let body = result.operation_info.body.as_ref().expect("Should have body metadata");
+    assert_eq!(body.body_type.as_ref().unwrap().to_rust_type(), "Pet");
+    assert!(body.media_variants.is_empty());
+  }
+  Ok(())
+}
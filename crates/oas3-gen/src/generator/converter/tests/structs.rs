@@ -5,8 +5,8 @@ use serde_json::json;
 
 use crate::{
   generator::{
-    ast::{RustType, SerdeAttribute},
-    converter::{SchemaConverter, discriminator::DiscriminatorConverter},
+    ast::{DeriveTrait, RustType, SerdeAttribute, StructToken},
+    converter::{BuilderPolicy, CodegenConfig, SchemaConverter, discriminator::DiscriminatorConverter},
     metrics::GenerationStats,
     schema_registry::SchemaRegistry,
   },
@@ -67,6 +67,43 @@ fn discriminated_base_struct_renamed() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[test]
+fn strict_and_lenient_types_override_additional_properties() -> anyhow::Result<()> {
+  let order_schema = parse_schema(json!({
+    "type": "object",
+    "properties": { "id": { "type": "string" } }
+  }));
+  let invoice_schema = parse_schema(json!({
+    "type": "object",
+    "additionalProperties": false,
+    "properties": { "total": { "type": "number" } }
+  }));
+  let graph = create_test_graph(BTreeMap::from([
+    ("Order".to_string(), order_schema),
+    ("Invoice".to_string(), invoice_schema),
+  ]));
+  let denies = |config: CodegenConfig, name: &str| -> anyhow::Result<bool> {
+    let context = create_test_context(graph.clone(), config);
+    let result = SchemaConverter::new(&context).convert_schema(name, graph.get(name).unwrap())?;
+    let RustType::Struct(def) = &result[0] else {
+      panic!("{name} should convert to a struct");
+    };
+    Ok(def.serde_attrs.contains(&SerdeAttribute::DenyUnknownFields))
+  };
+
+  assert!(!denies(default_config(), "Order")?);
+  assert!(denies(default_config(), "Invoice")?);
+
+  let overridden = || CodegenConfig {
+    strict_types: vec!["Order".to_string()],
+    lenient_types: vec!["Invoice".to_string()],
+    ..default_config()
+  };
+  assert!(denies(overridden(), "Order")?, "strict types deny unknown fields");
+  assert!(!denies(overridden(), "Invoice")?, "lenient types accept them");
+  Ok(())
+}
+
 #[test]
 fn discriminator_with_enum_remains_visible() -> anyhow::Result<()> {
   let bark_schema = parse_schema(json!({
@@ -397,3 +434,71 @@ fn discriminator_mappings_preserve_mapping_order() {
     "Mappings should follow discriminator mapping order"
   );
 }
+
+#[test]
+fn large_builder_policy_derives_builder_for_large_structs_only() -> anyhow::Result<()> {
+  let optional_fields = |count: usize| {
+    (0..count)
+      .map(|i| (format!("field_{i}"), json!({ "type": "string" })))
+      .collect::<serde_json::Map<_, _>>()
+  };
+  let components = json!({
+    "Corgi": { "type": "object", "properties": optional_fields(5) },
+    "Frappe": { "type": "object", "properties": optional_fields(4) },
+  });
+
+  let graph = create_graph_from_json(&components);
+  let config = CodegenConfig {
+    builders: BuilderPolicy::Large,
+    ..default_config()
+  };
+  let context = create_test_context(graph.clone(), config);
+  let converter = SchemaConverter::new(&context);
+
+  for (name, expected) in [("Corgi", true), ("Frappe", false)] {
+    let result = converter.convert_schema(name, graph.get(name).unwrap())?;
+    let RustType::Struct(def) = &result[0] else {
+      panic!("{name} should convert to a struct");
+    };
+    assert_eq!(
+      def.additional_derives.contains(&DeriveTrait::Builder),
+      expected,
+      "{name} builder derive"
+    );
+  }
+  Ok(())
+}
+
+#[test]
+fn all_of_child_records_parent_structs() -> anyhow::Result<()> {
+  let components = json!({
+    "Entity": {
+      "type": "object",
+      "required": ["id"],
+      "properties": { "id": { "type": "integer" } }
+    },
+    "User": {
+      "allOf": [
+        { "$ref": "#/components/schemas/Entity" },
+        { "type": "object", "properties": { "nickname": { "type": "string" } } }
+      ]
+    }
+  });
+
+  let graph = create_graph_from_json(&components);
+  let context = create_test_context(graph.clone(), default_config());
+  let converter = SchemaConverter::new(&context);
+
+  let result = converter.convert_schema("User", graph.get("User").unwrap())?;
+  let RustType::Struct(user) = &result[0] else {
+    panic!("Expected struct")
+  };
+  assert_eq!(user.parents, vec![StructToken::new("Entity")]);
+
+  let result = converter.convert_schema("Entity", graph.get("Entity").unwrap())?;
+  let RustType::Struct(entity) = &result[0] else {
+    panic!("Expected struct")
+  };
+  assert!(entity.parents.is_empty());
+  Ok(())
+}
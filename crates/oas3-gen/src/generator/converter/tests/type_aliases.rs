@@ -201,3 +201,75 @@ fn test_nullable_array_type_alias_with_inline_union_items() -> anyhow::Result<()
 
   Ok(())
 }
+
+#[test]
+fn test_map_type_aliases() -> anyhow::Result<()> {
+  let schemas = parse_schemas(vec![
+    (
+      "Corgi",
+      json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+    ),
+    (
+      "CorgiMap",
+      json!({"type": "object", "additionalProperties": {"$ref": "#/components/schemas/Corgi"}}),
+    ),
+    (
+      "Kennels",
+      json!({
+        "type": "object",
+        "additionalProperties": {
+          "type": "object",
+          "additionalProperties": {"type": "array", "items": {"$ref": "#/components/schemas/Corgi"}}
+        }
+      }),
+    ),
+    ("Treats", json!({"type": "object", "additionalProperties": true})),
+    (
+      "MaybeScores",
+      json!({"type": ["object", "null"], "additionalProperties": {"type": "integer"}}),
+    ),
+  ]);
+  let cases = [
+    ("CorgiMap", "indexmap::IndexMap<String, Corgi>"),
+    (
+      "Kennels",
+      "indexmap::IndexMap<String, indexmap::IndexMap<String, Vec<Corgi>>>",
+    ),
+    ("Treats", "indexmap::IndexMap<String, serde_json::Value>"),
+    ("MaybeScores", "Option<indexmap::IndexMap<String, i64>>"),
+  ];
+  for (name, expected_type) in cases {
+    let graph = create_test_graph(schemas.clone());
+    let context = create_test_context(graph.clone(), default_config());
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema(name, graph.get(name).unwrap())?;
+    assert_single_type_alias(&result, name, expected_type);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_objects_with_properties_or_closed_maps_stay_structs() -> anyhow::Result<()> {
+  let schemas = parse_schemas(vec![
+    (
+      "Corgi",
+      json!({
+        "type": "object",
+        "properties": {"name": {"type": "string"}},
+        "additionalProperties": {"type": "string"}
+      }),
+    ),
+    ("Sealed", json!({"type": "object", "additionalProperties": false})),
+  ]);
+  for name in ["Corgi", "Sealed"] {
+    let graph = create_test_graph(schemas.clone());
+    let context = create_test_context(graph.clone(), default_config());
+    let converter = SchemaConverter::new(&context);
+    let result = converter.convert_schema(name, graph.get(name).unwrap())?;
+    assert!(
+      matches!(result.as_slice(), [RustType::Struct(_)]),
+      "{name} should be a struct: {result:?}"
+    );
+  }
+  Ok(())
+}
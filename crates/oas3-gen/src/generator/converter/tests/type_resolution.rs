@@ -138,6 +138,36 @@ fn array_type_resolution() {
       })),
       "Vec<CustomType>",
     ),
+    (
+      "closed_prefix_items_tuple",
+      parse_schema(json!({
+        "type": "array",
+        "prefixItems": [
+          { "type": "integer" },
+          { "type": "string" },
+          { "$ref": "#/components/schemas/CustomType" }
+        ],
+        "items": false
+      })),
+      "(i64, String, CustomType)",
+    ),
+    (
+      "single_prefix_item_tuple",
+      parse_schema(json!({
+        "type": "array",
+        "prefixItems": [{ "type": "number" }],
+        "items": false
+      })),
+      "(f64,)",
+    ),
+    (
+      "open_prefix_items_fallback",
+      parse_schema(json!({
+        "type": "array",
+        "prefixItems": [{ "type": "string" }]
+      })),
+      "Vec<serde_json::Value>",
+    ),
   ];
 
   for (case_name, schema, expected_type) in cases {
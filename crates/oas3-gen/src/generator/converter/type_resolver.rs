@@ -1,9 +1,8 @@
 use std::rc::Rc;
 
 use anyhow::{Context, Result};
-use inflections::Inflect;
 use itertools::Itertools;
-use oas3::spec::{ObjectSchema, Schema, SchemaType, Spec};
+use oas3::spec::{BooleanSchema, ObjectSchema, Schema, SchemaType, Spec};
 
 use super::{
   ConversionOutput,
@@ -14,7 +13,12 @@ use crate::{
   generator::{
     ast::{RustPrimitive, TypeRef},
     converter::ConverterContext,
-    naming::{constants::VARIANT_KIND_SUFFIX, identifiers::strip_parent_prefix, inference::CommonVariantName},
+    naming::{
+      casing::to_pascal_case,
+      constants::{DECIMAL_FORMAT, VARIANT_KIND_SUFFIX},
+      identifiers::strip_parent_prefix,
+      inference::CommonVariantName,
+    },
   },
   utils::{
     SchemaExt, SchemaInspect, SchemaRefName, SchemaResolveExt, extract_union_fingerprint, parse_schema_ref_path,
@@ -51,12 +55,19 @@ impl TypeResolver {
   }
 
   /// Resolves a schema to its Rust type reference.
+  ///
+  /// A schema naming an existing type with `x-rust-type` resolves to that type, bypassing
+  /// the cache, which does not tell such schemas apart from plain ones.
   pub(crate) fn resolve_type(&self, schema: &ObjectSchema) -> Result<TypeRef> {
+    if let Some(path) = schema.rust_type_extension() {
+      return external_type(path);
+    }
+
     if let Some(type_ref) = self.context.cache().get_type_ref(schema)? {
       return Ok(type_ref);
     }
 
-    let type_ref = self.resolve_type_uncached(schema)?;
+    let type_ref = self.vendor_nullable(schema, self.resolve_type_uncached(schema)?);
 
     if schema.is_primitive() {
       let _ = self
@@ -107,6 +118,16 @@ impl TypeResolver {
     Ok(TypeRef::new(RustPrimitive::Value))
   }
 
+  /// Wraps `type_ref` in `Option` when vendor nullability is enabled and the schema
+  /// carries `nullable: true` or `x-nullable: true`.
+  fn vendor_nullable(&self, schema: &ObjectSchema, type_ref: TypeRef) -> TypeRef {
+    if self.context.config().vendor_nullable() && schema.has_nullable_extension() {
+      type_ref.with_option()
+    } else {
+      type_ref
+    }
+  }
+
   /// Resolves a property schema to its Rust type reference with inline type tracking.
   pub(crate) fn resolve_property(
     &self,
@@ -114,11 +135,27 @@ impl TypeResolver {
     property_name: &str,
     schema: &ObjectSchema,
     schema_ref: &Schema,
+  ) -> Result<ConversionOutput<TypeRef>> {
+    let mut output = self.resolve_property_type(parent_name, property_name, schema, schema_ref)?;
+    output.result = self.vendor_nullable(schema, output.result);
+    Ok(output)
+  }
+
+  fn resolve_property_type(
+    &self,
+    parent_name: &str,
+    property_name: &str,
+    schema: &ObjectSchema,
+    schema_ref: &Schema,
   ) -> Result<ConversionOutput<TypeRef>> {
     if let Some(ref_path) = schema_ref.ref_path() {
       return self.resolve_ref(ref_path, schema);
     }
 
+    if let Some(path) = schema.rust_type_extension() {
+      return Ok(ConversionOutput::new(external_type(path)?));
+    }
+
     if schema.all_of.len() == 1
       && let Some(type_ref) = self.try_union(&schema.all_of)?
     {
@@ -126,12 +163,15 @@ impl TypeResolver {
     }
 
     if schema.is_inline_object() {
+      if let Some(type_ref) = self.try_inline_map(schema) {
+        return Ok(ConversionOutput::new(type_ref));
+      }
       return self
         .inline_resolver
         .resolve_inline_struct(parent_name, property_name, schema);
     }
 
-    if schema.has_enum_values() {
+    if schema.has_enum_values() || schema.is_scalar_const() {
       return self.inline_enum(parent_name, property_name, schema);
     }
 
@@ -150,14 +190,25 @@ impl TypeResolver {
 
   /// Resolves a `$ref` path to a type reference.
   ///
-  /// For primitive types at ref targets, returns the primitive directly.
+  /// Schemas replaced by an existing Rust type resolve to the alias generated for them.
+  /// For primitive types at ref targets, returns the primitive directly, except for
+  /// scalar `const` schemas, which keep their named single-variant enum.
   /// For wrapper unions (nullable ref), unwraps to the inner type.
   /// Otherwise, returns a reference to the named schema type.
   fn resolve_ref(&self, ref_path: &str, schema: &ObjectSchema) -> Result<ConversionOutput<TypeRef>> {
     let ref_name =
       parse_schema_ref_path(ref_path).ok_or_else(|| anyhow::anyhow!("Invalid reference path: {ref_path}"))?;
 
-    if schema.is_primitive() && !schema.has_inline_union_array_items(self.spec()) {
+    if self
+      .context
+      .config()
+      .rust_type_override(Some(&ref_name), schema)
+      .is_some()
+    {
+      return Ok(ConversionOutput::new(self.context.graph().type_ref(&ref_name)));
+    }
+
+    if schema.is_primitive() && !schema.is_scalar_const() && !schema.has_inline_union_array_items(self.spec()) {
       return Ok(ConversionOutput::new(self.resolve_type(schema)?));
     }
 
@@ -185,10 +236,12 @@ impl TypeResolver {
     Ok(ConversionOutput::new(self.context.graph().type_ref(&ref_name)))
   }
 
-  /// Creates an inline enum type from a schema with enum values.
+  /// Creates an inline enum type from a schema with enum values or an integer,
+  /// number, or boolean `const`.
   ///
   /// Single-value enums are treated as constants rather than generating
-  /// a separate enum type.
+  /// a separate enum type. Scalar consts become single-variant enums, as they do
+  /// for component schemas.
   pub(crate) fn inline_enum(
     &self,
     parent_name: &str,
@@ -239,7 +292,7 @@ impl TypeResolver {
       return Ok(result);
     }
 
-    let property_pascal = property_name.to_pascal_case();
+    let property_pascal = to_pascal_case(property_name);
     let suffix = format!("{property_pascal}{VARIANT_KIND_SUFFIX}");
     let base_name = CommonVariantName::union_name_or(variants, &suffix, || format!("{parent_name}{property_pascal}"));
 
@@ -273,7 +326,7 @@ impl TypeResolver {
     };
 
     let unique = self.preserve_unique_items(schema);
-    let singular = cruet::to_singular(property_name).to_pascal_case();
+    let singular = to_pascal_case(&cruet::to_singular(property_name));
 
     let result = if items.is_inline_object() {
       let base = format!("{parent_name}{}", strip_parent_prefix(parent_name, &singular));
@@ -472,7 +525,7 @@ impl TypeResolver {
   fn primitive(&self, typ: SchemaType, schema: &ObjectSchema) -> Result<TypeRef> {
     match typ {
       SchemaType::String | SchemaType::Number | SchemaType::Integer => {
-        Ok(TypeRef::new(Self::format_or_default(typ, schema)))
+        Ok(TypeRef::new(self.format_or_default(typ, schema)))
       }
       SchemaType::Boolean => Ok(TypeRef::new(RustPrimitive::Bool)),
       SchemaType::Object => {
@@ -483,6 +536,9 @@ impl TypeResolver {
       }
       SchemaType::Null => Ok(TypeRef::new(RustPrimitive::Unit).with_option()),
       SchemaType::Array => {
+        if let Some(tuple) = self.try_tuple_type(schema)? {
+          return Ok(tuple);
+        }
         let item = self.array_item_type(schema)?;
         let unique = self.preserve_unique_items(schema);
         Ok(TypeRef::new(item.to_rust_type()).with_vec().with_unique_items(unique))
@@ -491,14 +547,35 @@ impl TypeResolver {
   }
 
   /// Returns the Rust primitive for a schema type, applying format overrides.
-  fn format_or_default(typ: SchemaType, schema: &ObjectSchema) -> RustPrimitive {
+  ///
+  /// Numeric epoch timestamps (see [`SchemaExt::epoch_timestamp`]) map to `DateTime`,
+  /// `x-format: money` strings map to `oas3_gen_support::Money<SCALE>`, and
+  /// `format: decimal` strings map to the configured decimal type. Integers are
+  /// widened when the config asks for it.
+  fn format_or_default(&self, typ: SchemaType, schema: &ObjectSchema) -> RustPrimitive {
+    if schema.epoch_timestamp().is_some() {
+      return RustPrimitive::DateTime;
+    }
+    if let Some(scale) = schema.money_scale() {
+      return RustPrimitive::Custom(format!("oas3_gen_support::Money<{scale}>").into());
+    }
+    if typ == SchemaType::String
+      && schema.format.as_deref() == Some(DECIMAL_FORMAT)
+      && let Some(decimal) = self.context.config().decimal_primitive()
+    {
+      return decimal;
+    }
     let default = match typ {
       SchemaType::String => RustPrimitive::String,
       SchemaType::Number => RustPrimitive::F64,
       SchemaType::Integer => RustPrimitive::I64,
       _ => return RustPrimitive::Value,
     };
-    RustPrimitive::with_format_override(default, schema.format.as_deref())
+    let primitive = RustPrimitive::with_format_override(default, schema.format.as_deref());
+    self
+      .context
+      .config()
+      .integer_primitive(primitive, schema.has_non_negative_minimum())
   }
 
   /// Attempts to recognize an object schema as a map type.
@@ -508,7 +585,7 @@ impl TypeResolver {
   /// concrete map type follows
   /// [`CollectionTypePolicy`](crate::generator::CollectionTypePolicy):
   /// `indexmap::IndexMap` when ordered, `std::collections::HashMap` when hashed.
-  fn try_map_type(&self, schema: &ObjectSchema) -> Result<Option<TypeRef>> {
+  pub(crate) fn try_map_type(&self, schema: &ObjectSchema) -> Result<Option<TypeRef>> {
     let Some(ref additional) = schema.additional_properties else {
       return Ok(None);
     };
@@ -529,6 +606,35 @@ impl TypeResolver {
     ))))
   }
 
+  /// Recognizes an inline object that [`InlineObjectPolicy`](crate::generator::InlineObjectPolicy)
+  /// keeps anonymous: only scalar properties, no typed `additionalProperties`, and no more
+  /// properties than the configured threshold. Such objects become a map of `serde_json::Value`.
+  fn try_inline_map(&self, schema: &ObjectSchema) -> Option<TypeRef> {
+    if !self.context.config().inline_object_as_map(schema.properties.len())
+      || matches!(schema.additional_properties, Some(Schema::Object(_)))
+    {
+      return None;
+    }
+
+    let scalar_properties = schema.properties.values().all(|property| {
+      self.resolve(property).is_ok_and(|property| {
+        property.is_primitive()
+          && property.enum_values.is_empty()
+          && matches!(
+            property.single_type().or_else(|| property.non_null_type()),
+            Some(SchemaType::String | SchemaType::Number | SchemaType::Integer | SchemaType::Boolean)
+          )
+      })
+    });
+    scalar_properties.then(|| {
+      TypeRef::new(format!(
+        "{}<String, {}>",
+        self.context.config().map_type_path(),
+        TypeRef::new(RustPrimitive::Value).to_rust_type()
+      ))
+    })
+  }
+
   /// Resolves whether an array schema's `uniqueItems` flag should propagate to
   /// the generated type. With the ordered policy this returns the schema flag
   /// directly; with the hashed policy it returns `false` so the array stays a
@@ -566,7 +672,33 @@ impl TypeResolver {
     let Some(items_ref) = schema.items.as_deref() else {
       return Ok(TypeRef::new(RustPrimitive::Value));
     };
+    self.item_type(items_ref)
+  }
 
+  /// Resolves a closed tuple array, whose `prefixItems` give the type of every
+  /// position and whose `items` is `false`, to a Rust tuple of those types.
+  ///
+  /// Serde reads and writes tuples as fixed-length JSON arrays, so each position
+  /// keeps its own type instead of degrading to `Vec<serde_json::Value>`.
+  fn try_tuple_type(&self, schema: &ObjectSchema) -> Result<Option<TypeRef>> {
+    if schema.prefix_items.is_empty() || !matches!(schema.items.as_deref(), Some(Schema::Boolean(BooleanSchema(false))))
+    {
+      return Ok(None);
+    }
+    let positions = schema
+      .prefix_items
+      .iter()
+      .map(|item| Ok(self.item_type(item)?.to_rust_type()))
+      .collect::<Result<Vec<_>>>()?;
+    let tuple = match positions.as_slice() {
+      [single] => format!("({single},)"),
+      _ => format!("({})", positions.join(", ")),
+    };
+    Ok(Some(TypeRef::new(RustPrimitive::Custom(tuple.into()))))
+  }
+
+  /// Resolves the type of one array element, given by `items` or a `prefixItems` entry.
+  fn item_type(&self, items_ref: &Schema) -> Result<TypeRef> {
     let items = self.resolve(items_ref)?;
 
     let type_ref = if let Some(ref_path) = items_ref.ref_path() {
@@ -652,3 +784,10 @@ impl TypeResolver {
     }
   }
 }
+
+/// Returns a reference to the existing Rust type at `path`, given by `x-rust-type` or
+/// the config file.
+pub(crate) fn external_type(path: &str) -> Result<TypeRef> {
+  syn::parse_str::<syn::Type>(path).map_err(|err| anyhow::anyhow!("Invalid Rust type `{path}`: {err}"))?;
+  Ok(TypeRef::new(RustPrimitive::Custom(path.into())))
+}
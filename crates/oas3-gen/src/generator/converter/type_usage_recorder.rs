@@ -134,6 +134,25 @@ impl SerdeUsageRecorder {
     }
   }
 
+  /// Records the custom type within a [`TypeRef`] as used in a request.
+  ///
+  /// The counterpart of [`mark_response_type_ref`](Self::mark_response_type_ref),
+  /// for response payloads that are also sent, as with callbacks.
+  pub(crate) fn mark_request_type_ref(&mut self, type_ref: &TypeRef) {
+    if let RustPrimitive::Custom(name) = &type_ref.base_type {
+      self.mark_request(name.as_ref());
+    }
+  }
+
+  /// Moves the usage recorded for each type in `replacements` to its replacement.
+  pub(crate) fn rename_types(&mut self, replacements: &BTreeMap<String, String>) {
+    for (from, to) in replacements {
+      if let Some(flags) = self.entries.remove(&EnumToken::new(from)) {
+        self.entries.entry(EnumToken::new(to)).or_default().merge(flags);
+      }
+    }
+  }
+
   /// Combines another recorder's usage data into this one.
   ///
   /// Usage flags are merged with logical OR: if either recorder marked a type
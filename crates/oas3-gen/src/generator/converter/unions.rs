@@ -36,9 +36,11 @@ impl EnumConverter {
   pub(crate) fn new(context: Rc<ConverterContext>) -> Self {
     let case_insensitive = context.config().case_insensitive_enums();
     let sort_variants = context.config().sort_enum_variants();
+    let tolerate_unknown = context.config().lenient_deserialization();
+    let open_integers = context.config().open_integer_enums();
     Self {
       context,
-      value_enum_builder: ValueEnumBuilder::new(case_insensitive, sort_variants),
+      value_enum_builder: ValueEnumBuilder::new(case_insensitive, sort_variants, tolerate_unknown, open_integers),
     }
   }
 
@@ -59,7 +61,7 @@ impl EnumConverter {
     };
 
     let variants = schema.extract_enum_entries(self.context.graph().spec());
-    let scalar_repr = Self::scalar_repr(schema);
+    let scalar_repr = self.scalar_repr(schema);
 
     self.value_enum_builder.build_enum_from_variants(
       name,
@@ -70,22 +72,72 @@ impl EnumConverter {
     )
   }
 
-  /// Determines the backing scalar primitive for a numeric value enum.
+  /// Returns `true` if every branch of a `oneOf`/`anyOf` union is an integer, number,
+  /// or boolean literal of one kind, so the union is a value enum over those literals.
+  pub(crate) fn is_scalar_literal_union(&self, schema: &ObjectSchema) -> bool {
+    schema.has_union() && self.scalar_repr(schema).is_some()
+  }
+
+  /// Determines the backing scalar primitive for a numeric or boolean value enum.
   ///
   /// Returns `Some` for `type: integer` / `type: number` schemas (honoring any
-  /// numeric `format`), and `None` for string-backed enums, which use the
-  /// derived serde path.
-  fn scalar_repr(schema: &ObjectSchema) -> Option<RustPrimitive> {
-    match schema.single_type() {
-      Some(SchemaType::Integer) => Some(RustPrimitive::with_format_override(
-        RustPrimitive::I64,
-        schema.format.as_deref(),
-      )),
-      Some(SchemaType::Number) => Some(RustPrimitive::with_format_override(
-        RustPrimitive::F64,
-        schema.format.as_deref(),
-      )),
-      _ => None,
+  /// numeric `format`) and `type: boolean` schemas. Untyped schemas are inferred
+  /// from their `enum`, `const`, or union branch literals. Returns `None` for
+  /// string-backed enums, which use the derived serde path.
+  fn scalar_repr(&self, schema: &ObjectSchema) -> Option<RustPrimitive> {
+    let primitive = match schema.single_type() {
+      Some(SchemaType::Integer) => RustPrimitive::I64,
+      Some(SchemaType::Number) => RustPrimitive::F64,
+      Some(SchemaType::Boolean) => RustPrimitive::Bool,
+      Some(_) => return None,
+      None => Self::infer_scalar_repr(&self.literal_values(schema)?)?,
+    };
+    if primitive == RustPrimitive::Bool {
+      return Some(primitive);
+    }
+    let primitive = RustPrimitive::with_format_override(primitive, schema.format.as_deref());
+    Some(
+      self
+        .context
+        .config()
+        .integer_primitive(primitive, schema.has_non_negative_minimum()),
+    )
+  }
+
+  /// Collects the literal values a schema admits from `enum`, `const`, or its union
+  /// branches. Returns `None` if any union branch is not itself a literal.
+  fn literal_values(&self, schema: &ObjectSchema) -> Option<Vec<serde_json::Value>> {
+    if !schema.enum_values.is_empty() {
+      return Some(schema.enum_values.clone());
+    }
+    if let Some(value) = &schema.const_value {
+      return Some(vec![value.clone()]);
+    }
+
+    let spec = self.context.graph().spec();
+    let mut values = vec![];
+    for variant in schema.union_variants() {
+      let resolved = variant.resolve_object(spec).ok()?;
+      if !resolved.enum_values.is_empty() {
+        values.extend(resolved.enum_values);
+      } else {
+        values.push(resolved.const_value?);
+      }
+    }
+    Some(values)
+  }
+
+  fn infer_scalar_repr(values: &[serde_json::Value]) -> Option<RustPrimitive> {
+    if values.is_empty() {
+      None
+    } else if values.iter().all(serde_json::Value::is_boolean) {
+      Some(RustPrimitive::Bool)
+    } else if values.iter().all(serde_json::Value::is_i64) {
+      Some(RustPrimitive::I64)
+    } else if values.iter().all(serde_json::Value::is_number) {
+      Some(RustPrimitive::F64)
+    } else {
+      None
     }
   }
 }
@@ -3,12 +3,23 @@ use std::collections::BTreeMap;
 use itertools::Itertools;
 
 use super::union_types::CollisionStrategy;
-use crate::generator::ast::{Documentation, EnumDef, EnumToken, EnumVariantToken, RustPrimitive, RustType, VariantDef};
+use crate::generator::{
+  ast::{
+    Documentation, EnumDef, EnumToken, EnumVariantToken, RustPrimitive, RustType, SerdeAttribute, TypeRef,
+    VariantContent, VariantDef,
+  },
+  naming::{
+    constants::{OTHER_ENUM_VARIANT, UNKNOWN_ENUM_VARIANT},
+    identifiers::{has_spelled_symbols, to_spelled_variant_name},
+  },
+};
 
 #[derive(Clone, Debug)]
 pub(crate) struct ValueEnumBuilder {
   case_insensitive: bool,
   sort_variants: bool,
+  tolerate_unknown: bool,
+  open_integers: bool,
 }
 
 impl ValueEnumBuilder {
@@ -20,10 +31,18 @@ impl ValueEnumBuilder {
   ///
   /// When `sort_variants` is `true`, generated variants are emitted in alphabetical
   /// order by Rust variant name regardless of declaration order in the OpenAPI spec.
-  pub(crate) fn new(case_insensitive: bool, sort_variants: bool) -> Self {
+  ///
+  /// When `tolerate_unknown` is `true`, string-backed enums gain an `Unknown` variant
+  /// that unrecognized values deserialize into instead of failing.
+  ///
+  /// When `open_integers` is `true`, integer-backed enums gain an `Other` variant holding
+  /// the unrecognized integer, so it serializes back unchanged.
+  pub(crate) fn new(case_insensitive: bool, sort_variants: bool, tolerate_unknown: bool, open_integers: bool) -> Self {
     Self {
       case_insensitive,
       sort_variants,
+      tolerate_unknown,
+      open_integers,
     }
   }
 
@@ -44,6 +63,7 @@ impl ValueEnumBuilder {
     docs: Documentation,
     scalar_repr: Option<RustPrimitive>,
   ) -> RustType {
+    let variants = Self::spell_colliding_symbols(variants);
     let (resolved_variants, _) = variants.into_iter().enumerate().fold(
       (vec![], BTreeMap::<String, usize>::new()),
       |(mut acc, mut seen): (Vec<VariantDef>, BTreeMap<String, usize>), (i, mut variant)| {
@@ -76,6 +96,14 @@ impl ValueEnumBuilder {
       resolved_variants
     };
 
+    let resolved_variants = match &scalar_repr {
+      None if self.tolerate_unknown => self.with_unknown_variant(resolved_variants),
+      Some(primitive) if self.open_integers && primitive.is_integer() => {
+        Self::with_other_variant(resolved_variants, primitive)
+      }
+      _ => resolved_variants,
+    };
+
     RustType::Enum(
       EnumDef::builder()
         .name(EnumToken::from_raw(name))
@@ -87,4 +115,72 @@ impl ValueEnumBuilder {
         .build(),
     )
   }
+
+  /// Spells out the symbols of values that would otherwise share a variant name
+  /// with another value, so `"A/B"` and `"AB"` become `ASlashB` and `AB`.
+  ///
+  /// Values whose spelled names still match, such as `"$lte"` and `"$LTE"`, are
+  /// left to the collision strategy. The result does not depend on value order.
+  fn spell_colliding_symbols(mut variants: Vec<VariantDef>) -> Vec<VariantDef> {
+    let name_counts = variants.iter().map(|v| v.name.to_string()).counts();
+    for variant in &mut variants {
+      let value = variant.serde_name();
+      if matches!(variant.content, VariantContent::Unit)
+        && name_counts[variant.name.as_str()] > 1
+        && has_spelled_symbols(&value)
+      {
+        variant.name = EnumVariantToken::new(to_spelled_variant_name(&value));
+      }
+    }
+    variants
+  }
+
+  /// Appends an `Other` variant holding integers the spec does not list, as `i64`, or
+  /// `u64` for unsigned enums. It is named `OtherValue` when a listed value already
+  /// took the name `Other`.
+  fn with_other_variant(mut variants: Vec<VariantDef>, primitive: &RustPrimitive) -> Vec<VariantDef> {
+    let wire_type = if primitive.is_unsigned_integer() {
+      RustPrimitive::U64
+    } else {
+      RustPrimitive::I64
+    };
+    let name = if variants.iter().any(|v| v.name == OTHER_ENUM_VARIANT) {
+      format!("{OTHER_ENUM_VARIANT}Value")
+    } else {
+      OTHER_ENUM_VARIANT.to_string()
+    };
+    variants.push(
+      VariantDef::builder()
+        .name(EnumVariantToken::from_raw(name))
+        .docs(Documentation::from_lines(["Value not recognized by this client."]))
+        .content(VariantContent::Tuple(vec![TypeRef::new(wire_type)]))
+        .build(),
+    );
+    variants
+  }
+
+  /// Ensures a unit `Unknown` variant exists to catch unrecognized values.
+  ///
+  /// Derived deserializers route unmatched values to it via `#[serde(other)]`; the
+  /// case-insensitive deserializer already falls back to a variant named `Unknown`.
+  fn with_unknown_variant(&self, mut variants: Vec<VariantDef>) -> Vec<VariantDef> {
+    let existing = variants
+      .iter()
+      .position(|v| v.name == UNKNOWN_ENUM_VARIANT && matches!(v.content, VariantContent::Unit));
+    let index = existing.unwrap_or_else(|| {
+      variants.push(
+        VariantDef::builder()
+          .name(EnumVariantToken::from_raw(UNKNOWN_ENUM_VARIANT))
+          .docs(Documentation::from_lines(["Value not recognized by this client."]))
+          .content(VariantContent::Unit)
+          .build(),
+      );
+      variants.len() - 1
+    });
+
+    if !self.case_insensitive {
+      variants[index].serde_attrs.push(SerdeAttribute::Other);
+    }
+    variants
+  }
 }
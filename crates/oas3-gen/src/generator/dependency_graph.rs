@@ -0,0 +1,103 @@
+use std::fmt::Write as _;
+
+const CYCLE_COLOR: &str = "#d93025";
+const ERROR_FILL: &str = "#fde2e1";
+
+/// A component schema in the dependency graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+  /// The schema name under `components/schemas`.
+  pub name: String,
+  /// Index of the reference cycle the schema belongs to, whose members get boxed.
+  pub cycle: Option<usize>,
+  /// Whether the schema is an error response body or nested in one, and implements `Error`.
+  pub error: bool,
+}
+
+/// The references between component schemas, as the generator sees them when deciding
+/// which types to box and which to treat as errors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyGraph {
+  pub nodes: Vec<GraphNode>,
+  /// Indices into `nodes` of each schema and a schema it references.
+  pub edges: Vec<(usize, usize)>,
+}
+
+impl DependencyGraph {
+  /// Returns `true` when both ends of `edge` belong to the same reference cycle.
+  #[must_use]
+  pub fn is_cycle_edge(&self, (from, to): (usize, usize)) -> bool {
+    self.nodes[from].cycle.is_some() && self.nodes[from].cycle == self.nodes[to].cycle
+  }
+
+  /// Renders the graph as a Mermaid flowchart.
+  ///
+  /// Cycle edges are drawn thick, cyclic schemas get a red border, and error schemas
+  /// a red fill.
+  #[must_use]
+  pub fn to_mermaid(&self) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for (index, node) in self.nodes.iter().enumerate() {
+      let _ = writeln!(out, "  n{index}[\"{}\"]", node.name.replace('"', "#quot;"));
+    }
+    for &(from, to) in &self.edges {
+      let arrow = if self.is_cycle_edge((from, to)) { "==>" } else { "-->" };
+      let _ = writeln!(out, "  n{from} {arrow} n{to}");
+    }
+    let _ = writeln!(out, "  classDef cyclic stroke:{CYCLE_COLOR},stroke-width:2px");
+    let _ = writeln!(out, "  classDef error fill:{ERROR_FILL}");
+    for (index, node) in self.nodes.iter().enumerate() {
+      if node.cycle.is_some() {
+        let _ = writeln!(out, "  class n{index} cyclic");
+      }
+      if node.error {
+        let _ = writeln!(out, "  class n{index} error");
+      }
+    }
+    out
+  }
+
+  /// Renders the graph in Graphviz DOT, styled like [`Self::to_mermaid`].
+  #[must_use]
+  pub fn to_dot(&self) -> String {
+    let mut out = String::from("digraph schemas {\n  rankdir=LR;\n  node [shape=box];\n");
+    for node in &self.nodes {
+      let mut attrs = vec![];
+      if node.cycle.is_some() {
+        attrs.push(format!("color=\"{CYCLE_COLOR}\", penwidth=2"));
+      }
+      if node.error {
+        attrs.push(format!("style=filled, fillcolor=\"{ERROR_FILL}\""));
+      }
+      let _ = writeln!(out, "  {}{};", dot_id(&node.name), dot_attrs(&attrs));
+    }
+    for &(from, to) in &self.edges {
+      let attrs = if self.is_cycle_edge((from, to)) {
+        vec![format!("color=\"{CYCLE_COLOR}\", penwidth=2")]
+      } else {
+        vec![]
+      };
+      let _ = writeln!(
+        out,
+        "  {} -> {}{};",
+        dot_id(&self.nodes[from].name),
+        dot_id(&self.nodes[to].name),
+        dot_attrs(&attrs)
+      );
+    }
+    out.push_str("}\n");
+    out
+  }
+}
+
+fn dot_id(name: &str) -> String {
+  format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn dot_attrs(attrs: &[String]) -> String {
+  if attrs.is_empty() {
+    String::new()
+  } else {
+    format!(" [{}]", attrs.join(", "))
+  }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use oas3::{
+  Spec,
+  spec::{ObjectOrReference, ObjectSchema, Schema},
+};
+
+use crate::generator::{ast::RustType, operation_registry::OperationRegistry};
+
+/// Label used for operations that declare no tag.
+pub const UNTAGGED: &str = "(untagged)";
+
+/// The kind of Rust item a component schema is generated as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+  Struct,
+  Enum,
+  Alias,
+}
+
+impl SchemaKind {
+  pub(crate) fn of(rust_type: &RustType) -> Self {
+    match rust_type {
+      RustType::Struct(_) => Self::Struct,
+      RustType::Enum(_) | RustType::DiscriminatedEnum(_) | RustType::ResponseEnum(_) => Self::Enum,
+      RustType::TypeAlias(_) => Self::Alias,
+    }
+  }
+}
+
+impl fmt::Display for SchemaKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Struct => "struct",
+      Self::Enum => "enum",
+      Self::Alias => "alias",
+    })
+  }
+}
+
+/// Whether operations send a schema, receive it, or both.
+///
+/// Usage follows references, so a schema nested in a request body counts as a request
+/// schema. Schemas no operation reaches are `Unused`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaUsage {
+  Request,
+  Response,
+  Both,
+  Unused,
+}
+
+impl SchemaUsage {
+  pub(crate) fn from_flags(in_request: bool, in_response: bool) -> Self {
+    match (in_request, in_response) {
+      (true, true) => Self::Both,
+      (true, false) => Self::Request,
+      (false, true) => Self::Response,
+      (false, false) => Self::Unused,
+    }
+  }
+}
+
+impl fmt::Display for SchemaUsage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Request => "request",
+      Self::Response => "response",
+      Self::Both => "both",
+      Self::Unused => "unused",
+    })
+  }
+}
+
+/// A component schema as it appears in generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaSummary {
+  /// The schema name under `components/schemas`.
+  pub name: String,
+  /// The generated Rust type name.
+  pub rust_name: String,
+  /// The generated item kind, or `None` when the schema produces no type of its own.
+  pub kind: Option<SchemaKind>,
+  /// Whether the schema is part of a reference cycle and gets boxed.
+  pub cyclic: bool,
+  pub usage: SchemaUsage,
+}
+
+/// The number of operations under one tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagSummary {
+  pub name: String,
+  pub description: Option<String>,
+  /// Operations listing this tag anywhere in their `tags`.
+  pub operations: usize,
+  /// Operations listing this tag first, which `--split-by tag` places in its module.
+  pub primary: usize,
+}
+
+/// Counts the operations of each tag.
+///
+/// Tags declared at the top level of the spec come first in declaration order,
+/// followed by undeclared tags in the order operations use them. Operations
+/// without tags are counted under [`UNTAGGED`].
+#[must_use]
+pub fn summarize_tags(spec: &Spec, registry: &OperationRegistry) -> Vec<TagSummary> {
+  let mut tags = spec
+    .tags
+    .iter()
+    .map(|tag| {
+      let summary = TagSummary {
+        name: tag.name.clone(),
+        description: tag.description.clone(),
+        operations: 0,
+        primary: 0,
+      };
+      (tag.name.clone(), summary)
+    })
+    .collect::<IndexMap<_, _>>();
+
+  for entry in registry.operations() {
+    let names = if entry.operation.tags.is_empty() {
+      vec![UNTAGGED.to_string()]
+    } else {
+      entry.operation.tags.clone()
+    };
+
+    for (position, name) in names.into_iter().enumerate() {
+      let summary = tags.entry(name.clone()).or_insert_with(|| TagSummary {
+        name,
+        description: None,
+        operations: 0,
+        primary: 0,
+      });
+      summary.operations += 1;
+      if position == 0 {
+        summary.primary += 1;
+      }
+    }
+  }
+
+  tags.into_values().collect()
+}
+
+/// Size and shape of a spec, for estimating the impact of generating it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecStatistics {
+  /// Schemas under `components/schemas`.
+  pub schemas: usize,
+  pub operations: usize,
+  /// Mean depth of inline schemas across component schemas. A schema without
+  /// inline subschemas has depth 1, and each level of inline properties, items,
+  /// or composition adds one.
+  pub average_nesting_depth: f64,
+  /// Component and inline schemas declaring a `oneOf`.
+  pub one_of_unions: usize,
+  /// Component and inline schemas declaring an `anyOf`.
+  pub any_of_unions: usize,
+  /// Schemas participating in a reference cycle.
+  pub cyclic_schemas: usize,
+  /// Rust types `generate` would emit for the spec.
+  pub projected_types: usize,
+}
+
+/// Returns the depth of inline schemas nested in `schema`, counting `schema` itself.
+///
+/// References end the walk, so cycles between components never recurse.
+pub(crate) fn nesting_depth(schema: &ObjectSchema) -> usize {
+  1 + inline_children(schema).map(nesting_depth).max().unwrap_or_default()
+}
+
+/// Counts the `oneOf` and `anyOf` unions declared by `schema` and its inline schemas.
+pub(crate) fn count_unions(schema: &ObjectSchema) -> (usize, usize) {
+  inline_children(schema).map(count_unions).fold(
+    (
+      usize::from(!schema.one_of.is_empty()),
+      usize::from(!schema.any_of.is_empty()),
+    ),
+    |(one_of, any_of), (child_one_of, child_any_of)| (one_of + child_one_of, any_of + child_any_of),
+  )
+}
+
+fn inline_children(schema: &ObjectSchema) -> impl Iterator<Item = &ObjectSchema> {
+  schema
+    .properties
+    .values()
+    .chain(schema.items.as_deref())
+    .chain(&schema.prefix_items)
+    .chain(&schema.additional_properties)
+    .chain(&schema.all_of)
+    .chain(&schema.any_of)
+    .chain(&schema.one_of)
+    .filter_map(|child| match child {
+      Schema::Object(object) => match object.as_ref() {
+        ObjectOrReference::Object(inline) => Some(inline),
+        ObjectOrReference::Ref { .. } => None,
+      },
+      Schema::Boolean(_) => None,
+    })
+}
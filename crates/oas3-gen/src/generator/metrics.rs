@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use strum::Display;
 
 use crate::generator::{
@@ -14,12 +16,21 @@ pub struct GenerationStats {
   pub type_aliases_generated: usize,
   pub operations_converted: usize,
   pub webhooks_converted: usize,
+  pub callbacks_converted: usize,
   pub cycles_detected: usize,
   pub cycle_details: Vec<Vec<String>>,
   pub warnings: Vec<GenerationWarning>,
   pub orphaned_schemas_count: usize,
+  pub orphaned_schemas: Vec<String>,
+  pub clone_skipped: Vec<String>,
+  pub merged_types: Vec<String>,
   pub client_methods_generated: usize,
   pub client_headers_generated: usize,
+  pub modules_skipped: usize,
+  pub schemas_with_examples: usize,
+  pub schemas_without_examples: Vec<String>,
+  /// Time spent turning generated token streams into source text.
+  pub formatting_time: Duration,
 }
 
 impl GenerationStats {
@@ -58,8 +69,10 @@ impl GenerationStats {
 
   pub fn record_operation(&mut self, operation: &OperationInfo) {
     self.operations_converted += 1;
-    if matches!(operation.kind, OperationKind::Webhook) {
-      self.webhooks_converted += 1;
+    match operation.kind {
+      OperationKind::Http => {}
+      OperationKind::Webhook => self.webhooks_converted += 1,
+      OperationKind::Callback => self.callbacks_converted += 1,
     }
   }
 
@@ -80,8 +93,18 @@ impl GenerationStats {
     }
   }
 
-  pub fn record_orphaned_schemas(&mut self, count: usize) {
-    self.orphaned_schemas_count += count;
+  pub fn record_orphaned_schemas(&mut self, names: impl IntoIterator<Item = String>) {
+    let start = self.orphaned_schemas.len();
+    self.orphaned_schemas.extend(names);
+    self.orphaned_schemas_count += self.orphaned_schemas.len() - start;
+  }
+
+  pub fn record_clone_skipped(&mut self, names: impl IntoIterator<Item = String>) {
+    self.clone_skipped.extend(names);
+  }
+
+  pub fn record_merged_types(&mut self, names: impl IntoIterator<Item = String>) {
+    self.merged_types.extend(names);
   }
 
   pub fn record_client_methods(&mut self, count: usize) {
@@ -92,6 +115,23 @@ impl GenerationStats {
     self.client_headers_generated += count;
   }
 
+  pub fn record_formatting_time(&mut self, elapsed: Duration) {
+    self.formatting_time += elapsed;
+  }
+
+  pub fn record_skipped_modules(&mut self, count: usize) {
+    self.modules_skipped += count;
+  }
+
+  /// Counts a component schema toward example coverage.
+  pub fn record_schema_example(&mut self, has_example: bool, name: &str) {
+    if has_example {
+      self.schemas_with_examples += 1;
+    } else {
+      self.schemas_without_examples.push(name.to_string());
+    }
+  }
+
   pub fn record_warning(&mut self, warning: GenerationWarning) {
     self.warnings.push(warning);
   }
@@ -101,7 +141,7 @@ impl GenerationStats {
   }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
 pub enum GenerationWarning {
   #[strum(to_string = "Failed to convert schema '{schema_name}': {error}")]
   SchemaConversionFailed { schema_name: String, error: String },
@@ -115,6 +155,19 @@ pub enum GenerationWarning {
   OperationSpecific { operation_id: String, message: String },
   #[strum(to_string = "Schema '{schema_name}': {message}")]
   DiscriminatorMappingFailed { schema_name: String, message: String },
+  #[strum(
+    to_string = "Type '{type_name}': property '{property}' collides with '{conflicts_with}' and was renamed to field '{field}'"
+  )]
+  FieldNameCollision {
+    type_name: String,
+    property: String,
+    conflicts_with: String,
+    field: String,
+  },
+  #[strum(to_string = "Formatting '{file}' failed and was skipped: {error}")]
+  FormattingFailed { file: String, error: String },
+  #[strum(to_string = "`not` in {location} is not enforced: {reason}")]
+  UnsupportedNotSchema { location: String, reason: String },
 }
 
 impl GenerationWarning {
@@ -3,19 +3,31 @@
 pub(crate) mod ast;
 pub(crate) mod codegen;
 pub(crate) mod converter;
+pub mod dependency_graph;
+pub mod inventory;
 pub mod metrics;
 pub mod mode;
 pub(crate) mod naming;
 pub mod operation_registry;
 pub mod orchestrator;
+pub mod origins;
 pub(crate) mod postprocess;
 pub(crate) mod schema_registry;
 
 pub use converter::{
-  CodegenConfig, CollectionTypePolicy, EnumCasePolicy, EnumDeserializePolicy, EnumHelperPolicy, EnumLayoutPolicy,
-  GenerationTarget, HeaderScope, ODataPolicy, SchemaScope,
+  BatchPolicy, BuilderPolicy, ClientErrorPolicy, ClientRuntime, ClonePolicy, CodegenConfig, CollectionTypePolicy,
+  ConversionPolicy, DecimalPolicy, DeserializeProfile, DocFormatPolicy, DurationFormatPolicy, EnumCasePolicy,
+  EnumDeserializePolicy, EnumHelperPolicy, EnumLayoutPolicy, ExtraDerivePolicy, GenerationTarget, HeaderScope,
+  InlineObjectPolicy, IntegerEnumPolicy, IntegerWidthPolicy, LastResponsePolicy, MalformedResponsePolicy,
+  ModuleSplitPolicy, NullabilityPolicy, NumberCoercionPolicy, ODataPolicy, OutputFormatPolicy, PaginationPolicy,
+  PreludePolicy, PropertyAccessPolicy, RawBodyPolicy, RequestBodyPolicy, RequestHookPolicy, RequestValidationPolicy,
+  ResponseClassPolicy, RetryPolicy, RouteTestPolicy, SchemaScope, TowerServicePolicy, TypeMergePolicy,
+  UnsignedIntegerPolicy, VcrPolicy,
+};
+pub use mode::{
+  ClientModMode, ClientMode, EmitTargets, GenerationMode, MockMode, PropertyTestsMode, ServerModMode, ServerMode,
+  TestsMode, TypesMode, WorkspaceMode,
 };
-pub use mode::{ClientModMode, ClientMode, GenerationMode, ServerModMode, TypesMode};
 
 #[cfg(test)]
 mod tests;
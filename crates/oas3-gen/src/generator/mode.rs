@@ -1,7 +1,20 @@
-use crate::generator::codegen::{GeneratedResult, SchemaCodeGenerator};
+use crate::generator::codegen::{GeneratedResult, SchemaCodeGenerator, workspace::WorkspaceCrates};
 
 pub trait GenerationMode {
   fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult>;
+
+  /// Returns `true` when every type must both serialize and deserialize, whichever
+  /// direction operations use it in.
+  fn round_trips_types(&self) -> bool {
+    false
+  }
+}
+
+/// Outputs produced by one `--emit` run, alongside the types every output needs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmitTargets {
+  pub client: bool,
+  pub server: bool,
 }
 
 pub struct TypesMode;
@@ -20,6 +33,46 @@ impl GenerationMode for ClientMode {
   }
 }
 
+pub struct ServerMode;
+
+impl GenerationMode for ServerMode {
+  fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult> {
+    codegen.generate_server()
+  }
+}
+
+pub struct MockMode;
+
+impl GenerationMode for MockMode {
+  fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult> {
+    codegen.generate_mock()
+  }
+}
+
+pub struct TestsMode;
+
+impl GenerationMode for TestsMode {
+  fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult> {
+    codegen.generate_example_tests()
+  }
+
+  fn round_trips_types(&self) -> bool {
+    true
+  }
+}
+
+pub struct PropertyTestsMode;
+
+impl GenerationMode for PropertyTestsMode {
+  fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult> {
+    codegen.generate_property_tests()
+  }
+
+  fn round_trips_types(&self) -> bool {
+    true
+  }
+}
+
 pub struct ClientModMode;
 
 impl GenerationMode for ClientModMode {
@@ -35,3 +88,13 @@ impl GenerationMode for ServerModMode {
     codegen.generate_server_mod()
   }
 }
+
+pub struct WorkspaceMode {
+  pub crates: WorkspaceCrates,
+}
+
+impl GenerationMode for WorkspaceMode {
+  fn generate(&self, codegen: &SchemaCodeGenerator) -> anyhow::Result<GeneratedResult> {
+    codegen.generate_workspace(&self.crates)
+  }
+}
@@ -0,0 +1,183 @@
+use std::{cell::RefCell, collections::BTreeSet};
+
+use any_ascii::any_ascii;
+
+use crate::utils::scoped;
+
+/// Acronyms recognized when splitting and rendering identifiers.
+const DEFAULT_ACRONYMS: &[&str] = &[
+  "ACL", "API", "ASCII", "CPU", "CSS", "CSV", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IO", "IP", "JSON",
+  "JWT", "LHS", "MCP", "OS", "PDF", "QPS", "RAM", "RHS", "RPC", "SDK", "SLA", "SMTP", "SQL", "SSH", "SSL", "TCP",
+  "TLS", "TTL", "UDP", "UI", "URI", "URL", "UTC", "UTF8", "UUID", "VM", "XML", "XSRF", "XSS",
+];
+
+thread_local! {
+  static CASING: RefCell<Casing> = RefCell::new(Casing::default());
+}
+
+/// How acronyms are capitalized in `PascalCase` identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AcronymCase {
+  /// Names written in mixed case keep their capitalization (`HTTPProxyURL`), while
+  /// separated names are capitalized word by word (`http_proxy_url` becomes `HttpProxyUrl`).
+  #[default]
+  Preserve,
+  /// Known acronyms are always uppercase (`HTTPProxyURL`).
+  Upper,
+  /// Acronyms are capitalized like any other word (`HttpProxyUrl`).
+  Pascal,
+}
+
+/// Casing rules shared by type, field, constant, and method names.
+///
+/// Conversions only use ASCII case mapping after transliteration, so the output does
+/// not depend on the locale or on the path that names a given item.
+#[derive(Debug, Clone)]
+pub struct Casing {
+  acronym_case: AcronymCase,
+  acronyms: BTreeSet<String>,
+}
+
+impl Default for Casing {
+  fn default() -> Self {
+    Self::new(AcronymCase::default(), [])
+  }
+}
+
+impl Casing {
+  /// Creates casing rules recognizing the default acronyms plus `acronyms`.
+  #[must_use]
+  pub fn new(acronym_case: AcronymCase, acronyms: impl IntoIterator<Item = String>) -> Self {
+    let acronyms = DEFAULT_ACRONYMS
+      .iter()
+      .map(ToString::to_string)
+      .chain(acronyms.into_iter().map(|acronym| acronym.to_ascii_uppercase()))
+      .collect();
+    Self { acronym_case, acronyms }
+  }
+
+  /// Runs `f` with these rules applied by every identifier conversion on the current
+  /// thread, restoring the previous rules afterwards.
+  pub(crate) fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+    scoped(&CASING, self.clone(), f)
+  }
+
+  /// Splits `input` into words.
+  ///
+  /// Non-alphanumeric characters separate words, as do a lowercase letter followed by
+  /// an uppercase letter, and the last letter of an uppercase run followed by a
+  /// lowercase letter (`HTTPProxy` is `HTTP`, `Proxy`). Digits stay with the letters
+  /// before them (`v2Api` is `v2`, `Api`). A known acronym followed
+  /// by a plural `s` stays one word (`userIDs` is `user`, `IDs`).
+  pub(crate) fn split_words(&self, input: &str) -> Vec<String> {
+    let mut words = vec![];
+    for segment in any_ascii(input)
+      .split(|c: char| !c.is_ascii_alphanumeric())
+      .filter(|segment| !segment.is_empty())
+    {
+      let chars = segment.chars().collect::<Vec<_>>();
+      let mut start = 0;
+      for i in 1..chars.len() {
+        let (prev, current) = (chars[i - 1], chars[i]);
+        let boundary = if current.is_ascii_uppercase() {
+          prev.is_ascii_lowercase()
+        } else if current.is_ascii_lowercase() && prev.is_ascii_uppercase() && i - start >= 2 {
+          let plural = current == 's' && chars.get(i + 1).is_none_or(|next| !next.is_ascii_lowercase());
+          let run = &chars[start..i];
+          !(plural && run.iter().all(char::is_ascii_uppercase) && self.is_acronym(&String::from_iter(run)))
+        } else {
+          false
+        };
+
+        if boundary {
+          let end = if current.is_ascii_lowercase() { i - 1 } else { i };
+          if end > start {
+            words.push(String::from_iter(&chars[start..end]));
+            start = end;
+          }
+        }
+      }
+      words.push(String::from_iter(&chars[start..]));
+    }
+    words
+  }
+
+  /// Converts `input` to `PascalCase` following the acronym policy.
+  pub(crate) fn to_pascal_case(&self, input: &str) -> String {
+    if self.acronym_case == AcronymCase::Preserve && is_mixed_case(input) {
+      let mut capitalize_next = true;
+      return any_ascii(input)
+        .chars()
+        .filter_map(|c| {
+          if !c.is_ascii_alphanumeric() {
+            capitalize_next = true;
+            return None;
+          }
+          let c = if capitalize_next { c.to_ascii_uppercase() } else { c };
+          capitalize_next = false;
+          Some(c)
+        })
+        .collect();
+    }
+
+    self
+      .split_words(input)
+      .iter()
+      .map(|word| self.pascal_word(word))
+      .collect()
+  }
+
+  /// Converts `input` to `snake_case`.
+  pub(crate) fn to_snake_case(&self, input: &str) -> String {
+    self.split_words(input).join("_").to_ascii_lowercase()
+  }
+
+  /// Converts `input` to `SCREAMING_SNAKE_CASE`.
+  pub(crate) fn to_constant_case(&self, input: &str) -> String {
+    self.split_words(input).join("_").to_ascii_uppercase()
+  }
+
+  fn pascal_word(&self, word: &str) -> String {
+    if self.acronym_case == AcronymCase::Upper {
+      if self.is_acronym(word) {
+        return word.to_ascii_uppercase();
+      }
+      if let Some(singular) = word.strip_suffix(['s', 'S'])
+        && self.is_acronym(singular)
+      {
+        return format!("{}s", singular.to_ascii_uppercase());
+      }
+    }
+
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+      first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+    })
+  }
+
+  fn is_acronym(&self, word: &str) -> bool {
+    self.acronyms.contains(&word.to_ascii_uppercase())
+  }
+}
+
+/// Returns `true` for names with both cases and no separators, like `HTTPProxyURL`.
+fn is_mixed_case(input: &str) -> bool {
+  !input.contains(['-', '_', '.', ' '])
+    && input.chars().any(|c| c.is_ascii_uppercase())
+    && input.chars().any(|c| c.is_ascii_lowercase())
+}
+
+/// Converts `input` to `PascalCase` with the casing rules in scope.
+pub(crate) fn to_pascal_case(input: &str) -> String {
+  CASING.with_borrow(|casing| casing.to_pascal_case(input))
+}
+
+/// Converts `input` to `snake_case` with the casing rules in scope.
+pub(crate) fn to_snake_case(input: &str) -> String {
+  CASING.with_borrow(|casing| casing.to_snake_case(input))
+}
+
+/// Converts `input` to `SCREAMING_SNAKE_CASE` with the casing rules in scope.
+pub(crate) fn to_constant_case(input: &str) -> String {
+  CASING.with_borrow(|casing| casing.to_constant_case(input))
+}
@@ -1,3 +1,6 @@
+pub const API_ERROR_ALIAS: &str = "ApiError";
+pub const BATCH_METHOD: &str = "batch";
+pub const BATCH_METHOD_FALLBACK: &str = "batch_requests";
 pub const BODY_FIELD_NAME: &str = "body";
 pub const DEFAULT_RESPONSE_DESCRIPTION: &str = "Unknown response";
 pub const DEFAULT_RESPONSE_VARIANT: &str = "Unknown";
@@ -6,7 +9,11 @@ pub const HEADER_PARAMS_FIELD: &str = "header";
 pub const HEADER_PARAMS_SUFFIX: &str = "Header";
 pub const VARIANT_KIND_SUFFIX: &str = "Kind";
 pub const KNOWN_ENUM_VARIANT: &str = "Known";
+pub const MALFORMED_RESPONSE_VARIANT: &str = "Malformed";
+pub const RATE_LIMITED_VARIANT: &str = "RateLimited";
 pub const OTHER_ENUM_VARIANT: &str = "Other";
+pub const UNKNOWN_ENUM_VARIANT: &str = "Unknown";
+pub const PAGE_LINK_FIELD: &str = "page_link";
 pub const PATH_PARAMS_FIELD: &str = "path";
 pub const PATH_PARAMS_SUFFIX: &str = "Path";
 pub const QUERY_PARAMS_FIELD: &str = "query";
@@ -17,7 +24,32 @@ pub const REQUEST_SUFFIX: &str = "Request";
 pub const RESPONSE_ENUM_SUFFIX: &str = "Enum";
 pub const RESPONSE_PREFIX: &str = "Response";
 pub const RESPONSE_SUFFIX: &str = "Response";
+pub const WEBHOOK_HANDLER_TRAIT: &str = "WebhookHandler";
 
 pub const DEFAULT_MEDIA_TYPE: &str = "application/json";
+pub const FILE_PART_TYPE: &str = "oas3_gen_support::FilePart";
+pub const SERVER_EVENT_STREAM_TYPE: &str = "axum::response::Response";
+pub const JSON_BODY_VARIANT: &str = "Json";
+
+pub const TIMESTAMP_FORMAT_EXTENSION: &str = "timestamp-format";
+pub const ENUM_VARNAMES_EXTENSION: &str = "enum-varnames";
+pub const ENUM_NAMES_EXTENSION: &str = "enumNames";
+pub const FORMAT_EXTENSION: &str = "format";
+pub const SCALE_EXTENSION: &str = "scale";
+pub const ERROR_MESSAGE_FIELD_EXTENSION: &str = "error-message-field";
+pub const EVENT_NAME_EXTENSION: &str = "event-name";
+pub const RUST_TYPE_EXTENSION: &str = "rust-type";
+pub const NAME_EXTENSION: &str = "name";
+pub const REQUEST_COMPRESSION_EXTENSION: &str = "request-compression";
+pub const DEPRECATED_REPLACEMENT_EXTENSION: &str = "deprecated-replacement";
+pub const PAGINATION_EXTENSION: &str = "pagination";
+pub const CONTENT_ENCODING_HEADER: &str = "content-encoding";
+pub const LINK_HEADER: &str = "link";
+pub const RETRY_AFTER_HEADER: &str = "retry-after";
+pub const EVENT_ENUM_SUFFIX: &str = "Event";
+pub const UNKNOWN_EVENT_VARIANT: &str = "Unknown";
+pub const MONEY_FORMAT: &str = "money";
+pub const DECIMAL_FORMAT: &str = "decimal";
+pub const DEFAULT_MONEY_SCALE: u32 = 2;
 
 pub const BON_RESERVED_FIELD_NAMES: &[&str] = &["build", "builder"];
@@ -0,0 +1,245 @@
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  io::{BufRead, BufReader, Write},
+  path::Path,
+  process::{Child, ChildStdin, Command, Stdio},
+  sync::{
+    Arc, Mutex, MutexGuard, PoisonError,
+    mpsc::{self, Receiver, RecvTimeoutError},
+  },
+  thread,
+  time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::utils::scoped;
+
+thread_local! {
+  static NAMING_HOOK: RefCell<Option<NamingHook>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `hook` consulted by every type, field, and method name conversion on
+/// the current thread, restoring the previous hook afterwards.
+pub(crate) fn scope_naming_hook<R>(hook: Option<&NamingHook>, f: impl FnOnce() -> R) -> R {
+  scoped(&NAMING_HOOK, hook.cloned(), f)
+}
+
+/// Asks the hook in scope for a name, returning `None` to fall back to the built-in
+/// rules.
+pub(crate) fn hook_name(request: &NameRequest) -> Option<String> {
+  NAMING_HOOK.with_borrow(|hook| hook.as_ref()?.name(request))
+}
+
+/// The naming decision a hook is asked to make.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NameKind {
+  /// A struct, enum, enum variant, or type alias name.
+  Type,
+  /// A struct field, parameter, or module name.
+  Field,
+  /// A client or server method name, from an operation.
+  Method,
+}
+
+/// A name the generator is about to convert, sent to the hook as one JSON line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NameRequest<'a> {
+  pub kind: NameKind,
+  /// The name as written in the spec, before any conversion.
+  pub name: &'a str,
+  /// The HTTP method of the operation, for method names.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub method: Option<&'a str>,
+  /// The path of the operation, for method names.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path: Option<&'a str>,
+}
+
+impl<'a> NameRequest<'a> {
+  #[must_use]
+  pub fn new(kind: NameKind, name: &'a str) -> Self {
+    Self {
+      kind,
+      name,
+      method: None,
+      path: None,
+    }
+  }
+
+  #[must_use]
+  pub fn operation(name: &'a str, method: &'a str, path: &'a str) -> Self {
+    Self {
+      kind: NameKind::Method,
+      name,
+      method: Some(method),
+      path: Some(path),
+    }
+  }
+}
+
+/// A user-provided executable asked to name types, fields, and methods.
+///
+/// The program is started once, with the spec path as its only argument. For each
+/// name it reads one JSON request line on stdin and writes one line back: a JSON
+/// string with the Rust identifier to use, or `null` to keep the built-in name.
+/// Answers are cached, and names the hook returned are kept as they are when
+/// converted again. Clones share the same process, which is stopped and reaped when
+/// the hook is closed or the last clone is dropped.
+#[derive(Debug, Clone)]
+pub struct NamingHook {
+  process: Arc<Mutex<HookProcess>>,
+}
+
+impl NamingHook {
+  /// How long the hook has to answer one request before generation fails.
+  pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+  /// Starts the hook program at `program`, passing it `spec_path`.
+  pub fn spawn(program: &Path, spec_path: &Path) -> anyhow::Result<Self> {
+    let mut child = Command::new(program)
+      .arg(spec_path)
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .spawn()
+      .map_err(|e| anyhow::anyhow!("Failed to start naming hook '{}': {e}", program.display()))?;
+    let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+      anyhow::bail!("Failed to connect to naming hook '{}'", program.display());
+    };
+    let (sender, lines) = mpsc::channel();
+    thread::spawn(move || {
+      for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        if sender.send(line).is_err() {
+          break;
+        }
+      }
+    });
+    Ok(Self {
+      process: Arc::new(Mutex::new(HookProcess {
+        child,
+        stdin: Some(stdin),
+        lines,
+        timeout: Self::DEFAULT_TIMEOUT,
+        answers: HashMap::new(),
+        error: None,
+      })),
+    })
+  }
+
+  /// Sets how long the hook has to answer one request, [`Self::DEFAULT_TIMEOUT`] by
+  /// default.
+  #[must_use]
+  pub fn with_timeout(self, timeout: Duration) -> Self {
+    self.lock().timeout = timeout;
+    self
+  }
+
+  /// Returns the hook's name for `request`, or `None` to use the built-in rules.
+  ///
+  /// A hook that fails, times out, or answers with an invalid identifier records an
+  /// [`error`](Self::error) and is not asked again.
+  #[must_use]
+  pub fn name(&self, request: &NameRequest) -> Option<String> {
+    self.lock().name(request)
+  }
+
+  /// Returns the error that stopped the hook from being asked, if any.
+  #[must_use]
+  pub fn error(&self) -> Option<String> {
+    self.lock().error.clone()
+  }
+
+  /// Closes the hook's stdin and waits for the process, killing it if it is still
+  /// running. Asking for a name afterwards records an error.
+  pub fn close(&self) {
+    self.lock().close();
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HookProcess> {
+    self.process.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+#[derive(Debug)]
+struct HookProcess {
+  child: Child,
+  stdin: Option<ChildStdin>,
+  lines: Receiver<String>,
+  timeout: Duration,
+  answers: HashMap<(NameKind, String), Option<String>>,
+  error: Option<String>,
+}
+
+impl HookProcess {
+  fn name(&mut self, request: &NameRequest) -> Option<String> {
+    if self.error.is_some() {
+      return None;
+    }
+    let key = (request.kind, request.name.to_string());
+    if let Some(answer) = self.answers.get(&key) {
+      return answer.clone();
+    }
+    match self.ask(request) {
+      Ok(answer) => {
+        if let Some(name) = &answer {
+          self
+            .answers
+            .entry((request.kind, name.clone()))
+            .or_insert_with(|| Some(name.clone()));
+        }
+        self.answers.insert(key, answer.clone());
+        answer
+      }
+      Err(e) => {
+        self.error = Some(e.to_string());
+        None
+      }
+    }
+  }
+
+  fn close(&mut self) {
+    if self.stdin.take().is_none() {
+      return;
+    }
+    if !matches!(self.child.try_wait(), Ok(Some(_))) {
+      self.child.kill().ok();
+    }
+    self.child.wait().ok();
+  }
+
+  fn ask(&mut self, request: &NameRequest) -> anyhow::Result<Option<String>> {
+    let Some(stdin) = self.stdin.as_mut() else {
+      anyhow::bail!("Naming hook was asked to name '{}' after it was closed", request.name);
+    };
+    writeln!(stdin, "{}", serde_json::to_string(request)?)?;
+    stdin.flush()?;
+    let line = match self.lines.recv_timeout(self.timeout) {
+      Ok(line) => line,
+      Err(RecvTimeoutError::Timeout) => anyhow::bail!(
+        "Naming hook did not answer '{}' within {:?}",
+        request.name,
+        self.timeout
+      ),
+      Err(RecvTimeoutError::Disconnected) => anyhow::bail!("Naming hook exited before naming '{}'", request.name),
+    };
+    let answer = serde_json::from_str::<Option<String>>(&line)
+      .map_err(|e| anyhow::anyhow!("Naming hook answered '{}' with invalid JSON: {e}", request.name))?;
+    if let Some(name) = &answer
+      && syn::parse_str::<syn::Ident>(name).is_err()
+    {
+      anyhow::bail!(
+        "Naming hook answered '{}' with '{name}', which is not a valid identifier",
+        request.name
+      );
+    }
+    Ok(answer)
+  }
+}
+
+impl Drop for HookProcess {
+  fn drop(&mut self) {
+    self.close();
+  }
+}
@@ -1,31 +1,38 @@
 use std::{
-  char::{ToLowercase, ToUppercase},
-  collections::{BTreeSet, HashSet},
-  iter::Peekable,
+  cell::RefCell,
+  collections::{BTreeSet, HashMap, HashSet},
   sync::LazyLock,
 };
 
 use any_ascii::any_ascii;
-use inflections::Inflect;
 use regex::Regex;
 
+use crate::{
+  generator::naming::{
+    casing::{to_constant_case, to_pascal_case, to_snake_case},
+    hooks::{NameKind, NameRequest, hook_name},
+  },
+  utils::scoped,
+};
+
 pub(crate) static FORBIDDEN_IDENTIFIERS: LazyLock<HashSet<&str>> = LazyLock::new(|| {
   [
     "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in",
     "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct", "super", "trait", "true",
     "type", "unsafe", "use", "where", "while", "async", "await", "dyn", "try", "abstract", "become", "box", "do",
-    "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "gen",
-    // 'self' is a special case for fields but is treated as a keyword here for simplicity.
-    // The field-specific logic will handle the `self_` transformation.
-    "self", "Self",
+    "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "gen", "self", "Self",
   ]
   .into_iter()
   .collect()
 });
 
+/// Path keywords that cannot be written as raw identifiers (`r#self` is invalid).
+static NON_RAW_KEYWORDS: LazyLock<HashSet<&str>> =
+  LazyLock::new(|| ["crate", "self", "Self", "super"].into_iter().collect());
+
 static PRELUDE_TYPE_NAMES: LazyLock<HashSet<&str>> = LazyLock::new(|| {
   [
-    "Clone", "Copy", "Display", "Option", "Result", "Send", "Sync", "Type", "Vec",
+    "Clone", "Copy", "Display", "Option", "Result", "Self", "Send", "Sync", "Type", "Vec",
   ]
   .into_iter()
   .collect()
@@ -142,36 +149,47 @@ where
 /// Converts a string into a valid Rust field name (`snake_case`).
 ///
 /// # Rules:
-/// 1. If the string starts with `-`, it's stripped and "negative_" is prepended to the result.
-/// 2. Sanitizes the base string.
-/// 3. Converts to `snake_case`.
-/// 4. If the result is `self`, it becomes `self_`.
-/// 5. If the result is a keyword, it gets a raw identifier prefix (`r#`).
-/// 6. If the result starts with a digit, it's prefixed with `_`.
-/// 7. If the result is empty, it becomes `_`.
+/// 1. If the string starts with `r#`, it's stripped and the rest is converted.
+/// 2. If the string starts with `-`, it's stripped and "negative_" is prepended to the result.
+/// 3. Sanitizes the base string, transliterating non-ASCII characters.
+/// 4. Converts to `snake_case`.
+/// 5. If the result is `self`, `super`, or `crate`, it gets a `_` suffix.
+/// 6. If the result is any other keyword, it gets a raw identifier prefix (`r#`).
+/// 7. If the result starts with a digit, it's prefixed with `_`.
+/// 8. If the result is empty, it becomes `unnamed`.
+///
+/// The output is a valid identifier and converts to itself.
+///
+/// The installed naming hook is asked first; the rules apply when it keeps the
+/// built-in name.
 pub(crate) fn to_rust_field_name(name: &str) -> String {
+  hook_name(&NameRequest::new(NameKind::Field, name)).unwrap_or_else(|| builtin_field_name(name))
+}
+
+/// Converts a string into a field name by the rules of [`to_rust_field_name`],
+/// without asking the naming hook.
+pub(crate) fn builtin_field_name(name: &str) -> String {
   if let Some(raw) = name.strip_prefix("r#")
     && !raw.is_empty()
-    && raw.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
   {
-    return format!("r#{raw}");
+    return builtin_field_name(raw);
   }
 
   let has_leading_minus = name.starts_with('-');
   let name_without_minus = name.strip_prefix('-').unwrap_or(name);
 
-  let mut ident = sanitize(name_without_minus).to_snake_case();
+  let mut ident = to_snake_case(&sanitize(name_without_minus));
 
   if ident.is_empty() {
-    return "_".to_string();
+    return "unnamed".to_string();
   }
 
   if has_leading_minus {
     ident = format!("negative_{ident}");
   }
 
-  if ident == "self" {
-    return "self_".to_string();
+  if NON_RAW_KEYWORDS.contains(ident.as_str()) {
+    return format!("{ident}_");
   }
 
   if FORBIDDEN_IDENTIFIERS.contains(ident.as_str()) {
@@ -188,56 +206,58 @@ pub(crate) fn to_rust_const_name(input: &str) -> String {
     return "UNNAMED".to_string();
   }
 
-  let mut ident = sanitized.to_constant_case();
+  let mut ident = to_constant_case(&sanitized);
   if ident.starts_with(|c: char| c.is_ascii_digit()) {
     ident.insert(0, '_');
   }
   ident
 }
 
+thread_local! {
+  static TYPE_NAME_OVERRIDES: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+/// Runs `f` with `overrides` used in place of the converted schema names on the current
+/// thread, restoring the previous overrides afterwards.
+///
+/// Each replacement must already be a valid type name that is not itself a key, so
+/// converting it again leaves it unchanged.
+pub(crate) fn scope_type_name_overrides<R>(overrides: &HashMap<String, String>, f: impl FnOnce() -> R) -> R {
+  scoped(&TYPE_NAME_OVERRIDES, overrides.clone(), f)
+}
+
 /// Converts a string into a valid Rust type name (`PascalCase`).
 ///
+/// A name with an override in scope is replaced as-is, then the
+/// installed naming hook is asked; the rules below apply to every other name.
+///
 /// # Rules:
 /// 1. If the string starts with `r#`, strip it (raw identifiers should be re-evaluated for type names).
 /// 2. If the string starts with `-`, it's stripped and "Negative" is prepended to the result.
-/// 3. If the input already has mixed case (both upper and lowercase, no separators), preserve capitalization.
-/// 4. Otherwise, sanitizes the base string and converts to `PascalCase` using capitalize_words.
-/// 5. If the result is a reserved name (e.g., `Clone`, `Vec`), it gets a `Type` suffix.
-/// 6. If the result starts with a digit, it's prefixed with `T`.
-/// 7. If the result is empty, it becomes `Unnamed`.
+/// 3. Non-ASCII characters are transliterated; a word following a dropped character is capitalized.
+/// 4. If the input already has mixed case (both upper and lowercase, no separators), capitalization is
+///    preserved unless the acronym policy says otherwise.
+/// 5. Otherwise, converts to `PascalCase` word by word, following the installed acronym policy.
+/// 6. If the result is a reserved name (e.g., `Self`, `Clone`, `Vec`), it gets a `Type` suffix.
+/// 7. If the result starts with a digit, it's prefixed with `T`.
+/// 8. If the result is empty, it becomes `Unnamed`.
+///
+/// The output is a valid identifier and, unless it is all uppercase, converts to itself.
 pub(crate) fn to_rust_type_name(name: &str) -> String {
+  if let Some(rust_name) = TYPE_NAME_OVERRIDES.with_borrow(|overrides| overrides.get(name).cloned()) {
+    return rust_name;
+  }
+
+  if let Some(hooked) = hook_name(&NameRequest::new(NameKind::Type, name)) {
+    return hooked;
+  }
+
   let name = name.strip_prefix("r#").unwrap_or(name);
 
   let has_leading_minus = name.starts_with('-');
   let name_without_minus = name.strip_prefix('-').unwrap_or(name);
 
-  let has_separators = name_without_minus.contains(['-', '_', '.', ' ']);
-  let has_upper = name_without_minus.chars().any(|c| c.is_ascii_uppercase());
-  let has_lower = name_without_minus.chars().any(|c| c.is_ascii_lowercase());
-  let appears_mixed_case = !has_separators && has_upper && has_lower;
-
-  let mut ident = if appears_mixed_case {
-    let ascii = any_ascii(name_without_minus);
-    let cleaned = ascii.chars().filter(char::is_ascii_alphanumeric).collect::<String>();
-
-    if cleaned.is_empty() {
-      cleaned
-    } else {
-      let mut chars = cleaned.chars();
-      match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-      }
-    }
-  } else {
-    let ascii = any_ascii(name_without_minus);
-
-    ascii
-      .chars()
-      .capitalize_words_with_boundaries()
-      .filter(char::is_ascii_alphanumeric)
-      .collect()
-  };
+  let mut ident = to_pascal_case(name_without_minus);
 
   if ident.is_empty() {
     return "Unnamed".to_string();
@@ -247,10 +267,6 @@ pub(crate) fn to_rust_type_name(name: &str) -> String {
     ident = format!("Negative{ident}");
   }
 
-  if ident == "Self" {
-    return "r#Self".to_string();
-  }
-
   if PRELUDE_TYPE_NAMES.contains(ident.as_str()) {
     return format!("{ident}Type");
   }
@@ -259,97 +275,86 @@ pub(crate) fn to_rust_type_name(name: &str) -> String {
   ident
 }
 
-fn prefix_if_digit_start(ident: &mut String, prefix: char) {
-  if ident.starts_with(|c: char| c.is_ascii_digit()) {
-    ident.insert(0, prefix);
+/// Words substituted for symbols in enum values, so `"$lte"` becomes `DollarLte`.
+const SYMBOL_WORDS: &[(char, &str)] = &[
+  ('!', "Not"),
+  ('#', "Hash"),
+  ('$', "Dollar"),
+  ('%', "Percent"),
+  ('&', "And"),
+  ('*', "Asterisk"),
+  ('+', "Plus"),
+  ('/', "Slash"),
+  ('<', "Lt"),
+  ('=', "Eq"),
+  ('>', "Gt"),
+  ('@', "At"),
+  ('\\', "Backslash"),
+  ('^', "Caret"),
+  ('|', "Pipe"),
+  ('~', "Tilde"),
+];
+
+static NUMBER_WITH_UNIT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]+)([A-Za-z]+)$").unwrap());
+
+/// Converts an enum value into a PascalCase variant name.
+///
+/// A number followed by a unit moves the unit to the front (`"1080p"` becomes
+/// `P1080`), and symbols leading the value or making up all of it are spelled out
+/// (`"$lte"` becomes `DollarLte`, `"*"` becomes `Asterisk`). Symbols inside the
+/// value are dropped like in [`to_rust_type_name`].
+pub(crate) fn to_rust_variant_name(value: &str) -> String {
+  if let Some(captures) = NUMBER_WITH_UNIT_RE.captures(value) {
+    return format!("{}{}", to_rust_type_name(&captures[2]), &captures[1]);
   }
+  let spell_all = !value.chars().any(char::is_alphanumeric);
+  to_rust_type_name(&spell_symbols(value, spell_all))
 }
 
-pub(crate) fn to_http_header_name(name: &str) -> String {
-  name.to_ascii_lowercase()
-}
-
-/// An extension trait for char iterators to add word capitalization.
-pub trait CapitalizeWordsExt: Iterator<Item = char> {
-  fn capitalize_words_with_boundaries(self) -> CapitalizeWordsWithBoundaries<Self>
-  where
-    Self: Sized;
+/// Converts an enum value into a variant name with every symbol spelled out.
+///
+/// Used when values differing only in their symbols, like `"A/B"` and `"AB"`,
+/// would otherwise share a variant.
+pub(crate) fn to_spelled_variant_name(value: &str) -> String {
+  to_rust_type_name(&spell_symbols(value, true))
 }
 
-impl<I> CapitalizeWordsExt for I
-where
-  I: Iterator<Item = char>,
-{
-  fn capitalize_words_with_boundaries(self) -> CapitalizeWordsWithBoundaries<Self>
-  where
-    Self: Sized,
-  {
-    CapitalizeWordsWithBoundaries {
-      iter: self.peekable(),
-      capitalize_next: true,
-      prev_was_lower: false,
-      pending_upper: None,
-      pending_lower: None,
-    }
-  }
+/// Returns `true` when `value` contains a symbol that [`to_spelled_variant_name`] spells out.
+pub(crate) fn has_spelled_symbols(value: &str) -> bool {
+  value.chars().any(|c| symbol_word(c).is_some())
 }
 
-pub struct CapitalizeWordsWithBoundaries<I>
-where
-  I: Iterator<Item = char>,
-{
-  iter: Peekable<I>,
-  capitalize_next: bool,
-  prev_was_lower: bool,
-  pending_upper: Option<ToUppercase>,
-  pending_lower: Option<ToLowercase>,
+fn symbol_word(c: char) -> Option<&'static str> {
+  SYMBOL_WORDS
+    .iter()
+    .find_map(|(symbol, word)| (*symbol == c).then_some(*word))
 }
 
-impl<I> Iterator for CapitalizeWordsWithBoundaries<I>
-where
-  I: Iterator<Item = char>,
-{
-  type Item = char;
-
-  fn next(&mut self) -> Option<Self::Item> {
-    if let Some(ref mut upper_iter) = self.pending_upper {
-      if let Some(c) = upper_iter.next() {
-        return Some(c);
+/// Replaces symbols with space-separated words, only before the first alphanumeric
+/// character unless `all` is set.
+fn spell_symbols(value: &str, all: bool) -> String {
+  let mut leading = true;
+  let mut spelled = String::with_capacity(value.len());
+  for c in value.chars() {
+    leading &= !c.is_alphanumeric();
+    match symbol_word(c) {
+      Some(word) if all || leading => {
+        spelled.push(' ');
+        spelled.push_str(word);
+        spelled.push(' ');
       }
-      self.pending_upper = None;
+      _ => spelled.push(c),
     }
+  }
+  spelled
+}
 
-    if let Some(ref mut lower_iter) = self.pending_lower {
-      if let Some(c) = lower_iter.next() {
-        return Some(c);
-      }
-      self.pending_lower = None;
-    }
-
-    let c = self.iter.next()?;
-
-    if !c.is_ascii_alphanumeric() {
-      self.capitalize_next = self.iter.peek().is_some_and(char::is_ascii_alphanumeric);
-      self.prev_was_lower = false;
-      return Some(c);
-    }
-
-    let is_lower = c.is_ascii_lowercase();
-    let is_upper = c.is_ascii_uppercase();
-
-    let should_capitalize = self.capitalize_next
-      || (self.prev_was_lower && is_upper)
-      || (is_upper && self.iter.peek().is_some_and(char::is_ascii_lowercase));
-
-    self.prev_was_lower = is_lower;
-    self.capitalize_next = false;
-
-    if should_capitalize {
-      self.pending_upper = Some(c.to_uppercase());
-      self.pending_upper.as_mut().unwrap().next()
-    } else {
-      self.pending_lower = Some(c.to_lowercase());
-      self.pending_lower.as_mut().unwrap().next()
-    }
+fn prefix_if_digit_start(ident: &mut String, prefix: char) {
+  if ident.starts_with(|c: char| c.is_ascii_digit()) {
+    ident.insert(0, prefix);
   }
 }
+
+pub(crate) fn to_http_header_name(name: &str) -> String {
+  name.to_ascii_lowercase()
+}
@@ -3,14 +3,13 @@ use std::{
   hash::Hash,
 };
 
-use inflections::Inflect;
-
 use crate::{
   generator::{
     ast::{EnumVariantToken, VariantDef},
     naming::{
+      casing::to_snake_case,
       constants::VARIANT_KIND_SUFFIX,
-      identifiers::{split_pascal_case, to_rust_type_name},
+      identifiers::{split_pascal_case, to_rust_variant_name},
     },
   },
   utils::SchemaRefName,
@@ -182,7 +181,7 @@ impl TryFrom<&serde_json::Value> for NormalizedVariant {
   fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
     match value {
       serde_json::Value::String(str_val) => Ok(NormalizedVariant {
-        name: to_rust_type_name(str_val),
+        name: to_rust_variant_name(str_val),
         rename_value: str_val.clone(),
       }),
       serde_json::Value::Number(num) => {
@@ -378,7 +377,7 @@ where
 
       let short_name = if parts.is_empty() {
         // Fallback: If all words were filtered out, use the full name as the "short" name.
-        variant.to_snake_case()
+        to_snake_case(variant)
       } else {
         parts.join("_")
       };
@@ -397,7 +396,7 @@ where
     .map(|ctx| {
       // If the short name appears more than once, fall back to the full original name.
       if short_counts[&ctx.short] > 1 {
-        ctx.original.to_snake_case()
+        to_snake_case(&ctx.original)
       } else {
         ctx.short.clone()
       }
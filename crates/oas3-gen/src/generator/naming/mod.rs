@@ -1,4 +1,6 @@
+pub mod casing;
 pub mod constants;
+pub mod hooks;
 pub mod identifiers;
 pub mod inference;
 pub mod name_index;
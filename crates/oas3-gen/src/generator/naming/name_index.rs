@@ -1,14 +1,13 @@
 use std::{collections::BTreeSet, hash::Hash};
 
 use indexmap::{IndexMap, IndexSet};
-use inflections::Inflect;
 use oas3::{Spec, spec::ObjectSchema};
 
 use super::identifiers::{FORBIDDEN_IDENTIFIERS, ensure_unique, to_rust_type_name};
 use crate::{
   generator::{
     converter::{hashing::CanonicalSchema, union_types::variants_to_cache_key},
-    naming::constants::KNOWN_ENUM_VARIANT,
+    naming::{casing::to_pascal_case, constants::KNOWN_ENUM_VARIANT},
   },
   utils::{SchemaExt, SchemaInspect, SchemaMap},
 };
@@ -132,7 +131,7 @@ impl<'a> TypeNameIndex<'a> {
         continue;
       };
 
-      let next_parent = format!("{parent_name}{}", prop_name.to_pascal_case());
+      let next_parent = format!("{parent_name}{}", to_pascal_case(prop_name));
 
       if prop_schema.requires_type_definition() {
         let canonical = CanonicalSchema::from_schema(prop_schema)?;
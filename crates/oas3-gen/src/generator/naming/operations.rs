@@ -1,6 +1,7 @@
 use crate::generator::naming::{
   constants::{REQUEST_PARAMS_SUFFIX, REQUEST_SUFFIX, RESPONSE_ENUM_SUFFIX, RESPONSE_SUFFIX},
-  identifiers::{split_snake_case, to_rust_field_name, to_rust_type_name},
+  hooks::{NameRequest, hook_name},
+  identifiers::{builtin_field_name, split_snake_case, to_rust_type_name},
   inference::{all_non_empty_and_unique, common_prefix_len, common_suffix_len, extract_middle_segments},
 };
 
@@ -89,11 +90,19 @@ where
   }
 }
 
+/// Names an operation after its `operationId`, or its method and path without one.
+///
+/// The installed naming hook is asked with the method and path; the field name rules
+/// apply when it keeps the built-in name.
 pub fn compute_stable_id<S>(method: S, path: S, operation_id: Option<S>) -> String
 where
   S: AsRef<str>,
 {
-  to_rust_field_name(&operation_id.map_or_else(|| generate_operation_id(method, path), |s| s.as_ref().to_string()))
+  let name = operation_id.map_or_else(
+    || generate_operation_id(method.as_ref(), path.as_ref()),
+    |s| s.as_ref().to_string(),
+  );
+  hook_name(&NameRequest::operation(&name, method.as_ref(), path.as_ref())).unwrap_or_else(|| builtin_field_name(&name))
 }
 
 pub(crate) fn generate_operation_id<S>(method: S, path: S) -> String
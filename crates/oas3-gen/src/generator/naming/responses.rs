@@ -65,6 +65,11 @@ fn find_primary_response(spec: &Spec, operation: &Operation) -> Option<Response>
     .and_then(|(_, resp_ref)| resp_ref.resolve(spec).ok())
 }
 
+/// Returns `true` when `response` declares the header `name`, compared ignoring case.
+pub fn declares_header(response: &Response, name: &str) -> bool {
+  response.headers.keys().any(|header| header.eq_ignore_ascii_case(name))
+}
+
 pub fn is_success_code(code: &str) -> bool {
   code.starts_with(SUCCESS_RESPONSE_PREFIX)
 }
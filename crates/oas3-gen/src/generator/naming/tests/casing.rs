@@ -0,0 +1,72 @@
+use crate::generator::naming::casing::{AcronymCase, Casing};
+
+#[test]
+fn test_split_words() {
+  let casing = Casing::default();
+  let cases = [
+    ("HTTPProxyURL", vec!["HTTP", "Proxy", "URL"]),
+    ("httpProxyUrl", vec!["http", "Proxy", "Url"]),
+    ("http_proxy_url", vec!["http", "proxy", "url"]),
+    ("XMLHttpRequest", vec!["XML", "Http", "Request"]),
+    ("getHTTPResponse", vec!["get", "HTTP", "Response"]),
+    ("v2Api", vec!["v2", "Api"]),
+    ("oauth2Token", vec!["oauth2", "Token"]),
+    ("HTTP2Server", vec!["HTTP2", "Server"]),
+    ("userIDs", vec!["user", "IDs"]),
+    ("userIDsList", vec!["user", "IDs", "List"]),
+    ("odata.nextLink", vec!["odata", "next", "Link"]),
+    ("Größe", vec!["Grosse"]),
+    ("", vec![]),
+  ];
+  for (input, expected) in cases {
+    assert_eq!(casing.split_words(input), expected, "failed for input {input:?}");
+  }
+}
+
+#[test]
+fn test_casing_is_consistent_across_spellings() {
+  let spellings = ["HTTPProxyURL", "httpProxyUrl", "http_proxy_url", "http-proxy-url"];
+  for input in spellings {
+    assert_eq!(
+      Casing::default().to_snake_case(input),
+      "http_proxy_url",
+      "snake for {input:?}"
+    );
+    assert_eq!(
+      Casing::default().to_constant_case(input),
+      "HTTP_PROXY_URL",
+      "constant for {input:?}"
+    );
+    assert_eq!(
+      Casing::new(AcronymCase::Pascal, []).to_pascal_case(input),
+      "HttpProxyUrl",
+      "pascal for {input:?}"
+    );
+    assert_eq!(
+      Casing::new(AcronymCase::Upper, []).to_pascal_case(input),
+      "HTTPProxyURL",
+      "upper for {input:?}"
+    );
+  }
+}
+
+#[test]
+fn test_acronym_case_policies() {
+  let cases = [
+    (AcronymCase::Preserve, "HTTPProxyURL", "HTTPProxyURL"),
+    (AcronymCase::Preserve, "http_proxy_url", "HttpProxyUrl"),
+    (AcronymCase::Upper, "user_ids", "UserIDs"),
+    (AcronymCase::Upper, "etag_value", "EtagValue"),
+    (AcronymCase::Pascal, "userIDs", "UserIds"),
+  ];
+  for (acronym_case, input, expected) in cases {
+    assert_eq!(
+      Casing::new(acronym_case, []).to_pascal_case(input),
+      expected,
+      "{acronym_case:?} failed for input {input:?}"
+    );
+  }
+
+  let custom = Casing::new(AcronymCase::Upper, ["ETag".to_string()]);
+  assert_eq!(custom.to_pascal_case("etag_value"), "ETAGValue");
+}
@@ -0,0 +1,97 @@
+use std::{fs, os::unix::fs::PermissionsExt, path::Path, time::Duration};
+
+use crate::generator::naming::hooks::{NameKind, NameRequest, NamingHook};
+
+fn write_hook(dir: &Path, body: &str) -> std::path::PathBuf {
+  let path = dir.join("hook.sh");
+  fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+  fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+  path
+}
+
+#[test]
+fn test_naming_hook_answers_and_falls_back() {
+  let dir = tempfile::tempdir().unwrap();
+  let program = write_hook(
+    dir.path(),
+    r#"while read -r line; do
+  case "$line" in
+    *'"kind":"type","name":"Pet"'*) echo '"Animal"' ;;
+    *'"kind":"method"'*'"path":"/pets"'*) echo '"fetch_pets"' ;;
+    *) echo null ;;
+  esac
+done"#,
+  );
+  let hook = NamingHook::spawn(&program, Path::new("spec.json")).unwrap();
+
+  assert_eq!(
+    hook.name(&NameRequest::new(NameKind::Type, "Pet")),
+    Some("Animal".to_string())
+  );
+  assert_eq!(
+    hook.name(&NameRequest::new(NameKind::Type, "Animal")),
+    Some("Animal".to_string())
+  );
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Field, "Pet")), None);
+  assert_eq!(
+    hook.name(&NameRequest::operation("listPets", "GET", "/pets")),
+    Some("fetch_pets".to_string())
+  );
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Owner")), None);
+}
+
+#[test]
+fn test_naming_hook_rejects_invalid_identifiers() {
+  let dir = tempfile::tempdir().unwrap();
+  let program = write_hook(dir.path(), r#"while read -r line; do echo '"not valid"'; done"#);
+  let hook = NamingHook::spawn(&program, Path::new("spec.json")).unwrap();
+
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Pet")), None);
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Owner")), None);
+  assert_eq!(
+    hook.error().as_deref(),
+    Some("Naming hook answered 'Pet' with 'not valid', which is not a valid identifier")
+  );
+}
+
+#[test]
+fn test_naming_hook_close_reaps_the_process() {
+  let dir = tempfile::tempdir().unwrap();
+  let pid_file = dir.path().join("pid");
+  let program = write_hook(dir.path(), &format!("echo $$ > {}\nexec sleep 60", pid_file.display()));
+  let hook = NamingHook::spawn(&program, Path::new("spec.json")).unwrap();
+  while !fs::read_to_string(&pid_file).is_ok_and(|pid| pid.ends_with('\n')) {
+    std::thread::yield_now();
+  }
+  let pid = fs::read_to_string(&pid_file).unwrap().trim().to_string();
+
+  hook.close();
+
+  let alive = std::process::Command::new("kill")
+    .args(["-0", &pid])
+    .stderr(std::process::Stdio::null())
+    .status()
+    .unwrap();
+  assert!(!alive.success(), "naming hook process {pid} is still running");
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Pet")), None);
+  assert_eq!(
+    hook.error().as_deref(),
+    Some("Naming hook was asked to name 'Pet' after it was closed")
+  );
+}
+
+#[test]
+fn test_naming_hook_times_out_without_an_answer() {
+  let dir = tempfile::tempdir().unwrap();
+  let program = write_hook(dir.path(), "while read -r line; do :; done");
+  let hook = NamingHook::spawn(&program, Path::new("spec.json"))
+    .unwrap()
+    .with_timeout(Duration::from_millis(100));
+
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Pet")), None);
+  assert_eq!(
+    hook.error().as_deref(),
+    Some("Naming hook did not answer 'Pet' within 100ms")
+  );
+  assert_eq!(hook.name(&NameRequest::new(NameKind::Type, "Owner")), None);
+}
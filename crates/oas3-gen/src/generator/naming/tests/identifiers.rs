@@ -2,9 +2,66 @@ use std::collections::BTreeSet;
 
 use crate::generator::{
   ast::{RegexKey, StructToken, tokens::ConstToken},
-  naming::identifiers::{ensure_unique, split_pascal_case, strip_parent_prefix, to_rust_field_name, to_rust_type_name},
+  naming::identifiers::{
+    ensure_unique, split_pascal_case, strip_parent_prefix, to_rust_const_name, to_rust_field_name, to_rust_type_name,
+    to_rust_variant_name, to_spelled_variant_name,
+  },
 };
 
+const HOSTILE_NAMES: &[&str] = &[
+  "",
+  "_",
+  "__proto__",
+  "-",
+  "--",
+  "r#",
+  "r#_",
+  "r#123",
+  "r#self",
+  "r#crate",
+  "r#Self",
+  "r#type",
+  "type",
+  "Type",
+  "self",
+  "Self",
+  "SELF",
+  "super",
+  "crate",
+  "Crate",
+  "async",
+  "gen",
+  "try",
+  "dyn",
+  "union",
+  "123",
+  "1st",
+  "-1",
+  "9lives",
+  "🚀",
+  "🎉 party",
+  "Foo🚀Bar",
+  "naïve",
+  "Größe",
+  "日本語",
+  "Ωmega",
+  "\u{200b}",
+  "$ref",
+  "@type",
+  "odata.nextLink",
+  "x-api-key",
+  "a.b.c",
+  "Content-Type",
+  "foo bar",
+  "snake_case",
+  "PascalCase",
+  "camelCase",
+  "SCREAMING_CASE",
+  "Vec",
+  "Option",
+  "clone",
+];
+
 #[test]
 fn test_field_names() {
   let cases = [
@@ -15,19 +72,60 @@ fn test_field_names() {
     ("type", "r#type"),
     ("self", "self_"),
     ("123name", "_123name"),
-    ("", "_"),
-    ("  ", "_"),
+    ("", "unnamed"),
+    ("  ", "unnamed"),
+    ("_", "unnamed"),
+    // Keywords that cannot be raw identifiers get a suffix
+    ("Self", "self_"),
+    ("super", "super_"),
+    ("crate", "crate_"),
+    // Raw identifier prefixes are stripped and the rest re-evaluated
+    ("r#type", "r#type"),
+    ("r#self", "self_"),
+    ("r#123", "_123"),
+    ("r#_", "unnamed"),
+    // Non-ASCII input is transliterated
+    ("naïve", "naive"),
+    ("Größe", "grosse"),
+    ("🚀 launch", "rocket_launch"),
+    ("1st-place", "_1st_place"),
+    // Acronyms and digits split the same way as in type names
+    ("HTTPProxyURL", "http_proxy_url"),
+    ("userIDs", "user_ids"),
+    ("v2GetUsers", "v2_get_users"),
     // Negative prefix handling
     ("-created-date", "negative_created_date"),
     ("-id", "negative_id"),
     ("-modified-date", "negative_modified_date"),
-    ("-", "_"),
+    ("-", "unnamed"),
   ];
   for (input, expected) in cases {
     assert_eq!(to_rust_field_name(input), expected, "failed for input {input:?}");
   }
 }
 
+#[test]
+fn test_identifiers_are_valid_and_stable() {
+  let converters = [
+    ("field", to_rust_field_name as fn(&str) -> String),
+    ("type", to_rust_type_name),
+    ("const", to_rust_const_name),
+  ];
+  for (kind, convert) in converters {
+    for &input in HOSTILE_NAMES {
+      let ident = convert(input);
+      assert!(
+        syn::parse_str::<syn::Ident>(&ident).is_ok(),
+        "{kind} name {ident:?} from {input:?} is not a valid identifier"
+      );
+      // All-uppercase type names such as `ABC` are normalized to `Abc` on conversion.
+      if kind != "type" || ident.chars().any(|c| c.is_ascii_lowercase()) {
+        assert_eq!(convert(&ident), ident, "{kind} name from {input:?} does not round-trip");
+      }
+    }
+  }
+}
+
 #[test]
 fn test_type_names() {
   let cases = [
@@ -74,8 +172,15 @@ fn test_type_names() {
     ("Send", "SendType"),
     ("Sync", "SyncType"),
     ("Type", "TypeType"),
-    // Self is a keyword, so it gets raw identifier prefix
-    ("Self", "r#Self"),
+    // Self cannot be a raw identifier, so it gets the Type suffix
+    ("Self", "SelfType"),
+    ("self", "SelfType"),
+    ("r#Self", "SelfType"),
+    // Non-ASCII input is transliterated, capitalizing words after dropped characters
+    ("naïveValue", "NaiveValue"),
+    ("Größe", "Grosse"),
+    ("Foo🚀Bar", "FooRocketBar"),
+    ("🎉", "Tada"),
     // Raw identifier prefixes should be stripped and PascalCased
     ("r#move", "Move"),
     ("r#static", "Static"),
@@ -92,6 +197,39 @@ fn test_type_names() {
   }
 }
 
+#[test]
+fn test_variant_names() {
+  let cases = [
+    // Numbers with a unit lead with the unit
+    ("1080p", "P1080"),
+    ("4k", "K4"),
+    ("2XL", "Xl2"),
+    // Other numeric-leading values keep the type name prefix
+    ("2024", "T2024"),
+    ("3d-model", "T3dModel"),
+    ("-1", "Negative1"),
+    // Leading and symbol-only values spell their symbols
+    ("$lte", "DollarLte"),
+    ("@type", "AtType"),
+    ("+1", "Plus1"),
+    ("*", "Asterisk"),
+    ("<=", "LtEq"),
+    ("!=", "NotEq"),
+    // Interior symbols are dropped
+    ("A/B", "AB"),
+    ("text/plain", "TextPlain"),
+    ("in_progress", "InProgress"),
+  ];
+  for (input, expected) in cases {
+    assert_eq!(to_rust_variant_name(input), expected, "variant name for {input:?}");
+  }
+
+  let spelled = [("A/B", "ASlashB"), ("C++", "CPlusPlus"), ("a&b", "AAndB")];
+  for (input, expected) in spelled {
+    assert_eq!(to_spelled_variant_name(input), expected, "spelled name for {input:?}");
+  }
+}
+
 #[test]
 fn test_const_token_from_regex_key() {
   let cases = [
@@ -443,6 +443,56 @@ fn test_infer_variant_name_object_variants() {
   );
 }
 
+#[test]
+fn test_infer_union_variant_label_prefers_names_over_placeholders() {
+  let object = |extra: Value| {
+    let mut schema = json!({
+      "type": "object",
+      "properties": { "id": { "type": "string" }, "text": { "type": "string" } }
+    });
+    schema
+      .as_object_mut()
+      .unwrap()
+      .extend(extra.as_object().unwrap().clone());
+    serde_json::from_value::<ObjectSchema>(schema).unwrap()
+  };
+
+  let cases = [
+    (object(json!({})), "Object"),
+    (
+      object(json!({ "title": "Text Block", "x-name": "Snippet" })),
+      "TextBlock",
+    ),
+    (object(json!({ "x-name": "snippet" })), "Snippet"),
+    (
+      object(json!({ "description": "A text block shown inline. Supports markdown." })),
+      "TextBlockShown",
+    ),
+    (
+      object(json!({ "description": "Citation: a quoted source" })),
+      "Citation",
+    ),
+    (object(json!({ "description": "2 or more items" })), "Object"),
+    (
+      serde_json::from_value(json!({ "type": "object", "properties": { "id": { "type": "string" } }, "required": ["id"], "description": "An identifier" })).unwrap(),
+      "Id",
+    ),
+    (
+      serde_json::from_value(json!({ "description": "The fallback value" })).unwrap(),
+      "FallbackValue",
+    ),
+    (ObjectSchema::default(), "Variant3"),
+  ];
+  for (schema, expected) in cases {
+    assert_eq!(
+      schema.infer_union_variant_label(None, 3),
+      expected,
+      "{:?}",
+      schema.description
+    );
+  }
+}
+
 fn make_variant(name: &str) -> VariantDef {
   VariantDef::builder()
     .name(EnumVariantToken::from(name))
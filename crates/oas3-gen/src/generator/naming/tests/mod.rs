@@ -1,3 +1,5 @@
+mod casing;
+mod hooks;
 mod identifiers;
 mod inference;
 mod operations;
@@ -1,4 +1,7 @@
-use std::{collections::HashSet, rc::Rc};
+use std::{
+  collections::{HashMap, HashSet},
+  rc::Rc,
+};
 
 use http::Method;
 use indexmap::IndexMap;
@@ -7,7 +10,7 @@ use oas3::{Spec, spec::Operation};
 use crate::generator::{
   ast::OperationKind,
   naming::{
-    identifiers::ensure_unique_snake_case_id,
+    identifiers::{ensure_unique_snake_case_id, to_rust_field_name},
     operations::{compute_stable_id, trim_common_affixes},
   },
 };
@@ -26,19 +29,23 @@ pub struct OperationEntry {
   pub path: String,
   /// The original OpenAPI operation definition.
   pub operation: Rc<Operation>,
-  /// Whether this is a standard HTTP operation or a webhook.
+  /// Whether this is a standard HTTP operation, a webhook, or a callback.
   pub kind: OperationKind,
+  /// For callbacks, the stable ID of the operation declaring them.
+  pub callback_of: Option<String>,
 }
 
 /// Filter for including or excluding operations from code generation.
 ///
-/// Allows selective generation of specific operations by their identifiers.
-/// Both inclusion and exclusion filters can be combined; exclusion takes
-/// precedence if an operation matches both.
+/// Allows selective generation of specific operations by their identifiers,
+/// tags, or paths. All inclusion filters must accept an operation for it to be
+/// generated, and exclusion takes precedence if an operation matches both.
 #[derive(Debug, Clone, Default)]
 pub struct OperationFilter {
   only: Option<HashSet<String>>,
   excluded: Option<HashSet<String>>,
+  tags: Option<HashSet<String>>,
+  paths: Option<Vec<String>>,
 }
 
 impl OperationFilter {
@@ -48,15 +55,39 @@ impl OperationFilter {
     Self {
       only: only.cloned(),
       excluded: excluded.cloned(),
+      ..Self::default()
     }
   }
 
-  /// Returns whether the given operation ID passes this filter.
+  /// Restricts the filter to operations carrying at least one of the given tags.
+  #[must_use]
+  pub fn with_tags(mut self, tags: Option<&HashSet<String>>) -> Self {
+    self.tags = tags.cloned();
+    self
+  }
+
+  /// Restricts the filter to operations under one of the given path prefixes.
   ///
-  /// An ID passes if it is either in the inclusion set (or there is no
-  /// inclusion set) AND not in the exclusion set.
+  /// A prefix matches whole segments, so `/users` covers `/users` and
+  /// `/users/{id}` but not `/usersettings`.
   #[must_use]
-  pub fn accepts<S>(&self, base_id: S) -> bool
+  pub fn with_paths(mut self, paths: Option<&[String]>) -> Self {
+    self.paths = paths.map(|paths| {
+      paths
+        .iter()
+        .map(|path| path.trim_end_matches('/').to_string())
+        .collect()
+    });
+    self
+  }
+
+  /// Returns whether the given operation passes this filter.
+  ///
+  /// An operation passes if its ID is in the inclusion set (or there is no
+  /// inclusion set), it carries an included tag and lives under an included
+  /// path (when those are set), AND its ID is not in the exclusion set.
+  #[must_use]
+  pub fn accepts<S>(&self, base_id: S, path: &str, operation: &Operation) -> bool
   where
     S: AsRef<str>,
   {
@@ -66,14 +97,38 @@ impl OperationFilter {
       return false;
     }
 
-    if let Some(ref excluded) = self.excluded
-      && excluded.contains(base_id.as_ref())
+    if let Some(ref tags) = self.tags
+      && !operation.tags.iter().any(|tag| tags.contains(tag))
+    {
+      return false;
+    }
+
+    if let Some(ref paths) = self.paths
+      && !paths.iter().any(|prefix| path_has_prefix(path, prefix))
     {
       return false;
     }
 
-    true
+    !self.excludes(base_id)
   }
+
+  /// Returns whether the given operation ID is in the exclusion set.
+  #[must_use]
+  pub fn excludes<S>(&self, base_id: S) -> bool
+  where
+    S: AsRef<str>,
+  {
+    self
+      .excluded
+      .as_ref()
+      .is_some_and(|excluded| excluded.contains(base_id.as_ref()))
+  }
+}
+
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+  path
+    .strip_prefix(prefix)
+    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
 }
 
 /// Internal context for collecting operations during the build process.
@@ -100,9 +155,15 @@ impl RegistrationContext {
   ///
   /// This improves the ergonomics of generated method names by trimming
   /// redundant affixes (e.g., converting `petstore_get_pet` to `get_pet`).
+  /// Callbacks keep pointing at their declaring operation.
   fn simplify_keys(&mut self) {
     let original_keys = self.entries.keys().cloned().collect::<Vec<_>>();
     let simplified_keys = trim_common_affixes(&original_keys);
+    let renames = original_keys
+      .iter()
+      .cloned()
+      .zip(simplified_keys.iter().cloned())
+      .collect::<HashMap<_, _>>();
 
     let remapped = original_keys
       .into_iter()
@@ -110,6 +171,11 @@ impl RegistrationContext {
       .filter_map(|(old, new)| {
         self.entries.shift_remove(&old).map(|mut entry| {
           entry.stable_id.clone_from(&new);
+          if let Some(parent) = entry.callback_of.as_mut()
+            && let Some(renamed) = renames.get(parent)
+          {
+            parent.clone_from(renamed);
+          }
           (new, entry)
         })
       })
@@ -150,7 +216,7 @@ impl OperationSource for HttpOperationSource {
     for (path, method, operation) in self.spec.operations() {
       let base_id = compute_stable_id(method.as_str(), &path, operation.operation_id.as_deref());
 
-      if !filter.accepts(&base_id) {
+      if !filter.accepts(&base_id, &path, operation) {
         continue;
       }
 
@@ -162,6 +228,7 @@ impl OperationSource for HttpOperationSource {
         path,
         operation: Rc::new(operation.clone()),
         kind: OperationKind::Http,
+        callback_of: None,
       });
     }
   }
@@ -188,7 +255,7 @@ impl OperationSource for WebhookOperationSource {
         let display_path = format!("webhooks/{name}");
         let base_id = compute_stable_id(method.as_str(), &display_path, operation.operation_id.as_deref());
 
-        if !filter.accepts(&base_id) {
+        if !filter.accepts(&base_id, &display_path, operation) {
           continue;
         }
 
@@ -200,12 +267,71 @@ impl OperationSource for WebhookOperationSource {
           path: display_path,
           operation: Rc::new(operation.clone()),
           kind: OperationKind::Webhook,
+          callback_of: None,
         });
       }
     }
   }
 }
 
+/// Source that extracts the callbacks declared by already registered HTTP operations.
+///
+/// Callbacks are named after their declaring operation and callback name unless
+/// they carry their own `operationId`. They follow their declaring operation
+/// through the inclusion filters, but can still be excluded by name.
+struct CallbackOperationSource {
+  spec: Rc<Spec>,
+}
+
+impl CallbackOperationSource {
+  /// Creates a new callback operation source from the given specification.
+  fn new(spec: &Spec) -> Self {
+    Self {
+      spec: Rc::new(spec.clone()),
+    }
+  }
+}
+
+impl OperationSource for CallbackOperationSource {
+  fn ingest(&self, context: &mut RegistrationContext, filter: &OperationFilter) {
+    let parents = context
+      .entries
+      .values()
+      .filter(|entry| entry.kind == OperationKind::Http && !entry.operation.callbacks.is_empty())
+      .map(|entry| (entry.stable_id.clone(), entry.operation.clone()))
+      .collect::<Vec<_>>();
+
+    for (parent_id, parent) in parents {
+      for (name, callback) in parent.callbacks(&self.spec) {
+        for path_item in callback.paths.values() {
+          let path = format!("callbacks/{name}");
+          for (method, operation) in path_item.methods() {
+            let base_id = match operation.operation_id.as_deref() {
+              Some(operation_id) => compute_stable_id(method.as_str(), &path, Some(operation_id)),
+              None => to_rust_field_name(&format!("{parent_id}_{name}")),
+            };
+
+            if filter.excludes(&base_id) {
+              continue;
+            }
+
+            let stable_id = ensure_unique_snake_case_id(&base_id, |id| context.contains_id(id));
+
+            context.register(OperationEntry {
+              stable_id,
+              method: method.clone(),
+              path: path.clone(),
+              operation: Rc::new(operation.clone()),
+              kind: OperationKind::Callback,
+              callback_of: Some(parent_id.clone()),
+            });
+          }
+        }
+      }
+    }
+  }
+}
+
 /// Builder for constructing an [`OperationRegistry`] from multiple sources.
 #[derive(Default)]
 struct OperationRegistryBuilder {
@@ -276,18 +402,25 @@ impl OperationRegistry {
     only_operations: Option<&HashSet<String>>,
     excluded_operations: Option<&HashSet<String>>,
   ) -> Self {
+    Self::with_operation_filter(spec, OperationFilter::new(only_operations, excluded_operations))
+  }
+
+  /// Creates a registry keeping only the operations accepted by `filter`.
+  #[must_use]
+  pub fn with_operation_filter(spec: &Spec, filter: OperationFilter) -> Self {
     OperationRegistryBuilder::new()
-      .with_filter(OperationFilter::new(only_operations, excluded_operations))
+      .with_filter(filter)
       .with_source(HttpOperationSource::new(spec))
       .with_source(WebhookOperationSource::new(spec))
+      .with_source(CallbackOperationSource::new(spec))
       .build()
   }
 
   /// Returns an iterator over all registered operations.
   ///
   /// Operations are yielded in the order they were registered (HTTP
-  /// operations first, then webhooks), with original specification order
-  /// preserved within each category.
+  /// operations first, then webhooks, then callbacks), with original
+  /// specification order preserved within each category.
   pub fn operations(&self) -> impl Iterator<Item = &OperationEntry> {
     self.entries.iter()
   }
@@ -1,36 +1,56 @@
-use std::{collections::HashSet, rc::Rc, sync::Arc};
+use std::{
+  collections::{BTreeMap, HashSet},
+  rc::Rc,
+  sync::Arc,
+};
 
+use json_canon::to_string as to_canonical_json;
 use oas3::Spec;
 
 use crate::generator::{
-  ast::{ClientRootNode, OperationInfo, RustType, constants::HttpHeaderRef},
-  codegen::{GeneratedResult, SchemaCodeGenerator, Visibility},
+  ast::{ClientRootNode, EnumToken, FieldAccess, OperationInfo, OperationKind, RustType, constants::HttpHeaderRef},
+  codegen::{GeneratedResult, SchemaCodeGenerator, Visibility, formatting::SourceFormatter, incremental::ModuleCache},
   converter::{
     CodegenConfig, ConverterContext, GenerationTarget, OperationsProcessor, SchemaConverter, SerdeUsageRecorder,
-    build_server_trait, cache::SharedSchemaCache,
+    build_callback_traits, build_server_trait, build_webhook_trait, cache::SharedSchemaCache, hashing::content_hash,
   },
+  dependency_graph::{DependencyGraph, GraphNode},
+  inventory::{SchemaKind, SchemaSummary, SchemaUsage, SpecStatistics, count_unions, nesting_depth},
   metrics::GenerationStats,
-  mode::GenerationMode,
-  operation_registry::OperationRegistry,
-  postprocess::PostprocessOutput,
+  mode::{EmitTargets, GenerationMode},
+  naming::identifiers::to_rust_type_name,
+  operation_registry::{OperationFilter, OperationRegistry},
+  origins::{ItemOrigin, ItemOrigins},
+  postprocess::{
+    PostprocessOutput, RustTypeDeduplication, add_ordering_derives, mark_error_schemas, propagate_usage,
+    skip_oversized_clones,
+  },
   schema_registry::SchemaRegistry,
 };
 
 const OAS3_GEN_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Path item keys holding operations.
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
 #[derive(Debug)]
 pub struct Orchestrator {
   spec: Spec,
   visibility: Visibility,
   config: CodegenConfig,
   operation_registry: OperationRegistry,
+  module_cache: Option<Rc<ModuleCache>>,
+  formatter: Rc<SourceFormatter>,
 }
 
 struct GenerationArtifacts {
+  schema_graph: Arc<SchemaRegistry>,
   rust_types: Vec<RustType>,
   operations_info: Vec<OperationInfo>,
   serde_recorder: SerdeUsageRecorder,
   unique_headers: Vec<HttpHeaderRef>,
+  /// Example of each component schema that has one, keyed by Rust type name.
+  schema_examples: BTreeMap<String, serde_json::Value>,
   stats: GenerationStats,
   config: CodegenConfig,
 }
@@ -39,11 +59,13 @@ struct GenerationArtifacts {
 pub struct GeneratedFinalOutput {
   pub code: GeneratedResult,
   pub stats: GenerationStats,
+  /// Schema or operation each generated item came from, for reporting build errors
+  pub origins: ItemOrigins,
 }
 
 impl GeneratedFinalOutput {
-  pub fn new(code: GeneratedResult, stats: GenerationStats) -> Self {
-    Self { code, stats }
+  pub fn new(code: GeneratedResult, stats: GenerationStats, origins: ItemOrigins) -> Self {
+    Self { code, stats, origins }
   }
 }
 
@@ -56,47 +78,402 @@ impl Orchestrator {
     only_operations: Option<&HashSet<String>>,
     excluded_operations: Option<&HashSet<String>>,
   ) -> Self {
-    let operation_registry = OperationRegistry::with_filters(&spec, only_operations, excluded_operations);
+    Self::with_operation_filter(
+      spec,
+      visibility,
+      config,
+      OperationFilter::new(only_operations, excluded_operations),
+    )
+  }
+
+  /// Creates an orchestrator that generates only the operations accepted by `filter`,
+  /// along with the schemas they reach.
+  #[must_use]
+  pub fn with_operation_filter(
+    spec: Spec,
+    visibility: Visibility,
+    config: CodegenConfig,
+    filter: OperationFilter,
+  ) -> Self {
+    let operation_registry = config.scope(|| OperationRegistry::with_operation_filter(&spec, filter));
+    let formatter = Rc::new(SourceFormatter::new(config.output_format));
     Self {
       spec,
       visibility,
       config,
       operation_registry,
+      module_cache: None,
+      formatter,
+    }
+  }
+
+  /// Records [`Self::spec_hash`] in the header of every generated file.
+  #[must_use]
+  pub fn with_spec_hash(mut self, settings: &str) -> Self {
+    self.config.lints.spec_hash = Some(self.spec_hash(settings));
+    self
+  }
+
+  /// Hashes the spec as it is generated from, with the operations the filter left out
+  /// removed, together with `settings` describing everything else that shapes the output.
+  ///
+  /// Key order and formatting of the spec do not change the hash.
+  #[must_use]
+  pub fn spec_hash(&self, settings: &str) -> String {
+    let selected = self
+      .operation_registry
+      .operations()
+      .filter(|entry| entry.kind != OperationKind::Callback)
+      .map(|entry| (entry.path.as_str(), entry.method.as_str().to_ascii_lowercase()))
+      .collect::<HashSet<_>>();
+    let mut spec = serde_json::to_value(&self.spec).unwrap_or_default();
+    for (section, prefix) in [("paths", ""), ("webhooks", "webhooks/")] {
+      let Some(items) = spec.get_mut(section).and_then(serde_json::Value::as_object_mut) else {
+        continue;
+      };
+      items.retain(|name, item| {
+        let path = format!("{prefix}{name}");
+        let Some(item) = item.as_object_mut() else {
+          return false;
+        };
+        item.retain(|key, _| !HTTP_METHODS.contains(&key.as_str()) || selected.contains(&(path.as_str(), key.clone())));
+        item.keys().any(|key| HTTP_METHODS.contains(&key.as_str()))
+      });
     }
+    let effective = serde_json::json!({ "spec": spec, "settings": settings, "version": OAS3_GEN_VERSION });
+    content_hash(&to_canonical_json(&effective).unwrap_or_default())
+  }
+
+  /// Reuses the formatted sources in `cache` for modules whose inputs are unchanged.
+  ///
+  /// Only the `types` module and the tag modules split from it are cached; the number
+  /// reused is reported as skipped modules in the generation stats.
+  #[must_use]
+  pub fn with_module_cache(mut self, cache: ModuleCache) -> Self {
+    self.module_cache = Some(Rc::new(cache));
+    self
   }
 
   pub fn generate(&self, mode: &dyn GenerationMode, source_path: &str) -> anyhow::Result<GeneratedFinalOutput> {
-    let artifacts = self.collect_generation_artifacts();
-    let serde_usage = artifacts.serde_recorder.into_usage_map();
-    let postprocessed = PostprocessOutput::new(
-      artifacts.rust_types,
-      artifacts.operations_info,
-      serde_usage,
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      let codegen = if mode.round_trips_types() {
+        self.round_trip_code_generator(&artifacts, source_path)
+      } else {
+        self.code_generator(&artifacts, artifacts.config.target, source_path)
+      };
+      let code = mode.generate(&codegen)?;
+      let origins = self.item_origins(&artifacts);
+      Ok(GeneratedFinalOutput::new(
+        code,
+        self.final_stats(artifacts.stats),
+        origins,
+      ))
+    })
+  }
+
+  /// Generates every output in `targets` from a single conversion of the spec.
+  ///
+  /// Types, client, and server output are laid out as one module tree. When both a
+  /// client and a server are requested each gets its own submodule, because the two
+  /// targets derive different serde traits and response handling for the same types.
+  pub fn generate_emit(&self, targets: EmitTargets, source_path: &str) -> anyhow::Result<GeneratedFinalOutput> {
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      let generator = |target| self.code_generator(&artifacts, target, source_path);
+
+      let code = match (targets.client, targets.server) {
+        (false, false) => generator(GenerationTarget::Client).generate_types_mod()?,
+        (true, false) => generator(GenerationTarget::Client).generate_client_mod()?,
+        (false, true) => generator(GenerationTarget::Server).generate_server_mod()?,
+        (true, true) => {
+          let client = generator(GenerationTarget::Client);
+          let server = generator(GenerationTarget::Server);
+          client.generate_nested_mod([
+            ("client", client.generate_client_mod()?),
+            ("server", server.generate_server_mod()?),
+          ])?
+        }
+      };
+      let origins = self.item_origins(&artifacts);
+      Ok(GeneratedFinalOutput::new(
+        code,
+        self.final_stats(artifacts.stats),
+        origins,
+      ))
+    })
+  }
+
+  /// Generates the example payloads of the spec as fixture files, keyed by path, plus a
+  /// tests file deserializing each into the generated type imported from `types_path`.
+  pub fn generate_fixtures(
+    &self,
+    types_path: &syn::Path,
+    source_path: &str,
+  ) -> anyhow::Result<BTreeMap<String, String>> {
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      self
+        .code_generator(&artifacts, artifacts.config.target, source_path)
+        .generate_fixtures(types_path)
+    })
+  }
+
+  /// Generates a criterion benchmark target for the spec's largest schema structs and
+  /// discriminated enums, keyed by file name, importing the generated types from `types_path`.
+  pub fn generate_benches(
+    &self,
+    types_path: &syn::Path,
+    source_path: &str,
+  ) -> anyhow::Result<BTreeMap<String, String>> {
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      self
+        .code_generator(&artifacts, artifacts.config.target, source_path)
+        .generate_benches(types_path)
+    })
+  }
+
+  /// Summarizes the component schemas: the Rust type each becomes, whether it is
+  /// part of a reference cycle, and whether operations send it, receive it, or both.
+  #[must_use]
+  pub fn schema_summaries(&self) -> Vec<SchemaSummary> {
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      let usage = propagate_usage(&artifacts.rust_types, artifacts.serde_recorder.into_usage_map());
+      let cyclic = artifacts.stats.cycle_details.iter().flatten().collect::<HashSet<_>>();
+
+      self
+        .spec
+        .components
+        .iter()
+        .flat_map(|components| components.schemas.keys())
+        .map(|name| {
+          let rust_name = to_rust_type_name(name);
+          let kind = artifacts
+            .rust_types
+            .iter()
+            .find(|rust_type| *rust_type.type_name() == *rust_name)
+            .map(SchemaKind::of);
+          let (in_request, in_response) = usage
+            .get(&EnumToken::from(rust_name.as_str()))
+            .copied()
+            .unwrap_or_default();
+          SchemaSummary {
+            name: name.clone(),
+            rust_name,
+            kind,
+            cyclic: cyclic.contains(name),
+            usage: SchemaUsage::from_flags(in_request, in_response),
+          }
+        })
+        .collect()
+    })
+  }
+
+  /// Measures the spec: its schemas and operations, how deeply inline schemas nest,
+  /// how many unions and cycles it declares, and how many types it generates.
+  #[must_use]
+  pub fn spec_statistics(&self) -> SpecStatistics {
+    self.config.scope(|| {
+      let artifacts = self.collect_generation_artifacts();
+      let schemas = artifacts.schema_graph.schemas();
+      let depths = schemas.values().map(nesting_depth).sum::<usize>();
+      let (one_of_unions, any_of_unions) = schemas
+        .values()
+        .map(count_unions)
+        .fold((0, 0), |(one_of, any_of), (schema_one_of, schema_any_of)| {
+          (one_of + schema_one_of, any_of + schema_any_of)
+        });
+
+      #[allow(clippy::cast_precision_loss)]
+      let average_nesting_depth = if schemas.is_empty() {
+        0.0
+      } else {
+        depths as f64 / schemas.len() as f64
+      };
+
+      SpecStatistics {
+        schemas: schemas.len(),
+        operations: self.operation_registry.operations().count(),
+        average_nesting_depth,
+        one_of_unions,
+        any_of_unions,
+        cyclic_schemas: artifacts.schema_graph.cyclic_schema_count(),
+        projected_types: artifacts.rust_types.len(),
+      }
+    })
+  }
+
+  /// Builds the graph of references between component schemas, marking the schemas in
+  /// each reference cycle and those implementing `Error`.
+  #[must_use]
+  pub fn dependency_graph(&self) -> DependencyGraph {
+    self.config.scope(|| {
+      let mut artifacts = self.collect_generation_artifacts();
+      mark_error_schemas(&mut artifacts.rust_types);
+      let error_types = artifacts
+        .rust_types
+        .iter()
+        .filter_map(|rust_type| match rust_type {
+          RustType::Struct(def) if def.error_impl => Some(def.name.to_string()),
+          _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+      let graph = &artifacts.schema_graph;
+      let names = graph.keys();
+      let nodes = names
+        .iter()
+        .map(|name| GraphNode {
+          name: (*name).clone(),
+          cycle: artifacts
+            .stats
+            .cycle_details
+            .iter()
+            .position(|cycle| cycle.contains(name)),
+          error: error_types.contains(&to_rust_type_name(name)),
+        })
+        .collect::<Vec<_>>();
+      let edges = names
+        .iter()
+        .enumerate()
+        .flat_map(|(from, name)| {
+          graph
+            .dependencies(name)
+            .filter_map(|dep| names.iter().position(|other| *other == dep))
+            .map(move |to| (from, to))
+        })
+        .collect::<Vec<_>>();
+
+      DependencyGraph { nodes, edges }
+    })
+  }
+
+  /// Records the operation behind each request type, response enum, and method, and
+  /// the component schema behind each type named after one.
+  fn item_origins(&self, artifacts: &GenerationArtifacts) -> ItemOrigins {
+    let mut origins = ItemOrigins::default();
+    for info in &artifacts.operations_info {
+      let origin = ItemOrigin::Operation(info.operation_id.clone());
+      let items = [
+        info.request_type.as_ref().map(ToString::to_string),
+        info.response_enum.as_ref().map(ToString::to_string),
+        Some(info.stable_id.clone()),
+      ];
+      for item in items.into_iter().flatten() {
+        origins.insert(item, origin.clone());
+      }
+    }
+    for name in self
+      .spec
+      .components
+      .iter()
+      .flat_map(|components| components.schemas.keys())
+    {
+      origins.insert(to_rust_type_name(name), ItemOrigin::Schema(name.clone()));
+    }
+    origins
+  }
+
+  fn final_stats(&self, mut stats: GenerationStats) -> GenerationStats {
+    if let Some(cache) = &self.module_cache {
+      stats.record_skipped_modules(cache.take_reused());
+    }
+    stats.record_formatting_time(self.formatter.take_elapsed());
+    stats.record_warnings(self.formatter.take_failures());
+    stats
+  }
+
+  fn code_generator(
+    &self,
+    artifacts: &GenerationArtifacts,
+    target: GenerationTarget,
+    source_path: &str,
+  ) -> SchemaCodeGenerator {
+    self.code_generator_with_usage(
+      artifacts,
+      artifacts.rust_types.clone(),
+      target,
+      artifacts.serde_recorder.clone().into_usage_map(),
+      source_path,
+    )
+  }
+
+  /// Creates a code generator whose types all derive both `Serialize` and `Deserialize`.
+  ///
+  /// Fields a client would skip when sending are serialized like any other, so that
+  /// values survive the round trip.
+  fn round_trip_code_generator(&self, artifacts: &GenerationArtifacts, source_path: &str) -> SchemaCodeGenerator {
+    let mut rust_types = artifacts.rust_types.clone();
+    for field in rust_types.iter_mut().flat_map(|rust_type| match rust_type {
+      RustType::Struct(def) => def.fields.iter_mut(),
+      _ => [].iter_mut(),
+    }) {
+      if field.access == FieldAccess::ServerManaged {
+        field.access = FieldAccess::ReadWrite;
+      }
+    }
+    self.code_generator_with_usage(
+      artifacts,
+      rust_types,
       artifacts.config.target,
-      artifacts.unique_headers,
+      BTreeMap::new(),
+      source_path,
+    )
+  }
+
+  fn code_generator_with_usage(
+    &self,
+    artifacts: &GenerationArtifacts,
+    rust_types: Vec<RustType>,
+    target: GenerationTarget,
+    seed_usage: BTreeMap<EnumToken, (bool, bool)>,
+    source_path: &str,
+  ) -> SchemaCodeGenerator {
+    let postprocessed = PostprocessOutput::new(
+      rust_types,
+      artifacts.operations_info.clone(),
+      seed_usage,
+      target,
+      artifacts.unique_headers.clone(),
+      &artifacts.config.pagination_cursors(),
     );
 
-    let server_trait_def = if artifacts.config.target == GenerationTarget::Server {
-      build_server_trait(&postprocessed.operations)
-    } else {
-      None
+    let (server_trait_def, webhook_trait_def, callback_traits) = match target {
+      GenerationTarget::Server => (
+        build_server_trait(&postprocessed.operations),
+        build_webhook_trait(&postprocessed.operations),
+        vec![],
+      ),
+      GenerationTarget::Client => (None, None, build_callback_traits(&postprocessed.operations)),
     };
 
-    let codegen = SchemaCodeGenerator::builder()
-      .config(artifacts.config)
+    let config = CodegenConfig {
+      target,
+      ..artifacts.config.clone()
+    };
+
+    let tag_modules = config.split_by_tag().then(|| postprocessed.split_by_tag(target));
+
+    SchemaCodeGenerator::builder()
+      .config(config)
       .rust_types(postprocessed.types)
       .operations(postprocessed.operations)
       .header_refs(postprocessed.header_refs)
       .uses(postprocessed.uses)
       .client(ClientRootNode::from(&self.spec))
       .maybe_server_trait(server_trait_def)
+      .maybe_webhook_trait(webhook_trait_def)
+      .callback_traits(callback_traits)
+      .schema_examples(artifacts.schema_examples.clone())
+      .maybe_tag_modules(tag_modules)
+      .maybe_module_cache(self.module_cache.clone())
+      .formatter(self.formatter.clone())
       .visibility(self.visibility)
       .source_path(source_path.to_string())
       .gen_version(OAS3_GEN_VERSION.to_string())
-      .build();
-
-    let code = mode.generate(&codegen)?;
-    Ok(GeneratedFinalOutput::new(code, artifacts.stats))
+      .build()
   }
 
   fn collect_generation_artifacts(&self) -> GenerationArtifacts {
@@ -139,27 +516,85 @@ impl Orchestrator {
     rust_types.extend(operation_results.types);
     rust_types.extend(context.cache.borrow_mut().take_types());
 
-    stats.record_orphaned_schemas(if let Some(ref schemas) = filtered_schemas {
-      let total = schema_graph.keys().len();
-      total.saturating_sub(schemas.len())
-    } else {
-      0
-    });
+    let mut operations = operation_results.operations;
+    let mut usage_recorder = operation_results.usage_recorder;
+    if self.config.merge_identical_types() {
+      let components = schema_graph
+        .keys()
+        .into_iter()
+        .map(|name| to_rust_type_name(name))
+        .collect();
+      let (merged_types, merged) = RustTypeDeduplication::new(rust_types).merge_identical(&mut operations, &components);
+      rust_types = merged_types;
+      usage_recorder.rename_types(&merged);
+      stats.record_merged_types(merged.into_keys());
+    }
+
+    if let Some(max_fields) = self.config.max_clone_fields() {
+      stats.record_clone_skipped(skip_oversized_clones(&mut rust_types, max_fields));
+    }
+
+    if self.config.ordering_derives() {
+      add_ordering_derives(&mut rust_types);
+    }
+
+    if let Some(ref schemas) = filtered_schemas {
+      stats.record_orphaned_schemas(
+        schema_graph
+          .keys()
+          .into_iter()
+          .filter(|name| !schemas.contains(name.as_str()))
+          .cloned(),
+      );
+    }
+
+    let schema_examples = schema_examples(&schema_graph, &rust_types, &mut stats);
 
     stats.record_warnings(operation_results.warnings);
+    stats.record_warnings(context.take_warnings());
     stats.record_rust_types(&rust_types);
-    stats.record_operations(&operation_results.operations);
+    stats.record_operations(&operations);
     stats.record_cycles(cycle_info);
-    stats.record_client_methods(operation_results.operations.len());
+    stats.record_client_methods(operations.len());
     stats.record_client_headers(operation_results.unique_headers.len());
 
     GenerationArtifacts {
+      schema_graph,
       rust_types,
-      operations_info: operation_results.operations,
-      serde_recorder: operation_results.usage_recorder,
+      operations_info: operations,
+      serde_recorder: usage_recorder,
       unique_headers: operation_results.unique_headers.into_iter().collect::<Vec<_>>(),
+      schema_examples,
       stats,
       config: context.config.clone(),
     }
   }
 }
+
+/// Collects the `example` of every component schema converted to a type, or its first
+/// `examples` entry, keyed by Rust type name. Schemas without one are recorded in `stats`.
+fn schema_examples(
+  schema_graph: &SchemaRegistry,
+  rust_types: &[RustType],
+  stats: &mut GenerationStats,
+) -> BTreeMap<String, serde_json::Value> {
+  let type_names = rust_types
+    .iter()
+    .map(|rust_type| rust_type.type_name().to_string())
+    .collect::<HashSet<_>>();
+  let mut examples = BTreeMap::new();
+  for (name, schema) in schema_graph.schemas() {
+    let rust_name = to_rust_type_name(name);
+    if !type_names.contains(&rust_name) {
+      continue;
+    }
+    match schema.example.clone().or_else(|| schema.examples.first().cloned()) {
+      Some(example) => {
+        stats.record_schema_example(true, name);
+        examples.insert(rust_name, example);
+      }
+      None => stats.record_schema_example(false, name),
+    }
+  }
+  examples
+}
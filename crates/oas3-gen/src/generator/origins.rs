@@ -0,0 +1,46 @@
+use std::{collections::BTreeMap, fmt};
+
+/// Where a generated Rust item came from in the OpenAPI spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemOrigin {
+  Schema(String),
+  Operation(String),
+}
+
+impl fmt::Display for ItemOrigin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Schema(name) => write!(f, "schema `{name}`"),
+      Self::Operation(id) => write!(f, "operation `{id}`"),
+    }
+  }
+}
+
+/// Maps generated item names back to the schema or operation that produced them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ItemOrigins(BTreeMap<String, ItemOrigin>);
+
+impl ItemOrigins {
+  pub fn insert(&mut self, item: impl Into<String>, origin: ItemOrigin) {
+    self.0.entry(item.into()).or_insert(origin);
+  }
+
+  /// Returns the origin of `item`, falling back to the longest known name it extends,
+  /// so inline types such as `PetStatus` resolve to the `Pet` schema.
+  #[must_use]
+  pub fn origin_of(&self, item: &str) -> Option<&ItemOrigin> {
+    self.0.get(item).or_else(|| {
+      self
+        .0
+        .iter()
+        .filter(|(name, _)| {
+          item
+            .strip_prefix(name.as_str())
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_ascii_uppercase() || c == '_')
+        })
+        .max_by_key(|(name, _)| name.len())
+        .map(|(_, origin)| origin)
+    })
+  }
+}
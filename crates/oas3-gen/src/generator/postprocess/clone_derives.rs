@@ -0,0 +1,109 @@
+use std::collections::BTreeSet;
+
+use crate::generator::{
+  ast::{DefaultAtom, RustPrimitive, RustType, TypeRef},
+  postprocess::serde_usage::SerdeUsage,
+};
+
+/// Leaves `Clone` off structs with more than `max_fields` fields, so copying one is
+/// always a visible, deliberate step.
+///
+/// A type can only derive `Clone` when everything it holds does, so every type that
+/// reaches an oversized struct loses `Clone` as well. Returns the names of all affected
+/// types in declaration order.
+pub(crate) fn skip_oversized_clones(types: &mut [RustType], max_fields: usize) -> Vec<String> {
+  let references = types
+    .iter()
+    .map(|rust_type| (rust_type.type_name(), referenced_types(rust_type)))
+    .collect::<Vec<_>>();
+
+  let mut skipped = types
+    .iter()
+    .filter(|rust_type| matches!(rust_type, RustType::Struct(def) if def.fields.len() > max_fields))
+    .map(RustType::type_name)
+    .collect::<BTreeSet<_>>();
+
+  loop {
+    let before = skipped.len();
+    for (name, refs) in &references {
+      if !skipped.contains(name) && refs.iter().any(|r| skipped.contains(r)) {
+        skipped.insert(name.clone());
+      }
+    }
+    if skipped.len() == before {
+      break;
+    }
+  }
+
+  for rust_type in types.iter_mut() {
+    if !skipped.contains(&rust_type.type_name()) {
+      continue;
+    }
+    match rust_type {
+      RustType::Struct(def) => def.skip_clone = true,
+      RustType::Enum(def) => def.skip_clone = true,
+      RustType::DiscriminatedEnum(def) => def.skip_clone = true,
+      RustType::ResponseEnum(def) => {
+        def.skip_clone = true;
+        let named_events = def
+          .variants
+          .iter_mut()
+          .flat_map(|variant| &mut variant.media_types)
+          .filter_map(|media_type| media_type.named_events.as_mut());
+        for events in named_events {
+          events.skip_clone = events
+            .events
+            .iter()
+            .flat_map(|event| custom_type_names(&event.schema_type))
+            .any(|name| skipped.contains(&name));
+        }
+      }
+      RustType::TypeAlias(_) => {}
+    }
+  }
+
+  types
+    .iter()
+    .map(RustType::type_name)
+    .filter(|name| skipped.contains(name))
+    .map(|name| name.to_string())
+    .collect()
+}
+
+/// Custom types a type holds, including the bodies of every response media type and
+/// the payloads of named events.
+fn referenced_types(rust_type: &RustType) -> Vec<DefaultAtom> {
+  let mut refs = SerdeUsage::dependencies(rust_type)
+    .flat_map(|name| type_names(name.as_str()))
+    .collect::<Vec<_>>();
+  if let RustType::ResponseEnum(def) = rust_type {
+    for media_type in def.variants.iter().flat_map(|variant| &variant.media_types) {
+      refs.extend(media_type.schema_type.iter().flat_map(custom_type_names));
+      refs.extend(
+        media_type
+          .named_events
+          .iter()
+          .flat_map(|events| &events.events)
+          .flat_map(|event| custom_type_names(&event.schema_type)),
+      );
+    }
+  }
+  refs
+}
+
+/// Type names a reference mentions, including those nested in generic maps such as
+/// `IndexMap<String, Vec<Pet>>`.
+pub(super) fn custom_type_names(type_ref: &TypeRef) -> Vec<DefaultAtom> {
+  match &type_ref.base_type {
+    RustPrimitive::Custom(name) => type_names(name),
+    _ => vec![],
+  }
+}
+
+fn type_names(rendered: &str) -> Vec<DefaultAtom> {
+  rendered
+    .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+    .filter(|name| !name.is_empty())
+    .map(DefaultAtom::from)
+    .collect()
+}
@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::generator::ast::{
+  DefaultAtom, ErrorResponseType, ErrorSourceField, FieldDef, MessageFieldAccess, OperationInfo, ResponseEnumDef,
+  ResponseVariant, RustPrimitive, RustType, SerdeAttribute, StructDef, StructKind, TypeRef,
+};
+
+/// Field names that commonly hold the human-readable message of an error body, in order
+/// of preference.
+const MESSAGE_FIELD_NAMES: &[&str] = &[
+  "message",
+  "detail",
+  "title",
+  "error_description",
+  "description",
+  "error",
+];
+
+/// Field name that commonly wraps the message in a nested error object.
+const NESTED_ERROR_FIELD: &str = "error";
+
+/// Field names that commonly hold a nested error object, in order of preference.
+const SOURCE_FIELD_NAMES: &[&str] = &["source", "cause", "inner_error", "innererror", "inner", "error"];
+
+/// Returns the schema types used as error response bodies.
+///
+/// Bodies of `4XX` and `5XX` responses are errors. The body of a `default` response is
+/// an error too when every explicit response of the operation is a `2XX` success, since
+/// the catch-all then covers only failures.
+pub(crate) fn compute_error_schemas(types: &[RustType]) -> BTreeSet<DefaultAtom> {
+  types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::ResponseEnum(def) => Some(def),
+      _ => None,
+    })
+    .flat_map(error_body_types)
+    .collect()
+}
+
+/// Flags the schema structs returned by [`compute_error_schemas`] for `Error` impls.
+///
+/// Each flagged struct also gets the path to the field its `Display` impl shows, taken
+/// from `x-error-message-field` or inferred from common field names, and the nested
+/// error object its `source` returns. Nested error objects are flagged in turn, so
+/// `error.inner` chains implement `Error` all the way down.
+pub(crate) fn mark_error_schemas(types: &mut [RustType]) {
+  let structs = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Struct(def) if def.kind == StructKind::Schema => Some((def.name.to_atom(), def)),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut pending = compute_error_schemas(types).into_iter().collect::<Vec<_>>();
+  let mut error_impls = BTreeMap::new();
+  while let Some(name) = pending.pop() {
+    if error_impls.contains_key(&name) {
+      continue;
+    }
+    let Some(def) = structs.get(&name) else {
+      continue;
+    };
+
+    let message_path = resolve_message_path(def, &structs);
+    let source = find_source_field(def, &structs).map(|(field, nested)| {
+      pending.push(nested.name.to_atom());
+      error_source(field, &message_path)
+    });
+    error_impls.insert(name, (message_path, source));
+  }
+
+  for rust_type in types {
+    if let RustType::Struct(def) = rust_type
+      && def.kind == StructKind::Schema
+      && let Some((message_path, source)) = error_impls.get(&def.name.to_atom())
+    {
+      def.error_impl = true;
+      def.error_message_path.clone_from(message_path);
+      def.error_source.clone_from(source);
+    }
+  }
+}
+
+/// Finds the first field named like [`SOURCE_FIELD_NAMES`] that holds a single nested
+/// object, returning it with the struct of that object.
+fn find_source_field<'a, 'b>(
+  def: &'a StructDef,
+  structs: &HashMap<DefaultAtom, &'b StructDef>,
+) -> Option<(&'a FieldDef, &'b StructDef)> {
+  SOURCE_FIELD_NAMES.iter().find_map(|name| {
+    let field = def.user_fields().find(|field| field.name == *name)?;
+    Some((field, nested_struct(field, structs)?))
+  })
+}
+
+/// Describes how `source` reaches `field`.
+///
+/// When the message is read from inside the field, the field and its parent describe
+/// the same failure, so `source` forwards to the field's own source instead of
+/// returning the field.
+fn error_source(field: &FieldDef, message_path: &[MessageFieldAccess]) -> ErrorSourceField {
+  ErrorSourceField {
+    name: field.name.clone(),
+    optional: field.rust_type.nullable,
+    boxed: field.rust_type.boxed,
+    forwarded: message_path.len() > 1 && message_path[0].name == field.name,
+  }
+}
+
+/// Finds the field accesses leading to the message of an error body.
+///
+/// A path declared via `x-error-message-field` wins when it resolves to a displayable
+/// field. Otherwise the first string field named like [`MESSAGE_FIELD_NAMES`] is used,
+/// looking inside a nested `error` object when the body has none. Returns an empty path
+/// when nothing matches.
+fn resolve_message_path(def: &StructDef, structs: &HashMap<DefaultAtom, &StructDef>) -> Vec<MessageFieldAccess> {
+  def
+    .error_message_field
+    .as_deref()
+    .and_then(|field_path| declared_message_path(def, field_path, structs))
+    .or_else(|| inferred_message_path(def, structs))
+    .unwrap_or_default()
+}
+
+fn declared_message_path(
+  def: &StructDef,
+  field_path: &str,
+  structs: &HashMap<DefaultAtom, &StructDef>,
+) -> Option<Vec<MessageFieldAccess>> {
+  let mut current = def;
+  let mut path = vec![];
+  let mut segments = field_path.split('.').peekable();
+
+  while let Some(segment) = segments.next() {
+    let field = current.fields.iter().find(|field| json_name(field) == segment)?;
+    path.push(field_access(field));
+
+    if segments.peek().is_none() {
+      return is_displayable(&field.rust_type).then_some(path);
+    }
+    current = nested_struct(field, structs)?;
+  }
+
+  None
+}
+
+fn inferred_message_path(
+  def: &StructDef,
+  structs: &HashMap<DefaultAtom, &StructDef>,
+) -> Option<Vec<MessageFieldAccess>> {
+  if let Some(field) = find_message_field(def) {
+    return Some(vec![field_access(field)]);
+  }
+
+  let wrapper = def.user_fields().find(|field| field.name == NESTED_ERROR_FIELD)?;
+  let field = find_message_field(nested_struct(wrapper, structs)?)?;
+  Some(vec![field_access(wrapper), field_access(field)])
+}
+
+fn find_message_field(def: &StructDef) -> Option<&FieldDef> {
+  MESSAGE_FIELD_NAMES.iter().find_map(|name| {
+    def
+      .user_fields()
+      .find(|field| field.name == *name && field.rust_type.is_string_like())
+  })
+}
+
+fn nested_struct<'a>(field: &FieldDef, structs: &HashMap<DefaultAtom, &'a StructDef>) -> Option<&'a StructDef> {
+  match &field.rust_type.base_type {
+    RustPrimitive::Custom(name) if !field.rust_type.is_array => structs.get(name).copied(),
+    _ => None,
+  }
+}
+
+fn json_name(field: &FieldDef) -> &str {
+  field
+    .serde_attrs
+    .iter()
+    .find_map(|attr| match attr {
+      SerdeAttribute::Rename(name) => Some(name.as_str()),
+      _ => None,
+    })
+    .unwrap_or(field.name.as_str())
+}
+
+fn field_access(field: &FieldDef) -> MessageFieldAccess {
+  MessageFieldAccess {
+    name: field.name.clone(),
+    optional: field.rust_type.nullable,
+  }
+}
+
+fn is_displayable(rust_type: &TypeRef) -> bool {
+  !rust_type.is_array
+    && (rust_type.is_string_like()
+      || rust_type.base_type.is_numeric()
+      || matches!(
+        rust_type.base_type,
+        RustPrimitive::Bool | RustPrimitive::Uuid | RustPrimitive::Value
+      ))
+}
+
+fn error_body_types(def: &ResponseEnumDef) -> impl Iterator<Item = DefaultAtom> + '_ {
+  let default_is_error = default_is_error(def);
+
+  def
+    .variants
+    .iter()
+    .filter(move |variant| is_error_variant(variant, default_is_error))
+    .filter_map(|variant| match &variant.schema_type.as_ref()?.base_type {
+      RustPrimitive::Custom(name) => Some(name.clone()),
+      _ => None,
+    })
+}
+
+/// Returns `true` when the `default` response of an operation covers only failures,
+/// because every explicit response is a `2XX` success.
+fn default_is_error(def: &ResponseEnumDef) -> bool {
+  let mut explicit = def
+    .variants
+    .iter()
+    .filter(|variant| !variant.status_code.is_default())
+    .peekable();
+  explicit.peek().is_some() && explicit.all(|variant| variant.status_code.is_success())
+}
+
+fn is_error_variant(variant: &ResponseVariant, default_is_error: bool) -> bool {
+  variant.status_code.is_error() || (default_is_error && variant.status_code.is_default())
+}
+
+/// Records on each operation the response variants a client with typed errors returns
+/// as `Err`.
+///
+/// These are the variants whose bodies [`compute_error_schemas`] treats as errors, and
+/// the bodiless variants of error and `default` statuses, including the `Unknown`
+/// catch-all. Operations whose every response would be an error are left untouched, so
+/// their methods still have a success value to return.
+pub(crate) fn assign_error_response_types(types: &[RustType], operations: &mut [OperationInfo]) {
+  let response_enums = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::ResponseEnum(def) => Some((&def.name, def)),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let error_impls = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Struct(def) if def.error_impl => Some(def.name.to_atom()),
+      _ => None,
+    })
+    .collect::<HashSet<_>>();
+
+  for operation in operations {
+    let Some(def) = operation
+      .response_enum
+      .as_ref()
+      .and_then(|name| response_enums.get(name))
+    else {
+      continue;
+    };
+
+    let default_is_error = default_is_error(def);
+    let error_responses = def
+      .variants
+      .iter()
+      .filter_map(|variant| {
+        let body = match &variant.schema_type {
+          None if variant.status_code.is_error() || variant.status_code.is_default() => None,
+          Some(
+            rust_type @ TypeRef {
+              base_type: RustPrimitive::Custom(name),
+              ..
+            },
+          ) if !rust_type.is_array
+            && !rust_type.nullable
+            && !rust_type.boxed
+            && is_error_variant(variant, default_is_error) =>
+          {
+            Some(name.clone())
+          }
+          _ => return None,
+        };
+        Some(ErrorResponseType {
+          variant: variant.variant_name.clone(),
+          error_impl: body.as_ref().is_some_and(|name| error_impls.contains(name)),
+          body,
+          named_fields: variant.has_named_fields(),
+        })
+      })
+      .collect::<Vec<_>>();
+
+    if error_responses.len() < def.variants.len() || def.malformed_variant.is_some() {
+      operation.error_response_types = error_responses;
+    }
+  }
+}
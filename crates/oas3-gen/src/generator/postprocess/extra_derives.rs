@@ -0,0 +1,155 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::generator::ast::{DefaultAtom, DeriveTrait, RustPrimitive, RustType, StructKind, TypeRef, VariantContent};
+
+/// Traits added to every type that can derive them.
+const ORDERING_DERIVES: [DeriveTrait; 4] = [
+  DeriveTrait::Eq,
+  DeriveTrait::Hash,
+  DeriveTrait::PartialOrd,
+  DeriveTrait::Ord,
+];
+
+/// Types that implement `Eq`, `Hash`, and `Ord` when they appear as a whole or as a generic argument.
+const ORDERED_TYPES: &[&str] = &[
+  "String",
+  "bool",
+  "i8",
+  "i16",
+  "i32",
+  "i64",
+  "i128",
+  "isize",
+  "u8",
+  "u16",
+  "u32",
+  "u64",
+  "u128",
+  "usize",
+  "oas3_gen_support::Decimal",
+];
+
+/// Generic types that implement `Eq`, `Hash`, and `Ord` when all their type arguments do.
+const ORDERED_CONTAINERS: &[&str] = &[
+  "Option",
+  "Vec",
+  "Box",
+  "BTreeMap",
+  "BTreeSet",
+  "std::collections::BTreeMap",
+  "std::collections::BTreeSet",
+];
+
+/// Generic types that implement `Eq`, `Hash`, and `Ord` for every const argument.
+const ORDERED_CONST_GENERICS: &[&str] = &["oas3_gen_support::Money"];
+
+/// Derives `Eq`, `Hash`, `PartialOrd`, and `Ord` on schema structs and enums whose
+/// contents all support them, so they can be used as map keys and sorted.
+///
+/// Floats, `serde_json::Value`, hash maps, index sets, and types from outside the
+/// generated code rule a type out, as does holding a type that is ruled out.
+pub(crate) fn add_ordering_derives(types: &mut [RustType]) {
+  let mut candidates = BTreeMap::<DefaultAtom, Vec<TypeRef>>::new();
+  for rust_type in types.iter() {
+    if let Some(held) = held_types(rust_type) {
+      candidates.entry(rust_type.type_name()).or_default().extend(held);
+    }
+  }
+
+  loop {
+    let ordered = candidates.keys().cloned().collect::<BTreeSet<_>>();
+    let before = candidates.len();
+    candidates.retain(|_, held| held.iter().all(|type_ref| is_ordered(type_ref, &ordered)));
+    if candidates.len() == before {
+      break;
+    }
+  }
+
+  for rust_type in types.iter_mut() {
+    if !candidates.contains_key(&rust_type.type_name()) {
+      continue;
+    }
+    match rust_type {
+      RustType::Struct(def) => def.additional_derives.extend(ORDERING_DERIVES),
+      RustType::Enum(def) => def.additional_derives.extend(ORDERING_DERIVES),
+      RustType::TypeAlias(_) | RustType::DiscriminatedEnum(_) | RustType::ResponseEnum(_) => {}
+    }
+  }
+}
+
+/// The types a candidate holds, or `None` when the type cannot take the derives.
+fn held_types(rust_type: &RustType) -> Option<Vec<TypeRef>> {
+  match rust_type {
+    RustType::Struct(def) if def.kind == StructKind::Schema => {
+      Some(def.fields.iter().map(|field| field.rust_type.clone()).collect())
+    }
+    RustType::Enum(def) => Some(
+      def
+        .variants
+        .iter()
+        .flat_map(|variant| match &variant.content {
+          VariantContent::Unit => vec![],
+          VariantContent::Tuple(types) => types.clone(),
+        })
+        .collect(),
+    ),
+    RustType::TypeAlias(def) => Some(vec![def.target.clone()]),
+    _ => None,
+  }
+}
+
+fn is_ordered(type_ref: &TypeRef, ordered: &BTreeSet<DefaultAtom>) -> bool {
+  if type_ref.is_array && type_ref.unique_items {
+    return false;
+  }
+  match &type_ref.base_type {
+    RustPrimitive::Custom(rendered) => is_ordered_rendered(rendered, ordered),
+    primitive => primitive.is_totally_ordered(),
+  }
+}
+
+/// Whether a rendered type such as `Vec<Option<Pet>>` or `(String, i64)` is built only
+/// from known ordered types and generated candidates, matched by their full path.
+fn is_ordered_rendered(rendered: &str, ordered: &BTreeSet<DefaultAtom>) -> bool {
+  let rendered = rendered.trim();
+  if let Some(elements) = rendered.strip_prefix('(').and_then(|rest| rest.strip_suffix(')')) {
+    return split_arguments(elements)
+      .into_iter()
+      .filter(|element| !element.is_empty())
+      .all(|element| is_ordered_rendered(element, ordered));
+  }
+  let Some((path, arguments)) = rendered.split_once('<') else {
+    return ORDERED_TYPES.contains(&rendered) || ordered.contains(&DefaultAtom::from(rendered));
+  };
+  let Some(arguments) = arguments.strip_suffix('>') else {
+    return false;
+  };
+  let path = path.trim();
+  let arguments = split_arguments(arguments);
+  if ORDERED_CONST_GENERICS.contains(&path) {
+    return arguments
+      .iter()
+      .all(|argument| argument.chars().all(|c| c.is_ascii_digit()));
+  }
+  ORDERED_CONTAINERS.contains(&path) && arguments.iter().all(|argument| is_ordered_rendered(argument, ordered))
+}
+
+/// Splits generic or tuple arguments on the commas that are not nested in brackets.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+  let mut parts = vec![];
+  let mut depth = 0usize;
+  let mut start = 0;
+  for (index, c) in arguments.char_indices() {
+    match c {
+      '<' | '(' => depth += 1,
+      '>' | ')' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        parts.push(arguments[start..index].trim());
+        start = index + 1;
+      }
+      _ => {}
+    }
+  }
+  parts.push(arguments[start..].trim());
+  parts
+}
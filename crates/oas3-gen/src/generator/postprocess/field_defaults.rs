@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use crate::generator::ast::{EnumDef, FieldDef, MethodKind, RustPrimitive, RustType, StructDef};
+
+/// Resolves spec defaults that need the other generated types to express.
+///
+/// Fields holding a value enum get the variant their default names, so their
+/// `Default` value is that variant rather than the enum's own default. Request
+/// builders then take a parameter's default from the `Default` impl of the struct
+/// holding it, for every default `Default` reproduces exactly.
+pub(crate) fn resolve_field_defaults(types: &mut [RustType]) {
+  let enums = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Enum(def) => Some((def.name.to_string(), def.clone())),
+      _ => None,
+    })
+    .collect::<HashMap<_, EnumDef>>();
+
+  for rust_type in &mut *types {
+    if let RustType::Struct(def) = rust_type {
+      for field in &mut def.fields {
+        resolve_default_variant(field, &enums);
+      }
+    }
+  }
+
+  let structs = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Struct(def) => Some((def.name.to_string(), def.clone())),
+      _ => None,
+    })
+    .collect::<HashMap<_, StructDef>>();
+
+  for rust_type in types {
+    let RustType::Struct(def) = rust_type else {
+      continue;
+    };
+    for method in &mut def.methods {
+      let MethodKind::Builder { fields, nested_structs } = &mut method.kind else {
+        continue;
+      };
+      for field in fields {
+        field.has_default = field
+          .owner_field
+          .as_ref()
+          .and_then(|owner| nested_structs.iter().find(|nested| nested.field_name == *owner))
+          .and_then(|nested| structs.get(nested.struct_name.as_str()))
+          .and_then(|owner| owner.fields.iter().find(|owner_field| owner_field.name == field.name))
+          .is_some_and(FieldDef::has_exact_default);
+      }
+    }
+  }
+}
+
+fn resolve_default_variant(field: &mut FieldDef, enums: &HashMap<String, EnumDef>) {
+  let (Some(default_value), RustPrimitive::Custom(name)) = (&field.default_value, &field.rust_type.base_type) else {
+    return;
+  };
+  if field.rust_type.is_array {
+    return;
+  }
+  field.default_variant = enums
+    .get(name.as_ref())
+    .and_then(|enum_def| enum_def.variant_for_value(default_value))
+    .map(|variant| variant.name.clone());
+}
@@ -1,20 +1,38 @@
+mod clone_derives;
+mod error_schemas;
+mod extra_derives;
+mod field_defaults;
+mod pagination;
+mod parent_conversions;
+mod renames;
 mod response_enum;
 mod serde_usage;
+mod tag_modules;
 mod uses;
 mod validation;
 
 #[cfg(test)]
 mod tests;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+
+pub(crate) use clone_derives::skip_oversized_clones;
+pub(crate) use error_schemas::mark_error_schemas;
+pub(crate) use extra_derives::add_ordering_derives;
+pub use tag_modules::{TagModules, TypeModule};
+pub(crate) use uses::RustTypeDeduplication;
 
 use crate::generator::{
   ast::{EnumToken, OperationInfo, RustType, constants::HttpHeaderRef},
   converter::GenerationTarget,
   postprocess::{
-    response_enum::ResponseEnumDeduplicator,
+    error_schemas::assign_error_response_types,
+    field_defaults::resolve_field_defaults,
+    pagination::assign_pagination,
+    parent_conversions::assign_parent_conversions,
+    response_enum::{ResponseEnumDeduplicator, ResponseTargetAdapter},
     serde_usage::SerdeUsage,
-    uses::{ModuleImports, RustTypeDeduplication},
+    uses::ModuleImports,
     validation::NestedValidationProcessor,
   },
 };
@@ -31,17 +49,26 @@ impl PostprocessOutput {
   pub(crate) fn new(
     types: Vec<RustType>,
     operations: Vec<OperationInfo>,
-    seed_usage: std::collections::BTreeMap<EnumToken, (bool, bool)>,
+    seed_usage: BTreeMap<EnumToken, (bool, bool)>,
     target: GenerationTarget,
     header_refs: Vec<HttpHeaderRef>,
+    pagination_cursors: &[String],
   ) -> Self {
-    let (mut types, operations) = ResponseEnumDeduplicator::new(types, operations).process();
+    let mut types = types;
+    ResponseTargetAdapter::new(target).process(&mut types);
+
+    let (mut types, mut operations) = ResponseEnumDeduplicator::new(types, operations).process();
 
     NestedValidationProcessor::new(&types).process(&mut types);
+    mark_error_schemas(&mut types);
+    assign_error_response_types(&types, &mut operations);
 
     SerdeUsage::new(&types, seed_usage, target).apply(&mut types);
+    assign_parent_conversions(&mut types);
 
-    let dedup_output = RustTypeDeduplication::new(types).process();
+    let mut dedup_output = RustTypeDeduplication::new(types).process();
+    resolve_field_defaults(&mut dedup_output);
+    assign_pagination(&dedup_output, &mut operations, pagination_cursors);
     let uses_output = ModuleImports::new(dedup_output.clone(), target).process();
 
     Self {
@@ -51,4 +78,19 @@ impl PostprocessOutput {
       uses: uses_output,
     }
   }
+
+  /// Splits the types into one module per OpenAPI tag plus a shared module.
+  pub(crate) fn split_by_tag(&self, target: GenerationTarget) -> TagModules {
+    TagModules::new(&self.types, &self.operations, target)
+  }
+}
+
+/// Propagates the recorded request and response usage through type references.
+///
+/// Types no operation reaches are absent from the result.
+pub(crate) fn propagate_usage(
+  types: &[RustType],
+  seed_usage: BTreeMap<EnumToken, (bool, bool)>,
+) -> BTreeMap<EnumToken, (bool, bool)> {
+  SerdeUsage::new(types, seed_usage, GenerationTarget::Client).seeded_usage()
 }
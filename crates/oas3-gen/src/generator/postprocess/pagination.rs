@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use http::Method;
+
+use crate::generator::ast::{
+  FieldDef, FieldNameToken, NextPage, OperationInfo, PageCursor, Pagination, PaginationHint, ParameterLocation,
+  ResponseEnumDef, ResponseVariant, RustPrimitive, RustType, StructDef, TypeRef,
+};
+
+/// A page of results, as the success body of a list operation.
+#[derive(Clone, Copy)]
+enum PageBody<'a> {
+  /// An object holding the items array alongside other fields.
+  Struct(&'a StructDef),
+  /// The items array itself, written inline or through a type alias.
+  Array(&'a TypeRef),
+}
+
+/// The items, cursor, and next request of a page, as resolved by a rule.
+struct PageShape {
+  items: Option<FieldNameToken>,
+  items_optional: bool,
+  item_type: TypeRef,
+  cursor: PageCursor,
+  next_page: NextPage,
+}
+
+/// A vendor convention for paging through results, tried against operations without an
+/// `x-pagination` extension.
+type DetectionRule = fn(&OperationInfo, &ResponseVariant, PageBody<'_>, &[String]) -> Option<PageShape>;
+
+/// Conventions tried in order until one matches.
+///
+/// - [`cursor_field`]: a cursor field in the body, such as AWS `NextToken`, Google
+///   `nextPageToken`, or OData `@odata.nextLink`.
+/// - [`link_header`]: the `rel="next"` link of a declared `Link` header, as used by GitHub.
+const DETECTION_RULES: &[DetectionRule] = &[cursor_field, link_header];
+
+/// Resolves how each list operation pages through its results.
+///
+/// Operations with an `x-pagination` extension use the fields it names. Others are
+/// detected by the first of [`DETECTION_RULES`] they follow, when `cursors` is not empty.
+///
+/// Names are compared ignoring case and punctuation, so `next_page_token` also matches
+/// `nextPageToken`. Requests that leave `Clone` off are never paginated, since each page
+/// resends a copy of the request.
+pub(crate) fn assign_pagination(types: &[RustType], operations: &mut [OperationInfo], cursors: &[String]) {
+  let response_enums = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::ResponseEnum(def) => Some((&def.name, def)),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let structs = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Struct(def) => Some((def.name.to_atom(), def)),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let aliases = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::TypeAlias(def) => Some((def.name.to_atom(), &def.target)),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let cursors = cursors.iter().map(|name| normalize(name)).collect::<Vec<_>>();
+
+  for operation in operations {
+    let Some(request) = &operation.request_type else {
+      continue;
+    };
+    if structs.get(&request.to_atom()).is_some_and(|def| def.skip_clone) {
+      continue;
+    }
+    let Some(def) = operation
+      .response_enum
+      .as_ref()
+      .and_then(|name| response_enums.get(name))
+    else {
+      continue;
+    };
+    let Some((variant, page)) = def.variants.iter().find_map(|variant| {
+      if !variant.status_code.is_success() {
+        return None;
+      }
+      match &variant.schema_type {
+        Some(
+          ty @ TypeRef {
+            is_array: true,
+            nullable: false,
+            ..
+          },
+        ) => Some((variant, PageBody::Array(ty))),
+        Some(TypeRef {
+          base_type: RustPrimitive::Custom(name),
+          is_array: false,
+          nullable: false,
+          ..
+        }) => match (structs.get(name), aliases.get(name)) {
+          (Some(page), _) => Some((variant, PageBody::Struct(page))),
+          (None, Some(target)) if target.is_array && !target.nullable => Some((variant, PageBody::Array(target))),
+          _ => None,
+        },
+        _ => None,
+      }
+    }) else {
+      continue;
+    };
+
+    let resolved = match &operation.pagination_hint {
+      Some(hint) => from_hint(hint, page, &operation.parameters),
+      None if cursors.is_empty() => None,
+      None => DETECTION_RULES
+        .iter()
+        .find_map(|rule| rule(operation, variant, page, &cursors)),
+    };
+    operation.pagination = resolved.map(|shape| Pagination {
+      page_variant: variant.variant_name.clone(),
+      other_variants: has_other_variants(def),
+      page_links: variant.page_links,
+      items: shape.items,
+      items_optional: shape.items_optional,
+      item_type: shape.item_type,
+      cursor: shape.cursor,
+      next_page: shape.next_page,
+    });
+  }
+}
+
+fn has_other_variants(def: &ResponseEnumDef) -> bool {
+  def.variants.len() > 1 || def.malformed_variant.is_some()
+}
+
+fn from_hint(hint: &PaginationHint, page: PageBody<'_>, parameters: &[FieldDef]) -> Option<PageShape> {
+  let PageBody::Struct(page) = page else {
+    return None;
+  };
+  let cursor = page
+    .fields
+    .iter()
+    .find(|field| field.serialized_name() == hint.cursor && field.rust_type.is_string_like())?;
+  let items = match &hint.items {
+    Some(name) => page
+      .fields
+      .iter()
+      .find(|field| field.serialized_name() == name && field.rust_type.is_array)?,
+    None => single_array_field(page)?,
+  };
+  let next_page = match &hint.param {
+    Some(param) => query_param(parameters, |name| name == param)?,
+    None => NextPage::Link,
+  };
+  Some(PageShape::from_field(items, field_cursor(cursor), next_page))
+}
+
+/// Finds a string field named in `cursors`, sent back in the query parameter of the same
+/// name with any leading `next` dropped, so `nextPageToken` fills `pageToken`. A cursor
+/// without such a parameter whose name ends in `link` or `url` is followed as the next
+/// page's URL.
+fn cursor_field(
+  operation: &OperationInfo,
+  _variant: &ResponseVariant,
+  page: PageBody<'_>,
+  cursors: &[String],
+) -> Option<PageShape> {
+  let PageBody::Struct(page) = page else {
+    return None;
+  };
+  let cursor = page
+    .fields
+    .iter()
+    .find(|field| field.rust_type.is_string_like() && cursors.contains(&normalize(field.serialized_name())))?;
+  let items = single_array_field(page)?;
+
+  let cursor_name = normalize(cursor.serialized_name());
+  let param_name = cursor_name.strip_prefix("next").unwrap_or(&cursor_name);
+  let next_page = query_param(&operation.parameters, |name| {
+    let name = normalize(name);
+    name == cursor_name || name == param_name
+  })
+  .or_else(|| (cursor_name.ends_with("link") || cursor_name.ends_with("url")).then_some(NextPage::Link))?;
+  Some(PageShape::from_field(items, field_cursor(cursor), next_page))
+}
+
+/// Follows the `rel="next"` link of a `GET` operation whose page variant carries the
+/// links of a declared `Link` header, with a body that is the items array or has a
+/// single array field.
+fn link_header(
+  operation: &OperationInfo,
+  variant: &ResponseVariant,
+  page: PageBody<'_>,
+  _cursors: &[String],
+) -> Option<PageShape> {
+  if !operation.page_link || !variant.page_links || operation.method != Method::GET {
+    return None;
+  }
+  Some(match page {
+    PageBody::Array(ty) => PageShape {
+      items: None,
+      items_optional: false,
+      item_type: element_type(ty),
+      cursor: PageCursor::LinkHeader,
+      next_page: NextPage::Link,
+    },
+    PageBody::Struct(page) => PageShape::from_field(single_array_field(page)?, PageCursor::LinkHeader, NextPage::Link),
+  })
+}
+
+impl PageShape {
+  fn from_field(items: &FieldDef, cursor: PageCursor, next_page: NextPage) -> Self {
+    Self {
+      items: Some(items.name.clone()),
+      items_optional: items.rust_type.nullable,
+      item_type: element_type(&items.rust_type),
+      cursor,
+      next_page,
+    }
+  }
+}
+
+fn field_cursor(field: &FieldDef) -> PageCursor {
+  PageCursor::Field {
+    name: field.name.clone(),
+    optional: field.rust_type.nullable,
+  }
+}
+
+fn element_type(array: &TypeRef) -> TypeRef {
+  TypeRef {
+    is_array: false,
+    unique_items: false,
+    nullable: false,
+    ..array.clone()
+  }
+}
+
+fn single_array_field(page: &StructDef) -> Option<&FieldDef> {
+  let mut arrays = page.fields.iter().filter(|field| field.rust_type.is_array);
+  let items = arrays.next()?;
+  arrays.next().is_none().then_some(items)
+}
+
+/// Finds the string query parameter whose spec name satisfies `matches`.
+fn query_param(parameters: &[FieldDef], matches: impl Fn(&str) -> bool) -> Option<NextPage> {
+  parameters
+    .iter()
+    .find(|param| {
+      matches!(param.parameter_location, Some(ParameterLocation::Query))
+        && param.rust_type.is_string_like()
+        && matches(param.original_name.as_deref().unwrap_or(param.name.as_str()))
+    })
+    .map(|param| NextPage::Query {
+      field: param.name.clone(),
+      optional: param.rust_type.nullable,
+    })
+}
+
+fn normalize(name: &str) -> String {
+  name
+    .chars()
+    .filter(char::is_ascii_alphanumeric)
+    .map(|c| c.to_ascii_lowercase())
+    .collect()
+}
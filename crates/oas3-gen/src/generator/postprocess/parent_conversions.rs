@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::generator::{
+  ast::{DefaultAtom, FieldDef, ParentConversion, RustType, StructDef, StructKind, StructToken},
+  postprocess::clone_derives::custom_type_names,
+};
+
+/// Fills in the conversions between `allOf` children and the parent structs they extend.
+///
+/// A child converts into a parent when every visible parent field appears on the child
+/// with the same type. The child can be built back from the parent when, in addition,
+/// each field only the child has is optional or has a default. Hidden discriminator
+/// fields are never copied, since each struct fixes its own value. Children sharing a
+/// field whose type leaves `Clone` off get no conversions, since they copy every field.
+pub(crate) fn assign_parent_conversions(types: &mut [RustType]) {
+  let structs = types
+    .iter()
+    .filter_map(|rust_type| match rust_type {
+      RustType::Struct(def) if def.kind == StructKind::Schema => Some((def.name.clone(), def.fields.clone())),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let non_clone = non_clone_types(types);
+
+  for rust_type in types {
+    if let RustType::Struct(def) = rust_type
+      && !def.parents.is_empty()
+    {
+      def.parent_conversions = def
+        .parents
+        .iter()
+        .filter_map(|parent| {
+          let parent_fields = structs.get(parent)?;
+          parent_conversion(def, parent, parent_fields)
+        })
+        .filter(|conversion| {
+          !conversion
+            .shared_fields
+            .iter()
+            .flat_map(|(_, rust_type)| custom_type_names(rust_type))
+            .any(|name| non_clone.contains(&name))
+        })
+        .collect();
+    }
+  }
+}
+
+fn parent_conversion(child: &StructDef, parent: &StructToken, parent_fields: &[FieldDef]) -> Option<ParentConversion> {
+  if *parent == child.name {
+    return None;
+  }
+
+  let shared_fields = parent_fields
+    .iter()
+    .filter(|field| !field.doc_hidden)
+    .collect::<Vec<_>>();
+  let all_shared_on_child = shared_fields.iter().all(|shared| {
+    child
+      .fields
+      .iter()
+      .any(|field| field.name == shared.name && !field.doc_hidden && field.rust_type == shared.rust_type)
+  });
+  if !all_shared_on_child {
+    return None;
+  }
+
+  let is_shared = |field: &FieldDef| shared_fields.iter().any(|shared| shared.name == field.name);
+  let from_parent = child
+    .fields
+    .iter()
+    .filter(|field| !is_shared(field))
+    .all(|field| field.doc_hidden || !field.is_required());
+
+  Some(ParentConversion {
+    parent: parent.clone(),
+    covers_parent: shared_fields.len() == parent_fields.len(),
+    covers_child: shared_fields.len() == child.fields.len(),
+    shared_fields: shared_fields
+      .into_iter()
+      .map(|field| (field.name.clone(), field.rust_type.clone()))
+      .collect(),
+    from_parent,
+  })
+}
+
+fn non_clone_types(types: &[RustType]) -> HashSet<DefaultAtom> {
+  types
+    .iter()
+    .filter(|rust_type| match rust_type {
+      RustType::Struct(def) => def.skip_clone,
+      RustType::Enum(def) => def.skip_clone,
+      RustType::DiscriminatedEnum(def) => def.skip_clone,
+      RustType::ResponseEnum(def) => def.skip_clone,
+      RustType::TypeAlias(_) => false,
+    })
+    .map(RustType::type_name)
+    .collect()
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use crate::generator::ast::{
+  EnumMethodKind, EnumToken, FieldDef, MethodKind, OperationInfo, ResponseMediaType, ResponseStatusCategory,
+  ResponseVariant, RustPrimitive, RustType, StructToken, TypeRef, VariantContent,
+};
+
+/// Points every reference to a type named in `replacements` at its replacement,
+/// across type definitions and operations.
+pub(super) fn rename_type_references(
+  types: &mut [RustType],
+  operations: &mut [OperationInfo],
+  replacements: &BTreeMap<String, String>,
+) {
+  let renamer = TypeRenamer { replacements };
+  for rust_type in types {
+    renamer.rust_type(rust_type);
+  }
+  for operation in operations {
+    renamer.operation(operation);
+  }
+}
+
+struct TypeRenamer<'a> {
+  replacements: &'a BTreeMap<String, String>,
+}
+
+impl TypeRenamer<'_> {
+  fn rust_type(&self, rust_type: &mut RustType) {
+    match rust_type {
+      RustType::Struct(def) => {
+        self.fields(&mut def.fields);
+        for parent in &mut def.parents {
+          self.struct_token(parent);
+        }
+        for conversion in &mut def.parent_conversions {
+          self.struct_token(&mut conversion.parent);
+          for (_, type_ref) in &mut conversion.shared_fields {
+            self.type_ref(type_ref);
+          }
+        }
+        for method in &mut def.methods {
+          self.struct_method(&mut method.kind);
+        }
+      }
+      RustType::Enum(def) => {
+        for variant in &mut def.variants {
+          if let VariantContent::Tuple(types) = &mut variant.content {
+            types.iter_mut().for_each(|type_ref| self.type_ref(type_ref));
+          }
+        }
+        for method in &mut def.methods {
+          self.enum_method(&mut method.kind);
+        }
+      }
+      RustType::TypeAlias(def) => self.type_ref(&mut def.target),
+      RustType::DiscriminatedEnum(def) => {
+        for variant in def.variants.iter_mut().chain(def.fallback.as_mut()) {
+          self.type_ref(&mut variant.type_name);
+        }
+        for method in &mut def.methods {
+          self.enum_method(&mut method.kind);
+        }
+      }
+      RustType::ResponseEnum(def) => {
+        for variant in &mut def.variants {
+          self.response_variant(variant);
+        }
+        for try_from in &mut def.try_from {
+          self.type_ref(&mut try_from.into);
+        }
+      }
+    }
+  }
+
+  fn operation(&self, operation: &mut OperationInfo) {
+    if let Some(response_type) = &mut operation.response_type
+      && let Some(renamed) = self.rendered(response_type)
+    {
+      *response_type = renamed;
+    }
+    self.media_types(&mut operation.response_media_types);
+    self.fields(&mut operation.parameters);
+    if let Some(body_type) = operation.body.as_mut().and_then(|body| body.body_type.as_mut()) {
+      self.type_ref(body_type);
+    }
+    for error_type in &mut operation.error_response_types {
+      if let Some(body) = &mut error_type.body
+        && let Some(renamed) = self.replacements.get(body.as_ref())
+      {
+        *body = renamed.as_str().into();
+      }
+    }
+    if let Some(pagination) = &mut operation.pagination {
+      self.type_ref(&mut pagination.item_type);
+    }
+  }
+
+  fn struct_method(&self, kind: &mut MethodKind) {
+    match kind {
+      MethodKind::ParseResponse {
+        status_handlers,
+        default_handler,
+        ..
+      }
+      | MethodKind::IntoAxumResponse {
+        status_handlers,
+        default_handler,
+        ..
+      } => {
+        for handler in status_handlers {
+          match &mut handler.dispatch {
+            ResponseStatusCategory::Single(category) => self.response_variant(&mut category.variant),
+            ResponseStatusCategory::ContentDispatch { streams, variants } => {
+              for category in streams.iter_mut().chain(variants) {
+                self.response_variant(&mut category.variant);
+              }
+            }
+          }
+        }
+        if let Some(category) = default_handler {
+          self.response_variant(&mut category.variant);
+        }
+      }
+      MethodKind::Builder { fields, nested_structs } => {
+        for field in fields {
+          self.type_ref(&mut field.rust_type);
+        }
+        for nested in nested_structs {
+          self.struct_token(&mut nested.struct_name);
+        }
+      }
+    }
+  }
+
+  fn enum_method(&self, kind: &mut EnumMethodKind) {
+    match kind {
+      EnumMethodKind::SimpleConstructor { wrapped_type, .. } => self.type_ref(wrapped_type),
+      EnumMethodKind::ParameterizedConstructor {
+        wrapped_type,
+        param_type,
+        ..
+      } => {
+        self.type_ref(wrapped_type);
+        self.type_ref(param_type);
+      }
+      EnumMethodKind::KnownValueConstructor { known_type, .. } => {
+        if let Some(renamed) = self.replacements.get(&known_type.to_string()) {
+          *known_type = EnumToken::new(renamed);
+        }
+      }
+    }
+  }
+
+  fn response_variant(&self, variant: &mut ResponseVariant) {
+    if let Some(schema_type) = &mut variant.schema_type {
+      self.type_ref(schema_type);
+    }
+    self.media_types(&mut variant.media_types);
+  }
+
+  fn media_types(&self, media_types: &mut [ResponseMediaType]) {
+    for media_type in media_types {
+      if let Some(schema_type) = &mut media_type.schema_type {
+        self.type_ref(schema_type);
+      }
+      for event in media_type.named_events.iter_mut().flat_map(|events| &mut events.events) {
+        self.type_ref(&mut event.schema_type);
+      }
+    }
+  }
+
+  fn fields(&self, fields: &mut [FieldDef]) {
+    for field in fields {
+      self.type_ref(&mut field.rust_type);
+    }
+  }
+
+  fn struct_token(&self, token: &mut StructToken) {
+    if let Some(renamed) = self.replacements.get(&token.to_string()) {
+      *token = StructToken::new(renamed);
+    }
+  }
+
+  fn type_ref(&self, type_ref: &mut TypeRef) {
+    if let RustPrimitive::Custom(name) = &type_ref.base_type
+      && let Some(renamed) = self.rendered(name)
+    {
+      type_ref.base_type = RustPrimitive::Custom(renamed.into());
+    }
+  }
+
+  /// Renames the type names in a rendered type such as `IndexMap<String, Vec<Pet>>`,
+  /// leaving path segments like the `Pet` in `crate::Pet` alone.
+  fn rendered(&self, rendered: &str) -> Option<String> {
+    let mut out = String::with_capacity(rendered.len());
+    let mut changed = false;
+    let mut rest = rendered;
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+      let (before, word_start) = rest.split_at(start);
+      out.push_str(before);
+      let end = word_start
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(word_start.len());
+      let (word, after) = word_start.split_at(end);
+      let qualified = out.ends_with("::");
+      match self.replacements.get(word) {
+        Some(renamed) if !qualified => {
+          out.push_str(renamed);
+          changed = true;
+        }
+        _ => out.push_str(word),
+      }
+      rest = after;
+    }
+    out.push_str(rest);
+    changed.then_some(out)
+  }
+}
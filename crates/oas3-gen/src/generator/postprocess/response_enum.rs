@@ -1,7 +1,11 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use crate::generator::ast::{
-  ContentCategory, EnumToken, MethodKind, OperationInfo, ResponseEnumDef, RustType, StatusCodeToken, TypeRef,
+use crate::generator::{
+  ast::{
+    ContentCategory, Documentation, EnumToken, MethodKind, OperationInfo, ResponseEnumDef, RustType, StatusCodeToken,
+    TypeRef,
+  },
+  converter::GenerationTarget,
 };
 
 type Signature = Vec<(StatusCodeToken, String, Vec<(ContentCategory, String)>)>;
@@ -71,6 +75,10 @@ impl ResponseEnumDeduplicator {
       let RustType::ResponseEnum(def) = rt else {
         continue;
       };
+      // `next()` and `prev()` build the enum's own request type, so it cannot be shared.
+      if def.variants.iter().any(|v| v.page_links) {
+        continue;
+      }
 
       let signature = Self::compute_signature(def);
       signature_map.entry(signature).or_default().push(Candidate {
@@ -137,3 +145,50 @@ impl ResponseEnumDeduplicator {
     }
   }
 }
+
+/// Rewrites response handling converted for one target into the form another target expects.
+///
+/// Conversion builds `parse_response` methods and malformed-response variants for the
+/// configured target. When one converted AST feeds both client and server output, the
+/// server copy is rewritten here so each target sees what its own conversion would produce.
+pub(crate) struct ResponseTargetAdapter {
+  target: GenerationTarget,
+}
+
+impl ResponseTargetAdapter {
+  pub(crate) fn new(target: GenerationTarget) -> Self {
+    Self { target }
+  }
+
+  pub(crate) fn process(&self, types: &mut [RustType]) {
+    if self.target != GenerationTarget::Server {
+      return;
+    }
+
+    for rust_type in types {
+      match rust_type {
+        RustType::ResponseEnum(def) => def.malformed_variant = None,
+        RustType::Struct(def) => {
+          for method in &mut def.methods {
+            let MethodKind::ParseResponse {
+              response_enum,
+              status_handlers,
+              default_handler,
+              ..
+            } = &method.kind
+            else {
+              continue;
+            };
+            method.kind = MethodKind::IntoAxumResponse {
+              response_enum: response_enum.clone(),
+              status_handlers: status_handlers.clone(),
+              default_handler: default_handler.clone(),
+            };
+            method.docs = Documentation::from_lines(["Server code does not need to parse responses."]);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
@@ -4,8 +4,8 @@ use petgraph::{Graph, graph::NodeIndex};
 
 use crate::generator::{
   ast::{
-    DerivesProvider, DiscriminatedEnumDef, EnumDef, EnumToken, OuterAttr, RustPrimitive, RustType, SerdeImpl,
-    SerdeMode, StructDef, StructKind, TypeRef,
+    DerivesProvider, DiscriminatedEnumDef, EnumDef, EnumToken, FieldAccess, OuterAttr, RustPrimitive, RustType,
+    SerdeAttribute, SerdeImpl, SerdeMode, StructDef, StructKind, TypeRef,
   },
   converter::GenerationTarget,
 };
@@ -59,6 +59,15 @@ impl SerdeUsage {
     }
   }
 
+  /// Returns the `(request, response)` usage of every type reached from the seeds.
+  ///
+  /// Unlike [`Self::apply`], types no seed reaches are left out instead of being
+  /// treated as used in both directions.
+  pub(crate) fn seeded_usage(mut self) -> BTreeMap<EnumToken, UsageFlags> {
+    self.propagate_from_seeds();
+    self.usage
+  }
+
   pub(crate) fn apply(mut self, types: &mut [RustType]) {
     self.propagate();
     self.update_types(types);
@@ -88,7 +97,7 @@ impl SerdeUsage {
     (graph, indices)
   }
 
-  fn dependencies(rust_type: &RustType) -> impl Iterator<Item = EnumToken> + '_ {
+  pub(super) fn dependencies(rust_type: &RustType) -> impl Iterator<Item = EnumToken> + '_ {
     let refs: Box<dyn Iterator<Item = &TypeRef> + '_> = match rust_type {
       RustType::Struct(def) => Box::new(def.fields.iter().map(|f| &f.rust_type)),
       RustType::Enum(def) => Box::new(def.variants.iter().filter_map(|v| v.content.tuple_types()).flatten()),
@@ -196,9 +205,45 @@ impl SerdeUsage {
       def.fields.iter_mut().for_each(|f| f.validation_attrs.clear());
     }
 
+    if def.kind == StructKind::Schema {
+      self.update_field_access(def, self.get_usage(&key));
+    }
+
     Self::update_skip_serializing_none(def);
   }
 
+  /// Keeps `readOnly` fields out of requests and `writeOnly` fields out of responses.
+  ///
+  /// A struct used in one direction drops the fields that never travel in it. A struct
+  /// used both ways keeps them, skipping each when sending or receiving the message it
+  /// never appears in, depending on which side the target is.
+  fn update_field_access(&self, def: &mut StructDef, usage: TypeUsage) {
+    if self.target == GenerationTarget::Client && usage != TypeUsage::ResponseOnly {
+      for field in &mut def.fields {
+        if field.access == FieldAccess::ServerManaged {
+          field.serde_attrs.insert(SerdeAttribute::SkipSerializing);
+        }
+      }
+    }
+    match usage {
+      TypeUsage::RequestOnly => def.fields.retain(|field| field.access != FieldAccess::ReadOnly),
+      TypeUsage::ResponseOnly => def.fields.retain(|field| field.access != FieldAccess::WriteOnly),
+      TypeUsage::Bidirectional => {
+        let (never_sent, never_received) = match self.target {
+          GenerationTarget::Client => (FieldAccess::ReadOnly, FieldAccess::WriteOnly),
+          GenerationTarget::Server => (FieldAccess::WriteOnly, FieldAccess::ReadOnly),
+        };
+        for field in &mut def.fields {
+          if field.access == never_sent {
+            field.serde_attrs.insert(SerdeAttribute::SkipSerializing);
+          } else if field.access == never_received {
+            field.serde_attrs.insert(SerdeAttribute::SkipDeserializing);
+          }
+        }
+      }
+    }
+  }
+
   fn struct_serde_mode(&self, def: &StructDef) -> SerdeMode {
     match def.kind {
       StructKind::Schema => {
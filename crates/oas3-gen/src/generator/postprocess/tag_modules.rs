@@ -0,0 +1,139 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use crate::generator::{
+  ast::{OperationInfo, RustType},
+  converter::GenerationTarget,
+  naming::identifiers::{ensure_unique_snake_case_id, to_rust_field_name},
+  postprocess::{serde_usage::SerdeUsage, uses::ModuleImports},
+};
+
+/// Module names already used by the files generated next to the tag modules.
+const RESERVED_MODULE_NAMES: &[&str] = &["client", "mod", "prelude", "server", "types"];
+
+/// Only modules defining response enums implement axum's `IntoResponse`.
+const INTO_RESPONSE_IMPORT: &str = "axum::response::IntoResponse";
+
+/// The types written to one generated module, with the imports they need.
+#[derive(Debug, Clone, Default)]
+pub struct TypeModule {
+  pub types: Vec<RustType>,
+  pub uses: BTreeSet<String>,
+}
+
+/// Types split into one module per OpenAPI tag plus a shared module.
+///
+/// Each operation belongs to its first tag. A type moves to a tag's module when
+/// only that tag's operations reach it, directly or through other types. Types
+/// reached from several tags, from untagged operations, or from no operation at
+/// all stay in the shared module.
+#[derive(Debug, Clone, Default)]
+pub struct TagModules {
+  pub shared: TypeModule,
+  /// Tag modules keyed by module name.
+  pub tagged: BTreeMap<String, TypeModule>,
+}
+
+impl TagModules {
+  pub(crate) fn new(types: &[RustType], operations: &[OperationInfo], target: GenerationTarget) -> Self {
+    let owners = Self::owners(types, operations);
+
+    let mut shared = vec![];
+    let mut tagged = BTreeMap::<String, Vec<RustType>>::new();
+    for rust_type in types {
+      match owners.get(&*rust_type.type_name()) {
+        Some(Some(module)) => tagged.entry(module.clone()).or_default().push(rust_type.clone()),
+        _ => shared.push(rust_type.clone()),
+      }
+    }
+
+    let module = |types: Vec<RustType>| {
+      let mut uses = ModuleImports::new(types.clone(), target).process();
+      if !types
+        .iter()
+        .any(|rust_type| matches!(rust_type, RustType::ResponseEnum(_)))
+      {
+        uses.remove(INTO_RESPONSE_IMPORT);
+      }
+      TypeModule { types, uses }
+    };
+    Self {
+      shared: module(shared),
+      tagged: tagged.into_iter().map(|(name, types)| (name, module(types))).collect(),
+    }
+  }
+
+  /// Maps each reachable type name to its tag module, or `None` when it is shared.
+  fn owners(types: &[RustType], operations: &[OperationInfo]) -> HashMap<String, Option<String>> {
+    let dependencies = types
+      .iter()
+      .map(|rust_type| {
+        let names = SerdeUsage::dependencies(rust_type)
+          .flat_map(|dep| {
+            dep
+              .as_str()
+              .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+              .filter(|name| !name.is_empty())
+              .map(str::to_string)
+              .collect::<Vec<_>>()
+          })
+          .collect::<Vec<_>>();
+        (rust_type.type_name().to_string(), names)
+      })
+      .collect::<HashMap<_, _>>();
+
+    let module_names = Self::module_names(operations);
+    let mut owners = HashMap::<String, Option<String>>::new();
+    for operation in operations {
+      let module = operation.tags.first().map(|tag| module_names[tag].clone());
+      let roots = [
+        operation.request_type.as_ref().map(ToString::to_string),
+        operation.response_enum.as_ref().map(ToString::to_string),
+      ];
+
+      let mut pending = roots.into_iter().flatten().collect::<VecDeque<_>>();
+      let mut visited = BTreeSet::new();
+      while let Some(name) = pending.pop_front() {
+        if !visited.insert(name.clone()) {
+          continue;
+        }
+        let Some(names) = dependencies.get(&name) else {
+          continue;
+        };
+        owners
+          .entry(name)
+          .and_modify(|owner| {
+            if *owner != module {
+              *owner = None;
+            }
+          })
+          .or_insert_with(|| module.clone());
+        pending.extend(names.iter().cloned());
+      }
+    }
+    owners
+  }
+
+  /// Derives a unique `snake_case` module name for every primary tag.
+  fn module_names(operations: &[OperationInfo]) -> HashMap<String, String> {
+    let tags = operations
+      .iter()
+      .filter_map(|operation| operation.tags.first())
+      .collect::<BTreeSet<_>>();
+
+    let mut used = RESERVED_MODULE_NAMES
+      .iter()
+      .map(ToString::to_string)
+      .collect::<BTreeSet<_>>();
+    let mut names = HashMap::new();
+    for tag in tags {
+      let base = to_rust_field_name(tag);
+      let base = base
+        .strip_prefix("r#")
+        .map_or(base.clone(), |keyword| format!("{keyword}_"));
+      let name = ensure_unique_snake_case_id(&base, |candidate| used.contains(candidate));
+      used.insert(name.clone());
+      names.insert(tag.clone(), name);
+    }
+    names
+  }
+}
@@ -45,5 +45,5 @@ fn postprocess_types_with_target(
   usage_seeds: BTreeMap<EnumToken, (bool, bool)>,
   target: GenerationTarget,
 ) -> Vec<RustType> {
-  PostprocessOutput::new(types, vec![], usage_seeds, target, vec![]).types
+  PostprocessOutput::new(types, vec![], usage_seeds, target, vec![], &[]).types
 }
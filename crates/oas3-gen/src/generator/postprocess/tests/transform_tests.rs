@@ -1,12 +1,18 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::generator::{
   ast::{
-    DeriveTrait, DerivesProvider, EnumDef, EnumToken, EnumVariantToken, FieldDef, OuterAttr, RustType, StructDef,
-    StructKind, StructToken, TypeRef, ValidationAttribute, VariantContent, VariantDef, tokens::FieldNameToken,
+    BuilderField, DeriveTrait, DerivesProvider, EnumDef, EnumToken, EnumVariantToken, ErrorResponseType,
+    ErrorSourceField, FieldDef, MessageFieldAccess, MethodKind, NextPage, OperationInfo, OperationKind, OuterAttr,
+    PageCursor, PaginationHint, ParameterLocation, ParentConversion, ParsedPath, ResponseEnumDef, ResponseVariant,
+    RustType, SerdeAttribute, StatusCodeToken, StructDef, StructKind, StructToken, TypeRef, ValidationAttribute,
+    VariantContent, VariantDef, tokens::FieldNameToken,
   },
+  converter::{GenerationTarget, methods::MethodGenerator},
   postprocess::{
+    PostprocessOutput,
     serde_usage::TypeUsage,
+    skip_oversized_clones,
     tests::{postprocess_types_for_server, postprocess_types_with_usage},
   },
 };
@@ -445,3 +451,886 @@ fn test_client_vs_server_serde_inversion() {
   assert!(server_resp.derives().contains(&DeriveTrait::Serialize));
   assert!(!server_resp.derives().contains(&DeriveTrait::Deserialize));
 }
+
+fn response_enum(name: &str, variants: &[(StatusCodeToken, Option<&str>)]) -> RustType {
+  RustType::ResponseEnum(
+    ResponseEnumDef::builder()
+      .name(EnumToken::new(name))
+      .variants(
+        variants
+          .iter()
+          .map(|(status_code, schema)| {
+            ResponseVariant::builder()
+              .variant_name(status_code.to_variant_token())
+              .status_code(*status_code)
+              .maybe_schema_type(schema.map(TypeRef::new))
+              .build()
+          })
+          .collect(),
+      )
+      .build(),
+  )
+}
+
+#[test]
+fn test_marks_error_response_bodies_for_error_impls() {
+  let types = vec![
+    RustType::Struct(create_struct("NotFound", StructKind::Schema, false)),
+    RustType::Struct(create_struct("Fallback", StructKind::Schema, false)),
+    RustType::Struct(create_struct("Mixed", StructKind::Schema, false)),
+    RustType::Struct(create_struct("Pet", StructKind::Schema, false)),
+    response_enum(
+      "GetPetResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("Pet")),
+        (StatusCodeToken::NotFound404, Some("NotFound")),
+        (StatusCodeToken::Default, Some("Mixed")),
+      ],
+    ),
+    response_enum(
+      "ListPetsResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("Pet")),
+        (StatusCodeToken::Created201, None),
+        (StatusCodeToken::Default, Some("Fallback")),
+      ],
+    ),
+    response_enum(
+      "MovePetResponse",
+      &[
+        (StatusCodeToken::Ok200, None),
+        (StatusCodeToken::Found302, None),
+        (StatusCodeToken::Default, Some("Mixed")),
+      ],
+    ),
+  ];
+
+  let processed = postprocess_types_with_usage(types, BTreeMap::new());
+  let error_impl = |name: &str| {
+    processed
+      .iter()
+      .find_map(|rust_type| match rust_type {
+        RustType::Struct(def) if def.name == name => Some(def.error_impl),
+        _ => None,
+      })
+      .unwrap()
+  };
+
+  assert!(error_impl("NotFound"), "4XX bodies are errors");
+  assert!(
+    error_impl("Fallback"),
+    "default is an error when explicit responses are 2XX"
+  );
+  assert!(
+    !error_impl("Mixed"),
+    "default is not an error next to explicit non-2XX responses"
+  );
+  assert!(!error_impl("Pet"), "success bodies are not errors");
+}
+
+fn error_body(name: &str, fields: Vec<FieldDef>, message_field: Option<&str>) -> RustType {
+  RustType::Struct(
+    StructDef::builder()
+      .name(name)
+      .fields(fields)
+      .kind(StructKind::Schema)
+      .maybe_error_message_field(message_field)
+      .build(),
+  )
+}
+
+fn message_field(name: &str, rust_type: TypeRef) -> FieldDef {
+  FieldDef::builder()
+    .name(FieldNameToken::new(name))
+    .rust_type(rust_type)
+    .build()
+}
+
+#[test]
+fn test_resolves_error_message_paths() {
+  let types = vec![
+    error_body(
+      "OAuthError",
+      vec![
+        message_field("error", TypeRef::new("String")),
+        message_field("error_description", TypeRef::new("String").with_option()),
+        message_field("detail", TypeRef::new("String")),
+      ],
+      None,
+    ),
+    error_body("Wrapped", vec![message_field("error", TypeRef::new("Details"))], None),
+    error_body(
+      "Declared",
+      vec![
+        message_field("message", TypeRef::new("String")),
+        message_field("info", TypeRef::new("Details").with_option()),
+      ],
+      Some("info.statusText"),
+    ),
+    error_body(
+      "Unresolved",
+      vec![message_field("message", TypeRef::new("String"))],
+      Some("missing"),
+    ),
+    error_body(
+      "Opaque",
+      vec![message_field("messages", TypeRef::new("String").with_vec())],
+      None,
+    ),
+    error_body(
+      "Details",
+      vec![
+        message_field("message", TypeRef::new("String")),
+        FieldDef::builder()
+          .name(FieldNameToken::new("status_text"))
+          .rust_type(TypeRef::new("String").with_option())
+          .serde_attrs(BTreeSet::from([SerdeAttribute::Rename("statusText".to_string())]))
+          .build(),
+      ],
+      None,
+    ),
+    response_enum(
+      "GetResponse",
+      &[
+        (StatusCodeToken::Ok200, None),
+        (StatusCodeToken::BadRequest400, Some("OAuthError")),
+        (StatusCodeToken::NotFound404, Some("Wrapped")),
+        (StatusCodeToken::Conflict409, Some("Declared")),
+        (StatusCodeToken::Gone410, Some("Unresolved")),
+        (StatusCodeToken::InternalServerError500, Some("Opaque")),
+      ],
+    ),
+  ];
+
+  let processed = postprocess_types_with_usage(types, BTreeMap::new());
+  let message_path = |name: &str| {
+    processed
+      .iter()
+      .find_map(|rust_type| match rust_type {
+        RustType::Struct(def) if def.name == name => Some(
+          def
+            .error_message_path
+            .iter()
+            .map(|MessageFieldAccess { name, optional }| (name.to_string(), *optional))
+            .collect::<Vec<_>>(),
+        ),
+        _ => None,
+      })
+      .unwrap()
+  };
+  let path = |segments: &[(&str, bool)]| {
+    segments
+      .iter()
+      .map(|(name, optional)| ((*name).to_string(), *optional))
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    message_path("OAuthError"),
+    path(&[("detail", false)]),
+    "detail is preferred over error_description and error"
+  );
+  assert_eq!(
+    message_path("Wrapped"),
+    path(&[("error", false), ("message", false)]),
+    "message is found inside a nested error object"
+  );
+  assert_eq!(
+    message_path("Declared"),
+    path(&[("info", true), ("status_text", true)]),
+    "x-error-message-field matches serialized names"
+  );
+  assert_eq!(
+    message_path("Unresolved"),
+    path(&[("message", false)]),
+    "unresolved extension paths fall back to inference"
+  );
+  assert!(message_path("Opaque").is_empty(), "arrays are not messages");
+  assert_eq!(
+    message_path("Details"),
+    path(&[("message", false)]),
+    "nested error objects get their own path"
+  );
+}
+
+#[test]
+fn test_chains_nested_error_sources() {
+  let types = vec![
+    error_body(
+      "GraphError",
+      vec![message_field("error", TypeRef::new("MainError"))],
+      None,
+    ),
+    error_body(
+      "MainError",
+      vec![
+        message_field("message", TypeRef::new("String").with_option()),
+        message_field("inner_error", TypeRef::new("InnerError").with_option()),
+      ],
+      None,
+    ),
+    error_body(
+      "InnerError",
+      vec![
+        message_field("code", TypeRef::new("String")),
+        message_field("inner_error", TypeRef::new("InnerError").with_boxed().with_option()),
+      ],
+      None,
+    ),
+    error_body(
+      "Unrelated",
+      vec![message_field("message", TypeRef::new("String"))],
+      None,
+    ),
+    response_enum(
+      "GetResponse",
+      &[
+        (StatusCodeToken::Ok200, None),
+        (StatusCodeToken::BadRequest400, Some("GraphError")),
+      ],
+    ),
+  ];
+
+  let processed = postprocess_types_with_usage(types, BTreeMap::new());
+  let error_def = |name: &str| {
+    processed
+      .iter()
+      .find_map(|rust_type| match rust_type {
+        RustType::Struct(def) if def.name == name => Some(def),
+        _ => None,
+      })
+      .unwrap()
+  };
+
+  assert_eq!(
+    error_def("GraphError").error_source,
+    Some(ErrorSourceField {
+      name: FieldNameToken::new("error"),
+      optional: false,
+      boxed: false,
+      forwarded: true,
+    }),
+    "the message wrapper forwards to its own source"
+  );
+  assert_eq!(
+    error_def("MainError").error_source,
+    Some(ErrorSourceField {
+      name: FieldNameToken::new("inner_error"),
+      optional: true,
+      boxed: false,
+      forwarded: false,
+    }),
+  );
+  assert_eq!(
+    error_def("InnerError").error_source,
+    Some(ErrorSourceField {
+      name: FieldNameToken::new("inner_error"),
+      optional: true,
+      boxed: true,
+      forwarded: false,
+    }),
+    "self-referencing chains resolve once"
+  );
+  assert!(
+    ["MainError", "InnerError"]
+      .iter()
+      .all(|name| error_def(name).error_impl),
+    "nested error objects implement Error"
+  );
+  assert!(!error_def("Unrelated").error_impl);
+}
+
+#[test]
+fn test_assigns_error_response_types_to_operations() {
+  let operation = |id: &str, response_enum: &str| {
+    OperationInfo::builder()
+      .stable_id(id)
+      .operation_id(id)
+      .method(http::Method::GET)
+      .path(ParsedPath {
+        segments: vec![],
+        query_string: None,
+      })
+      .kind(OperationKind::Http)
+      .request_type(StructToken::new(format!("{response_enum}Request")))
+      .response_enum(EnumToken::new(response_enum))
+      .build()
+  };
+  let types = vec![
+    error_body("Problem", vec![message_field("detail", TypeRef::new("String"))], None),
+    RustType::Struct(create_struct("Pet", StructKind::Schema, false)),
+    response_enum(
+      "GetPetResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("Pet")),
+        (StatusCodeToken::NotFound404, Some("Problem")),
+        (StatusCodeToken::ServiceUnavailable503, None),
+        (StatusCodeToken::Default, None),
+      ],
+    ),
+    response_enum(
+      "DeletePetResponse",
+      &[
+        (StatusCodeToken::BadRequest400, Some("Problem")),
+        (StatusCodeToken::Default, None),
+      ],
+    ),
+  ];
+  let operations = vec![
+    operation("get_pet", "GetPetResponse"),
+    operation("delete_pet", "DeletePetResponse"),
+  ];
+
+  let output = PostprocessOutput::new(
+    types,
+    operations,
+    BTreeMap::new(),
+    GenerationTarget::Client,
+    vec![],
+    &[],
+  );
+
+  assert_eq!(
+    output.operations[0].error_response_types,
+    vec![
+      ErrorResponseType {
+        variant: StatusCodeToken::NotFound404.to_variant_token(),
+        body: Some("Problem".into()),
+        named_fields: false,
+        error_impl: true,
+      },
+      ErrorResponseType {
+        variant: StatusCodeToken::ServiceUnavailable503.to_variant_token(),
+        body: None,
+        named_fields: false,
+        error_impl: false,
+      },
+      ErrorResponseType {
+        variant: StatusCodeToken::Default.to_variant_token(),
+        body: None,
+        named_fields: false,
+        error_impl: false,
+      },
+    ]
+  );
+  assert!(
+    output.operations[1].error_response_types.is_empty(),
+    "operations without a success response keep every variant"
+  );
+}
+
+#[test]
+fn test_assigns_pagination_from_cursor_fields() {
+  let renamed = |name: &str, original: &str, rust_type: TypeRef| FieldDef {
+    serde_attrs: BTreeSet::from([SerdeAttribute::Rename(original.to_string())]),
+    ..message_field(name, rust_type)
+  };
+  let query = |name: &str, original: &str| FieldDef {
+    parameter_location: Some(ParameterLocation::Query),
+    original_name: Some(original.to_string()),
+    ..message_field(name, TypeRef::new("String").with_option())
+  };
+  let operation = |id: &str, response_enum: &str, parameters: Vec<FieldDef>| {
+    OperationInfo::builder()
+      .stable_id(id)
+      .operation_id(id)
+      .method(http::Method::GET)
+      .path(ParsedPath {
+        segments: vec![],
+        query_string: None,
+      })
+      .kind(OperationKind::Http)
+      .request_type(StructToken::new(format!("{response_enum}Request")))
+      .response_enum(EnumToken::new(response_enum))
+      .parameters(parameters)
+      .build()
+  };
+  let types = vec![
+    RustType::Struct(create_struct("Pet", StructKind::Schema, false)),
+    error_body(
+      "PetPage",
+      vec![
+        message_field("pets", TypeRef::new("Pet").with_vec()),
+        renamed("next_page_token", "nextPageToken", TypeRef::new("String").with_option()),
+      ],
+      None,
+    ),
+    error_body(
+      "OwnerPage",
+      vec![
+        message_field("value", TypeRef::new("String").with_vec().with_option()),
+        renamed(
+          "odata_next_link",
+          "@odata.nextLink",
+          TypeRef::new("String").with_option(),
+        ),
+      ],
+      None,
+    ),
+    error_body(
+      "ToyPage",
+      vec![
+        message_field("data", TypeRef::new("String").with_vec()),
+        message_field("after", TypeRef::new("String")),
+      ],
+      None,
+    ),
+    response_enum("ListPetsResponse", &[(StatusCodeToken::Ok200, Some("PetPage"))]),
+    response_enum(
+      "ListOwnersResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("OwnerPage")),
+        (StatusCodeToken::Default, None),
+      ],
+    ),
+    response_enum(
+      "ListToysResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("ToyPage")),
+        (StatusCodeToken::NotFound404, None),
+      ],
+    ),
+  ];
+  let mut toys = operation("list_toys", "ListToysResponse", vec![query("cursor", "cursor")]);
+  toys.pagination_hint = Some(PaginationHint {
+    cursor: "after".to_string(),
+    param: Some("cursor".to_string()),
+    items: None,
+  });
+  let operations = vec![
+    operation("list_pets", "ListPetsResponse", vec![query("page_token", "pageToken")]),
+    operation("list_owners", "ListOwnersResponse", vec![]),
+    toys,
+  ];
+  let cursors = ["next_page_token".to_string(), "@odata.nextLink".to_string()];
+
+  let output = PostprocessOutput::new(
+    types.clone(),
+    operations.clone(),
+    BTreeMap::new(),
+    GenerationTarget::Client,
+    vec![],
+    &cursors,
+  );
+
+  let pets = output.operations[0].pagination.as_ref().expect("pets are paginated");
+  assert_eq!(pets.items, Some(FieldNameToken::new("pets")));
+  assert_eq!(pets.item_type, TypeRef::new("Pet"));
+  assert_eq!(
+    pets.cursor,
+    PageCursor::Field {
+      name: FieldNameToken::new("next_page_token"),
+      optional: true,
+    }
+  );
+  assert!(!pets.other_variants);
+  assert_eq!(
+    pets.next_page,
+    NextPage::Query {
+      field: FieldNameToken::new("page_token"),
+      optional: true,
+    },
+    "nextPageToken is sent back as pageToken"
+  );
+
+  let owners = output.operations[1].pagination.as_ref().expect("owners are paginated");
+  assert!(owners.items_optional);
+  assert!(owners.other_variants);
+  assert_eq!(
+    owners.next_page,
+    NextPage::Link,
+    "a link cursor without a matching parameter is followed"
+  );
+
+  let toys = output.operations[2]
+    .pagination
+    .as_ref()
+    .expect("the extension names the cursor");
+  assert_eq!(toys.items, Some(FieldNameToken::new("data")));
+  assert_eq!(
+    toys.cursor,
+    PageCursor::Field {
+      name: FieldNameToken::new("after"),
+      optional: false,
+    }
+  );
+
+  let extension_only = PostprocessOutput::new(
+    types,
+    operations,
+    BTreeMap::new(),
+    GenerationTarget::Client,
+    vec![],
+    &[],
+  );
+  assert!(extension_only.operations[0].pagination.is_none());
+  assert!(extension_only.operations[1].pagination.is_none());
+  assert!(
+    extension_only.operations[2].pagination.is_some(),
+    "x-pagination applies without detection"
+  );
+}
+
+#[test]
+fn test_assigns_pagination_from_link_headers() {
+  let operation = |id: &str, method: http::Method, response_enum: &str, page_link: bool| {
+    OperationInfo::builder()
+      .stable_id(id)
+      .operation_id(id)
+      .method(method)
+      .path(ParsedPath {
+        segments: vec![],
+        query_string: None,
+      })
+      .kind(OperationKind::Http)
+      .request_type(StructToken::new(format!("{response_enum}Request")))
+      .response_enum(EnumToken::new(response_enum))
+      .page_link(page_link)
+      .build()
+  };
+  let array_response = |name: &str| {
+    RustType::ResponseEnum(
+      ResponseEnumDef::builder()
+        .name(EnumToken::new(name))
+        .variants(vec![
+          ResponseVariant::builder()
+            .variant_name(StatusCodeToken::Ok200.to_variant_token())
+            .status_code(StatusCodeToken::Ok200)
+            .schema_type(TypeRef::new("Repo").with_vec())
+            .build(),
+        ])
+        .build(),
+    )
+  };
+  let types = vec![
+    RustType::Struct(create_struct("Repo", StructKind::Schema, false)),
+    error_body(
+      "RunPage",
+      vec![
+        message_field("total_count", TypeRef::new("i64")),
+        message_field("workflow_runs", TypeRef::new("Repo").with_vec()),
+      ],
+      None,
+    ),
+    array_response("ListReposResponse"),
+    response_enum(
+      "ListRunsResponse",
+      &[
+        (StatusCodeToken::Ok200, Some("RunPage")),
+        (StatusCodeToken::Default, None),
+      ],
+    ),
+    array_response("SearchReposResponse"),
+    array_response("ListForksResponse"),
+  ];
+  let types = types
+    .into_iter()
+    .map(|mut rt| {
+      if let RustType::ResponseEnum(def) = &mut rt
+        && def.name != "ListForksResponse"
+      {
+        for variant in &mut def.variants {
+          variant.page_links = variant.status_code == StatusCodeToken::Ok200;
+        }
+      }
+      rt
+    })
+    .collect::<Vec<_>>();
+  let operations = vec![
+    operation("list_repos", http::Method::GET, "ListReposResponse", true),
+    operation("list_runs", http::Method::GET, "ListRunsResponse", true),
+    operation("search_repos", http::Method::POST, "SearchReposResponse", true),
+    operation("list_forks", http::Method::GET, "ListForksResponse", false),
+  ];
+
+  let output = PostprocessOutput::new(
+    types.clone(),
+    operations.clone(),
+    BTreeMap::new(),
+    GenerationTarget::Client,
+    vec![],
+    &["next_page_token".to_string()],
+  );
+
+  let pagination = |output: &PostprocessOutput, id: &str| {
+    output
+      .operations
+      .iter()
+      .find(|op| op.stable_id == id)
+      .and_then(|op| op.pagination.clone())
+  };
+
+  let repos = pagination(&output, "list_repos").expect("repos are paginated");
+  assert_eq!(repos.items, None, "the body is the items array");
+  assert_eq!(repos.item_type, TypeRef::new("Repo"));
+  assert_eq!(repos.cursor, PageCursor::LinkHeader);
+  assert_eq!(repos.next_page, NextPage::Link);
+  assert!(repos.page_links, "the page variant carries its links");
+
+  let runs = pagination(&output, "list_runs").expect("runs are paginated");
+  assert_eq!(runs.items, Some(FieldNameToken::new("workflow_runs")));
+  assert_eq!(runs.cursor, PageCursor::LinkHeader);
+
+  assert!(
+    pagination(&output, "search_repos").is_none(),
+    "only GET operations follow links"
+  );
+  assert!(
+    pagination(&output, "list_forks").is_none(),
+    "a Link header must be declared"
+  );
+
+  let extension_only = PostprocessOutput::new(
+    types,
+    operations,
+    BTreeMap::new(),
+    GenerationTarget::Client,
+    vec![],
+    &[],
+  );
+  assert!(pagination(&extension_only, "list_repos").is_none());
+}
+
+#[test]
+fn test_parent_conversions_between_all_of_structs() {
+  let field = |name: &str, rust_type: TypeRef| {
+    FieldDef::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(rust_type)
+      .build()
+  };
+  let schema_struct = |name: &str, fields: Vec<FieldDef>| StructDef {
+    name: StructToken::new(name),
+    fields,
+    kind: StructKind::Schema,
+    parents: vec![StructToken::new("Entity")],
+    ..Default::default()
+  };
+
+  let entity = StructDef {
+    parents: vec![],
+    ..schema_struct(
+      "Entity",
+      vec![field("id", TypeRef::new("i64")), field("name", TypeRef::new("String"))],
+    )
+  };
+  let user = schema_struct(
+    "User",
+    vec![
+      field("id", TypeRef::new("i64")),
+      field("name", TypeRef::new("String")),
+      field("nickname", TypeRef::new("String").with_option()),
+    ],
+  );
+  let member = schema_struct(
+    "Member",
+    vec![
+      field("id", TypeRef::new("i64")),
+      field("name", TypeRef::new("String")),
+      field("joined", TypeRef::new("String")),
+    ],
+  );
+  let renamed = schema_struct(
+    "Renamed",
+    vec![
+      field("id", TypeRef::new("i64")),
+      field("name", TypeRef::new("String").with_option()),
+    ],
+  );
+
+  let types = [entity, user, member, renamed]
+    .into_iter()
+    .map(RustType::Struct)
+    .collect::<Vec<_>>();
+  let output = PostprocessOutput::new(types, vec![], BTreeMap::new(), GenerationTarget::Client, vec![], &[]);
+  let conversions = |name: &str| {
+    output
+      .types
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) if def.name == name => Some(def.parent_conversions.clone()),
+        _ => None,
+      })
+      .unwrap()
+  };
+
+  assert_eq!(
+    conversions("User"),
+    vec![ParentConversion {
+      parent: StructToken::new("Entity"),
+      shared_fields: vec![
+        (FieldNameToken::new("id"), TypeRef::new("i64")),
+        (FieldNameToken::new("name"), TypeRef::new("String")),
+      ],
+      covers_parent: true,
+      from_parent: true,
+      covers_child: false,
+    }]
+  );
+  assert!(
+    !conversions("Member")[0].from_parent,
+    "a required child-only field cannot be filled from the parent"
+  );
+  assert!(
+    conversions("Renamed").is_empty(),
+    "a shared field with a different type prevents conversions"
+  );
+  assert!(conversions("Entity").is_empty());
+}
+
+#[test]
+fn test_resolves_parameter_defaults_for_builders() {
+  let param = |name: &str, rust_type: TypeRef, default: Option<serde_json::Value>| {
+    FieldDef::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(rust_type)
+      .maybe_default_value(default)
+      .build()
+  };
+  let query = StructDef {
+    name: StructToken::new("ListPetsRequestQuery"),
+    fields: vec![
+      param("limit", TypeRef::new("i32").with_option(), Some(serde_json::json!(20))),
+      param("sort", TypeRef::new("PetSort"), Some(serde_json::json!("desc"))),
+      param(
+        "since",
+        TypeRef::new("chrono::NaiveDate"),
+        Some(serde_json::json!("2024-01-01")),
+      ),
+      param("q", TypeRef::new("String").with_option(), None),
+    ],
+    kind: StructKind::QueryParams,
+    ..Default::default()
+  };
+  let sort = EnumDef {
+    name: EnumToken::new("PetSort"),
+    variants: ["Asc", "Desc"]
+      .into_iter()
+      .map(|name| {
+        VariantDef::builder()
+          .name(EnumVariantToken::new(name))
+          .content(VariantContent::Unit)
+          .serde_attrs(vec![SerdeAttribute::Rename(name.to_lowercase())])
+          .build()
+      })
+      .collect(),
+    ..Default::default()
+  };
+  let query_field = FieldDef::builder()
+    .name(FieldNameToken::new("query"))
+    .rust_type(TypeRef::new("ListPetsRequestQuery"))
+    .build();
+  let request = StructDef {
+    name: StructToken::new("ListPetsRequest"),
+    methods: MethodGenerator::build_builder_method(
+      std::slice::from_ref(&query),
+      std::slice::from_ref(&query_field),
+      &[],
+    )
+    .into_iter()
+    .collect(),
+    fields: vec![query_field],
+    kind: StructKind::OperationRequest,
+    ..Default::default()
+  };
+
+  let types = vec![RustType::Struct(request), RustType::Struct(query), RustType::Enum(sort)];
+  let output = PostprocessOutput::new(types, vec![], BTreeMap::new(), GenerationTarget::Client, vec![], &[]);
+  let find_struct = |name: &str| {
+    output
+      .types
+      .iter()
+      .find_map(|ty| match ty {
+        RustType::Struct(def) if def.name == name => Some(def.clone()),
+        _ => None,
+      })
+      .unwrap()
+  };
+
+  let query = find_struct("ListPetsRequestQuery");
+  assert_eq!(
+    query.fields[1].default_variant,
+    Some(EnumVariantToken::new("Desc")),
+    "an enum default resolves to the variant it names"
+  );
+  assert_eq!(query.fields[2].default_variant, None);
+
+  let request = find_struct("ListPetsRequest");
+  let MethodKind::Builder { fields, .. } = &request.methods[0].kind else {
+    panic!("expected a builder method");
+  };
+  let defaulted = fields
+    .iter()
+    .filter(|field| field.has_default)
+    .map(|field: &BuilderField| field.name.to_string())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    defaulted,
+    ["limit", "sort"],
+    "defaults without an exact `Default` value stay required"
+  );
+}
+
+#[test]
+fn test_skip_oversized_clones_propagates_to_holders() {
+  let field = |name: &str, rust_type: &str| {
+    FieldDef::builder()
+      .name(FieldNameToken::new(name))
+      .rust_type(TypeRef::new(rust_type))
+      .build()
+  };
+  let big = StructDef {
+    name: StructToken::new("Big"),
+    fields: vec![field("a", "String"), field("b", "String"), field("c", "String")],
+    ..Default::default()
+  };
+  let holder = StructDef {
+    name: StructToken::new("Holder"),
+    fields: vec![field("items", "IndexMap<String, Big>")],
+    ..Default::default()
+  };
+  let wrapper = EnumDef {
+    name: EnumToken::new("Wrapper"),
+    variants: vec![
+      VariantDef::builder()
+        .name(EnumVariantToken::new("Holder"))
+        .content(VariantContent::Tuple(vec![TypeRef::new("Holder")]))
+        .build(),
+    ],
+    ..Default::default()
+  };
+  let small = StructDef {
+    name: StructToken::new("Small"),
+    fields: vec![field("a", "String")],
+    ..Default::default()
+  };
+
+  let mut types = vec![
+    RustType::Struct(small),
+    RustType::Enum(wrapper),
+    RustType::Struct(holder),
+    RustType::Struct(big),
+    response_enum("GetBigResponse", &[(StatusCodeToken::Ok200, Some("Big"))]),
+    response_enum("GetSmallResponse", &[(StatusCodeToken::Ok200, Some("Small"))]),
+  ];
+  let skipped = skip_oversized_clones(&mut types, 2);
+
+  assert_eq!(
+    skipped,
+    ["Wrapper", "Holder", "Big", "GetBigResponse"],
+    "types holding an oversized struct, even through a map, lose `Clone` too"
+  );
+  let clones = types
+    .iter()
+    .map(|rust_type| {
+      let derives = match rust_type {
+        RustType::Struct(def) => def.derives(),
+        RustType::Enum(def) => def.derives(),
+        RustType::ResponseEnum(def) => def.derives(),
+        _ => unreachable!(),
+      };
+      derives.contains(&DeriveTrait::Clone)
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(clones, [true, false, false, false, false, true]);
+}
@@ -1,10 +1,14 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use indexmap::{IndexMap, map::Entry};
 
 use crate::generator::{
-  ast::{MethodKind, RustType, SerdeImpl, ValidationAttribute},
+  ast::{
+    Documentation, EnumToken, MethodKind, OperationInfo, RustType, SerdeImpl, StructKind, StructToken,
+    ValidationAttribute,
+  },
   converter::GenerationTarget,
+  postprocess::renames::rename_type_references,
 };
 
 pub(crate) struct RustTypeDeduplication {
@@ -37,6 +41,109 @@ impl RustTypeDeduplication {
 
     map.into_values().collect::<Vec<_>>()
   }
+
+  /// Merges inline structs and enums that differ only in their names and docs into one
+  /// type, pointing every reference in the types and `operations` at the survivor.
+  ///
+  /// Types named in `keep`, the component schemas, are never merged away; a group with
+  /// one of them keeps it, and other groups keep the shortest name. Merging repeats
+  /// until no group is left, since renamed references can make their owners identical.
+  /// Returns the merged types and the type each one was merged into.
+  pub(crate) fn merge_identical(
+    self,
+    operations: &mut [OperationInfo],
+    keep: &BTreeSet<String>,
+  ) -> (Vec<RustType>, BTreeMap<String, String>) {
+    let mut types = self.types;
+    let mut merged = BTreeMap::<String, String>::new();
+
+    loop {
+      let replacements = Self::identical_groups(&types, keep);
+      if replacements.is_empty() {
+        break;
+      }
+      types.retain(|rust_type| !replacements.contains_key(rust_type.type_name().as_ref()));
+      rename_type_references(&mut types, operations, &replacements);
+      for target in merged.values_mut() {
+        if let Some(renamed) = replacements.get(target) {
+          target.clone_from(renamed);
+        }
+      }
+      merged.extend(replacements);
+    }
+
+    (types, merged)
+  }
+
+  fn identical_groups(types: &[RustType], keep: &BTreeSet<String>) -> BTreeMap<String, String> {
+    let mut groups = Vec::<(RustType, Vec<String>)>::new();
+    for rust_type in types {
+      let Some(shape) = Self::shape(rust_type) else {
+        continue;
+      };
+      let name = rust_type.type_name().to_string();
+      match groups.iter_mut().find(|(other, _)| *other == shape) {
+        Some((_, names)) => names.push(name),
+        None => groups.push((shape, vec![name])),
+      }
+    }
+
+    let mut replacements = BTreeMap::new();
+    for (_, names) in groups.into_iter().filter(|(_, names)| names.len() > 1) {
+      let Some(canonical) = names
+        .iter()
+        .min_by_key(|name| (!keep.contains(*name), name.len(), name.as_str()))
+        .cloned()
+      else {
+        continue;
+      };
+      for name in names {
+        if name != canonical && !keep.contains(&name) {
+          replacements.insert(name, canonical.clone());
+        }
+      }
+    }
+    replacements
+  }
+
+  /// Returns the type with its name and docs cleared, for comparing structure, or `None`
+  /// for types that are never merged.
+  fn shape(rust_type: &RustType) -> Option<RustType> {
+    let mut shape = rust_type.clone();
+    match &mut shape {
+      RustType::Struct(def) => {
+        if def.kind != StructKind::Schema
+          || def
+            .methods
+            .iter()
+            .any(|m| !matches!(m.kind, MethodKind::Builder { .. }))
+        {
+          return None;
+        }
+        def.name = StructToken::new("");
+        def.docs = Documentation::default();
+        for field in &mut def.fields {
+          field.docs = Documentation::default();
+          field.example_value = None;
+        }
+        for method in &mut def.methods {
+          method.docs = Documentation::default();
+        }
+      }
+      RustType::Enum(def) => {
+        def.name = EnumToken::new("");
+        def.docs = Documentation::default();
+        for variant in &mut def.variants {
+          variant.docs = Documentation::default();
+        }
+        for method in &mut def.methods {
+          method.docs = Documentation::default();
+        }
+      }
+      _ => return None,
+    }
+    Some(shape)
+  }
 }
 
 pub(crate) struct ModuleImports {
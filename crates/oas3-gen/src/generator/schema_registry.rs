@@ -264,7 +264,7 @@ impl SchemaRegistry {
   ///
   /// If the schema has an explicit `mapping`, returns it directly. Otherwise,
   /// reconstructs the mapping from the discriminator cache (which may contain
-  /// mappings synthesized from `const` values). The returned map is in the
+  /// mappings synthesized from `const` values or schema names). The returned map is in the
   /// format expected by OpenAPI: `(discriminator_value -> $ref_path)`.
   ///
   /// Returns `None` if no mapping (explicit or implicit) is available.
@@ -300,6 +300,16 @@ impl SchemaRegistry {
     self.cyclic_schemas.contains(schema_name)
   }
 
+  /// Returns the schemas `schema_name` references directly.
+  pub(crate) fn dependencies(&self, schema_name: &str) -> impl Iterator<Item = &String> {
+    self.dependencies.get(schema_name).into_iter().flatten()
+  }
+
+  /// Returns the number of schemas participating in dependency cycles.
+  pub(crate) fn cyclic_schema_count(&self) -> usize {
+    self.cyclic_schemas.len()
+  }
+
   /// Creates a type reference for a named schema, applying `Box` wrapping if the
   /// schema participates in a dependency cycle.
   pub(crate) fn type_ref(&self, schema_name: &str) -> TypeRef {
@@ -366,7 +376,7 @@ impl SchemaRegistry {
   /// Recursively collects all named schema references from a schema.
   ///
   /// Traverses properties, composition keywords (`all_of`, `one_of`, `any_of`),
-  /// array items, and inline objects to identify every schema that the
+  /// array items and `prefixItems`, and inline objects to identify every schema that the
   /// given schema depends on.
   ///
   /// Union fingerprints are used to identify named union types that may
@@ -402,7 +412,7 @@ impl SchemaRegistry {
       }
     }
 
-    if let Some(items) = schema.items.as_deref() {
+    for items in schema.items.as_deref().into_iter().chain(&schema.prefix_items) {
       if let Some(ref_name) = items.schema_ref_name() {
         refs.insert(ref_name);
       }
@@ -633,8 +643,8 @@ impl SchemaRegistry {
   ///
   /// Handles two cases:
   /// 1. Explicit mappings from the discriminator's `mapping` field
-  /// 2. Implicit mappings inferred from `const` values on the discriminator
-  ///    property in each `oneOf`/`anyOf` variant schema
+  /// 2. Implicit mappings for each `oneOf`/`anyOf` variant schema, from a `const`
+  ///    value on its discriminator property or else from its schema name
   fn build_discriminator_cache(
     schemas: &SchemaMap,
     stats: &mut GenerationStats,
@@ -667,12 +677,12 @@ impl SchemaRegistry {
     cache
   }
 
-  /// Synthesizes discriminator mappings from `const` values on variant schemas.
+  /// Synthesizes discriminator mappings for the variants of a union without a `mapping`.
   ///
-  /// When a discriminator has no explicit `mapping`, examines each `oneOf`/`anyOf`
-  /// variant's discriminator property for a `const` value. All variants must have
-  /// a unique string `const` value for synthesis to succeed. Records a warning
-  /// and skips synthesis on any failure (missing const, non-string const, duplicates).
+  /// Each `oneOf`/`anyOf` variant is identified by the string `const` value of its
+  /// discriminator property, or, as the OpenAPI implicit mapping specifies, by its
+  /// schema name. Values must be unique for synthesis to succeed. Records a warning
+  /// and skips synthesis on any failure (missing variant schema, duplicates).
   fn synthesize_implicit_mappings(
     parent_name: &str,
     schema: &ObjectSchema,
@@ -697,18 +707,12 @@ impl SchemaRegistry {
         return;
       };
 
-      let Some(const_value) = Self::extract_const_discriminator_value(variant_schema, &discriminator.property_name)
-      else {
-        stats.record_warning(warn(format!(
-          "cannot build implicit discriminator mapping: variant '{variant_name}' has no string const value for property '{}'",
-          discriminator.property_name
-        )));
-        return;
-      };
+      let value = Self::extract_const_discriminator_value(variant_schema, &discriminator.property_name)
+        .unwrap_or_else(|| variant_name.clone());
 
-      if !seen_values.insert(const_value.clone()) {
+      if !seen_values.insert(value.clone()) {
         stats.record_warning(warn(format!(
-          "cannot build implicit discriminator mapping: duplicate const value '{const_value}' on variant '{variant_name}'"
+          "cannot build implicit discriminator mapping: duplicate value '{value}' on variant '{variant_name}'"
         )));
         return;
       }
@@ -717,7 +721,7 @@ impl SchemaRegistry {
         variant_name,
         DiscriminatorMapping {
           field_name: discriminator.property_name.clone(),
-          field_value: const_value,
+          field_value: value,
         },
       );
     }
@@ -0,0 +1,94 @@
+use super::support::{make_orchestrator, parse_spec};
+use crate::generator::dependency_graph::DependencyGraph;
+
+const SPEC: &str = r##"{
+  "openapi": "3.1.0",
+  "info": {"title": "Graph", "version": "1.0"},
+  "paths": {
+    "/folders": {
+      "get": {
+        "operationId": "listFolders",
+        "responses": {
+          "200": {
+            "description": "ok",
+            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Folder"}}}
+          },
+          "404": {
+            "description": "missing",
+            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Problem"}}}
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Folder": {
+        "type": "object",
+        "properties": {
+          "files": {"type": "array", "items": {"$ref": "#/components/schemas/File"}},
+          "parent": {"$ref": "#/components/schemas/Folder"}
+        }
+      },
+      "File": {"type": "object", "properties": {"name": {"type": "string"}}},
+      "Problem": {"type": "object", "properties": {"message": {"type": "string"}}}
+    }
+  }
+}"##;
+
+fn graph() -> DependencyGraph {
+  make_orchestrator(parse_spec(SPEC), true).dependency_graph()
+}
+
+#[test]
+fn dependency_graph_marks_cycles_and_error_schemas() {
+  let graph = graph();
+  let node = |name: &str| {
+    graph
+      .nodes
+      .iter()
+      .position(|node| node.name == name)
+      .unwrap_or_else(|| panic!("missing node for {name}"))
+  };
+  let (folder, file, problem) = (node("Folder"), node("File"), node("Problem"));
+
+  assert!(graph.nodes[folder].cycle.is_some(), "self-referencing schema is cyclic");
+  assert!(graph.nodes[file].cycle.is_none(), "File is not cyclic");
+  assert!(graph.nodes[problem].error, "404 body is an error schema");
+  assert!(!graph.nodes[folder].error, "200 body is not an error schema");
+
+  let mut edges = graph.edges.clone();
+  edges.sort_unstable();
+  let mut expected = vec![(folder, file), (folder, folder)];
+  expected.sort_unstable();
+  assert_eq!(edges, expected);
+  assert!(graph.is_cycle_edge((folder, folder)));
+  assert!(!graph.is_cycle_edge((folder, file)));
+}
+
+#[test]
+fn dependency_graph_renders_mermaid_and_dot() {
+  let graph = graph();
+
+  let mermaid = graph.to_mermaid();
+  assert!(mermaid.starts_with("flowchart LR\n"), "{mermaid}");
+  for snippet in [
+    "n0[\"Folder\"]",
+    "n0 ==> n0",
+    "n0 --> n1",
+    "class n0 cyclic",
+    "class n2 error",
+  ] {
+    assert!(mermaid.contains(snippet), "missing `{snippet}` in\n{mermaid}");
+  }
+
+  let dot = graph.to_dot();
+  assert!(dot.starts_with("digraph schemas {\n"), "{dot}");
+  for snippet in [
+    "\"Folder\" -> \"Folder\" [color=\"#d93025\", penwidth=2];",
+    "\"Folder\" -> \"File\";",
+    "\"Problem\" [style=filled, fillcolor=\"#fde2e1\"];",
+  ] {
+    assert!(dot.contains(snippet), "missing `{snippet}` in\n{dot}");
+  }
+}
@@ -0,0 +1,179 @@
+use super::support::{make_orchestrator, parse_spec};
+use crate::generator::{
+  inventory::{SchemaKind, SchemaUsage, SpecStatistics, TagSummary, UNTAGGED, summarize_tags},
+  operation_registry::OperationRegistry,
+};
+
+const SPEC: &str = r##"{
+  "openapi": "3.1.0",
+  "info": {"title": "Inventory", "version": "1.0"},
+  "tags": [
+    {"name": "store", "description": "Orders"},
+    {"name": "admin"}
+  ],
+  "paths": {
+    "/orders": {
+      "post": {
+        "operationId": "createOrder",
+        "tags": ["store"],
+        "requestBody": {
+          "required": true,
+          "content": {"application/json": {"schema": {"$ref": "#/components/schemas/NewOrder"}}}
+        },
+        "responses": {
+          "200": {
+            "description": "ok",
+            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Order"}}}
+          }
+        }
+      },
+      "get": {
+        "operationId": "listOrders",
+        "tags": ["store", "reports"],
+        "responses": {
+          "200": {
+            "description": "ok",
+            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Orders"}}}
+          }
+        }
+      }
+    },
+    "/health": {
+      "get": {"operationId": "health", "responses": {"204": {"description": "ok"}}}
+    }
+  },
+  "components": {
+    "schemas": {
+      "NewOrder": {
+        "type": "object",
+        "properties": {"status": {"$ref": "#/components/schemas/Status"}}
+      },
+      "Order": {
+        "type": "object",
+        "properties": {
+          "status": {"$ref": "#/components/schemas/Status"},
+          "parent": {"$ref": "#/components/schemas/Order"}
+        }
+      },
+      "Orders": {"type": "array", "items": {"$ref": "#/components/schemas/Order"}},
+      "Status": {"type": "string", "enum": ["open", "closed"]},
+      "Unreferenced": {"type": "object", "properties": {"id": {"type": "string"}}}
+    }
+  }
+}"##;
+
+#[test]
+fn schema_summaries_report_kind_cycles_and_usage() {
+  let summaries = make_orchestrator(parse_spec(SPEC), true).schema_summaries();
+  let summary = |name: &str| {
+    summaries
+      .iter()
+      .find(|summary| summary.name == name)
+      .unwrap_or_else(|| panic!("missing summary for {name}"))
+  };
+
+  let cases = [
+    ("NewOrder", SchemaKind::Struct, false, SchemaUsage::Request),
+    ("Order", SchemaKind::Struct, true, SchemaUsage::Response),
+    ("Orders", SchemaKind::Alias, false, SchemaUsage::Response),
+    ("Status", SchemaKind::Enum, false, SchemaUsage::Both),
+    ("Unreferenced", SchemaKind::Struct, false, SchemaUsage::Unused),
+  ];
+  for (name, kind, cyclic, usage) in cases {
+    let summary = summary(name);
+    assert_eq!(summary.kind, Some(kind), "{name} kind");
+    assert_eq!(summary.cyclic, cyclic, "{name} cycle membership");
+    assert_eq!(summary.usage, usage, "{name} usage");
+  }
+  assert_eq!(summaries.len(), cases.len());
+}
+
+#[test]
+fn summarize_tags_counts_operations_per_tag() {
+  let spec = parse_spec(SPEC);
+  let tags = summarize_tags(&spec, &OperationRegistry::new(&spec));
+
+  let tag = |name: &str, description: Option<&str>, operations, primary| TagSummary {
+    name: name.to_string(),
+    description: description.map(ToString::to_string),
+    operations,
+    primary,
+  };
+  assert_eq!(
+    tags,
+    [
+      tag("store", Some("Orders"), 2, 2),
+      tag("admin", None, 0, 0),
+      tag("reports", None, 1, 0),
+      tag(UNTAGGED, None, 1, 1),
+    ]
+  );
+}
+
+#[test]
+fn spec_statistics_measure_nesting_unions_and_cycles() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Stats", "version": "1.0"},
+      "paths": {
+        "/shapes": {
+          "get": {
+            "operationId": "listShapes",
+            "responses": {
+              "200": {
+                "description": "ok",
+                "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Drawing"}}}
+              }
+            }
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "Drawing": {
+            "type": "object",
+            "properties": {
+              "canvas": {
+                "type": "object",
+                "properties": {
+                  "fill": {"anyOf": [{"type": "string"}, {"type": "integer"}]}
+                }
+              },
+              "shape": {"$ref": "#/components/schemas/Shape"}
+            }
+          },
+          "Shape": {
+            "oneOf": [
+              {"$ref": "#/components/schemas/Circle"},
+              {"$ref": "#/components/schemas/Group"}
+            ]
+          },
+          "Circle": {"type": "object", "properties": {"radius": {"type": "number"}}},
+          "Group": {
+            "type": "object",
+            "properties": {"children": {"type": "array", "items": {"$ref": "#/components/schemas/Shape"}}}
+          }
+        }
+      }
+    }"##,
+  );
+  let stats = make_orchestrator(spec, false).spec_statistics();
+
+  assert_eq!(
+    SpecStatistics {
+      projected_types: 0,
+      ..stats
+    },
+    SpecStatistics {
+      schemas: 4,
+      operations: 1,
+      average_nesting_depth: 2.25,
+      one_of_unions: 1,
+      any_of_unions: 1,
+      cyclic_schemas: 2,
+      projected_types: 0,
+    }
+  );
+  assert!(stats.projected_types >= 4, "every schema should project a type");
+}
@@ -1,3 +1,5 @@
+mod dependency_graph;
+mod inventory;
 mod operation_registry;
 mod orchestrator;
 mod schema_graph;
@@ -8,7 +8,7 @@ use super::support::parse_spec;
 use crate::generator::{
   ast::OperationKind,
   naming::operations::{compute_stable_id, generate_operation_id},
-  operation_registry::OperationRegistry,
+  operation_registry::{OperationFilter, OperationRegistry},
 };
 
 type TestOperation<'a> = (&'a str, &'a str, Option<&'a str>);
@@ -63,7 +63,7 @@ fn test_compute_stable_id() {
     ("GET", "/users/{id}", Some("getUserById"), "get_user_by_id"),
     ("GET", "/users/{id}", None, "get_users_by_id"),
     ("GET", "/user-profile", Some("user-profile.get"), "user_profile_get"),
-    ("GET", "/v2/users", Some("v2GetUsers"), "v2get_users"),
+    ("GET", "/v2/users", Some("v2GetUsers"), "v2_get_users"),
     ("GET", "/type", Some("type"), "r#type"),
   ];
 
@@ -216,6 +216,54 @@ fn test_operation_registry_applies_exclude_filters() {
   );
 }
 
+#[test]
+fn test_operation_registry_applies_tag_and_path_filters() {
+  let spec = parse_spec(
+    r#"{
+      "openapi": "3.1.0",
+      "info": {"title": "Filter API", "version": "1.0.0"},
+      "paths": {
+        "/users": {"get": {"operationId": "listUsers", "tags": ["users"]}},
+        "/users/{id}": {"get": {"operationId": "getUser", "tags": ["users", "admin"]}},
+        "/usersettings": {"get": {"operationId": "getSettings", "tags": ["settings"]}},
+        "/orders": {"get": {"operationId": "listOrders"}}
+      },
+      "webhooks": {
+        "userAdded": {"post": {"operationId": "userAddedHook", "tags": ["users"]}}
+      }
+    }"#,
+  );
+
+  let tags = HashSet::from(["users".to_string()]);
+  let registry = OperationRegistry::with_operation_filter(&spec, OperationFilter::default().with_tags(Some(&tags)));
+  assert_stable_ids(
+    &registry,
+    &["get_user", "list_users", "user_added_hook"],
+    "any matching tag should include the operation",
+  );
+
+  let paths = ["/users/".to_string(), "/orders".to_string()];
+  let registry = OperationRegistry::with_operation_filter(&spec, OperationFilter::default().with_paths(Some(&paths)));
+  assert_stable_ids(
+    &registry,
+    &["get_user", "list_orders", "list_users"],
+    "path prefixes should match whole segments",
+  );
+
+  let excluded = HashSet::from(["get_user".to_string()]);
+  let registry = OperationRegistry::with_operation_filter(
+    &spec,
+    OperationFilter::new(None, Some(&excluded))
+      .with_tags(Some(&tags))
+      .with_paths(Some(&paths)),
+  );
+  assert_stable_ids(
+    &registry,
+    &["list_users"],
+    "filters should combine and exclusion should win",
+  );
+}
+
 #[test]
 fn test_operation_registry_includes_webhooks() {
   let spec = parse_spec(
@@ -244,6 +292,63 @@ fn test_operation_registry_includes_webhooks() {
   assert_eq!(entry.method, Method::POST);
 }
 
+#[test]
+fn test_operation_registry_includes_callbacks() {
+  let spec = parse_spec(
+    r#"{
+      "openapi": "3.1.0",
+      "info": {"title": "Callback API", "version": "1.0.0"},
+      "paths": {
+        "/subscriptions": {
+          "post": {
+            "operationId": "apiCreateSubscription",
+            "responses": {"201": {"description": "created"}},
+            "callbacks": {
+              "onEvent": {
+                "{$request.body#/callbackUrl}": {
+                  "post": {"responses": {"204": {"description": "received"}}}
+                }
+              }
+            }
+          }
+        },
+        "/pets": {
+          "get": {"operationId": "apiListPets", "responses": {"200": {"description": "ok"}}}
+        }
+      }
+    }"#,
+  );
+
+  let registry = OperationRegistry::new(&spec);
+  let callback = registry
+    .operations()
+    .find(|entry| entry.kind == OperationKind::Callback)
+    .expect("callback operation should exist");
+  assert_eq!(callback.stable_id, "create_subscription_on_event");
+  assert_eq!(callback.path, "callbacks/onEvent");
+  assert_eq!(callback.method, Method::POST);
+  assert_eq!(
+    callback.callback_of.as_deref(),
+    Some("create_subscription"),
+    "parent id should follow affix simplification"
+  );
+
+  let only = HashSet::from(["api_create_subscription".to_string()]);
+  let filtered = OperationRegistry::with_filters(&spec, Some(&only), None);
+  assert_eq!(
+    sorted_stable_ids(&filtered),
+    ["subscription", "subscription_on_event"],
+    "callbacks should follow their operation through --only"
+  );
+
+  let excluded = HashSet::from(["api_create_subscription_on_event".to_string()]);
+  let filtered = OperationRegistry::with_filters(&spec, None, Some(&excluded));
+  assert!(
+    filtered.operations().all(|entry| entry.kind != OperationKind::Callback),
+    "callbacks should be excludable by name"
+  );
+}
+
 #[test]
 fn test_operation_registry_strips_common_prefix_with_numeric_suffixes() {
   let spec = create_test_spec(&[
@@ -2,9 +2,22 @@ use std::collections::HashMap;
 
 use super::support::{
   assert_contains, assert_contains_all, assert_not_contains, assert_occurs_at_least, generate_types, make_orchestrator,
-  make_orchestrator_with_customizations, make_orchestrator_with_ops, parse_spec, string_set,
+  make_orchestrator_with_config, make_orchestrator_with_customizations, make_orchestrator_with_ops, parse_spec,
+  string_set,
+};
+use crate::generator::{
+  ClientModMode, ClientMode, CodegenConfig, ConversionPolicy, EmitTargets, ExtraDerivePolicy, GenerationMode,
+  GenerationTarget, MalformedResponsePolicy, MockMode, ModuleSplitPolicy, NullabilityPolicy, PreludePolicy,
+  PropertyAccessPolicy, PropertyTestsMode, RequestValidationPolicy, SchemaScope, ServerModMode, ServerMode, TestsMode,
+  TypeMergePolicy, TypesMode, WorkspaceMode,
+  ast::{ClientRootNode, StructToken},
+  codegen::{GeneratedFileType, header_spec_hash, incremental::ModuleCache, workspace::WorkspaceCrates},
+  metrics::GenerationWarning,
+  naming::{
+    casing::{AcronymCase, Casing},
+    hooks::NamingHook,
+  },
 };
-use crate::generator::ast::{ClientRootNode, StructToken};
 
 type PresenceCheck<'a> = (&'a str, usize, &'a str);
 type AbsenceCheck<'a> = (&'a str, &'a str);
@@ -137,6 +150,11 @@ fn test_all_schemas_overrides_operation_filtering() {
     without_all_schemas.orphaned_schemas_count, 2,
     "without all_schemas: 2 orphaned"
   );
+  assert_eq!(
+    without_all_schemas.orphaned_schemas,
+    ["AdminResponse", "UnreferencedSchema"],
+    "without all_schemas: pruned schema names"
+  );
   assert_eq!(
     with_all_schemas.orphaned_schemas_count, 0,
     "with all_schemas: 0 orphaned"
@@ -425,3 +443,1446 @@ fn test_no_customization_no_serde_as() {
     "Frappe struct should not have serde_as outer attribute without customizations"
   );
 }
+
+#[test]
+fn test_malformed_response_variant_only_for_client_target() {
+  let cases = [(GenerationTarget::Client, true), (GenerationTarget::Server, false)];
+
+  for (target, expect_malformed) in cases {
+    let config = CodegenConfig::builder()
+      .target(target)
+      .schema_scope(SchemaScope::All)
+      .malformed_responses(MalformedResponsePolicy::Capture)
+      .build();
+    let orchestrator =
+      make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/petstore.json")), config);
+    let output = generate_types(&orchestrator, "petstore.json");
+    let checks = [
+      "Malformed(oas3_gen_support::MalformedResponse)",
+      "oas3_gen_support::MalformedResponse::json::<Pets>(req)",
+    ];
+
+    for pattern in checks {
+      if expect_malformed {
+        assert_contains(&output.code, pattern, &format!("{target:?} malformed capture"));
+      } else {
+        assert_not_contains(&output.code, pattern, &format!("{target:?} malformed capture"));
+      }
+    }
+  }
+}
+
+#[test]
+fn test_emit_matches_separate_generation_runs() {
+  let orchestrator_for = |target| {
+    let config = CodegenConfig::builder()
+      .target(target)
+      .malformed_responses(MalformedResponsePolicy::Capture)
+      .build();
+    make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/petstore.json")), config)
+  };
+  let separate = |target, mode: &dyn GenerationMode| orchestrator_for(target).generate(mode, "petstore.json").unwrap();
+  let emit = |client, server| {
+    orchestrator_for(GenerationTarget::Client)
+      .generate_emit(EmitTargets { client, server }, "petstore.json")
+      .unwrap()
+  };
+
+  let client = separate(GenerationTarget::Client, &ClientModMode).code;
+  let server = separate(GenerationTarget::Server, &ServerModMode).code;
+  let types = separate(GenerationTarget::Client, &TypesMode).code;
+
+  assert_eq!(emit(true, false).code, client, "client only");
+  assert_eq!(emit(false, true).code, server, "server only");
+
+  let types_only = emit(false, false).code;
+  assert_eq!(
+    types_only.code(&GeneratedFileType::Types),
+    types.code(&GeneratedFileType::Types),
+    "types only"
+  );
+  assert_contains(
+    types_only.code(&GeneratedFileType::Module).unwrap(),
+    "pub use types::*;",
+    "types module re-exports",
+  );
+
+  let both = emit(true, true).code;
+  assert_eq!(both.modules["client"], client, "client submodule");
+  assert_eq!(both.modules["server"], server, "server submodule");
+  let mod_file = both.code(&GeneratedFileType::Module).unwrap();
+  assert_contains_all(
+    mod_file,
+    &[
+      ("pub mod client;", "client module declaration"),
+      ("pub mod server;", "server module declaration"),
+    ],
+  );
+  assert_not_contains(mod_file, "pub use", "nested modules are not glob re-exported");
+}
+
+#[test]
+fn test_server_mode_generates_single_file() {
+  let config = CodegenConfig::builder().target(GenerationTarget::Server).build();
+  let orchestrator = make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/petstore.json")), config);
+  let output = orchestrator.generate(&ServerMode, "petstore.json").unwrap();
+  let code = output.code.code(&GeneratedFileType::Server).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      ("pub struct Pet", "types are inlined"),
+      (
+        "impl IntoResponse for ShowPetByIdResponse",
+        "response enums map to IntoResponse",
+      ),
+      ("pub trait ApiServer", "handler trait"),
+      ("Path(path): Path<ShowPetByIdRequestPath>", "typed path extractor"),
+      ("Query(query): Query<ListCatsRequestQuery>", "typed query extractor"),
+      ("pub fn router<S>(service: S) -> Router", "router"),
+    ],
+  );
+  assert_not_contains(code, "use super::types::*;", "no sibling types module");
+  assert_eq!(
+    code.matches("IntoResponse;").count() + code.matches("response::IntoResponse,").count(),
+    1,
+    "IntoResponse is imported once"
+  );
+}
+
+#[test]
+fn test_mock_mode_answers_event_streams_with_an_error() {
+  let config = CodegenConfig::builder().target(GenerationTarget::Server).build();
+  let orchestrator =
+    make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/event_stream.json")), config);
+  let output = orchestrator.generate(&MockMode, "event_stream.json").unwrap();
+  let code = output.code.code(&GeneratedFileType::Server).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      (
+        "Ok(axum::response::Response)",
+        "server event streams are axum responses",
+      ),
+      (
+        "Self::Ok(response) => (http::StatusCode::OK, response).into_response()",
+        "event stream responses are passed through",
+      ),
+      (
+        r#"Err(anyhow::anyhow!("MockServer has no example event stream for `events`"))"#,
+        "mock answers event streams with an error",
+      ),
+    ],
+  );
+  assert_not_contains(code, "EventStream<", "server code has no client event streams");
+  assert_not_contains(code, "Default::default()", "event streams have no default");
+}
+
+#[test]
+fn test_server_request_validation_answers_problem_details() {
+  let problem = "oas3_gen_support::ProblemDetails::from_validation_errors(&errors)";
+  for (policy, expected) in [
+    (RequestValidationPolicy::Disabled, false),
+    (RequestValidationPolicy::Problem, true),
+  ] {
+    let config = CodegenConfig::builder()
+      .target(GenerationTarget::Server)
+      .request_validation(policy)
+      .build();
+    let orchestrator =
+      make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/petstore.json")), config);
+    let output = orchestrator.generate(&ServerMode, "petstore.json").unwrap();
+    let code = output.code.code(&GeneratedFileType::Server).unwrap();
+
+    if expected {
+      assert_contains_all(
+        code,
+        &[
+          ("validator::Validate::validate(&request)", "request is validated"),
+          (problem, "violations become a problem"),
+          ("oas3_gen_support::PROBLEM_JSON", "problem media type"),
+        ],
+      );
+      let validated = code.find("validator::Validate::validate(&request)").unwrap();
+      let called = code.find("service.list_pets(request)").unwrap();
+      assert!(validated < called, "validation runs before the service is called");
+    } else {
+      assert_not_contains(code, problem, "validation is off by default");
+    }
+  }
+}
+
+#[test]
+fn test_not_schemas_exclude_values_and_warn_when_unsupported() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": { "title": "Users", "version": "1" },
+      "paths": {},
+      "components": {
+        "schemas": {
+          "User": {
+            "type": "object",
+            "not": { "required": ["legacy"] },
+            "properties": {
+              "role": { "type": "string", "not": { "enum": ["admin", "root"] } },
+              "level": { "type": "integer", "not": { "const": 13 } },
+              "code": { "type": "string", "not": { "pattern": "^x" } },
+              "not": { "type": "string" }
+            }
+          }
+        }
+      }
+    }"##,
+  );
+  let output = make_orchestrator(spec, true)
+    .generate(&TypesMode, "users.json")
+    .unwrap();
+  let code = output.code.code(&GeneratedFileType::Types).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      ("custom(function = \"exclude_user_role\")", "role validator"),
+      (
+        r#"oas3_gen_support::exclude_values(&value, &["\"admin\"", "\"root\""])"#,
+        "role exclusions",
+      ),
+      (
+        r#"oas3_gen_support::exclude_values(&value, &["13"])"#,
+        "level exclusion",
+      ),
+      ("pub not: Option<String>", "a property named `not` is kept"),
+    ],
+  );
+  assert_not_contains(code, "exclude_user_code", "pattern exclusions are not enforced");
+
+  let warnings = output
+    .stats
+    .warnings
+    .iter()
+    .filter_map(|warning| match warning {
+      GenerationWarning::UnsupportedNotSchema { location, .. } => Some(location.as_str()),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(warnings, vec!["schema 'User'", "property 'code' of 'User'"]);
+}
+
+#[test]
+fn test_client_mode_imports_types_from_configured_path() {
+  let spec = include_str!("../../../fixtures/petstore.json");
+  let cases = [
+    (None, "use super::types::*;"),
+    (Some("crate::api::types"), "use crate::api::types::*;"),
+  ];
+  for (path, expected) in cases {
+    let config = CodegenConfig::builder()
+      .maybe_types_import_path(path.map(str::to_string))
+      .build();
+    let orchestrator = make_orchestrator_with_config(parse_spec(spec), config);
+    let output = orchestrator.generate(&ClientMode, "petstore.json").unwrap();
+    let code = output.code.code(&GeneratedFileType::Client).unwrap();
+
+    assert_contains(code, expected, "client imports the separately generated types");
+    assert_not_contains(code, "pub struct Pet ", "types are not inlined");
+  }
+}
+
+#[test]
+fn test_workspace_mode_generates_types_and_client_crates() {
+  let orchestrator = make_orchestrator(parse_spec(include_str!("../../../fixtures/petstore.json")), false);
+  let mode = WorkspaceMode {
+    crates: WorkspaceCrates::from_prefix("petstore"),
+  };
+  let output = orchestrator.generate(&mode, "petstore.json").unwrap().code;
+
+  assert_eq!(
+    output.code(&GeneratedFileType::Manifest).unwrap(),
+    "[workspace]\nmembers = [\"petstore-types\", \"petstore-client\"]\nresolver = \"3\"\n"
+  );
+
+  let types = &output.modules["petstore-types"];
+  let types_manifest = types.code(&GeneratedFileType::Manifest).unwrap();
+  assert_contains_all(
+    types_manifest,
+    &[
+      ("name = \"petstore-types\"", "types package name"),
+      (
+        "serde = { version = \"1.0\", features = [\"derive\"] }",
+        "serde dependency",
+      ),
+      ("oas3-gen-support = \"", "support dependency"),
+    ],
+  );
+  assert_not_contains(types_manifest, "path = ", "types crate has no path dependencies");
+  assert_contains(
+    types.code(&GeneratedFileType::Library).unwrap(),
+    "pub struct Pet",
+    "types library",
+  );
+
+  let client = &output.modules["petstore-client"];
+  assert_contains_all(
+    client.code(&GeneratedFileType::Manifest).unwrap(),
+    &[
+      ("name = \"petstore-client\"", "client package name"),
+      (
+        "petstore-types = { path = \"../petstore-types\" }",
+        "types crate dependency",
+      ),
+      ("reqwest = ", "reqwest dependency"),
+    ],
+  );
+  let client_code = client.code(&GeneratedFileType::Library).unwrap();
+  assert_contains(client_code, "use petstore_types::*;", "client imports the types crate");
+  assert_not_contains(client_code, "pub struct Pet ", "client does not redefine types");
+}
+
+#[test]
+fn test_prelude_reexports_client_operation_and_support_types() {
+  let generate_mod = |prelude| {
+    let config = CodegenConfig::builder()
+      .prelude(prelude)
+      .malformed_responses(MalformedResponsePolicy::Capture)
+      .build();
+    let orchestrator =
+      make_orchestrator_with_config(parse_spec(include_str!("../../../fixtures/petstore.json")), config);
+    let output = orchestrator.generate(&ClientModMode, "petstore.json").unwrap();
+    output.code.code(&GeneratedFileType::Module).unwrap().clone()
+  };
+
+  let mod_file = generate_mod(PreludePolicy::Generate);
+  assert_contains_all(
+    &mod_file,
+    &[
+      ("pub mod prelude {", "prelude module"),
+      ("SwaggerPetstoreClient,", "client"),
+      ("SwaggerPetstoreClientAuth,", "auth credentials"),
+      ("ListPetsRequest,", "request type"),
+      ("ListPetsResponse,", "response enum"),
+      ("AuthError, ClientCredentials, MalformedResponse", "support types"),
+    ],
+  );
+  assert_eq!(
+    mod_file.matches("ShowPetByIdResponse").count(),
+    1,
+    "shared response enums are listed once"
+  );
+  assert_not_contains(&generate_mod(PreludePolicy::Disabled), "prelude", "prelude is opt-in");
+}
+
+#[test]
+fn test_split_by_tag_writes_one_module_per_tag() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.0.0",
+      "info": { "title": "Shop", "version": "1.0.0" },
+      "paths": {
+        "/pets/{id}": {
+          "get": {
+            "operationId": "getPet",
+            "tags": ["Pets"],
+            "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+            "responses": {
+              "200": { "description": "ok", "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } } },
+              "default": { "description": "error", "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } } }
+            }
+          }
+        },
+        "/orders": {
+          "get": {
+            "operationId": "listOrders",
+            "tags": ["store", "Pets"],
+            "responses": {
+              "200": { "description": "ok", "content": { "application/json": { "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Order" } } } } },
+              "default": { "description": "error", "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } } }
+            }
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "Pet": { "type": "object", "properties": { "name": { "type": "string" } } },
+          "Order": {
+            "type": "object",
+            "properties": { "kind": { "type": "string", "enum": ["pet", "toy"] } }
+          },
+          "Error": { "type": "object", "properties": { "message": { "type": "string" } } }
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().module_split(ModuleSplitPolicy::Tag).build();
+  let output = make_orchestrator_with_config(spec, config)
+    .generate(&ClientModMode, "shop.json")
+    .unwrap()
+    .code;
+
+  let pets = output.code(&GeneratedFileType::Tag("pets".to_string())).unwrap();
+  assert_contains_all(
+    pets,
+    &[
+      ("pub struct Pet {", "tag-only schema"),
+      ("pub enum GetPetResponse", "operation response"),
+      ("use super::types::*;", "imports shared types"),
+    ],
+  );
+
+  let store = output.code(&GeneratedFileType::Tag("store".to_string())).unwrap();
+  assert_contains_all(
+    store,
+    &[
+      ("pub struct Order {", "schema owned by the first tag"),
+      ("Pet,", "enum variant named like a type"),
+      ("use super::types::*;", "imports shared types"),
+    ],
+  );
+  assert_not_contains(store, "use super::pets::*;", "enum variants are not references");
+
+  let types = output.code(&GeneratedFileType::Types).unwrap();
+  assert_contains(types, "pub struct Error {", "schema shared between tags");
+  assert_not_contains(types, "use super::", "shared module references no tag module");
+
+  assert_contains_all(
+    output.code(&GeneratedFileType::Client).unwrap(),
+    &[
+      ("use super::pets::*;", "client imports pets"),
+      ("use super::store::*;", "client imports store"),
+    ],
+  );
+  assert_contains_all(
+    output.code(&GeneratedFileType::Module).unwrap(),
+    &[
+      ("mod types;\nmod pets;\nmod store;\nmod client;", "module declarations"),
+      ("pub use pets::*;", "pets re-export"),
+      ("pub use store::*;", "store re-export"),
+    ],
+  );
+}
+
+#[test]
+fn test_module_cache_reuses_unchanged_tag_modules() {
+  let spec = |pet_properties: &str| {
+    parse_spec(&format!(
+      r##"{{
+        "openapi": "3.0.0",
+        "info": {{ "title": "Shop", "version": "1.0.0" }},
+        "paths": {{
+          "/pets": {{
+            "get": {{
+              "operationId": "listPets",
+              "tags": ["pets"],
+              "responses": {{ "200": {{ "description": "ok", "content": {{ "application/json": {{ "schema": {{ "$ref": "#/components/schemas/Pet" }} }} }} }} }}
+            }}
+          }},
+          "/orders": {{
+            "get": {{
+              "operationId": "listOrders",
+              "tags": ["store"],
+              "responses": {{ "200": {{ "description": "ok", "content": {{ "application/json": {{ "schema": {{ "$ref": "#/components/schemas/Order" }} }} }} }} }}
+            }}
+          }}
+        }},
+        "components": {{
+          "schemas": {{
+            "Pet": {{ "type": "object", "properties": {{ {pet_properties} }} }},
+            "Order": {{ "type": "object", "properties": {{ "id": {{ "type": "integer" }} }} }}
+          }}
+        }}
+      }}"##
+    ))
+  };
+  let config = || CodegenConfig::builder().module_split(ModuleSplitPolicy::Tag).build();
+
+  let first = make_orchestrator_with_config(spec(r#""name": { "type": "string" }"#), config())
+    .with_module_cache(ModuleCache::default())
+    .generate(&ClientModMode, "shop.json")
+    .unwrap();
+  assert_eq!(first.stats.modules_skipped, 0);
+  let mut cache = ModuleCache::default();
+  for (file_type, fingerprint) in &first.code.fingerprints {
+    cache.insert(fingerprint, first.code.code(file_type).unwrap());
+  }
+  assert_eq!(first.code.fingerprints.len(), 3, "types, pets, and store are cached");
+
+  let changed_spec = || spec(r#""name": { "type": "string" }, "age": { "type": "integer" }"#);
+  let fresh = make_orchestrator_with_config(changed_spec(), config())
+    .generate(&ClientModMode, "shop.json")
+    .unwrap();
+  let cached = make_orchestrator_with_config(changed_spec(), config())
+    .with_module_cache(cache)
+    .generate(&ClientModMode, "shop.json")
+    .unwrap();
+
+  assert_eq!(cached.stats.modules_skipped, 2, "only the pets module changed");
+  assert_eq!(cached.code.code, fresh.code.code);
+  assert_contains(
+    cached.code.code(&GeneratedFileType::Tag("pets".to_string())).unwrap(),
+    "pub age: Option<i64>,",
+    "changed module is regenerated",
+  );
+}
+
+#[test]
+fn test_callbacks_generate_trait_for_client_and_stay_out_of_server() {
+  let spec = || {
+    parse_spec(
+      r##"{
+        "openapi": "3.1.0",
+        "info": {"title": "Subs", "version": "1.0"},
+        "paths": {
+          "/subscriptions": {
+            "post": {
+              "operationId": "createSubscription",
+              "responses": {"201": {"description": "created"}},
+              "callbacks": {
+                "onEvent": {
+                  "{$request.body#/callbackUrl}": {
+                    "post": {
+                      "requestBody": {
+                        "required": true,
+                        "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Event"}}}
+                      },
+                      "responses": {"204": {"description": "received"}}
+                    }
+                  }
+                }
+              }
+            }
+          },
+          "/events": {
+            "get": {"operationId": "listEvents", "responses": {"200": {"description": "ok"}}}
+          }
+        },
+        "components": {
+          "schemas": {
+            "Event": {"type": "object", "properties": {"kind": {"type": "string"}}}
+          }
+        }
+      }"##,
+    )
+  };
+
+  let client = make_orchestrator_with_config(spec(), CodegenConfig::default())
+    .generate(&ClientModMode, "subs.json")
+    .unwrap()
+    .code;
+  let types = client.code(&GeneratedFileType::Types).unwrap();
+  assert_contains_all(
+    types,
+    &[
+      ("pub trait CreateSubscriptionCallbacks", "callback trait"),
+      ("fn create_subscription_on_event(", "callback method"),
+      ("pub struct CreateSubscriptionOnEventRequest", "callback request"),
+      (
+        "Serialize, Deserialize, oas3_gen_support::Default)]\npub struct Event",
+        "payload derives both directions",
+      ),
+    ],
+  );
+  assert_not_contains(
+    client.code(&GeneratedFileType::Client).unwrap(),
+    "create_subscription_on_event",
+    "callbacks are not client methods",
+  );
+
+  let config = CodegenConfig::builder().target(GenerationTarget::Server).build();
+  let server = make_orchestrator_with_config(spec(), config)
+    .generate(&ServerMode, "subs.json")
+    .unwrap()
+    .code;
+  let code = server.code(&GeneratedFileType::Server).unwrap();
+  assert_contains(code, "fn create_subscription(", "server handler");
+  assert_not_contains(code, "fn create_subscription_on_event(", "callbacks are not handlers");
+}
+
+#[test]
+fn test_webhooks_get_handler_trait_and_router_in_server() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Pets", "version": "1.0"},
+      "paths": {
+        "/pets": {
+          "get": {"operationId": "listPets", "responses": {"200": {"description": "ok"}}}
+        }
+      },
+      "webhooks": {
+        "petAdded": {
+          "post": {
+            "operationId": "petAdded",
+            "requestBody": {
+              "required": true,
+              "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}
+            },
+            "responses": {"204": {"description": "received"}}
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "Pet": {"type": "object", "properties": {"name": {"type": "string"}}}
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().target(GenerationTarget::Server).build();
+  let server = make_orchestrator_with_config(spec, config)
+    .generate(&ServerMode, "pets.json")
+    .unwrap()
+    .code;
+  let code = server.code(&GeneratedFileType::Server).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      ("pub trait WebhookHandler: Send + Sync", "webhook trait"),
+      ("request: PetAddedRequest", "typed webhook payload"),
+      (
+        "S: WebhookHandler + Clone + Send + Sync + 'static",
+        "handler bound to the webhook trait",
+      ),
+      ("pub fn webhook_router<S>(service: S) -> Router", "webhook router"),
+      (
+        r#".route("/webhooks/petAdded", post(pet_added::<S>))"#,
+        "webhook routed by path",
+      ),
+      (
+        r#"Router::new().route("/pets", get(list_pets::<S>))"#,
+        "API router keeps operations only",
+      ),
+    ],
+  );
+  let api_server = &code[code.find("pub trait ApiServer").unwrap()..code.find("pub trait WebhookHandler").unwrap()];
+  assert_not_contains(api_server, "fn pet_added(", "webhooks are not API operations");
+}
+
+#[test]
+fn test_tests_mode_round_trips_schema_examples() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Pets", "version": "1.0"},
+      "paths": {
+        "/pets": {
+          "get": {
+            "operationId": "listPets",
+            "responses": {
+              "200": {
+                "description": "ok",
+                "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}
+              }
+            }
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "Pet": {
+            "type": "object",
+            "properties": {"name": {"type": "string"}, "owner": {"$ref": "#/components/schemas/Owner"}},
+            "example": {"name": "Rex"}
+          },
+          "Owner": {"type": "object", "properties": {"name": {"type": "string"}}},
+          "Status": {"type": "string", "enum": ["available", "sold"], "examples": ["sold"]}
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let output = make_orchestrator_with_config(spec, config)
+    .generate(&TestsMode, "pets.json")
+    .unwrap();
+  let code = output.code.code(&GeneratedFileType::Types).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      ("mod example_tests {", "test module"),
+      ("async fn pet_example_round_trips()", "test for the struct example"),
+      (
+        r##"serde_json::from_str(r#"{"name":"Rex"}"#)"##,
+        "example embedded verbatim",
+      ),
+      (
+        "async fn status_example_round_trips()",
+        "first `examples` entry is used",
+      ),
+      (
+        "Serialize, Deserialize, oas3_gen_support::Default)]\npub struct Pet",
+        "response-only types still derive both directions",
+      ),
+    ],
+  );
+  assert_not_contains(
+    code,
+    "owner_example_round_trips",
+    "schemas without examples are skipped",
+  );
+  assert_eq!(output.stats.schemas_with_examples, 2);
+  assert_eq!(output.stats.schemas_without_examples, ["Owner"]);
+}
+
+#[test]
+fn test_property_tests_respect_bounds_and_cycles() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Pets", "version": "1.0"},
+      "paths": {},
+      "components": {
+        "schemas": {
+          "Pet": {
+            "type": "object",
+            "required": ["name", "lives"],
+            "properties": {
+              "name": {"type": "string", "minLength": 2, "maxLength": 8},
+              "lives": {"type": "integer", "format": "int32", "minimum": 1, "exclusiveMaximum": 10},
+              "weight": {"type": "number", "minimum": 0.5, "maximum": 40},
+              "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 2},
+              "parent": {"$ref": "#/components/schemas/Pet"}
+            }
+          },
+          "Toy": {"anyOf": [{"type": "string"}, {"type": "integer"}]}
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let output = make_orchestrator_with_config(spec, config)
+    .generate(&PropertyTestsMode, "pets.json")
+    .unwrap();
+  let code = output.code.code(&GeneratedFileType::Types).unwrap();
+
+  assert_contains_all(
+    code,
+    &[
+      ("mod proptests {", "test module"),
+      ("fn arb_pet() -> impl Strategy<Value = Pet>", "strategy for the struct"),
+      (
+        "proptest::collection::vec(proptest::char::range('a', 'z'), 2..=8)",
+        "string within its length bounds",
+      ),
+      ("(1i32..=9i32)", "integer within its range bounds"),
+      ("(1i32..=40i32).prop_map(f64::from)", "whole floats within the range"),
+      (
+        "proptest::option::of(proptest::collection::vec(any::<String>(), 0..=2))",
+        "array within its item bounds",
+      ),
+      (
+        "proptest::strategy::LazyJust::new(<Option<Box<Pet>>>::default)",
+        "cyclic reference left empty",
+      ),
+      ("proptest::strategy::Union::new([", "union over the anyOf variants"),
+      ("fn toy_round_trips()", "round-trip test for the enum"),
+      ("prop_assert_eq!(round_tripped, value);", "round-trip assertion"),
+    ],
+  );
+}
+
+#[test]
+fn test_fixtures_pair_examples_with_deserialize_tests() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Pets", "version": "1.0"},
+      "paths": {
+        "/pets": {
+          "get": {
+            "operationId": "listPets",
+            "responses": {
+              "200": {
+                "description": "ok",
+                "content": {
+                  "application/json": {
+                    "schema": {"type": "array", "items": {"$ref": "#/components/schemas/Pet"}},
+                    "example": [{"name": "Fido"}]
+                  }
+                }
+              },
+              "404": {"description": "missing"}
+            }
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "Pet": {"type": "object", "properties": {"name": {"type": "string"}}, "example": {"name": "Rex"}},
+          "Owner": {"type": "object", "properties": {"name": {"type": "string"}}}
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let files = make_orchestrator_with_config(spec, config)
+    .generate_fixtures(&syn::parse_quote!(crate::types), "pets.json")
+    .unwrap();
+
+  assert_eq!(
+    files.keys().map(String::as_str).collect::<Vec<_>>(),
+    ["responses/list_pets_200.json", "schemas/Pet.json", "tests.rs"],
+    "one file per example, none for schemas or responses without one"
+  );
+  assert_eq!(files["schemas/Pet.json"], "{\n  \"name\": \"Rex\"\n}\n");
+  assert_contains_all(
+    &files["tests.rs"],
+    &[
+      ("use crate::types::*;", "types imported from the given path"),
+      ("fn pet_fixture_deserializes()", "schema fixture test"),
+      (
+        r#"serde_json::from_str::<Pet>(include_str!("schemas/Pet.json"))"#,
+        "schema fixture loaded relative to the tests file",
+      ),
+      (
+        "fn list_pets_200_response_fixture_deserializes()",
+        "response fixture test",
+      ),
+    ],
+  );
+}
+
+#[test]
+fn test_benches_cover_largest_structs_and_discriminated_enums() {
+  let spec = parse_spec(
+    r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Pets", "version": "1.0"},
+      "paths": {},
+      "components": {
+        "schemas": {
+          "Cat": {
+            "type": "object",
+            "required": ["kind"],
+            "properties": {"kind": {"type": "string"}, "lives": {"type": "integer"}},
+            "example": {"lives": 9}
+          },
+          "Dog": {"type": "object", "required": ["kind"], "properties": {"kind": {"type": "string"}}},
+          "Pet": {
+            "oneOf": [{"$ref": "#/components/schemas/Cat"}, {"$ref": "#/components/schemas/Dog"}],
+            "discriminator": {
+              "propertyName": "kind",
+              "mapping": {"cat": "#/components/schemas/Cat", "dog": "#/components/schemas/Dog"}
+            }
+          },
+          "Owner": {"type": "object", "properties": {"name": {"type": "string"}}}
+        }
+      }
+    }"##,
+  );
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let files = make_orchestrator_with_config(spec, config)
+    .generate_benches(&syn::parse_quote!(pets_api::types), "pets.json")
+    .unwrap();
+
+  assert_eq!(files.keys().map(String::as_str).collect::<Vec<_>>(), ["serde.rs"]);
+  let code = &files["serde.rs"];
+  assert_contains_all(
+    code,
+    &[
+      ("use pets_api::types::*;", "types imported from the given path"),
+      ("fn cat(c: &mut Criterion)", "struct with an example benchmarked"),
+      (
+        r##"const PAYLOAD: &str = r#"{"lives":9,"kind":"cat"}"#;"##,
+        "enum payload tagged with the variant discriminator",
+      ),
+      (
+        "serde_json::from_str::<Pet>(black_box(PAYLOAD))",
+        "enum deserialize bench",
+      ),
+      ("criterion_group!(benches, cat, pet);", "every benchmark registered"),
+    ],
+  );
+  assert!(!code.contains("Owner"), "types without an example are skipped");
+}
+
+#[test]
+fn test_parallel_conversion_matches_sequential_output() {
+  let fixtures = [
+    include_str!("../../../fixtures/basic_api.json"),
+    include_str!("../../../fixtures/union_serde.json"),
+    include_str!("../../../fixtures/enum_deduplication.json"),
+    include_str!("../../../fixtures/oas_3_1_2_pet_benchmark.json"),
+  ];
+  for fixture in fixtures {
+    let generate = |conversion| {
+      let config = CodegenConfig::builder()
+        .schema_scope(SchemaScope::All)
+        .conversion(conversion)
+        .build();
+      make_orchestrator_with_config(parse_spec(fixture), config)
+        .generate(&TypesMode, "spec.json")
+        .expect("types generation should succeed")
+    };
+    let sequential = generate(ConversionPolicy::Sequential);
+    for jobs in [2, 3, 16] {
+      let parallel = generate(ConversionPolicy::Parallel(jobs));
+      assert_eq!(
+        parallel.code, sequential.code,
+        "{jobs} jobs changed the generated types"
+      );
+      assert_eq!(
+        parallel.stats.warnings, sequential.stats.warnings,
+        "{jobs} jobs changed the warnings"
+      );
+    }
+  }
+}
+
+#[test]
+fn test_acronym_casing_applies_per_run() {
+  let spec = r#"{
+    "openapi": "3.1.0",
+    "info": {"title": "Proxies", "version": "1.0"},
+    "paths": {},
+    "components": {"schemas": {"http_proxy_url": {"type": "object", "properties": {"port": {"type": "integer"}}}}}
+  }"#;
+  let generate = |acronym_case, conversion| {
+    let config = CodegenConfig::builder()
+      .schema_scope(SchemaScope::All)
+      .casing(Casing::new(acronym_case, []))
+      .conversion(conversion)
+      .build();
+    generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "spec.json").code
+  };
+
+  for conversion in [ConversionPolicy::Sequential, ConversionPolicy::Parallel(2)] {
+    assert_contains(
+      &generate(AcronymCase::Upper, conversion),
+      "pub struct HTTPProxyURL",
+      "upper acronyms",
+    );
+    assert_contains(
+      &generate(AcronymCase::Pascal, conversion),
+      "pub struct HttpProxyUrl",
+      "a later run uses its own casing",
+    );
+  }
+  assert_contains(
+    &generate(AcronymCase::default(), ConversionPolicy::Sequential),
+    "pub struct HttpProxyUrl",
+    "casing is not left behind by earlier runs",
+  );
+}
+
+#[test]
+fn test_type_name_overrides_apply_per_run() {
+  let spec = r#"{
+    "openapi": "3.1.0",
+    "info": {"title": "Pets", "version": "1.0"},
+    "paths": {},
+    "components": {"schemas": {"Pet": {"type": "object", "properties": {"name": {"type": "string"}}}}}
+  }"#;
+  let generate = |type_names: &[(&str, &str)], conversion| {
+    let config = CodegenConfig::builder()
+      .schema_scope(SchemaScope::All)
+      .type_names(
+        type_names
+          .iter()
+          .map(|(schema, rust)| ((*schema).to_string(), (*rust).to_string()))
+          .collect(),
+      )
+      .conversion(conversion)
+      .build();
+    generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "spec.json").code
+  };
+
+  for conversion in [ConversionPolicy::Sequential, ConversionPolicy::Parallel(2)] {
+    assert_contains(
+      &generate(&[("Pet", "Animal")], conversion),
+      "pub struct Animal",
+      "overridden type name",
+    );
+    assert_contains(
+      &generate(&[("Pet", "Companion")], conversion),
+      "pub struct Companion",
+      "a later run uses its own overrides",
+    );
+  }
+  assert_contains(
+    &generate(&[], ConversionPolicy::Sequential),
+    "pub struct Pet",
+    "overrides are not left behind by earlier runs",
+  );
+}
+
+#[test]
+fn test_naming_hook_applies_per_run() {
+  let spec = r#"{
+    "openapi": "3.1.0",
+    "info": {"title": "Pets", "version": "1.0"},
+    "paths": {},
+    "components": {"schemas": {"Pet": {"type": "object", "properties": {"name": {"type": "string"}}}}}
+  }"#;
+  let dir = tempfile::tempdir().unwrap();
+  let program = dir.path().join("hook.sh");
+  std::fs::write(
+    &program,
+    r#"#!/bin/sh
+while read -r line; do
+  case "$line" in
+    *'"kind":"type","name":"Pet"'*) echo '"Animal"' ;;
+    *) echo null ;;
+  esac
+done
+"#,
+  )
+  .unwrap();
+  std::fs::set_permissions(&program, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+  let generate = |naming_hook: Option<NamingHook>, conversion| {
+    let config = CodegenConfig::builder()
+      .schema_scope(SchemaScope::All)
+      .maybe_naming_hook(naming_hook)
+      .conversion(conversion)
+      .build();
+    generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "spec.json").code
+  };
+
+  for conversion in [ConversionPolicy::Sequential, ConversionPolicy::Parallel(2)] {
+    let hook = NamingHook::spawn(&program, std::path::Path::new("spec.json")).unwrap();
+    assert_contains(
+      &generate(Some(hook.clone()), conversion),
+      "pub struct Animal",
+      "hook-named type",
+    );
+    hook.close();
+    assert_eq!(hook.error(), None, "hook error");
+  }
+  assert_contains(
+    &generate(None, ConversionPolicy::Sequential),
+    "pub struct Pet",
+    "the hook is not left behind by earlier runs",
+  );
+}
+
+#[test]
+fn test_read_write_only_keeps_fields_in_their_direction() {
+  let spec = r##"{
+      "openapi": "3.1.0",
+      "info": {"title": "Users", "version": "1.0"},
+      "paths": {
+        "/users": {
+          "post": {
+            "operationId": "createUser",
+            "requestBody": {"required": true, "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+            "responses": {"201": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+          }
+        },
+        "/sessions": {
+          "post": {
+            "operationId": "createSession",
+            "requestBody": {"required": true, "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Login"}}}},
+            "responses": {"204": {"description": "ok"}}
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "User": {
+            "type": "object",
+            "required": ["id", "name", "password"],
+            "properties": {
+              "id": {"type": "string", "readOnly": true},
+              "name": {"type": "string"},
+              "password": {"type": "string", "writeOnly": true}
+            }
+          },
+          "Login": {
+            "type": "object",
+            "required": ["user", "secret", "sessionId"],
+            "properties": {
+              "user": {"type": "string"},
+              "secret": {"type": "string", "writeOnly": true},
+              "sessionId": {"type": "string", "readOnly": true}
+            }
+          }
+        }
+      }
+    }"##;
+  let cases = [
+    (
+      GenerationTarget::Client,
+      "#[serde(skip_serializing)]\n    pub id: Option<String>,",
+      "#[serde(skip_deserializing)]\n    pub password: Option<String>,",
+    ),
+    (
+      GenerationTarget::Server,
+      "#[serde(skip_deserializing)]\n    pub id: Option<String>,",
+      "#[serde(skip_serializing)]\n    pub password: Option<String>,",
+    ),
+  ];
+
+  for (target, read_only, write_only) in cases {
+    let config = CodegenConfig::builder()
+      .target(target)
+      .property_access(PropertyAccessPolicy::PerDirection)
+      .build();
+    let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "users.json");
+    assert_contains_all(
+      &output.code,
+      &[
+        (read_only, "readOnly field skipped when the target sends it"),
+        (write_only, "writeOnly field skipped when the target receives it"),
+        (
+          "pub secret: Option<String>,",
+          "writeOnly field kept in a request-only struct",
+        ),
+      ],
+    );
+    assert_not_contains(
+      &output.code,
+      "pub session_id",
+      &format!("{target:?} readOnly field dropped from a request-only struct"),
+    );
+  }
+
+  let output = generate_types(
+    &make_orchestrator_with_config(parse_spec(spec), CodegenConfig::default()),
+    "users.json",
+  );
+  assert_contains_all(
+    &output.code,
+    &[
+      ("#[serde(skip_serializing)]", "readOnly field not sent by default"),
+      ("pub id: String,", "readOnly field stays required by default"),
+      (
+        "#[serde(rename = \"sessionId\", skip_serializing)]",
+        "readOnly field kept but not sent in a request-only struct by default",
+      ),
+      ("pub session_id: String,", "readOnly field kept in requests by default"),
+      ("pub password: String,", "writeOnly ignored by default"),
+    ],
+  );
+
+  let config = CodegenConfig::builder().target(GenerationTarget::Server).build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "users.json");
+  assert_not_contains(
+    &output.code,
+    "skip_serializing)]",
+    "server sends readOnly fields by default",
+  );
+
+  let config = CodegenConfig::builder()
+    .property_access(PropertyAccessPolicy::Ignore)
+    .build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "users.json");
+  assert_not_contains(&output.code, "skip_serializing)]", "readOnly sent when ignored");
+}
+
+#[test]
+fn test_rust_type_overrides_replace_generated_types() {
+  let spec = r##"{
+    "openapi": "3.1.0",
+    "info": {"title": "Users", "version": "1.0"},
+    "paths": {
+      "/users/{id}": {
+        "get": {
+          "operationId": "getUser",
+          "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"$ref": "#/components/schemas/UserId"}}],
+          "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}}
+        }
+      }
+    },
+    "components": {
+      "schemas": {
+        "UserId": {"type": "string", "format": "uuid", "x-rust-type": "crate::ids::UserId"},
+        "Money": {"type": "object", "properties": {"amount": {"type": "string"}}},
+        "User": {
+          "type": "object",
+          "required": ["id", "balance"],
+          "properties": {
+            "id": {"$ref": "#/components/schemas/UserId"},
+            "balance": {"$ref": "#/components/schemas/Money"},
+            "tags": {"type": "array", "items": {"type": "string", "x-rust-type": "crate::ids::Tag"}},
+            "home": {"type": "object", "x-rust-type": "crate::ids::Address", "properties": {"street": {"type": "string"}}}
+          }
+        }
+      }
+    }
+  }"##;
+
+  let config = CodegenConfig::builder()
+    .rust_types(HashMap::from([("Money".to_string(), "crate::ids::Money".to_string())]))
+    .build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "users.json");
+  assert_contains_all(
+    &output.code,
+    &[
+      (
+        "pub type UserId = crate::ids::UserId;",
+        "x-rust-type on a component schema",
+      ),
+      (
+        "pub type Money = crate::ids::Money;",
+        "config override on a component schema",
+      ),
+      (
+        "pub tags: Option<Vec<crate::ids::Tag>>,",
+        "x-rust-type on inline array items",
+      ),
+      (
+        "pub home: Option<crate::ids::Address>,",
+        "x-rust-type on an inline object",
+      ),
+    ],
+  );
+  assert_not_contains(&output.code, "pub struct Money", "overridden schema is not generated");
+  assert_not_contains(&output.code, "pub amount", "overridden schema fields are not generated");
+}
+
+#[test]
+fn test_identical_inline_types_are_merged() {
+  let spec = r##"{
+    "openapi": "3.1.0",
+    "info": {"title": "Merge", "version": "1.0"},
+    "paths": {
+      "/foo": {"get": {"operationId": "getFoo", "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Foo"}}}}}}},
+      "/bar": {"get": {"operationId": "getBar", "responses": {"200": {"description": "ok", "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Bar"}}}}}}}
+    },
+    "components": {
+      "schemas": {
+        "Point": {"type": "object", "properties": {"x": {"type": "integer"}, "y": {"type": "integer"}}},
+        "Foo": {"type": "object", "properties": {
+          "origin": {"type": "object", "description": "Where the foo starts", "properties": {"x": {"type": "integer"}, "y": {"type": "integer"}}},
+          "meta": {"type": "object", "description": "Foo metadata", "properties": {"owner": {"type": "string", "description": "Foo owner"}}}
+        }},
+        "Bar": {"type": "object", "properties": {
+          "details": {"type": "object", "description": "Bar metadata", "properties": {"owner": {"type": "string"}}}
+        }}
+      }
+    }
+  }"##;
+
+  let output = make_orchestrator(parse_spec(spec), true)
+    .generate(&TypesMode, "merge.json")
+    .unwrap();
+  assert_eq!(output.stats.merged_types, vec!["BarDetails", "FooOrigin"]);
+  let code = output.code.code(&GeneratedFileType::Types).unwrap();
+  assert_contains_all(
+    code,
+    &[
+      (
+        "pub origin: Option<Point>,",
+        "inline object merged into a component schema",
+      ),
+      (
+        "pub meta: Option<FooMeta>,",
+        "surviving inline type keeps its references",
+      ),
+      (
+        "pub details: Option<FooMeta>,",
+        "inline objects differing in docs merged",
+      ),
+    ],
+  );
+  assert_not_contains(code, "pub struct FooOrigin", "merged inline type removed");
+  assert_not_contains(code, "pub struct BarDetails", "merged inline type removed");
+
+  let config = CodegenConfig::builder()
+    .type_merge(TypeMergePolicy::KeepAll)
+    .schema_scope(SchemaScope::All)
+    .build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "merge.json");
+  assert_contains_all(
+    &output.code,
+    &[
+      ("pub struct FooOrigin", "opt-out keeps the inline type"),
+      ("pub struct BarDetails", "opt-out keeps the inline type"),
+    ],
+  );
+}
+
+#[test]
+fn test_extra_derives_only_on_hashable_types() {
+  let spec = r##"{
+    "openapi": "3.1.0",
+    "info": {"title": "Derives", "version": "1.0"},
+    "paths": {},
+    "components": {
+      "schemas": {
+        "Status": {"type": "string", "enum": ["active", "retired"]},
+        "Point": {"type": "object", "required": ["x", "y"], "properties": {"x": {"type": "integer"}, "y": {"type": "integer"}}},
+        "Route": {"type": "object", "properties": {
+          "status": {"$ref": "#/components/schemas/Status"},
+          "stops": {"type": "array", "items": {"$ref": "#/components/schemas/Point"}}
+        }},
+        "Reading": {"type": "object", "properties": {"value": {"type": "number"}}},
+        "Sensor": {"type": "object", "properties": {"last": {"$ref": "#/components/schemas/Reading"}}},
+        "Labels": {"type": "object", "properties": {"tags": {"type": "object", "additionalProperties": {"type": "string"}}}}
+      }
+    }
+  }"##;
+
+  let config = CodegenConfig::builder()
+    .extra_derives(ExtraDerivePolicy::Ordering)
+    .schema_scope(SchemaScope::All)
+    .build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "derives.json");
+  let ordered = "    PartialOrd,\n    Ord,\n    Serialize,\n    Deserialize,\n    oas3_gen_support::Default\n)]";
+  assert_contains_all(
+    &output.code,
+    &[
+      (&format!("{ordered}\npub enum Status"), "string enum derives ordering"),
+      (
+        &format!("{ordered}\npub struct Point"),
+        "integer fields derive ordering",
+      ),
+      (
+        &format!("{ordered}\npub struct Route"),
+        "fields of ordered types derive ordering",
+      ),
+    ],
+  );
+  for name in ["Reading", "Sensor", "Labels"] {
+    assert_contains_all(
+      &output.code,
+      &[(
+        &format!("PartialEq, Serialize, Deserialize, oas3_gen_support::Default)]\npub struct {name}"),
+        "floats, maps, and types holding them keep the standard derives",
+      )],
+    );
+  }
+
+  let output = generate_types(&make_orchestrator(parse_spec(spec), true), "derives.json");
+  assert_not_contains(&output.code, "PartialOrd", "ordering derives are opt-in");
+}
+
+#[test]
+fn test_extra_derives_skip_types_holding_file_parts() {
+  let spec = r##"{
+    "openapi": "3.1.0",
+    "info": {"title": "Uploads", "version": "1.0"},
+    "paths": {
+      "/uploads": {"post": {
+        "operationId": "upload",
+        "requestBody": {"required": true, "content": {"multipart/form-data": {"schema": {
+          "type": "object",
+          "required": ["image"],
+          "properties": {"image": {"type": "string", "format": "binary"}, "caption": {"type": "string"}}
+        }}}},
+        "responses": {"204": {"description": "Uploaded"}}
+      }}
+    }
+  }"##;
+
+  let config = CodegenConfig::builder()
+    .extra_derives(ExtraDerivePolicy::Ordering)
+    .schema_scope(SchemaScope::All)
+    .build();
+  let output = generate_types(&make_orchestrator_with_config(parse_spec(spec), config), "uploads.json");
+  assert_contains_all(
+    &output.code,
+    &[(
+      "pub image: oas3_gen_support::FilePart",
+      "binary multipart field becomes a file part",
+    )],
+  );
+  assert_not_contains(&output.code, "Ord,", "file parts do not implement Ord");
+}
+
+#[test]
+fn test_spec_hash_covers_only_the_effective_spec() {
+  let spec = |summary: &str, admin_summary: &str| {
+    format!(
+      r#"{{
+        "openapi": "3.1.0",
+        "info": {{"title": "Hashes", "version": "1.0"}},
+        "paths": {{
+          "/users": {{"get": {{"operationId": "list_users", "summary": "{summary}", "responses": {{"200": {{"description": "OK"}}}}}}}},
+          "/admin": {{"post": {{"operationId": "admin_action", "summary": "{admin_summary}", "responses": {{"200": {{"description": "OK"}}}}}}}}
+        }}
+      }}"#
+    )
+  };
+  let excluded = string_set(&["admin_action"]);
+  let hash = |spec: &str, settings: &str| {
+    make_orchestrator_with_ops(parse_spec(spec), false, None, Some(&excluded)).spec_hash(settings)
+  };
+  let base = hash(&spec("List", "Admin"), "settings");
+
+  let reordered = r#"{
+    "paths": {
+      "/admin": {"post": {"responses": {"200": {"description": "OK"}}, "summary": "Admin", "operationId": "admin_action"}},
+      "/users": {"get": {"responses": {"200": {"description": "OK"}}, "summary": "List", "operationId": "list_users"}}
+    },
+    "info": {"version": "1.0", "title": "Hashes"},
+    "openapi": "3.1.0"
+  }"#;
+  assert_eq!(hash(reordered, "settings"), base, "key order does not change the hash");
+  assert_eq!(
+    hash(&spec("List", "Changed"), "settings"),
+    base,
+    "filtered out operations do not change the hash"
+  );
+  assert_ne!(
+    hash(&spec("Changed", "Admin"), "settings"),
+    base,
+    "selected operations change the hash"
+  );
+  assert_ne!(hash(&spec("List", "Admin"), "other"), base, "settings change the hash");
+
+  let orchestrator = make_orchestrator_with_ops(parse_spec(&spec("List", "Admin")), false, None, Some(&excluded));
+  assert_not_contains(
+    &generate_types(&orchestrator, "hashes.json").code,
+    "Spec hash:",
+    "the hash is opt-in",
+  );
+  let output = generate_types(&orchestrator.with_spec_hash("settings"), "hashes.json");
+  assert_contains(&output.code, &format!("//! Spec hash: {base}"), "hash in header");
+  assert_eq!(
+    header_spec_hash(&output.code),
+    Some(base.as_str()),
+    "hash read back from header"
+  );
+}
+
+#[test]
+fn test_vendor_nullability_makes_required_fields_optional() {
+  let spec_json = r##"{
+    "openapi": "3.1.0",
+    "info": {"title": "Pets", "version": "1.0"},
+    "paths": {},
+    "components": {
+      "schemas": {
+        "Owner": {"type": "object", "properties": {"name": {"type": "string"}}},
+        "Pet": {
+          "type": "object",
+          "required": ["tag", "legacy", "owner", "tags"],
+          "properties": {
+            "tag": {"type": "string", "nullable": true},
+            "legacy": {"type": "integer", "x-nullable": true},
+            "owner": {"$ref": "#/components/schemas/Owner", "x-nullable": true},
+            "tags": {"type": "array", "items": {"type": "string", "nullable": true}}
+          }
+        }
+      }
+    }
+  }"##;
+  let fields = [
+    "pub tag: Option<String>,",
+    "pub legacy: Option<i64>,",
+    "pub owner: Option<Owner>,",
+    "pub tags: Vec<Option<String>>,",
+  ];
+  let generate = |nullability| {
+    let config = CodegenConfig::builder()
+      .schema_scope(SchemaScope::All)
+      .nullability(nullability)
+      .build();
+    generate_types(
+      &make_orchestrator_with_config(parse_spec(spec_json), config),
+      "pets.json",
+    )
+    .code
+  };
+
+  let vendor = generate(NullabilityPolicy::VendorExtensions);
+  for field in fields {
+    assert_contains(&vendor, field, "vendor nullable field");
+  }
+
+  let standard = generate(NullabilityPolicy::Standard);
+  assert_contains(&standard, "pub tag: String,", "nullable keyword ignored");
+  assert_contains(&standard, "pub owner: Owner,", "x-nullable sibling ignored");
+}
@@ -430,7 +430,7 @@ fn implicit_discriminator_mapping_from_const_values() {
 }
 
 #[test]
-fn implicit_discriminator_mapping_warns_on_missing_const() {
+fn implicit_discriminator_mapping_falls_back_to_schema_names() {
   let spec = spec_with_schemas(&json!({
     "Allergies": {
       "type": "object",
@@ -458,18 +458,15 @@ fn implicit_discriminator_mapping_warns_on_missing_const() {
   let mut stats = GenerationStats::default();
   let registry = SchemaRegistry::new(&spec, &mut stats);
 
-  assert!(
-    registry.mapping("Allergies").is_none(),
-    "no mapping should be synthesized"
-  );
-  assert!(registry.mapping("Diet").is_none(), "no mapping should be synthesized");
-  assert_eq!(stats.warnings.len(), 1, "should have one warning");
-  assert!(
-    matches!(&stats.warnings[0], GenerationWarning::DiscriminatorMappingFailed { schema_name, message }
-      if schema_name == "Health" && message.contains("Diet") && message.contains("no string const")),
-    "warning should mention Diet missing const: {:?}",
-    stats.warnings[0]
+  let am = registry.mapping("Allergies").expect("Allergies should have a mapping");
+  assert_eq!(am.field_value, "allergies", "const values are used when present");
+  let dm = registry.mapping("Diet").expect("Diet should have a mapping");
+  assert_eq!(dm.field_name, "type", "field_name should be 'type'");
+  assert_eq!(
+    dm.field_value, "Diet",
+    "variants without a const are identified by schema name"
   );
+  assert!(stats.warnings.is_empty(), "should have no warnings");
 }
 
 #[test]
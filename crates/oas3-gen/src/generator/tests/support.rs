@@ -2,20 +2,25 @@ use std::collections::{HashMap, HashSet};
 
 use oas3::Spec;
 
-use crate::generator::{
-  CodegenConfig, SchemaScope, TypesMode,
-  codegen::{GeneratedFileType, Visibility},
-  orchestrator::Orchestrator,
+use crate::{
+  generator::{
+    CodegenConfig, SchemaScope, TypesMode,
+    codegen::{GeneratedFileType, Visibility},
+    orchestrator::Orchestrator,
+  },
+  utils::spec,
 };
 
 pub(super) struct GeneratedTypes {
   pub(super) code: String,
   pub(super) operations_converted: usize,
   pub(super) orphaned_schemas_count: usize,
+  pub(super) orphaned_schemas: Vec<String>,
 }
 
 pub(super) fn parse_spec(spec_json: &str) -> Spec {
-  oas3::from_json(spec_json).expect("failed to parse test spec")
+  let document = serde_json::from_str(spec_json).expect("failed to parse test spec");
+  spec::from_document(document).expect("failed to parse test spec")
 }
 
 pub(super) fn string_set(values: &[&str]) -> HashSet<String> {
@@ -59,6 +64,10 @@ pub(super) fn make_orchestrator_with_customizations(
   Orchestrator::new(spec, Visibility::default(), config, None, None)
 }
 
+pub(super) fn make_orchestrator_with_config(spec: Spec, config: CodegenConfig) -> Orchestrator {
+  Orchestrator::new(spec, Visibility::default(), config, None, None)
+}
+
 pub(super) fn generate_types(orchestrator: &Orchestrator, source_path: &str) -> GeneratedTypes {
   let output = orchestrator
     .generate(&TypesMode, source_path)
@@ -72,6 +81,7 @@ pub(super) fn generate_types(orchestrator: &Orchestrator, source_path: &str) ->
     code,
     operations_converted: output.stats.operations_converted,
     orphaned_schemas_count: output.stats.orphaned_schemas_count,
+    orphaned_schemas: output.stats.orphaned_schemas,
   }
 }
 
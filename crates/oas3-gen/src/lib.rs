@@ -0,0 +1,94 @@
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::too_many_lines)]
+//! Generates idiomatic, type-safe Rust code from OpenAPI 3.1 specifications.
+//!
+//! Besides the `oas3-gen` command line tool, the crate exposes the generation pipeline
+//! as a library, so a project can generate its client or types from `build.rs`:
+//!
+//! ```no_run
+//! use oas3_gen::{CodegenConfig, GenOptions, OutputMode};
+//!
+//! let spec = oas3_gen::load_spec("openapi.json").expect("spec should load");
+//! let options = GenOptions::builder()
+//!   .mode(OutputMode::ClientMod)
+//!   .config(CodegenConfig::builder().build())
+//!   .source_path("openapi.json")
+//!   .build();
+//! let output = oas3_gen::generate(spec, options).expect("spec should generate");
+//! output.write_to("src/api").expect("generated files should be written");
+//! ```
+
+mod api;
+mod generator;
+mod ui;
+mod utils;
+
+pub use api::{GenOptions, GeneratedOutput, OutputMode, generate, load_spec};
+pub use generator::ast::LintConfig;
+pub use generator::codegen::{Visibility, templates::ClientTemplates};
+pub use generator::naming::{
+  casing::{AcronymCase, Casing},
+  hooks::NamingHook,
+};
+pub use generator::{
+  BatchPolicy, BuilderPolicy, ClientErrorPolicy, ClientRuntime, ClonePolicy, CodegenConfig, CollectionTypePolicy,
+  ConversionPolicy, DecimalPolicy, DeserializeProfile, DocFormatPolicy, DurationFormatPolicy, EnumCasePolicy,
+  EnumDeserializePolicy, EnumHelperPolicy, EnumLayoutPolicy, ExtraDerivePolicy, GenerationTarget, HeaderScope,
+  InlineObjectPolicy, IntegerEnumPolicy, IntegerWidthPolicy, LastResponsePolicy, MalformedResponsePolicy,
+  ModuleSplitPolicy, NullabilityPolicy, NumberCoercionPolicy, ODataPolicy, OutputFormatPolicy, PaginationPolicy,
+  PreludePolicy, PropertyAccessPolicy, RawBodyPolicy, RequestBodyPolicy, RequestHookPolicy, RequestValidationPolicy,
+  ResponseClassPolicy, RetryPolicy, RouteTestPolicy, SchemaScope, TowerServicePolicy, TypeMergePolicy,
+  UnsignedIntegerPolicy, VcrPolicy,
+};
+
+/// Runs the `oas3-gen` command line tool with the process arguments.
+#[doc(hidden)]
+pub async fn run_cli() -> anyhow::Result<()> {
+  use clap::Parser;
+
+  use crate::ui::{Cli, Colors, Commands, ListCommands, colors};
+
+  let cli = Cli::parse();
+  let colors = Colors::new(colors::colors_enabled(cli.color), colors::detect_theme(cli.theme));
+
+  match cli.command {
+    Commands::List { list_command } => match list_command {
+      ListCommands::Operations { input } => ui::commands::list_operations(&input, &colors).await?,
+      ListCommands::Schemas { input } => ui::commands::list_schemas(&input, &colors).await?,
+      ListCommands::Tags { input } => ui::commands::list_tags(&input, &colors).await?,
+    },
+    Commands::Generate(command) => {
+      let config = ui::commands::GenerateConfig::from_command(*command)?;
+      ui::commands::generate_code(config, &colors).await?;
+    }
+    Commands::Fixtures {
+      input,
+      output,
+      types_path,
+    } => ui::commands::write_fixtures(&input, &output, &types_path, &colors).await?,
+    Commands::Benches {
+      input,
+      output,
+      types_path,
+    } => ui::commands::write_benches(&input, &output, &types_path, &colors).await?,
+    Commands::Stats { input } => ui::commands::print_spec_stats(&input, &colors).await?,
+    Commands::Graph { input, output, format } => {
+      ui::commands::export_graph(&input, output.as_deref(), format, &colors).await?;
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+#[path = "../fixtures"]
+mod fixtures {
+  pub mod intersection_union;
+  pub mod petstore;
+  pub mod petstore_server;
+  pub mod union_serde;
+}
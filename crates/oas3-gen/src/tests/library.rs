@@ -0,0 +1,126 @@
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+};
+
+use crate::{AcronymCase, Casing, CodegenConfig, GenOptions, OutputMode, SchemaScope, generate, load_spec};
+
+const PETSTORE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/petstore.json");
+
+#[test]
+fn test_generate_client_mod_lays_out_module_files() {
+  let spec = load_spec(PETSTORE).expect("petstore spec should load");
+  let options = GenOptions::builder()
+    .mode(OutputMode::ClientMod)
+    .source_path("petstore.json")
+    .build();
+
+  let output = generate(spec, options).expect("petstore should generate");
+
+  assert_eq!(
+    output.files().keys().collect::<Vec<_>>(),
+    [Path::new("client.rs"), Path::new("mod.rs"), Path::new("types.rs")]
+  );
+  assert!(output.files()[Path::new("client.rs")].contains("Source: petstore.json"));
+}
+
+#[test]
+fn test_generate_server_mode_sets_server_target() {
+  let spec = load_spec(PETSTORE).expect("petstore spec should load");
+  let options = GenOptions::builder()
+    .mode(OutputMode::Server)
+    .config(CodegenConfig::builder().schema_scope(SchemaScope::All).build())
+    .build();
+
+  let output = generate(spec, options).expect("petstore should generate");
+
+  let server = &output.files()[Path::new("server.rs")];
+  assert!(server.contains("pub trait ApiServer"), "server trait generated");
+}
+
+#[test]
+fn test_write_to_creates_files_and_skips_unchanged() {
+  let spec = load_spec(PETSTORE).expect("petstore spec should load");
+  let output = generate(spec, GenOptions::builder().build()).expect("petstore should generate");
+  let dir = tempfile::tempdir().expect("temp dir should be created");
+  let out = dir.path().join("api");
+
+  output.write_to(&out).expect("files should be written");
+  let types = out.join("types.rs");
+  let written = std::fs::metadata(&types).and_then(|meta| meta.modified()).unwrap();
+  output.write_to(&out).expect("files should be written again");
+
+  assert_eq!(
+    std::fs::read_to_string(&types).unwrap(),
+    output.files()[Path::new("types.rs")]
+  );
+  assert_eq!(
+    std::fs::metadata(&types).and_then(|meta| meta.modified()).unwrap(),
+    written,
+    "unchanged file left untouched"
+  );
+}
+
+#[test]
+fn test_generate_applies_tag_and_path_filters() {
+  let generate_client = |options: GenOptions| {
+    let output =
+      generate(load_spec(PETSTORE).expect("petstore spec should load"), options).expect("petstore should generate");
+    output.files()[Path::new("client.rs")].clone()
+  };
+
+  let by_path = generate_client(
+    GenOptions::builder()
+      .mode(OutputMode::Client)
+      .included_paths(vec!["/cats".to_string()])
+      .build(),
+  );
+  assert!(by_path.contains("pub async fn list_cats"), "included path kept");
+  assert!(!by_path.contains("pub async fn show_pet_by_id"), "other paths dropped");
+
+  let by_tag = |tag: &str| {
+    generate_client(
+      GenOptions::builder()
+        .mode(OutputMode::Client)
+        .included_tags(HashSet::from([tag.to_string()]))
+        .build(),
+    )
+  };
+  assert!(
+    by_tag("pets").contains("pub async fn list_cats"),
+    "tagged operations kept"
+  );
+  assert!(
+    !by_tag("owners").contains("pub async fn list_cats"),
+    "other operations dropped"
+  );
+}
+
+#[test]
+fn test_generate_applies_naming_settings_from_config() {
+  let config = CodegenConfig::builder()
+    .schema_scope(SchemaScope::All)
+    .casing(Casing::new(AcronymCase::Upper, ["PET".to_string()]))
+    .type_names(HashMap::from([("Cat".to_string(), "Feline".to_string())]))
+    .build();
+  let output = generate(
+    load_spec(PETSTORE).expect("petstore spec should load"),
+    GenOptions::builder().config(config).build(),
+  )
+  .expect("petstore should generate");
+
+  let types = &output.files()[Path::new("types.rs")];
+  assert!(types.contains("pub struct Feline"), "type name override applied");
+  assert!(types.contains("pub struct PET {"), "acronym casing applied");
+
+  let output = generate(
+    load_spec(PETSTORE).expect("petstore spec should load"),
+    GenOptions::builder()
+      .config(CodegenConfig::builder().schema_scope(SchemaScope::All).build())
+      .build(),
+  )
+  .expect("petstore should generate");
+  let types = &output.files()[Path::new("types.rs")];
+  assert!(types.contains("pub struct Cat"), "overrides do not outlive their run");
+  assert!(types.contains("pub struct Pet {"), "casing does not outlive its run");
+}
@@ -5,6 +5,8 @@ mod event_stream;
 #[cfg(test)]
 mod intersection_union;
 #[cfg(test)]
+mod library;
+#[cfg(test)]
 mod petstore;
 #[cfg(test)]
 mod petstore_server;
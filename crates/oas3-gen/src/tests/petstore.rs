@@ -8,7 +8,7 @@ fn test_list_pets_request_compiles() {
   let request = ListPetsRequest::builder()
     .api_version("v1".to_string())
     .x_sort_order(ListPetsRequestHeaderXSortOrder::Asc)
-    .x_only(vec![ListPetsRequestHeaderXonly::Bird, ListPetsRequestHeaderXonly::Fish])
+    .x_only(vec![ListPetsRequestHeaderXOnly::Bird, ListPetsRequestHeaderXOnly::Fish])
     .x_compatibility_date(chrono::NaiveDate::from_ymd_opt(2026, 6, 9).unwrap())
     .limit(50)
     .build();
@@ -152,6 +152,28 @@ fn test_error_struct_compiles() {
   assert_eq!(error.message, "Not found", "message should match");
 }
 
+#[tokio::test]
+async fn test_default_response_keeps_status_and_error_body() {
+  let mock_response = http::Response::builder()
+    .status(503)
+    .header("content-type", "application/json")
+    .body(r#"{"code": 503, "message": "Unavailable"}"#)
+    .unwrap();
+  let result = ListCatsRequest::parse_response(reqwest::Response::from(mock_response))
+    .await
+    .unwrap();
+  let ListCatsResponse::Unknown { status, body } = result else {
+    panic!("Expected Unknown response, got {result:?}");
+  };
+  assert_eq!(status, http::StatusCode::SERVICE_UNAVAILABLE, "status should be kept");
+
+  let error: Box<dyn std::error::Error> = Box::new(body);
+  assert!(
+    error.to_string().contains("Unavailable"),
+    "error should display its body"
+  );
+}
+
 #[test]
 fn test_pets_type_alias() {
   let pets: Pets = vec![
@@ -9,7 +9,7 @@ fn test_list_pets_request_compiles() {
   let request = ListPetsRequest::builder()
     .api_version("v1".to_string())
     .x_sort_order(ListPetsRequestHeaderXSortOrder::Asc)
-    .x_only(vec![ListPetsRequestHeaderXonly::Bird, ListPetsRequestHeaderXonly::Fish])
+    .x_only(vec![ListPetsRequestHeaderXOnly::Bird, ListPetsRequestHeaderXOnly::Fish])
     .x_compatibility_date(chrono::NaiveDate::from_ymd_opt(2026, 6, 9).unwrap())
     .limit(50)
     .build();
@@ -196,15 +196,18 @@ fn test_list_pets_response_into_response() {
     "ok response should have 200 status"
   );
 
-  let error_response = ListPetsResponse::Unknown(Error {
-    code: 500,
-    message: "Internal error".to_string(),
-  });
+  let error_response = ListPetsResponse::Unknown {
+    status: http::StatusCode::SERVICE_UNAVAILABLE,
+    body: Error {
+      code: 503,
+      message: "Unavailable".to_string(),
+    },
+  };
   let response = error_response.into_response();
   assert_eq!(
     response.status(),
-    http::StatusCode::OK,
-    "unknown response uses default status"
+    http::StatusCode::SERVICE_UNAVAILABLE,
+    "default response keeps its status"
   );
 }
 
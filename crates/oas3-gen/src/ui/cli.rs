@@ -49,15 +49,60 @@ pub enum Commands {
     list_command: ListCommands,
   },
   /// Generates idiomatic, type-safe Rust code from an OpenAPI v3.1 (OAS31) specification.
-  Generate(GenerateCommand),
+  Generate(Box<GenerateCommand>),
+  /// Writes the spec's schema and response examples as JSON files, with tests that
+  /// deserialize each into the generated types
+  Fixtures {
+    /// Path to the OpenAPI specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+    /// Directory to write the fixtures and `tests.rs` into
+    #[arg(short, long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    output: PathBuf,
+    /// Module path `tests.rs` imports the generated types from
+    #[arg(long, value_name = "PATH", default_value = "super")]
+    types_path: String,
+  },
+  /// Writes a criterion benchmark target (de)serializing the spec examples of the largest
+  /// generated structs and of every discriminated enum
+  Benches {
+    /// Path to the OpenAPI specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+    /// Directory to write `serde.rs` into, usually the crate's `benches` directory
+    #[arg(short, long, value_name = "DIR", value_hint = ValueHint::DirPath)]
+    output: PathBuf,
+    /// Module path the benchmarks import the generated types from, such as `my_api::types`
+    #[arg(long, value_name = "PATH")]
+    types_path: String,
+  },
+  /// Reports schema and operation counts, nesting depth, unions, cycles, and the
+  /// number of types generation would produce
+  Stats {
+    /// Path to the OpenAPI specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+  },
+  /// Exports the schema dependency graph, highlighting reference cycles and error schemas
+  Graph {
+    /// Path to the OpenAPI specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+    /// File to write the graph to [default: stdout]
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    output: Option<PathBuf>,
+    /// Graph description language
+    #[arg(long, value_enum, default_value_t = GraphFormat::Mermaid)]
+    format: GraphFormat,
+  },
 }
 
 #[derive(Args, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct GenerateCommand {
-  /// Sets the generation mode
-  #[arg(value_enum, default_value = "types")]
-  pub mode: GenerateMode,
+  /// Sets the generation mode [default: types]
+  #[arg(value_enum)]
+  pub mode: Option<GenerateMode>,
 
   /// Path to the OpenAPI specification file
   #[arg(
@@ -68,9 +113,9 @@ pub struct GenerateCommand {
     display_order = 0,
     help_heading = "Required"
   )]
-  pub input: PathBuf,
+  pub input: Option<PathBuf>,
 
-  /// Path for generated output (file for types/client, directory for client-mod/server-mod)
+  /// Path for generated output (file for types/client/server/mock/tests/proptests, directory for client-mod/server-mod/workspace/--emit)
   #[arg(
     short,
     long,
@@ -81,40 +126,109 @@ pub struct GenerateCommand {
   )]
   pub output: Option<PathBuf>,
 
-  /// Module visibility for generated items
+  /// Also write a sorted list of every public item in the generated code to this file,
+  /// for diffing between generator and spec versions
   #[arg(
-    short = 'C',
     long,
-    value_name = "PUB",
-    default_value = "public",
-    display_order = 10,
+    value_name = "FILE",
+    value_hint = ValueHint::FilePath,
+    display_order = 9,
     help_heading = "Code Generation"
   )]
-  pub visibility: Visibility,
+  pub public_api_report: Option<PathBuf>,
 
-  /// Enable OData-specific field optionality rules (makes @odata.* fields optional on concrete types)
-  #[arg(long, default_value_t = false, display_order = 11, help_heading = "Code Generation")]
-  pub odata_support: bool,
+  /// Executable asked to name types, fields, and methods before the built-in rules;
+  /// it reads one JSON request per line and answers with a JSON name or null
+  #[arg(
+    long,
+    value_name = "FILE",
+    value_hint = ValueHint::FilePath,
+    display_order = 9,
+    help_heading = "Code Generation"
+  )]
+  pub naming_hook: Option<PathBuf>,
+
+  /// After writing, compile the generated code in a scratch crate and report errors
+  /// with the schema or operation each one came from
+  #[arg(long, default_value_t = false, display_order = 9, help_heading = "Code Generation")]
+  pub verify_build: bool,
 
-  /// Specifies how to handle enum case sensitivity and duplicates
+  /// Keep a content hash cache in the output directory and rewrite only the modules
+  /// whose inputs changed; combine with `--split-by tag` to skip unchanged tag modules
+  #[arg(long, default_value_t = false, display_order = 9, help_heading = "Code Generation")]
+  pub incremental: bool,
+
+  /// Leave files untouched when the spec hash recorded in their header matches the
+  /// hash of the current spec and options
+  #[arg(long, default_value_t = false, display_order = 9, help_heading = "Code Generation")]
+  pub skip_if_unchanged: bool,
+
+  /// Generate without writing anything, and print a unified diff against the files
+  /// already in the output location; exits with an error when any file would change
+  #[arg(long, default_value_t = false, display_order = 9, help_heading = "Code Generation")]
+  pub dry_run: bool,
+
+  /// Read options from this file instead of `oas3-gen.toml` or `.oas3-gen.yaml` in the current directory
+  #[arg(
+    long,
+    value_name = "FILE",
+    value_hint = ValueHint::FilePath,
+    display_order = 1,
+    help_heading = "Config File"
+  )]
+  pub config: Option<PathBuf>,
+
+  /// Ignore any config file in the current directory
+  #[arg(
+    long,
+    default_value_t = false,
+    conflicts_with = "config",
+    display_order = 1,
+    help_heading = "Config File"
+  )]
+  pub no_config: bool,
+
+  /// Generate these outputs together into the output directory, sharing one conversion (replaces the mode)
   #[arg(
     long,
     value_enum,
-    default_value_t,
-    display_order = 12,
+    value_name = "OUTPUTS",
+    value_delimiter = ',',
+    conflicts_with = "mode",
+    display_order = 9,
     help_heading = "Code Generation"
   )]
-  pub enum_mode: EnumCaseMode,
+  pub emit: Option<Vec<EmitTarget>>,
+
+  /// Crate name prefix for workspace mode, which generates `<PREFIX>-types` and `<PREFIX>-client` [default: api]
+  #[arg(long, value_name = "PREFIX", display_order = 9, help_heading = "Code Generation")]
+  pub crate_prefix: Option<String>,
 
-  /// Controls how enum variants are ordered in generated code
+  /// Module path the `client` mode output imports generated types from, as in `use <PATH>::*;` [default: super::types]
+  #[arg(long, value_name = "PATH", display_order = 9, help_heading = "Code Generation")]
+  pub types_import_path: Option<String>,
+
+  /// Module visibility for generated items [default: public]
   #[arg(
+    short = 'C',
     long,
-    value_enum,
-    default_value_t,
-    display_order = 12,
+    value_name = "PUB",
+    display_order = 10,
     help_heading = "Code Generation"
   )]
-  pub enum_layout: EnumLayout,
+  pub visibility: Option<Visibility>,
+
+  /// Enable OData-specific field optionality rules (makes @odata.* fields optional on concrete types)
+  #[arg(long, default_value_t = false, display_order = 11, help_heading = "Code Generation")]
+  pub odata_support: bool,
+
+  /// Specifies how to handle enum case sensitivity and duplicates [default: merge]
+  #[arg(long, value_enum, display_order = 12, help_heading = "Code Generation")]
+  pub enum_mode: Option<EnumCaseMode>,
+
+  /// Controls how enum variants are ordered in generated code [default: spec]
+  #[arg(long, value_enum, display_order = 12, help_heading = "Code Generation")]
+  pub enum_layout: Option<EnumLayout>,
 
   /// Disable generation of ergonomic helper methods for enum variants
   #[arg(long, default_value_t = false, display_order = 13, help_heading = "Code Generation")]
@@ -131,6 +245,28 @@ pub struct GenerateCommand {
   )]
   pub customize: Option<Vec<String>>,
 
+  /// Comma-separated schemas whose structs reject unknown fields, whatever their `additionalProperties`
+  #[arg(
+    long = "strict-type",
+    action = ArgAction::Append,
+    value_name = "SCHEMA",
+    value_delimiter = ',',
+    display_order = 14,
+    help_heading = "Code Generation"
+  )]
+  pub strict_types: Option<Vec<String>>,
+
+  /// Comma-separated schemas whose structs accept unknown fields, even with `additionalProperties: false`
+  #[arg(
+    long = "lenient-type",
+    action = ArgAction::Append,
+    value_name = "SCHEMA",
+    value_delimiter = ',',
+    display_order = 14,
+    help_heading = "Code Generation"
+  )]
+  pub lenient_types: Option<Vec<String>>,
+
   /// Generate all schemas, even those unreferenced by selected operations
   #[arg(
     group = "filter",
@@ -149,16 +285,246 @@ pub struct GenerateCommand {
   #[arg(long, default_value_t = false, display_order = 16, help_heading = "Code Generation")]
   pub enable_builders: bool,
 
+  /// Generate builder methods on request structs and bon builder derives on schema structs
+  /// with at least five optional fields
+  #[arg(long, default_value_t = false, display_order = 16, help_heading = "Code Generation")]
+  pub request_builders: bool,
+
   /// Emit `std::collections::HashMap` and `Vec` instead of `indexmap::IndexMap`/`IndexSet`
   /// for generated map fields and `uniqueItems` arrays. JSON key/element order is no longer
   /// preserved at runtime when this is enabled.
   #[arg(long, default_value_t = false, display_order = 18, help_heading = "Code Generation")]
   pub no_ordered_collections: bool,
 
+  /// Keep one type per inline schema instead of merging inline structs and enums that
+  /// differ only in their names and docs
+  #[arg(long, default_value_t = false, display_order = 18, help_heading = "Code Generation")]
+  pub no_merge_identical_types: bool,
+
+  /// Derive `Eq`, `Hash`, `PartialOrd`, and `Ord` on schema structs and enums whose fields
+  /// all support them, so they can be used as map keys and sorted
+  #[arg(long, default_value_t = false, display_order = 18, help_heading = "Code Generation")]
+  pub extra_derives: bool,
+
+  /// Generate inline objects with at most N properties, all of them scalars, as maps of
+  /// `serde_json::Value` instead of named structs
+  #[arg(long, value_name = "N", display_order = 18, help_heading = "Code Generation")]
+  pub inline_object_threshold: Option<usize>,
+
+  /// Leave `Clone` off structs with more than N fields, and off every type that holds
+  /// one; the affected types are listed in the generation stats
+  #[arg(long, value_name = "N", display_order = 18, help_heading = "Code Generation")]
+  pub max_clone_fields: Option<usize>,
+
+  /// Return `oas3_gen_support::WithRawBody<T>` from client methods, retaining the raw
+  /// response bytes alongside the typed value (streaming responses are unaffected)
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub retain_raw_body: bool,
+
+  /// Generate clients that can record HTTP interactions to cassette files and replay them
+  /// in tests via `with_cassette` (set `OAS3_VCR=record` to record)
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub vcr: bool,
+
+  /// Generate clients with a `with_retry` method that retries rate-limited (429) and
+  /// unavailable (503) responses with backoff, honoring `Retry-After`
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub retry: bool,
+
+  /// Generate clients with a `with_hook` method for `before_send`/`after_receive`
+  /// middleware, and `{operation}_with_options` methods taking a per-call timeout and headers
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub request_hooks: bool,
+
+  /// Generate clients with a `last_response` method returning the status, headers, and
+  /// timing of the most recent response alongside the typed results
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub last_response: bool,
+
+  /// Implement `tower::Service<{Op}Request>` on the client for every operation, so calls
+  /// can be layered with tower middleware such as timeouts and load shedding
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub tower_services: bool,
+
+  /// Add a `batch` method to clients that runs many requests for one operation with
+  /// bounded concurrency and returns the results in input order
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub batch: bool,
+
+  /// Generate a `route_tests` module asserting the URL each client method builds from
+  /// sample path parameters, including percent-encoding
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub route_tests: bool,
+
+  /// Make generated server handlers validate requests against the spec's constraints and
+  /// answer violations with an RFC 7807 `application/problem+json` response
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub validate_requests: bool,
+
+  /// Add a `classify` function to every response enum mapping the statuses its operation
+  /// documents to success, client error, server error, or retryable
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub response_classes: bool,
+
+  /// Make the client body an enum with one variant per media type when an operation
+  /// accepts several, such as JSON and `multipart/form-data`
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub request_body_variants: bool,
+
+  /// Generate `{operation}_paginated` stream helpers for list operations whose response
+  /// carries a cursor such as `next_page_token` or `nextLink`, or a `Link` header
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub paginate: bool,
+
+  /// Additional comma-separated response fields to treat as pagination cursors (implies --paginate)
+  #[arg(
+    long = "pagination-cursor",
+    action = ArgAction::Append,
+    value_name = "FIELD",
+    value_delimiter = ',',
+    display_order = 19,
+    help_heading = "Code Generation"
+  )]
+  pub pagination_cursors: Option<Vec<String>>,
+
+  /// Runtime the generated client is compiled for: `native` uses tokio, `wasm` targets
+  /// `wasm32-unknown-unknown` with `wasm-bindgen-futures` [default: native]
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub client_runtime: Option<ClientRuntimeMode>,
+
+  /// Return a generated `{Client}Error` enum from client methods instead of
+  /// `anyhow::Error`, with a variant per error response schema
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub typed_errors: bool,
+
+  /// Add a `prelude` module to client modules re-exporting the client, request and
+  /// response types, and error types
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub prelude: bool,
+
+  /// Write one types module per OpenAPI tag next to a shared `types` module
+  /// (client-mod, server-mod, and --emit only)
+  #[arg(
+    long,
+    value_enum,
+    value_name = "KEY",
+    display_order = 19,
+    help_heading = "Code Generation"
+  )]
+  pub split_by: Option<SplitBy>,
+
+  /// Add a `Malformed` variant to response enums that captures the status, raw body, and
+  /// error when a response fails to deserialize, instead of returning an error
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub malformed_variant: bool,
+
+  /// Controls how tolerant generated types are of responses that deviate from the spec [default: strict]
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub deserialize_profile: Option<DeserializeMode>,
+
+  /// Accept numeric values sent as quoted strings (e.g. `"42"`) on integer and number fields
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub coerce_numbers: bool,
+
+  /// Keep `readOnly` properties out of requests and `writeOnly` properties out of responses, making both optional
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub read_write_only: bool,
+
+  /// Send `readOnly` properties in client requests instead of skipping them when serializing
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub send_read_only: bool,
+
+  /// Wire format for `format: duration` string fields [default: iso8601]
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub duration_format: Option<DurationFormat>,
+
+  /// Map `type: string, format: decimal` fields to a decimal type instead of `String`
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub decimal_crate: Option<DecimalCrate>,
+
+  /// Width of generated integer types: `spec` honors `format: int32`, `wide` makes every integer `i64` or `u64` [default: spec]
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub integer_width: Option<IntegerWidth>,
+
+  /// Map integers with `minimum: 0` (or a non-negative `exclusiveMinimum`) to unsigned types and drop the redundant range check
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub unsigned_from_minimum: bool,
+
+  /// Add an `Other` variant to integer enums that keeps values the spec does not list instead of failing to deserialize
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub open_integer_enums: bool,
+
+  /// Treat `nullable: true` and `x-nullable: true` as nullability wherever they appear, including next to `$ref`, as in OpenAPI 3.0-era specs
+  #[arg(long, default_value_t = false, display_order = 19, help_heading = "Code Generation")]
+  pub vendor_nullable: bool,
+
+  /// Controls how acronyms are capitalized in type names [default: preserve]
+  #[arg(long, value_enum, display_order = 19, help_heading = "Code Generation")]
+  pub acronym_case: Option<AcronymCaseMode>,
+
+  /// Additional comma-separated acronyms to recognize in names (e.g., ETAG,OIDC)
+  #[arg(
+    long = "acronym",
+    action = ArgAction::Append,
+    value_name = "WORD",
+    value_delimiter = ',',
+    display_order = 19,
+    help_heading = "Code Generation"
+  )]
+  pub acronyms: Option<Vec<String>>,
+
   /// Format documentation comments using mdformat (requires mdformat installed)
   #[arg(long, default_value_t = false, display_order = 17, help_heading = "Code Generation")]
   pub doc_format: bool,
 
+  /// How generated code is formatted: `none` writes it unformatted, `rustfmt` runs the external rustfmt after prettyplease [default: prettyplease]
+  #[arg(long, value_enum, display_order = 17, help_heading = "Code Generation")]
+  pub format: Option<OutputFormat>,
+
+  /// Convert component schemas on N threads, merging the results in schema order [default: 1]
+  #[arg(long, value_name = "N", display_order = 17, help_heading = "Code Generation")]
+  pub jobs: Option<usize>,
+
+  /// Additional comma-separated lints to allow at the top of generated files (e.g., clippy::pedantic)
+  #[arg(
+    long = "allow-lint",
+    action = ArgAction::Append,
+    value_name = "LINT",
+    value_delimiter = ',',
+    display_order = 17,
+    help_heading = "File Header"
+  )]
+  pub allow_lints: Option<Vec<String>>,
+
+  /// Comma-separated lints to deny at the top of generated files (e.g., unsafe_code)
+  #[arg(
+    long = "deny-lint",
+    action = ArgAction::Append,
+    value_name = "LINT",
+    value_delimiter = ',',
+    display_order = 17,
+    help_heading = "File Header"
+  )]
+  pub deny_lints: Option<Vec<String>>,
+
+  /// File whose contents are written as a comment at the top of every generated file
+  #[arg(
+    long,
+    value_name = "FILE",
+    value_hint = ValueHint::FilePath,
+    display_order = 17,
+    help_heading = "File Header"
+  )]
+  pub license_header: Option<PathBuf>,
+
+  /// Start every generated file with a `// @generated` marker comment
+  #[arg(long, default_value_t = false, display_order = 17, help_heading = "File Header")]
+  pub generated_marker: bool,
+
+  /// Leave the input spec path out of the generated file header
+  #[arg(long, default_value_t = false, display_order = 17, help_heading = "File Header")]
+  pub omit_source_path: bool,
+
   /// Include only the specified comma-separated operation IDs
   #[arg(
     group = "filter",
@@ -174,6 +540,7 @@ pub struct GenerateCommand {
   #[arg(
     group = "filter",
     long, action = ArgAction::Append,
+    visible_alias = "exclude-operations",
     value_name = "id_1,id_2,...",
     value_delimiter = ',',
     display_order = 21,
@@ -181,6 +548,26 @@ pub struct GenerateCommand {
   )]
   pub exclude: Option<Vec<String>>,
 
+  /// Include only operations carrying at least one of the comma-separated tags
+  #[arg(
+    long, action = ArgAction::Append,
+    value_name = "tag_1,tag_2,...",
+    value_delimiter = ',',
+    display_order = 23,
+    help_heading = "Operation Filtering"
+  )]
+  pub include_tags: Option<Vec<String>>,
+
+  /// Include only operations under the comma-separated path prefixes (e.g., /users,/orders)
+  #[arg(
+    long, action = ArgAction::Append,
+    value_name = "path_1,path_2,...",
+    value_delimiter = ',',
+    display_order = 24,
+    help_heading = "Operation Filtering"
+  )]
+  pub include_paths: Option<Vec<String>>,
+
   /// Enable verbose output with detailed progress information
   #[arg(
     short,
@@ -208,10 +595,33 @@ pub struct GenerateCommand {
 pub enum GenerateMode {
   Types,
   Client,
+  Server,
   #[value(name = "client-mod")]
   ClientMod,
   #[value(name = "server-mod")]
   ServerMod,
+  Workspace,
+  Mock,
+  Tests,
+  Proptests,
+}
+
+/// An output selectable with `--emit`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmitTarget {
+  /// Type definitions (`types.rs`)
+  Types,
+  /// HTTP client (`client.rs`)
+  Client,
+  /// Axum server trait (`server.rs`)
+  Server,
+}
+
+/// How `--split-by` groups generated types into modules.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitBy {
+  /// One module per operation's first OpenAPI tag
+  Tag,
 }
 
 #[derive(ValueEnum, Clone, Debug, Default)]
@@ -234,6 +644,83 @@ pub enum EnumLayout {
   Sorted,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AcronymCaseMode {
+  #[default]
+  /// Keep the capitalization of mixed-case names (e.g., "HTTPProxyURL" stays "HTTPProxyURL")
+  Preserve,
+  /// Always uppercase known acronyms (e.g., "http_proxy_url" becomes "HTTPProxyURL")
+  Upper,
+  /// Capitalize acronyms like any other word (e.g., "HTTPProxyURL" becomes "HttpProxyUrl")
+  Pascal,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeserializeMode {
+  #[default]
+  /// Deserialize exactly as the spec declares
+  Strict,
+  /// Accept quoted numbers, empty strings for optional fields, and unknown enum values
+  Lenient,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DurationFormat {
+  #[default]
+  /// ISO 8601 durations (e.g., "PT5M")
+  Iso8601,
+  /// Human-readable durations (e.g., "5m", "1h 30m")
+  Humantime,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+  /// Write the generated token streams unformatted
+  None,
+  /// Format with prettyplease, then with the `rustfmt` found on `PATH`
+  Rustfmt,
+  #[default]
+  /// Format with prettyplease
+  Prettyplease,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecimalCrate {
+  /// `rust_decimal::Decimal`, re-exported by `oas3-gen-support` (28 significant digits)
+  #[value(name = "rust_decimal")]
+  RustDecimal,
+  /// `bigdecimal::BigDecimal`, for arbitrary precision
+  #[value(name = "bigdecimal")]
+  BigDecimal,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntegerWidth {
+  #[default]
+  /// Integer types follow each schema's `format` (e.g., `int32` becomes `i32`)
+  Spec,
+  /// Every integer is `i64`, or `u64` for unsigned formats
+  Wide,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClientRuntimeMode {
+  #[default]
+  /// Native targets, with `tokio` timers and request compression
+  Native,
+  /// Browsers (`wasm32-unknown-unknown`), with `wasm-bindgen-futures` timers and no disk access
+  Wasm,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphFormat {
+  #[default]
+  /// Mermaid flowchart, rendered by GitHub and most Markdown viewers
+  Mermaid,
+  /// Graphviz DOT, rendered with `dot -Tsvg`
+  Dot,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ListCommands {
   /// List all operations defined in the OpenAPI specification
@@ -242,4 +729,16 @@ pub enum ListCommands {
     #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
     input: PathBuf,
   },
+  /// List component schemas with their generated kind, cycle membership, and usage
+  Schemas {
+    /// Path to the OpenAPI JSON specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+  },
+  /// List tags with the number of operations under each
+  Tags {
+    /// Path to the OpenAPI JSON specification file
+    #[arg(short, long, value_name = "FILE", value_hint = ValueHint::AnyPath)]
+    input: PathBuf,
+  },
 }
@@ -140,6 +140,28 @@ impl Colors {
     }
   }
 
+  pub const fn added(&self) -> Color {
+    if !self.enabled {
+      return Color::Reset;
+    }
+
+    match self.theme {
+      Theme::Dark => Color::Rgb { r: 126, g: 186, b: 112 },
+      Theme::Light => Color::Rgb { r: 34, g: 134, b: 58 },
+    }
+  }
+
+  pub const fn removed(&self) -> Color {
+    if !self.enabled {
+      return Color::Reset;
+    }
+
+    match self.theme {
+      Theme::Dark => Color::Rgb { r: 224, g: 108, b: 117 },
+      Theme::Light => Color::Rgb { r: 185, g: 44, b: 44 },
+    }
+  }
+
   const fn to_clap(color: Color) -> Option<clap::builder::styling::Color> {
     use clap::builder::styling::{AnsiColor, Color as ClapColor, RgbColor};
 
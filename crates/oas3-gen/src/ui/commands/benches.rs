@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crossterm::style::Stylize;
+
+use crate::{
+  generator::{CodegenConfig, SchemaScope, codegen::Visibility, orchestrator::Orchestrator},
+  ui::Colors,
+  utils::spec::SpecLoader,
+};
+
+/// Writes a criterion benchmark target under `output` that (de)serializes the spec
+/// examples of the largest structs and the discriminated enums, importing the generated
+/// types from `types_path`.
+pub async fn write_benches(input: &Path, output: &Path, types_path: &str, colors: &Colors) -> anyhow::Result<()> {
+  let types_path = syn::parse_str::<syn::Path>(types_path)
+    .map_err(|err| anyhow::anyhow!("Invalid --types-path `{types_path}`: {err}"))?;
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let orchestrator = Orchestrator::new(spec, Visibility::default(), config, None, None);
+  let files = orchestrator.generate_benches(&types_path, &input.display().to_string())?;
+
+  tokio::fs::create_dir_all(output).await?;
+  for (path, contents) in &files {
+    tokio::fs::write(output.join(path), contents).await?;
+  }
+
+  println!(
+    "{} {}",
+    "Wrote serde benchmarks to".with(colors.success()),
+    output.display().to_string().with(colors.value())
+  );
+  Ok(())
+}
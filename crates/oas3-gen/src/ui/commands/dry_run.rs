@@ -0,0 +1,318 @@
+use std::{
+  fmt,
+  ops::Range,
+  path::{Path, PathBuf},
+};
+
+use crossterm::style::Stylize;
+
+use crate::ui::Colors;
+
+/// Unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Edits past which a file is shown as replaced outright instead of diffed line by line.
+const MAX_EDIT_DISTANCE: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+  Equal,
+  Delete,
+  Insert,
+}
+
+/// One line of a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiffLine<'a> {
+  Header(String),
+  Hunk(String),
+  Context(&'a str),
+  Removed(&'a str),
+  Added(&'a str),
+}
+
+impl fmt::Display for DiffLine<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let strip = |line: &'_ str| line.strip_suffix('\n').unwrap_or(line).to_string();
+    match self {
+      Self::Header(text) | Self::Hunk(text) => write!(f, "{text}"),
+      Self::Context(line) => write!(f, " {}", strip(line)),
+      Self::Removed(line) => write!(f, "-{}", strip(line)),
+      Self::Added(line) => write!(f, "+{}", strip(line)),
+    }
+  }
+}
+
+/// Prints a unified diff between each file on disk and the contents generation would
+/// write in its place, and returns how many files differ.
+///
+/// Missing files are diffed against `/dev/null`.
+pub async fn print_output_diff(files: &[(PathBuf, String)], colors: &Colors) -> anyhow::Result<usize> {
+  let mut changed = 0;
+  for (path, generated) in files {
+    let existing = match tokio::fs::read_to_string(path).await {
+      Ok(existing) => Some(existing),
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+      Err(e) => anyhow::bail!("Failed to read {}: {e}", path.display()),
+    };
+    if existing.as_deref() == Some(generated.as_str()) {
+      continue;
+    }
+
+    changed += 1;
+    for line in unified_diff(path, existing.as_deref(), generated) {
+      let color = match line {
+        DiffLine::Header(_) => colors.label(),
+        DiffLine::Hunk(_) => colors.info(),
+        DiffLine::Context(_) => colors.value(),
+        DiffLine::Removed(_) => colors.removed(),
+        DiffLine::Added(_) => colors.added(),
+      };
+      println!("{}", line.to_string().with(color));
+    }
+  }
+  Ok(changed)
+}
+
+fn unified_diff<'a>(path: &Path, old: Option<&'a str>, new: &'a str) -> Vec<DiffLine<'a>> {
+  let old_lines = old
+    .map(|old| old.split_inclusive('\n').collect::<Vec<_>>())
+    .unwrap_or_default();
+  let new_lines = new.split_inclusive('\n').collect::<Vec<_>>();
+  let edits = line_edits(&old_lines, &new_lines);
+
+  let old_name = old.map_or_else(|| "/dev/null".to_string(), |_| path.display().to_string());
+  let mut lines = vec![
+    DiffLine::Header(format!("--- {old_name}")),
+    DiffLine::Header(format!("+++ {}", path.display())),
+  ];
+
+  let (mut old_line, mut new_line, mut pos) = (0, 0, 0);
+  for range in hunk_ranges(&edits) {
+    for (edit, _) in &edits[pos..range.start] {
+      old_line += usize::from(*edit != Edit::Insert);
+      new_line += usize::from(*edit != Edit::Delete);
+    }
+    let hunk = &edits[range.clone()];
+    let old_len = hunk.iter().filter(|(edit, _)| *edit != Edit::Insert).count();
+    let new_len = hunk.iter().filter(|(edit, _)| *edit != Edit::Delete).count();
+    lines.push(DiffLine::Hunk(format!(
+      "@@ -{} +{} @@",
+      hunk_span(old_line, old_len),
+      hunk_span(new_line, new_len)
+    )));
+    lines.extend(hunk.iter().map(|&(edit, line)| match edit {
+      Edit::Equal => DiffLine::Context(line),
+      Edit::Delete => DiffLine::Removed(line),
+      Edit::Insert => DiffLine::Added(line),
+    }));
+    old_line += old_len;
+    new_line += new_len;
+    pos = range.end;
+  }
+  lines
+}
+
+/// Formats the line span of one side of a hunk, where `start` counts the lines before it.
+fn hunk_span(start: usize, len: usize) -> String {
+  match len {
+    0 => format!("{start},0"),
+    1 => (start + 1).to_string(),
+    _ => format!("{},{len}", start + 1),
+  }
+}
+
+/// Groups changed lines with their context, merging groups whose context overlaps.
+fn hunk_ranges(edits: &[(Edit, &str)]) -> Vec<Range<usize>> {
+  let mut ranges = Vec::<Range<usize>>::new();
+  for (index, _) in edits.iter().enumerate().filter(|(_, (edit, _))| *edit != Edit::Equal) {
+    let start = index.saturating_sub(CONTEXT_LINES);
+    let end = (index + 1 + CONTEXT_LINES).min(edits.len());
+    match ranges.last_mut() {
+      Some(last) if start <= last.end => last.end = end,
+      _ => ranges.push(start..end),
+    }
+  }
+  ranges
+}
+
+/// Lines to keep, remove, and add to turn `old` into `new`.
+///
+/// Shared leading and trailing lines are matched up front, so a small change to a large
+/// file stays cheap to diff.
+fn line_edits<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Edit, &'a str)> {
+  let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+  let suffix = old[prefix..]
+    .iter()
+    .rev()
+    .zip(new[prefix..].iter().rev())
+    .take_while(|(a, b)| a == b)
+    .count();
+  let old_middle = &old[prefix..old.len() - suffix];
+  let new_middle = &new[prefix..new.len() - suffix];
+
+  let mut edits = old[..prefix]
+    .iter()
+    .map(|line| (Edit::Equal, *line))
+    .collect::<Vec<_>>();
+  edits.extend(shortest_edits(old_middle, new_middle).unwrap_or_else(|| {
+    old_middle
+      .iter()
+      .map(|line| (Edit::Delete, *line))
+      .chain(new_middle.iter().map(|line| (Edit::Insert, *line)))
+      .collect()
+  }));
+  edits.extend(old[old.len() - suffix..].iter().map(|line| (Edit::Equal, *line)));
+  edits
+}
+
+/// Finds the shortest edit script with Myers' algorithm, giving up once it would take
+/// more than [`MAX_EDIT_DISTANCE`] edits.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn shortest_edits<'a>(old: &[&'a str], new: &[&'a str]) -> Option<Vec<(Edit, &'a str)>> {
+  let (n, m) = (old.len() as isize, new.len() as isize);
+  let max = (n + m).min(MAX_EDIT_DISTANCE as isize);
+  let offset = max + 1;
+  let index = |k: isize| (k + offset) as usize;
+
+  let mut furthest = vec![0isize; 2 * offset as usize + 1];
+  let mut trace = vec![];
+  for d in 0..=max {
+    trace.push(furthest.clone());
+    for k in (-d..=d).step_by(2) {
+      let mut x = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+        furthest[index(k + 1)]
+      } else {
+        furthest[index(k - 1)] + 1
+      };
+      let mut y = x - k;
+      while x < n && y < m && old[x as usize] == new[y as usize] {
+        x += 1;
+        y += 1;
+      }
+      furthest[index(k)] = x;
+      if x >= n && y >= m {
+        return Some(backtrack(old, new, &trace, index));
+      }
+    }
+  }
+  None
+}
+
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn backtrack<'a>(
+  old: &[&'a str],
+  new: &[&'a str],
+  trace: &[Vec<isize>],
+  index: impl Fn(isize) -> usize,
+) -> Vec<(Edit, &'a str)> {
+  let (mut x, mut y) = (old.len() as isize, new.len() as isize);
+  let mut edits = vec![];
+  for (d, furthest) in trace.iter().enumerate().rev() {
+    let d = d as isize;
+    let k = x - y;
+    let prev_k = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+      k + 1
+    } else {
+      k - 1
+    };
+    let prev_x = furthest[index(prev_k)];
+    let prev_y = prev_x - prev_k;
+    while x > prev_x && y > prev_y {
+      edits.push((Edit::Equal, old[(x - 1) as usize]));
+      x -= 1;
+      y -= 1;
+    }
+    if d > 0 {
+      if x == prev_x {
+        edits.push((Edit::Insert, new[(y - 1) as usize]));
+      } else {
+        edits.push((Edit::Delete, old[(x - 1) as usize]));
+      }
+    }
+    x = prev_x;
+    y = prev_y;
+  }
+  edits.reverse();
+  edits
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn render(path: &str, old: Option<&str>, new: &str) -> Vec<String> {
+    unified_diff(Path::new(path), old, new)
+      .iter()
+      .map(ToString::to_string)
+      .collect()
+  }
+
+  #[test]
+  fn test_unified_diff_shows_changes_with_context() {
+    let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+    let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\nk\n";
+
+    assert_eq!(
+      render("src/types.rs", Some(old), new),
+      [
+        "--- src/types.rs",
+        "+++ src/types.rs",
+        "@@ -2,9 +2,10 @@",
+        " b",
+        " c",
+        " d",
+        "-e",
+        "+E",
+        " f",
+        " g",
+        " h",
+        " i",
+        " j",
+        "+k",
+      ],
+      "changes within twice the context of each other share a hunk"
+    );
+  }
+
+  #[test]
+  fn test_unified_diff_splits_distant_changes() {
+    let old = (1..=20).map(|n| format!("{n}\n")).collect::<String>();
+    let new = (1..=20)
+      .map(|n| match n {
+        2 => "two\n".to_string(),
+        19 => "nineteen\n".to_string(),
+        _ => format!("{n}\n"),
+      })
+      .collect::<String>();
+
+    let lines = render("lib.rs", Some(&old), &new);
+    let hunks = lines.iter().filter(|line| line.starts_with("@@")).collect::<Vec<_>>();
+    assert_eq!(hunks, ["@@ -1,5 +1,5 @@", "@@ -16,5 +16,5 @@"]);
+  }
+
+  #[test]
+  fn test_unified_diff_of_new_file() {
+    assert_eq!(
+      render("mod.rs", None, "pub mod types;\n"),
+      ["--- /dev/null", "+++ mod.rs", "@@ -0,0 +1 @@", "+pub mod types;"]
+    );
+  }
+
+  #[test]
+  fn test_line_edits_are_minimal() {
+    let old = ["a\n", "b\n", "c\n", "a\n", "b\n", "b\n", "a\n"];
+    let new = ["c\n", "b\n", "a\n", "b\n", "a\n", "c\n"];
+
+    let edits = line_edits(&old, &new);
+    let changes = edits.iter().filter(|(edit, _)| *edit != Edit::Equal).count();
+    assert_eq!(changes, 5, "Myers finds the shortest edit script");
+
+    let rebuilt = edits
+      .iter()
+      .filter(|(edit, _)| *edit != Edit::Delete)
+      .map(|(_, line)| *line)
+      .collect::<Vec<_>>();
+    assert_eq!(rebuilt, new);
+  }
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use crossterm::style::Stylize;
+
+use crate::{
+  generator::{CodegenConfig, SchemaScope, codegen::Visibility, orchestrator::Orchestrator},
+  ui::Colors,
+  utils::spec::SpecLoader,
+};
+
+/// Writes the example payloads of the spec under `output`, with a `tests.rs` that
+/// deserializes each into the generated type imported from `types_path`.
+pub async fn write_fixtures(input: &Path, output: &Path, types_path: &str, colors: &Colors) -> anyhow::Result<()> {
+  let types_path = syn::parse_str::<syn::Path>(types_path)
+    .map_err(|err| anyhow::anyhow!("Invalid --types-path `{types_path}`: {err}"))?;
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let orchestrator = Orchestrator::new(spec, Visibility::default(), config, None, None);
+  let files = orchestrator.generate_fixtures(&types_path, &input.display().to_string())?;
+
+  for (path, contents) in &files {
+    let path = output.join(path);
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, contents).await?;
+  }
+
+  println!(
+    "{} {}",
+    format!("Wrote {} fixtures and their tests to", files.len() - 1).with(colors.success()),
+    output.display().to_string().with(colors.value())
+  );
+  Ok(())
+}
@@ -1,25 +1,48 @@
 use std::{
-  collections::{HashMap, HashSet},
-  path::PathBuf,
+  collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+  path::{Path, PathBuf},
 };
 
 use chrono::{Local, Timelike};
 use crossterm::style::Stylize;
+use tokio::task::JoinSet;
 
 use crate::{
   generator::{
-    ClientModMode, ClientMode, CodegenConfig, CollectionTypePolicy, EnumCasePolicy, EnumDeserializePolicy,
-    EnumHelperPolicy, EnumLayoutPolicy, GenerationMode, GenerationTarget, HeaderScope, ODataPolicy, SchemaScope,
-    ServerModMode, TypesMode,
-    ast::documentation::init_doc_format,
-    codegen::{GeneratedFileType, Visibility},
+    BatchPolicy, BuilderPolicy, ClientErrorPolicy, ClientModMode, ClientMode, ClientRuntime, ClonePolicy,
+    CodegenConfig, CollectionTypePolicy, ConversionPolicy, DecimalPolicy, DeserializeProfile, DocFormatPolicy,
+    DurationFormatPolicy, EmitTargets, EnumCasePolicy, EnumDeserializePolicy, EnumHelperPolicy, EnumLayoutPolicy,
+    ExtraDerivePolicy, GenerationMode, GenerationTarget, HeaderScope, InlineObjectPolicy, IntegerEnumPolicy,
+    IntegerWidthPolicy, LastResponsePolicy, MalformedResponsePolicy, MockMode, ModuleSplitPolicy, NullabilityPolicy,
+    NumberCoercionPolicy, ODataPolicy, OutputFormatPolicy, PaginationPolicy, PreludePolicy, PropertyAccessPolicy,
+    PropertyTestsMode, RawBodyPolicy, RequestBodyPolicy, RequestHookPolicy, RequestValidationPolicy,
+    ResponseClassPolicy, RetryPolicy, RouteTestPolicy, SchemaScope, ServerModMode, ServerMode, TestsMode,
+    TowerServicePolicy, TypeMergePolicy, TypesMode, UnsignedIntegerPolicy, VcrPolicy, WorkspaceMode,
+    ast::LintConfig,
+    codegen::{
+      GeneratedFileType, GeneratedResult, Visibility, header_spec_hash, public_api::public_api_report,
+      templates::ClientTemplates, workspace::WorkspaceCrates,
+    },
     metrics::GenerationStats,
-    orchestrator::{GeneratedFinalOutput, Orchestrator},
+    naming::{
+      casing::{AcronymCase, Casing},
+      hooks::NamingHook,
+      identifiers::to_rust_type_name,
+    },
+    operation_registry::OperationFilter,
+    orchestrator::Orchestrator,
+  },
+  ui::{
+    AcronymCaseMode, ClientRuntimeMode, Colors, DecimalCrate, DeserializeMode, DurationFormat, EmitTarget,
+    EnumCaseMode, EnumLayout, GenerateCommand, GenerateMode, IntegerWidth, OutputFormat, SplitBy,
+    commands::{dry_run::print_output_diff, incremental::OutputCache, verify::verify_build},
+    config::{ConfigFile, TemplateFiles},
   },
-  ui::{Colors, EnumCaseMode, EnumLayout, GenerateCommand, GenerateMode},
   utils::spec::SpecLoader,
 };
 
+const OAS3_GEN_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 fn format_timestamp() -> String {
   let now = Local::now();
   format!("[{:02}:{:02}:{:02}]", now.hour(), now.minute(), now.second())
@@ -29,8 +52,17 @@ fn format_timestamp() -> String {
 #[allow(clippy::struct_excessive_bools)]
 pub struct GenerateConfig {
   pub mode: GenerateMode,
+  pub emit: Option<EmitTargets>,
+  pub crate_prefix: String,
+  pub types_import_path: Option<String>,
   pub input: PathBuf,
   pub output: PathBuf,
+  pub public_api_report: Option<PathBuf>,
+  pub naming_hook: Option<PathBuf>,
+  pub verify_build: bool,
+  pub incremental: bool,
+  pub skip_if_unchanged: bool,
+  pub dry_run: bool,
   pub visibility: Visibility,
   pub verbose: bool,
   pub quiet: bool,
@@ -42,11 +74,56 @@ pub struct GenerateConfig {
   pub enum_layout: EnumLayout,
   pub only_operations: Option<HashSet<String>>,
   pub excluded_operations: Option<HashSet<String>>,
+  pub included_tags: Option<HashSet<String>>,
+  pub included_paths: Option<Vec<String>>,
   pub no_helpers: bool,
   pub enable_builders: bool,
+  pub request_builders: bool,
   pub no_ordered_collections: bool,
+  pub no_merge_identical_types: bool,
+  pub extra_derives: bool,
+  pub inline_object_threshold: Option<usize>,
+  pub max_clone_fields: Option<usize>,
+  pub retain_raw_body: bool,
+  pub vcr: bool,
+  pub retry: bool,
+  pub request_hooks: bool,
+  pub last_response: bool,
+  pub client_runtime: ClientRuntimeMode,
+  pub tower_services: bool,
+  pub batch: bool,
+  pub route_tests: bool,
+  pub validate_requests: bool,
+  pub response_classes: bool,
+  pub request_body_variants: bool,
+  pub paginate: bool,
+  pub pagination_cursors: Vec<String>,
+  pub typed_errors: bool,
+  pub prelude: bool,
+  pub split_by: Option<SplitBy>,
+  pub malformed_variant: bool,
+  pub deserialize_profile: DeserializeMode,
+  pub coerce_numbers: bool,
+  pub read_write_only: bool,
+  pub send_read_only: bool,
+  pub duration_format: DurationFormat,
+  pub decimal_crate: Option<DecimalCrate>,
+  pub integer_width: IntegerWidth,
+  pub unsigned_from_minimum: bool,
+  pub open_integer_enums: bool,
+  pub vendor_nullable: bool,
+  pub acronym_case: AcronymCaseMode,
+  pub acronyms: Vec<String>,
   pub doc_format: bool,
+  pub format: OutputFormat,
+  pub jobs: Option<usize>,
+  pub lints: LintConfig,
   pub customizations: HashMap<String, String>,
+  pub strict_types: Vec<String>,
+  pub lenient_types: Vec<String>,
+  pub type_names: HashMap<String, String>,
+  pub rust_types: HashMap<String, String>,
+  pub client_templates: ClientTemplates,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -60,7 +137,7 @@ impl GenerateConfig {
     SpecLoader::open(&self.input).await?.parse()
   }
 
-  fn create_orchestrator(&self, spec: oas3::Spec) -> Orchestrator {
+  fn create_orchestrator(&self, spec: oas3::Spec, naming_hook: Option<NamingHook>) -> Orchestrator {
     let config = CodegenConfig::builder()
       .enum_case(if self.preserve_case_variants {
         EnumCasePolicy::Preserve
@@ -82,8 +159,9 @@ impl GenerateConfig {
       } else {
         ODataPolicy::Disabled
       })
-      .target(match self.mode {
-        GenerateMode::ServerMod => GenerationTarget::Server,
+      .target(match (self.emit, &self.mode) {
+        (Some(emit), _) if emit.server && !emit.client => GenerationTarget::Server,
+        (None, GenerateMode::Server | GenerateMode::ServerMod | GenerateMode::Mock) => GenerationTarget::Server,
         _ => GenerationTarget::Client,
       })
       .schema_scope(if self.all_schemas {
@@ -96,6 +174,16 @@ impl GenerateConfig {
       } else {
         HeaderScope::ReferencedOnly
       })
+      .type_merge(if self.no_merge_identical_types {
+        TypeMergePolicy::KeepAll
+      } else {
+        TypeMergePolicy::MergeIdentical
+      })
+      .extra_derives(if self.extra_derives {
+        ExtraDerivePolicy::Ordering
+      } else {
+        ExtraDerivePolicy::Standard
+      })
       .collection_types(if self.no_ordered_collections {
         CollectionTypePolicy::Hashed
       } else {
@@ -105,20 +193,255 @@ impl GenerateConfig {
         EnumLayout::Spec => EnumLayoutPolicy::Spec,
         EnumLayout::Sorted => EnumLayoutPolicy::Sorted,
       })
-      .enable_builders(self.enable_builders)
+      .raw_body(if self.retain_raw_body {
+        RawBodyPolicy::Retain
+      } else {
+        RawBodyPolicy::Discard
+      })
+      .vcr(if self.vcr {
+        VcrPolicy::Enabled
+      } else {
+        VcrPolicy::Disabled
+      })
+      .retry(if self.retry {
+        RetryPolicy::Enabled
+      } else {
+        RetryPolicy::Disabled
+      })
+      .request_hooks(if self.request_hooks {
+        RequestHookPolicy::Enabled
+      } else {
+        RequestHookPolicy::Disabled
+      })
+      .last_response(if self.last_response {
+        LastResponsePolicy::Enabled
+      } else {
+        LastResponsePolicy::Disabled
+      })
+      .client_runtime(self.runtime())
+      .tower_services(if self.tower_services {
+        TowerServicePolicy::Generate
+      } else {
+        TowerServicePolicy::Disabled
+      })
+      .batch(if self.batch {
+        BatchPolicy::Generate
+      } else {
+        BatchPolicy::Disabled
+      })
+      .route_tests(if self.route_tests {
+        RouteTestPolicy::Generate
+      } else {
+        RouteTestPolicy::Disabled
+      })
+      .response_classes(if self.response_classes {
+        ResponseClassPolicy::Generate
+      } else {
+        ResponseClassPolicy::Disabled
+      })
+      .request_bodies(if self.request_body_variants {
+        RequestBodyPolicy::Variants
+      } else {
+        RequestBodyPolicy::FirstMediaType
+      })
+      .request_validation(if self.validate_requests {
+        RequestValidationPolicy::Problem
+      } else {
+        RequestValidationPolicy::Disabled
+      })
+      .pagination(if self.paginate {
+        PaginationPolicy::Detect
+      } else {
+        PaginationPolicy::Extension
+      })
+      .pagination_cursors(self.pagination_cursors.clone())
+      .client_errors(if self.typed_errors {
+        ClientErrorPolicy::Typed
+      } else {
+        ClientErrorPolicy::Anyhow
+      })
+      .prelude(if self.prelude {
+        PreludePolicy::Generate
+      } else {
+        PreludePolicy::Disabled
+      })
+      .module_split(match self.split_by {
+        Some(SplitBy::Tag) => ModuleSplitPolicy::Tag,
+        None => ModuleSplitPolicy::Single,
+      })
+      .malformed_responses(if self.malformed_variant {
+        MalformedResponsePolicy::Capture
+      } else {
+        MalformedResponsePolicy::Fail
+      })
+      .deserialize_profile(match self.deserialize_profile {
+        DeserializeMode::Strict => DeserializeProfile::Strict,
+        DeserializeMode::Lenient => DeserializeProfile::Lenient,
+      })
+      .number_coercion(if self.coerce_numbers {
+        NumberCoercionPolicy::Coerce
+      } else {
+        NumberCoercionPolicy::Strict
+      })
+      .property_access(if self.read_write_only {
+        PropertyAccessPolicy::PerDirection
+      } else if self.send_read_only {
+        PropertyAccessPolicy::Ignore
+      } else {
+        PropertyAccessPolicy::SkipReadOnly
+      })
+      .duration_format(match self.duration_format {
+        DurationFormat::Iso8601 => DurationFormatPolicy::Iso8601,
+        DurationFormat::Humantime => DurationFormatPolicy::Humantime,
+      })
+      .output_format(match self.format {
+        OutputFormat::None => OutputFormatPolicy::None,
+        OutputFormat::Rustfmt => OutputFormatPolicy::Rustfmt,
+        OutputFormat::Prettyplease => OutputFormatPolicy::Prettyplease,
+      })
+      .doc_format(if self.doc_format {
+        DocFormatPolicy::Mdformat
+      } else {
+        DocFormatPolicy::Preserve
+      })
+      .conversion(
+        self
+          .jobs
+          .filter(|jobs| *jobs > 1)
+          .map_or(ConversionPolicy::Sequential, ConversionPolicy::Parallel),
+      )
+      .decimal(match self.decimal_crate {
+        None => DecimalPolicy::String,
+        Some(DecimalCrate::RustDecimal) => DecimalPolicy::RustDecimal,
+        Some(DecimalCrate::BigDecimal) => DecimalPolicy::BigDecimal,
+      })
+      .integer_width(match self.integer_width {
+        IntegerWidth::Spec => IntegerWidthPolicy::Spec,
+        IntegerWidth::Wide => IntegerWidthPolicy::Wide,
+      })
+      .unsigned_integers(if self.unsigned_from_minimum {
+        UnsignedIntegerPolicy::FromMinimum
+      } else {
+        UnsignedIntegerPolicy::Format
+      })
+      .integer_enums(if self.open_integer_enums {
+        IntegerEnumPolicy::Open
+      } else {
+        IntegerEnumPolicy::Closed
+      })
+      .nullability(if self.vendor_nullable {
+        NullabilityPolicy::VendorExtensions
+      } else {
+        NullabilityPolicy::Standard
+      })
+      .builders(match (self.enable_builders, self.request_builders) {
+        (true, _) => BuilderPolicy::All,
+        (false, true) => BuilderPolicy::Large,
+        (false, false) => BuilderPolicy::Disabled,
+      })
+      .inline_objects(
+        self
+          .inline_object_threshold
+          .map_or(InlineObjectPolicy::Promote, InlineObjectPolicy::MapUpTo),
+      )
+      .clone_derives(
+        self
+          .max_clone_fields
+          .map_or(ClonePolicy::Always, ClonePolicy::UpToFields),
+      )
       .customizations(self.customizations.clone())
+      .rust_types(self.rust_types.clone())
+      .client_templates(self.client_templates.clone())
+      .maybe_types_import_path(self.types_import_path.clone())
+      .strict_types(self.strict_types.clone())
+      .lenient_types(self.lenient_types.clone())
+      .lints(self.lints.clone())
+      .casing(Casing::new(
+        match self.acronym_case {
+          AcronymCaseMode::Preserve => AcronymCase::Preserve,
+          AcronymCaseMode::Upper => AcronymCase::Upper,
+          AcronymCaseMode::Pascal => AcronymCase::Pascal,
+        },
+        self.acronyms.clone(),
+      ))
+      .type_names(self.type_names.clone())
+      .maybe_naming_hook(naming_hook)
       .build();
 
-    Orchestrator::new(
-      spec,
-      self.visibility,
-      config,
-      self.only_operations.as_ref(),
-      self.excluded_operations.as_ref(),
-    )
+    let filter = OperationFilter::new(self.only_operations.as_ref(), self.excluded_operations.as_ref())
+      .with_tags(self.included_tags.as_ref())
+      .with_paths(self.included_paths.as_deref());
+
+    Orchestrator::with_operation_filter(spec, self.visibility, config, filter)
+  }
+
+  fn runtime(&self) -> ClientRuntime {
+    match self.client_runtime {
+      ClientRuntimeMode::Native => ClientRuntime::Native,
+      ClientRuntimeMode::Wasm => ClientRuntime::Wasm,
+    }
+  }
+
+  /// The file type written to `output` by the single-file modes, or `None` when a
+  /// module tree is written into the `output` directory.
+  fn single_file_type(&self) -> Option<GeneratedFileType> {
+    match self.mode {
+      _ if self.emit.is_some() => None,
+      GenerateMode::Types | GenerateMode::Tests | GenerateMode::Proptests => Some(GeneratedFileType::Types),
+      GenerateMode::Client => Some(GeneratedFileType::Client),
+      GenerateMode::Server | GenerateMode::Mock => Some(GeneratedFileType::Server),
+      GenerateMode::ClientMod | GenerateMode::ServerMod | GenerateMode::Workspace => None,
+    }
+  }
+
+  /// Every file generation writes, with its path and contents.
+  fn output_files(&self, code: &GeneratedResult) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut files = match self.single_file_type() {
+      Some(file_type) => vec![(self.output.clone(), code.code(&file_type).cloned().unwrap_or_default())],
+      None => code
+        .files()
+        .into_iter()
+        .map(|(path, source, _)| (self.output.join(path), source.clone()))
+        .collect(),
+    };
+    if let Some(path) = &self.public_api_report {
+      files.push((path.clone(), public_api_report(code)?));
+    }
+    Ok(files)
+  }
+
+  /// Describes every option that shapes the generated code, for [`Orchestrator::spec_hash`].
+  fn settings_fingerprint(&self) -> String {
+    let mut settings = self.clone();
+    settings.output = PathBuf::new();
+    settings.verify_build = false;
+    settings.skip_if_unchanged = false;
+    settings.dry_run = false;
+    settings.verbose = false;
+    settings.quiet = false;
+    let sorted = |set: Option<HashSet<String>>| set.map(|set| set.into_iter().collect::<BTreeSet<_>>());
+    let filters = [
+      sorted(settings.only_operations.take()),
+      sorted(settings.excluded_operations.take()),
+      sorted(settings.included_tags.take()),
+    ];
+    let names = [
+      std::mem::take(&mut settings.customizations),
+      std::mem::take(&mut settings.type_names),
+      std::mem::take(&mut settings.rust_types),
+    ]
+    .map(|names| names.into_iter().collect::<BTreeMap<_, _>>());
+    let naming_hook = settings
+      .naming_hook
+      .as_ref()
+      .and_then(|program| std::fs::read_to_string(program).ok());
+    format!("{settings:?}{filters:?}{names:?}{naming_hook:?}")
   }
 
   async fn write_output(&self, code: String) -> anyhow::Result<()> {
+    if self.skip_if_unchanged && is_current(&self.output, &code).await {
+      return Ok(());
+    }
     if let Some(parent) = self.output.parent() {
       tokio::fs::create_dir_all(parent).await?;
     }
@@ -126,41 +449,56 @@ impl GenerateConfig {
     Ok(())
   }
 
-  async fn write_module_output(&self, output: &GeneratedFinalOutput) -> anyhow::Result<()> {
-    tokio::fs::create_dir_all(&self.output).await?;
-    let types_code = output.code.code(&GeneratedFileType::Types).cloned().unwrap_or_default();
-    let client_code = output
-      .code
-      .code(&GeneratedFileType::Client)
-      .cloned()
-      .unwrap_or_default();
-    let mod_code = output
-      .code
-      .code(&GeneratedFileType::Module)
-      .cloned()
-      .unwrap_or_default();
-    tokio::fs::write(self.output.join("types.rs"), types_code).await?;
-    tokio::fs::write(self.output.join("client.rs"), client_code).await?;
-    tokio::fs::write(self.output.join("mod.rs"), mod_code).await?;
+  async fn write_public_api_report(&self, code: &GeneratedResult) -> anyhow::Result<()> {
+    let Some(path) = &self.public_api_report else {
+      return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, public_api_report(code)?).await?;
     Ok(())
   }
 
-  async fn write_server_module_output(&self, output: &GeneratedFinalOutput) -> anyhow::Result<()> {
+  /// Writes every file in the module tree concurrently, skipping those `previous`
+  /// shows are already on disk, then records the tree for the next incremental run.
+  ///
+  /// Fails with every file that could not be written, not just the first.
+  async fn write_module_tree(&self, code: &GeneratedResult, previous: &OutputCache) -> anyhow::Result<()> {
     tokio::fs::create_dir_all(&self.output).await?;
-    let types_code = output.code.code(&GeneratedFileType::Types).cloned().unwrap_or_default();
-    let server_code = output
-      .code
-      .code(&GeneratedFileType::Server)
-      .cloned()
-      .unwrap_or_default();
-    let mod_code = output
-      .code
-      .code(&GeneratedFileType::Module)
-      .cloned()
-      .unwrap_or_default();
-    tokio::fs::write(self.output.join("types.rs"), types_code).await?;
-    tokio::fs::write(self.output.join("server.rs"), server_code).await?;
-    tokio::fs::write(self.output.join("mod.rs"), mod_code).await?;
+    let mut writes = JoinSet::new();
+    for (path, source, _) in code.files() {
+      if previous.is_unchanged(&path, source) {
+        continue;
+      }
+      let path = self.output.join(path);
+      let source = source.clone();
+      let skip_if_unchanged = self.skip_if_unchanged;
+      writes.spawn(async move {
+        if skip_if_unchanged && is_current(&path, &source).await {
+          return Ok(());
+        }
+        let write = async {
+          if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+          }
+          tokio::fs::write(&path, source).await
+        };
+        write.await.map_err(|e| format!("  {}: {e}", path.display()))
+      });
+    }
+    let failures = writes
+      .join_all()
+      .await
+      .into_iter()
+      .filter_map(Result::err)
+      .collect::<Vec<_>>();
+    if !failures.is_empty() {
+      anyhow::bail!("Failed to write {} file(s):\n{}", failures.len(), failures.join("\n"));
+    }
+    if self.incremental {
+      OutputCache::save(&self.output, code).await?;
+    }
     Ok(())
   }
 }
@@ -171,6 +509,17 @@ impl GenerateConfig {
       mode,
       input,
       output,
+      public_api_report,
+      naming_hook,
+      verify_build,
+      incremental,
+      skip_if_unchanged,
+      dry_run,
+      config,
+      no_config,
+      emit,
+      crate_prefix,
+      types_import_path,
       visibility,
       odata_support,
       enum_mode,
@@ -179,47 +528,279 @@ impl GenerateConfig {
       all_schemas,
       all_headers,
       enable_builders,
+      request_builders,
       no_ordered_collections,
+      no_merge_identical_types,
+      extra_derives,
+      inline_object_threshold,
+      max_clone_fields,
+      retain_raw_body,
+      vcr,
+      retry,
+      request_hooks,
+      last_response,
+      client_runtime,
+      tower_services,
+      batch,
+      route_tests,
+      validate_requests,
+      response_classes,
+      request_body_variants,
+      paginate,
+      pagination_cursors,
+      typed_errors,
+      prelude,
+      split_by,
+      malformed_variant,
+      deserialize_profile,
+      coerce_numbers,
+      read_write_only,
+      send_read_only,
+      duration_format,
+      decimal_crate,
+      integer_width,
+      unsigned_from_minimum,
+      open_integer_enums,
+      vendor_nullable,
+      acronym_case,
+      acronyms,
       doc_format,
+      format,
+      jobs,
+      allow_lints,
+      deny_lints,
+      license_header,
+      generated_marker,
+      omit_source_path,
       only,
       exclude,
+      include_tags,
+      include_paths,
       verbose,
       quiet,
       customize,
+      strict_types,
+      lenient_types,
     } = command;
 
-    let output = match (&mode, output) {
-      (GenerateMode::ClientMod | GenerateMode::ServerMod, None) => PathBuf::from("."),
-      (_, None) => anyhow::bail!("Output path (-o) is required for types and client modes"),
+    let file = if no_config {
+      ConfigFile::default()
+    } else {
+      ConfigFile::discover(config.as_deref())?.unwrap_or_default()
+    };
+    let emit = match mode {
+      Some(_) => None,
+      None => emit.or_else(|| file.emit.clone()),
+    };
+    let mode = mode.or_else(|| file.mode.clone()).unwrap_or(GenerateMode::Types);
+    let emit = emit.map(|targets| EmitTargets {
+      client: targets.contains(&EmitTarget::Client),
+      server: targets.contains(&EmitTarget::Server),
+    });
+    let options = file.options_for(match (emit, &mode) {
+      (Some(emit), _) if emit.client => EmitTarget::Client,
+      (Some(emit), _) if emit.server => EmitTarget::Server,
+      (Some(_), _) | (None, GenerateMode::Types | GenerateMode::Tests | GenerateMode::Proptests) => EmitTarget::Types,
+      (None, GenerateMode::Client | GenerateMode::ClientMod | GenerateMode::Workspace) => EmitTarget::Client,
+      (None, GenerateMode::Server | GenerateMode::ServerMod | GenerateMode::Mock) => EmitTarget::Server,
+    });
+    let filter = file.filter;
+
+    let input = input
+      .or(file.input)
+      .ok_or_else(|| anyhow::anyhow!("Input path (-i) is required, either as a flag or in the config file"))?;
+    let output = match (&mode, output.or(file.output)) {
+      (_, None) if emit.is_some() => PathBuf::from("."),
+      (GenerateMode::ClientMod | GenerateMode::ServerMod | GenerateMode::Workspace, None) => PathBuf::from("."),
+      (_, None) => anyhow::bail!("Output path (-o) is required for types, client, server, mock, and tests modes"),
       (_, Some(path)) => path,
     };
-    let enum_policies = EnumPolicies::from(enum_mode);
-    let customizations = parse_customizations(customize)?;
+    let visibility = visibility.or(options.visibility).unwrap_or_default();
+    if mode == GenerateMode::Workspace && visibility != Visibility::Public {
+      anyhow::bail!("Workspace mode requires public visibility so the client crate can use the types crate");
+    }
+    let split_by = split_by.or(options.split_by);
+    if split_by.is_some() && emit.is_none() && !matches!(mode, GenerateMode::ClientMod | GenerateMode::ServerMod) {
+      anyhow::bail!("--split-by requires client-mod or server-mod mode, or --emit");
+    }
+    let incremental = incremental || file.incremental.unwrap_or_default();
+    if incremental
+      && emit.is_none()
+      && !matches!(
+        mode,
+        GenerateMode::ClientMod | GenerateMode::ServerMod | GenerateMode::Workspace
+      )
+    {
+      anyhow::bail!("--incremental requires client-mod, server-mod, or workspace mode, or --emit");
+    }
+    let skip_if_unchanged = skip_if_unchanged || file.skip_if_unchanged.unwrap_or_default();
+    if skip_if_unchanged && incremental {
+      anyhow::bail!("--skip-if-unchanged cannot be combined with --incremental");
+    }
+    let vcr = vcr || options.vcr.unwrap_or_default();
+    let client_runtime = client_runtime.or(options.client_runtime).unwrap_or_default();
+    if vcr && client_runtime == ClientRuntimeMode::Wasm {
+      anyhow::bail!("--vcr reads and writes cassette files, which the wasm client runtime cannot access");
+    }
+    let pagination_cursors = pagination_cursors.or(options.pagination_cursors).unwrap_or_default();
+    let paginate = paginate || options.paginate.unwrap_or_default() || !pagination_cursors.is_empty();
+    let enum_policies = EnumPolicies::from(enum_mode.or(options.enum_mode).unwrap_or_default());
+    let mut customizations = options.customize;
+    customizations.extend(parse_customizations(customize)?);
+    let strict_types = strict_types.or(options.strict_types).unwrap_or_default();
+    let lenient_types = lenient_types.or(options.lenient_types).unwrap_or_default();
+    if let Some(name) = strict_types.iter().find(|name| lenient_types.contains(name)) {
+      anyhow::bail!("`{name}` cannot be both a --strict-type and a --lenient-type");
+    }
+    let license_header = license_header
+      .or(options.license_header)
+      .map(|path| {
+        std::fs::read_to_string(&path)
+          .map_err(|e| anyhow::anyhow!("Failed to read license header '{}': {e}", path.display()))
+      })
+      .transpose()?;
+    let lints = LintConfig::builder()
+      .allow(parse_lints(allow_lints.or(options.allow_lints))?)
+      .deny(parse_lints(deny_lints.or(options.deny_lints))?)
+      .maybe_license_header(license_header)
+      .generated_marker(generated_marker || options.generated_marker.unwrap_or_default())
+      .include_source_path(!(omit_source_path || options.omit_source_path.unwrap_or_default()))
+      .build();
+    let type_names = parse_type_names(file.names)?;
+    let rust_types = parse_rust_types(file.rust_types)?;
+    let client_templates = read_client_templates(file.templates)?;
+    let types_import_path = types_import_path.or(file.types_import_path);
+    if let Some(path) = &types_import_path {
+      syn::parse_str::<syn::Path>(path).map_err(|e| anyhow::anyhow!("Invalid --types-import-path `{path}`: {e}"))?;
+    }
 
     Ok(Self {
       mode,
+      emit,
+      crate_prefix: crate_prefix.or(file.crate_prefix).unwrap_or_else(|| "api".to_string()),
+      types_import_path,
       input,
       output,
+      public_api_report: public_api_report.or(file.public_api_report),
+      naming_hook,
+      verify_build: verify_build || file.verify_build.unwrap_or_default(),
+      incremental,
+      skip_if_unchanged,
+      dry_run,
       visibility,
       verbose,
       quiet,
-      all_schemas,
-      all_headers,
-      odata_support,
+      all_schemas: all_schemas || filter.all_schemas.unwrap_or_default(),
+      all_headers: all_headers || options.all_headers.unwrap_or_default(),
+      odata_support: odata_support || options.odata_support.unwrap_or_default(),
       preserve_case_variants: enum_policies.preserve_case_variants,
       case_insensitive_enums: enum_policies.case_insensitive_enums,
-      enum_layout,
-      only_operations: only.map(|ops| ops.into_iter().collect()),
-      excluded_operations: exclude.map(|ops| ops.into_iter().collect()),
-      no_helpers,
-      enable_builders,
-      no_ordered_collections,
-      doc_format,
+      enum_layout: enum_layout.or(options.enum_layout).unwrap_or_default(),
+      only_operations: only.or(filter.only).map(|ops| ops.into_iter().collect()),
+      excluded_operations: exclude.or(filter.exclude).map(|ops| ops.into_iter().collect()),
+      included_tags: include_tags
+        .or(filter.include_tags)
+        .map(|tags| tags.into_iter().collect()),
+      included_paths: include_paths.or(filter.include_paths),
+      no_helpers: no_helpers || options.no_helpers.unwrap_or_default(),
+      enable_builders: enable_builders || options.enable_builders.unwrap_or_default(),
+      request_builders: request_builders || options.request_builders.unwrap_or_default(),
+      no_ordered_collections: no_ordered_collections || options.no_ordered_collections.unwrap_or_default(),
+      no_merge_identical_types: no_merge_identical_types || options.no_merge_identical_types.unwrap_or_default(),
+      extra_derives: extra_derives || options.extra_derives.unwrap_or_default(),
+      inline_object_threshold: inline_object_threshold.or(options.inline_object_threshold),
+      max_clone_fields: max_clone_fields.or(options.max_clone_fields),
+      retain_raw_body: retain_raw_body || options.retain_raw_body.unwrap_or_default(),
+      vcr,
+      retry: retry || options.retry.unwrap_or_default(),
+      request_hooks: request_hooks || options.request_hooks.unwrap_or_default(),
+      last_response: last_response || options.last_response.unwrap_or_default(),
+      client_runtime,
+      tower_services: tower_services || options.tower_services.unwrap_or_default(),
+      batch: batch || options.batch.unwrap_or_default(),
+      route_tests: route_tests || options.route_tests.unwrap_or_default(),
+      validate_requests: validate_requests || options.validate_requests.unwrap_or_default(),
+      response_classes: response_classes || options.response_classes.unwrap_or_default(),
+      request_body_variants: request_body_variants || options.request_body_variants.unwrap_or_default(),
+      paginate,
+      pagination_cursors,
+      typed_errors: typed_errors || options.typed_errors.unwrap_or_default(),
+      prelude: prelude || options.prelude.unwrap_or_default(),
+      split_by,
+      malformed_variant: malformed_variant || options.malformed_variant.unwrap_or_default(),
+      deserialize_profile: deserialize_profile.or(options.deserialize_profile).unwrap_or_default(),
+      coerce_numbers: coerce_numbers || options.coerce_numbers.unwrap_or_default(),
+      read_write_only: read_write_only || options.read_write_only.unwrap_or_default(),
+      send_read_only: send_read_only || options.send_read_only.unwrap_or_default(),
+      duration_format: duration_format.or(options.duration_format).unwrap_or_default(),
+      decimal_crate: decimal_crate.or(options.decimal_crate),
+      integer_width: integer_width.or(options.integer_width).unwrap_or_default(),
+      unsigned_from_minimum: unsigned_from_minimum || options.unsigned_from_minimum.unwrap_or_default(),
+      open_integer_enums: open_integer_enums || options.open_integer_enums.unwrap_or_default(),
+      vendor_nullable: vendor_nullable || options.vendor_nullable.unwrap_or_default(),
+      acronym_case: acronym_case.or(options.acronym_case).unwrap_or_default(),
+      acronyms: acronyms.or(options.acronyms).unwrap_or_default(),
+      doc_format: doc_format || options.doc_format.unwrap_or_default(),
+      format: format.or(options.format).unwrap_or_default(),
+      jobs: jobs.or(options.jobs),
+      lints,
       customizations,
+      strict_types,
+      lenient_types,
+      type_names,
+      rust_types,
+      client_templates,
     })
   }
 }
 
+fn parse_lints(lints: Option<Vec<String>>) -> anyhow::Result<Vec<String>> {
+  let lints = lints.unwrap_or_default();
+  for lint in &lints {
+    syn::parse_str::<syn::Path>(lint)
+      .map_err(|_| anyhow::anyhow!("Invalid lint '{lint}': expected a lint path (e.g., clippy::pedantic)"))?;
+  }
+  Ok(lints)
+}
+
+fn parse_type_names(names: HashMap<String, String>) -> anyhow::Result<HashMap<String, String>> {
+  for (schema, rust_name) in &names {
+    if syn::parse_str::<syn::Ident>(rust_name).is_err() || to_rust_type_name(rust_name) != *rust_name {
+      anyhow::bail!("Invalid name for schema '{schema}': '{rust_name}' is not a PascalCase Rust type name");
+    }
+    if names.contains_key(rust_name) {
+      anyhow::bail!("Invalid name for schema '{schema}': '{rust_name}' is itself renamed");
+    }
+  }
+  Ok(names)
+}
+
+fn parse_rust_types(rust_types: HashMap<String, String>) -> anyhow::Result<HashMap<String, String>> {
+  for (schema, path) in &rust_types {
+    if syn::parse_str::<syn::Type>(path).is_err() {
+      anyhow::bail!("Invalid Rust type for schema '{schema}': '{path}' is not a type path");
+    }
+  }
+  Ok(rust_types)
+}
+
+fn read_client_templates(files: TemplateFiles) -> anyhow::Result<ClientTemplates> {
+  let read = |path: Option<PathBuf>| {
+    path
+      .map(|path| {
+        std::fs::read_to_string(&path).map_err(|e| anyhow::anyhow!("Failed to read template '{}': {e}", path.display()))
+      })
+      .transpose()
+  };
+  let templates = ClientTemplates {
+    client_struct: read(files.client_struct)?,
+    method: read(files.client_method)?,
+    error_handling: read(files.client_error)?,
+  };
+  templates.validate()?;
+  Ok(templates)
+}
+
 fn parse_customizations(customize: Option<Vec<String>>) -> anyhow::Result<HashMap<String, String>> {
   let Some(entries) = customize else {
     return Ok(HashMap::new());
@@ -290,10 +871,16 @@ impl<'a> GenerateLogger<'a> {
 
   fn log_generating(&self) {
     let message = match self.config.mode {
+      _ if self.config.emit.is_some() => "Generating Rust module tree...",
       GenerateMode::Types => "Generating Rust types...",
       GenerateMode::Client => "Generating Rust client...",
+      GenerateMode::Server => "Generating Rust server...",
       GenerateMode::ClientMod => "Generating Rust client module...",
       GenerateMode::ServerMod => "Generating Rust server module...",
+      GenerateMode::Workspace => "Generating Rust types and client crates...",
+      GenerateMode::Mock => "Generating Rust mock server...",
+      GenerateMode::Tests => "Generating Rust types and example tests...",
+      GenerateMode::Proptests => "Generating Rust types and property tests...",
     };
     self.info(&message.with(self.colors.primary()).to_string());
   }
@@ -304,20 +891,31 @@ impl<'a> GenerateLogger<'a> {
     }
 
     match self.config.mode {
+      _ if self.config.emit.is_some_and(|emit| emit.client) => {
+        self.print_type_stats(stats);
+        self.print_client_stats(stats);
+      }
+      _ if self.config.emit.is_some() => self.print_type_stats(stats),
       GenerateMode::Types => self.print_type_stats(stats),
       GenerateMode::Client => self.print_client_stats(stats),
-      GenerateMode::ClientMod => {
+      GenerateMode::ClientMod | GenerateMode::Workspace => {
         self.print_type_stats(stats);
         self.print_client_stats(stats);
       }
-      GenerateMode::ServerMod => {
+      GenerateMode::Server | GenerateMode::ServerMod | GenerateMode::Mock | GenerateMode::Proptests => {
         self.print_type_stats(stats);
       }
+      GenerateMode::Tests => {
+        self.print_type_stats(stats);
+        self.print_example_coverage(stats);
+      }
     }
 
     self.print_common_stats(stats);
     self.print_cycles(stats);
     self.print_orphaned_schemas(stats);
+    self.print_clone_skipped(stats);
+    self.print_merged_types(stats);
     self.print_warnings(stats);
   }
 
@@ -340,6 +938,9 @@ impl<'a> GenerateLogger<'a> {
     if stats.webhooks_converted > 0 {
       self.stat("", format!("{} webhooks", stats.webhooks_converted));
     }
+    if stats.callbacks_converted > 0 {
+      self.stat("", format!("{} callbacks", stats.callbacks_converted));
+    }
   }
 
   fn print_client_stats(&self, stats: &GenerationStats) {
@@ -351,7 +952,27 @@ impl<'a> GenerateLogger<'a> {
     }
   }
 
+  fn print_example_coverage(&self, stats: &GenerationStats) {
+    let total = stats.schemas_with_examples + stats.schemas_without_examples.len();
+    self.stat(
+      "Example tests:",
+      format!("{} of {total} schemas", stats.schemas_with_examples),
+    );
+
+    if self.config.verbose {
+      for name in &stats.schemas_without_examples {
+        println!("              {}", name.as_str().with(self.colors.info()));
+      }
+    }
+  }
+
   fn print_common_stats(&self, stats: &GenerationStats) {
+    if stats.modules_skipped > 0 {
+      self.stat("Modules skipped:", stats.modules_skipped.to_string());
+    }
+    if self.config.verbose {
+      self.stat("Formatting:", format!("{:.2?}", stats.formatting_time));
+    }
     if !stats.warnings.is_empty() {
       self.stat("Warnings:", stats.warnings.len().to_string());
     }
@@ -376,8 +997,44 @@ impl<'a> GenerateLogger<'a> {
   }
 
   fn print_orphaned_schemas(&self, stats: &GenerationStats) {
-    if stats.orphaned_schemas_count > 0 && self.config.verbose {
-      self.stat("Orphaned schemas:", stats.orphaned_schemas_count.to_string());
+    if stats.orphaned_schemas_count == 0 {
+      return;
+    }
+
+    self.stat("Pruned schemas:", stats.orphaned_schemas_count.to_string());
+
+    if self.config.verbose {
+      for name in &stats.orphaned_schemas {
+        println!("              {}", name.as_str().with(self.colors.info()));
+      }
+    }
+  }
+
+  fn print_clone_skipped(&self, stats: &GenerationStats) {
+    if stats.clone_skipped.is_empty() {
+      return;
+    }
+
+    self.stat("Without Clone:", stats.clone_skipped.len().to_string());
+
+    if self.config.verbose {
+      for name in &stats.clone_skipped {
+        println!("              {}", name.as_str().with(self.colors.info()));
+      }
+    }
+  }
+
+  fn print_merged_types(&self, stats: &GenerationStats) {
+    if stats.merged_types.is_empty() {
+      return;
+    }
+
+    self.stat("Merged types:", stats.merged_types.len().to_string());
+
+    if self.config.verbose {
+      for name in &stats.merged_types {
+        println!("              {}", name.as_str().with(self.colors.info()));
+      }
     }
   }
 
@@ -422,13 +1079,36 @@ impl<'a> GenerateLogger<'a> {
     );
   }
 
+  fn log_comparing(&self) {
+    self.info(
+      &format!("Comparing with: {}", self.config.output.display())
+        .with(self.colors.primary())
+        .to_string(),
+    );
+  }
+
+  fn log_verifying(&self) {
+    self.info(
+      &"Verifying the generated code builds..."
+        .with(self.colors.primary())
+        .to_string(),
+    );
+  }
+
   fn log_success(&self) {
     if !self.config.quiet {
       let message = match self.config.mode {
+        _ if self.config.dry_run => "Generated code matches the existing output",
+        _ if self.config.emit.is_some() => "Successfully generated Rust module tree",
         GenerateMode::Types => "Successfully generated Rust types",
         GenerateMode::Client => "Successfully generated Rust client",
+        GenerateMode::Server => "Successfully generated Rust server",
         GenerateMode::ClientMod => "Successfully generated Rust client module",
         GenerateMode::ServerMod => "Successfully generated Rust server module",
+        GenerateMode::Workspace => "Successfully generated Rust types and client crates",
+        GenerateMode::Mock => "Successfully generated Rust mock server",
+        GenerateMode::Tests => "Successfully generated Rust example tests",
+        GenerateMode::Proptests => "Successfully generated Rust property tests",
       };
       println!();
       println!(
@@ -440,47 +1120,98 @@ impl<'a> GenerateLogger<'a> {
   }
 }
 
+/// Whether the file at `path` was generated with the same spec hash as `source`, or
+/// already holds `source` when it records no hash.
+async fn is_current(path: &Path, source: &str) -> bool {
+  let Ok(existing) = tokio::fs::read_to_string(path).await else {
+    return false;
+  };
+  match header_spec_hash(source) {
+    Some(hash) => header_spec_hash(&existing) == Some(hash),
+    None => existing == source,
+  }
+}
+
 pub async fn generate_code(config: GenerateConfig, colors: &Colors) -> anyhow::Result<()> {
   let logger = GenerateLogger::new(&config, colors);
 
   logger.log_loading();
   let spec = config.load_spec().await?;
-  init_doc_format(config.doc_format);
+  let naming_hook = config
+    .naming_hook
+    .as_deref()
+    .map(|program| NamingHook::spawn(program, &config.input))
+    .transpose()?;
 
   logger.log_generating();
-  let orchestrator = config.create_orchestrator(spec);
+  let mut orchestrator = config.create_orchestrator(spec, naming_hook.clone());
+  if !config.incremental {
+    orchestrator = orchestrator.with_spec_hash(&config.settings_fingerprint());
+  }
+  let previous = if config.incremental && !config.dry_run {
+    let (previous, modules) = OutputCache::load(&config.output).await;
+    orchestrator = orchestrator.with_module_cache(modules);
+    previous
+  } else {
+    OutputCache::default()
+  };
   let source_path = config.input.display().to_string();
 
-  let mode: &dyn GenerationMode = match config.mode {
-    GenerateMode::Types => &TypesMode,
-    GenerateMode::Client => &ClientMode,
-    GenerateMode::ClientMod => &ClientModMode,
-    GenerateMode::ServerMod => &ServerModMode,
+  let workspace_mode;
+  let output = if let Some(emit) = config.emit {
+    orchestrator.generate_emit(emit, &source_path)
+  } else {
+    let mode: &dyn GenerationMode = match config.mode {
+      GenerateMode::Types => &TypesMode,
+      GenerateMode::Client => &ClientMode,
+      GenerateMode::Server => &ServerMode,
+      GenerateMode::ClientMod => &ClientModMode,
+      GenerateMode::ServerMod => &ServerModMode,
+      GenerateMode::Mock => &MockMode,
+      GenerateMode::Tests => &TestsMode,
+      GenerateMode::Proptests => &PropertyTestsMode,
+      GenerateMode::Workspace => {
+        workspace_mode = WorkspaceMode {
+          crates: WorkspaceCrates::from_prefix(&config.crate_prefix),
+        };
+        &workspace_mode
+      }
+    };
+    orchestrator.generate(mode, &source_path)
   };
-
-  let output = orchestrator.generate(mode, &source_path)?;
+  if let Some(hook) = &naming_hook {
+    hook.close();
+  }
+  let output = output?;
+  if let Some(error) = naming_hook.as_ref().and_then(NamingHook::error) {
+    anyhow::bail!(error);
+  }
   logger.print_statistics(&output.stats);
-  logger.log_writing();
 
-  match config.mode {
-    GenerateMode::Types => {
-      let code = output.code.code(&GeneratedFileType::Types).cloned().unwrap_or_default();
-      config.write_output(code).await?;
-    }
-    GenerateMode::Client => {
-      let code = output
-        .code
-        .code(&GeneratedFileType::Client)
-        .cloned()
-        .unwrap_or_default();
-      config.write_output(code).await?;
-    }
-    GenerateMode::ClientMod => {
-      config.write_module_output(&output).await?;
-    }
-    GenerateMode::ServerMod => {
-      config.write_server_module_output(&output).await?;
+  let changed_files = if config.dry_run {
+    logger.log_comparing();
+    print_output_diff(&config.output_files(&output.code)?, colors).await?
+  } else {
+    logger.log_writing();
+    match config.single_file_type() {
+      Some(file_type) => {
+        let code = output.code.code(&file_type).cloned().unwrap_or_default();
+        config.write_output(code).await?;
+      }
+      None => config.write_module_tree(&output.code, &previous).await?,
     }
+    config.write_public_api_report(&output.code).await?;
+    0
+  };
+  if config.verify_build {
+    logger.log_verifying();
+    verify_build(&output, OAS3_GEN_VERSION, config.runtime()).await?;
+  }
+  if changed_files > 0 {
+    anyhow::bail!(
+      "{changed_files} generated file(s) differ from {}",
+      config.output.display()
+    );
   }
 
   logger.log_success();
@@ -548,4 +1279,65 @@ mod tests {
     let result = parse_customizations(Some(vec!["date_time=crate::Type=Something".to_string()])).unwrap();
     assert_eq!(result.get("date_time"), Some(&"crate::Type=Something".to_string()));
   }
+
+  #[test]
+  fn test_parse_type_names() {
+    let names = HashMap::from([("Pet".to_string(), "Animal".to_string())]);
+    assert_eq!(parse_type_names(names.clone()).unwrap(), names);
+
+    let err = parse_type_names(HashMap::from([("Pet".to_string(), "pet_type".to_string())])).unwrap_err();
+    assert!(err.to_string().contains("not a PascalCase Rust type name"));
+
+    let err = parse_type_names(HashMap::from([
+      ("Pet".to_string(), "Animal".to_string()),
+      ("Animal".to_string(), "Creature".to_string()),
+    ]))
+    .unwrap_err();
+    assert!(err.to_string().contains("is itself renamed"));
+  }
+
+  #[test]
+  fn test_parse_rust_types() {
+    let rust_types = HashMap::from([("UserId".to_string(), "my_crate::UserId".to_string())]);
+    assert_eq!(parse_rust_types(rust_types.clone()).unwrap(), rust_types);
+
+    let err = parse_rust_types(HashMap::from([("UserId".to_string(), "my crate".to_string())])).unwrap_err();
+    assert!(err.to_string().contains("is not a type path"));
+  }
+
+  #[test]
+  fn test_from_command_reads_config_file() {
+    use clap::Parser;
+
+    use crate::ui::{Cli, Commands};
+
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("oas3-gen.toml");
+    std::fs::write(
+      &config_path,
+      "input = \"spec.json\"\nmode = \"client-mod\"\n\n[options]\nvisibility = \"crate\"\nenum-layout = \"sorted\"\n\n[client]\nretry = true\n\n[server]\nvcr = true\n",
+    )
+    .unwrap();
+
+    let cli = Cli::try_parse_from([
+      "oas3-gen",
+      "generate",
+      "--config",
+      config_path.to_str().unwrap(),
+      "-C",
+      "public",
+    ])
+    .unwrap();
+    let Commands::Generate(command) = cli.command else {
+      panic!("expected generate command");
+    };
+    let config = GenerateConfig::from_command(*command).unwrap();
+
+    assert_eq!(config.mode, GenerateMode::ClientMod);
+    assert_eq!(config.input, dir.path().join("spec.json"));
+    assert_eq!(config.visibility, Visibility::Public, "flags override the config file");
+    assert_eq!(config.enum_layout, EnumLayout::Sorted);
+    assert!(config.retry, "the client section applies in client-mod mode");
+    assert!(!config.vcr, "the server section does not apply in client-mod mode");
+  }
 }
@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use crossterm::style::Stylize;
+
+use crate::{
+  generator::{CodegenConfig, SchemaScope, codegen::Visibility, orchestrator::Orchestrator},
+  ui::{Colors, GraphFormat},
+  utils::spec::SpecLoader,
+};
+
+/// Writes the schema dependency graph of the spec to `output`, or prints it when no
+/// output is given.
+pub async fn export_graph(
+  input: &Path,
+  output: Option<&Path>,
+  format: GraphFormat,
+  colors: &Colors,
+) -> anyhow::Result<()> {
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let orchestrator = Orchestrator::new(spec, Visibility::default(), config, None, None);
+  let graph = orchestrator.dependency_graph();
+  let rendered = match format {
+    GraphFormat::Mermaid => graph.to_mermaid(),
+    GraphFormat::Dot => graph.to_dot(),
+  };
+
+  let Some(output) = output else {
+    print!("{rendered}");
+    return Ok(());
+  };
+
+  if let Some(parent) = output.parent() {
+    tokio::fs::create_dir_all(parent).await?;
+  }
+  tokio::fs::write(output, rendered).await?;
+  println!(
+    "{} {}",
+    format!(
+      "Wrote a graph of {} schemas and {} references to",
+      graph.nodes.len(),
+      graph.edges.len()
+    )
+    .with(colors.success()),
+    output.display().to_string().with(colors.value())
+  );
+  Ok(())
+}
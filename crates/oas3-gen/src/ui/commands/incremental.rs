@@ -0,0 +1,152 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::generator::{
+  codegen::{GeneratedResult, incremental::ModuleCache},
+  converter::hashing::content_hash,
+};
+
+/// File in the output directory recording what the previous incremental run wrote.
+pub const CACHE_FILE: &str = ".oas3-gen-cache.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheManifest {
+  modules: BTreeMap<PathBuf, CachedModule>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedModule {
+  /// Fingerprint of the unformatted source, for modules a [`ModuleCache`] can reuse.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  input: Option<String>,
+  /// Hash of the source as written.
+  output: String,
+}
+
+/// The files an earlier incremental run wrote that are still unchanged on disk.
+#[derive(Debug, Default)]
+pub struct OutputCache {
+  written: HashMap<PathBuf, String>,
+}
+
+impl OutputCache {
+  /// Reads the cache from the `output` directory, keeping only the files whose
+  /// contents still match what was written, and returns the module sources that
+  /// code generation can reuse.
+  ///
+  /// A missing or unreadable cache is treated as empty, so the first run writes
+  /// everything.
+  pub async fn load(output: &Path) -> (Self, ModuleCache) {
+    let mut cache = Self::default();
+    let mut modules = ModuleCache::default();
+    let Ok(json) = tokio::fs::read_to_string(output.join(CACHE_FILE)).await else {
+      return (cache, modules);
+    };
+    let Ok(manifest) = serde_json::from_str::<CacheManifest>(&json) else {
+      return (cache, modules);
+    };
+
+    for (path, module) in manifest.modules {
+      let Ok(source) = tokio::fs::read_to_string(output.join(&path)).await else {
+        continue;
+      };
+      if content_hash(&source) != module.output {
+        continue;
+      }
+      if let Some(input) = module.input {
+        modules.insert(input, source);
+      }
+      cache.written.insert(path, module.output);
+    }
+    (cache, modules)
+  }
+
+  /// Whether `source` is already on disk at `path`, relative to the output directory.
+  pub fn is_unchanged(&self, path: &Path, source: &str) -> bool {
+    self.written.get(path).is_some_and(|hash| *hash == content_hash(source))
+  }
+
+  /// Records every file in `code` as the cache for the next run.
+  pub async fn save(output: &Path, code: &GeneratedResult) -> anyhow::Result<()> {
+    let modules = code
+      .files()
+      .into_iter()
+      .map(|(path, source, fingerprint)| {
+        let module = CachedModule {
+          input: fingerprint.cloned(),
+          output: content_hash(source),
+        };
+        (path, module)
+      })
+      .collect();
+    let json = serde_json::to_string_pretty(&CacheManifest { modules })?;
+    tokio::fs::write(output.join(CACHE_FILE), json).await?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::generator::codegen::GeneratedFileType;
+
+  fn tree(types: &str, tag: &str) -> GeneratedResult {
+    let mut code = GeneratedResult::types_mod("mod types;\n".to_string(), types.to_string())
+      .with_tag_modules(BTreeMap::from([("pets".to_string(), tag.to_string())]));
+    code
+      .fingerprints
+      .insert(GeneratedFileType::Types, content_hash(&format!("in:{types}")));
+    code.fingerprints.insert(
+      GeneratedFileType::Tag("pets".to_string()),
+      content_hash(&format!("in:{tag}")),
+    );
+    code
+  }
+
+  async fn write_tree(dir: &Path, code: &GeneratedResult) {
+    for (path, source, _) in code.files() {
+      let path = dir.join(path);
+      tokio::fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+      tokio::fs::write(path, source).await.unwrap();
+    }
+  }
+
+  #[tokio::test]
+  async fn test_load_keeps_only_files_unchanged_on_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let code = tree("struct Pet;\n", "fn list_pets() {}\n");
+    write_tree(dir.path(), &code).await;
+    OutputCache::save(dir.path(), &code).await.unwrap();
+
+    let (tag_path, tag_source, _) = code
+      .files()
+      .into_iter()
+      .find(|(_, source, _)| source.contains("list_pets"))
+      .unwrap();
+    tokio::fs::write(dir.path().join(&tag_path), "// edited by hand\n")
+      .await
+      .unwrap();
+
+    let (cache, modules) = OutputCache::load(dir.path()).await;
+    assert!(cache.is_unchanged(&PathBuf::from("types.rs"), "struct Pet;\n"));
+    assert!(!cache.is_unchanged(&PathBuf::from("types.rs"), "struct Dog;\n"));
+    assert!(!cache.is_unchanged(&tag_path, tag_source), "edited files are rewritten");
+    assert_eq!(
+      modules.get(&content_hash("in:struct Pet;\n")).as_deref(),
+      Some("struct Pet;\n")
+    );
+    assert_eq!(modules.get(&content_hash("in:fn list_pets() {}\n")), None);
+  }
+
+  #[tokio::test]
+  async fn test_load_without_cache_file_is_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let (cache, modules) = OutputCache::load(dir.path()).await;
+    assert!(!cache.is_unchanged(&PathBuf::from("mod.rs"), ""));
+    assert_eq!(modules.take_reused(), 0);
+  }
+}
@@ -3,11 +3,32 @@ use std::path::Path;
 use comfy_table::{Attribute, Cell, CellAlignment, ContentArrangement, Row, Table};
 
 use crate::{
-  generator::operation_registry::OperationRegistry,
+  generator::{
+    CodegenConfig, SchemaScope,
+    codegen::Visibility,
+    inventory::{SchemaUsage, summarize_tags},
+    operation_registry::OperationRegistry,
+    orchestrator::Orchestrator,
+  },
   ui::{Colors, colors::IntoComfyColor, term_width},
   utils::spec::SpecLoader,
 };
 
+pub(super) fn new_table(colors: &Colors, headers: &[&str]) -> Table {
+  let mut table = Table::new();
+  table
+    .load_preset("  ── ──            ")
+    .set_content_arrangement(ContentArrangement::Dynamic)
+    .set_width(term_width());
+
+  let mut row = Row::new();
+  for header in headers {
+    row.add_cell(Cell::new(header).fg(IntoComfyColor::into(colors.label())));
+  }
+  table.set_header(row);
+  table
+}
+
 pub async fn list_operations(input: &Path, colors: &Colors) -> anyhow::Result<()> {
   let spec = SpecLoader::open(input).await?.parse()?;
 
@@ -18,17 +39,7 @@ pub async fn list_operations(input: &Path, colors: &Colors) -> anyhow::Result<()
     .map(|entry| (entry.stable_id.clone(), entry.method.clone(), entry.path.clone()))
     .collect();
 
-  let mut table = Table::new();
-  table
-    .load_preset("  ── ──            ")
-    .set_content_arrangement(ContentArrangement::Dynamic)
-    .set_width(term_width());
-
-  let mut row = Row::new();
-  row.add_cell(Cell::new("OPERATION ID").fg(IntoComfyColor::into(colors.label())));
-  row.add_cell(Cell::new("METHOD").fg(IntoComfyColor::into(colors.label())));
-  row.add_cell(Cell::new("PATH").fg(IntoComfyColor::into(colors.label())));
-  table.set_header(row);
+  let mut table = new_table(colors, &["OPERATION ID", "METHOD", "PATH"]);
 
   for (operation_id, method, path) in operations {
     let mut row = Row::new();
@@ -50,3 +61,71 @@ pub async fn list_operations(input: &Path, colors: &Colors) -> anyhow::Result<()
 
   Ok(())
 }
+
+pub async fn list_schemas(input: &Path, colors: &Colors) -> anyhow::Result<()> {
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let config = CodegenConfig::builder().schema_scope(SchemaScope::All).build();
+  let orchestrator = Orchestrator::new(spec, Visibility::default(), config, None, None);
+
+  let mut table = new_table(colors, &["SCHEMA", "RUST TYPE", "KIND", "CYCLE", "USAGE"]);
+
+  for schema in orchestrator.schema_summaries() {
+    let usage_color = match schema.usage {
+      SchemaUsage::Unused => colors.label(),
+      _ => colors.primary(),
+    };
+
+    let mut row = Row::new();
+    row.add_cell(
+      Cell::new(schema.name)
+        .fg(IntoComfyColor::into(colors.value()))
+        .add_attribute(Attribute::Bold),
+    );
+    row.add_cell(Cell::new(schema.rust_name).fg(IntoComfyColor::into(colors.primary())));
+    row.add_cell(
+      Cell::new(schema.kind.map_or_else(|| "-".to_string(), |kind| kind.to_string()))
+        .fg(IntoComfyColor::into(colors.accent())),
+    );
+    row.add_cell(Cell::new(if schema.cyclic { "yes" } else { "" }).fg(IntoComfyColor::into(colors.accent())));
+    row.add_cell(Cell::new(schema.usage).fg(IntoComfyColor::into(usage_color)));
+    table.add_row(row);
+  }
+
+  println!("{table}");
+
+  Ok(())
+}
+
+pub async fn list_tags(input: &Path, colors: &Colors) -> anyhow::Result<()> {
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let registry = OperationRegistry::new(&spec);
+
+  let mut table = new_table(colors, &["TAG", "OPERATIONS", "PRIMARY", "DESCRIPTION"]);
+
+  for tag in summarize_tags(&spec, &registry) {
+    let mut row = Row::new();
+    row.add_cell(
+      Cell::new(tag.name)
+        .fg(IntoComfyColor::into(colors.value()))
+        .add_attribute(Attribute::Bold),
+    );
+    row.add_cell(
+      Cell::new(tag.operations)
+        .fg(IntoComfyColor::into(colors.accent()))
+        .set_alignment(CellAlignment::Right),
+    );
+    row.add_cell(
+      Cell::new(tag.primary)
+        .fg(IntoComfyColor::into(colors.accent()))
+        .set_alignment(CellAlignment::Right),
+    );
+    row.add_cell(Cell::new(tag.description.unwrap_or_default()).fg(IntoComfyColor::into(colors.primary())));
+    table.add_row(row);
+  }
+
+  println!("{table}");
+
+  Ok(())
+}
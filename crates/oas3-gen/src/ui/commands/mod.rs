@@ -1,5 +1,16 @@
+pub mod benches;
+pub mod dry_run;
+pub mod fixtures;
 pub mod generate;
+pub mod graph;
+pub mod incremental;
 pub mod list;
+pub mod stats;
+pub mod verify;
 
+pub use benches::write_benches;
+pub use fixtures::write_fixtures;
 pub use generate::{GenerateConfig, generate_code};
-pub use list::list_operations;
+pub use graph::export_graph;
+pub use list::{list_operations, list_schemas, list_tags};
+pub use stats::print_spec_stats;
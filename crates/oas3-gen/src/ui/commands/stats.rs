@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use comfy_table::{Attribute, Cell, CellAlignment, Row};
+
+use crate::{
+  generator::{CodegenConfig, codegen::Visibility, orchestrator::Orchestrator},
+  ui::{Colors, colors::IntoComfyColor, commands::list::new_table},
+  utils::spec::SpecLoader,
+};
+
+/// Prints the size and shape of the spec, with the number of types generating it
+/// with default options would produce.
+pub async fn print_spec_stats(input: &Path, colors: &Colors) -> anyhow::Result<()> {
+  let spec = SpecLoader::open(input).await?.parse()?;
+
+  let orchestrator = Orchestrator::new(spec, Visibility::default(), CodegenConfig::default(), None, None);
+  let stats = orchestrator.spec_statistics();
+
+  let rows = [
+    ("Schemas", stats.schemas.to_string()),
+    ("Operations", stats.operations.to_string()),
+    ("Average nesting depth", format!("{:.2}", stats.average_nesting_depth)),
+    ("oneOf unions", stats.one_of_unions.to_string()),
+    ("anyOf unions", stats.any_of_unions.to_string()),
+    ("Cyclic schemas", stats.cyclic_schemas.to_string()),
+    ("Projected types", stats.projected_types.to_string()),
+  ];
+
+  let mut table = new_table(colors, &["STATISTIC", "VALUE"]);
+
+  for (label, value) in rows {
+    let mut row = Row::new();
+    row.add_cell(
+      Cell::new(label)
+        .fg(IntoComfyColor::into(colors.value()))
+        .add_attribute(Attribute::Bold),
+    );
+    row.add_cell(
+      Cell::new(value)
+        .fg(IntoComfyColor::into(colors.accent()))
+        .set_alignment(CellAlignment::Right),
+    );
+    table.add_row(row);
+  }
+
+  println!("{table}");
+
+  Ok(())
+}
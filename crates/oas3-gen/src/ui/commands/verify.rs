@@ -0,0 +1,315 @@
+use std::{
+  ffi::OsString,
+  fmt::Write as _,
+  path::{Path, PathBuf},
+  sync::LazyLock,
+};
+
+use regex::Regex;
+
+use crate::generator::{
+  ClientRuntime,
+  codegen::verify::{VERIFY_CRATE, verification_files},
+  orchestrator::GeneratedFinalOutput,
+  origins::ItemOrigins,
+};
+
+/// Compiler errors listed in full before the rest are only counted.
+const MAX_REPORTED_ERRORS: usize = 5;
+
+static ITEM_RE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(?:pub(?:\([^)]*\))?\s+)?(?:async\s+)?(?:unsafe\s+)?(?:struct|enum|union|type|trait|fn|const|static|mod)\s+(?:r#)?(\w+)").unwrap()
+});
+static METHOD_RE: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^\s+(?:pub(?:\([^)]*\))?\s+)?(?:async\s+)?fn\s+(?:r#)?(\w+)").unwrap());
+
+/// A compiler error in the generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BuildError {
+  code: Option<String>,
+  message: String,
+  file: PathBuf,
+  line: usize,
+}
+
+/// Builds the generated code in a scratch crate under the system temp directory and
+/// fails with the first compiler errors, each traced back to the schema or operation
+/// that produced it.
+///
+/// Test targets are checked too, so generated `tests`, `proptests`, and route tests
+/// must compile as well.
+///
+/// The scratch crate is reused between runs so its dependencies are compiled once.
+pub async fn verify_build(
+  output: &GeneratedFinalOutput,
+  gen_version: &str,
+  runtime: ClientRuntime,
+) -> anyhow::Result<()> {
+  let dir = std::env::temp_dir().join(VERIFY_CRATE);
+  let files = verification_files(&output.code, gen_version, runtime);
+  write_scratch_crate(&dir, &files).await?;
+
+  let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+  let result = tokio::process::Command::new(cargo)
+    .args(["check", "--workspace", "--all-targets", "--message-format=json"])
+    .current_dir(&dir)
+    .output()
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to run cargo to verify the build: {e}"))?;
+  if result.status.success() {
+    return Ok(());
+  }
+
+  let errors = build_errors(&String::from_utf8_lossy(&result.stdout));
+  if errors.is_empty() {
+    anyhow::bail!(
+      "cargo check failed on the generated code in {}:\n{}",
+      dir.display(),
+      String::from_utf8_lossy(&result.stderr).trim_end()
+    );
+  }
+  anyhow::bail!(build_report(&errors, &files, &output.origins))
+}
+
+/// Replaces everything in the scratch crate except its build cache and lock file.
+async fn write_scratch_crate(dir: &Path, files: &[(PathBuf, String)]) -> anyhow::Result<()> {
+  tokio::fs::create_dir_all(dir).await?;
+  let mut entries = tokio::fs::read_dir(dir).await?;
+  while let Some(entry) = entries.next_entry().await? {
+    if matches!(entry.file_name().to_str(), Some("target" | "Cargo.lock")) {
+      continue;
+    }
+    if entry.file_type().await?.is_dir() {
+      tokio::fs::remove_dir_all(entry.path()).await?;
+    } else {
+      tokio::fs::remove_file(entry.path()).await?;
+    }
+  }
+
+  for (path, source) in files {
+    let path = dir.join(path);
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, source).await?;
+  }
+  Ok(())
+}
+
+/// Collects the errors from `cargo check --all-targets --message-format=json` output.
+fn build_errors(stdout: &str) -> Vec<BuildError> {
+  stdout
+    .lines()
+    .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+    .filter(|message| message["reason"] == "compiler-message")
+    .filter_map(|message| {
+      let diagnostic = &message["message"];
+      if diagnostic["level"] != "error" {
+        return None;
+      }
+      let span = diagnostic["spans"]
+        .as_array()?
+        .iter()
+        .find(|span| span["is_primary"] == true)?;
+      Some(BuildError {
+        code: diagnostic["code"]["code"].as_str().map(ToString::to_string),
+        message: diagnostic["message"].as_str()?.to_string(),
+        file: PathBuf::from(span["file_name"].as_str()?),
+        line: usize::try_from(span["line_start"].as_u64()?).ok()?,
+      })
+    })
+    .collect()
+}
+
+fn build_report(errors: &[BuildError], files: &[(PathBuf, String)], origins: &ItemOrigins) -> String {
+  let mut report = format!("Generated code does not compile ({} errors):", errors.len());
+  for error in errors.iter().take(MAX_REPORTED_ERRORS) {
+    let code = error.code.as_ref().map(|code| format!("[{code}]")).unwrap_or_default();
+    let _ = write!(
+      report,
+      "\n\nerror{code}: {}\n  --> {}:{}",
+      error.message,
+      error.file.display(),
+      error.line
+    );
+
+    let source = files
+      .iter()
+      .find(|(path, _)| *path == error.file)
+      .map(|(_, source)| source);
+    let Some((item, method)) = source.and_then(|source| enclosing_item(source, error.line)) else {
+      continue;
+    };
+    let location = match &method {
+      Some(method) => format!("`{item}::{method}`"),
+      None => format!("`{item}`"),
+    };
+    let origin = method
+      .as_deref()
+      .and_then(|method| origins.origin_of(method))
+      .or_else(|| origins.origin_of(&item));
+    let _ = match origin {
+      Some(origin) => write!(report, "\n  in {location}, generated from {origin}"),
+      None => write!(report, "\n  in {location}"),
+    };
+  }
+  if errors.len() > MAX_REPORTED_ERRORS {
+    let _ = write!(report, "\n\n...and {} more", errors.len() - MAX_REPORTED_ERRORS);
+  }
+  report
+}
+
+/// Finds the top-level item containing the 1-based `line`, and the method within it.
+///
+/// Errors reported on an attribute or doc comment belong to the item that follows it.
+fn enclosing_item(source: &str, line: usize) -> Option<(String, Option<String>)> {
+  let lines = source.lines().collect::<Vec<_>>();
+  let index = line.checked_sub(1).filter(|&index| index < lines.len())?;
+
+  let is_preamble = |text: &str| text.starts_with("#[") || text.starts_with("//");
+  if is_preamble(lines[index]) {
+    return lines[index..]
+      .iter()
+      .find_map(|text| item_name(text))
+      .map(|item| (item, None));
+  }
+
+  let mut method = None;
+  for text in lines[..=index].iter().rev() {
+    if let Some(item) = item_name(text) {
+      return Some((item, method));
+    }
+    if method.is_none() {
+      method = METHOD_RE.captures(text).map(|captures| captures[1].to_string());
+    }
+  }
+  None
+}
+
+/// Returns the name a top-level line declares: the item itself, or an `impl`'s self type.
+fn item_name(line: &str) -> Option<String> {
+  if let Some(captures) = ITEM_RE.captures(line) {
+    return Some(captures[1].to_string());
+  }
+
+  let rest = line.strip_prefix("impl")?;
+  let rest = match rest.strip_prefix('<') {
+    Some(generics) => {
+      let mut depth = 1;
+      let end = generics.char_indices().find_map(|(i, c)| {
+        match c {
+          '<' => depth += 1,
+          '>' => depth -= 1,
+          _ => {}
+        }
+        (depth == 0).then_some(i + 1)
+      })?;
+      &generics[end..]
+    }
+    None => rest,
+  };
+  let self_type = rest
+    .rsplit_once(" for ")
+    .map_or(rest, |(_, self_type)| self_type)
+    .trim_start();
+  let path = self_type
+    .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+    .next()?;
+  path
+    .rsplit("::")
+    .next()
+    .filter(|name| !name.is_empty())
+    .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::generator::origins::ItemOrigin;
+
+  const TYPES: &str = r"#[derive(Debug, Clone)]
+pub struct Pet {
+    pub id: i64,
+}
+impl<'de> serde::Deserialize<'de> for PetStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> {
+        todo!()
+    }
+}
+pub trait ApiServer: Send + Sync {}
+impl ApiServer for MockServer {
+    async fn list_pets(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+";
+
+  #[test]
+  fn test_enclosing_item() {
+    let cases = [
+      (1, Some(("Pet", None))),
+      (3, Some(("Pet", None))),
+      (7, Some(("PetStatus", Some("deserialize")))),
+      (10, Some(("ApiServer", None))),
+      (13, Some(("MockServer", Some("list_pets")))),
+      (99, None),
+    ];
+    for (line, expected) in cases {
+      let expected =
+        expected.map(|(item, method): (&str, Option<&str>)| (item.to_string(), method.map(ToString::to_string)));
+      assert_eq!(enclosing_item(TYPES, line), expected, "line {line}");
+    }
+  }
+
+  #[test]
+  fn test_build_errors_reads_primary_spans() {
+    let stdout = [
+      r#"{"reason":"compiler-artifact","target":{"name":"serde"}}"#,
+      r#"{"reason":"compiler-message","message":{"level":"warning","message":"unused","code":null,"spans":[{"is_primary":true,"file_name":"src/lib.rs","line_start":1}]}}"#,
+      r#"{"reason":"compiler-message","message":{"level":"error","message":"mismatched types","code":{"code":"E0308"},"spans":[{"is_primary":false,"file_name":"src/lib.rs","line_start":2},{"is_primary":true,"file_name":"src/types.rs","line_start":13}]}}"#,
+      r#"{"reason":"compiler-message","message":{"level":"error","message":"aborting due to 1 previous error","code":null,"spans":[]}}"#,
+      r#"{"reason":"build-finished","success":false}"#,
+    ]
+    .join("\n");
+    assert_eq!(
+      build_errors(&stdout),
+      vec![BuildError {
+        code: Some("E0308".to_string()),
+        message: "mismatched types".to_string(),
+        file: PathBuf::from("src/types.rs"),
+        line: 13,
+      }]
+    );
+  }
+
+  #[test]
+  fn test_build_report_names_origins() {
+    let mut origins = ItemOrigins::default();
+    origins.insert("Pet", ItemOrigin::Schema("Pet".to_string()));
+    origins.insert("list_pets", ItemOrigin::Operation("listPets".to_string()));
+    let files = vec![(PathBuf::from("src/types.rs"), TYPES.to_string())];
+    let error = |line| BuildError {
+      code: Some("E0308".to_string()),
+      message: "mismatched types".to_string(),
+      file: PathBuf::from("src/types.rs"),
+      line,
+    };
+
+    let report = build_report(&[error(7), error(13)], &files, &origins);
+    assert!(
+      report.starts_with("Generated code does not compile (2 errors):"),
+      "{report}"
+    );
+    assert!(
+      report.contains("error[E0308]: mismatched types\n  --> src/types.rs:7\n  in `PetStatus::deserialize`, generated from schema `Pet`"),
+      "{report}"
+    );
+    assert!(
+      report.contains("in `MockServer::list_pets`, generated from operation `listPets`"),
+      "{report}"
+    );
+
+    let many = (0..7).map(|_| error(3)).collect::<Vec<_>>();
+    assert!(build_report(&many, &files, &origins).ends_with("...and 2 more"));
+  }
+}
@@ -0,0 +1,433 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer, de::Error as _};
+use serde_json::{Map, Value};
+
+use crate::{
+  generator::codegen::Visibility,
+  ui::{
+    AcronymCaseMode, ClientRuntimeMode, DecimalCrate, DeserializeMode, DurationFormat, EmitTarget, EnumCaseMode,
+    EnumLayout, GenerateMode, IntegerWidth, OutputFormat, SplitBy,
+  },
+};
+
+/// Config file names searched for in the current directory, in order.
+pub const CONFIG_FILE_NAMES: &[&str] = &["oas3-gen.toml", ".oas3-gen.yaml", ".oas3-gen.yml"];
+
+/// Generation options read from an `oas3-gen.toml` or `.oas3-gen.yaml` file.
+///
+/// Every value is optional; flags given on the command line take precedence. Relative
+/// paths are resolved against the directory containing the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ConfigFile {
+  pub input: Option<PathBuf>,
+  pub output: Option<PathBuf>,
+  pub public_api_report: Option<PathBuf>,
+  pub verify_build: Option<bool>,
+  pub incremental: Option<bool>,
+  pub skip_if_unchanged: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub mode: Option<GenerateMode>,
+  #[serde(deserialize_with = "value_enum_list")]
+  pub emit: Option<Vec<EmitTarget>>,
+  pub crate_prefix: Option<String>,
+  pub types_import_path: Option<String>,
+  /// Options shared by every target.
+  pub options: GenerateOptions,
+  /// Options applied on top of `options` when generating types only.
+  pub types: GenerateOptions,
+  /// Options applied on top of `options` when generating a client.
+  pub client: GenerateOptions,
+  /// Options applied on top of `options` when generating a server.
+  pub server: GenerateOptions,
+  pub filter: FilterOptions,
+  /// Rust type names to use for schemas, keyed by schema name.
+  pub names: HashMap<String, String>,
+  /// Existing Rust types used in place of schemas, keyed by schema name.
+  pub rust_types: HashMap<String, String>,
+  pub templates: TemplateFiles,
+}
+
+/// Template files replacing parts of the generated client.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TemplateFiles {
+  pub client_struct: Option<PathBuf>,
+  pub client_method: Option<PathBuf>,
+  pub client_error: Option<PathBuf>,
+}
+
+/// Code generation options mirroring the `generate` flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct GenerateOptions {
+  #[serde(deserialize_with = "value_enum")]
+  pub visibility: Option<Visibility>,
+  pub odata_support: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub enum_mode: Option<EnumCaseMode>,
+  #[serde(deserialize_with = "value_enum")]
+  pub enum_layout: Option<EnumLayout>,
+  pub no_helpers: Option<bool>,
+  pub customize: HashMap<String, String>,
+  pub strict_types: Option<Vec<String>>,
+  pub lenient_types: Option<Vec<String>>,
+  pub all_headers: Option<bool>,
+  pub enable_builders: Option<bool>,
+  pub request_builders: Option<bool>,
+  pub no_ordered_collections: Option<bool>,
+  pub no_merge_identical_types: Option<bool>,
+  pub extra_derives: Option<bool>,
+  pub inline_object_threshold: Option<usize>,
+  pub max_clone_fields: Option<usize>,
+  pub retain_raw_body: Option<bool>,
+  pub vcr: Option<bool>,
+  pub retry: Option<bool>,
+  pub request_hooks: Option<bool>,
+  pub last_response: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub client_runtime: Option<ClientRuntimeMode>,
+  pub tower_services: Option<bool>,
+  pub batch: Option<bool>,
+  pub route_tests: Option<bool>,
+  pub validate_requests: Option<bool>,
+  pub response_classes: Option<bool>,
+  pub request_body_variants: Option<bool>,
+  pub paginate: Option<bool>,
+  pub pagination_cursors: Option<Vec<String>>,
+  pub typed_errors: Option<bool>,
+  pub prelude: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub split_by: Option<SplitBy>,
+  pub malformed_variant: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub deserialize_profile: Option<DeserializeMode>,
+  pub coerce_numbers: Option<bool>,
+  pub read_write_only: Option<bool>,
+  pub send_read_only: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub duration_format: Option<DurationFormat>,
+  #[serde(deserialize_with = "value_enum")]
+  pub decimal_crate: Option<DecimalCrate>,
+  #[serde(deserialize_with = "value_enum")]
+  pub integer_width: Option<IntegerWidth>,
+  pub unsigned_from_minimum: Option<bool>,
+  pub open_integer_enums: Option<bool>,
+  pub vendor_nullable: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub acronym_case: Option<AcronymCaseMode>,
+  pub acronyms: Option<Vec<String>>,
+  pub doc_format: Option<bool>,
+  #[serde(deserialize_with = "value_enum")]
+  pub format: Option<OutputFormat>,
+  pub jobs: Option<usize>,
+  pub allow_lints: Option<Vec<String>>,
+  pub deny_lints: Option<Vec<String>>,
+  pub license_header: Option<PathBuf>,
+  pub generated_marker: Option<bool>,
+  pub omit_source_path: Option<bool>,
+}
+
+/// Operation and schema filters mirroring the `Operation Filtering` flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct FilterOptions {
+  pub only: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+  pub include_tags: Option<Vec<String>>,
+  pub include_paths: Option<Vec<String>>,
+  pub all_schemas: Option<bool>,
+}
+
+impl ConfigFile {
+  /// Loads `path`, or the first of [`CONFIG_FILE_NAMES`] found in the current directory.
+  ///
+  /// Returns `None` when no path is given and no config file exists.
+  pub fn discover(path: Option<&Path>) -> anyhow::Result<Option<Self>> {
+    if let Some(path) = path {
+      return Self::load(path).map(Some);
+    }
+    CONFIG_FILE_NAMES
+      .iter()
+      .map(Path::new)
+      .find(|path| path.is_file())
+      .map(Self::load)
+      .transpose()
+  }
+
+  /// Parses the config file at `path`, choosing TOML or YAML by its extension.
+  pub fn load(path: &Path) -> anyhow::Result<Self> {
+    let contents = std::fs::read_to_string(path)
+      .map_err(|e| anyhow::anyhow!("Failed to read config file '{}': {e}", path.display()))?;
+    let config = match path.extension().and_then(|ext| ext.to_str()) {
+      Some("toml") => Self::from_toml(&contents),
+      Some("yaml" | "yml") => yaml_serde::from_str(&contents).map_err(anyhow::Error::from),
+      _ => anyhow::bail!(
+        "Unsupported config file '{}': expected a .toml, .yaml, or .yml extension",
+        path.display()
+      ),
+    }
+    .map_err(|e| anyhow::anyhow!("Invalid config file '{}': {e}", path.display()))?;
+    Ok(config.relative_to(path.parent().unwrap_or(Path::new(""))))
+  }
+
+  fn from_toml(contents: &str) -> anyhow::Result<Self> {
+    let document = contents.parse::<toml_edit::Document<String>>()?;
+    Ok(serde_json::from_value(toml_table_to_json(document.as_table()))?)
+  }
+
+  fn relative_to(mut self, dir: &Path) -> Self {
+    self.input = self.input.map(|path| dir.join(path));
+    self.output = self.output.map(|path| dir.join(path));
+    self.public_api_report = self.public_api_report.map(|path| dir.join(path));
+    for template in [
+      &mut self.templates.client_struct,
+      &mut self.templates.client_method,
+      &mut self.templates.client_error,
+    ] {
+      *template = template.take().map(|path| dir.join(path));
+    }
+    for options in [&mut self.options, &mut self.types, &mut self.client, &mut self.server] {
+      options.license_header = options.license_header.take().map(|path| dir.join(path));
+    }
+    self
+  }
+
+  /// Returns the shared options overlaid with the section for `target`.
+  #[must_use]
+  pub fn options_for(&self, target: EmitTarget) -> GenerateOptions {
+    let section = match target {
+      EmitTarget::Types => &self.types,
+      EmitTarget::Client => &self.client,
+      EmitTarget::Server => &self.server,
+    };
+    self.options.clone().overlay(section.clone())
+  }
+}
+
+impl GenerateOptions {
+  /// Takes each value from `other` when set, falling back to `self`. Customizations are merged.
+  #[must_use]
+  pub fn overlay(self, other: Self) -> Self {
+    let mut customize = self.customize;
+    customize.extend(other.customize);
+    Self {
+      visibility: other.visibility.or(self.visibility),
+      odata_support: other.odata_support.or(self.odata_support),
+      enum_mode: other.enum_mode.or(self.enum_mode),
+      enum_layout: other.enum_layout.or(self.enum_layout),
+      no_helpers: other.no_helpers.or(self.no_helpers),
+      customize,
+      strict_types: other.strict_types.or(self.strict_types),
+      lenient_types: other.lenient_types.or(self.lenient_types),
+      all_headers: other.all_headers.or(self.all_headers),
+      enable_builders: other.enable_builders.or(self.enable_builders),
+      request_builders: other.request_builders.or(self.request_builders),
+      no_ordered_collections: other.no_ordered_collections.or(self.no_ordered_collections),
+      no_merge_identical_types: other.no_merge_identical_types.or(self.no_merge_identical_types),
+      extra_derives: other.extra_derives.or(self.extra_derives),
+      inline_object_threshold: other.inline_object_threshold.or(self.inline_object_threshold),
+      max_clone_fields: other.max_clone_fields.or(self.max_clone_fields),
+      retain_raw_body: other.retain_raw_body.or(self.retain_raw_body),
+      vcr: other.vcr.or(self.vcr),
+      retry: other.retry.or(self.retry),
+      request_hooks: other.request_hooks.or(self.request_hooks),
+      last_response: other.last_response.or(self.last_response),
+      client_runtime: other.client_runtime.or(self.client_runtime),
+      tower_services: other.tower_services.or(self.tower_services),
+      batch: other.batch.or(self.batch),
+      route_tests: other.route_tests.or(self.route_tests),
+      validate_requests: other.validate_requests.or(self.validate_requests),
+      response_classes: other.response_classes.or(self.response_classes),
+      request_body_variants: other.request_body_variants.or(self.request_body_variants),
+      paginate: other.paginate.or(self.paginate),
+      pagination_cursors: other.pagination_cursors.or(self.pagination_cursors),
+      typed_errors: other.typed_errors.or(self.typed_errors),
+      prelude: other.prelude.or(self.prelude),
+      split_by: other.split_by.or(self.split_by),
+      malformed_variant: other.malformed_variant.or(self.malformed_variant),
+      deserialize_profile: other.deserialize_profile.or(self.deserialize_profile),
+      coerce_numbers: other.coerce_numbers.or(self.coerce_numbers),
+      read_write_only: other.read_write_only.or(self.read_write_only),
+      send_read_only: other.send_read_only.or(self.send_read_only),
+      duration_format: other.duration_format.or(self.duration_format),
+      decimal_crate: other.decimal_crate.or(self.decimal_crate),
+      integer_width: other.integer_width.or(self.integer_width),
+      unsigned_from_minimum: other.unsigned_from_minimum.or(self.unsigned_from_minimum),
+      open_integer_enums: other.open_integer_enums.or(self.open_integer_enums),
+      vendor_nullable: other.vendor_nullable.or(self.vendor_nullable),
+      acronym_case: other.acronym_case.or(self.acronym_case),
+      acronyms: other.acronyms.or(self.acronyms),
+      doc_format: other.doc_format.or(self.doc_format),
+      format: other.format.or(self.format),
+      jobs: other.jobs.or(self.jobs),
+      allow_lints: other.allow_lints.or(self.allow_lints),
+      deny_lints: other.deny_lints.or(self.deny_lints),
+      license_header: other.license_header.or(self.license_header),
+      generated_marker: other.generated_marker.or(self.generated_marker),
+      omit_source_path: other.omit_source_path.or(self.omit_source_path),
+    }
+  }
+}
+
+/// Deserializes a value by its command-line spelling (e.g. `client-mod`).
+fn value_enum<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: ValueEnum,
+{
+  Option::<String>::deserialize(deserializer)?
+    .map(|value| T::from_str(&value, false).map_err(D::Error::custom))
+    .transpose()
+}
+
+fn value_enum_list<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: ValueEnum,
+{
+  Option::<Vec<String>>::deserialize(deserializer)?
+    .map(|values| {
+      values
+        .iter()
+        .map(|value| T::from_str(value, false).map_err(D::Error::custom))
+        .collect()
+    })
+    .transpose()
+}
+
+fn toml_table_to_json(table: &toml_edit::Table) -> Value {
+  Value::Object(
+    table
+      .iter()
+      .filter_map(|(key, item)| Some((key.to_string(), toml_item_to_json(item)?)))
+      .collect::<Map<_, _>>(),
+  )
+}
+
+fn toml_item_to_json(item: &toml_edit::Item) -> Option<Value> {
+  match item {
+    toml_edit::Item::None => None,
+    toml_edit::Item::Value(value) => Some(toml_value_to_json(value)),
+    toml_edit::Item::Table(table) => Some(toml_table_to_json(table)),
+    toml_edit::Item::ArrayOfTables(tables) => Some(Value::Array(tables.iter().map(toml_table_to_json).collect())),
+  }
+}
+
+fn toml_value_to_json(value: &toml_edit::Value) -> Value {
+  match value {
+    toml_edit::Value::String(value) => Value::String(value.value().clone()),
+    toml_edit::Value::Integer(value) => Value::from(*value.value()),
+    toml_edit::Value::Float(value) => Value::from(*value.value()),
+    toml_edit::Value::Boolean(value) => Value::Bool(*value.value()),
+    toml_edit::Value::Datetime(value) => Value::String(value.value().to_string()),
+    toml_edit::Value::Array(values) => Value::Array(values.iter().map(toml_value_to_json).collect()),
+    toml_edit::Value::InlineTable(table) => Value::Object(
+      table
+        .iter()
+        .map(|(key, value)| (key.to_string(), toml_value_to_json(value)))
+        .collect(),
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_toml_config_file() {
+    let config = ConfigFile::from_toml(
+      r#"
+input = "openapi.yaml"
+mode = "client-mod"
+
+[options]
+visibility = "crate"
+typed-errors = true
+customize = { date_time = "crate::MyDateTime" }
+strict-types = ["Order"]
+
+[client]
+visibility = "public"
+retry = true
+
+[filter]
+include-tags = ["pets"]
+
+[names]
+Pet = "Animal"
+
+[rust-types]
+UserId = "my_crate::UserId"
+
+[templates]
+client-method = "templates/method.rs"
+"#,
+    )
+    .unwrap()
+    .relative_to(Path::new("api"));
+
+    assert_eq!(config.input, Some(PathBuf::from("api/openapi.yaml")));
+    assert_eq!(config.mode, Some(GenerateMode::ClientMod));
+    assert_eq!(config.filter.include_tags, Some(vec!["pets".to_string()]));
+    assert_eq!(config.names.get("Pet"), Some(&"Animal".to_string()));
+    assert_eq!(config.rust_types.get("UserId"), Some(&"my_crate::UserId".to_string()));
+    assert_eq!(
+      config.templates.client_method,
+      Some(PathBuf::from("api/templates/method.rs"))
+    );
+
+    let client = config.options_for(EmitTarget::Client);
+    assert_eq!(client.visibility, Some(Visibility::Public));
+    assert_eq!(client.typed_errors, Some(true));
+    assert_eq!(client.retry, Some(true));
+    assert_eq!(client.strict_types, Some(vec!["Order".to_string()]));
+    assert_eq!(
+      client.customize.get("date_time"),
+      Some(&"crate::MyDateTime".to_string())
+    );
+
+    let types = config.options_for(EmitTarget::Types);
+    assert_eq!(types.visibility, Some(Visibility::Crate));
+    assert_eq!(types.retry, None);
+  }
+
+  #[test]
+  fn test_yaml_config_file() {
+    let config: ConfigFile = yaml_serde::from_str(
+      "
+emit: [types, client]
+options:
+  enum-mode: relaxed
+  acronyms: [ETAG]
+server:
+  prelude: true
+",
+    )
+    .unwrap();
+
+    assert_eq!(config.emit, Some(vec![EmitTarget::Types, EmitTarget::Client]));
+    let server = config.options_for(EmitTarget::Server);
+    assert!(matches!(server.enum_mode, Some(EnumCaseMode::Relaxed)));
+    assert_eq!(server.acronyms, Some(vec!["ETAG".to_string()]));
+    assert_eq!(server.prelude, Some(true));
+  }
+
+  #[test]
+  fn test_config_file_rejects_unknown_keys_and_values() {
+    let err = ConfigFile::from_toml("[options]\nvisibilty = \"crate\"").unwrap_err();
+    assert!(err.to_string().contains("unknown field `visibilty`"), "{err}");
+
+    let err = ConfigFile::from_toml("mode = \"library\"").unwrap_err();
+    assert!(err.to_string().contains("library"), "{err}");
+
+    let err = ConfigFile::from_toml("naming-hook = \"names.py\"").unwrap_err();
+    assert!(err.to_string().contains("unknown field `naming-hook`"), "{err}");
+  }
+}
@@ -1,8 +1,13 @@
 pub mod cli;
 pub mod colors;
 pub mod commands;
+pub mod config;
 
-pub use cli::{Cli, Commands, EnumCaseMode, EnumLayout, GenerateCommand, GenerateMode, ListCommands};
+pub use cli::{
+  AcronymCaseMode, Cli, ClientRuntimeMode, Commands, DecimalCrate, DeserializeMode, DurationFormat, EmitTarget,
+  EnumCaseMode, EnumLayout, GenerateCommand, GenerateMode, GraphFormat, IntegerWidth, ListCommands, OutputFormat,
+  SplitBy,
+};
 pub use colors::Colors;
 
 fn term_width() -> u16 {
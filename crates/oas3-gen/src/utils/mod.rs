@@ -1,5 +1,6 @@
 pub mod refs;
 pub mod schema_ext;
+mod scoped;
 pub mod spec;
 
 pub(crate) use refs::{
@@ -7,3 +8,4 @@ pub(crate) use refs::{
   extract_union_fingerprint, parse_schema_ref_path,
 };
 pub(crate) use schema_ext::{SchemaExt, SchemaResolveExt, variant_is_nullable};
+pub(crate) use scoped::scoped;
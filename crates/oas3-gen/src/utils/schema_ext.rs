@@ -1,4 +1,3 @@
-use inflections::Inflect;
 use itertools::Itertools;
 use oas3::{
   Spec,
@@ -7,16 +6,33 @@ use oas3::{
 
 use crate::{
   generator::{
-    ast::{VariantContent, VariantDef},
+    ast::{EpochTimestamp, VariantContent, VariantDef},
     naming::{
-      constants::{REQUEST_BODY_SUFFIX, RESPONSE_PREFIX, RESPONSE_SUFFIX},
+      casing::to_pascal_case,
+      constants::{
+        DEFAULT_MONEY_SCALE, ENUM_NAMES_EXTENSION, ENUM_VARNAMES_EXTENSION, ERROR_MESSAGE_FIELD_EXTENSION,
+        EVENT_NAME_EXTENSION, FORMAT_EXTENSION, MONEY_FORMAT, NAME_EXTENSION, REQUEST_BODY_SUFFIX, RESPONSE_PREFIX,
+        RESPONSE_SUFFIX, RUST_TYPE_EXTENSION, SCALE_EXTENSION, TIMESTAMP_FORMAT_EXTENSION,
+      },
       identifiers::{sanitize, to_rust_type_name},
       inference::{NormalizedVariant, extract_common_variant_prefix},
     },
   },
-  utils::refs::{SchemaInspect, SchemaRefName},
+  utils::{
+    refs::{SchemaInspect, SchemaRefName},
+    spec::NULLABLE_EXTENSION,
+  },
 };
 
+/// Largest scale representable by `rust_decimal::Decimal`.
+const MAX_DECIMAL_SCALE: u32 = 28;
+
+/// Words taken from a description when it names a union variant.
+const MAX_DESCRIPTION_NAME_WORDS: usize = 3;
+
+/// Leading words skipped when deriving a name from a description.
+const DESCRIPTION_FILLER_WORDS: &[&str] = &["a", "an", "the"];
+
 pub(crate) trait SchemaResolveExt {
   fn resolve_object(&self, spec: &Spec) -> Result<ObjectSchema, RefError>;
 }
@@ -161,6 +177,12 @@ pub(crate) trait SchemaExt {
   /// Returns true if the schema has enum values defined.
   fn has_enum_values(&self) -> bool;
 
+  /// Returns true if the schema is a bare integer, number, or boolean `const`.
+  ///
+  /// These become single-variant enums serialized as their fixed value rather than
+  /// plain primitives that would accept any value.
+  fn is_scalar_const(&self) -> bool;
+
   /// Returns true if the schema has multiple enum values that a user can choose between.
   ///
   /// A single-value enum is semantically equivalent to `const` (no choice exists),
@@ -233,6 +255,80 @@ pub(crate) trait SchemaExt {
   /// ```
   fn is_constrained(&self) -> bool;
 
+  /// Returns the epoch encoding for a numeric timestamp schema.
+  ///
+  /// Recognizes `format: unix-time` (seconds) and the `x-timestamp-format` extension
+  /// (`epoch-seconds` or `epoch-millis`) on `integer` and `number` schemas.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "integer", "format": "unix-time" }                    => Some(Seconds)
+  /// { "type": "integer", "x-timestamp-format": "epoch-millis" }     => Some(Milliseconds)
+  /// { "type": "number", "format": "unix-time" }                     => Some(FractionalSeconds)
+  /// { "type": "string", "format": "unix-time" }                     => None
+  /// ```
+  fn epoch_timestamp(&self) -> Option<EpochTimestamp>;
+
+  /// Returns the number of decimal places for a string schema marked `x-format: money`.
+  ///
+  /// The scale comes from `x-scale`, or defaults to 2 when absent or out of range.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "string", "x-format": "money" }                 => Some(2)
+  /// { "type": "string", "x-format": "money", "x-scale": 4 }   => Some(4)
+  /// { "type": "number", "x-format": "money" }                 => None
+  /// ```
+  fn money_scale(&self) -> Option<u32>;
+
+  /// Returns true if `minimum` or `exclusiveMinimum` rules out negative values.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "integer", "minimum": 0 }           => true
+  /// { "type": "integer", "exclusiveMinimum": 0 }  => true
+  /// { "type": "integer", "minimum": -1 }          => false
+  /// { "type": "integer" }                         => false
+  /// ```
+  fn has_non_negative_minimum(&self) -> bool;
+
+  /// Returns the dotted property path from `x-error-message-field`, naming the field
+  /// that holds the human-readable message of an error body.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "object", "x-error-message-field": "error.description" } => Some("error.description")
+  /// ```
+  fn error_message_field(&self) -> Option<&str>;
+
+  /// Returns the SSE `event:` name from `x-event-name`, naming the server-sent event
+  /// whose `data` this schema describes.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "object", "x-event-name": "message_start" } => Some("message_start")
+  /// ```
+  fn event_name(&self) -> Option<&str>;
+
+  /// Returns the Rust type path from `x-rust-type`, naming an existing type to use in
+  /// place of one generated from the schema.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "string", "x-rust-type": "my_crate::UserId" } => Some("my_crate::UserId")
+  /// ```
+  fn rust_type_extension(&self) -> Option<&str>;
+
+  /// Checks if the schema carries `nullable: true` or `x-nullable: true`, which
+  /// OpenAPI 3.1 no longer reads as nullability.
+  ///
+  /// # Example
+  /// ```text
+  /// { "type": "string", "x-nullable": true } => true
+  /// { "type": "string", "nullable": false } => false
+  /// ```
+  fn has_nullable_extension(&self) -> bool;
+
   /// Checks if a schema matches the "relaxed enum" pattern.
   ///
   /// A relaxed enum is defined as having a freeform string variant (no enum values, no const)
@@ -242,9 +338,18 @@ pub(crate) trait SchemaExt {
   /// Infers a variant name for an inline schema in a union.
   fn infer_variant_name(&self, index: usize) -> String;
 
-  /// Infers a union variant label from the schema, checking const value, ref name, and title.
+  /// Infers a union variant label from the schema, checking const value, ref name, title,
+  /// and `x-name`, then the schema's shape. Shapes that only yield a placeholder such as
+  /// `Object` or `Variant0` fall back to words from the description first.
   fn infer_union_variant_label(&self, ref_name: Option<&str>, index: usize) -> String;
 
+  /// Returns the name given by the `x-name` extension.
+  fn name_extension(&self) -> Option<&str>;
+
+  /// Infers a name from the first words of the description's first sentence, such as
+  /// `TextBlock` for "A text block shown inline.".
+  fn infer_name_from_description(&self) -> Option<String>;
+
   /// Infers a variant name for an object schema based on its properties.
   fn infer_object_variant_name(&self) -> String;
 
@@ -265,7 +370,8 @@ pub(crate) trait SchemaExt {
   /// Extracts all enum variant definitions from the schema.
   ///
   /// Handles multiple patterns:
-  /// - Direct `enum` arrays: each value becomes a unit variant
+  /// - Direct `enum` arrays: each value becomes a unit variant, named by `x-enum-varnames`
+  ///   or `x-enumNames` when either lists one name per value
   /// - `const` values: single variant with schema's docs/deprecated
   /// - oneOf/anyOf with const variants: per-variant metadata preserved
   /// - Relaxed enum patterns: known values extracted from constrained variants
@@ -417,6 +523,17 @@ impl SchemaExt for ObjectSchema {
     !self.enum_values.is_empty()
   }
 
+  fn is_scalar_const(&self) -> bool {
+    self.enum_values.is_empty()
+      && self.properties.is_empty()
+      && !self.has_union()
+      && !self.has_intersection()
+      && self
+        .const_value
+        .as_ref()
+        .is_some_and(|value| value.is_number() || value.is_boolean())
+  }
+
   fn has_selectable_values(&self) -> bool {
     self.enum_values.len() > 1
   }
@@ -482,6 +599,79 @@ impl SchemaExt for ObjectSchema {
     !self.enum_values.is_empty() || self.const_value.is_some()
   }
 
+  fn epoch_timestamp(&self) -> Option<EpochTimestamp> {
+    let fractional = match self.single_type_or_nullable()? {
+      SchemaType::Integer => false,
+      SchemaType::Number => true,
+      _ => return None,
+    };
+    let encoding = self
+      .extensions
+      .get(TIMESTAMP_FORMAT_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+      .or(self.format.as_deref())?;
+    let millis = match encoding {
+      "unix-time" | "epoch-seconds" => false,
+      "epoch-millis" => true,
+      _ => return None,
+    };
+    Some(EpochTimestamp::new(millis, fractional))
+  }
+
+  fn money_scale(&self) -> Option<u32> {
+    let is_money = self
+      .extensions
+      .get(FORMAT_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+      == Some(MONEY_FORMAT);
+    if !is_money || !self.is_string_type() {
+      return None;
+    }
+    let scale = self
+      .extensions
+      .get(SCALE_EXTENSION)
+      .and_then(serde_json::Value::as_u64)
+      .and_then(|scale| u32::try_from(scale).ok())
+      .filter(|scale| *scale <= MAX_DECIMAL_SCALE)
+      .unwrap_or(DEFAULT_MONEY_SCALE);
+    Some(scale)
+  }
+
+  fn has_non_negative_minimum(&self) -> bool {
+    let non_negative =
+      |bound: Option<&serde_json::Number>| bound.and_then(serde_json::Number::as_f64).is_some_and(|b| b >= 0.0);
+    non_negative(self.minimum.as_ref()) || non_negative(self.exclusive_minimum.as_ref())
+  }
+
+  fn error_message_field(&self) -> Option<&str> {
+    self
+      .extensions
+      .get(ERROR_MESSAGE_FIELD_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+  }
+
+  fn event_name(&self) -> Option<&str> {
+    self
+      .extensions
+      .get(EVENT_NAME_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+  }
+
+  fn rust_type_extension(&self) -> Option<&str> {
+    self
+      .extensions
+      .get(RUST_TYPE_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+  }
+
+  fn has_nullable_extension(&self) -> bool {
+    self
+      .extensions
+      .get(NULLABLE_EXTENSION)
+      .and_then(serde_json::Value::as_bool)
+      .unwrap_or_default()
+  }
+
   fn is_relaxed_enum_pattern(&self) -> bool {
     has_mixed_string_variants(self.union_variants())
   }
@@ -528,7 +718,43 @@ impl SchemaExt for ObjectSchema {
       return to_rust_type_name(title);
     }
 
-    self.infer_variant_name(index)
+    if let Some(name) = self.name_extension() {
+      return to_rust_type_name(name);
+    }
+
+    let inferred = self.infer_variant_name(index);
+    let is_placeholder = matches!(inferred.as_str(), "Object" | "Mixed") || inferred == format!("Variant{index}");
+    if is_placeholder && let Some(name) = self.infer_name_from_description() {
+      return name;
+    }
+    inferred
+  }
+
+  fn name_extension(&self) -> Option<&str> {
+    self
+      .extensions
+      .get(NAME_EXTENSION)
+      .and_then(serde_json::Value::as_str)
+      .filter(|name| !name.trim().is_empty())
+  }
+
+  fn infer_name_from_description(&self) -> Option<String> {
+    let description = self.description.as_deref()?;
+    let sentence = description
+      .split(['.', ',', ';', ':', '(', '\n'])
+      .next()
+      .unwrap_or_default();
+    let words = sentence
+      .split_whitespace()
+      .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+      .skip_while(|word| DESCRIPTION_FILLER_WORDS.contains(&word.to_ascii_lowercase().as_str()))
+      .take_while(|word| !word.is_empty() && word.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_'))
+      .take(MAX_DESCRIPTION_NAME_WORDS)
+      .collect::<Vec<_>>();
+    if words.first()?.starts_with(|c: char| c.is_ascii_digit()) {
+      return None;
+    }
+    Some(to_rust_type_name(&words.join(" ")))
   }
 
   fn infer_object_variant_name(&self) -> String {
@@ -553,7 +779,7 @@ impl SchemaExt for ObjectSchema {
 
   fn infer_name_from_required_fields(&self) -> Option<String> {
     if self.required.len() == 1 {
-      return Some(self.required[0].to_pascal_case());
+      return Some(to_pascal_case(&self.required[0]));
     }
     None
   }
@@ -564,7 +790,7 @@ impl SchemaExt for ObjectSchema {
     if let Some(first) = ref_names.next()
       && ref_names.next().is_none()
     {
-      return Some(first.to_pascal_case());
+      return Some(to_pascal_case(&first));
     }
 
     None
@@ -572,7 +798,7 @@ impl SchemaExt for ObjectSchema {
 
   fn infer_name_from_single_property(&self) -> Option<String> {
     if self.properties.len() == 1 {
-      return self.properties.keys().next().map(|name| name.to_pascal_case());
+      return self.properties.keys().next().map(|name| to_pascal_case(name));
     }
     None
   }
@@ -629,7 +855,26 @@ impl SchemaExt for ObjectSchema {
 
   fn extract_enum_entries(&self, spec: &Spec) -> Vec<VariantDef> {
     if !self.enum_values.is_empty() {
-      return self.enum_values.iter().variants().collect();
+      let overrides = [ENUM_VARNAMES_EXTENSION, ENUM_NAMES_EXTENSION]
+        .into_iter()
+        .filter_map(|key| self.extensions.get(key))
+        .filter_map(serde_json::Value::as_array)
+        .find(|names| names.len() == self.enum_values.len());
+      return self
+        .enum_values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| {
+          let variant = VariantDef::builder()
+            .value(value)?
+            .content(VariantContent::Unit)
+            .build();
+          Some(match overrides.and_then(|names| names[index].as_str()) {
+            Some(name) => variant.with_name(name),
+            None => variant,
+          })
+        })
+        .collect();
     }
 
     if let Some(const_val) = &self.const_value {
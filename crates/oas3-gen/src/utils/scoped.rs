@@ -0,0 +1,24 @@
+use std::{cell::RefCell, thread::LocalKey};
+
+/// Replaces the value in `key` with `value` while `f` runs on the current thread,
+/// restoring the previous value afterwards, even when `f` panics.
+pub(crate) fn scoped<T: 'static, R>(key: &'static LocalKey<RefCell<T>>, value: T, f: impl FnOnce() -> R) -> R {
+  struct Restore<T: 'static> {
+    key: &'static LocalKey<RefCell<T>>,
+    previous: Option<T>,
+  }
+
+  impl<T: 'static> Drop for Restore<T> {
+    fn drop(&mut self) {
+      if let Some(previous) = self.previous.take() {
+        self.key.set(previous);
+      }
+    }
+  }
+
+  let _restore = Restore {
+    key,
+    previous: Some(key.replace(value)),
+  };
+  f()
+}
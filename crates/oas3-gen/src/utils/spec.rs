@@ -2,6 +2,42 @@ use std::{ffi::OsStr, path::Path};
 
 use fmmap::tokio::{AsyncMmapFile, AsyncMmapFileExt};
 use oas3::OpenApiV3Spec;
+use serde_json::Value;
+
+/// Extension key a schema's `not` keyword is kept under, since `oas3` drops the keyword.
+pub const NOT_EXTENSION: &str = "not";
+/// Extension key a schema's OpenAPI 3.0 `nullable` keyword is kept under, alongside
+/// the `x-nullable` vendor extension it shares with.
+pub const NULLABLE_EXTENSION: &str = "nullable";
+
+/// Keys whose object values map names to entries, so a `not` key inside them is a name
+/// rather than a keyword.
+const NAME_MAPS: &[&str] = &[
+  "$defs",
+  "callbacks",
+  "content",
+  "definitions",
+  "dependentSchemas",
+  "encoding",
+  "headers",
+  "links",
+  "mapping",
+  "parameters",
+  "pathItems",
+  "paths",
+  "patternProperties",
+  "properties",
+  "requestBodies",
+  "responses",
+  "schemas",
+  "scopes",
+  "securitySchemes",
+  "variables",
+  "webhooks",
+];
+
+/// Keys whose values are instance data rather than spec objects.
+const OPAQUE_KEYS: &[&str] = &["const", "default", "enum", "example", "examples", "value"];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SpecFormat {
@@ -18,6 +54,24 @@ impl SpecFormat {
       _ => Self::Json,
     }
   }
+
+  /// Picks the format from the extension of `path`, defaulting to JSON.
+  #[must_use]
+  pub fn from_path(path: &Path) -> Self {
+    path
+      .extension()
+      .and_then(OsStr::to_str)
+      .map_or(Self::default(), Self::from_extension)
+  }
+
+  /// Parses `content` in this format into a spec, keeping its `not` keywords.
+  pub fn parse(self, content: &[u8]) -> anyhow::Result<oas3::Spec> {
+    let document = match self {
+      Self::Json => serde_json::from_slice::<Value>(content)?,
+      Self::Yaml => yaml_serde::from_str::<Value>(std::str::from_utf8(content)?)?,
+    };
+    from_document(document)
+  }
 }
 
 pub struct SpecLoader {
@@ -27,23 +81,78 @@ pub struct SpecLoader {
 
 impl SpecLoader {
   pub async fn open(path: &Path) -> anyhow::Result<Self> {
-    let format = path
-      .extension()
-      .and_then(OsStr::to_str)
-      .map_or(SpecFormat::default(), SpecFormat::from_extension);
-
+    let format = SpecFormat::from_path(path);
     let file = unsafe { AsyncMmapFile::open(path).await? };
 
     Ok(Self { file, format })
   }
 
   pub fn parse(&self) -> anyhow::Result<oas3::Spec> {
-    match self.format {
-      SpecFormat::Json => Ok(serde_json::from_slice::<OpenApiV3Spec>(self.file.as_slice())?),
-      SpecFormat::Yaml => {
-        let content = std::str::from_utf8(self.file.as_slice())?;
-        Ok(oas3::from_yaml(content)?)
+    self.format.parse(self.file.as_slice())
+  }
+}
+
+/// Builds a spec from a parsed JSON or YAML document, keeping its `not` and `nullable`
+/// keywords.
+pub fn from_document(mut document: Value) -> anyhow::Result<oas3::Spec> {
+  preserve_keywords(&mut document);
+  Ok(serde_json::from_value::<OpenApiV3Spec>(document)?)
+}
+
+/// Moves each schema's `not` keyword to an `x-not` extension, where it survives parsing
+/// as the [`NOT_EXTENSION`] entry of the schema's extensions.
+///
+/// A `nullable` keyword moves to `x-nullable` the same way. A `$ref` with an
+/// `x-nullable` sibling is wrapped in a single-entry `allOf`, because a reference
+/// object keeps no extensions when parsed.
+///
+/// Keys of name maps such as `properties`, and instance data such as `example`, are left
+/// alone, so a property named `not` stays a property.
+pub fn preserve_keywords(document: &mut Value) {
+  rewrite_keywords(document, false);
+}
+
+fn preserve_nullable(map: &mut serde_json::Map<String, Value>) {
+  let extension = format!("x-{NULLABLE_EXTENSION}");
+  if let Some(nullable) = map.remove(NULLABLE_EXTENSION) {
+    map.entry(extension.clone()).or_insert(nullable);
+  }
+  if map.contains_key(&extension)
+    && let Some(reference) = map.remove("$ref")
+  {
+    map.insert(
+      "allOf".to_string(),
+      Value::Array(vec![serde_json::json!({ "$ref": reference })]),
+    );
+  }
+}
+
+fn rewrite_keywords(value: &mut Value, in_name_map: bool) {
+  match value {
+    Value::Object(map) => {
+      let extension = format!("x-{NOT_EXTENSION}");
+      if !in_name_map
+        && !map.contains_key(&extension)
+        && let Some(not) = map.remove("not")
+      {
+        map.insert(extension, not);
+      }
+      if !in_name_map {
+        preserve_nullable(map);
+      }
+      for (key, child) in map.iter_mut() {
+        if in_name_map {
+          rewrite_keywords(child, false);
+        } else if !key.starts_with("x-") && !OPAQUE_KEYS.contains(&key.as_str()) {
+          rewrite_keywords(child, NAME_MAPS.contains(&key.as_str()));
+        }
+      }
+    }
+    Value::Array(items) => {
+      for item in items {
+        rewrite_keywords(item, false);
       }
     }
+    _ => {}
   }
 }